@@ -31,6 +31,38 @@ use crate::*;
 /// - This layer is auto-added to the operator if it's accessor doesn't support blocking APIs.
 ///
 /// Tracking issue: #2678
+///
+/// # Runtime
+///
+/// The runtime handle is captured once while building the layer and reused
+/// by every blocking call, no runtime will be created per call. Please make
+/// sure the runtime outlives the operator.
+///
+/// Use [`BlockingLayer::create`] inside a runtime context, or supply the
+/// handle of a runtime created by yourself via [`BlockingLayer::with_handle`]:
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::layers::BlockingLayer;
+/// use opendal::services;
+/// use opendal::BlockingOperator;
+/// use opendal::Operator;
+///
+/// fn main() -> Result<()> {
+///     // Create the runtime once and reuse it for all blocking operators.
+///     let runtime = tokio::runtime::Builder::new_multi_thread()
+///         .enable_all()
+///         .build()?;
+///
+///     let op: BlockingOperator = Operator::new(services::Memory::default())?
+///         .layer(BlockingLayer::with_handle(runtime.handle().clone()))
+///         .finish()
+///         .blocking();
+///
+///     let bs = op.read("path/to/file")?;
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug, Clone)]
 pub struct BlockingLayer {
     handle: Handle,
@@ -44,6 +76,13 @@ impl BlockingLayer {
                 .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to get current handle"))?,
         })
     }
+
+    /// Create a new `BlockingLayer` with the given runtime's handle.
+    ///
+    /// This is useful while building operators outside of a runtime context.
+    pub fn with_handle(handle: Handle) -> Self {
+        Self { handle }
+    }
 }
 
 impl<A: Accessor> Layer<A> for BlockingLayer {
@@ -174,6 +213,10 @@ impl<A: Accessor> LayeredAccessor for BlockingAccessor<A> {
             Ok((rp, blocking_pager))
         })
     }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.handle.block_on(self.inner.presign(path, args))
+    }
 }
 
 pub struct BlockingWrapper<I> {
@@ -256,4 +299,81 @@ mod tests {
         let layer = BlockingLayer::create();
         assert!(layer.is_ok());
     }
+
+    #[test]
+    #[cfg(feature = "services-fs")]
+    fn test_blocking_layer_with_handle() -> Result<()> {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering;
+        use std::sync::Arc;
+
+        // Count threads started by the runtime. Current thread runtime
+        // doesn't have workers, so threads are only started by its
+        // blocking pool while `tokio::fs` is used.
+        let started = Arc::new(AtomicUsize::new(0));
+        let runtime = {
+            let started = started.clone();
+            tokio::runtime::Builder::new_current_thread()
+                .on_thread_start(move || {
+                    started.fetch_add(1, Ordering::SeqCst);
+                })
+                .enable_all()
+                .build()
+                .unwrap()
+        };
+        assert_eq!(started.load(Ordering::SeqCst), 0);
+
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let mut builder = services::Fs::default();
+        builder.root(&root.to_string_lossy());
+
+        // Build operator outside of runtime context.
+        let op = Operator::new(builder)?
+            .layer(BlockingLayer::with_handle(runtime.handle().clone()))
+            .finish()
+            .blocking();
+        op.write("test", "Hello, World!")?;
+        assert_eq!(op.read("test")?, b"Hello, World!");
+
+        // Fs operations are spawned on the blocking pool of given runtime.
+        assert!(started.load(Ordering::SeqCst) > 0);
+
+        std::fs::remove_dir_all(root).expect("remove root must succeed");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "services-s3")]
+    fn test_blocking_presign() -> Result<()> {
+        use std::time::Duration;
+
+        let mut builder = services::S3::default();
+        builder
+            .bucket("test")
+            .endpoint("http://127.0.0.1:9000")
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key");
+
+        let op = Operator::new(builder)?
+            .layer(create_blocking_layer()?)
+            .finish()
+            .blocking();
+
+        let req = op.presign_read("test.txt", Duration::from_secs(3600))?;
+        assert_eq!(req.method(), http::Method::GET);
+        assert!(req
+            .uri()
+            .to_string()
+            .starts_with("http://127.0.0.1:9000/test/test.txt?"));
+        assert!(req.uri().query().unwrap().contains("X-Amz-Signature"));
+
+        let req = op.presign_write("test.txt", Duration::from_secs(3600))?;
+        assert_eq!(req.method(), http::Method::PUT);
+
+        let req = op.presign_stat("test.txt", Duration::from_secs(3600))?;
+        assert_eq!(req.method(), http::Method::HEAD);
+
+        Ok(())
+    }
 }
@@ -118,6 +118,26 @@ use crate::*;
 /// - if only `hierarchy`, with [`oio::to_hierarchy_pager`].
 /// - If neither not supported, something must be wrong.
 ///
+/// ## Rename Completion
+///
+/// Object storage services like s3 and gcs don't support rename natively.
+/// If underlying services support both `copy` and `delete`, CompleteLayer
+/// will emulate rename by copying `from` to `to` and then deleting `from`.
+/// If the delete fails, CompleteLayer will try its best to delete `to` so
+/// that callers won't see both files. Emulated rename is not atomic, and
+/// will be advertised via [`Capability::rename_emulated`].
+///
+/// ## Recursive Delete Completion
+///
+/// Services like `s3` and `sftp` can't delete a directory with all its
+/// children natively. If `recursive` is set and the service doesn't support
+/// [`Capability::delete_with_recursive`], CompleteLayer will list the
+/// directory without delimiter and delete all children before deleting the
+/// directory itself.
+///
+/// Deleting a non-empty directory without `recursive` will return an
+/// [`ErrorKind::IsADirectory`] error instead.
+///
 /// ## Capability Check
 ///
 /// Before performing any operations, `CompleteLayer` will first check
@@ -131,7 +151,14 @@ impl<A: Accessor> Layer<A> for CompleteLayer {
     type LayeredAccessor = CompleteReaderAccessor<A>;
 
     fn layer(&self, inner: A) -> Self::LayeredAccessor {
-        let meta = inner.info();
+        let mut meta = inner.info();
+
+        let mut cap = meta.capability();
+        if !cap.rename && cap.copy && cap.delete {
+            cap.rename_emulated = true;
+            meta.set_capability(cap);
+        }
+
         CompleteReaderAccessor {
             meta,
             inner: Arc::new(inner),
@@ -161,13 +188,25 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
         if !capability.read {
             return new_capability_unsupported_error(Operation::Read);
         }
+        if args.verify() && !capability.read_with_verify {
+            return new_capability_unsupported_error(Operation::Read);
+        }
+        if args.if_range().is_some() && !capability.read_with_if_range {
+            return new_capability_unsupported_error(Operation::Read);
+        }
 
         let seekable = capability.read_can_seek;
         let streamable = capability.read_can_next;
 
-        let range = args.range();
+        let if_range = args.if_range().is_some();
+        let mut range = args.range();
         let (rp, r) = self.inner.read(path, args).await?;
         let content_length = rp.metadata().content_length();
+        // The whole content will be returned without `Content-Range` if
+        // `If-Range` doesn't match.
+        if if_range && !rp.metadata().contains_bit(Metakey::ContentRange) {
+            range = BytesRange::default();
+        }
 
         match (seekable, streamable) {
             (true, true) => Ok((rp, CompleteReader::AlreadyComplete(r))),
@@ -214,13 +253,23 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
         if !capability.read || !capability.blocking {
             return new_capability_unsupported_error(Operation::BlockingRead);
         }
+        if args.verify() && !capability.read_with_verify {
+            return new_capability_unsupported_error(Operation::BlockingRead);
+        }
+        if args.if_range().is_some() && !capability.read_with_if_range {
+            return new_capability_unsupported_error(Operation::BlockingRead);
+        }
 
         let seekable = capability.read_can_seek;
         let streamable = capability.read_can_next;
 
-        let range = args.range();
+        let if_range = args.if_range().is_some();
+        let mut range = args.range();
         let (rp, r) = self.inner.blocking_read(path, args)?;
         let content_length = rp.metadata().content_length();
+        if if_range && !rp.metadata().contains_bit(Metakey::ContentRange) {
+            range = BytesRange::default();
+        }
 
         match (seekable, streamable) {
             (true, true) => Ok((rp, CompleteReader::AlreadyComplete(r))),
@@ -274,6 +323,14 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
                     .with_operation("list"),
             );
         }
+        if args.continuation().is_some() && !cap.list_with_continuation {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "list with continuation is not supported",
+            )
+            .with_context("service", self.meta.scheme())
+            .with_operation("list"));
+        }
 
         let delimiter = args.delimiter();
 
@@ -302,6 +359,11 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
             };
         }
 
+        if cap.list_with_delimiter_custom {
+            let (rp, p) = self.inner.list(path, args).await?;
+            return Ok((rp, CompletePager::AlreadyComplete(p)));
+        }
+
         Err(Error::new(
             ErrorKind::Unsupported,
             "list with other delimiter is not supported",
@@ -310,6 +372,119 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
         .with_context("delimiter", delimiter))
     }
 
+    async fn complete_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let cap = self.meta.capability();
+        // Only recursive delete against directories need to be completed.
+        if !path.ends_with('/') || !args.recursive() || cap.delete_with_recursive {
+            let recursive = args.recursive();
+            return match ignore_not_found(self.inner.delete(path, args).await) {
+                // Services return all kinds of errors for non-empty dir, we
+                // only check it after delete failed to avoid extra list.
+                Err(err) if path.ends_with('/') && !recursive && cap.list => {
+                    match self.is_non_empty_dir(path).await {
+                        Ok(true) => Err(new_dir_not_empty_error(path, err)),
+                        _ => Err(err),
+                    }
+                }
+                res => res,
+            };
+        }
+
+        let (_, mut p) = match self
+            .complete_list(path, OpList::new().with_delimiter(""))
+            .await
+        {
+            Ok(v) => v,
+            // The dir to delete doesn't exist.
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(RpDelete::default()),
+            Err(err) => return Err(err),
+        };
+        let mut dirs = vec![];
+        while let Some(entries) = oio::Page::next(&mut p).await? {
+            for entry in entries {
+                if entry.path() == path {
+                    continue;
+                }
+                if entry.mode().is_dir() {
+                    dirs.push(entry.path().to_string());
+                    continue;
+                }
+                ignore_not_found(self.inner.delete(entry.path(), OpDelete::new()).await)?;
+            }
+        }
+
+        // Nested dirs must be deleted before their parents.
+        dirs.sort_by_key(|v| std::cmp::Reverse(v.len()));
+        for dir in dirs {
+            ignore_not_found(self.inner.delete(&dir, OpDelete::new()).await)?;
+        }
+
+        ignore_not_found(self.inner.delete(path, args.with_recursive(false)).await)
+    }
+
+    fn complete_blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let cap = self.meta.capability();
+        if !path.ends_with('/') || !args.recursive() || cap.delete_with_recursive {
+            let recursive = args.recursive();
+            return match ignore_not_found(self.inner.blocking_delete(path, args)) {
+                Err(err) if path.ends_with('/') && !recursive && cap.list => {
+                    match self.is_blocking_non_empty_dir(path) {
+                        Ok(true) => Err(new_dir_not_empty_error(path, err)),
+                        _ => Err(err),
+                    }
+                }
+                res => res,
+            };
+        }
+
+        let (_, mut p) = match self.complete_blocking_list(path, OpList::new().with_delimiter("")) {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(RpDelete::default()),
+            Err(err) => return Err(err),
+        };
+        let mut dirs = vec![];
+        while let Some(entries) = oio::BlockingPage::next(&mut p)? {
+            for entry in entries {
+                if entry.path() == path {
+                    continue;
+                }
+                if entry.mode().is_dir() {
+                    dirs.push(entry.path().to_string());
+                    continue;
+                }
+                ignore_not_found(self.inner.blocking_delete(entry.path(), OpDelete::new()))?;
+            }
+        }
+
+        dirs.sort_by_key(|v| std::cmp::Reverse(v.len()));
+        for dir in dirs {
+            ignore_not_found(self.inner.blocking_delete(&dir, OpDelete::new()))?;
+        }
+
+        ignore_not_found(self.inner.blocking_delete(path, args.with_recursive(false)))
+    }
+
+    /// Check if the given dir contains any children by listing its first page.
+    async fn is_non_empty_dir(&self, path: &str) -> Result<bool> {
+        let (_, mut p) = self.complete_list(path, OpList::new()).await?;
+        while let Some(entries) = oio::Page::next(&mut p).await? {
+            if entries.iter().any(|v| v.path() != path) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn is_blocking_non_empty_dir(&self, path: &str) -> Result<bool> {
+        let (_, mut p) = self.complete_blocking_list(path, OpList::new())?;
+        while let Some(entries) = oio::BlockingPage::next(&mut p)? {
+            if entries.iter().any(|v| v.path() != path) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     fn complete_blocking_list(
         &self,
         path: &str,
@@ -352,6 +527,11 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
             };
         }
 
+        if cap.list_with_delimiter_custom {
+            let (rp, p) = self.inner.blocking_list(path, args)?;
+            return Ok((rp, CompletePager::AlreadyComplete(p)));
+        }
+
         Err(Error::new(
             ErrorKind::Unsupported,
             "list with other delimiter is not supported",
@@ -376,6 +556,10 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         &self.inner
     }
 
+    fn metadata(&self) -> AccessorInfo {
+        self.meta.clone()
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         self.complete_reader(path, args).await
     }
@@ -417,6 +601,20 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.write {
             return new_capability_unsupported_error(Operation::Write);
         }
+        if args.chunked_unknown_size()
+            && !capability.write_without_content_length
+            && !capability.write_can_chunked
+        {
+            return new_capability_unsupported_error(Operation::Write);
+        }
+        check_write_with_object_lock(capability, &args, Operation::Write)?;
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            match self.inner.stat(path, OpStat::new()).await {
+                Ok(_) => return Err(new_already_exists_error(Operation::Write, path)),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
 
         let size = args.content_length();
         self.inner
@@ -430,6 +628,14 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.write || !capability.blocking {
             return new_capability_unsupported_error(Operation::BlockingWrite);
         }
+        check_write_with_object_lock(capability, &args, Operation::BlockingWrite)?;
+        if args.if_not_exists() && !capability.write_with_if_not_exists {
+            match self.inner.blocking_stat(path, OpStat::new()) {
+                Ok(_) => return Err(new_already_exists_error(Operation::BlockingWrite, path)),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
 
         let size = args.content_length();
         self.inner
@@ -473,7 +679,7 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
             return new_capability_unsupported_error(Operation::Delete);
         }
 
-        self.inner().delete(path, args).await
+        self.complete_delete(path, args).await
     }
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
@@ -482,7 +688,7 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
             return new_capability_unsupported_error(Operation::BlockingDelete);
         }
 
-        self.inner().blocking_delete(path, args)
+        self.complete_blocking_delete(path, args)
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
@@ -490,6 +696,16 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.copy {
             return new_capability_unsupported_error(Operation::Copy);
         }
+        if !args.range().is_full() && !capability.copy_with_range {
+            return new_capability_unsupported_error(Operation::Copy);
+        }
+        if args.if_not_exists() && !capability.copy_with_if_not_exists {
+            match self.inner.stat(to, OpStat::new()).await {
+                Ok(_) => return Err(new_already_exists_error(Operation::Copy, to)),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
 
         self.inner().copy(from, to, args).await
     }
@@ -499,26 +715,59 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         if !capability.copy || !capability.blocking {
             return new_capability_unsupported_error(Operation::BlockingCopy);
         }
+        if !args.range().is_full() && !capability.copy_with_range {
+            return new_capability_unsupported_error(Operation::BlockingCopy);
+        }
+        if args.if_not_exists() && !capability.copy_with_if_not_exists {
+            match self.inner.blocking_stat(to, OpStat::new()) {
+                Ok(_) => return Err(new_already_exists_error(Operation::BlockingCopy, to)),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
 
         self.inner().blocking_copy(from, to, args)
     }
 
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
         let capability = self.meta.capability();
-        if !capability.rename {
+        if capability.rename {
+            return self.inner().rename(from, to, args).await;
+        }
+        if !capability.rename_emulated {
             return new_capability_unsupported_error(Operation::Rename);
         }
 
-        self.inner().rename(from, to, args).await
+        self.inner().copy(from, to, OpCopy::new()).await?;
+        if let Err(err) = self.inner().delete(from, OpDelete::new()).await {
+            // Roll back the copy so that callers won't see both files.
+            let _ = self.inner().delete(to, OpDelete::new()).await;
+            return Err(err);
+        }
+
+        Ok(RpRename::default())
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
         let capability = self.meta.capability();
-        if !capability.rename || !capability.blocking {
+        if !capability.blocking {
+            return new_capability_unsupported_error(Operation::BlockingRename);
+        }
+        if capability.rename {
+            return self.inner().blocking_rename(from, to, args);
+        }
+        if !capability.rename_emulated {
             return new_capability_unsupported_error(Operation::BlockingRename);
         }
 
-        self.inner().blocking_rename(from, to, args)
+        self.inner().blocking_copy(from, to, OpCopy::new())?;
+        if let Err(err) = self.inner().blocking_delete(from, OpDelete::new()) {
+            // Roll back the copy so that callers won't see both files.
+            let _ = self.inner().blocking_delete(to, OpDelete::new());
+            return Err(err);
+        }
+
+        Ok(RpRename::default())
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
@@ -539,6 +788,15 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         self.complete_blocking_list(path, args)
     }
 
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let capability = self.meta.capability();
+        if !capability.presign || !capability.blocking {
+            return new_capability_unsupported_error(Operation::BlockingPresign);
+        }
+
+        self.inner.blocking_presign(path, args)
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let capability = self.meta.capability();
         if !capability.presign {
@@ -548,11 +806,34 @@ impl<A: Accessor> LayeredAccessor for CompleteReaderAccessor<A> {
         self.inner.presign(path, args).await
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        let capability = self.meta.capability();
+        if !capability.write || !capability.write_can_multi {
+            return new_capability_unsupported_error(Operation::Write);
+        }
+
+        self.inner.multipart(path, args).await
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let capability = self.meta.capability();
         if !capability.batch {
             return new_capability_unsupported_error(Operation::Batch);
         }
+        // Services that don't support batch write will treat all operations
+        // as delete, we must reject them here.
+        if !capability.batch_write
+            && args
+                .operation()
+                .iter()
+                .any(|(_, op)| matches!(op, BatchOperation::Write(..)))
+        {
+            return new_capability_unsupported_error(Operation::Batch);
+        }
 
         self.inner().batch(args).await
     }
@@ -664,6 +945,16 @@ where
             NeedHierarchy(p) => p.next().await,
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        use CompletePager::*;
+
+        // Token of emulated listing can't be used to continue.
+        match self {
+            AlreadyComplete(p) => p.continuation_token(),
+            NeedFlat(_) | NeedHierarchy(_) => None,
+        }
+    }
 }
 
 impl<A, P> oio::BlockingPage for CompletePager<A, P>
@@ -759,6 +1050,20 @@ where
         Ok(())
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        if let Some(size) = self.size {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                &format!("writer expects {size} bytes, can't sink stream of unknown size"),
+            ));
+        }
+
+        let w = self.inner.as_mut().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
+        })?;
+        w.sink_unsized(s).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
@@ -897,12 +1202,55 @@ fn new_capability_unsupported_error<R>(operation: Operation) -> Result<R> {
     Err(Error::new(ErrorKind::Unsupported, "operation is not supported").with_operation(operation))
 }
 
+/// Object lock mode and retain until date must be set together.
+fn check_write_with_object_lock(
+    capability: Capability,
+    args: &OpWrite,
+    operation: Operation,
+) -> Result<()> {
+    match (args.object_lock_mode(), args.retain_until()) {
+        (None, None) => Ok(()),
+        (Some(_), Some(_)) if capability.write_with_object_lock => Ok(()),
+        (Some(_), Some(_)) => new_capability_unsupported_error(operation),
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "object lock mode and retain until must be set together",
+        )
+        .with_operation(operation)),
+    }
+}
+
+fn new_already_exists_error(operation: Operation, path: &str) -> Error {
+    Error::new(ErrorKind::AlreadyExists, "the target path already exists")
+        .with_operation(operation)
+        .with_context("path", path)
+}
+
+/// Delete is idempotent: deleting a path that doesn't exist is not an error.
+fn ignore_not_found(res: Result<RpDelete>) -> Result<RpDelete> {
+    match res {
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(RpDelete::default()),
+        res => res,
+    }
+}
+
+fn new_dir_not_empty_error(path: &str, source: Error) -> Error {
+    Error::new(
+        ErrorKind::IsADirectory,
+        "directory is not empty, please delete with recursive",
+    )
+    .with_operation(Operation::Delete)
+    .with_context("path", path)
+    .set_source(source)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use std::time::Duration;
 
     use async_trait::async_trait;
+    use chrono::Utc;
     use http::HeaderMap;
     use http::Method as HttpMethod;
 
@@ -981,7 +1329,10 @@ mod tests {
             Ok(RpCreateDir {})
         }
 
-        async fn delete(&self, _: &str, _: OpDelete) -> Result<RpDelete> {
+        async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+            if path.contains("not_exist") {
+                return Err(Error::new(ErrorKind::NotFound, "path not found"));
+            }
             Ok(RpDelete {})
         }
 
@@ -1046,6 +1397,136 @@ mod tests {
         op.rename("/path/to/mock_file", "/path/to/mock_file_2")
     });
     capability_test!(list, |op| { op.lister("/path/to/mock_dir/") });
+
+    #[tokio::test]
+    async fn test_capability_rename_emulated() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            copy: true,
+            delete: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+        assert!(op.info().capability().rename_emulated);
+        assert!(!op.info().capability().rename_atomic);
+        assert!(op.info().can_rename());
+
+        op.rename("/path/to/mock_file", "/path/to/mock_file_2")
+            .await
+            .expect("emulated rename should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_delete_not_exist() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            delete: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+
+        op.delete("/path/to/not_exist_file")
+            .await
+            .expect("delete not exist file should succeed");
+        op.remove(vec!["/path/to/not_exist_file".to_string()])
+            .await
+            .expect("remove not exist file should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_capability_read_with_verify() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            read: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+
+        let err = op
+            .read_with("/path/to/mock_file")
+            .verify(true)
+            .await
+            .expect_err("read with verify should fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn test_capability_write_chunked_unknown_size() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            write: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+
+        let res = op
+            .writer_with("/path/to/mock_file")
+            .chunked_unknown_size()
+            .await;
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capability_copy_with_range() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            copy: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+
+        op.copy("/path/to/mock_file", "/path/to/mock_file_2")
+            .await
+            .expect("copy must succeed");
+        let res = op
+            .copy_with("/path/to/mock_file", "/path/to/mock_file_2")
+            .range(1024..2048)
+            .await;
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capability_write_with_object_lock() {
+        let builder = MockBuilder::default().with_capacity(Capability {
+            write: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+        let retain_until = Utc::now() + chrono::Duration::days(1);
+
+        let res = op
+            .writer_with("/path/to/mock_file")
+            .object_lock_mode(ObjectLockMode::Governance)
+            .retain_until(retain_until)
+            .await;
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::Unsupported)
+        );
+
+        let builder = MockBuilder::default().with_capacity(Capability {
+            write: true,
+            write_with_object_lock: true,
+            ..Default::default()
+        });
+        let op = Operator::new(builder).expect("should build").finish();
+
+        op.writer_with("/path/to/mock_file")
+            .object_lock_mode(ObjectLockMode::Governance)
+            .retain_until(retain_until)
+            .await
+            .expect("write with object lock must succeed");
+        let res = op
+            .writer_with("/path/to/mock_file")
+            .object_lock_mode(ObjectLockMode::Compliance)
+            .await;
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::InvalidInput)
+        );
+    }
+
     capability_test!(presign, |op| {
         op.presign_read("/path/to/mock_file", Duration::from_secs(1))
     });
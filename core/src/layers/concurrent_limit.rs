@@ -25,6 +25,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
+use tokio::sync::SemaphorePermit;
 
 use crate::raw::*;
 use crate::*;
@@ -36,6 +37,21 @@ use crate::*;
 /// Users can control how many concurrent connections could be established
 /// between OpenDAL and underlying storage services.
 ///
+/// ## Operation Classes
+///
+/// Operations are grouped into classes:
+///
+/// - `read`: `read` and `stat`
+/// - `write`: `write`, `append`, `multipart`, `create_dir`, `delete` and `batch`
+/// - `list`: `list`
+///
+/// Users can set separate permits for every class via
+/// [`ConcurrentLimitLayer::with_read_permits`],
+/// [`ConcurrentLimitLayer::with_write_permits`] and
+/// [`ConcurrentLimitLayer::with_list_permits`]. Classes with their own
+/// permits will not contend with other classes. Classes without their own
+/// permits share the global permits.
+///
 /// # Examples
 ///
 /// ```
@@ -50,15 +66,59 @@ use crate::*;
 ///     .layer(ConcurrentLimitLayer::new(1024))
 ///     .finish();
 /// ```
+///
+/// Allow many reads while capping expensive writes:
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::ConcurrentLimitLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         ConcurrentLimitLayer::new(1024)
+///             .with_read_permits(1024)
+///             .with_write_permits(8),
+///     )
+///     .finish();
+/// ```
 #[derive(Clone)]
 pub struct ConcurrentLimitLayer {
     permits: usize,
+    read_permits: Option<usize>,
+    write_permits: Option<usize>,
+    list_permits: Option<usize>,
 }
 
 impl ConcurrentLimitLayer {
     /// Create a new ConcurrentLimitLayer will specify permits
     pub fn new(permits: usize) -> Self {
-        Self { permits }
+        Self {
+            permits,
+            read_permits: None,
+            write_permits: None,
+            list_permits: None,
+        }
+    }
+
+    /// Set separate permits for read operations.
+    pub fn with_read_permits(mut self, permits: usize) -> Self {
+        self.read_permits = Some(permits);
+        self
+    }
+
+    /// Set separate permits for write operations.
+    pub fn with_write_permits(mut self, permits: usize) -> Self {
+        self.write_permits = Some(permits);
+        self
+    }
+
+    /// Set separate permits for list operations.
+    pub fn with_list_permits(mut self, permits: usize) -> Self {
+        self.list_permits = Some(permits);
+        self
     }
 }
 
@@ -66,9 +126,17 @@ impl<A: Accessor> Layer<A> for ConcurrentLimitLayer {
     type LayeredAccessor = ConcurrentLimitAccessor<A>;
 
     fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        let semaphore = Arc::new(Semaphore::new(self.permits));
+        let class_semaphore = |permits: Option<usize>| match permits {
+            Some(permits) => Arc::new(Semaphore::new(permits)),
+            None => semaphore.clone(),
+        };
+
         ConcurrentLimitAccessor {
             inner,
-            semaphore: Arc::new(Semaphore::new(self.permits)),
+            read_semaphore: class_semaphore(self.read_permits),
+            write_semaphore: class_semaphore(self.write_permits),
+            list_semaphore: class_semaphore(self.list_permits),
         }
     }
 }
@@ -76,7 +144,9 @@ impl<A: Accessor> Layer<A> for ConcurrentLimitLayer {
 #[derive(Debug, Clone)]
 pub struct ConcurrentLimitAccessor<A: Accessor> {
     inner: A,
-    semaphore: Arc<Semaphore>,
+    read_semaphore: Arc<Semaphore>,
+    write_semaphore: Arc<Semaphore>,
+    list_semaphore: Arc<Semaphore>,
 }
 
 #[async_trait]
@@ -96,7 +166,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let _permit = self
-            .semaphore
+            .write_semaphore
             .acquire()
             .await
             .expect("semaphore must be valid");
@@ -106,7 +176,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let permit = self
-            .semaphore
+            .read_semaphore
             .clone()
             .acquire_owned()
             .await
@@ -120,7 +190,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let permit = self
-            .semaphore
+            .write_semaphore
             .clone()
             .acquire_owned()
             .await
@@ -134,7 +204,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
         let permit = self
-            .semaphore
+            .write_semaphore
             .clone()
             .acquire_owned()
             .await
@@ -146,9 +216,32 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
             .map(|(rp, a)| (rp, ConcurrentLimitWrapper::new(a, permit)))
     }
 
+    /// Parts could be uploaded concurrently, so every call of the returned
+    /// uploader will acquire a permit instead of holding one for the whole
+    /// upload.
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        let _permit = self
+            .write_semaphore
+            .acquire()
+            .await
+            .expect("semaphore must be valid");
+
+        self.inner.multipart(path, args).await.map(|(rp, u)| {
+            let u: oio::MultipartUploader = Box::new(ConcurrentLimitUploader {
+                inner: u,
+                semaphore: self.write_semaphore.clone(),
+            });
+            (rp, u)
+        })
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let _permit = self
-            .semaphore
+            .read_semaphore
             .acquire()
             .await
             .expect("semaphore must be valid");
@@ -158,7 +251,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let _permit = self
-            .semaphore
+            .write_semaphore
             .acquire()
             .await
             .expect("semaphore must be valid");
@@ -168,7 +261,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         let permit = self
-            .semaphore
+            .list_semaphore
             .clone()
             .acquire_owned()
             .await
@@ -182,7 +275,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let _permit = self
-            .semaphore
+            .write_semaphore
             .acquire()
             .await
             .expect("semaphore must be valid");
@@ -192,7 +285,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         let _permit = self
-            .semaphore
+            .write_semaphore
             .try_acquire()
             .expect("semaphore must be valid");
 
@@ -201,7 +294,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         let permit = self
-            .semaphore
+            .read_semaphore
             .clone()
             .try_acquire_owned()
             .expect("semaphore must be valid");
@@ -213,7 +306,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let permit = self
-            .semaphore
+            .write_semaphore
             .clone()
             .try_acquire_owned()
             .expect("semaphore must be valid");
@@ -225,7 +318,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let _permit = self
-            .semaphore
+            .read_semaphore
             .try_acquire()
             .expect("semaphore must be valid");
 
@@ -234,7 +327,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let _permit = self
-            .semaphore
+            .write_semaphore
             .try_acquire()
             .expect("semaphore must be valid");
 
@@ -243,7 +336,7 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         let permit = self
-            .semaphore
+            .list_semaphore
             .clone()
             .try_acquire_owned()
             .expect("semaphore must be valid");
@@ -312,6 +405,10 @@ impl<R: oio::Write> oio::Write for ConcurrentLimitWrapper<R> {
         self.inner.sink(size, s).await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
     async fn close(&mut self) -> Result<()> {
         self.inner.close().await
     }
@@ -343,6 +440,10 @@ impl<R: oio::Page> oio::Page for ConcurrentLimitWrapper<R> {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
         self.inner.next().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingPage> oio::BlockingPage for ConcurrentLimitWrapper<R> {
@@ -350,3 +451,89 @@ impl<R: oio::BlockingPage> oio::BlockingPage for ConcurrentLimitWrapper<R> {
         self.inner.next()
     }
 }
+
+/// ConcurrentLimitUploader acquires a permit for every request of the
+/// multipart upload.
+pub struct ConcurrentLimitUploader {
+    inner: oio::MultipartUploader,
+    semaphore: Arc<Semaphore>,
+}
+
+impl ConcurrentLimitUploader {
+    async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.semaphore
+            .acquire()
+            .await
+            .expect("semaphore must be valid")
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for ConcurrentLimitUploader {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let _permit = self.acquire().await;
+        self.inner.write_once(size, body).await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        let _permit = self.acquire().await;
+        self.inner.initiate_part().await
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let _permit = self.acquire().await;
+        self.inner
+            .write_part(upload_id, part_number, size, body)
+            .await
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        let _permit = self.acquire().await;
+        self.inner.complete_part(upload_id, parts).await
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        let _permit = self.acquire().await;
+        self.inner.abort_part(upload_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_separate_permits() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?
+            .layer(ConcurrentLimitLayer::new(1).with_write_permits(1))
+            .finish();
+        op.write("test", "Hello, World!").await?;
+
+        // Hold the only write permit.
+        let _w = op.writer("a").await?;
+
+        let res = timeout(Duration::from_millis(100), op.writer("b")).await;
+        assert!(res.is_err(), "write must be throttled");
+
+        let bs = timeout(Duration::from_millis(100), op.read("test"))
+            .await
+            .expect("read must not be blocked by write")?;
+        assert_eq!(bs, b"Hello, World!");
+        Ok(())
+    }
+}
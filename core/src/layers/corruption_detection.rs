@@ -0,0 +1,528 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
+
+use crate::raw::oio::ReadOperation;
+use crate::raw::oio::WriteOperation;
+use crate::raw::*;
+use crate::*;
+
+/// Detect corrupted content by verifying the size of content.
+///
+/// Flaky networks could deliver truncated bodies without any error reported
+/// by services. CorruptionDetectionLayer will make sure:
+///
+/// - For `read`, the bytes actually read matches the content length returned
+///   by services if it's known. A temporary error with kind
+///   `ContentIncomplete` will be returned on mismatch, so that users and
+///   `RetryLayer` know that the read could succeed by reading again.
+/// - For `write`, the content length of written file matches the bytes that
+///   users have written. An extra `stat` will be sent after the writer closed
+///   if service supports it. Errors on mismatch are not temporary since the
+///   content has been consumed and can't be written again.
+///
+/// # Notes
+///
+/// Please make sure `CorruptionDetectionLayer` is added before `RetryLayer`,
+/// so that `RetryLayer` can see the errors returned by it.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::CorruptionDetectionLayer;
+/// use opendal::layers::RetryLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(CorruptionDetectionLayer)
+///     .layer(RetryLayer::new())
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CorruptionDetectionLayer;
+
+impl<A: Accessor> Layer<A> for CorruptionDetectionLayer {
+    type LayeredAccessor = CorruptionDetectionAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        CorruptionDetectionAccessor {
+            inner: Arc::new(inner),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CorruptionDetectionAccessor<A: Accessor> {
+    inner: Arc<A>,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for CorruptionDetectionAccessor<A> {
+    type Inner = A;
+    type Reader = CorruptionDetectionReader<A::Reader>;
+    type BlockingReader = CorruptionDetectionReader<A::BlockingReader>;
+    type Writer = CorruptionDetectionWriter<A, A::Writer>;
+    type BlockingWriter = CorruptionDetectionWriter<A, A::BlockingWriter>;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (rp, r) = self.inner.read(path, args).await?;
+        let size = rp.metadata().content_length_raw();
+        Ok((rp, CorruptionDetectionReader::new(r, path, size)))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let (rp, r) = self.inner.blocking_read(path, args)?;
+        let size = rp.metadata().content_length_raw();
+        Ok((rp, CorruptionDetectionReader::new(r, path, size)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, w) = self.inner.write(path, args).await?;
+        Ok((
+            rp,
+            CorruptionDetectionWriter::new(self.inner.clone(), w, path),
+        ))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (rp, w) = self.inner.blocking_write(path, args)?;
+        Ok((
+            rp,
+            CorruptionDetectionWriter::new(self.inner.clone(), w, path),
+        ))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+pub struct CorruptionDetectionReader<R> {
+    inner: R,
+    path: String,
+    /// The content length returned by services, `None` means unknown.
+    size: Option<u64>,
+    pos: u64,
+}
+
+impl<R> CorruptionDetectionReader<R> {
+    fn new(inner: R, path: &str, size: Option<u64>) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            size,
+            pos: 0,
+        }
+    }
+
+    /// Advance the position, the content is corrupted if we read more
+    /// than the content length.
+    fn advance(&mut self, op: ReadOperation, n: usize) -> Result<()> {
+        self.pos += n as u64;
+        match self.size {
+            Some(size) if self.pos > size => Err(self.new_corrupted_error(op, size)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the position when reaching EOF, the content is corrupted if
+    /// we read less than the content length.
+    fn check_eof(&self, op: ReadOperation) -> Result<()> {
+        match self.size {
+            Some(size) if self.pos != size => Err(self.new_corrupted_error(op, size)),
+            _ => Ok(()),
+        }
+    }
+
+    fn new_corrupted_error(&self, op: ReadOperation, size: u64) -> Error {
+        let kind = if self.pos < size {
+            ErrorKind::ContentIncomplete
+        } else {
+            ErrorKind::ContentTruncated
+        };
+
+        Error::new(kind, "read content doesn't match the content length")
+            .with_operation(op)
+            .with_context("path", &self.path)
+            .with_context("expect", size.to_string())
+            .with_context("actual", self.pos.to_string())
+            .set_temporary()
+    }
+}
+
+impl<R: oio::Read> oio::Read for CorruptionDetectionReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_read(cx, buf))?;
+        if n == 0 && !buf.is_empty() {
+            self.check_eof(ReadOperation::Read)?;
+        } else {
+            self.advance(ReadOperation::Read, n)?;
+        }
+
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let pos = ready!(self.inner.poll_seek(cx, pos))?;
+        self.pos = pos;
+
+        Poll::Ready(Ok(pos))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let res = match ready!(self.inner.poll_next(cx)) {
+            Some(Ok(bs)) => self.advance(ReadOperation::Next, bs.len()).map(|_| bs),
+            Some(Err(err)) => Err(err),
+            None => match self.check_eof(ReadOperation::Next) {
+                Ok(()) => return Poll::Ready(None),
+                Err(err) => Err(err),
+            },
+        };
+
+        Poll::Ready(Some(res))
+    }
+}
+
+impl<R: oio::BlockingRead> oio::BlockingRead for CorruptionDetectionReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        if n == 0 && !buf.is_empty() {
+            self.check_eof(ReadOperation::BlockingRead)?;
+        } else {
+            self.advance(ReadOperation::BlockingRead, n)?;
+        }
+
+        Ok(n)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let pos = self.inner.seek(pos)?;
+        self.pos = pos;
+
+        Ok(pos)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        let res = match self.inner.next() {
+            Some(Ok(bs)) => self
+                .advance(ReadOperation::BlockingNext, bs.len())
+                .map(|_| bs),
+            Some(Err(err)) => Err(err),
+            None => match self.check_eof(ReadOperation::BlockingNext) {
+                Ok(()) => return None,
+                Err(err) => Err(err),
+            },
+        };
+
+        Some(res)
+    }
+}
+
+pub struct CorruptionDetectionWriter<A, W> {
+    acc: Arc<A>,
+    inner: W,
+    path: String,
+    written: u64,
+}
+
+impl<A: Accessor, W> CorruptionDetectionWriter<A, W> {
+    fn new(acc: Arc<A>, inner: W, path: &str) -> Self {
+        Self {
+            acc,
+            inner,
+            path: path.to_string(),
+            written: 0,
+        }
+    }
+
+    /// Check the content length of written file with the bytes written.
+    fn check_written(&self, op: WriteOperation, meta: &Metadata) -> Result<()> {
+        match meta.content_length_raw() {
+            Some(size) if size != self.written => Err(Error::new(
+                ErrorKind::ContentIncomplete,
+                "written content doesn't match the bytes written",
+            )
+            .with_operation(op)
+            .with_context("path", &self.path)
+            .with_context("expect", self.written.to_string())
+            .with_context("actual", size.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Accessor, W: oio::Write> oio::Write for CorruptionDetectionWriter<A, W> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let n = bs.len();
+        self.inner.write(bs).await?;
+        self.written += n as u64;
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.inner.sink(size, s).await?;
+        self.written += size;
+        Ok(())
+    }
+
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        // Size of stream is unknown, count the bytes while polling.
+        let n = Arc::new(AtomicU64::new(0));
+        let counter = n.clone();
+        let s = s.inspect(move |res| {
+            if let Ok(bs) = res {
+                counter.fetch_add(bs.len() as u64, Ordering::Relaxed);
+            }
+        });
+        self.inner
+            .sink_unsized(Box::new(oio::into_stream(s)))
+            .await?;
+        self.written += n.load(Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await?;
+
+        if !self.acc.info().capability().stat {
+            return Ok(());
+        }
+        let rp = self.acc.stat(&self.path, OpStat::new()).await?;
+        self.check_written(WriteOperation::Close, &rp.into_metadata())
+    }
+}
+
+impl<A: Accessor, W: oio::BlockingWrite> oio::BlockingWrite for CorruptionDetectionWriter<A, W> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        let n = bs.len();
+        self.inner.write(bs)?;
+        self.written += n as u64;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()?;
+
+        if !self.acc.info().capability().stat {
+            return Ok(());
+        }
+        let rp = self.acc.blocking_stat(&self.path, OpStat::new())?;
+        self.check_written(WriteOperation::BlockingClose, &rp.into_metadata())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::AsyncReadExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// TruncateHook truncates all content read or written to `limit` bytes
+    /// silently to simulate flaky networks.
+    #[derive(Debug, Clone)]
+    struct TruncateHook {
+        limit: usize,
+    }
+
+    #[async_trait]
+    impl LayerHook for TruncateHook {
+        async fn read<A: Accessor>(
+            &self,
+            inner: &A,
+            path: &str,
+            args: OpRead,
+        ) -> Result<(RpRead, oio::Reader)> {
+            let (rp, r) = inner.read(path, args).await?;
+            Ok((rp, Box::new(TruncateWrapper::new(r, self.limit))))
+        }
+
+        async fn write<A: Accessor>(
+            &self,
+            inner: &A,
+            path: &str,
+            args: OpWrite,
+        ) -> Result<(RpWrite, oio::Writer)> {
+            // Make sure the service won't complain about the content length.
+            let args = match args.content_length() {
+                Some(size) => args.with_content_length(size.min(self.limit as u64)),
+                None => args,
+            };
+            let (rp, w) = inner.write(path, args).await?;
+            Ok((rp, Box::new(TruncateWrapper::new(w, self.limit))))
+        }
+    }
+
+    struct TruncateWrapper<R> {
+        inner: R,
+        remaining: usize,
+    }
+
+    impl<R> TruncateWrapper<R> {
+        fn new(inner: R, limit: usize) -> Self {
+            Self {
+                inner,
+                remaining: limit,
+            }
+        }
+    }
+
+    impl<R: oio::Read> oio::Read for TruncateWrapper<R> {
+        fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+            let size = buf.len().min(self.remaining);
+            if size == 0 {
+                return Poll::Ready(Ok(0));
+            }
+            let n = ready!(self.inner.poll_read(cx, &mut buf[..size]))?;
+            self.remaining -= n;
+            Poll::Ready(Ok(n))
+        }
+
+        fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+            self.inner.poll_seek(cx, pos)
+        }
+
+        fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+            if self.remaining == 0 {
+                return Poll::Ready(None);
+            }
+            match ready!(self.inner.poll_next(cx)) {
+                Some(Ok(bs)) => {
+                    let bs = bs.slice(..bs.len().min(self.remaining));
+                    self.remaining -= bs.len();
+                    Poll::Ready(Some(Ok(bs)))
+                }
+                v => Poll::Ready(v),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl<W: oio::Write> oio::Write for TruncateWrapper<W> {
+        async fn write(&mut self, bs: Bytes) -> Result<()> {
+            let bs = bs.slice(..bs.len().min(self.remaining));
+            self.remaining -= bs.len();
+            self.inner.write(bs).await
+        }
+
+        async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+            self.inner.sink(size, s).await
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            self.inner.abort().await
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            self.inner.close().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detect_truncated_read() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("test", "Hello, World!").await?;
+
+        let op = op
+            .layer(HookLayer(TruncateHook { limit: 5 }))
+            .layer(CorruptionDetectionLayer);
+
+        let err = op.read("test").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+        assert!(err.is_temporary());
+
+        let mut r = op.reader("test").await?;
+        let mut buf = Vec::new();
+        let err = r.read_to_end(&mut buf).await.unwrap_err();
+        let err = err.into_inner().unwrap().downcast::<Error>().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+
+        // Content that is not truncated should pass.
+        op.write("small", "Hello").await?;
+        assert_eq!(op.read("small").await?, b"Hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_detect_truncated_write() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(HookLayer(TruncateHook { limit: 5 }))
+            .layer(CorruptionDetectionLayer)
+            .finish();
+
+        let err = op.write("test", "Hello, World!").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+        assert!(!err.is_temporary());
+
+        op.write("small", "Hello").await?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_truncated_blocking_next() {
+        let mut r = CorruptionDetectionReader::new(
+            oio::Cursor::from(Bytes::from("Hello")),
+            "test",
+            Some(13),
+        );
+
+        let mut buf = Vec::new();
+        let err = loop {
+            match oio::BlockingRead::next(&mut r) {
+                Some(Ok(bs)) => buf.extend_from_slice(&bs),
+                Some(Err(err)) => break err,
+                None => panic!("truncated content must be detected"),
+            }
+        };
+        assert_eq!(buf, b"Hello");
+        assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+    }
+}
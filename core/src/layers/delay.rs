@@ -0,0 +1,362 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add deterministic latency for every operation.
+///
+/// # Notes
+///
+/// DelayLayer is designed for testing, for example verifying the behavior of
+/// [`TimeoutLayer`][crate::layers::TimeoutLayer] and
+/// [`ConcurrentLimitLayer`][crate::layers::ConcurrentLimitLayer]. Unlike
+/// `ChaosLayer`, it never returns errors, it only sleeps.
+///
+/// - `before` delay is applied before the operation is sent to the inner accessor.
+/// - `after` delay is applied after the inner accessor returned, no matter the
+///   result is ok or not.
+///
+/// The delays are applied to operations of [`Accessor`] only, IO operations on
+/// the returned readers, writers and pagers are not delayed. Blocking operations
+/// are delayed via [`std::thread::sleep`].
+///
+/// Delays for a specific operation can be set via
+/// [`DelayLayer::with_operation_delay`], which overrides the default ones.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::layers::DelayLayer;
+/// use opendal::raw::Operation;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         DelayLayer::new(Duration::from_millis(10))
+///             .with_operation_delay(Operation::Stat, Duration::ZERO, Duration::from_millis(100)),
+///     )
+///     .finish();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DelayLayer {
+    before: Duration,
+    after: Duration,
+    operations: HashMap<Operation, (Duration, Duration)>,
+}
+
+impl DelayLayer {
+    /// Create a new `DelayLayer` which sleeps `delay` before every operation.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            before: delay,
+            ..Default::default()
+        }
+    }
+
+    /// Set the delay before every operation.
+    pub fn with_before(mut self, delay: Duration) -> Self {
+        self.before = delay;
+        self
+    }
+
+    /// Set the delay after every operation.
+    pub fn with_after(mut self, delay: Duration) -> Self {
+        self.after = delay;
+        self
+    }
+
+    /// Set the delays before and after given operation, which overrides
+    /// the default delays.
+    pub fn with_operation_delay(
+        mut self,
+        op: Operation,
+        before: Duration,
+        after: Duration,
+    ) -> Self {
+        self.operations.insert(op, (before, after));
+        self
+    }
+}
+
+impl<A: Accessor> Layer<A> for DelayLayer {
+    type LayeredAccessor = DelayAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        DelayAccessor {
+            inner,
+            before: self.before,
+            after: self.after,
+            operations: Arc::new(self.operations.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DelayAccessor<A: Accessor> {
+    inner: A,
+
+    before: Duration,
+    after: Duration,
+    operations: Arc<HashMap<Operation, (Duration, Duration)>>,
+}
+
+impl<A: Accessor> DelayAccessor<A> {
+    fn delays(&self, op: Operation) -> (Duration, Duration) {
+        self.operations
+            .get(&op)
+            .copied()
+            .unwrap_or((self.before, self.after))
+    }
+
+    async fn delay<T>(&self, op: Operation, fut: impl Future<Output = T>) -> T {
+        let (before, after) = self.delays(op);
+
+        sleep(before).await;
+        let res = fut.await;
+        sleep(after).await;
+        res
+    }
+
+    fn blocking_delay<T>(&self, op: Operation, f: impl FnOnce() -> T) -> T {
+        let (before, after) = self.delays(op);
+
+        blocking_sleep(before);
+        let res = f();
+        blocking_sleep(after);
+        res
+    }
+}
+
+async fn sleep(delay: Duration) {
+    if !delay.is_zero() {
+        tokio::time::sleep(delay).await;
+    }
+}
+
+fn blocking_sleep(delay: Duration) {
+    if !delay.is_zero() {
+        std::thread::sleep(delay);
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for DelayAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.delay(Operation::CreateDir, self.inner.create_dir(path, args))
+            .await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.delay(Operation::Read, self.inner.read(path, args))
+            .await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.delay(Operation::Write, self.inner.write(path, args))
+            .await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.delay(Operation::Append, self.inner.append(path, args))
+            .await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.delay(Operation::Copy, self.inner.copy(from, to, args))
+            .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.delay(Operation::Rename, self.inner.rename(from, to, args))
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.delay(Operation::Stat, self.inner.stat(path, args))
+            .await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.delay(Operation::Delete, self.inner.delete(path, args))
+            .await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.delay(Operation::List, self.inner.list(path, args))
+            .await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.delay(Operation::Batch, self.inner.batch(args)).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.delay(Operation::Presign, self.inner.presign(path, args))
+            .await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.blocking_delay(Operation::BlockingCreateDir, || {
+            self.inner.blocking_create_dir(path, args)
+        })
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.blocking_delay(Operation::BlockingRead, || {
+            self.inner.blocking_read(path, args)
+        })
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.blocking_delay(Operation::BlockingWrite, || {
+            self.inner.blocking_write(path, args)
+        })
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.blocking_delay(Operation::BlockingCopy, || {
+            self.inner.blocking_copy(from, to, args)
+        })
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.blocking_delay(Operation::BlockingRename, || {
+            self.inner.blocking_rename(from, to, args)
+        })
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.blocking_delay(Operation::BlockingStat, || {
+            self.inner.blocking_stat(path, args)
+        })
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.blocking_delay(Operation::BlockingDelete, || {
+            self.inner.blocking_delete(path, args)
+        })
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.blocking_delay(Operation::BlockingList, || {
+            self.inner.blocking_list(path, args)
+        })
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.blocking_delay(Operation::BlockingPresign, || {
+            self.inner.blocking_presign(path, args)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::layers::TimeoutLayer;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_operation_delay() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(DelayLayer::new(Duration::from_millis(200)))
+            .finish();
+
+        let start = Instant::now();
+        let _ = op.stat("not_exist").await;
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_operation_specific_delay() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(
+                DelayLayer::default()
+                    .with_after(Duration::from_secs(10))
+                    .with_operation_delay(Operation::Write, Duration::ZERO, Duration::ZERO)
+                    .with_operation_delay(
+                        Operation::Stat,
+                        Duration::from_millis(100),
+                        Duration::from_millis(100),
+                    ),
+            )
+            .finish();
+
+        let start = Instant::now();
+        op.write("test", "Hello, World!").await?;
+        op.stat("test").await?;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200));
+        assert!(elapsed < Duration::from_secs(10));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delay_with_timeout() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(DelayLayer::new(Duration::from_millis(500)))
+            .layer(TimeoutLayer::new().with_timeout(Duration::from_millis(100)))
+            .finish();
+
+        let err = op.read("test").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_operation_delay() -> Result<()> {
+        let op = Operator::new(Memory::default())?
+            .layer(DelayLayer::new(Duration::from_millis(200)))
+            .finish()
+            .blocking();
+
+        let start = Instant::now();
+        let _ = op.stat("not_exist");
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        Ok(())
+    }
+}
@@ -0,0 +1,513 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add dry run for underlying storage services.
+///
+/// DryRunLayer will intercept all mutations like `write`, `append`,
+/// `multipart`, `create_dir`, `delete`, `copy` and `rename`. Instead of sending them to
+/// the underlying service, DryRunLayer records the intended mutations as
+/// [`DryRunAction`] and returns success. Other operations like `read`,
+/// `stat` and `list` will be passed through.
+///
+/// Recorded actions can be queried via [`DryRunLayer::actions`] after the run.
+///
+/// # Notes
+///
+/// All operators built from the same DryRunLayer (and its clones) share
+/// the same recorded actions.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::DryRunAction;
+/// use opendal::layers::DryRunLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let dry_run = DryRunLayer::new();
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(dry_run.clone())
+///     .finish();
+///
+/// op.delete("path/to/file").await?;
+/// assert_eq!(
+///     dry_run.actions(),
+///     vec![DryRunAction::Delete {
+///         path: "path/to/file".to_string()
+///     }]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct DryRunLayer {
+    actions: Arc<Mutex<Vec<DryRunAction>>>,
+}
+
+impl DryRunLayer {
+    /// Create a new dry run layer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get all actions recorded by this layer in order.
+    pub fn actions(&self) -> Vec<DryRunAction> {
+        self.actions.lock().expect("lock must succeed").clone()
+    }
+
+    /// Clear all actions recorded by this layer.
+    pub fn clear(&self) {
+        self.actions.lock().expect("lock must succeed").clear()
+    }
+}
+
+impl<A: Accessor> Layer<A> for DryRunLayer {
+    type LayeredAccessor = DryRunAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        DryRunAccessor {
+            inner,
+            actions: self.actions.clone(),
+        }
+    }
+}
+
+/// DryRunAction is the mutation recorded by [`DryRunLayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DryRunAction {
+    /// Create the dir at `path`.
+    CreateDir {
+        /// The path of dir.
+        path: String,
+    },
+    /// Write `size` bytes into file at `path`.
+    Write {
+        /// The path of file.
+        path: String,
+        /// The size of content that has been written.
+        size: u64,
+    },
+    /// Append `size` bytes into file at `path`.
+    Append {
+        /// The path of file.
+        path: String,
+        /// The size of content that has been appended.
+        size: u64,
+    },
+    /// Delete the file or dir at `path`.
+    Delete {
+        /// The path of file or dir.
+        path: String,
+    },
+    /// Copy file from `from` to `to`.
+    Copy {
+        /// The source path.
+        from: String,
+        /// The target path.
+        to: String,
+    },
+    /// Rename file from `from` to `to`.
+    Rename {
+        /// The source path.
+        from: String,
+        /// The target path.
+        to: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunAccessor<A: Accessor> {
+    inner: A,
+    actions: Arc<Mutex<Vec<DryRunAction>>>,
+}
+
+impl<A: Accessor> DryRunAccessor<A> {
+    fn record(&self, action: DryRunAction) {
+        self.actions.lock().expect("lock must succeed").push(action)
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for DryRunAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = DryRunWriter;
+    type BlockingWriter = DryRunWriter;
+    type Appender = DryRunWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
+        self.record(DryRunAction::CreateDir {
+            path: path.to_string(),
+        });
+        Ok(RpCreateDir::default())
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        Ok((
+            RpWrite::new(),
+            DryRunWriter::new(path, false, self.actions.clone()),
+        ))
+    }
+
+    async fn append(&self, path: &str, _: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        Ok((
+            RpAppend::new(),
+            DryRunWriter::new(path, true, self.actions.clone()),
+        ))
+    }
+
+    async fn multipart(&self, path: &str, _: OpWrite) -> Result<(RpWrite, oio::MultipartUploader)> {
+        Ok((
+            RpWrite::new(),
+            Box::new(DryRunUploader::new(path, self.actions.clone())),
+        ))
+    }
+
+    async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        self.record(DryRunAction::Copy {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        Ok(RpCopy::new())
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.record(DryRunAction::Rename {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        Ok(RpRename::new())
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        self.record(DryRunAction::Delete {
+            path: path.to_string(),
+        });
+        Ok(RpDelete::default())
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let mut results = Vec::with_capacity(args.operation().len());
+        for (path, op) in args.into_operation() {
+            match op {
+                BatchOperation::Delete(_) => {
+                    self.record(DryRunAction::Delete { path: path.clone() });
+                    results.push((path, Ok(RpDelete::default().into())));
+                }
+                BatchOperation::Write(_, bs) => {
+                    self.record(DryRunAction::Write {
+                        path: path.clone(),
+                        size: bs.len() as u64,
+                    });
+                    results.push((path, Ok(RpWrite::default().into())));
+                }
+            }
+        }
+        Ok(RpBatch::new(results))
+    }
+
+    fn blocking_create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
+        self.record(DryRunAction::CreateDir {
+            path: path.to_string(),
+        });
+        Ok(RpCreateDir::default())
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        Ok((
+            RpWrite::new(),
+            DryRunWriter::new(path, false, self.actions.clone()),
+        ))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
+        self.record(DryRunAction::Copy {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        Ok(RpCopy::new())
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.record(DryRunAction::Rename {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        Ok(RpRename::new())
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        self.record(DryRunAction::Delete {
+            path: path.to_string(),
+        });
+        Ok(RpDelete::default())
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// DryRunWriter discards all content and records the written size while
+/// closing.
+pub struct DryRunWriter {
+    path: String,
+    append: bool,
+    size: u64,
+    actions: Arc<Mutex<Vec<DryRunAction>>>,
+}
+
+impl DryRunWriter {
+    fn new(path: &str, append: bool, actions: Arc<Mutex<Vec<DryRunAction>>>) -> Self {
+        Self {
+            path: path.to_string(),
+            append,
+            size: 0,
+            actions,
+        }
+    }
+
+    fn finish(&mut self) {
+        let path = self.path.clone();
+        let size = self.size;
+        let action = if self.append {
+            DryRunAction::Append { path, size }
+        } else {
+            DryRunAction::Write { path, size }
+        };
+
+        self.actions.lock().expect("lock must succeed").push(action)
+    }
+}
+
+#[async_trait]
+impl oio::Write for DryRunWriter {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.size += bs.len() as u64;
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, _: oio::Streamer) -> Result<()> {
+        self.size += size;
+        Ok(())
+    }
+
+    async fn sink_unsized(&mut self, mut s: oio::Streamer) -> Result<()> {
+        // Size of stream is unknown, consume it to record the size.
+        while let Some(bs) = s.next().await {
+            self.size += bs?.len() as u64;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.size = 0;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.finish();
+        Ok(())
+    }
+}
+
+impl oio::BlockingWrite for DryRunWriter {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.size += bs.len() as u64;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.finish();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl oio::Append for DryRunWriter {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        self.size += bs.len() as u64;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.finish();
+        Ok(())
+    }
+}
+
+/// DryRunUploader discards all parts and records the size of completed
+/// upload.
+pub struct DryRunUploader {
+    path: String,
+    /// The size of uploaded parts, keyed by part number.
+    parts: Mutex<HashMap<usize, u64>>,
+    actions: Arc<Mutex<Vec<DryRunAction>>>,
+}
+
+impl DryRunUploader {
+    fn new(path: &str, actions: Arc<Mutex<Vec<DryRunAction>>>) -> Self {
+        Self {
+            path: path.to_string(),
+            parts: Mutex::default(),
+            actions,
+        }
+    }
+
+    fn finish(&self, size: u64) {
+        self.actions
+            .lock()
+            .expect("lock must succeed")
+            .push(DryRunAction::Write {
+                path: self.path.clone(),
+                size,
+            })
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for DryRunUploader {
+    async fn write_once(&self, size: u64, _: AsyncBody) -> Result<()> {
+        self.finish(size);
+        Ok(())
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        Ok("dry-run".to_string())
+    }
+
+    async fn write_part(
+        &self,
+        _: &str,
+        part_number: usize,
+        size: u64,
+        _: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        self.parts
+            .lock()
+            .expect("lock must succeed")
+            .insert(part_number, size);
+
+        Ok(oio::MultipartUploadPart {
+            part_number,
+            etag: part_number.to_string(),
+            checksum: None,
+        })
+    }
+
+    async fn complete_part(&self, _: &str, parts: &[oio::MultipartUploadPart]) -> Result<()> {
+        let size = {
+            let uploaded = self.parts.lock().expect("lock must succeed");
+            parts
+                .iter()
+                .filter_map(|v| uploaded.get(&v.part_number))
+                .sum()
+        };
+        self.finish(size);
+        Ok(())
+    }
+
+    async fn abort_part(&self, _: &str) -> Result<()> {
+        self.parts.lock().expect("lock must succeed").clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_dry_run() {
+        let op = Operator::new(Memory::default()).unwrap().finish();
+        op.write("existing", "Hello, World!").await.unwrap();
+
+        let dry_run = DryRunLayer::new();
+        let dop = op.clone().layer(dry_run.clone());
+
+        dop.write("new", "abc").await.unwrap();
+        dop.delete("existing").await.unwrap();
+        dop.copy("existing", "copied").await.unwrap();
+        dop.rename("existing", "renamed").await.unwrap();
+
+        // Reads are passed through.
+        assert_eq!(dop.read("existing").await.unwrap(), b"Hello, World!");
+
+        // No mutations happened on the underlying service.
+        assert!(op.is_exist("existing").await.unwrap());
+        assert!(!op.is_exist("new").await.unwrap());
+        assert!(!op.is_exist("copied").await.unwrap());
+        assert!(!op.is_exist("renamed").await.unwrap());
+
+        assert_eq!(
+            dry_run.actions(),
+            vec![
+                DryRunAction::Write {
+                    path: "new".to_string(),
+                    size: 3,
+                },
+                DryRunAction::Delete {
+                    path: "existing".to_string(),
+                },
+                DryRunAction::Copy {
+                    from: "existing".to_string(),
+                    to: "copied".to_string(),
+                },
+                DryRunAction::Rename {
+                    from: "existing".to_string(),
+                    to: "renamed".to_string(),
+                },
+            ]
+        );
+    }
+}
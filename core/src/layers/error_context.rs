@@ -235,6 +235,18 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
         })
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.inner.multipart(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Write)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         self.inner
             .batch(args)
@@ -362,6 +374,14 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
                     .with_context("path", path)
             })
     }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(path, args).map_err(|err| {
+            err.with_operation(Operation::BlockingPresign)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
@@ -450,6 +470,14 @@ impl<T: oio::Write> oio::Write for ErrorContextWrapper<T> {
         })
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await.map_err(|err| {
+            err.with_operation(WriteOperation::SinkUnsized)
+                .with_context("service", self.scheme)
+                .with_context("path", &self.path)
+        })
+    }
+
     async fn close(&mut self) -> Result<()> {
         self.inner.close().await.map_err(|err| {
             err.with_operation(WriteOperation::Close)
@@ -505,6 +533,10 @@ impl<T: oio::Page> oio::Page for ErrorContextWrapper<T> {
                 .with_context("path", &self.path)
         })
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<T: oio::BlockingPage> oio::BlockingPage for ErrorContextWrapper<T> {
@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use log::debug;
+
+use crate::raw::*;
+use crate::*;
+
+/// Fall back to a secondary [`Operator`] while reading from the underlying
+/// service (the primary) fails.
+///
+/// FallbackLayer will try `read`, `stat` and `list` on the primary first,
+/// and retry them on the secondary if the primary fails with configured
+/// [`ErrorKind`]s. By default, FallbackLayer falls back on
+/// [`ErrorKind::NotFound`] and [`ErrorKind::Unexpected`], which can be
+/// changed via [`FallbackLayer::with_error_kinds`].
+///
+/// All mutations like `write`, `delete`, `copy` and `rename` only go to
+/// the primary.
+///
+/// # Notes
+///
+/// Primary and secondary are not synced by FallbackLayer, users should be
+/// aware of the consistency caveats:
+///
+/// - Content read from secondary could be stale or already deleted on
+///   primary.
+/// - `stat` and `read` of the same path could be served by different
+///   sides if primary fails in between.
+/// - `list` will NOT merge entries of both sides, it's served by secondary
+///   only if primary fails.
+/// - Errors happened while reading content (after the reader has been
+///   returned) will not fall back.
+///
+/// If both sides fail, the error of secondary will be returned with the
+/// error of primary attached in its context.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::FallbackLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let secondary = Operator::new(services::Memory::default())?.finish();
+/// secondary.write("path/to/file", "Hello, World!").await?;
+///
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(FallbackLayer::new(secondary))
+///     .finish();
+/// assert_eq!(op.read("path/to/file").await?, b"Hello, World!");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FallbackLayer {
+    secondary: Operator,
+    error_kinds: Vec<ErrorKind>,
+}
+
+impl FallbackLayer {
+    /// Create a new fallback layer which falls back to `secondary`.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondary,
+            error_kinds: vec![ErrorKind::NotFound, ErrorKind::Unexpected],
+        }
+    }
+
+    /// Set the error kinds of primary that should fall back to secondary.
+    pub fn with_error_kinds(mut self, kinds: impl IntoIterator<Item = ErrorKind>) -> Self {
+        self.error_kinds = kinds.into_iter().collect();
+        self
+    }
+}
+
+impl<A: Accessor> Layer<A> for FallbackLayer {
+    type LayeredAccessor = FallbackAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        FallbackAccessor {
+            inner,
+            secondary: self.secondary.clone().into_inner(),
+            error_kinds: self.error_kinds.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FallbackAccessor<A: Accessor> {
+    inner: A,
+    secondary: FusedAccessor,
+    error_kinds: Vec<ErrorKind>,
+}
+
+impl<A: Accessor> FallbackAccessor<A> {
+    /// Check if the error of primary should fall back to secondary.
+    fn should_fallback(&self, operation: Operation, path: &str, err: &Error) -> bool {
+        let fallback = self.error_kinds.contains(&err.kind());
+        if fallback {
+            debug!(
+                target: "opendal::layers::fallback",
+                "{} on path {} failed on primary, fall back to secondary: {}", operation, path, err
+            );
+        }
+        fallback
+    }
+}
+
+/// Attach the error of primary to the error of secondary.
+fn with_primary_error(err: Error, primary: Error) -> Error {
+    err.with_context("primary_error", primary.to_string())
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for FallbackAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = oio::Pager;
+    type BlockingPager = oio::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match self.inner.read(path, args.clone()).await {
+            Ok((rp, r)) => Ok((rp, Box::new(r) as oio::Reader)),
+            Err(err) if self.should_fallback(Operation::Read, path, &err) => self
+                .secondary
+                .read(path, args)
+                .await
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.stat(path, args.clone()).await {
+            Ok(rp) => Ok(rp),
+            Err(err) if self.should_fallback(Operation::Stat, path, &err) => self
+                .secondary
+                .stat(path, args)
+                .await
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        match self.inner.list(path, args.clone()).await {
+            Ok((rp, p)) => Ok((rp, Box::new(p) as oio::Pager)),
+            Err(err) if self.should_fallback(Operation::List, path, &err) => self
+                .secondary
+                .list(path, args)
+                .await
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        match self.inner.blocking_read(path, args.clone()) {
+            Ok((rp, r)) => Ok((rp, Box::new(r) as oio::BlockingReader)),
+            Err(err) if self.should_fallback(Operation::BlockingRead, path, &err) => self
+                .secondary
+                .blocking_read(path, args)
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match self.inner.blocking_stat(path, args.clone()) {
+            Ok(rp) => Ok(rp),
+            Err(err) if self.should_fallback(Operation::BlockingStat, path, &err) => self
+                .secondary
+                .blocking_stat(path, args)
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        match self.inner.blocking_list(path, args.clone()) {
+            Ok((rp, p)) => Ok((rp, Box::new(p) as oio::BlockingPager)),
+            Err(err) if self.should_fallback(Operation::BlockingList, path, &err) => self
+                .secondary
+                .blocking_list(path, args)
+                .map_err(|e| with_primary_error(e, err)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[tokio::test]
+    async fn test_fallback() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        secondary.write("file", "Hello, World!").await?;
+
+        let op = Operator::new(Memory::default())?
+            .layer(FallbackLayer::new(secondary.clone()))
+            .finish();
+
+        // Primary misses and secondary serves the object.
+        assert_eq!(op.read("file").await?, b"Hello, World!");
+        assert_eq!(op.stat("file").await?.content_length(), 13);
+        assert_eq!(op.blocking().read("file")?, b"Hello, World!");
+
+        // Writes only go to primary, which will be served first.
+        op.write("file", "Hello, OpenDAL!").await?;
+        assert_eq!(op.read("file").await?, b"Hello, OpenDAL!");
+        assert_eq!(secondary.read("file").await?, b"Hello, World!");
+
+        // Both sides miss.
+        let err = op.read("not_exist").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fallback_with_error_kinds() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        secondary.write("file", "Hello, World!").await?;
+
+        let op = Operator::new(Memory::default())?
+            .layer(FallbackLayer::new(secondary).with_error_kinds([ErrorKind::RateLimited]))
+            .finish();
+
+        let err = op.read("file").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+}
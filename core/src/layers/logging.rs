@@ -16,19 +16,23 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::io;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::FutureExt;
 use futures::TryFutureExt;
-use log::debug;
 use log::log;
-use log::trace;
 use log::Level;
 
+use crate::layers::Tenant;
+use crate::raw::oio::MultipartUploadOperation;
 use crate::raw::oio::ReadOperation;
 use crate::raw::oio::WriteOperation;
 use crate::raw::*;
@@ -46,6 +50,7 @@ use crate::*;
 ///   - `failed`: the operation returns an unexpected error.
 /// - The default log level while expected error happened is `Warn`.
 /// - The default log level while unexpected failure happened is `Error`.
+/// - Operations carrying a [`Tenant`] extension will be logged with `tenant`.
 ///
 /// # Todo
 ///
@@ -83,10 +88,33 @@ use crate::*;
 /// ```shell
 /// RUST_LOG="info,opendal::services=debug" ./app
 /// ```
+///
+/// # Structured Fields
+///
+/// With feature `layers-tracing` enabled, [`LoggingLayer::with_structured_fields`]
+/// makes OpenDAL emit events with typed `tracing` fields instead of formatted
+/// messages:
+///
+/// ```
+/// # #[cfg(feature = "layers-tracing")]
+/// # fn main() {
+/// use opendal::layers::LoggingLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(LoggingLayer::default().with_structured_fields(true))
+///     .finish();
+/// # }
+/// # #[cfg(not(feature = "layers-tracing"))]
+/// # fn main() {}
+/// ```
 #[derive(Debug, Copy, Clone)]
 pub struct LoggingLayer {
     error_level: Option<Level>,
     failure_level: Option<Level>,
+    structured: bool,
 }
 
 impl Default for LoggingLayer {
@@ -94,6 +122,7 @@ impl Default for LoggingLayer {
         Self {
             error_level: Some(Level::Warn),
             failure_level: Some(Level::Error),
+            structured: false,
         }
     }
 }
@@ -134,6 +163,30 @@ impl LoggingLayer {
         }
         Ok(self)
     }
+
+    /// Emit events with typed [`tracing`](https://docs.rs/tracing/) fields
+    /// instead of formatted messages.
+    ///
+    /// Every finished or failed operation will be emitted as an event with
+    /// the following fields so that structured backends can index them:
+    ///
+    /// - `service`: the scheme of service, like `s3`.
+    /// - `operation`: the operation name, like `read` or `Reader::read`.
+    /// - `tenant`: the [`Tenant`] attached to the operation, if any.
+    /// - `path`: the path of this operation, and `to` for `copy` and `rename`.
+    /// - `count`: the count of operations in a batch.
+    /// - `bytes`: the bytes have been read or written.
+    /// - `duration`: the elapsed seconds since the operation started.
+    /// - `error_kind` and `error`: only present if the operation failed.
+    ///
+    /// `started`, `pending` and per chunk events are not emitted in this mode.
+    ///
+    /// The human-readable mode via [`log`](https://docs.rs/log/) is used by default.
+    #[cfg(feature = "layers-tracing")]
+    pub fn with_structured_fields(mut self, enabled: bool) -> Self {
+        self.structured = enabled;
+        self
+    }
 }
 
 impl<A: Accessor> Layer<A> for LoggingLayer {
@@ -147,6 +200,7 @@ impl<A: Accessor> Layer<A> for LoggingLayer {
 
             error_level: self.error_level,
             failure_level: self.failure_level,
+            structured: self.structured,
         }
     }
 }
@@ -158,9 +212,10 @@ pub struct LoggingAccessor<A: Accessor> {
 
     error_level: Option<Level>,
     failure_level: Option<Level>,
+    structured: bool,
 }
 
-static LOGGING_TARGET: &str = "opendal::services";
+const LOGGING_TARGET: &str = "opendal::services";
 
 impl<A: Accessor> LoggingAccessor<A> {
     #[inline]
@@ -180,6 +235,199 @@ impl<A: Accessor> LoggingAccessor<A> {
             self.error_level
         }
     }
+
+    #[inline]
+    fn event<'a>(&self, op: Operation) -> LogEvent<'a> {
+        LogEvent::new(self.scheme, op, self.structured)
+    }
+
+    /// Emit the event of a failed operation with the level and status
+    /// decided by the kind of error.
+    fn emit_err<'a>(&self, event: LogEvent<'a>, err: &'a Error) {
+        if let Some(lvl) = self.err_level(err) {
+            event.with_error(err).emit(lvl, self.err_status(err));
+        }
+    }
+}
+
+/// LogEvent carries the fields of a single log entry.
+///
+/// It will be formatted as `service=s3 operation=read path=abc -> finished`
+/// by default, and emitted with typed `tracing` fields while
+/// [`LoggingLayer::with_structured_fields`] is enabled.
+struct LogEvent<'a> {
+    scheme: Scheme,
+    operation: &'static str,
+    structured: bool,
+
+    tenant: Option<&'a Tenant>,
+    batch: Option<(Operation, usize)>,
+    path: Option<&'a str>,
+    to: Option<&'a str>,
+    range: Option<BytesRange>,
+    bytes: Option<(&'static str, u64)>,
+    duration: Option<Duration>,
+    error: Option<&'a Error>,
+    detail: Option<&'a dyn Debug>,
+}
+
+impl<'a> LogEvent<'a> {
+    fn new(scheme: Scheme, operation: impl Into<&'static str>, structured: bool) -> Self {
+        Self {
+            scheme,
+            operation: operation.into(),
+            structured,
+
+            tenant: None,
+            batch: None,
+            path: None,
+            to: None,
+            range: None,
+            bytes: None,
+            duration: None,
+            error: None,
+            detail: None,
+        }
+    }
+
+    fn with_tenant(mut self, tenant: Option<&'a Tenant>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    fn with_batch(mut self, op: Operation, count: usize) -> Self {
+        self.batch = Some((op, count));
+        self
+    }
+
+    fn with_path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    fn with_to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    fn with_range(mut self, range: BytesRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    fn with_read(mut self, bytes: u64) -> Self {
+        self.bytes = Some(("read", bytes));
+        self
+    }
+
+    fn with_written(mut self, bytes: u64) -> Self {
+        self.bytes = Some(("written", bytes));
+        self
+    }
+
+    fn with_duration(mut self, start: Instant) -> Self {
+        self.duration = Some(start.elapsed());
+        self
+    }
+
+    fn with_error(mut self, err: &'a Error) -> Self {
+        self.error = Some(err);
+        self
+    }
+
+    /// Attach the result of operation, which is only used in the
+    /// human-readable mode.
+    fn with_detail(mut self, detail: &'a dyn Debug) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+
+    /// Emit the progress of an operation like `started` or `pending`.
+    ///
+    /// Progress is skipped while structured fields are enabled.
+    fn emit_progress(self, level: Level, message: impl Display) {
+        if !self.structured {
+            self.emit(level, message)
+        }
+    }
+
+    fn emit(self, level: Level, message: impl Display) {
+        if self.structured {
+            return self.emit_structured(level, message);
+        }
+
+        match (self.error, self.detail) {
+            (Some(err), _) => log!(target: LOGGING_TARGET, level, "{self} -> {message}: {err:?}"),
+            (None, Some(detail)) => {
+                log!(target: LOGGING_TARGET, level, "{self} -> {message}: {detail:?}")
+            }
+            (None, None) => log!(target: LOGGING_TARGET, level, "{self} -> {message}"),
+        }
+    }
+
+    #[cfg(feature = "layers-tracing")]
+    fn emit_structured(self, level: Level, message: impl Display) {
+        // tracing requires the level of event to be a constant.
+        macro_rules! event {
+            ($level:expr) => {
+                tracing::event!(
+                    target: LOGGING_TARGET,
+                    $level,
+                    service = self.scheme.into_static(),
+                    operation = self.operation,
+                    tenant = self.tenant.map(|v| v.as_str()),
+                    count = self.batch.map(|(_, count)| count),
+                    path = self.path,
+                    to = self.to,
+                    bytes = self.bytes.map(|(_, bytes)| bytes),
+                    duration = self.duration.map(|v| v.as_secs_f64()),
+                    error_kind = self.error.map(|v| v.kind().into_static()),
+                    error = self.error.map(tracing::field::display),
+                    "{}",
+                    message
+                )
+            };
+        }
+
+        match level {
+            Level::Error => event!(tracing::Level::ERROR),
+            Level::Warn => event!(tracing::Level::WARN),
+            Level::Info => event!(tracing::Level::INFO),
+            Level::Debug => event!(tracing::Level::DEBUG),
+            Level::Trace => event!(tracing::Level::TRACE),
+        }
+    }
+
+    /// Structured mode can't be enabled without `layers-tracing`, so
+    /// there is nothing to emit.
+    #[cfg(not(feature = "layers-tracing"))]
+    fn emit_structured(self, level: Level, message: impl Display) {
+        let _ = (level, message);
+    }
+}
+
+impl Display for LogEvent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "service={} operation={}", self.scheme, self.operation)?;
+        if let Some((op, count)) = self.batch {
+            write!(f, "-{op} count={count}")?;
+        }
+        if let Some(tenant) = self.tenant {
+            write!(f, " tenant={tenant}")?;
+        }
+        match (self.path, self.to) {
+            (Some(from), Some(to)) => write!(f, " from={from} to={to}")?,
+            (Some(path), None) => write!(f, " path={path}")?,
+            _ => {}
+        }
+        if let Some(range) = self.range {
+            write!(f, " range={range}")?;
+        }
+        if let Some((name, bytes)) = self.bytes {
+            write!(f, " {name}={bytes}")?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -198,371 +446,350 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
     }
 
     fn metadata(&self) -> AccessorInfo {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} -> started",
-            self.scheme,
-            Operation::Info
-        );
+        let start = Instant::now();
+
+        self.event(Operation::Info)
+            .emit_progress(Level::Debug, "started");
         let result = self.inner.info();
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} -> finished: {:?}",
-            self.scheme,
-            Operation::Info,
-            result
-        );
+        self.event(Operation::Info)
+            .with_duration(start)
+            .with_detail(&result)
+            .emit(Level::Debug, "finished");
 
         result
     }
 
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::CreateDir,
-            path
-        );
+        let start = Instant::now();
+
+        self.event(Operation::CreateDir)
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .create_dir(path, args)
             .await
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished",
-                    self.scheme,
-                    Operation::CreateDir,
-                    path
-                );
+                self.event(Operation::CreateDir)
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::CreateDir,
-                        path,
-                        self.err_status(&err)
-                    )
-                };
+                let event = self
+                    .event(Operation::CreateDir)
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} range={} -> started",
-            self.scheme,
-            Operation::Read,
-            path,
-            args.range()
-        );
-
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
         let range = args.range();
 
+        self.event(Operation::Read)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .with_range(range)
+            .emit_progress(Level::Debug, "started");
+
         self.inner
             .read(path, args)
             .await
             .map(|(rp, r)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} range={} -> got reader",
-                    self.scheme,
-                    Operation::Read,
-                    path,
-                    range
-                );
+                self.event(Operation::Read)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_range(range)
+                    .with_duration(start)
+                    .emit(Level::Debug, "got reader");
                 (
                     rp,
-                    LoggingReader::new(self.scheme, Operation::Read, path, r, self.failure_level),
-                )
-            })
-            .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} range={} -> {}: {err:?}",
+                    LoggingReader::new(
                         self.scheme,
                         Operation::Read,
                         path,
-                        range,
-                        self.err_status(&err)
+                        r,
+                        self.failure_level,
+                        self.structured,
                     )
-                }
+                    .with_tenant(tenant.clone()),
+                )
+            })
+            .map_err(|err| {
+                let event = self
+                    .event(Operation::Read)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_range(range)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::Write,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::Write)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .write(path, args)
             .await
             .map(|(rp, w)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> start writing",
+                self.event(Operation::Write)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "start writing");
+                let w = LoggingWriter::new(
                     self.scheme,
                     Operation::Write,
                     path,
-                );
-                let w =
-                    LoggingWriter::new(self.scheme, Operation::Write, path, w, self.failure_level);
+                    w,
+                    self.failure_level,
+                    self.structured,
+                )
+                .with_tenant(tenant.clone());
                 (rp, w)
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Write,
-                        path,
-                        self.err_status(&err)
-                    )
-                };
+                let event = self
+                    .event(Operation::Write)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
+                err
+            })
+    }
+
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::Write)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
+
+        self.inner
+            .multipart(path, args)
+            .await
+            .map(|(rp, u)| {
+                self.event(Operation::Write)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "start multipart uploading");
+                let u: oio::MultipartUploader = Box::new(LoggingUploader {
+                    scheme: self.scheme,
+                    path: path.to_string(),
+                    failure_level: self.failure_level,
+                    structured: self.structured,
+                    tenant: tenant.clone(),
+                    start,
+                    inner: u,
+                });
+                (rp, u)
+            })
+            .map_err(|err| {
+                let event = self
+                    .event(Operation::Write)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::Append,
-            path
-        );
+        let start = Instant::now();
+
+        self.event(Operation::Append)
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .append(path, args)
             .await
             .map(|(rp, a)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> start appending",
-                    self.scheme,
-                    Operation::Append,
-                    path,
-                );
+                self.event(Operation::Append)
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "start appending");
                 let a = LoggingAppender::new(
                     self.scheme,
                     Operation::Append,
                     path,
                     a,
                     self.failure_level,
+                    self.structured,
                 );
                 (rp, a)
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Append,
-                        path,
-                        self.err_status(&err)
-                    )
-                };
+                let event = self
+                    .event(Operation::Append)
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} from={} to={} -> started",
-            self.scheme,
-            Operation::Copy,
-            from,
-            to
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::Copy)
+            .with_tenant(tenant.as_ref())
+            .with_path(from)
+            .with_to(to)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .copy(from, to, args)
             .await
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} from={} to={} -> finished",
-                    self.scheme,
-                    Operation::Copy,
-                    from,
-                    to
-                );
+                self.event(Operation::Copy)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} from={} to={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Copy,
-                        from,
-                        to,
-                        self.err_status(&err)
-                    )
-                };
+                let event = self
+                    .event(Operation::Copy)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} from={} to={} -> started",
-            self.scheme,
-            Operation::Rename,
-            from,
-            to
-        );
+        let start = Instant::now();
+
+        self.event(Operation::Rename)
+            .with_path(from)
+            .with_to(to)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .rename(from, to, args)
             .await
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} from={} to={} -> finished",
-                    self.scheme,
-                    Operation::Rename,
-                    from,
-                    to
-                );
+                self.event(Operation::Rename)
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} from={} to={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Rename,
-                        from,
-                        to,
-                        self.err_status(&err)
-                    )
-                };
+                let event = self
+                    .event(Operation::Rename)
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::Stat,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::Stat)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .stat(path, args)
             .await
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished: {v:?}",
-                    self.scheme,
-                    Operation::Stat,
-                    path
-                );
+                self.event(Operation::Stat)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .with_detail(&v)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Stat,
-                        path,
-                        self.err_status(&err)
-                    );
-                };
+                let event = self
+                    .event(Operation::Stat)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::Delete,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::Delete)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .delete(path, args.clone())
-            .inspect(|v| match v {
-                Ok(_) => {
-                    debug!(
-                        target: LOGGING_TARGET,
-                        "service={} operation={} path={} -> finished",
-                        self.scheme,
-                        Operation::Delete,
-                        path
-                    );
-                }
-                Err(err) => {
-                    if let Some(lvl) = self.err_level(err) {
-                        log!(
-                            target: LOGGING_TARGET,
-                            lvl,
-                            "service={} operation={} path={} -> {}: {err:?}",
-                            self.scheme,
-                            Operation::Delete,
-                            path,
-                            self.err_status(err)
-                        );
-                    }
+            .inspect(|v| {
+                let event = self
+                    .event(Operation::Delete)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                match v {
+                    Ok(_) => event.emit(Level::Debug, "finished"),
+                    Err(err) => self.emit_err(event, err),
                 }
             })
             .await
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::List,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::List)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .list(path, args)
             .map(|v| match v {
                 Ok((rp, v)) => {
-                    debug!(
-                        target: LOGGING_TARGET,
-                        "service={} operation={} path={} -> start listing dir",
-                        self.scheme,
-                        Operation::List,
-                        path
-                    );
+                    self.event(Operation::List)
+                        .with_tenant(tenant.as_ref())
+                        .with_path(path)
+                        .with_duration(start)
+                        .emit(Level::Debug, "start listing dir");
                     let streamer = LoggingPager::new(
                         self.scheme,
                         path,
@@ -570,21 +797,18 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
                         v,
                         self.error_level,
                         self.failure_level,
-                    );
+                        self.structured,
+                    )
+                    .with_tenant(tenant.clone());
                     Ok((rp, streamer))
                 }
                 Err(err) => {
-                    if let Some(lvl) = self.err_level(&err) {
-                        log!(
-                            target: LOGGING_TARGET,
-                            lvl,
-                            "service={} operation={} path={} -> {}: {err:?}",
-                            self.scheme,
-                            Operation::List,
-                            path,
-                            self.err_status(&err)
-                        );
-                    }
+                    let event = self
+                        .event(Operation::List)
+                        .with_tenant(tenant.as_ref())
+                        .with_path(path)
+                        .with_duration(start);
+                    self.emit_err(event, &err);
                     Err(err)
                 }
             })
@@ -592,384 +816,318 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
     }
 
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::Presign,
-            path
-        );
+        let start = Instant::now();
+
+        self.event(Operation::Presign)
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .presign(path, args)
             .await
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished: {v:?}",
-                    self.scheme,
-                    Operation::Presign,
-                    path
-                );
+                self.event(Operation::Presign)
+                    .with_path(path)
+                    .with_duration(start)
+                    .with_detail(&v)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Presign,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::Presign)
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let start = Instant::now();
+
         let (op, count) = (args.operation()[0].1.operation(), args.operation().len());
 
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={}-{op} count={count} -> started",
-            self.scheme,
-            Operation::Batch,
-        );
+        self.event(Operation::Batch)
+            .with_batch(op, count)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .batch(args)
             .map_ok(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={}-{op} count={count} -> finished: {}, succeed: {}, failed: {}",
-                    self.scheme,
-                    Operation::Batch,
-                    v.results().len(),
-                    v.results().iter().filter(|(_, v)|v.is_ok()).count(),
-                    v.results().iter().filter(|(_, v)|v.is_err()).count(),
-                );
+                self.event(Operation::Batch)
+                    .with_batch(op, count)
+                    .with_duration(start)
+                    .emit(
+                        Level::Debug,
+                        format_args!(
+                            "finished: {}, succeed: {}, failed: {}",
+                            v.results().len(),
+                            v.results().iter().filter(|(_, v)| v.is_ok()).count(),
+                            v.results().iter().filter(|(_, v)| v.is_err()).count(),
+                        ),
+                    );
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={}-{op} count={count} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::Batch,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::Batch)
+                    .with_batch(op, count)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
             .await
     }
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::BlockingCreateDir,
-            path
-        );
+        let start = Instant::now();
+
+        self.event(Operation::BlockingCreateDir)
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_create_dir(path, args)
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished",
-                    self.scheme,
-                    Operation::BlockingCreateDir,
-                    path
-                );
+                self.event(Operation::BlockingCreateDir)
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingCreateDir,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingCreateDir)
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} range={} -> started",
-            self.scheme,
-            Operation::BlockingRead,
-            path,
-            args.range(),
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+        let range = args.range();
+
+        self.event(Operation::BlockingRead)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .with_range(range)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
-            .blocking_read(path, args.clone())
+            .blocking_read(path, args)
             .map(|(rp, r)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} range={} -> got reader",
-                    self.scheme,
-                    Operation::BlockingRead,
-                    path,
-                    args.range(),
-                );
+                self.event(Operation::BlockingRead)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_range(range)
+                    .with_duration(start)
+                    .emit(Level::Debug, "got reader");
                 let r = LoggingReader::new(
                     self.scheme,
                     Operation::BlockingRead,
                     path,
                     r,
                     self.failure_level,
-                );
+                    self.structured,
+                )
+                .with_tenant(tenant.clone());
                 (rp, r)
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} range={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingRead,
-                        path,
-                        args.range(),
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingRead)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_range(range)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::BlockingWrite,
-            path,
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::BlockingWrite)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_write(path, args)
             .map(|(rp, w)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> start writing",
-                    self.scheme,
-                    Operation::BlockingWrite,
-                    path,
-                );
+                self.event(Operation::BlockingWrite)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "start writing");
                 let w = LoggingWriter::new(
                     self.scheme,
                     Operation::BlockingWrite,
                     path,
                     w,
                     self.failure_level,
-                );
+                    self.structured,
+                )
+                .with_tenant(tenant.clone());
                 (rp, w)
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingWrite,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingWrite)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} from={} to={} -> started",
-            self.scheme,
-            Operation::BlockingCopy,
-            from,
-            to,
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::BlockingCopy)
+            .with_tenant(tenant.as_ref())
+            .with_path(from)
+            .with_to(to)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_copy(from, to, args)
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} from={} to={} -> finished",
-                    self.scheme,
-                    Operation::BlockingCopy,
-                    from,
-                    to,
-                );
+                self.event(Operation::BlockingCopy)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} from={} to={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingCopy,
-                        from,
-                        to,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingCopy)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} from={} to={} -> started",
-            self.scheme,
-            Operation::BlockingRename,
-            from,
-            to,
-        );
+        let start = Instant::now();
+
+        self.event(Operation::BlockingRename)
+            .with_path(from)
+            .with_to(to)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_rename(from, to, args)
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} from={} to={} -> finished",
-                    self.scheme,
-                    Operation::BlockingRename,
-                    from,
-                    to,
-                );
+                self.event(Operation::BlockingRename)
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} from={} to={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingRename,
-                        from,
-                        to,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingRename)
+                    .with_path(from)
+                    .with_to(to)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::BlockingStat,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::BlockingStat)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_stat(path, args)
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished: {v:?}",
-                    self.scheme,
-                    Operation::BlockingStat,
-                    path
-                );
+                self.event(Operation::BlockingStat)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .with_detail(&v)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingStat,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingStat)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::BlockingDelete,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::BlockingDelete)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_delete(path, args)
             .map(|v| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished",
-                    self.scheme,
-                    Operation::BlockingDelete,
-                    path
-                );
+                self.event(Operation::BlockingDelete)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "finished");
                 v
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingDelete,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingDelete)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} -> started",
-            self.scheme,
-            Operation::BlockingList,
-            path
-        );
+        let start = Instant::now();
+        let tenant = args.extensions().get::<Tenant>().cloned();
+
+        self.event(Operation::BlockingList)
+            .with_tenant(tenant.as_ref())
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
 
         self.inner
             .blocking_list(path, args)
             .map(|(rp, v)| {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> got dir",
-                    self.scheme,
-                    Operation::BlockingList,
-                    path
-                );
+                self.event(Operation::BlockingList)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start)
+                    .emit(Level::Debug, "got dir");
                 let li = LoggingPager::new(
                     self.scheme,
                     path,
@@ -977,21 +1135,45 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
                     v,
                     self.error_level,
                     self.failure_level,
-                );
+                    self.structured,
+                )
+                .with_tenant(tenant.clone());
                 (rp, li)
             })
             .map_err(|err| {
-                if let Some(lvl) = self.err_level(&err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        Operation::BlockingList,
-                        path,
-                        self.err_status(&err)
-                    );
-                }
+                let event = self
+                    .event(Operation::BlockingList)
+                    .with_tenant(tenant.as_ref())
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
+                err
+            })
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let start = Instant::now();
+
+        self.event(Operation::BlockingPresign)
+            .with_path(path)
+            .emit_progress(Level::Debug, "started");
+
+        self.inner
+            .blocking_presign(path, args)
+            .map(|v| {
+                self.event(Operation::BlockingPresign)
+                    .with_path(path)
+                    .with_duration(start)
+                    .with_detail(&v)
+                    .emit(Level::Debug, "finished");
+                v
+            })
+            .map_err(|err| {
+                let event = self
+                    .event(Operation::BlockingPresign)
+                    .with_path(path)
+                    .with_duration(start);
+                self.emit_err(event, &err);
                 err
             })
     }
@@ -1005,6 +1187,9 @@ pub struct LoggingReader<R> {
 
     read: u64,
     failure_level: Option<Level>,
+    structured: bool,
+    tenant: Option<Tenant>,
+    start: Instant,
 
     inner: R,
 }
@@ -1016,6 +1201,7 @@ impl<R> LoggingReader<R> {
         path: &str,
         reader: R,
         failure_level: Option<Level>,
+        structured: bool,
     ) -> Self {
         Self {
             scheme,
@@ -1026,20 +1212,36 @@ impl<R> LoggingReader<R> {
 
             inner: reader,
             failure_level,
+            structured,
+            tenant: None,
+            start: Instant::now(),
+        }
+    }
+
+    fn with_tenant(mut self, tenant: Option<Tenant>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    #[inline]
+    fn event(&self, op: impl Into<&'static str>) -> LogEvent<'_> {
+        LogEvent::new(self.scheme, op, self.structured)
+            .with_tenant(self.tenant.as_ref())
+            .with_path(&self.path)
+            .with_read(self.read)
+            .with_duration(self.start)
+    }
+
+    fn emit_err(&self, op: ReadOperation, err: &Error) {
+        if let Some(lvl) = self.failure_level {
+            self.event(op).with_error(err).emit(lvl, "data read failed");
         }
     }
 }
 
 impl<R> Drop for LoggingReader<R> {
     fn drop(&mut self) {
-        debug!(
-            target: LOGGING_TARGET,
-            "service={} operation={} path={} read={} -> data read finished",
-            self.scheme,
-            self.op,
-            self.path,
-            self.read
-        );
+        self.event(self.op).emit(Level::Debug, "data read finished");
     }
 }
 
@@ -1049,41 +1251,18 @@ impl<R: oio::Read> oio::Read for LoggingReader<R> {
             Poll::Ready(res) => match res {
                 Ok(n) => {
                     self.read += n as u64;
-                    trace!(
-                        target: LOGGING_TARGET,
-                        "service={} operation={} path={} read={} -> data read {}B ",
-                        self.scheme,
-                        ReadOperation::Read,
-                        self.path,
-                        self.read,
-                        n
-                    );
+                    self.event(ReadOperation::Read)
+                        .emit_progress(Level::Trace, format_args!("data read {n}B "));
                     Poll::Ready(Ok(n))
                 }
                 Err(err) => {
-                    if let Some(lvl) = self.failure_level {
-                        log!(
-                            target: LOGGING_TARGET,
-                            lvl,
-                            "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                            self.scheme,
-                            ReadOperation::Read,
-                            self.path,
-                            self.read,
-                        )
-                    }
+                    self.emit_err(ReadOperation::Read, &err);
                     Poll::Ready(Err(err))
                 }
             },
             Poll::Pending => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data read pending",
-                    self.scheme,
-                    ReadOperation::Read,
-                    self.path,
-                    self.read
-                );
+                self.event(ReadOperation::Read)
+                    .emit_progress(Level::Trace, "data read pending");
                 Poll::Pending
             }
         }
@@ -1093,40 +1272,18 @@ impl<R: oio::Read> oio::Read for LoggingReader<R> {
         match self.inner.poll_seek(cx, pos) {
             Poll::Ready(res) => match res {
                 Ok(n) => {
-                    trace!(
-                        target: LOGGING_TARGET,
-                        "service={} operation={} path={} read={} -> data seek to offset {n}",
-                        self.scheme,
-                        ReadOperation::Seek,
-                        self.path,
-                        self.read,
-                    );
+                    self.event(ReadOperation::Seek)
+                        .emit_progress(Level::Trace, format_args!("data seek to offset {n}"));
                     Poll::Ready(Ok(n))
                 }
                 Err(err) => {
-                    if let Some(lvl) = self.failure_level {
-                        log!(
-                            target: LOGGING_TARGET,
-                            lvl,
-                            "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                            self.scheme,
-                            ReadOperation::Seek,
-                            self.path,
-                            self.read,
-                        )
-                    }
+                    self.emit_err(ReadOperation::Seek, &err);
                     Poll::Ready(Err(err))
                 }
             },
             Poll::Pending => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data seek pending",
-                    self.scheme,
-                    ReadOperation::Seek,
-                    self.path,
-                    self.read
-                );
+                self.event(ReadOperation::Seek)
+                    .emit_progress(Level::Trace, "data seek pending");
                 Poll::Pending
             }
         }
@@ -1137,42 +1294,19 @@ impl<R: oio::Read> oio::Read for LoggingReader<R> {
             Poll::Ready(res) => match res {
                 Some(Ok(bs)) => {
                     self.read += bs.len() as u64;
-                    trace!(
-                        target: LOGGING_TARGET,
-                        "service={} operation={} path={} read={} -> data read {}B",
-                        self.scheme,
-                        ReadOperation::Next,
-                        self.path,
-                        self.read,
-                        bs.len()
-                    );
+                    self.event(ReadOperation::Next)
+                        .emit_progress(Level::Trace, format_args!("data read {}B", bs.len()));
                     Poll::Ready(Some(Ok(bs)))
                 }
                 Some(Err(err)) => {
-                    if let Some(lvl) = self.failure_level {
-                        log!(
-                            target: LOGGING_TARGET,
-                            lvl,
-                            "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                            self.scheme,
-                            ReadOperation::Next,
-                            self.path,
-                            self.read,
-                        )
-                    }
+                    self.emit_err(ReadOperation::Next, &err);
                     Poll::Ready(Some(Err(err)))
                 }
                 None => Poll::Ready(None),
             },
             Poll::Pending => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data read pending",
-                    self.scheme,
-                    ReadOperation::Next,
-                    self.path,
-                    self.read
-                );
+                self.event(ReadOperation::Next)
+                    .emit_progress(Level::Trace, "data read pending");
                 Poll::Pending
             }
         }
@@ -1184,29 +1318,12 @@ impl<R: oio::BlockingRead> oio::BlockingRead for LoggingReader<R> {
         match self.inner.read(buf) {
             Ok(n) => {
                 self.read += n as u64;
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data read {}B",
-                    self.scheme,
-                    ReadOperation::BlockingRead,
-                    self.path,
-                    self.read,
-                    n
-                );
+                self.event(ReadOperation::BlockingRead)
+                    .emit_progress(Level::Trace, format_args!("data read {n}B"));
                 Ok(n)
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                        self.scheme,
-                        ReadOperation::BlockingRead,
-                        self.path,
-                        self.read,
-                    );
-                }
+                self.emit_err(ReadOperation::BlockingRead, &err);
                 Err(err)
             }
         }
@@ -1216,28 +1333,12 @@ impl<R: oio::BlockingRead> oio::BlockingRead for LoggingReader<R> {
     fn seek(&mut self, pos: io::SeekFrom) -> Result<u64> {
         match self.inner.seek(pos) {
             Ok(n) => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data seek to offset {n}",
-                    self.scheme,
-                    ReadOperation::BlockingSeek,
-                    self.path,
-                    self.read,
-                );
+                self.event(ReadOperation::BlockingSeek)
+                    .emit_progress(Level::Trace, format_args!("data seek to offset {n}"));
                 Ok(n)
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                        self.scheme,
-                        ReadOperation::BlockingSeek,
-                        self.path,
-                        self.read,
-                    );
-                }
+                self.emit_err(ReadOperation::BlockingSeek, &err);
                 Err(err)
             }
         }
@@ -1247,29 +1348,12 @@ impl<R: oio::BlockingRead> oio::BlockingRead for LoggingReader<R> {
         match self.inner.next() {
             Some(Ok(bs)) => {
                 self.read += bs.len() as u64;
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} read={} -> data read {}B",
-                    self.scheme,
-                    ReadOperation::BlockingNext,
-                    self.path,
-                    self.read,
-                    bs.len()
-                );
+                self.event(ReadOperation::BlockingNext)
+                    .emit_progress(Level::Trace, format_args!("data read {}B", bs.len()));
                 Some(Ok(bs))
             }
             Some(Err(err)) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} read={} -> data read failed: {err:?}",
-                        self.scheme,
-                        ReadOperation::BlockingNext,
-                        self.path,
-                        self.read,
-                    )
-                }
+                self.emit_err(ReadOperation::BlockingNext, &err);
                 Some(Err(err))
             }
             None => None,
@@ -1284,6 +1368,9 @@ pub struct LoggingWriter<W> {
 
     written: u64,
     failure_level: Option<Level>,
+    structured: bool,
+    tenant: Option<Tenant>,
+    start: Instant,
 
     inner: W,
 }
@@ -1295,6 +1382,7 @@ impl<W> LoggingWriter<W> {
         path: &str,
         writer: W,
         failure_level: Option<Level>,
+        structured: bool,
     ) -> Self {
         Self {
             scheme,
@@ -1304,6 +1392,29 @@ impl<W> LoggingWriter<W> {
             written: 0,
             inner: writer,
             failure_level,
+            structured,
+            tenant: None,
+            start: Instant::now(),
+        }
+    }
+
+    fn with_tenant(mut self, tenant: Option<Tenant>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    #[inline]
+    fn event(&self, op: impl Into<&'static str>) -> LogEvent<'_> {
+        LogEvent::new(self.scheme, op, self.structured)
+            .with_tenant(self.tenant.as_ref())
+            .with_path(&self.path)
+            .with_written(self.written)
+            .with_duration(self.start)
+    }
+
+    fn emit_err(&self, op: WriteOperation, err: &Error, message: &str) {
+        if let Some(lvl) = self.failure_level {
+            self.event(op).with_error(err).emit(lvl, message);
         }
     }
 }
@@ -1315,29 +1426,12 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
         match self.inner.write(bs).await {
             Ok(_) => {
                 self.written += size as u64;
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> data write {}B",
-                    self.scheme,
-                    WriteOperation::Write,
-                    self.path,
-                    self.written,
-                    size
-                );
+                self.event(WriteOperation::Write)
+                    .emit_progress(Level::Trace, format_args!("data write {size}B"));
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> data write failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::Write,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(WriteOperation::Write, &err, "data write failed");
                 Err(err)
             }
         }
@@ -1347,29 +1441,26 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
         match self.inner.sink(size, s).await {
             Ok(_) => {
                 self.written += size;
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> data sink {}B",
-                    self.scheme,
-                    WriteOperation::Sink,
-                    self.path,
-                    self.written,
-                    size
-                );
+                self.event(WriteOperation::Sink)
+                    .emit_progress(Level::Trace, format_args!("data sink {size}B"));
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> data sink failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::Sink,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(WriteOperation::Sink, &err, "data sink failed");
+                Err(err)
+            }
+        }
+    }
+
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        match self.inner.sink_unsized(s).await {
+            Ok(_) => {
+                self.event(WriteOperation::SinkUnsized)
+                    .emit_progress(Level::Trace, "data sink finished");
+                Ok(())
+            }
+            Err(err) => {
+                self.emit_err(WriteOperation::SinkUnsized, &err, "data sink failed");
                 Err(err)
             }
         }
@@ -1378,28 +1469,12 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
     async fn abort(&mut self) -> Result<()> {
         match self.inner.abort().await {
             Ok(_) => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> abort writer",
-                    self.scheme,
-                    WriteOperation::Abort,
-                    self.path,
-                    self.written,
-                );
+                self.event(WriteOperation::Abort)
+                    .emit_progress(Level::Trace, "abort writer");
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> abort writer failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::Abort,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(WriteOperation::Abort, &err, "abort writer failed");
                 Err(err)
             }
         }
@@ -1408,28 +1483,12 @@ impl<W: oio::Write> oio::Write for LoggingWriter<W> {
     async fn close(&mut self) -> Result<()> {
         match self.inner.close().await {
             Ok(_) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> data written finished",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                    self.written
-                );
+                self.event(self.op)
+                    .emit(Level::Debug, "data written finished");
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> data close failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::Close,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(WriteOperation::Close, &err, "data close failed");
                 Err(err)
             }
         }
@@ -1442,29 +1501,12 @@ impl<W: oio::BlockingWrite> oio::BlockingWrite for LoggingWriter<W> {
         match self.inner.write(bs) {
             Ok(_) => {
                 self.written += size as u64;
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> data write {}B",
-                    self.scheme,
-                    WriteOperation::BlockingWrite,
-                    self.path,
-                    self.written,
-                    size
-                );
+                self.event(WriteOperation::BlockingWrite)
+                    .emit_progress(Level::Trace, format_args!("data write {size}B"));
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> data write failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::BlockingWrite,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(WriteOperation::BlockingWrite, &err, "data write failed");
                 Err(err)
             }
         }
@@ -1473,28 +1515,152 @@ impl<W: oio::BlockingWrite> oio::BlockingWrite for LoggingWriter<W> {
     fn close(&mut self) -> Result<()> {
         match self.inner.close() {
             Ok(_) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} written={} -> data written finished",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                    self.written
+                self.event(self.op)
+                    .emit(Level::Debug, "data written finished");
+                Ok(())
+            }
+            Err(err) => {
+                self.emit_err(WriteOperation::BlockingClose, &err, "data close failed");
+                Err(err)
+            }
+        }
+    }
+}
+
+pub struct LoggingUploader {
+    scheme: Scheme,
+    path: String,
+
+    failure_level: Option<Level>,
+    structured: bool,
+    tenant: Option<Tenant>,
+    start: Instant,
+
+    inner: oio::MultipartUploader,
+}
+
+impl LoggingUploader {
+    #[inline]
+    fn event(&self, op: impl Into<&'static str>) -> LogEvent<'_> {
+        LogEvent::new(self.scheme, op, self.structured)
+            .with_tenant(self.tenant.as_ref())
+            .with_path(&self.path)
+            .with_duration(self.start)
+    }
+
+    fn emit_err(&self, op: MultipartUploadOperation, err: &Error, message: &str) {
+        if let Some(lvl) = self.failure_level {
+            self.event(op).with_error(err).emit(lvl, message);
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for LoggingUploader {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        match self.inner.write_once(size, body).await {
+            Ok(_) => {
+                self.event(Operation::Write)
+                    .with_written(size)
+                    .emit(Level::Debug, "data written finished");
+                Ok(())
+            }
+            Err(err) => {
+                self.emit_err(
+                    MultipartUploadOperation::WriteOnce,
+                    &err,
+                    "data write failed",
+                );
+                Err(err)
+            }
+        }
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        match self.inner.initiate_part().await {
+            Ok(upload_id) => {
+                self.event(MultipartUploadOperation::InitiatePart)
+                    .emit_progress(Level::Trace, format_args!("upload {upload_id} initiated"));
+                Ok(upload_id)
+            }
+            Err(err) => {
+                self.emit_err(
+                    MultipartUploadOperation::InitiatePart,
+                    &err,
+                    "upload initiate failed",
+                );
+                Err(err)
+            }
+        }
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        match self
+            .inner
+            .write_part(upload_id, part_number, size, body)
+            .await
+        {
+            Ok(part) => {
+                self.event(MultipartUploadOperation::WritePart)
+                    .with_written(size)
+                    .emit_progress(
+                        Level::Trace,
+                        format_args!("part {part_number} write {size}B"),
+                    );
+                Ok(part)
+            }
+            Err(err) => {
+                self.emit_err(
+                    MultipartUploadOperation::WritePart,
+                    &err,
+                    "part write failed",
                 );
+                Err(err)
+            }
+        }
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        match self.inner.complete_part(upload_id, parts).await {
+            Ok(_) => {
+                self.event(Operation::Write)
+                    .emit(Level::Debug, "data written finished");
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} written={} -> data close failed: {err:?}",
-                        self.scheme,
-                        WriteOperation::BlockingClose,
-                        self.path,
-                        self.written,
-                    )
-                }
+                self.emit_err(
+                    MultipartUploadOperation::CompletePart,
+                    &err,
+                    "upload complete failed",
+                );
+                Err(err)
+            }
+        }
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        match self.inner.abort_part(upload_id).await {
+            Ok(_) => {
+                self.event(MultipartUploadOperation::AbortPart)
+                    .emit_progress(Level::Trace, "abort upload");
+                Ok(())
+            }
+            Err(err) => {
+                self.emit_err(
+                    MultipartUploadOperation::AbortPart,
+                    &err,
+                    "abort upload failed",
+                );
                 Err(err)
             }
         }
@@ -1507,6 +1673,8 @@ pub struct LoggingAppender<A> {
     path: String,
 
     failure_level: Option<Level>,
+    structured: bool,
+    start: Instant,
 
     inner: A,
 }
@@ -1518,6 +1686,7 @@ impl<A> LoggingAppender<A> {
         path: &str,
         appender: A,
         failure_level: Option<Level>,
+        structured: bool,
     ) -> Self {
         Self {
             scheme,
@@ -1525,10 +1694,25 @@ impl<A> LoggingAppender<A> {
             path: path.to_string(),
 
             failure_level,
+            structured,
+            start: Instant::now(),
 
             inner: appender,
         }
     }
+
+    #[inline]
+    fn event(&self) -> LogEvent<'_> {
+        LogEvent::new(self.scheme, self.op, self.structured)
+            .with_path(&self.path)
+            .with_duration(self.start)
+    }
+
+    fn emit_err(&self, err: &Error, message: &str) {
+        if let Some(lvl) = self.failure_level {
+            self.event().with_error(err).emit(lvl, message);
+        }
+    }
 }
 
 #[async_trait]
@@ -1538,27 +1722,12 @@ impl<A: oio::Append> oio::Append for LoggingAppender<A> {
 
         match self.inner.append(bs).await {
             Ok(_) => {
-                trace!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> data append {}B",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                    len
-                );
+                self.event()
+                    .emit_progress(Level::Trace, format_args!("data append {len}B"));
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> data append failed: {err:?}",
-                        self.scheme,
-                        self.op,
-                        self.path,
-                    )
-                }
+                self.emit_err(&err, "data append failed");
                 Err(err)
             }
         }
@@ -1567,26 +1736,11 @@ impl<A: oio::Append> oio::Append for LoggingAppender<A> {
     async fn close(&mut self) -> Result<()> {
         match self.inner.close().await {
             Ok(_) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> data appended finished",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                );
+                self.event().emit(Level::Debug, "data appended finished");
                 Ok(())
             }
             Err(err) => {
-                if let Some(lvl) = self.failure_level {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> data appender close failed: {err:?}",
-                        self.scheme,
-                        self.op,
-                        self.path,
-                    )
-                }
+                self.emit_err(&err, "data appender close failed");
                 Err(err)
             }
         }
@@ -1602,6 +1756,9 @@ pub struct LoggingPager<P> {
     inner: P,
     error_level: Option<Level>,
     failure_level: Option<Level>,
+    structured: bool,
+    tenant: Option<Tenant>,
+    start: Instant,
 }
 
 impl<P> LoggingPager<P> {
@@ -1612,6 +1769,7 @@ impl<P> LoggingPager<P> {
         inner: P,
         error_level: Option<Level>,
         failure_level: Option<Level>,
+        structured: bool,
     ) -> Self {
         Self {
             scheme,
@@ -1621,28 +1779,25 @@ impl<P> LoggingPager<P> {
             inner,
             error_level,
             failure_level,
+            structured,
+            tenant: None,
+            start: Instant::now(),
         }
     }
+
+    fn with_tenant(mut self, tenant: Option<Tenant>) -> Self {
+        self.tenant = tenant;
+        self
+    }
 }
 
 impl<P> Drop for LoggingPager<P> {
     fn drop(&mut self) {
         if self.finished {
-            debug!(
-                target: LOGGING_TARGET,
-                "service={} operation={} path={} -> all entries read finished",
-                self.scheme,
-                self.op,
-                self.path
-            );
+            self.event().emit(Level::Debug, "all entries read finished");
         } else {
-            debug!(
-                target: LOGGING_TARGET,
-                "service={} operation={} path={} -> partial entries read finished",
-                self.scheme,
-                self.op,
-                self.path
-            );
+            self.event()
+                .emit(Level::Debug, "partial entries read finished");
         }
     }
 }
@@ -1665,87 +1820,177 @@ impl<P> LoggingPager<P> {
             self.error_level
         }
     }
-}
 
-#[async_trait]
-impl<P: oio::Page> oio::Page for LoggingPager<P> {
-    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
-        let res = self.inner.next().await;
+    #[inline]
+    fn event(&self) -> LogEvent<'_> {
+        LogEvent::new(self.scheme, self.op, self.structured)
+            .with_tenant(self.tenant.as_ref())
+            .with_path(&self.path)
+            .with_duration(self.start)
+    }
 
-        match &res {
+    fn emit_res(&mut self, res: &Result<Option<Vec<oio::Entry>>>) {
+        match res {
             Ok(Some(des)) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> listed {} entries",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                    des.len(),
-                );
+                self.event()
+                    .emit_progress(Level::Debug, format_args!("listed {} entries", des.len()));
             }
             Ok(None) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished", self.scheme, self.op, self.path
-                );
+                self.event().emit(Level::Debug, "finished");
                 self.finished = true;
             }
             Err(err) => {
                 if let Some(lvl) = self.err_level(err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        self.op,
-                        self.path,
-                        self.err_status(err)
-                    )
+                    self.event().with_error(err).emit(lvl, self.err_status(err));
                 }
             }
-        };
+        }
+    }
+}
 
+#[async_trait]
+impl<P: oio::Page> oio::Page for LoggingPager<P> {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let res = self.inner.next().await;
+        self.emit_res(&res);
         res
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<P: oio::BlockingPage> oio::BlockingPage for LoggingPager<P> {
     fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
         let res = self.inner.next();
+        self.emit_res(&res);
+        res
+    }
+}
 
-        match &res {
-            Ok(Some(des)) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> got {} entries",
-                    self.scheme,
-                    self.op,
-                    self.path,
-                    des.len(),
-                );
-            }
-            Ok(None) => {
-                debug!(
-                    target: LOGGING_TARGET,
-                    "service={} operation={} path={} -> finished", self.scheme, self.op, self.path
-                );
-                self.finished = true;
-            }
-            Err(err) => {
-                if let Some(lvl) = self.err_level(err) {
-                    log!(
-                        target: LOGGING_TARGET,
-                        lvl,
-                        "service={} operation={} path={} -> {}: {err:?}",
-                        self.scheme,
-                        self.op,
-                        self.path,
-                        self.err_status(err)
-                    )
-                }
-            }
-        };
+#[cfg(all(test, feature = "layers-tracing"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
 
-        res
+    use tracing::field::Field;
+    use tracing::field::Visit;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+    use crate::services;
+
+    type Events = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+    /// CaptureLayer records the fields of all events.
+    #[derive(Default, Clone)]
+    struct CaptureLayer {
+        events: Events,
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CaptureLayer {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut visitor = FieldVisitor::default();
+            event.record(&mut visitor);
+            self.events.lock().unwrap().push(visitor.0);
+        }
+    }
+
+    #[derive(Default)]
+    struct FieldVisitor(HashMap<String, String>);
+
+    impl Visit for FieldVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    #[test]
+    fn test_plain_format() {
+        let event = LogEvent::new(Scheme::Memory, Operation::Read, false)
+            .with_path("hello")
+            .with_range(BytesRange::new(Some(0), Some(5)))
+            .with_read(5);
+        assert_eq!(
+            event.to_string(),
+            "service=memory operation=read path=hello range=0-4 read=5"
+        );
+
+        let tenant = Tenant::new("alice");
+        let event = LogEvent::new(Scheme::Memory, Operation::Copy, false)
+            .with_tenant(Some(&tenant))
+            .with_path("from")
+            .with_to("to");
+        assert_eq!(
+            event.to_string(),
+            "service=memory operation=copy tenant=alice from=from to=to"
+        );
+
+        let event =
+            LogEvent::new(Scheme::Memory, Operation::Batch, false).with_batch(Operation::Delete, 3);
+        assert_eq!(
+            event.to_string(),
+            "service=memory operation=batch-delete count=3"
+        );
+    }
+
+    #[test]
+    fn test_structured_fields() -> Result<()> {
+        let layer = CaptureLayer::default();
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+
+        let op = Operator::new(services::Memory::default())?
+            .layer(LoggingLayer::default().with_structured_fields(true))
+            .finish()
+            .blocking();
+
+        tracing::subscriber::with_default(subscriber, || -> Result<()> {
+            op.write("hello", "world")?;
+            assert_eq!(op.read("hello")?, b"world");
+            let _ = op.stat("not_exist").unwrap_err();
+            Ok(())
+        })?;
+
+        let events = layer.events.lock().unwrap();
+        // No formatted message with `service=` should be emitted.
+        assert!(events.iter().all(|v| !v["message"].contains("service=")));
+
+        let written = events
+            .iter()
+            .find(|v| v["message"] == "data written finished")
+            .expect("write event must exist");
+        assert_eq!(written["service"], "memory");
+        assert_eq!(written["operation"], Operation::BlockingWrite.into_static());
+        assert_eq!(written["path"], "hello");
+        assert_eq!(written["bytes"], "5");
+        assert!(written.contains_key("duration"));
+        assert!(!written.contains_key("error_kind"));
+
+        let read = events
+            .iter()
+            .find(|v| v["message"] == "data read finished")
+            .expect("read event must exist");
+        assert_eq!(read["path"], "hello");
+        assert_eq!(read["bytes"], "5");
+
+        let stat = events
+            .iter()
+            .find(|v| v["operation"] == Operation::BlockingStat.into_static())
+            .expect("stat event must exist");
+        assert_eq!(stat["path"], "not_exist");
+        assert_eq!(stat["error_kind"], ErrorKind::NotFound.into_static());
+        assert!(stat.contains_key("duration"));
+        Ok(())
     }
 }
@@ -337,6 +337,13 @@ impl oio::Write for MadsimWriter {
         ))
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> crate::Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "will be supported in the future",
+        ))
+    }
+
     async fn abort(&mut self) -> crate::Result<()> {
         Err(Error::new(
             ErrorKind::Unsupported,
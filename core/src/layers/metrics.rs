@@ -18,6 +18,8 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::io;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
@@ -26,6 +28,7 @@ use std::time::Instant;
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::FutureExt;
+use futures::StreamExt;
 use futures::TryFutureExt;
 use metrics::increment_counter;
 use metrics::register_counter;
@@ -33,6 +36,7 @@ use metrics::register_histogram;
 use metrics::Counter;
 use metrics::Histogram;
 
+use crate::layers::Tenant;
 use crate::raw::*;
 use crate::*;
 
@@ -53,6 +57,8 @@ static METRIC_REQUESTS_DURATION_SECONDS: &str = "opendal_requests_duration_secon
 static METRICS_ERRORS_TOTAL: &str = "opendal_errors_total";
 /// bytes_total records all bytes processed by operator.
 static METRIC_BYTES_TOTAL: &str = "opendal_bytes_total";
+/// tenant_requests_total records requests carrying a [`Tenant`].
+static METRIC_TENANT_REQUESTS_TOTAL: &str = "opendal_tenant_requests_total";
 
 /// The scheme of the service.
 static LABEL_SERVICE: &str = "service";
@@ -60,6 +66,10 @@ static LABEL_SERVICE: &str = "service";
 static LABEL_OPERATION: &str = "operation";
 /// The error kind of this failed request.
 static LABEL_ERROR: &str = "error";
+/// The size bucket of the object being read or written.
+static LABEL_SIZE_BUCKET: &str = "size_bucket";
+/// The tenant of this request.
+static LABEL_TENANT: &str = "tenant";
 
 /// Add [metrics](https://docs.rs/metrics/) for every operations.
 ///
@@ -69,6 +79,7 @@ static LABEL_ERROR: &str = "error";
 /// - `opendal_requests_duration_seconds`: Request duration seconds.
 /// - `opendal_errors_total`: Total error numbers.
 /// - `opendal_bytes_total`: bytes read/write from/to underlying storage.
+/// - `opendal_tenant_requests_total`: Total request numbers of every tenant.
 ///
 /// # Labels
 ///
@@ -77,6 +88,17 @@ static LABEL_ERROR: &str = "error";
 /// - `service`: Service name from [`Scheme`]
 /// - `operation`: Operation name from [`Operation`]
 /// - `error`: [`ErrorKind`] received by requests
+/// - `size_bucket`: The size bucket of the object, only carried by
+///   `requests_duration_seconds` of read and write.
+/// - `tenant`: The [`Tenant`] attached to the operation, only carried by
+///   `tenant_requests_total`.
+///
+/// ## Size Bucket
+///
+/// `size_bucket` is derived from the content length of the object. If the
+/// content length is unknown, the bytes actually processed will be used
+/// instead. The default buckets are `<1K`, `<1M`, `<100M` and `>=100M`,
+/// users can change them via [`MetricsLayer::with_size_buckets`].
 ///
 /// # Notes
 ///
@@ -94,7 +116,7 @@ static LABEL_ERROR: &str = "error";
 ///
 /// let _ = Operator::new(services::Memory::default())
 ///     .expect("must init")
-///     .layer(MetricsLayer)
+///     .layer(MetricsLayer::default())
 ///     .finish();
 /// ```
 ///
@@ -113,8 +135,38 @@ static LABEL_ERROR: &str = "error";
 /// let (recorder, exporter) = builder.build().expect("failed to build recorder/exporter");
 /// let recorder = builder.build_recorder().expect("failed to build recorder");
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct MetricsLayer;
+#[derive(Debug, Clone, Default)]
+pub struct MetricsLayer {
+    size_buckets: SizeBuckets,
+}
+
+impl MetricsLayer {
+    /// Set the upper bounds of size buckets in bytes.
+    ///
+    /// For example, `[1024, 1024 * 1024]` will generate buckets
+    /// `<1K`, `<1M` and `>=1M`.
+    ///
+    /// Every bucket will create a new series for read and write durations,
+    /// please keep the number of buckets small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use anyhow::Result;
+    /// use opendal::layers::MetricsLayer;
+    /// use opendal::services;
+    /// use opendal::Operator;
+    ///
+    /// let _ = Operator::new(services::Memory::default())
+    ///     .expect("must init")
+    ///     .layer(MetricsLayer::default().with_size_buckets(vec![64 * 1024, 16 * 1024 * 1024]))
+    ///     .finish();
+    /// ```
+    pub fn with_size_buckets(mut self, bounds: Vec<u64>) -> Self {
+        self.size_buckets = SizeBuckets::new(bounds);
+        self
+    }
+}
 
 impl<A: Accessor> Layer<A> for MetricsLayer {
     type LayeredAccessor = MetricsAccessor<A>;
@@ -124,9 +176,82 @@ impl<A: Accessor> Layer<A> for MetricsLayer {
 
         MetricsAccessor {
             inner,
-            handle: Arc::new(MetricsHandler::new(meta.scheme().into_static())),
+            handle: Arc::new(MetricsHandler::new(
+                meta.scheme().into_static(),
+                self.size_buckets.clone(),
+            )),
+        }
+    }
+}
+
+/// SizeBuckets splits object sizes into a bounded set of labels.
+#[derive(Debug, Clone)]
+struct SizeBuckets {
+    bounds: Vec<u64>,
+    labels: Vec<String>,
+}
+
+impl Default for SizeBuckets {
+    fn default() -> Self {
+        SizeBuckets::new(vec![1024, 1024 * 1024, 100 * 1024 * 1024])
+    }
+}
+
+impl SizeBuckets {
+    fn new(mut bounds: Vec<u64>) -> Self {
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let mut labels: Vec<String> = bounds
+            .iter()
+            .map(|v| format!("<{}", format_size(*v)))
+            .collect();
+        labels.push(format!(
+            ">={}",
+            format_size(bounds.last().copied().unwrap_or(0))
+        ));
+
+        SizeBuckets { bounds, labels }
+    }
+
+    /// Returns the index of the bucket that given size belongs to.
+    fn index(&self, size: u64) -> usize {
+        self.bounds
+            .iter()
+            .position(|v| size < *v)
+            .unwrap_or(self.bounds.len())
+    }
+}
+
+/// Format size like `1K`, `100M` if it's a multiple of 1024.
+fn format_size(mut size: u64) -> String {
+    for unit in ["", "K", "M", "G", "T"] {
+        if size < 1024 || size % 1024 != 0 {
+            return format!("{size}{unit}");
         }
+        size /= 1024;
     }
+    format!("{size}P")
+}
+
+/// Register histograms of request duration for every size bucket.
+fn register_size_bucket_histograms(
+    service: &'static str,
+    op: Operation,
+    size_buckets: &SizeBuckets,
+) -> Vec<Histogram> {
+    size_buckets
+        .labels
+        .iter()
+        .map(|label| {
+            register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => op.into_static(),
+                LABEL_SIZE_BUCKET => label.clone(),
+            )
+        })
+        .collect()
 }
 
 /// metrics will hold all metrics handlers in a `RwLock<HashMap>`.
@@ -135,6 +260,7 @@ impl<A: Accessor> Layer<A> for MetricsLayer {
 /// cost on fetching them. All metrics update will be atomic operations.
 struct MetricsHandler {
     service: &'static str,
+    size_buckets: SizeBuckets,
 
     requests_total_metadata: Counter,
     requests_duration_seconds_metadata: Histogram,
@@ -143,11 +269,11 @@ struct MetricsHandler {
     requests_duration_seconds_create: Histogram,
 
     requests_total_read: Counter,
-    requests_duration_seconds_read: Histogram,
+    requests_duration_seconds_read: Vec<Histogram>,
     bytes_total_read: Counter,
 
     requests_total_write: Counter,
-    requests_duration_seconds_write: Histogram,
+    requests_duration_seconds_write: Vec<Histogram>,
     bytes_total_write: Counter,
 
     requests_total_stat: Counter,
@@ -169,12 +295,11 @@ struct MetricsHandler {
     requests_duration_seconds_blocking_create: Histogram,
 
     requests_total_blocking_read: Counter,
-    requests_duration_seconds_blocking_read: Histogram,
+    requests_duration_seconds_blocking_read: Vec<Histogram>,
     bytes_total_blocking_read: Counter,
 
     requests_total_blocking_write: Counter,
-    requests_duration_seconds_blocking_write: Histogram,
-    #[allow(dead_code)]
+    requests_duration_seconds_blocking_write: Vec<Histogram>,
     bytes_total_blocking_write: Counter,
 
     requests_total_blocking_stat: Counter,
@@ -185,10 +310,13 @@ struct MetricsHandler {
 
     requests_total_blocking_list: Counter,
     requests_duration_seconds_blocking_list: Histogram,
+
+    requests_total_blocking_presign: Counter,
+    requests_duration_seconds_blocking_presign: Histogram,
 }
 
 impl MetricsHandler {
-    fn new(service: &'static str) -> Self {
+    fn new(service: &'static str, size_buckets: SizeBuckets) -> Self {
         Self {
             service,
 
@@ -219,10 +347,10 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::Read.into_static(),
             ),
-            requests_duration_seconds_read: register_histogram!(
-                METRIC_REQUESTS_DURATION_SECONDS,
-                LABEL_SERVICE => service,
-                LABEL_OPERATION => Operation::Read.into_static(),
+            requests_duration_seconds_read: register_size_bucket_histograms(
+                service,
+                Operation::Read,
+                &size_buckets,
             ),
             bytes_total_read: register_counter!(
                 METRIC_BYTES_TOTAL,
@@ -235,10 +363,10 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::Write.into_static(),
             ),
-            requests_duration_seconds_write: register_histogram!(
-                METRIC_REQUESTS_DURATION_SECONDS,
-                LABEL_SERVICE => service,
-                LABEL_OPERATION => Operation::Write.into_static(),
+            requests_duration_seconds_write: register_size_bucket_histograms(
+                service,
+                Operation::Write,
+                &size_buckets,
             ),
             bytes_total_write: register_counter!(
                 METRIC_BYTES_TOTAL,
@@ -317,10 +445,10 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::BlockingRead.into_static(),
             ),
-            requests_duration_seconds_blocking_read: register_histogram!(
-                METRIC_REQUESTS_DURATION_SECONDS,
-                LABEL_SERVICE => service,
-                LABEL_OPERATION => Operation::BlockingRead.into_static(),
+            requests_duration_seconds_blocking_read: register_size_bucket_histograms(
+                service,
+                Operation::BlockingRead,
+                &size_buckets,
             ),
             bytes_total_blocking_read: register_counter!(
                 METRIC_BYTES_TOTAL,
@@ -333,10 +461,10 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::BlockingWrite.into_static(),
             ),
-            requests_duration_seconds_blocking_write: register_histogram!(
-                METRIC_REQUESTS_DURATION_SECONDS,
-                LABEL_SERVICE => service,
-                LABEL_OPERATION => Operation::BlockingWrite.into_static(),
+            requests_duration_seconds_blocking_write: register_size_bucket_histograms(
+                service,
+                Operation::BlockingWrite,
+                &size_buckets,
             ),
             bytes_total_blocking_write: register_counter!(
                 METRIC_BYTES_TOTAL,
@@ -376,6 +504,19 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::BlockingList.into_static(),
             ),
+
+            requests_total_blocking_presign: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingPresign.into_static(),
+            ),
+            requests_duration_seconds_blocking_presign: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingPresign.into_static(),
+            ),
+
+            size_buckets,
         }
     }
 
@@ -389,6 +530,19 @@ impl MetricsHandler {
             LABEL_ERROR => kind.into_static(),
         )
     }
+
+    /// Only requests carrying a [`Tenant`] will be counted, and tenants
+    /// are unknown in advance, so we will not init tenant counters.
+    #[inline]
+    fn increment_tenant_requests_total(&self, op: Operation, extensions: &Extensions) {
+        if let Some(tenant) = extensions.get::<Tenant>() {
+            increment_counter!(METRIC_TENANT_REQUESTS_TOTAL,
+                LABEL_SERVICE => self.service,
+                LABEL_OPERATION => op.into_static(),
+                LABEL_TENANT => tenant.as_str().to_string(),
+            )
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -455,6 +609,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         self.handle.requests_total_read.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::Read, args.extensions());
 
         let start = Instant::now();
 
@@ -462,6 +618,7 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
             .read(path, args)
             .map(|v| {
                 v.map(|(rp, r)| {
+                    let size = rp.metadata().content_length_raw();
                     (
                         rp,
                         MetricWrapper::new(
@@ -471,6 +628,7 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
                             self.handle.bytes_total_read.clone(),
                             self.handle.requests_duration_seconds_read.clone(),
                             Some(start),
+                            size,
                         ),
                     )
                 })
@@ -485,8 +643,11 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         self.handle.requests_total_write.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::Write, args.extensions());
 
         let start = Instant::now();
+        let size = args.content_length();
 
         self.inner
             .write(path, args)
@@ -500,6 +661,7 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
                         self.handle.bytes_total_write.clone(),
                         self.handle.requests_duration_seconds_write.clone(),
                         Some(start),
+                        size,
                     ),
                 )
             })
@@ -514,8 +676,39 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
         self.inner.append(path, args).await
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.handle.requests_total_write.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::Write, args.extensions());
+
+        let start = Instant::now();
+
+        self.inner
+            .multipart(path, args)
+            .map_ok(|(rp, u)| {
+                let u: oio::MultipartUploader = Box::new(MetricsUploader {
+                    inner: u,
+                    handle: self.handle.clone(),
+                    start,
+                    bytes: AtomicU64::new(0),
+                });
+                (rp, u)
+            })
+            .inspect_err(|e| {
+                self.handle
+                    .increment_errors_total(Operation::Write, e.kind());
+            })
+            .await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.handle.requests_total_stat.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::Stat, args.extensions());
 
         let start = Instant::now();
 
@@ -535,6 +728,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.handle.requests_total_delete.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::Delete, args.extensions());
 
         let start = Instant::now();
 
@@ -554,6 +749,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         self.handle.requests_total_list.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::List, args.extensions());
 
         let start = Instant::now();
 
@@ -623,9 +820,12 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         self.handle.requests_total_blocking_read.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::BlockingRead, args.extensions());
 
         let start = Instant::now();
         let result = self.inner.blocking_read(path, args).map(|(rp, r)| {
+            let size = rp.metadata().content_length_raw();
             (
                 rp,
                 MetricWrapper::new(
@@ -635,6 +835,7 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
                     self.handle.bytes_total_blocking_read.clone(),
                     self.handle.requests_duration_seconds_blocking_read.clone(),
                     Some(start),
+                    size,
                 ),
             )
         });
@@ -648,16 +849,14 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         self.handle.requests_total_blocking_write.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::BlockingWrite, args.extensions());
 
         let start = Instant::now();
-        let result = self.inner.blocking_write(path, args);
-        let dur = start.elapsed().as_secs_f64();
+        let size = args.content_length();
 
-        self.handle
-            .requests_duration_seconds_blocking_write
-            .record(dur);
-
-        result
+        self.inner
+            .blocking_write(path, args)
             .map(|(rp, w)| {
                 (
                     rp,
@@ -665,9 +864,10 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
                         w,
                         Operation::BlockingWrite,
                         self.handle.clone(),
-                        self.handle.bytes_total_write.clone(),
-                        self.handle.requests_duration_seconds_write.clone(),
+                        self.handle.bytes_total_blocking_write.clone(),
+                        self.handle.requests_duration_seconds_blocking_write.clone(),
                         Some(start),
+                        size,
                     ),
                 )
             })
@@ -680,6 +880,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         self.handle.requests_total_blocking_stat.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::BlockingStat, args.extensions());
 
         let start = Instant::now();
         let result = self.inner.blocking_stat(path, args);
@@ -698,6 +900,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         self.handle.requests_total_blocking_delete.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::BlockingDelete, args.extensions());
 
         let start = Instant::now();
         let result = self.inner.blocking_delete(path, args);
@@ -716,6 +920,8 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         self.handle.requests_total_blocking_list.increment(1);
+        self.handle
+            .increment_tenant_requests_total(Operation::BlockingList, args.extensions());
 
         let start = Instant::now();
         let result = self.inner.blocking_list(path, args);
@@ -731,6 +937,24 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
             e
         })
     }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.handle.requests_total_blocking_presign.increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_presign(path, args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_presign
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingPresign, e.kind());
+            e
+        })
+    }
 }
 
 pub struct MetricWrapper<R> {
@@ -738,10 +962,13 @@ pub struct MetricWrapper<R> {
 
     op: Operation,
     bytes_counter: Counter,
-    requests_duration_seconds: Histogram,
+    /// Histograms of request duration for every size bucket.
+    requests_duration_seconds: Vec<Histogram>,
     handle: Arc<MetricsHandler>,
 
     start: Option<Instant>,
+    /// The content length of this object if known.
+    size: Option<u64>,
     bytes: u64,
 }
 
@@ -751,8 +978,9 @@ impl<R> MetricWrapper<R> {
         op: Operation,
         handle: Arc<MetricsHandler>,
         bytes_counter: Counter,
-        requests_duration_seconds: Histogram,
+        requests_duration_seconds: Vec<Histogram>,
         start: Option<Instant>,
+        size: Option<u64>,
     ) -> Self {
         Self {
             inner,
@@ -761,6 +989,7 @@ impl<R> MetricWrapper<R> {
             bytes_counter,
             requests_duration_seconds,
             start,
+            size,
             bytes: 0,
         }
     }
@@ -771,7 +1000,11 @@ impl<R> Drop for MetricWrapper<R> {
         self.bytes_counter.increment(self.bytes);
         if let Some(instant) = self.start {
             let dur = instant.elapsed().as_secs_f64();
-            self.requests_duration_seconds.record(dur);
+            let idx = self
+                .handle
+                .size_buckets
+                .index(self.size.unwrap_or(self.bytes));
+            self.requests_duration_seconds[idx].record(dur);
         }
     }
 }
@@ -875,6 +1108,25 @@ impl<R: oio::Write> oio::Write for MetricWrapper<R> {
             })
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        let size = Arc::new(AtomicU64::new(0));
+        let counter = size.clone();
+        let s = s.inspect(move |res| {
+            if let Ok(bs) = res {
+                counter.fetch_add(bs.len() as u64, Ordering::Relaxed);
+            }
+        });
+
+        self.inner
+            .sink_unsized(Box::new(oio::into_stream(s)))
+            .await
+            .map(|_| self.bytes += size.load(Ordering::Relaxed))
+            .map_err(|err| {
+                self.handle.increment_errors_total(self.op, err.kind());
+                err
+            })
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await.map_err(|err| {
             self.handle.increment_errors_total(self.op, err.kind());
@@ -909,3 +1161,237 @@ impl<R: oio::BlockingWrite> oio::BlockingWrite for MetricWrapper<R> {
         })
     }
 }
+
+/// MetricsUploader records the bytes of uploaded parts, and the duration
+/// of the whole upload once it's completed.
+pub struct MetricsUploader {
+    inner: oio::MultipartUploader,
+    handle: Arc<MetricsHandler>,
+
+    start: Instant,
+    bytes: AtomicU64,
+}
+
+impl MetricsUploader {
+    fn record<T>(&self, res: Result<T>) -> Result<T> {
+        res.map_err(|err| {
+            self.handle
+                .increment_errors_total(Operation::Write, err.kind());
+            err
+        })
+    }
+
+    fn add_bytes(&self, size: u64) {
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        self.handle.bytes_total_write.increment(size);
+    }
+
+    fn finish(&self) {
+        let dur = self.start.elapsed().as_secs_f64();
+        let idx = self
+            .handle
+            .size_buckets
+            .index(self.bytes.load(Ordering::Relaxed));
+        self.handle.requests_duration_seconds_write[idx].record(dur);
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for MetricsUploader {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        self.record(self.inner.write_once(size, body).await)?;
+        self.add_bytes(size);
+        self.finish();
+        Ok(())
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        self.record(self.inner.initiate_part().await)
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let part = self.record(
+            self.inner
+                .write_part(upload_id, part_number, size, body)
+                .await,
+        )?;
+        self.add_bytes(size);
+        Ok(part)
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        self.record(self.inner.complete_part(upload_id, parts).await)?;
+        self.finish();
+        Ok(())
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.record(self.inner.abort_part(upload_id).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::Once;
+
+    use metrics::CounterFn;
+    use metrics::HistogramFn;
+    use metrics::Key;
+    use metrics::KeyName;
+    use metrics::Recorder;
+    use metrics::SharedString;
+    use metrics::Unit;
+    use once_cell::sync::Lazy;
+
+    use super::*;
+    use crate::services;
+
+    /// All recorded histogram keys.
+    static RECORDS: Lazy<Arc<Mutex<Vec<Key>>>> = Lazy::new(Arc::default);
+    /// All incremented counter keys.
+    static COUNTERS: Lazy<Arc<Mutex<Vec<Key>>>> = Lazy::new(Arc::default);
+
+    struct MockCounter(Key);
+
+    impl CounterFn for MockCounter {
+        fn increment(&self, _: u64) {
+            COUNTERS.lock().unwrap().push(self.0.clone());
+        }
+
+        fn absolute(&self, _: u64) {}
+    }
+
+    struct MockHistogram(Key);
+
+    impl HistogramFn for MockHistogram {
+        fn record(&self, _: f64) {
+            RECORDS.lock().unwrap().push(self.0.clone());
+        }
+    }
+
+    struct MockRecorder;
+
+    impl Recorder for MockRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key) -> Counter {
+            Counter::from_arc(Arc::new(MockCounter(key.clone())))
+        }
+
+        fn register_gauge(&self, _: &Key) -> metrics::Gauge {
+            metrics::Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key) -> Histogram {
+            Histogram::from_arc(Arc::new(MockHistogram(key.clone())))
+        }
+    }
+
+    /// Recorder can only be set once, so all tests share the same one.
+    fn install_recorder() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            metrics::set_boxed_recorder(Box::new(MockRecorder)).expect("must set recorder")
+        });
+    }
+
+    fn size_bucket_of(key: &Key) -> Option<String> {
+        key.labels()
+            .find(|l| l.key() == LABEL_SIZE_BUCKET)
+            .map(|l| l.value().to_string())
+    }
+
+    #[test]
+    fn test_size_buckets() {
+        let buckets = SizeBuckets::default();
+        assert_eq!(buckets.labels, vec!["<1K", "<1M", "<100M", ">=100M"]);
+        assert_eq!(buckets.labels[buckets.index(0)], "<1K");
+        assert_eq!(buckets.labels[buckets.index(1024)], "<1M");
+        assert_eq!(buckets.labels[buckets.index(100 * 1024 * 1024)], ">=100M");
+
+        let buckets = SizeBuckets::new(vec![1000, 64 * 1024]);
+        assert_eq!(buckets.labels, vec!["<1000", "<64K", ">=64K"]);
+    }
+
+    #[tokio::test]
+    async fn test_size_bucket_label() -> Result<()> {
+        install_recorder();
+
+        let op = Operator::new(services::Memory::default())?
+            .layer(MetricsLayer::default())
+            .finish();
+        op.write("small", vec![0; 10]).await?;
+        op.write("large", vec![0; 2 * 1024 * 1024]).await?;
+        op.read("large").await?;
+
+        let records = RECORDS.lock().unwrap();
+        let buckets: Vec<_> = records
+            .iter()
+            .filter(|k| k.name() == METRIC_REQUESTS_DURATION_SECONDS)
+            .map(|k| {
+                let op = k
+                    .labels()
+                    .find(|l| l.key() == LABEL_OPERATION)
+                    .map(|l| l.value().to_string())
+                    .unwrap();
+                (op, size_bucket_of(k))
+            })
+            .collect();
+
+        assert!(buckets.contains(&("write".to_string(), Some("<1K".to_string()))));
+        assert!(buckets.contains(&("write".to_string(), Some("<100M".to_string()))));
+        assert!(buckets.contains(&("read".to_string(), Some("<100M".to_string()))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tenant_label() -> Result<()> {
+        install_recorder();
+
+        let op = Operator::new(services::Memory::default())?
+            .layer(MetricsLayer::default())
+            .finish();
+        op.write_with("tenant", "hello")
+            .extension(Tenant::new("alice"))
+            .await?;
+        op.stat_with("tenant").extension(Tenant::new("bob")).await?;
+        op.stat("tenant").await?;
+
+        let counters = COUNTERS.lock().unwrap();
+        let tenants: Vec<_> = counters
+            .iter()
+            .filter(|k| k.name() == METRIC_TENANT_REQUESTS_TOTAL)
+            .map(|k| {
+                let label = |name: &str| {
+                    k.labels()
+                        .find(|l| l.key() == name)
+                        .map(|l| l.value().to_string())
+                        .unwrap()
+                };
+                (label(LABEL_OPERATION), label(LABEL_TENANT))
+            })
+            .collect();
+
+        assert_eq!(
+            tenants,
+            vec![
+                ("write".to_string(), "alice".to_string()),
+                ("stat".to_string(), "bob".to_string())
+            ]
+        );
+        Ok(())
+    }
+}
@@ -299,6 +299,11 @@ impl<A: Accessor> LayeredAccessor for MinitraceAccessor<A> {
             )
         })
     }
+
+    #[trace]
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(path, args)
+    }
 }
 
 pub struct MinitraceWrapper<R> {
@@ -374,6 +379,16 @@ impl<R: oio::Write> oio::Write for MinitraceWrapper<R> {
             .await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner
+            .sink_unsized(s)
+            .in_span(Span::enter_with_parent(
+                WriteOperation::SinkUnsized.into_static(),
+                &self.span,
+            ))
+            .await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner
             .abort()
@@ -443,6 +458,10 @@ impl<R: oio::Page> oio::Page for MinitraceWrapper<R> {
             ))
             .await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingPage> oio::BlockingPage for MinitraceWrapper<R> {
@@ -20,12 +20,38 @@
 mod concurrent_limit;
 pub use concurrent_limit::ConcurrentLimitLayer;
 
+mod corruption_detection;
+pub use corruption_detection::CorruptionDetectionLayer;
+
+mod delay;
+pub use delay::DelayLayer;
+
+mod dry_run;
+pub use dry_run::DryRunAction;
+pub use dry_run::DryRunLayer;
+
+mod fallback;
+pub use fallback::FallbackLayer;
+
 mod immutable_index;
 pub use immutable_index::ImmutableIndexLayer;
 
 mod logging;
 pub use logging::LoggingLayer;
 
+mod path_normalization;
+pub use path_normalization::PathNormalizationLayer;
+
+mod stat_cache;
+pub use stat_cache::StatCacheLayer;
+
+mod tenant;
+pub use tenant::Tenant;
+
+mod tee;
+pub use tee::TeeFailurePolicy;
+pub use tee::TeeLayer;
+
 mod timeout;
 pub use timeout::TimeoutLayer;
 
@@ -49,6 +75,7 @@ pub use self::prometheus::PrometheusLayer;
 
 mod retry;
 pub use self::retry::RetryInterceptor;
+pub use self::retry::RetryJitter;
 pub use self::retry::RetryLayer;
 
 #[cfg(feature = "layers-tracing")]
@@ -267,6 +267,16 @@ impl<A: Accessor> LayeredAccessor for OtelTraceAccessor<A> {
             .blocking_list(path, args)
             .map(|(rp, it)| (rp, OtelTraceWrapper::new(span, it)))
     }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let tracer = global::tracer("opendal");
+        tracer.in_span("blocking_presign", |cx| {
+            let span = cx.span();
+            span.set_attribute(KeyValue::new("path", path.to_string()));
+            span.set_attribute(KeyValue::new("args", format!("{:?}", args)));
+            self.inner().blocking_presign(path, args)
+        })
+    }
 }
 
 pub struct OtelTraceWrapper<R> {
@@ -326,6 +336,10 @@ impl<R: oio::Write> oio::Write for OtelTraceWrapper<R> {
         self.inner.sink(size, s).await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
@@ -350,6 +364,10 @@ impl<R: oio::Page> oio::Page for OtelTraceWrapper<R> {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
         self.inner.next().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingPage> oio::BlockingPage for OtelTraceWrapper<R> {
@@ -0,0 +1,393 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+
+use crate::raw::*;
+use crate::*;
+
+/// NORMALIZE_ENCODE_SET keeps the unreserved characters of
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3)
+/// (`A-Z a-z 0-9 - . _ ~`) and the path separator `/` as is.
+static NORMALIZE_ENCODE_SET: AsciiSet = NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Encode the path so that it can be stored by all services.
+fn encode_path(path: &str) -> String {
+    utf8_percent_encode(path, &NORMALIZE_ENCODE_SET).to_string()
+}
+
+/// Decode the path returned by services.
+///
+/// Paths that are not valid utf-8 after decoding can't be written via this
+/// layer, so we return them as is.
+fn decode_path(path: &str) -> String {
+    match percent_decode_str(path).decode_utf8() {
+        Ok(p) => p.into_owned(),
+        Err(_) => path.to_string(),
+    }
+}
+
+/// Normalize the characters of paths so that the same logical key works
+/// across services.
+///
+/// # Notes
+///
+/// Services treat characters like `:`, `#`, `?`, spaces and unicode
+/// differently: `fs` on Windows rejects `:` and `?`, some http based
+/// services mangle `#` and spaces, and unicode keys may be normalized into
+/// different forms. PathNormalizationLayer encodes paths on the way in and
+/// decodes them on the way out, so users always see the logical key.
+///
+/// The encoding scheme is percent-encoding of utf-8 bytes:
+///
+/// - `A-Z a-z 0-9 - . _ ~` and the path separator `/` are kept as is.
+/// - All other bytes, including `%` itself, are encoded as `%XX` with
+///   uppercase hex digits. For example, `a:b c.txt` will be stored as
+///   `a%3Ab%20c.txt` and `100%` as `100%25`.
+///
+/// The scheme is reversible, and decoding is applied to the paths of
+/// entries returned by `list` and the paths in `batch` results. Paths
+/// written without this layer which can't be decoded into valid utf-8
+/// will be returned as is.
+///
+/// Please make sure all clients accessing the same data use this layer,
+/// otherwise they will see the encoded keys.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::PathNormalizationLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(PathNormalizationLayer)
+///     .finish();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PathNormalizationLayer;
+
+impl<A: Accessor> Layer<A> for PathNormalizationLayer {
+    type LayeredAccessor = PathNormalizationAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        PathNormalizationAccessor { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PathNormalizationAccessor<A: Accessor> {
+    inner: A,
+}
+
+impl<A: Accessor> PathNormalizationAccessor<A> {
+    fn encode_list_args(args: OpList) -> OpList {
+        match args.start_after().map(encode_path) {
+            Some(start_after) => args.with_start_after(&start_after),
+            None => args,
+        }
+    }
+
+    fn encode_batch_args(args: OpBatch) -> OpBatch {
+        OpBatch::new(
+            args.into_operation()
+                .into_iter()
+                .map(|(path, op)| (encode_path(&path), op))
+                .collect(),
+        )
+    }
+
+    fn decode_batch_reply(rp: RpBatch) -> RpBatch {
+        RpBatch::new(
+            rp.into_results()
+                .into_iter()
+                .map(|(path, res)| (decode_path(&path), res))
+                .collect(),
+        )
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for PathNormalizationAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = PathNormalizationWrapper<A::Pager>;
+    type BlockingPager = PathNormalizationWrapper<A::BlockingPager>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.inner.create_dir(&encode_path(path), args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(&encode_path(path), args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(&encode_path(path), args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(&encode_path(path), args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.inner
+            .copy(&encode_path(from), &encode_path(to), args)
+            .await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.inner
+            .rename(&encode_path(from), &encode_path(to), args)
+            .await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(&encode_path(path), args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.inner.delete(&encode_path(path), args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner
+            .list(&encode_path(path), Self::encode_list_args(args))
+            .await
+            .map(|(rp, p)| (rp, PathNormalizationWrapper::new(p)))
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.inner
+            .batch(Self::encode_batch_args(args))
+            .await
+            .map(Self::decode_batch_reply)
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.presign(&encode_path(path), args).await
+    }
+
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.inner.multipart(&encode_path(path), args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.inner.blocking_create_dir(&encode_path(path), args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(&encode_path(path), args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(&encode_path(path), args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.inner
+            .blocking_copy(&encode_path(from), &encode_path(to), args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.inner
+            .blocking_rename(&encode_path(from), &encode_path(to), args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.blocking_stat(&encode_path(path), args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.inner.blocking_delete(&encode_path(path), args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner
+            .blocking_list(&encode_path(path), Self::encode_list_args(args))
+            .map(|(rp, p)| (rp, PathNormalizationWrapper::new(p)))
+    }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(&encode_path(path), args)
+    }
+}
+
+/// PathNormalizationWrapper decodes the paths of listed entries.
+pub struct PathNormalizationWrapper<P> {
+    inner: P,
+}
+
+impl<P> PathNormalizationWrapper<P> {
+    fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    fn decode_entries(entries: Option<Vec<oio::Entry>>) -> Option<Vec<oio::Entry>> {
+        entries.map(|mut entries| {
+            for entry in entries.iter_mut() {
+                let path = decode_path(entry.path());
+                entry.set_path(&path);
+            }
+            entries
+        })
+    }
+}
+
+#[async_trait]
+impl<P: oio::Page> oio::Page for PathNormalizationWrapper<P> {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        self.inner.next().await.map(Self::decode_entries)
+    }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
+}
+
+impl<P: oio::BlockingPage> oio::BlockingPage for PathNormalizationWrapper<P> {
+    fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        self.inner.next().map(Self::decode_entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services;
+
+    /// Keys that are treated differently by services.
+    const TRICKY_KEYS: &[&str] = &[
+        "a:b",
+        "hash#tag",
+        "what?.txt",
+        "with space",
+        "100%",
+        "%41",
+        "star*<pipe|>\"quote\"",
+        "back\\slash",
+        "你好，世界",
+        "caf\u{e9}",
+        "cafe\u{301}",
+    ];
+
+    #[test]
+    fn test_encode_decode_path() {
+        let cases = vec![
+            ("a:b c.txt", "a%3Ab%20c.txt"),
+            ("dir/100%", "dir/100%25"),
+            ("-._~/", "-._~/"),
+            ("你", "%E4%BD%A0"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(encode_path(input), expected, "{input}");
+            assert_eq!(decode_path(expected), input, "{input}");
+        }
+
+        // Invalid utf-8 after decoding will be kept as is.
+        assert_eq!(decode_path("%FF"), "%FF");
+    }
+
+    async fn check_round_trip(op: Operator) -> Result<()> {
+        for key in TRICKY_KEYS {
+            let path = format!("tricky/{key}");
+            op.write(&path, key.as_bytes().to_vec()).await?;
+
+            assert_eq!(op.read(&path).await?, key.as_bytes(), "{key}");
+            assert!(op.stat(&path).await?.mode().is_file(), "{key}");
+        }
+
+        let mut listed: Vec<String> = op
+            .list("tricky/")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        listed.sort();
+        let mut expected: Vec<String> = TRICKY_KEYS
+            .iter()
+            .map(|key| format!("tricky/{key}"))
+            .collect();
+        expected.sort();
+        assert_eq!(listed, expected);
+
+        for key in TRICKY_KEYS {
+            let path = format!("tricky/{key}");
+            op.delete(&path).await?;
+            assert!(!op.is_exist(&path).await?, "{key}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_memory() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?
+            .layer(PathNormalizationLayer)
+            .finish();
+
+        check_round_trip(op).await
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_fs() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("opendal-pn-{}", uuid::Uuid::new_v4()));
+        let mut builder = services::Fs::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::new(builder)?
+            .layer(PathNormalizationLayer)
+            .finish();
+
+        let result = check_round_trip(op).await;
+        let _ = std::fs::remove_dir_all(&root);
+        result
+    }
+
+    #[tokio::test]
+    async fn test_stored_keys_are_encoded() -> Result<()> {
+        let raw = Operator::new(services::Memory::default())?.finish();
+        let op = raw.clone().layer(PathNormalizationLayer);
+
+        op.write("a:b c", "hello").await?;
+        assert_eq!(raw.read("a%3Ab%20c").await?, b"hello");
+        assert!(!raw.is_exist("a:b c").await?);
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::io;
+use std::sync::atomic;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
@@ -25,6 +26,7 @@ use std::task::Poll;
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures::FutureExt;
+use futures::StreamExt;
 use futures::TryFutureExt;
 use log::debug;
 use prometheus::core::AtomicU64;
@@ -558,6 +560,27 @@ impl<A: Accessor> LayeredAccessor for PrometheusAccessor<A> {
             e
         })
     }
+
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.stats
+            .requests_total
+            .with_label_values(&[&self.scheme, Operation::BlockingPresign.into_static()])
+            .inc();
+
+        let timer = self
+            .stats
+            .requests_duration_seconds
+            .with_label_values(&[&self.scheme, Operation::BlockingPresign.into_static()])
+            .start_timer();
+        let result = self.inner.blocking_presign(path, args);
+        timer.observe_duration();
+
+        result.map_err(|e| {
+            self.stats
+                .increment_errors_total(Operation::BlockingPresign, e.kind());
+            e
+        })
+    }
 }
 
 pub struct PrometheusMetricWrapper<R> {
@@ -700,6 +723,30 @@ impl<R: oio::Write> oio::Write for PrometheusMetricWrapper<R> {
             })
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        let size = Arc::new(atomic::AtomicU64::new(0));
+        let counter = size.clone();
+        let s = s.inspect(move |res| {
+            if let Ok(bs) = res {
+                counter.fetch_add(bs.len() as u64, atomic::Ordering::Relaxed);
+            }
+        });
+
+        self.inner
+            .sink_unsized(Box::new(oio::into_stream(s)))
+            .await
+            .map(|_| {
+                self.stats
+                    .bytes_total
+                    .with_label_values(&[&self.scheme, Operation::Write.into_static()])
+                    .observe(size.load(atomic::Ordering::Relaxed) as f64)
+            })
+            .map_err(|err| {
+                self.stats.increment_errors_total(self.op, err.kind());
+                err
+            })
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await.map_err(|err| {
             self.stats.increment_errors_total(self.op, err.kind());
@@ -17,25 +17,27 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::future::Future;
 use std::io;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use backon::BackoffBuilder;
 use backon::BlockingRetryable;
-use backon::ExponentialBackoff;
-use backon::ExponentialBuilder;
 use backon::Retryable;
 use bytes::Bytes;
 use futures::FutureExt;
 use log::warn;
 
 use crate::raw::oio::AppendOperation;
+use crate::raw::oio::MultipartUploadOperation;
 use crate::raw::oio::PageOperation;
 use crate::raw::oio::ReadOperation;
 use crate::raw::oio::WriteOperation;
@@ -67,6 +69,84 @@ use crate::*;
 ///     .finish();
 /// ```
 ///
+/// ## Jittered backoff
+///
+/// By default, RetryLayer will retry with a deterministic exponential backoff.
+/// Many clients retrying against the same service at the same time could
+/// cause a thundering herd, users can select a [`RetryJitter`] strategy to
+/// spread the retries.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::layers::RetryJitter;
+/// use opendal::layers::RetryLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         RetryLayer::new()
+///             .with_jitter_strategy(RetryJitter::Decorrelated)
+///             .with_min_delay(Duration::from_millis(100))
+///             .with_max_delay(Duration::from_secs(10)),
+///     )
+///     .finish();
+/// ```
+///
+/// ## Retry budget and circuit breaker
+///
+/// Under a sustained outage, retrying every failed request will amplify the
+/// load on an already-failing service. RetryLayer can be configured with:
+///
+/// - a retry budget via [`RetryLayer::with_retry_budget`], which only allows
+///   a ratio of all requests sent to be retries.
+/// - a circuit breaker via [`RetryLayer::with_circuit_breaker`], which rejects
+///   new requests with a fast error for a cooldown window after too many
+///   consecutive failures.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::layers::RetryLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         RetryLayer::new()
+///             .with_retry_budget(0.1, 10)
+///             .with_circuit_breaker(5, Duration::from_secs(30)),
+///     )
+///     .finish();
+/// ```
+///
+/// ## Per-operation max retries
+///
+/// Latency-sensitive calls like probes may want to fail fast even though
+/// the operator has a RetryLayer. `read` and `stat` accept `max_retries` to
+/// override the max times for a single call, `0` means no retry at all.
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::RetryLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # async fn test() -> Result<()> {
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(RetryLayer::new())
+///     .finish();
+///
+/// let _ = op.stat_with("probe").max_retries(0).await;
+/// # Ok(())
+/// # }
+/// ```
+///
 /// ## Customize retry interceptor
 ///
 /// RetryLayer accepts [`RetryInterceptor`] to allow users to customize
@@ -97,8 +177,9 @@ use crate::*;
 ///     .finish();
 /// ```
 pub struct RetryLayer<I = DefaultRetryInterceptor> {
-    builder: ExponentialBuilder,
+    builder: RetryBackoffBuilder,
     notify: Arc<I>,
+    guard: Arc<RetryGuard>,
 }
 
 impl<I> Clone for RetryLayer<I> {
@@ -106,6 +187,7 @@ impl<I> Clone for RetryLayer<I> {
         Self {
             builder: self.builder.clone(),
             notify: self.notify.clone(),
+            guard: self.guard.clone(),
         }
     }
 }
@@ -113,8 +195,9 @@ impl<I> Clone for RetryLayer<I> {
 impl Default for RetryLayer {
     fn default() -> Self {
         Self {
-            builder: ExponentialBuilder::default(),
+            builder: RetryBackoffBuilder::default(),
             notify: Arc::new(DefaultRetryInterceptor),
+            guard: Arc::default(),
         }
     }
 }
@@ -168,15 +251,36 @@ impl RetryLayer {
         RetryLayer {
             builder: self.builder,
             notify: Arc::new(notify),
+            guard: self.guard,
         }
     }
+}
 
+impl<I> RetryLayer<I> {
     /// Set jitter of current backoff.
     ///
-    /// If jitter is enabled, ExponentialBackoff will add a random jitter in `[0, min_delay)
+    /// If jitter is enabled, backoff will add a random jitter in `[0, min_delay)`
     /// to current delay.
-    pub fn with_jitter(mut self) -> Self {
-        self.builder = self.builder.with_jitter();
+    ///
+    /// This is the same as `with_jitter_strategy(RetryJitter::Additive)`.
+    pub fn with_jitter(self) -> Self {
+        self.with_jitter_strategy(RetryJitter::Additive)
+    }
+
+    /// Set jitter strategy of current backoff.
+    ///
+    /// The default strategy is [`RetryJitter::None`].
+    pub fn with_jitter_strategy(mut self, jitter: RetryJitter) -> Self {
+        self.builder.jitter = jitter;
+        self
+    }
+
+    /// Set seed of the jitter random generator.
+    ///
+    /// Every backoff built with the same seed will yield the same delays,
+    /// which is useful for deterministic tests.
+    pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+        self.builder.seed = Some(seed);
         self
     }
 
@@ -186,21 +290,23 @@ impl RetryLayer {
     ///
     /// This function will panic if input factor smaller than `1.0`.
     pub fn with_factor(mut self, factor: f32) -> Self {
-        self.builder = self.builder.with_factor(factor);
+        assert!(factor >= 1.0, "invalid factor that lower than 1");
+
+        self.builder.factor = factor;
         self
     }
 
     /// Set min_delay of current backoff.
     pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
-        self.builder = self.builder.with_min_delay(min_delay);
+        self.builder.min_delay = min_delay;
         self
     }
 
     /// Set max_delay of current backoff.
     ///
-    /// Delay will not increasing if current delay is larger than max_delay.
+    /// Delay will not increase once it reaches max_delay.
     pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
-        self.builder = self.builder.with_max_delay(max_delay);
+        self.builder.max_delay = max_delay;
         self
     }
 
@@ -208,19 +314,98 @@ impl RetryLayer {
     ///
     /// Backoff will return `None` if max times is reaching.
     pub fn with_max_times(mut self, max_times: usize) -> Self {
-        self.builder = self.builder.with_max_times(max_times);
+        self.builder.max_times = max_times;
         self
     }
+
+    /// Set retry budget of current layer.
+    ///
+    /// Every request sent to the underlying service deposits `ratio` tokens
+    /// into the budget (capped at `burst`), and every retry withdraws one
+    /// token. Retries will be stopped once the budget is exhausted, so at
+    /// most `ratio` of all requests can be retries in the long run.
+    ///
+    /// The budget starts full, which allows `burst` retries before any
+    /// requests have been made.
+    ///
+    /// # Notes
+    ///
+    /// The budget is shared by all operators built from this layer.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if input ratio is not in `[0.0, 1.0]`.
+    pub fn with_retry_budget(self, ratio: f32, burst: usize) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "retry budget ratio must be in [0.0, 1.0]"
+        );
+
+        let guard = RetryGuard {
+            budget: Some(Mutex::new(RetryBudget {
+                ratio,
+                burst: burst as f32,
+                tokens: burst as f32,
+            })),
+            breaker: self.guard.clone_breaker(),
+        };
+        RetryLayer {
+            builder: self.builder,
+            notify: self.notify,
+            guard: Arc::new(guard),
+        }
+    }
+
+    /// Set circuit breaker of current layer.
+    ///
+    /// After `threshold` consecutive temporary failures, the circuit will
+    /// be opened and all new requests will fail fast without reaching the
+    /// underlying service. Once `cooldown` has elapsed, one request will be
+    /// allowed to probe the service: the circuit will be closed if it
+    /// succeeds, or opened for another `cooldown` if it fails.
+    ///
+    /// # Notes
+    ///
+    /// The circuit breaker is shared by all operators built from this layer.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if input threshold is `0`.
+    pub fn with_circuit_breaker(self, threshold: usize, cooldown: Duration) -> Self {
+        assert!(
+            threshold > 0,
+            "circuit breaker threshold must be larger than 0"
+        );
+
+        let guard = RetryGuard {
+            budget: self.guard.clone_budget(),
+            breaker: Some(Mutex::new(CircuitBreaker {
+                threshold,
+                cooldown,
+                failures: 0,
+                state: CircuitState::Closed,
+            })),
+        };
+        RetryLayer {
+            builder: self.builder,
+            notify: self.notify,
+            guard: Arc::new(guard),
+        }
+    }
 }
 
 impl<A: Accessor, I: RetryInterceptor> Layer<A> for RetryLayer<I> {
     type LayeredAccessor = RetryAccessor<A, I>;
 
     fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        let mut builder = self.builder.clone();
+        builder.guard = Some(self.guard.clone());
+
         RetryAccessor {
             inner,
-            builder: self.builder.clone(),
+            builder,
             notify: self.notify.clone(),
+            guard: self.guard.clone(),
         }
     }
 }
@@ -264,10 +449,290 @@ impl RetryInterceptor for DefaultRetryInterceptor {
     }
 }
 
+/// RetryJitter is the jitter strategy used by [`RetryLayer`] between retries.
+///
+/// Let `base` be `min(min_delay * factor ^ attempts, max_delay)`:
+///
+/// - [`RetryJitter::None`]: sleep `base`. This is the default strategy.
+/// - [`RetryJitter::Additive`]: sleep `base + random(0, min_delay)`.
+/// - [`RetryJitter::Full`]: sleep `random(0, base)`.
+/// - [`RetryJitter::Decorrelated`]: sleep `min(random(min_delay, previous * factor), max_delay)`,
+///   where `previous` is the previous delay (starting from `min_delay`).
+///
+/// Visit [Exponential Backoff And Jitter](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+/// for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RetryJitter {
+    /// No jitter, the delay grows exponentially.
+    #[default]
+    None,
+    /// Add a random jitter in `[0, min_delay)` to the exponential delay.
+    Additive,
+    /// Pick a random delay in `[0, base)`.
+    Full,
+    /// Pick a random delay in `[min_delay, previous * factor)`.
+    Decorrelated,
+}
+
+/// RetryBackoffBuilder builds [`RetryBackoff`] for every retried operation.
+#[derive(Debug, Clone)]
+struct RetryBackoffBuilder {
+    jitter: RetryJitter,
+    seed: Option<u64>,
+    factor: f32,
+    min_delay: Duration,
+    max_delay: Duration,
+    max_times: usize,
+    /// Every retry withdraws from the budget of guard if set.
+    guard: Option<Arc<RetryGuard>>,
+}
+
+impl Default for RetryBackoffBuilder {
+    fn default() -> Self {
+        Self {
+            jitter: RetryJitter::None,
+            seed: None,
+            factor: 2.0,
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_times: 3,
+            guard: None,
+        }
+    }
+}
+
+impl RetryBackoffBuilder {
+    /// Override the max times with the max retries of operation if set.
+    fn with_max_retries(&self, max_retries: Option<usize>) -> Self {
+        let mut builder = self.clone();
+        if let Some(v) = max_retries {
+            builder.max_times = v;
+        }
+        builder
+    }
+}
+
+impl BackoffBuilder for RetryBackoffBuilder {
+    type Backoff = RetryBackoff;
+
+    fn build(&self) -> Self::Backoff {
+        RetryBackoff {
+            jitter: self.jitter,
+            factor: self.factor,
+            min_delay: self.min_delay,
+            max_delay: self.max_delay,
+            max_times: self.max_times,
+            guard: self.guard.clone(),
+            rng: match self.seed {
+                Some(seed) => fastrand::Rng::with_seed(seed),
+                None => fastrand::Rng::new(),
+            },
+
+            attempts: 0,
+            base_delay: self.min_delay,
+            prev_delay: self.min_delay,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RetryBackoff {
+    jitter: RetryJitter,
+    factor: f32,
+    min_delay: Duration,
+    max_delay: Duration,
+    max_times: usize,
+    guard: Option<Arc<RetryGuard>>,
+    rng: fastrand::Rng,
+
+    attempts: usize,
+    /// The exponential delay without jitter.
+    base_delay: Duration,
+    /// The previous delay returned, used by decorrelated jitter.
+    prev_delay: Duration,
+}
+
+impl Iterator for RetryBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.attempts >= self.max_times {
+            return None;
+        }
+        // Only withdraw from the budget when a retry will happen.
+        if let Some(guard) = &self.guard {
+            if !guard.withdraw() {
+                return None;
+            }
+        }
+
+        if self.attempts > 0 {
+            self.base_delay = self
+                .base_delay
+                .mul_f64(self.factor as f64)
+                .min(self.max_delay);
+        }
+        self.attempts += 1;
+        let base = self.base_delay.min(self.max_delay);
+
+        let delay = match self.jitter {
+            RetryJitter::None => base,
+            RetryJitter::Additive => base + self.min_delay.mul_f64(self.rng.f64()),
+            RetryJitter::Full => base.mul_f64(self.rng.f64()),
+            RetryJitter::Decorrelated => {
+                let upper = self
+                    .prev_delay
+                    .mul_f64(self.factor as f64)
+                    .max(self.min_delay);
+                let delay = self.min_delay + (upper - self.min_delay).mul_f64(self.rng.f64());
+                delay.min(self.max_delay)
+            }
+        };
+        self.prev_delay = delay;
+
+        Some(delay)
+    }
+}
+
+/// RetryGuard carries the states shared by all requests from the same
+/// [`RetryLayer`], like retry budget and circuit breaker.
+#[derive(Debug, Default)]
+struct RetryGuard {
+    budget: Option<Mutex<RetryBudget>>,
+    breaker: Option<Mutex<CircuitBreaker>>,
+}
+
+#[derive(Debug, Clone)]
+struct RetryBudget {
+    ratio: f32,
+    burst: f32,
+    tokens: f32,
+}
+
+#[derive(Debug, Clone)]
+struct CircuitBreaker {
+    threshold: usize,
+    cooldown: Duration,
+    failures: usize,
+    state: CircuitState,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    /// Requests are allowed to reach the service.
+    Closed,
+    /// Requests are rejected until the instant has passed.
+    Open(Instant),
+    /// A probe request started at the instant is in flight, all other
+    /// requests are rejected.
+    HalfOpen(Instant),
+}
+
+impl RetryGuard {
+    fn clone_budget(&self) -> Option<Mutex<RetryBudget>> {
+        self.budget
+            .as_ref()
+            .map(|v| Mutex::new(v.lock().expect("lock must succeed").clone()))
+    }
+
+    fn clone_breaker(&self) -> Option<Mutex<CircuitBreaker>> {
+        self.breaker
+            .as_ref()
+            .map(|v| Mutex::new(v.lock().expect("lock must succeed").clone()))
+    }
+
+    /// Check whether a request is allowed to be sent.
+    ///
+    /// Every allowed request will deposit into the retry budget.
+    fn acquire(&self, op: Operation) -> Result<()> {
+        if let Some(breaker) = &self.breaker {
+            let mut breaker = breaker.lock().expect("lock must succeed");
+            let now = Instant::now();
+
+            match breaker.state {
+                CircuitState::Closed => {}
+                // The cooldown is over, let this request probe the service.
+                //
+                // If the previous probe has been dropped without result, we
+                // will allow another probe after one more cooldown.
+                CircuitState::Open(until) if now >= until => {
+                    breaker.state = CircuitState::HalfOpen(now);
+                }
+                CircuitState::HalfOpen(since) if now >= since + breaker.cooldown => {
+                    breaker.state = CircuitState::HalfOpen(now);
+                }
+                CircuitState::Open(_) | CircuitState::HalfOpen(_) => {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "circuit breaker is open, request is rejected",
+                    )
+                    .with_operation(op)
+                    .with_context("failures", breaker.failures.to_string())
+                    .set_persistent());
+                }
+            }
+        }
+
+        if let Some(budget) = &self.budget {
+            let mut budget = budget.lock().expect("lock must succeed");
+            budget.tokens = (budget.tokens + budget.ratio).min(budget.burst);
+        }
+
+        Ok(())
+    }
+
+    /// Record the result of a request into the circuit breaker.
+    ///
+    /// Only temporary errors are counted as failures, other errors mean
+    /// the service is still able to respond.
+    fn record<T>(&self, res: Result<T>) -> Result<T> {
+        let Some(breaker) = &self.breaker else {
+            return res;
+        };
+        let mut breaker = breaker.lock().expect("lock must succeed");
+
+        match &res {
+            Err(err) if err.is_temporary() => {
+                breaker.failures += 1;
+
+                if matches!(breaker.state, CircuitState::HalfOpen(_))
+                    || breaker.failures >= breaker.threshold
+                {
+                    breaker.state = CircuitState::Open(Instant::now() + breaker.cooldown);
+                }
+            }
+            _ => {
+                breaker.failures = 0;
+                breaker.state = CircuitState::Closed;
+            }
+        }
+
+        res
+    }
+
+    /// Withdraw one token from the retry budget for the next retry,
+    /// returns `false` if the budget is exhausted.
+    fn withdraw(&self) -> bool {
+        match &self.budget {
+            None => true,
+            Some(budget) => {
+                let mut budget = budget.lock().expect("lock must succeed");
+                if budget.tokens < 1.0 {
+                    return false;
+                }
+                budget.tokens -= 1.0;
+                true
+            }
+        }
+    }
+}
+
 pub struct RetryAccessor<A: Accessor, I: RetryInterceptor> {
     inner: A,
-    builder: ExponentialBuilder,
+    builder: RetryBackoffBuilder,
     notify: Arc<I>,
+    guard: Arc<RetryGuard>,
 }
 
 impl<A: Accessor, I: RetryInterceptor> Debug for RetryAccessor<A, I> {
@@ -294,6 +759,8 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
     }
 
     async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.guard.acquire(Operation::CreateDir)?;
+
         { || self.inner.create_dir(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -307,13 +774,16 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .map(|v| v.map_err(|e| e.set_persistent()))
+            .map(|v| self.guard.record(v).map_err(|e| e.set_persistent()))
             .await
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.guard.acquire(Operation::Read)?;
+
+        let builder = self.builder.with_max_retries(args.max_retries());
         { || self.inner.read(path, args.clone()) }
-            .retry(&self.builder)
+            .retry(&builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 self.notify.intercept(
@@ -323,13 +793,15 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                 )
             })
             .map(|v| {
-                v.map(|(rp, r)| {
-                    (
-                        rp,
-                        RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
-                    )
-                })
-                .map_err(|e| e.set_persistent())
+                self.guard
+                    .record(v)
+                    .map(|(rp, r)| {
+                        (
+                            rp,
+                            RetryWrapper::new(r, self.notify.clone(), path, builder.clone()),
+                        )
+                    })
+                    .map_err(|e| e.set_persistent())
             })
             .await
     }
@@ -338,6 +810,8 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
     ///
     /// Allowing users to retry the write request from upper logic.
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.guard.acquire(Operation::Write)?;
+
         { || self.inner.write(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -352,18 +826,22 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                 )
             })
             .map(|v| {
-                v.map(|(rp, r)| {
-                    (
-                        rp,
-                        RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
-                    )
-                })
-                .map_err(|e| e.set_persistent())
+                self.guard
+                    .record(v)
+                    .map(|(rp, r)| {
+                        (
+                            rp,
+                            RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
+                        )
+                    })
+                    .map_err(|e| e.set_persistent())
             })
             .await
     }
 
     async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.guard.acquire(Operation::Append)?;
+
         { || self.inner.append(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -378,20 +856,62 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                 )
             })
             .map(|v| {
-                v.map(|(rp, r)| {
-                    (
-                        rp,
-                        RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
-                    )
-                })
-                .map_err(|e| e.set_persistent())
+                self.guard
+                    .record(v)
+                    .map(|(rp, r)| {
+                        (
+                            rp,
+                            RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
+                        )
+                    })
+                    .map_err(|e| e.set_persistent())
+            })
+            .await
+    }
+
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.guard.acquire(Operation::Write)?;
+
+        { || self.inner.multipart(path, args.clone()) }
+            .retry(&self.builder)
+            .when(|e| e.is_temporary())
+            .notify(|err, dur| {
+                self.notify.intercept(
+                    err,
+                    dur,
+                    &[
+                        ("operation", Operation::Write.into_static()),
+                        ("path", path),
+                    ],
+                )
+            })
+            .map(|v| {
+                self.guard
+                    .record(v)
+                    .map(|(rp, u)| {
+                        let u: oio::MultipartUploader = Box::new(RetryWrapper::new(
+                            u,
+                            self.notify.clone(),
+                            path,
+                            self.builder.clone(),
+                        ));
+                        (rp, u)
+                    })
+                    .map_err(|e| e.set_persistent())
             })
             .await
     }
 
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.guard.acquire(Operation::Stat)?;
+
+        let builder = self.builder.with_max_retries(args.max_retries());
         { || self.inner.stat(path, args.clone()) }
-            .retry(&self.builder)
+            .retry(&builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 self.notify.intercept(
@@ -400,11 +920,13 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     &[("operation", Operation::Stat.into_static()), ("path", path)],
                 )
             })
-            .map(|v| v.map_err(|e| e.set_persistent()))
+            .map(|v| self.guard.record(v).map_err(|e| e.set_persistent()))
             .await
     }
 
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.guard.acquire(Operation::Delete)?;
+
         { || self.inner.delete(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -418,11 +940,13 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .map(|v| v.map_err(|e| e.set_persistent()))
+            .map(|v| self.guard.record(v).map_err(|e| e.set_persistent()))
             .await
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.guard.acquire(Operation::Copy)?;
+
         { || self.inner.copy(from, to, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -437,11 +961,13 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .map(|v| v.map_err(|e| e.set_persistent()))
+            .map(|v| self.guard.record(v).map_err(|e| e.set_persistent()))
             .await
     }
 
     async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        self.guard.acquire(Operation::Rename)?;
+
         { || self.inner.rename(from, to, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -456,11 +982,13 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .map(|v| v.map_err(|e| e.set_persistent()))
+            .map(|v| self.guard.record(v).map_err(|e| e.set_persistent()))
             .await
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.guard.acquire(Operation::List)?;
+
         { || self.inner.list(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
@@ -472,18 +1000,22 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                 )
             })
             .map(|v| {
-                v.map(|(l, p)| {
-                    let pager =
-                        RetryWrapper::new(p, self.notify.clone(), path, self.builder.clone());
-                    (l, pager)
-                })
-                .map_err(|e| e.set_persistent())
+                self.guard
+                    .record(v)
+                    .map(|(l, p)| {
+                        let pager =
+                            RetryWrapper::new(p, self.notify.clone(), path, self.builder.clone());
+                        (l, pager)
+                    })
+                    .map_err(|e| e.set_persistent())
             })
             .await
     }
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
-        {
+        self.guard.acquire(Operation::Batch)?;
+
+        let res = {
             || async {
                 let rp = self.inner.batch(args.clone()).await?;
                 let mut nrp = Vec::with_capacity(rp.results().len());
@@ -506,12 +1038,14 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                 ],
             )
         })
-        .await
-        .map_err(|e| e.set_persistent())
+        .await;
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
-        { || self.inner.blocking_create_dir(path, args.clone()) }
+        self.guard.acquire(Operation::BlockingCreateDir)?;
+
+        let res = { || self.inner.blocking_create_dir(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -524,13 +1058,16 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
-            .map_err(|e| e.set_persistent())
+            .call();
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        { || self.inner.blocking_read(path, args.clone()) }
-            .retry(&self.builder)
+        self.guard.acquire(Operation::BlockingRead)?;
+
+        let builder = self.builder.with_max_retries(args.max_retries());
+        let res = { || self.inner.blocking_read(path, args.clone()) }
+            .retry(&builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 self.notify.intercept(
@@ -542,18 +1079,22 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
+            .call();
+        self.guard
+            .record(res)
             .map(|(rp, r)| {
                 (
                     rp,
-                    RetryWrapper::new(r, self.notify.clone(), path, self.builder.clone()),
+                    RetryWrapper::new(r, self.notify.clone(), path, builder.clone()),
                 )
             })
             .map_err(|e| e.set_persistent())
     }
 
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
-        { || self.inner.blocking_write(path, args.clone()) }
+        self.guard.acquire(Operation::BlockingWrite)?;
+
+        let res = { || self.inner.blocking_write(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -566,7 +1107,9 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
+            .call();
+        self.guard
+            .record(res)
             .map(|(rp, r)| {
                 (
                     rp,
@@ -577,8 +1120,11 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
     }
 
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
-        { || self.inner.blocking_stat(path, args.clone()) }
-            .retry(&self.builder)
+        self.guard.acquire(Operation::BlockingStat)?;
+
+        let builder = self.builder.with_max_retries(args.max_retries());
+        let res = { || self.inner.blocking_stat(path, args.clone()) }
+            .retry(&builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
                 self.notify.intercept(
@@ -590,12 +1136,14 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
-            .map_err(|e| e.set_persistent())
+            .call();
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
-        { || self.inner.blocking_delete(path, args.clone()) }
+        self.guard.acquire(Operation::BlockingDelete)?;
+
+        let res = { || self.inner.blocking_delete(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -608,12 +1156,14 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
-            .map_err(|e| e.set_persistent())
+            .call();
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
-        { || self.inner.blocking_copy(from, to, args.clone()) }
+        self.guard.acquire(Operation::BlockingCopy)?;
+
+        let res = { || self.inner.blocking_copy(from, to, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -627,12 +1177,14 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
-            .map_err(|e| e.set_persistent())
+            .call();
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
-        { || self.inner.blocking_rename(from, to, args.clone()) }
+        self.guard.acquire(Operation::BlockingRename)?;
+
+        let res = { || self.inner.blocking_rename(from, to, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -646,12 +1198,14 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
-            .map_err(|e| e.set_persistent())
+            .call();
+        self.guard.record(res).map_err(|e| e.set_persistent())
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
-        { || self.inner.blocking_list(path, args.clone()) }
+        self.guard.acquire(Operation::BlockingList)?;
+
+        let res = { || self.inner.blocking_list(path, args.clone()) }
             .retry(&self.builder)
             .when(|e| e.is_temporary())
             .notify(|err, dur| {
@@ -664,7 +1218,9 @@ impl<A: Accessor, I: RetryInterceptor> LayeredAccessor for RetryAccessor<A, I> {
                     ],
                 )
             })
-            .call()
+            .call();
+        self.guard
+            .record(res)
             .map(|(rp, p)| {
                 let p = RetryWrapper::new(p, self.notify.clone(), path, self.builder.clone());
                 (rp, p)
@@ -678,13 +1234,13 @@ pub struct RetryWrapper<R, I> {
     notify: Arc<I>,
 
     path: String,
-    builder: ExponentialBuilder,
-    current_backoff: Option<ExponentialBackoff>,
+    builder: RetryBackoffBuilder,
+    current_backoff: Option<RetryBackoff>,
     sleep: Option<Pin<Box<tokio::time::Sleep>>>,
 }
 
 impl<R, I> RetryWrapper<R, I> {
-    fn new(inner: R, notify: Arc<I>, path: &str, backoff: ExponentialBuilder) -> Self {
+    fn new(inner: R, notify: Arc<I>, path: &str, backoff: RetryBackoffBuilder) -> Self {
         Self {
             inner,
             notify,
@@ -931,6 +1487,10 @@ impl<R: oio::Write, I: RetryInterceptor> oio::Write for RetryWrapper<R, I> {
         self.inner.sink(size, s).await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         let mut backoff = self.builder.build();
 
@@ -1077,6 +1637,103 @@ impl<A: oio::Append, I: RetryInterceptor> oio::Append for RetryWrapper<A, I> {
     }
 }
 
+impl<I: RetryInterceptor> RetryWrapper<oio::MultipartUploader, I> {
+    async fn retry<T, F, Fut>(&self, op: MultipartUploadOperation, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut backoff = self.builder.build();
+
+        loop {
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(e) if !e.is_temporary() => return Err(e),
+                Err(e) => match backoff.next() {
+                    None => return Err(e),
+                    Some(dur) => {
+                        self.notify.intercept(
+                            &e,
+                            dur,
+                            &[("operation", op.into_static()), ("path", &self.path)],
+                        );
+                        tokio::time::sleep(dur).await;
+                        continue;
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Only bytes body can be retried, stream body will be moved by the
+/// first request.
+#[async_trait]
+impl<I: RetryInterceptor> oio::MultipartUploadWrite for RetryWrapper<oio::MultipartUploader, I> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let bs = match body {
+            AsyncBody::Stream(_) => return self.inner.write_once(size, body).await,
+            AsyncBody::Empty => Bytes::new(),
+            AsyncBody::Bytes(bs) => bs,
+        };
+
+        self.retry(MultipartUploadOperation::WriteOnce, || {
+            self.inner.write_once(size, AsyncBody::Bytes(bs.clone()))
+        })
+        .await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        self.retry(MultipartUploadOperation::InitiatePart, || {
+            self.inner.initiate_part()
+        })
+        .await
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let bs = match body {
+            AsyncBody::Stream(_) => {
+                return self
+                    .inner
+                    .write_part(upload_id, part_number, size, body)
+                    .await
+            }
+            AsyncBody::Empty => Bytes::new(),
+            AsyncBody::Bytes(bs) => bs,
+        };
+
+        self.retry(MultipartUploadOperation::WritePart, || {
+            self.inner
+                .write_part(upload_id, part_number, size, AsyncBody::Bytes(bs.clone()))
+        })
+        .await
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        self.retry(MultipartUploadOperation::CompletePart, || {
+            self.inner.complete_part(upload_id, parts)
+        })
+        .await
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.retry(MultipartUploadOperation::AbortPart, || {
+            self.inner.abort_part(upload_id)
+        })
+        .await
+    }
+}
+
 #[async_trait]
 impl<P: oio::Page, I: RetryInterceptor> oio::Page for RetryWrapper<P, I> {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
@@ -1104,6 +1761,10 @@ impl<P: oio::Page, I: RetryInterceptor> oio::Page for RetryWrapper<P, I> {
             }
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<P: oio::BlockingPage, I: RetryInterceptor> oio::BlockingPage for RetryWrapper<P, I> {
@@ -1145,6 +1806,7 @@ mod tests {
     #[derive(Default, Clone)]
     struct MockBuilder {
         attempt: Arc<Mutex<usize>>,
+        outage: Arc<Mutex<bool>>,
     }
 
     impl Builder for MockBuilder {
@@ -1158,6 +1820,7 @@ mod tests {
         fn build(&mut self) -> Result<Self::Accessor> {
             Ok(MockService {
                 attempt: self.attempt.clone(),
+                outage: self.outage.clone(),
             })
         }
     }
@@ -1165,6 +1828,7 @@ mod tests {
     #[derive(Debug, Clone, Default)]
     struct MockService {
         attempt: Arc<Mutex<usize>>,
+        outage: Arc<Mutex<bool>>,
     }
 
     #[async_trait]
@@ -1181,6 +1845,7 @@ mod tests {
             let mut am = AccessorInfo::default();
             am.set_capability(Capability {
                 read: true,
+                stat: true,
                 list: true,
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
@@ -1201,6 +1866,18 @@ mod tests {
             ))
         }
 
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            *self.attempt.lock().unwrap() += 1;
+
+            if *self.outage.lock().unwrap() {
+                return Err(
+                    Error::new(ErrorKind::Unexpected, "retryable_error from outage")
+                        .set_temporary(),
+                );
+            }
+            Ok(RpStat::new(Metadata::new(EntryMode::FILE)))
+        }
+
         async fn list(&self, _: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
             let pager = MockPager::default();
             Ok((RpList::default(), pager))
@@ -1381,6 +2058,62 @@ mod tests {
         assert_eq!(*builder.attempt.lock().unwrap(), 5);
     }
 
+    #[tokio::test]
+    async fn test_retry_read_with_max_retries() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(RetryLayer::new())
+            .finish();
+
+        let mut r = op
+            .reader_with("retryable_error")
+            .max_retries(0)
+            .await
+            .unwrap();
+        let mut content = Vec::new();
+        r.read_to_end(&mut content)
+            .await
+            .expect_err("read must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_stat_with_max_retries() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        *builder.outage.lock().unwrap() = true;
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(
+                RetryLayer::new()
+                    .with_min_delay(Duration::from_secs(1))
+                    .with_max_times(3),
+            )
+            .finish();
+
+        // The probe should fail immediately without any retry.
+        let start = Instant::now();
+        let err = op
+            .stat_with("retryable_error")
+            .max_retries(0)
+            .await
+            .expect_err("stat must fail");
+        assert!(!err.is_temporary());
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(*builder.attempt.lock().unwrap(), 1);
+
+        // Override the max times with a smaller value.
+        op.stat_with("retryable_error")
+            .max_retries(1)
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 3);
+    }
+
     #[tokio::test]
     async fn test_retry_list() {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
@@ -1429,4 +2162,173 @@ mod tests {
         op.remove(paths).await.expect("batch must succeed");
         assert_eq!(*builder.attempt.lock().unwrap(), 5);
     }
+
+    #[tokio::test]
+    async fn test_retry_budget() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        *builder.outage.lock().unwrap() = true;
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(
+                RetryLayer::new()
+                    .with_min_delay(Duration::from_millis(1))
+                    .with_max_times(3)
+                    .with_retry_budget(0.0, 2),
+            )
+            .finish();
+
+        // The budget allows 2 retries only.
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 3);
+
+        // The budget has been exhausted, no more retries.
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_with_exhausted_backoff() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        *builder.outage.lock().unwrap() = true;
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(
+                RetryLayer::new()
+                    .with_min_delay(Duration::from_millis(1))
+                    .with_max_times(1)
+                    .with_retry_budget(0.0, 2),
+            )
+            .finish();
+
+        // No token will be withdrawn once the backoff is exhausted, so
+        // every stat can retry once.
+        for attempt in [2, 4] {
+            op.stat("retryable_error")
+                .await
+                .expect_err("stat must fail");
+            assert_eq!(*builder.attempt.lock().unwrap(), attempt);
+        }
+
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_retry_circuit_breaker() {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let builder = MockBuilder::default();
+        *builder.outage.lock().unwrap() = true;
+        let op = Operator::new(builder.clone())
+            .unwrap()
+            .layer(
+                RetryLayer::new()
+                    .with_min_delay(Duration::from_millis(1))
+                    .with_max_times(1)
+                    .with_circuit_breaker(2, Duration::from_millis(100)),
+            )
+            .finish();
+
+        // Two consecutive failures will open the circuit.
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 4);
+
+        // Requests will fail fast without reaching the service.
+        let err = op
+            .stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert!(err.to_string().contains("circuit breaker is open"));
+        assert!(!err.is_temporary());
+        assert_eq!(*builder.attempt.lock().unwrap(), 4);
+
+        // The probe fails while outage, the circuit will be opened again.
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        op.stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(*builder.attempt.lock().unwrap(), 6);
+        let err = op
+            .stat("retryable_error")
+            .await
+            .expect_err("stat must fail");
+        assert!(err.to_string().contains("circuit breaker is open"));
+        assert_eq!(*builder.attempt.lock().unwrap(), 6);
+
+        // The probe succeeds after recovery, the circuit will be closed.
+        *builder.outage.lock().unwrap() = false;
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        op.stat("retryable_error").await.expect("stat must succeed");
+        op.stat("retryable_error").await.expect("stat must succeed");
+        assert_eq!(*builder.attempt.lock().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_retry_backoff_jitter() {
+        let min_delay = Duration::from_millis(100);
+        let max_delay = Duration::from_secs(2);
+        let builder = RetryBackoffBuilder {
+            min_delay,
+            max_delay,
+            max_times: 8,
+            ..Default::default()
+        };
+
+        let delays = builder.build().collect::<Vec<_>>();
+        assert_eq!(delays.len(), 8);
+        assert_eq!(delays[0], min_delay);
+        assert_eq!(delays[1], min_delay * 2);
+        assert_eq!(delays[7], max_delay);
+
+        for _ in 0..1000 {
+            let mut builder = builder.clone();
+
+            builder.jitter = RetryJitter::Additive;
+            for (dur, base) in builder.build().zip(delays.iter()) {
+                assert!(dur >= *base && dur < *base + min_delay, "{dur:?}");
+            }
+
+            builder.jitter = RetryJitter::Full;
+            for (dur, base) in builder.build().zip(delays.iter()) {
+                assert!(dur < *base, "{dur:?}");
+            }
+
+            builder.jitter = RetryJitter::Decorrelated;
+            let mut prev = min_delay;
+            for dur in builder.build() {
+                assert!(dur >= min_delay && dur <= max_delay, "{dur:?}");
+                assert!(dur <= prev * 2, "{dur:?}");
+                prev = dur;
+            }
+        }
+    }
+
+    #[test]
+    fn test_retry_backoff_jitter_seed() {
+        let builder = RetryBackoffBuilder {
+            jitter: RetryJitter::Decorrelated,
+            seed: Some(42),
+            ..Default::default()
+        };
+
+        let expected = builder.build().collect::<Vec<_>>();
+        for _ in 0..10 {
+            assert_eq!(builder.build().collect::<Vec<_>>(), expected);
+        }
+    }
 }
@@ -0,0 +1,690 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Add an in-memory cache for stat results.
+///
+/// # Notes
+///
+/// StatCacheLayer caches the [`Metadata`] returned by `stat` for given ttl,
+/// which is friendly for read-mostly workloads like checking existence of
+/// the same objects in a loop.
+///
+/// - Cached metadata of a path will be invalidated by `write`, `append`,
+///   `multipart`, `create_dir`, `delete`, `copy`, `rename` and `batch` on the same path
+///   issued via this operator. Entries under the path will be invalidated
+///   too if it's a dir or the operation is recursive.
+/// - Not found results are not cached by default, users can enable it via
+///   [`StatCacheLayer::with_not_found_ttl`].
+/// - Stat with conditions like `if_match` or `version` will not be cached.
+///
+/// Changes made by others will not be visible until the cached entry expires.
+///
+/// At most 10000 entries will be cached by default, users can
+/// change it via [`StatCacheLayer::with_capacity`]. Expired entries will be
+/// purged while the cache is full, and then the entry closest to expire will
+/// be evicted.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::layers::StatCacheLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         StatCacheLayer::new(Duration::from_secs(60))
+///             .with_not_found_ttl(Duration::from_secs(5)),
+///     )
+///     .finish();
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatCacheLayer {
+    ttl: Duration,
+    not_found_ttl: Option<Duration>,
+    capacity: usize,
+}
+
+/// The default max number of entries cached by [`StatCacheLayer`].
+const DEFAULT_CAPACITY: usize = 10_000;
+
+impl StatCacheLayer {
+    /// Create a new StatCacheLayer which caches metadata for given ttl.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            not_found_ttl: None,
+            capacity: DEFAULT_CAPACITY,
+        }
+    }
+
+    /// Set the max number of entries to cache.
+    ///
+    /// Default to 10000.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Cache not found results for given ttl.
+    pub fn with_not_found_ttl(mut self, ttl: Duration) -> Self {
+        self.not_found_ttl = Some(ttl);
+        self
+    }
+}
+
+impl<A: Accessor> Layer<A> for StatCacheLayer {
+    type LayeredAccessor = StatCacheAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        StatCacheAccessor {
+            inner,
+            cache: Arc::new(StatCache {
+                ttl: self.ttl,
+                not_found_ttl: self.not_found_ttl,
+                capacity: self.capacity,
+                entries: Mutex::default(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StatCache {
+    ttl: Duration,
+    not_found_ttl: Option<Duration>,
+    capacity: usize,
+    /// Cached entries, `None` means the path is not found.
+    entries: Mutex<HashMap<String, (Option<Metadata>, Instant)>>,
+}
+
+impl StatCache {
+    fn get(&self, path: &str) -> Option<Result<RpStat>> {
+        let mut entries = self.entries.lock().expect("lock must succeed");
+
+        let (meta, expire_at) = entries.get(path)?;
+        if *expire_at <= Instant::now() {
+            entries.remove(path);
+            return None;
+        }
+
+        Some(match meta {
+            Some(meta) => Ok(RpStat::new(meta.clone())),
+            None => Err(
+                Error::new(ErrorKind::NotFound, "path not found, cached by stat cache")
+                    .with_context("path", path),
+            ),
+        })
+    }
+
+    fn update(&self, path: &str, result: &Result<RpStat>) {
+        let (meta, ttl) = match result {
+            Ok(rp) => (Some(rp.clone().into_metadata()), self.ttl),
+            Err(err) if err.kind() == ErrorKind::NotFound => match self.not_found_ttl {
+                Some(ttl) => (None, ttl),
+                None => return,
+            },
+            Err(_) => return,
+        };
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock().expect("lock must succeed");
+        if entries.len() >= self.capacity && !entries.contains_key(path) {
+            entries.retain(|_, (_, expire_at)| *expire_at > now);
+        }
+        if entries.len() >= self.capacity && !entries.contains_key(path) {
+            let evicted = entries
+                .iter()
+                .min_by_key(|(_, (_, expire_at))| *expire_at)
+                .map(|(k, _)| k.clone());
+            if let Some(evicted) = evicted {
+                entries.remove(&evicted);
+            }
+        }
+        entries.insert(path.to_string(), (meta, now + ttl));
+    }
+
+    fn invalidate(&self, path: &str) {
+        self.entries.lock().expect("lock must succeed").remove(path);
+    }
+
+    /// Invalidate given path along with all entries under it.
+    fn invalidate_recursive(&self, path: &str) {
+        let prefix = if path.ends_with('/') {
+            path.to_string()
+        } else {
+            format!("{path}/")
+        };
+
+        self.entries
+            .lock()
+            .expect("lock must succeed")
+            .retain(|k, _| k != path && !k.starts_with(&prefix));
+    }
+
+    /// Invalidate given path, entries under it will be invalidated too if
+    /// the path is a dir or the operation is recursive.
+    fn invalidate_path(&self, path: &str, recursive: bool) {
+        if recursive || path.ends_with('/') {
+            self.invalidate_recursive(path)
+        } else {
+            self.invalidate(path)
+        }
+    }
+}
+
+/// Only stat without any conditions can be cached.
+fn is_cacheable(args: &OpStat) -> bool {
+    args.if_match().is_none() && args.if_none_match().is_none() && args.version().is_none()
+}
+
+#[derive(Debug, Clone)]
+pub struct StatCacheAccessor<A: Accessor> {
+    inner: A,
+    cache: Arc<StatCache>,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for StatCacheAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = StatCacheWrapper<A::Writer>;
+    type BlockingWriter = StatCacheWrapper<A::BlockingWriter>;
+    type Appender = StatCacheWrapper<A::Appender>;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.cache.invalidate(path);
+        self.inner
+            .write(path, args)
+            .await
+            .map(|(rp, w)| (rp, StatCacheWrapper::new(w, path, self.cache.clone())))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.cache.invalidate(path);
+        self.inner
+            .append(path, args)
+            .await
+            .map(|(rp, a)| (rp, StatCacheWrapper::new(a, path, self.cache.clone())))
+    }
+
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.cache.invalidate(path);
+        self.inner.multipart(path, args).await.map(|(rp, u)| {
+            let u: oio::MultipartUploader =
+                Box::new(StatCacheWrapper::new(u, path, self.cache.clone()));
+            (rp, u)
+        })
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let result = self.inner.copy(from, to, args).await;
+        self.cache.invalidate(to);
+        result
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let result = self.inner.rename(from, to, args).await;
+        self.cache.invalidate_path(from, false);
+        self.cache.invalidate_path(to, false);
+        result
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.stat(path, args).await;
+        }
+        if let Some(result) = self.cache.get(path) {
+            return result;
+        }
+
+        let result = self.inner.stat(path, args).await;
+        self.cache.update(path, &result);
+        result
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let recursive = args.recursive();
+        let result = self.inner.delete(path, args).await;
+        self.cache.invalidate_path(path, recursive);
+        result
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let paths: Vec<(String, bool)> = args
+            .operation()
+            .iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(op) => (p.clone(), op.recursive()),
+                BatchOperation::Write(_, _) => (p.clone(), false),
+            })
+            .collect();
+
+        let result = self.inner.batch(args).await;
+        for (path, recursive) in paths {
+            self.cache.invalidate_path(&path, recursive);
+        }
+        result
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.cache.invalidate(path);
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.cache.invalidate(path);
+        self.inner
+            .blocking_write(path, args)
+            .map(|(rp, w)| (rp, StatCacheWrapper::new(w, path, self.cache.clone())))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let result = self.inner.blocking_copy(from, to, args);
+        self.cache.invalidate(to);
+        result
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let result = self.inner.blocking_rename(from, to, args);
+        self.cache.invalidate_path(from, false);
+        self.cache.invalidate_path(to, false);
+        result
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        if !is_cacheable(&args) {
+            return self.inner.blocking_stat(path, args);
+        }
+        if let Some(result) = self.cache.get(path) {
+            return result;
+        }
+
+        let result = self.inner.blocking_stat(path, args);
+        self.cache.update(path, &result);
+        result
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let recursive = args.recursive();
+        let result = self.inner.blocking_delete(path, args);
+        self.cache.invalidate_path(path, recursive);
+        result
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// StatCacheWrapper invalidates the cached metadata of given path after
+/// the content has been changed.
+pub struct StatCacheWrapper<R> {
+    inner: R,
+    path: String,
+    cache: Arc<StatCache>,
+}
+
+impl<R> StatCacheWrapper<R> {
+    fn new(inner: R, path: &str, cache: Arc<StatCache>) -> Self {
+        Self {
+            inner,
+            path: path.to_string(),
+            cache,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: oio::Write> oio::Write for StatCacheWrapper<R> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.inner.write(bs).await
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.inner.sink(size, s).await
+    }
+
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let result = self.inner.close().await;
+        self.cache.invalidate(&self.path);
+        result
+    }
+}
+
+impl<R: oio::BlockingWrite> oio::BlockingWrite for StatCacheWrapper<R> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.inner.write(bs)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let result = self.inner.close();
+        self.cache.invalidate(&self.path);
+        result
+    }
+}
+
+#[async_trait]
+impl<R: oio::Append> oio::Append for StatCacheWrapper<R> {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let result = self.inner.append(bs).await;
+        self.cache.invalidate(&self.path);
+        result
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let result = self.inner.close().await;
+        self.cache.invalidate(&self.path);
+        result
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for StatCacheWrapper<oio::MultipartUploader> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let result = self.inner.write_once(size, body).await;
+        self.cache.invalidate(&self.path);
+        result
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        self.inner.initiate_part().await
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        self.inner
+            .write_part(upload_id, part_number, size, body)
+            .await
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        let result = self.inner.complete_part(upload_id, parts).await;
+        self.cache.invalidate(&self.path);
+        result
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.inner.abort_part(upload_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::OperatorBuilder;
+
+    /// MockService counts all stat requests it received.
+    #[derive(Debug, Default)]
+    struct MockService {
+        stats: Arc<AtomicUsize>,
+        size: Arc<Mutex<Option<u64>>>,
+    }
+
+    struct MockWriter {
+        size: Arc<Mutex<Option<u64>>>,
+        written: u64,
+    }
+
+    #[async_trait]
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, bs: Bytes) -> Result<()> {
+            self.written += bs.len() as u64;
+            Ok(())
+        }
+
+        async fn sink(&mut self, _: u64, _: oio::Streamer) -> Result<()> {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "mock writer doesn't support sink",
+            ))
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            *self.size.lock().unwrap() = Some(self.written);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = MockWriter;
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.set_capability(Capability {
+                stat: true,
+                write: true,
+                ..Default::default()
+            });
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            self.stats.fetch_add(1, Ordering::SeqCst);
+
+            match *self.size.lock().unwrap() {
+                Some(size) => Ok(RpStat::new(
+                    Metadata::new(EntryMode::FILE).with_content_length(size),
+                )),
+                None => Err(Error::new(ErrorKind::NotFound, "not found")),
+            }
+        }
+
+        async fn write(&self, _: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            Ok((
+                RpWrite::default(),
+                MockWriter {
+                    size: self.size.clone(),
+                    written: 0,
+                },
+            ))
+        }
+    }
+
+    fn new_operator(layer: StatCacheLayer) -> (Operator, Arc<AtomicUsize>) {
+        let srv = MockService::default();
+        let stats = srv.stats.clone();
+        let op = OperatorBuilder::new(srv).layer(layer).finish();
+        (op, stats)
+    }
+
+    #[tokio::test]
+    async fn test_cache_hit() -> Result<()> {
+        let (op, stats) = new_operator(StatCacheLayer::new(Duration::from_secs(60)));
+        op.write("test", "Hello").await?;
+
+        for _ in 0..3 {
+            assert_eq!(op.stat("test").await?.content_length(), 5);
+        }
+        assert_eq!(stats.load(Ordering::SeqCst), 1);
+
+        // Stat with conditions should not be cached.
+        op.stat_with("test").if_match("etag").await?;
+        assert_eq!(stats.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_expired() -> Result<()> {
+        let (op, stats) = new_operator(
+            StatCacheLayer::new(Duration::from_millis(50))
+                .with_not_found_ttl(Duration::from_millis(50)),
+        );
+
+        for _ in 0..3 {
+            let err = op.stat("test").await.expect_err("must not found");
+            assert_eq!(err.kind(), ErrorKind::NotFound);
+        }
+        assert_eq!(stats.load(Ordering::SeqCst), 1);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(op.stat("test").await.is_err());
+        assert_eq!(stats.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_not_found_not_cached_by_default() -> Result<()> {
+        let (op, stats) = new_operator(StatCacheLayer::new(Duration::from_secs(60)));
+
+        assert!(op.stat("test").await.is_err());
+        assert!(op.stat("test").await.is_err());
+        assert_eq!(stats.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_after_write() -> Result<()> {
+        let (op, stats) = new_operator(
+            StatCacheLayer::new(Duration::from_secs(60))
+                .with_not_found_ttl(Duration::from_secs(60)),
+        );
+
+        assert!(op.stat("test").await.is_err());
+        op.write("test", "Hello").await?;
+        assert_eq!(op.stat("test").await?.content_length(), 5);
+        assert_eq!(stats.load(Ordering::SeqCst), 2);
+
+        op.write("test", "Hello, World!").await?;
+        assert_eq!(op.stat("test").await?.content_length(), 13);
+        assert_eq!(stats.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_capacity() -> Result<()> {
+        let (op, stats) = new_operator(
+            StatCacheLayer::new(Duration::from_secs(60))
+                .with_not_found_ttl(Duration::from_secs(60))
+                .with_capacity(2),
+        );
+
+        for path in ["a", "b", "c"] {
+            assert!(op.stat(path).await.is_err());
+        }
+        assert_eq!(stats.load(Ordering::SeqCst), 3);
+
+        // `a` is the first to expire and has been evicted.
+        assert!(op.stat("c").await.is_err());
+        assert!(op.stat("b").await.is_err());
+        assert_eq!(stats.load(Ordering::SeqCst), 3);
+        assert!(op.stat("a").await.is_err());
+        assert_eq!(stats.load(Ordering::SeqCst), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_dir() -> Result<()> {
+        let (op, stats) = new_operator(
+            StatCacheLayer::new(Duration::from_secs(60))
+                .with_not_found_ttl(Duration::from_secs(60)),
+        );
+
+        for path in ["dir/a", "dir/sub/b", "dir_c"] {
+            assert!(op.stat(path).await.is_err());
+        }
+        assert_eq!(stats.load(Ordering::SeqCst), 3);
+
+        let _ = op.delete("dir/").await;
+        for path in ["dir/a", "dir/sub/b", "dir_c"] {
+            assert!(op.stat(path).await.is_err());
+        }
+        // Only entries under `dir/` are invalidated.
+        assert_eq!(stats.load(Ordering::SeqCst), 5);
+        Ok(())
+    }
+}
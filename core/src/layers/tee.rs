@@ -0,0 +1,656 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::StreamExt;
+use log::warn;
+
+use crate::raw::*;
+use crate::*;
+
+/// Mirror mutations to a secondary [`Operator`].
+///
+/// TeeLayer will apply all mutations like `write`, `multipart`,
+/// `create_dir`, `delete`, `copy` and `rename` to the underlying service (the primary) first, and
+/// then mirror them to the secondary operator once the primary succeeds.
+/// Other operations like `read`, `stat` and `list` are only served by the
+/// primary.
+///
+/// Failures of secondary are handled by [`TeeFailurePolicy`]:
+///
+/// - [`TeeFailurePolicy::Fail`] (default): return the error of secondary
+///   to users.
+/// - [`TeeFailurePolicy::Ignore`]: log the error with `warn` level and
+///   stop mirroring this operation.
+///
+/// # Notes
+///
+/// - Mutations of primary will NOT be rolled back if secondary fails.
+/// - `append` is not mirrored.
+/// - `sink` will buffer the whole content in memory so that it can be
+///   sent to both sides.
+/// - `multipart` will buffer all uploaded parts in memory, and mirror the
+///   whole object to secondary after the upload is completed.
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::layers::TeeFailurePolicy;
+/// use opendal::layers::TeeLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let backup = Operator::new(services::Memory::default())?.finish();
+/// let op = Operator::new(services::Memory::default())?
+///     .layer(TeeLayer::new(backup.clone()).with_failure_policy(TeeFailurePolicy::Ignore))
+///     .finish();
+///
+/// op.write("path/to/file", "Hello, World!").await?;
+/// assert_eq!(backup.read("path/to/file").await?, b"Hello, World!");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TeeLayer {
+    secondary: Operator,
+    policy: TeeFailurePolicy,
+}
+
+impl TeeLayer {
+    /// Create a new tee layer which mirrors mutations to `secondary`.
+    pub fn new(secondary: Operator) -> Self {
+        Self {
+            secondary,
+            policy: TeeFailurePolicy::default(),
+        }
+    }
+
+    /// Set the policy to handle failures of secondary.
+    pub fn with_failure_policy(mut self, policy: TeeFailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<A: Accessor> Layer<A> for TeeLayer {
+    type LayeredAccessor = TeeAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        TeeAccessor {
+            inner,
+            secondary: self.secondary.clone().into_inner(),
+            policy: self.policy,
+        }
+    }
+}
+
+/// TeeFailurePolicy decides how [`TeeLayer`] handles failures of secondary.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TeeFailurePolicy {
+    /// Return the error of secondary to users.
+    #[default]
+    Fail,
+    /// Log the error of secondary and move on.
+    Ignore,
+}
+
+impl TeeFailurePolicy {
+    /// Handle the result of secondary, returns `None` if the error is ignored.
+    fn handle<T>(self, operation: Operation, path: &str, res: Result<T>) -> Result<Option<T>> {
+        match res {
+            Ok(v) => Ok(Some(v)),
+            Err(err) => match self {
+                TeeFailurePolicy::Fail => Err(err.with_context("tee", "secondary")),
+                TeeFailurePolicy::Ignore => {
+                    warn!(
+                        target: "opendal::layers::tee",
+                        "mirror {} on path {} to secondary failed: {}", operation, path, err
+                    );
+                    Ok(None)
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TeeAccessor<A: Accessor> {
+    inner: A,
+    secondary: FusedAccessor,
+    policy: TeeFailurePolicy,
+}
+
+impl<A: Accessor> TeeAccessor<A> {
+    fn mirror<T>(&self, operation: Operation, path: &str, res: Result<T>) -> Result<()> {
+        self.policy.handle(operation, path, res).map(|_| ())
+    }
+}
+
+/// Write the whole content into secondary.
+async fn mirror_write(
+    secondary: &FusedAccessor,
+    path: &str,
+    args: OpWrite,
+    bs: Bytes,
+) -> Result<()> {
+    let (_, mut w) = secondary.write(path, args).await?;
+    w.write(bs).await?;
+    w.close().await
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for TeeAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = TeeWriter<A::Writer, oio::Writer>;
+    type BlockingWriter = TeeWriter<A::BlockingWriter, oio::BlockingWriter>;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.create_dir(path, args.clone()).await?;
+        let res = self.secondary.create_dir(path, args).await;
+        self.mirror(Operation::CreateDir, path, res)?;
+        Ok(rp)
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (rp, mut w) = self.inner.write(path, args.clone()).await?;
+        let res = self.secondary.write(path, args).await;
+        let secondary = match self.policy.handle(Operation::Write, path, res) {
+            Ok(v) => v.map(|(_, w)| w),
+            Err(err) => {
+                // Abort the opened primary writer, the error of secondary
+                // will be returned instead.
+                let _ = oio::Write::abort(&mut w).await;
+                return Err(err);
+            }
+        };
+
+        Ok((rp, TeeWriter::new(w, secondary, path, self.policy)))
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        let (rp, u) = self.inner.multipart(path, args.clone()).await?;
+        let u: oio::MultipartUploader = Box::new(TeeUploader {
+            inner: u,
+            secondary: self.secondary.clone(),
+            path: path.to_string(),
+            args,
+            policy: self.policy,
+            parts: Mutex::default(),
+        });
+        Ok((rp, u))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.copy(from, to, args.clone()).await?;
+        let res = self.secondary.copy(from, to, args).await;
+        self.mirror(Operation::Copy, to, res)?;
+        Ok(rp)
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.rename(from, to, args.clone()).await?;
+        let res = self.secondary.rename(from, to, args).await;
+        self.mirror(Operation::Rename, to, res)?;
+        Ok(rp)
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.delete(path, args.clone()).await?;
+        let res = self.secondary.delete(path, args).await;
+        self.mirror(Operation::Delete, path, res)?;
+        Ok(rp)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    /// Only the succeeded operations of primary will be mirrored, one by
+    /// one, since secondary may not support batch.
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = args.operation().to_vec();
+        let rp = self.inner.batch(args).await?;
+
+        // Results of batch are not guaranteed to be in the same order.
+        let succeeded: HashSet<&str> = rp
+            .results()
+            .iter()
+            .filter(|(_, res)| res.is_ok())
+            .map(|(path, _)| path.as_str())
+            .collect();
+
+        for (path, op) in ops {
+            if !succeeded.contains(path.as_str()) {
+                continue;
+            }
+
+            match op {
+                BatchOperation::Delete(args) => {
+                    let res = self.secondary.delete(&path, args).await;
+                    self.mirror(Operation::Delete, &path, res)?;
+                }
+                BatchOperation::Write(args, bs) => {
+                    let res = mirror_write(&self.secondary, &path, args, bs).await;
+                    self.mirror(Operation::Write, &path, res)?;
+                }
+            }
+        }
+
+        Ok(rp)
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let rp = self.inner.blocking_create_dir(path, args.clone())?;
+        let res = self.secondary.blocking_create_dir(path, args);
+        self.mirror(Operation::BlockingCreateDir, path, res)?;
+        Ok(rp)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (rp, w) = self.inner.blocking_write(path, args.clone())?;
+        let res = self.secondary.blocking_write(path, args);
+        let secondary = self
+            .policy
+            .handle(Operation::BlockingWrite, path, res)?
+            .map(|(_, w)| w);
+
+        Ok((rp, TeeWriter::new(w, secondary, path, self.policy)))
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let rp = self.inner.blocking_copy(from, to, args.clone())?;
+        let res = self.secondary.blocking_copy(from, to, args);
+        self.mirror(Operation::BlockingCopy, to, res)?;
+        Ok(rp)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        let rp = self.inner.blocking_rename(from, to, args.clone())?;
+        let res = self.secondary.blocking_rename(from, to, args);
+        self.mirror(Operation::BlockingRename, to, res)?;
+        Ok(rp)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let rp = self.inner.blocking_delete(path, args.clone())?;
+        let res = self.secondary.blocking_delete(path, args);
+        self.mirror(Operation::BlockingDelete, path, res)?;
+        Ok(rp)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// TeeWriter writes content into both primary and secondary.
+///
+/// Secondary will be dropped if it fails with [`TeeFailurePolicy::Ignore`].
+pub struct TeeWriter<W, S> {
+    inner: W,
+    secondary: Option<S>,
+    path: String,
+    policy: TeeFailurePolicy,
+}
+
+impl<W, S> TeeWriter<W, S> {
+    fn new(inner: W, secondary: Option<S>, path: &str, policy: TeeFailurePolicy) -> Self {
+        Self {
+            inner,
+            secondary,
+            path: path.to_string(),
+            policy,
+        }
+    }
+
+    /// Handle the result of secondary, drop it if the error is ignored.
+    fn mirror(&mut self, operation: Operation, res: Result<()>) -> Result<()> {
+        if self.policy.handle(operation, &self.path, res)?.is_none() {
+            self.secondary = None;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<W: oio::Write> oio::Write for TeeWriter<W, oio::Writer> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.inner.write(bs.clone()).await?;
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.write(bs).await;
+            self.mirror(Operation::Write, res)?;
+        }
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, mut s: oio::Streamer) -> Result<()> {
+        // Stream can't be consumed twice, buffer it instead.
+        let mut buf = BytesMut::with_capacity(size as usize);
+        while let Some(bs) = s.next().await {
+            buf.extend_from_slice(&bs?);
+        }
+
+        oio::Write::write(self, buf.freeze()).await
+    }
+
+    async fn sink_unsized(&mut self, mut s: oio::Streamer) -> Result<()> {
+        // Stream can't be consumed twice, buffer it instead.
+        let mut buf = BytesMut::new();
+        while let Some(bs) = s.next().await {
+            buf.extend_from_slice(&bs?);
+        }
+
+        oio::Write::write(self, buf.freeze()).await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await?;
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.abort().await;
+            self.mirror(Operation::Write, res)?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await?;
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.close().await;
+            self.mirror(Operation::Write, res)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for TeeWriter<W, oio::BlockingWriter> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.inner.write(bs.clone())?;
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.write(bs);
+            self.mirror(Operation::BlockingWrite, res)?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()?;
+        if let Some(w) = self.secondary.as_mut() {
+            let res = w.close();
+            self.mirror(Operation::BlockingWrite, res)?;
+        }
+        Ok(())
+    }
+}
+
+/// TeeUploader keeps the content of all uploaded parts, and mirrors the
+/// whole object to secondary after the upload is completed.
+pub struct TeeUploader {
+    inner: oio::MultipartUploader,
+    secondary: FusedAccessor,
+    path: String,
+    args: OpWrite,
+    policy: TeeFailurePolicy,
+    /// Content of uploaded parts, keyed by part number.
+    parts: Mutex<HashMap<usize, Bytes>>,
+}
+
+impl TeeUploader {
+    async fn mirror(&self, bs: Bytes) -> Result<()> {
+        let res = mirror_write(&self.secondary, &self.path, self.args.clone(), bs).await;
+        self.policy
+            .handle(Operation::Write, &self.path, res)
+            .map(|_| ())
+    }
+}
+
+/// Body can't be consumed twice, buffer it instead.
+async fn buffer_body(body: AsyncBody) -> Result<Bytes> {
+    match body {
+        AsyncBody::Empty => Ok(Bytes::new()),
+        AsyncBody::Bytes(bs) => Ok(bs),
+        AsyncBody::Stream(mut s) => {
+            let mut buf = BytesMut::new();
+            while let Some(bs) = s.next().await {
+                buf.extend_from_slice(&bs?);
+            }
+            Ok(buf.freeze())
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for TeeUploader {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let bs = buffer_body(body).await?;
+        self.inner
+            .write_once(size, AsyncBody::Bytes(bs.clone()))
+            .await?;
+        self.mirror(bs).await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        self.inner.initiate_part().await
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let bs = buffer_body(body).await?;
+        let part = self
+            .inner
+            .write_part(upload_id, part_number, size, AsyncBody::Bytes(bs.clone()))
+            .await?;
+        self.parts
+            .lock()
+            .expect("lock must succeed")
+            .insert(part_number, bs);
+        Ok(part)
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        self.inner.complete_part(upload_id, parts).await?;
+
+        let bs = {
+            let uploaded = self.parts.lock().expect("lock must succeed");
+            let mut buf = BytesMut::new();
+            for part in parts {
+                if let Some(bs) = uploaded.get(&part.part_number) {
+                    buf.extend_from_slice(bs);
+                }
+            }
+            buf.freeze()
+        };
+        self.mirror(bs).await
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.inner.abort_part(upload_id).await?;
+        self.parts.lock().expect("lock must succeed").clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// AbortHook records whether the writer has been aborted.
+    #[derive(Debug, Clone, Default)]
+    struct AbortHook {
+        aborted: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl LayerHook for AbortHook {
+        async fn write<A: Accessor>(
+            &self,
+            inner: &A,
+            path: &str,
+            args: OpWrite,
+        ) -> Result<(RpWrite, oio::Writer)> {
+            let (rp, w) = inner.write(path, args).await?;
+            Ok((
+                rp,
+                Box::new(AbortWrapper {
+                    inner: Box::new(w),
+                    aborted: self.aborted.clone(),
+                }),
+            ))
+        }
+    }
+
+    struct AbortWrapper {
+        inner: oio::Writer,
+        aborted: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl oio::Write for AbortWrapper {
+        async fn write(&mut self, bs: Bytes) -> Result<()> {
+            self.inner.write(bs).await
+        }
+
+        async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+            self.inner.sink(size, s).await
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            self.aborted.store(true, Ordering::SeqCst);
+            self.inner.abort().await
+        }
+
+        async fn close(&mut self) -> Result<()> {
+            self.inner.close().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tee() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        let op = Operator::new(Memory::default())?
+            .layer(TeeLayer::new(secondary.clone()))
+            .finish();
+
+        op.write("file", "Hello, World!").await?;
+        op.create_dir("dir/").await?;
+        op.rename("file", "renamed").await?;
+        op.delete("renamed").await?;
+        op.write("file", "Hello, OpenDAL!").await?;
+
+        let mut w = op.writer("large").await?;
+        w.write("Hello, ").await?;
+        w.write("World!").await?;
+        w.close().await?;
+
+        for op in [&op, &secondary] {
+            assert_eq!(op.read("file").await?, b"Hello, OpenDAL!");
+            assert_eq!(op.read("large").await?, b"Hello, World!");
+            assert!(op.is_exist("dir/").await?);
+            assert!(!op.is_exist("renamed").await?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tee_failure_policy() -> Result<()> {
+        let secondary = Operator::new(Memory::default())?.finish();
+        secondary.write("file", "old").await?;
+
+        // Secondary fails since the file exists.
+        let op = Operator::new(Memory::default())?
+            .layer(TeeLayer::new(secondary.clone()))
+            .finish();
+        let err = op
+            .write_with("file", "new")
+            .if_not_exists(true)
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        // The opened primary writer will be aborted.
+        let hook = AbortHook::default();
+        let op = Operator::new(Memory::default())?
+            .layer(HookLayer(hook.clone()))
+            .layer(TeeLayer::new(secondary.clone()))
+            .finish();
+        let err = op
+            .writer_with("file")
+            .if_not_exists(true)
+            .await
+            .map(|_| ())
+            .expect_err("writer must fail");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert!(hook.aborted.load(Ordering::SeqCst));
+
+        let op = Operator::new(Memory::default())?
+            .layer(TeeLayer::new(secondary.clone()).with_failure_policy(TeeFailurePolicy::Ignore))
+            .finish();
+        op.write_with("file", "new").if_not_exists(true).await?;
+        assert_eq!(op.read("file").await?, b"new");
+        assert_eq!(secondary.read("file").await?, b"old");
+        Ok(())
+    }
+}
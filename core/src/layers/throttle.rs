@@ -256,6 +256,10 @@ impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
         self.inner.sink(size, s).await
     }
 
+    async fn sink_unsized(&mut self, s: Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::future::Future;
 use std::io::SeekFrom;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
@@ -23,8 +27,10 @@ use std::time::Instant;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::StreamExt;
 
 use crate::raw::oio::AppendOperation;
+use crate::raw::oio::MultipartUploadOperation;
 use crate::raw::oio::PageOperation;
 use crate::raw::oio::ReadOperation;
 use crate::raw::oio::WriteOperation;
@@ -191,6 +197,26 @@ impl<A: Accessor> LayeredAccessor for TimeoutAccessor<A> {
             .map(|(rp, r)| (rp, TimeoutWrapper::new(r, self.timeout, self.speed)))
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        tokio::time::timeout(self.timeout, self.inner.multipart(path, args))
+            .await
+            .map_err(|_| {
+                Error::new(ErrorKind::Unexpected, "operation timeout")
+                    .with_operation(Operation::Write)
+                    .with_context("timeout", self.timeout.as_secs_f64().to_string())
+                    .set_temporary()
+            })?
+            .map(|(rp, u)| {
+                let u: oio::MultipartUploader =
+                    Box::new(TimeoutWrapper::new(u, self.timeout, self.speed));
+                (rp, u)
+            })
+    }
+
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
         tokio::time::timeout(self.timeout, self.inner.list(path, args))
             .await
@@ -362,6 +388,36 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
             })?
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        // Size of stream is unknown, only time out if no chunk has been
+        // polled from the stream during the timeout.
+        let timeout = self.timeout;
+        let polled = Arc::new(AtomicU64::new(0));
+        let counter = polled.clone();
+        let s = s.inspect(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let fut = self.inner.sink_unsized(Box::new(oio::into_stream(s)));
+        futures::pin_mut!(fut);
+        let mut last = 0;
+        loop {
+            match tokio::time::timeout(timeout, &mut fut).await {
+                Ok(res) => return res,
+                Err(_) => {
+                    let current = polled.load(Ordering::Relaxed);
+                    if current == last {
+                        return Err(Error::new(ErrorKind::Unexpected, "operation timeout")
+                            .with_operation(WriteOperation::SinkUnsized)
+                            .with_context("timeout", timeout.as_secs_f64().to_string())
+                            .set_temporary());
+                    }
+                    last = current;
+                }
+            }
+        }
+    }
+
     async fn abort(&mut self) -> Result<()> {
         tokio::time::timeout(self.timeout, self.inner.abort())
             .await
@@ -412,6 +468,80 @@ impl<A: oio::Append> oio::Append for TimeoutWrapper<A> {
     }
 }
 
+impl TimeoutWrapper<oio::MultipartUploader> {
+    async fn with_timeout<T>(
+        &self,
+        timeout: Duration,
+        op: MultipartUploadOperation,
+        fut: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        tokio::time::timeout(timeout, fut).await.map_err(|_| {
+            Error::new(ErrorKind::Unexpected, "operation timeout")
+                .with_operation(op)
+                .with_context("timeout", timeout.as_secs_f64().to_string())
+                .set_temporary()
+        })?
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for TimeoutWrapper<oio::MultipartUploader> {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        self.with_timeout(
+            self.io_timeout(size),
+            MultipartUploadOperation::WriteOnce,
+            self.inner.write_once(size, body),
+        )
+        .await
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        self.with_timeout(
+            self.timeout,
+            MultipartUploadOperation::InitiatePart,
+            self.inner.initiate_part(),
+        )
+        .await
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        self.with_timeout(
+            self.io_timeout(size),
+            MultipartUploadOperation::WritePart,
+            self.inner.write_part(upload_id, part_number, size, body),
+        )
+        .await
+    }
+
+    async fn complete_part(
+        &self,
+        upload_id: &str,
+        parts: &[oio::MultipartUploadPart],
+    ) -> Result<()> {
+        self.with_timeout(
+            self.timeout,
+            MultipartUploadOperation::CompletePart,
+            self.inner.complete_part(upload_id, parts),
+        )
+        .await
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        self.with_timeout(
+            self.timeout,
+            MultipartUploadOperation::AbortPart,
+            self.inner.abort_part(upload_id),
+        )
+        .await
+    }
+}
+
 #[async_trait]
 impl<R: oio::Page> oio::Page for TimeoutWrapper<R> {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
@@ -424,4 +554,8 @@ impl<R: oio::Page> oio::Page for TimeoutWrapper<R> {
                     .set_temporary()
             })?
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
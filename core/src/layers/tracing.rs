@@ -16,7 +16,9 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::fmt::Formatter;
 use std::io;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 
@@ -42,7 +44,27 @@ use crate::*;
 ///
 /// let _ = Operator::new(services::Memory::default())
 ///     .expect("must init")
-///     .layer(TracingLayer)
+///     .layer(TracingLayer::default())
+///     .finish();
+/// ```
+///
+/// ## Custom span fields
+///
+/// Extra fields can be attached to the spans of `read`, `write` and `stat`
+/// via [`TracingLayer::with_span_fields`]. The closure is called for every
+/// operation, so it can pull values from task-local context as well.
+///
+/// ```
+/// use opendal::layers::TracingLayer;
+/// use opendal::services;
+/// use opendal::Operator;
+///
+/// let _ = Operator::new(services::Memory::default())
+///     .expect("must init")
+///     .layer(
+///         TracingLayer::default()
+///             .with_span_fields(|_, _| vec![("tenant", "tenant-a".to_string())]),
+///     )
 ///     .finish();
 /// ```
 ///
@@ -79,7 +101,7 @@ use crate::*;
 ///         let _ = dotenvy::dotenv();
 ///         let op = Operator::from_env::<services::S3>()
 ///             .expect("init operator must succeed")
-///             .layer(TracingLayer)
+///             .layer(TracingLayer::default())
 ///             .finish();
 ///
 ///         op.object("test")
@@ -115,19 +137,76 @@ use crate::*;
 /// ```
 ///
 /// For real-world usage, please take a look at [`tracing-opentelemetry`](https://crates.io/crates/tracing-opentelemetry).
-pub struct TracingLayer;
+#[derive(Default, Clone)]
+pub struct TracingLayer {
+    span_fields: Option<Arc<SpanFieldsFn>>,
+}
+
+type SpanFieldsFn = dyn Fn(Operation, &str) -> Vec<(&'static str, String)> + Send + Sync;
+
+impl TracingLayer {
+    /// Attach extra fields to the spans emitted for `read`, `write` and `stat`.
+    ///
+    /// The closure receives the operation and path, and returns key/value
+    /// pairs. Since `tracing` requires span fields to be declared ahead, all
+    /// pairs are recorded as `key=value` into the `fields` field of the span.
+    pub fn with_span_fields(
+        mut self,
+        f: impl Fn(Operation, &str) -> Vec<(&'static str, String)> + Send + Sync + 'static,
+    ) -> Self {
+        self.span_fields = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Debug for TracingLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracingLayer")
+            .field("span_fields", &self.span_fields.is_some())
+            .finish()
+    }
+}
 
 impl<A: Accessor> Layer<A> for TracingLayer {
     type LayeredAccessor = TracingAccessor<A>;
 
     fn layer(&self, inner: A) -> Self::LayeredAccessor {
-        TracingAccessor { inner }
+        TracingAccessor {
+            inner,
+            span_fields: self.span_fields.clone(),
+        }
     }
 }
 
-#[derive(Debug)]
 pub struct TracingAccessor<A> {
     inner: A,
+    span_fields: Option<Arc<SpanFieldsFn>>,
+}
+
+impl<A: Debug> Debug for TracingAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracingAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> TracingAccessor<A> {
+    /// Record user defined fields into current span.
+    fn record_span_fields(&self, op: Operation, path: &str) {
+        let Some(f) = &self.span_fields else {
+            return;
+        };
+
+        let fields = f(op, path)
+            .into_iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !fields.is_empty() {
+            Span::current().record("fields", fields.as_str());
+        }
+    }
 }
 
 #[async_trait]
@@ -155,16 +234,20 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.create_dir(path, args).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.record_span_fields(Operation::Read, path);
+
         self.inner
             .read(path, args)
             .map(|v| v.map(|(rp, r)| (rp, TracingWrapper::new(Span::current(), r))))
             .await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.record_span_fields(Operation::Write, path);
+
         self.inner
             .write(path, args)
             .await
@@ -185,8 +268,10 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner().rename(from, to, args).await
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.record_span_fields(Operation::Stat, path);
+
         self.inner.stat(path, args).await
     }
 
@@ -218,15 +303,19 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.blocking_create_dir(path, args)
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.record_span_fields(Operation::BlockingRead, path);
+
         self.inner
             .blocking_read(path, args)
             .map(|(rp, r)| (rp, TracingWrapper::new(Span::current(), r)))
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.record_span_fields(Operation::BlockingWrite, path);
+
         self.inner
             .blocking_write(path, args)
             .map(|(rp, r)| (rp, TracingWrapper::new(Span::current(), r)))
@@ -242,8 +331,10 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner().blocking_rename(from, to, args)
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self), fields(fields = tracing::field::Empty))]
     fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.record_span_fields(Operation::BlockingStat, path);
+
         self.inner.blocking_stat(path, args)
     }
 
@@ -258,6 +349,11 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
             .blocking_list(path, args)
             .map(|(rp, it)| (rp, TracingWrapper::new(Span::current(), it)))
     }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.inner.blocking_presign(path, args)
+    }
 }
 
 pub struct TracingWrapper<R> {
@@ -341,6 +437,10 @@ impl<R: oio::Write> oio::Write for TracingWrapper<R> {
         self.inner.sink(size, s).await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        self.inner.sink_unsized(s).await
+    }
+
     #[tracing::instrument(
         parent = &self.span,
         level = "trace",
@@ -382,6 +482,10 @@ impl<R: oio::Page> oio::Page for TracingWrapper<R> {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
         self.inner.next().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.inner.continuation_token()
+    }
 }
 
 impl<R: oio::BlockingPage> oio::BlockingPage for TracingWrapper<R> {
@@ -390,3 +494,99 @@ impl<R: oio::BlockingPage> oio::BlockingPage for TracingWrapper<R> {
         self.inner.next()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use tracing::field::Field;
+    use tracing::field::Visit;
+    use tracing::span::Attributes;
+    use tracing::span::Id;
+    use tracing::span::Record;
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::Context as LayerContext;
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::registry::LookupSpan;
+
+    use super::*;
+    use crate::services::Memory;
+
+    /// Capture `(span name, field name, value)` of every span.
+    #[derive(Clone, Default)]
+    struct SpanCapture {
+        fields: Arc<Mutex<Vec<(String, String, String)>>>,
+    }
+
+    struct CaptureVisitor<'a> {
+        span: &'a str,
+        fields: &'a Mutex<Vec<(String, String, String)>>,
+    }
+
+    impl Visit for CaptureVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields.lock().unwrap().push((
+                self.span.to_string(),
+                field.name().to_string(),
+                format!("{value:?}"),
+            ));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.lock().unwrap().push((
+                self.span.to_string(),
+                field.name().to_string(),
+                value.to_string(),
+            ));
+        }
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> tracing_subscriber::Layer<S> for SpanCapture {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: LayerContext<'_, S>) {
+            attrs.record(&mut CaptureVisitor {
+                span: attrs.metadata().name(),
+                fields: &self.fields,
+            });
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+            let span = ctx.span(id).expect("span must exist");
+            values.record(&mut CaptureVisitor {
+                span: span.name(),
+                fields: &self.fields,
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tracing_span_fields() {
+        let capture = SpanCapture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let op = Operator::new(Memory::default())
+            .unwrap()
+            .layer(TracingLayer::default().with_span_fields(|op, path| {
+                vec![
+                    ("tenant", "tenant-a".to_string()),
+                    ("op", format!("{op}:{path}")),
+                ]
+            }))
+            .finish();
+
+        op.write("test", "Hello, World!").await.unwrap();
+        op.stat("test").await.unwrap();
+        op.read("test").await.unwrap();
+
+        let fields = capture.fields.lock().unwrap().clone();
+        for (span, op) in [("write", "write"), ("stat", "stat"), ("read", "read")] {
+            let value = format!("tenant=tenant-a op={op}:test");
+            assert!(
+                fields
+                    .iter()
+                    .any(|(s, f, v)| s == span && f == "fields" && v == &value),
+                "span {span} must contain custom fields, got: {fields:?}"
+            );
+        }
+    }
+}
@@ -88,9 +88,9 @@ mod tests {
     /// unexpected struct/enum size change.
     #[test]
     fn assert_size() {
-        assert_eq!(24, size_of::<Operator>());
-        assert_eq!(264, size_of::<Entry>());
-        assert_eq!(240, size_of::<Metadata>());
+        assert_eq!(32, size_of::<Operator>());
+        assert_eq!(304, size_of::<Entry>());
+        assert_eq!(280, size_of::<Metadata>());
         assert_eq!(1, size_of::<EntryMode>());
         assert_eq!(24, size_of::<Scheme>());
     }
@@ -102,6 +102,7 @@ mod tests {
     impl AssertSendSync for Error {}
     impl AssertSendSync for Reader {}
     impl AssertSendSync for Writer {}
+    impl AssertSendSync for MultipartWriter {}
     impl AssertSendSync for Lister {}
     impl AssertSendSync for Operator {}
     impl AssertSendSync for BlockingReader {}
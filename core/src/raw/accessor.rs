@@ -211,6 +211,8 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
     ///
     /// - `delete` is an idempotent operation, it's safe to call `Delete` on the same path multiple times.
     /// - `delete` SHOULD return `Ok(())` if the path is deleted successfully or not exist.
+    /// - `NotFound` returned by services will be ignored by `CompleteLayer` so
+    ///   that users always get the same semantics as AWS S3.
     async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let (_, _) = (path, args);
 
@@ -253,6 +255,28 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `multipart` operation on the specified path.
+    ///
+    /// Require [`Capability::write_can_multi`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Returned uploader will not initiate the upload, callers should
+    ///   call `initiate_part` by themselves.
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `batch` operations.
     ///
     /// Require [`Capability::batch`]
@@ -380,6 +404,20 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
             "operation is not supported",
         ))
     }
+
+    /// Invoke the `blocking_presign` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::presign`]
+    ///
+    /// Require [`Capability::presign`] and [`Capability::blocking`]
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
 }
 
 /// Dummy implementation of accessor.
@@ -399,6 +437,7 @@ impl Accessor for () {
             root: "".to_string(),
             name: "dummy".to_string(),
             capability: Capability::default(),
+            checksum_algorithm: None,
         }
     }
 }
@@ -460,6 +499,14 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().presign(path, args).await
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        self.as_ref().multipart(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.as_ref().blocking_create_dir(path, args)
     }
@@ -487,6 +534,9 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         self.as_ref().blocking_list(path, args)
     }
+    fn blocking_presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        self.as_ref().blocking_presign(path, args)
+    }
 }
 
 /// FusedAccessor is the type erased accessor with `Arc<dyn Accessor>`.
@@ -510,6 +560,7 @@ pub struct AccessorInfo {
     name: String,
 
     capability: Capability,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
 }
 
 impl AccessorInfo {
@@ -568,4 +619,15 @@ impl AccessorInfo {
         self.capability = capability;
         self
     }
+
+    /// Get the default checksum algorithm used by backend's writes.
+    pub fn checksum_algorithm(&self) -> Option<ChecksumAlgorithm> {
+        self.checksum_algorithm
+    }
+
+    /// Set the default checksum algorithm used by backend's writes.
+    pub fn set_checksum_algorithm(&mut self, algorithm: ChecksumAlgorithm) -> &mut Self {
+        self.checksum_algorithm = Some(algorithm);
+        self
+    }
 }
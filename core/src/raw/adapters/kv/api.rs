@@ -18,9 +18,11 @@
 use std::fmt::Debug;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 
 use crate::raw::*;
 use crate::Capability;
+use crate::EntryMode;
 use crate::Error;
 use crate::ErrorKind;
 use crate::Result;
@@ -50,6 +52,25 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_get"))
     }
 
+    /// Get the metadata of a key from service.
+    ///
+    /// - return `Ok(None)` if this key is not exist.
+    ///
+    /// By default, the metadata is built from the value returned by `get`.
+    /// Services that store metadata of keys could return them directly.
+    async fn stat(&self, path: &str) -> Result<Option<crate::Metadata>> {
+        let bs = self.get(path).await?;
+
+        Ok(bs.map(|bs| crate::Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64)))
+    }
+
+    /// The blocking version of stat.
+    fn blocking_stat(&self, path: &str) -> Result<Option<crate::Metadata>> {
+        let bs = self.blocking_get(path)?;
+
+        Ok(bs.map(|bs| crate::Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64)))
+    }
+
     /// Set a key into service.
     async fn set(&self, path: &str, value: &[u8]) -> Result<()>;
 
@@ -82,6 +103,24 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_delete"))
     }
 
+    /// Rename a key atomically in service.
+    ///
+    /// - return `NotFound` error if the source key is not exist.
+    /// - the destination key will be overwritten if it's exist.
+    ///
+    /// Services without native rename should keep the default
+    /// implementation, then the backend will fall back to `get`, `set`
+    /// and `delete`, which is not atomic.
+    async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let _ = (from, to);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::rename"))
+    }
+
     /// Scan a key prefix to get all keys that start with this key.
     async fn scan(&self, path: &str) -> Result<Vec<String>> {
         let _ = path;
@@ -105,6 +144,22 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_scan"))
     }
 
+    /// Commit a batch of mutations into service.
+    ///
+    /// Services that support transactions should commit all mutations
+    /// atomically, the behavior of splitting must be documented.
+    ///
+    /// Require [`Capability::batch_write`].
+    async fn batch(&self, mutations: Vec<(String, Mutation)>) -> Result<()> {
+        let _ = mutations;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::batch"))
+    }
+
     /// Append a key into service
     async fn append(&self, path: &str, value: &[u8]) -> Result<()> {
         let _ = path;
@@ -131,6 +186,15 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     }
 }
 
+/// Mutation of a key that committed by [`Adapter::batch`].
+#[derive(Debug, Clone)]
+pub enum Mutation {
+    /// Set the key with given value.
+    Set(Bytes),
+    /// Delete the key.
+    Delete,
+}
+
 /// Metadata for this key value accessor.
 pub struct Metadata {
     scheme: Scheme,
@@ -21,6 +21,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 
 use super::Adapter;
+use super::Mutation;
 use crate::raw::*;
 use crate::*;
 
@@ -95,6 +96,11 @@ impl<S: Adapter> Accessor for Backend<S> {
             cap.list_without_delimiter = true;
         }
 
+        if cap.batch_write {
+            cap.batch = true;
+            cap.batch_delete = true;
+        }
+
         am
     }
 
@@ -169,11 +175,8 @@ impl<S: Adapter> Accessor for Backend<S> {
         if p.is_empty() || p.ends_with('/') {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.kv.get(&p).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+            match self.kv.stat(&p).await? {
+                Some(meta) => Ok(RpStat::new(meta)),
                 None => Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
             }
         }
@@ -185,11 +188,8 @@ impl<S: Adapter> Accessor for Backend<S> {
         if p.is_empty() || p.ends_with('/') {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            let bs = self.kv.blocking_get(&p)?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+            match self.kv.blocking_stat(&p)? {
+                Some(meta) => Ok(RpStat::new(meta)),
                 None => Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
             }
         }
@@ -243,6 +243,11 @@ impl<S: Adapter> Accessor for Backend<S> {
         let from = build_abs_path(&self.root, from);
         let to = build_abs_path(&self.root, to);
 
+        match self.kv.rename(&from, &to).await {
+            Err(err) if err.kind() == ErrorKind::Unsupported => {}
+            res => return res.map(|_| RpRename::default()),
+        }
+
         let bs = match self.kv.get(&from).await? {
             // TODO: we can reuse the metadata in value to build content range.
             Some(bs) => bs,
@@ -300,6 +305,31 @@ impl<S: Adapter> Accessor for Backend<S> {
 
         Ok(RpCopy::default())
     }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = args.into_operation();
+
+        let mut mutations = Vec::with_capacity(ops.len());
+        let mut results = Vec::with_capacity(ops.len());
+        for (path, op) in ops {
+            let p = build_abs_path(&self.root, &path);
+            match op {
+                BatchOperation::Delete(_) => {
+                    mutations.push((p, Mutation::Delete));
+                    results.push((path, Ok(RpDelete::default().into())));
+                }
+                BatchOperation::Write(_, bs) => {
+                    mutations.push((p, Mutation::Set(bs)));
+                    results.push((path, Ok(RpWrite::default().into())));
+                }
+            }
+        }
+
+        // The batch is committed as a whole, any error will fail all operations.
+        self.kv.batch(mutations).await?;
+
+        Ok(RpBatch::new(results))
+    }
 }
 
 impl<S> Backend<S>
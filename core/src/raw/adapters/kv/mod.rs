@@ -22,6 +22,7 @@
 mod api;
 pub use api::Adapter;
 pub use api::Metadata;
+pub use api::Mutation;
 
 mod backend;
 pub use backend::Backend;
@@ -87,6 +87,19 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         )
         .with_operation("typed_kv::Adapter::blocking_scan"))
     }
+
+    /// Append bytes to the end of the value, the value will be created
+    /// if not exist.
+    async fn append(&self, path: &str, value: Bytes) -> Result<()> {
+        let _ = path;
+        let _ = value;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "typed_kv adapter doesn't support this operation",
+        )
+        .with_operation("typed_kv::Adapter::append"))
+    }
 }
 
 /// Value is the typed value stored in adapter.
@@ -129,6 +142,8 @@ pub struct Capability {
     pub delete: bool,
     /// If typed_kv operator supports scan natively, it will be true.
     pub scan: bool,
+    /// If typed_kv operator supports append natively, it will be true.
+    pub append: bool,
 }
 
 impl Debug for Capability {
@@ -147,6 +162,9 @@ impl Debug for Capability {
         if self.scan {
             s.push("Scan");
         }
+        if self.append {
+            s.push("Append");
+        }
 
         write!(f, "{{ {} }}", s.join(" | "))
     }
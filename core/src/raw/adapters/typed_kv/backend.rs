@@ -50,6 +50,11 @@ where
         self.root = normalize_root(root);
         self
     }
+
+    /// Get the adapter of this backend.
+    pub(crate) fn adapter(&self) -> &S {
+        &self.kv
+    }
 }
 
 #[async_trait]
@@ -58,7 +63,7 @@ impl<S: Adapter> Accessor for Backend<S> {
     type BlockingReader = oio::Cursor;
     type Writer = KvWriter<S>;
     type BlockingWriter = KvWriter<S>;
-    type Appender = ();
+    type Appender = KvAppender<S>;
     type Pager = KvPager;
     type BlockingPager = KvPager;
 
@@ -92,6 +97,10 @@ impl<S: Adapter> Accessor for Backend<S> {
             cap.list_without_delimiter = true;
         }
 
+        if kv_cap.append {
+            cap.append = true;
+        }
+
         if cap.read && cap.write {
             cap.copy = true;
         }
@@ -158,6 +167,12 @@ impl<S: Adapter> Accessor for Backend<S> {
         Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p, args)))
     }
 
+    async fn append(&self, path: &str, _: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        let p = build_abs_path(&self.root, path);
+
+        Ok((RpAppend::new(), KvAppender::new(self.kv.clone(), p)))
+    }
+
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = build_abs_path(&self.root, path);
 
@@ -440,3 +455,25 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
         Ok(())
     }
 }
+
+pub struct KvAppender<S> {
+    kv: Arc<S>,
+    path: String,
+}
+
+impl<S> KvAppender<S> {
+    fn new(kv: Arc<S>, path: String) -> Self {
+        KvAppender { kv, path }
+    }
+}
+
+#[async_trait]
+impl<S: Adapter> oio::Append for KvAppender<S> {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        self.kv.append(&self.path, bs).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
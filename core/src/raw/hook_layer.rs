@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::*;
+
+/// LayerHook intercepts the async operations of [`HookLayer`] in tests.
+///
+/// All hooks forward to the inner accessor by default, so tests only need
+/// to override the operations they care about instead of implementing a
+/// whole [`LayeredAccessor`].
+#[async_trait]
+pub(crate) trait LayerHook: Debug + Send + Sync + Unpin + 'static {
+    async fn read<A: Accessor>(
+        &self,
+        inner: &A,
+        path: &str,
+        args: OpRead,
+    ) -> Result<(RpRead, oio::Reader)> {
+        let (rp, r) = inner.read(path, args).await?;
+        Ok((rp, Box::new(r) as oio::Reader))
+    }
+
+    async fn write<A: Accessor>(
+        &self,
+        inner: &A,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::Writer)> {
+        let (rp, w) = inner.write(path, args).await?;
+        Ok((rp, Box::new(w) as oio::Writer))
+    }
+
+    async fn stat<A: Accessor>(&self, inner: &A, path: &str, args: OpStat) -> Result<RpStat> {
+        inner.stat(path, args).await
+    }
+
+    async fn list<A: Accessor>(
+        &self,
+        inner: &A,
+        path: &str,
+        args: OpList,
+    ) -> Result<(RpList, A::Pager)> {
+        inner.list(path, args).await
+    }
+
+    async fn copy<A: Accessor>(
+        &self,
+        inner: &A,
+        from: &str,
+        to: &str,
+        args: OpCopy,
+    ) -> Result<RpCopy> {
+        inner.copy(from, to, args).await
+    }
+
+    async fn delete<A: Accessor>(&self, inner: &A, path: &str, args: OpDelete) -> Result<RpDelete> {
+        inner.delete(path, args).await
+    }
+}
+
+/// HookLayer is a test layer that calls [`LayerHook`] for every async
+/// operation and forwards blocking operations as is.
+#[derive(Debug, Clone)]
+pub(crate) struct HookLayer<H>(pub H);
+
+impl<A: Accessor, H: LayerHook + Clone> Layer<A> for HookLayer<H> {
+    type LayeredAccessor = HookAccessor<A, H>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        HookAccessor {
+            inner,
+            hook: self.0.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct HookAccessor<A, H> {
+    inner: A,
+    hook: H,
+}
+
+#[async_trait]
+impl<A: Accessor, H: LayerHook> LayeredAccessor for HookAccessor<A, H> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = oio::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Appender = A::Appender;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.hook.read(&self.inner, path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.hook.write(&self.inner, path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.hook.stat(&self.inner, path, args).await
+    }
+
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.hook.list(&self.inner, path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        self.hook.copy(&self.inner, from, to, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.hook.delete(&self.inner, path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
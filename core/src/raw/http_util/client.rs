@@ -18,11 +18,21 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::mem;
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
 
+use futures::FutureExt;
 use futures::TryStreamExt;
+use http::header::USER_AGENT;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
+use hyper::client::connect::dns::Name;
+use reqwest::dns::Resolve;
+use reqwest::dns::Resolving;
 
 use super::body::IncomingAsyncBody;
 use super::parse_content_length;
@@ -32,10 +42,22 @@ use crate::Error;
 use crate::ErrorKind;
 use crate::Result;
 
+/// The user agent that set by [`HttpClient::set_default_user_agent`].
+static DEFAULT_USER_AGENT: RwLock<Option<String>> = RwLock::new(None);
+
+type RequestInterceptor = Arc<dyn Fn(&mut Request<AsyncBody>) + Send + Sync>;
+type ResponseInterceptor = Arc<dyn Fn(&Response<IncomingAsyncBody>) + Send + Sync>;
+
 /// HttpClient that used across opendal.
+///
+/// All requests sent by HttpClient will carry a `User-Agent` like
+/// `opendal/{version}` if it's not set by services.
 #[derive(Clone)]
 pub struct HttpClient {
     client: reqwest::Client,
+    user_agent: HeaderValue,
+    request_interceptor: Option<RequestInterceptor>,
+    response_interceptor: Option<ResponseInterceptor>,
 }
 
 /// We don't want users to know details about our clients.
@@ -51,8 +73,21 @@ impl HttpClient {
         Self::build(reqwest::ClientBuilder::new())
     }
 
+    /// Create a new http client in async context with given connect options.
+    pub fn with_connect_options(options: HttpConnectOptions) -> Result<Self> {
+        Self::build_with_connect_options(reqwest::ClientBuilder::new(), options)
+    }
+
     /// Build a new http client in async context.
-    pub fn build(mut builder: reqwest::ClientBuilder) -> Result<Self> {
+    pub fn build(builder: reqwest::ClientBuilder) -> Result<Self> {
+        Self::build_with_connect_options(builder, HttpConnectOptions::default())
+    }
+
+    /// Build a new http client in async context with given connect options.
+    pub fn build_with_connect_options(
+        mut builder: reqwest::ClientBuilder,
+        options: HttpConnectOptions,
+    ) -> Result<Self> {
         // Make sure we don't enable auto gzip decompress.
         builder = builder.no_gzip();
         // Make sure we don't enable auto brotli decompress.
@@ -61,28 +96,107 @@ impl HttpClient {
         builder = builder.no_deflate();
 
         #[cfg(feature = "trust-dns")]
-        let builder = builder.trust_dns(true);
+        let mut builder = builder.trust_dns(true);
+
+        if let Some(timeout) = options.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if options.address_family != HttpAddressFamily::Any {
+            builder = builder.dns_resolver(Arc::new(AddressFamilyResolver(options.address_family)));
+        }
+
+        let mut user_agent = format!("opendal/{VERSION}");
+        if let Some(v) = DEFAULT_USER_AGENT
+            .read()
+            .expect("lock must be valid")
+            .as_deref()
+        {
+            user_agent.push(' ');
+            user_agent.push_str(v);
+        }
 
         Ok(Self {
             client: builder.build().map_err(|err| {
                 Error::new(ErrorKind::Unexpected, "async client build failed").set_source(err)
             })?,
+            user_agent: parse_user_agent(&user_agent)?,
+            request_interceptor: None,
+            response_interceptor: None,
         })
     }
 
+    /// Set the default user agent for all http clients that built later.
+    ///
+    /// The user agent will be appended to OpenDAL's user agent instead of
+    /// replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn set_default_user_agent(user_agent: &str) -> Result<()> {
+        parse_user_agent(user_agent)?;
+
+        *DEFAULT_USER_AGENT.write().expect("lock must be valid") = Some(user_agent.to_string());
+        Ok(())
+    }
+
+    /// Append the user agent to the user agent of this client.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Result<Self> {
+        let v = format!(
+            "{} {user_agent}",
+            self.user_agent.to_str().expect("user agent must be valid")
+        );
+        self.user_agent = parse_user_agent(&v)?;
+        Ok(self)
+    }
+
+    /// Get the user agent of this client.
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.to_str().expect("user agent must be valid")
+    }
+
+    /// Set the interceptor that will be called on every request before
+    /// it's sent.
+    ///
+    /// # Notes
+    ///
+    /// Requests have been signed by services before reaching here, changing
+    /// signed parts like uri or `x-amz-*` headers will break the signature.
+    pub fn with_request_interceptor(
+        mut self,
+        f: impl Fn(&mut Request<AsyncBody>) + Send + Sync + 'static,
+    ) -> Self {
+        self.request_interceptor = Some(Arc::new(f));
+        self
+    }
+
+    /// Set the interceptor that will be called on every response before
+    /// it's returned to services.
+    pub fn with_response_interceptor(
+        mut self,
+        f: impl Fn(&Response<IncomingAsyncBody>) + Send + Sync + 'static,
+    ) -> Self {
+        self.response_interceptor = Some(Arc::new(f));
+        self
+    }
+
     /// Get the async client from http client.
     pub fn client(&self) -> reqwest::Client {
         self.client.clone()
     }
 
     /// Send a request in async way.
-    pub async fn send(&self, req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
+    pub async fn send(&self, mut req: Request<AsyncBody>) -> Result<Response<IncomingAsyncBody>> {
+        if let Some(f) = &self.request_interceptor {
+            f(&mut req);
+        }
+
         // Uri stores all string alike data in `Bytes` which means
         // the clone here is cheap.
         let uri = req.uri().clone();
         let is_head = req.method() == http::Method::HEAD;
 
-        let (parts, body) = req.into_parts();
+        let (mut parts, body) = req.into_parts();
+        parts
+            .headers
+            .entry(USER_AGENT)
+            .or_insert_with(|| self.user_agent.clone());
 
         let mut req_builder = self
             .client
@@ -154,6 +268,240 @@ impl HttpClient {
 
         let resp = hr.body(body).expect("response must build succeed");
 
+        if let Some(f) = &self.response_interceptor {
+            f(&resp);
+        }
+
         Ok(resp)
     }
 }
+
+/// The address family that HttpClient uses to connect to services.
+///
+/// Connections will race between addresses of both families if both of
+/// them are allowed (a.k.a. Happy Eyeballs), preferring the first resolved
+/// address.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HttpAddressFamily {
+    /// Use addresses in the order returned by resolver.
+    #[default]
+    Any,
+    /// Only connect to IPv4 addresses.
+    Ipv4Only,
+    /// Only connect to IPv6 addresses.
+    Ipv6Only,
+    /// Try IPv4 addresses first and fall back to IPv6.
+    PreferIpv4,
+    /// Try IPv6 addresses first and fall back to IPv4.
+    PreferIpv6,
+}
+
+impl HttpAddressFamily {
+    /// Filter and sort addresses by this address family.
+    fn apply(self, addrs: impl Iterator<Item = SocketAddr>) -> Vec<SocketAddr> {
+        let mut addrs: Vec<_> = match self {
+            HttpAddressFamily::Ipv4Only => addrs.filter(|v| v.is_ipv4()).collect(),
+            HttpAddressFamily::Ipv6Only => addrs.filter(|v| v.is_ipv6()).collect(),
+            _ => addrs.collect(),
+        };
+        match self {
+            HttpAddressFamily::PreferIpv4 => addrs.sort_by_key(|v| v.is_ipv6()),
+            HttpAddressFamily::PreferIpv6 => addrs.sort_by_key(|v| v.is_ipv4()),
+            _ => {}
+        }
+        addrs
+    }
+}
+
+/// Options that control how HttpClient connects to services.
+///
+/// The default options keep the behavior of `reqwest`.
+#[derive(Debug, Clone, Default)]
+pub struct HttpConnectOptions {
+    address_family: HttpAddressFamily,
+    connect_timeout: Option<Duration>,
+}
+
+impl HttpConnectOptions {
+    /// Create a new connect options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the address family that used to connect.
+    ///
+    /// # Notes
+    ///
+    /// Addresses will be resolved by system resolver instead of trust-dns
+    /// if address family is not [`HttpAddressFamily::Any`].
+    pub fn with_address_family(mut self, address_family: HttpAddressFamily) -> Self {
+        self.address_family = address_family;
+        self
+    }
+
+    /// Get the address family that used to connect.
+    pub fn address_family(&self) -> HttpAddressFamily {
+        self.address_family
+    }
+
+    /// Set the timeout of connecting to services.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout of connecting to services.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+}
+
+/// AddressFamilyResolver resolves addresses via system resolver and
+/// filters them by given address family.
+struct AddressFamilyResolver(HttpAddressFamily);
+
+impl Resolve for AddressFamilyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let family = self.0;
+        let host = name.as_str().to_string();
+        async move {
+            // Port will be replaced by the connector, just use 0 here.
+            let addrs = tokio::net::lookup_host((host.as_str(), 0)).await?;
+            let addrs = family.apply(addrs);
+            if addrs.is_empty() {
+                return Err(format!("no address of {family:?} found for {host}").into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Box<dyn Iterator<Item = SocketAddr> + Send>)
+        }
+        .boxed()
+    }
+}
+
+fn parse_user_agent(user_agent: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(user_agent).map_err(|err| {
+        Error::new(ErrorKind::ConfigInvalid, "user agent is invalid")
+            .with_context("user_agent", user_agent)
+            .set_source(err)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_user_agent() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header(
+                "user-agent",
+                format!("opendal/{VERSION} my-app/1.0").as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new()?.with_user_agent("my-app/1.0")?;
+        assert_eq!(client.user_agent(), format!("opendal/{VERSION} my-app/1.0"));
+
+        let req = Request::get(mock_server.uri())
+            .body(AsyncBody::Empty)
+            .expect("request must be valid");
+        let resp = client.send(req).await?;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        assert!(HttpClient::new()?.with_user_agent("invalid\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_address_family_apply() {
+        let v4: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let v6: SocketAddr = "[::1]:0".parse().unwrap();
+        let addrs = || vec![v6, v4].into_iter();
+
+        assert_eq!(HttpAddressFamily::Any.apply(addrs()), vec![v6, v4]);
+        assert_eq!(HttpAddressFamily::Ipv4Only.apply(addrs()), vec![v4]);
+        assert_eq!(
+            HttpAddressFamily::Ipv6Only.apply(vec![v4].into_iter()),
+            vec![]
+        );
+        assert_eq!(HttpAddressFamily::PreferIpv4.apply(addrs()), vec![v4, v6]);
+        assert_eq!(
+            HttpAddressFamily::PreferIpv6.apply(vec![v4, v6].into_iter()),
+            vec![v6, v4]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_address_family() -> Result<()> {
+        // MockServer only listens on 127.0.0.1.
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        let uri = format!("http://localhost:{}", mock_server.address().port());
+
+        let client = HttpClient::with_connect_options(
+            HttpConnectOptions::new()
+                .with_address_family(HttpAddressFamily::Ipv4Only)
+                .with_connect_timeout(Duration::from_secs(3)),
+        )?;
+        let req = Request::get(&uri)
+            .body(AsyncBody::Empty)
+            .expect("request must be valid");
+        let resp = client.send(req).await?;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let client = HttpClient::with_connect_options(
+            HttpConnectOptions::new().with_address_family(HttpAddressFamily::Ipv6Only),
+        )?;
+        let req = Request::get(&uri)
+            .body(AsyncBody::Empty)
+            .expect("request must be valid");
+        assert!(client.send(req).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interceptor() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("x-tenant", "tenant-a"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-request-id", "abc"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request_id = Arc::new(std::sync::Mutex::new(None));
+        let request_id_cloned = request_id.clone();
+        let client = HttpClient::new()?
+            .with_request_interceptor(|req| {
+                req.headers_mut()
+                    .insert("x-tenant", HeaderValue::from_static("tenant-a"));
+            })
+            .with_response_interceptor(move |resp| {
+                *request_id_cloned.lock().unwrap() = resp
+                    .headers()
+                    .get("x-request-id")
+                    .map(|v| v.to_str().unwrap().to_string());
+            });
+
+        let req = Request::get(mock_server.uri())
+            .body(AsyncBody::Empty)
+            .expect("request must be valid");
+        let resp = client.send(req).await?;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(request_id.lock().unwrap().as_deref(), Some("abc"));
+        Ok(())
+    }
+}
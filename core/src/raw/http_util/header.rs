@@ -184,6 +184,45 @@ pub fn parse_etag(headers: &HeaderMap) -> Result<Option<&str>> {
     }
 }
 
+/// Normalize etag into its canonical form, returns whether it's weak and
+/// the opaque value without quotes.
+///
+/// - `"33a64df5"` => `(false, "33a64df5")`
+/// - `W/"0815"` => `(true, "0815")`
+/// - `33a64df5` => `(false, "33a64df5")`
+pub fn normalize_etag(v: &str) -> (bool, &str) {
+    let v = v.trim();
+    let (weak, v) = match v.strip_prefix("W/") {
+        Some(v) => (true, v),
+        None => (false, v),
+    };
+
+    let v = v
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(v);
+    (weak, v)
+}
+
+/// Format etag into the form that used in conditional headers like
+/// `If-Match` and `If-None-Match`.
+///
+/// Both quoted and unquoted etag are accepted, `*` will be kept as is.
+///
+/// - `33a64df5` => `"33a64df5"`
+/// - `"33a64df5"` => `"33a64df5"`
+/// - `W/"0815"` => `W/"0815"`
+pub fn format_etag(v: &str) -> String {
+    if v.trim() == "*" {
+        return "*".to_string();
+    }
+
+    match normalize_etag(v) {
+        (true, v) => format!("W/\"{v}\""),
+        (false, v) => format!("\"{v}\""),
+    }
+}
+
 /// Parse Content-Disposition for header map
 pub fn parse_content_disposition(headers: &HeaderMap) -> Result<Option<&str>> {
     match headers.get(CONTENT_DISPOSITION) {
@@ -257,6 +296,23 @@ pub fn format_content_md5(bs: &[u8]) -> String {
     general_purpose::STANDARD.encode(hasher.finalize())
 }
 
+/// format content md5 header by given async body.
+///
+/// # Errors
+///
+/// Streaming body can't be read twice, so it's content md5 can't be
+/// calculated ahead and function will return an unsupported error.
+pub fn format_content_md5_of_body(body: &AsyncBody) -> Result<String> {
+    match body {
+        AsyncBody::Empty => Ok(format_content_md5(&[])),
+        AsyncBody::Bytes(bs) => Ok(format_content_md5(bs)),
+        AsyncBody::Stream(_) => Err(Error::new(
+            ErrorKind::Unsupported,
+            "content md5 can't be calculated for streaming body",
+        )),
+    }
+}
+
 /// format authorization header by basic auth.
 ///
 /// # Errors
@@ -305,6 +361,8 @@ pub fn build_header_value(v: &str) -> Result<HeaderValue> {
 
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
+
     use super::*;
 
     /// Test cases is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html
@@ -329,6 +387,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize_etag() {
+        let cases = vec![
+            ("\"33a64df5\"", (false, "33a64df5")),
+            ("33a64df5", (false, "33a64df5")),
+            ("W/\"0815\"", (true, "0815")),
+            (" \"abc\" ", (false, "abc")),
+            ("\"\"", (false, "")),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(normalize_etag(input), expected, "{input}")
+        }
+    }
+
+    #[test]
+    fn test_format_etag() {
+        let cases = vec![
+            ("33a64df5", "\"33a64df5\""),
+            ("\"33a64df5\"", "\"33a64df5\""),
+            ("W/\"0815\"", "W/\"0815\""),
+            ("*", "*"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(format_etag(input), expected, "{input}")
+        }
+    }
+
+    #[test]
+    fn test_format_content_md5_of_body() {
+        assert_eq!(
+            format_content_md5_of_body(&AsyncBody::Empty).unwrap(),
+            "1B2M2Y8AsgTpgAmY7PhCfg=="
+        );
+        assert_eq!(
+            format_content_md5_of_body(&AsyncBody::Bytes(Bytes::from("Hello, World!"))).unwrap(),
+            format_content_md5(b"Hello, World!")
+        );
+
+        let stream = oio::into_stream(futures::stream::empty());
+        let err = format_content_md5_of_body(&AsyncBody::Stream(Box::new(stream))).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
     /// Test cases is borrowed from
     ///
     /// - RFC2617: https://datatracker.ietf.org/doc/html/rfc2617#section-2
@@ -23,7 +23,9 @@
 //! it easier to develop services and layers outside opendal.
 
 mod client;
+pub use client::HttpAddressFamily;
 pub use client::HttpClient;
+pub use client::HttpConnectOptions;
 
 mod body;
 pub use body::AsyncBody;
@@ -34,6 +36,9 @@ pub use header::build_header_value;
 pub use header::format_authorization_by_basic;
 pub use header::format_authorization_by_bearer;
 pub use header::format_content_md5;
+pub use header::format_content_md5_of_body;
+pub use header::format_etag;
+pub use header::normalize_etag;
 pub use header::parse_content_disposition;
 pub use header::parse_content_length;
 pub use header::parse_content_md5;
@@ -32,6 +32,11 @@ pub use accessor::*;
 mod layer;
 pub use layer::*;
 
+#[cfg(test)]
+mod hook_layer;
+#[cfg(test)]
+pub(crate) use hook_layer::*;
+
 mod path;
 pub use path::*;
 
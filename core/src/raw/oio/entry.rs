@@ -49,6 +49,22 @@ impl Entry {
         Entry { path, meta }
     }
 
+    /// Create a new dir entry grouped by given delimiter while listing.
+    ///
+    /// Dirs grouped by custom delimiter end with the delimiter instead of
+    /// `/`, this is the only case that dirs don't end with `/`.
+    pub fn new_delimited_dir(path: &str, delimiter: &str) -> Entry {
+        debug_assert!(
+            path.ends_with(delimiter),
+            "dir {path} not end with delimiter {delimiter}"
+        );
+
+        Entry {
+            path: path.to_string(),
+            meta: Metadata::new(EntryMode::DIR),
+        }
+    }
+
     /// Set path for entry.
     pub fn set_path(&mut self, path: &str) -> &mut Self {
         self.path = path.to_string();
@@ -66,6 +66,15 @@ pub trait Page: Send + Sync + 'static {
     /// `Ok(None)` means all pages have been returned. Any following call
     /// to `next` will always get the same result.
     async fn next(&mut self) -> Result<Option<Vec<Entry>>>;
+
+    /// Return the opaque token to continue listing after the pages that
+    /// have been returned.
+    ///
+    /// Only services with native continuation return token, and `None`
+    /// will be returned if there are no more pages.
+    fn continuation_token(&self) -> Option<String> {
+        None
+    }
 }
 
 /// The boxed version of [`Page`]
@@ -76,6 +85,10 @@ impl Page for Pager {
     async fn next(&mut self) -> Result<Option<Vec<Entry>>> {
         self.as_mut().next().await
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.as_ref().continuation_token()
+    }
 }
 
 #[async_trait]
@@ -93,6 +106,10 @@ impl<P: Page> Page for Option<P> {
             None => Ok(None),
         }
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        self.as_ref().and_then(|p| p.continuation_token())
+    }
 }
 
 /// BlockingPage is the blocking version of [`Page`].
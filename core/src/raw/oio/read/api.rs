@@ -381,6 +381,7 @@ fn format_io_error(err: Error) -> io::Error {
         ErrorKind::NotFound => io::ErrorKind::NotFound,
         ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
         ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+        ErrorKind::ChecksumMismatch => io::ErrorKind::InvalidData,
         _ => io::ErrorKind::Interrupted,
     };
 
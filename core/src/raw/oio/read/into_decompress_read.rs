@@ -0,0 +1,223 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::bufread::BzDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::XzDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use bytes::Bytes;
+use futures::io::BufReader;
+use futures::AsyncRead;
+
+use crate::raw::*;
+use crate::*;
+
+/// The size of chunk returned by [`DecompressReader`]'s `poll_next`.
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// CompressAlgorithm is the algorithm used to compress content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressAlgorithm {
+    /// [bzip2](https://sourceware.org/bzip2/) with extension `.bz2`.
+    Bz2,
+    /// [gzip](https://www.gnu.org/software/gzip/) with extension `.gz`.
+    Gzip,
+    /// [xz](https://tukaani.org/xz/) with extension `.xz`.
+    Xz,
+    /// [zstd](https://github.com/facebook/zstd) with extension `.zst`.
+    Zstd,
+}
+
+impl CompressAlgorithm {
+    /// Detect the compress algorithm from the extension of path.
+    ///
+    /// Returns `None` if the extension is unknown.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, ext) = path.rsplit_once('.')?;
+
+        match ext.to_ascii_lowercase().as_str() {
+            "bz2" => Some(CompressAlgorithm::Bz2),
+            "gz" | "gzip" => Some(CompressAlgorithm::Gzip),
+            "xz" => Some(CompressAlgorithm::Xz),
+            "zst" | "zstd" => Some(CompressAlgorithm::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// into_decompress_read will decompress the content of given [`oio::Reader`]
+/// with the specified algorithm.
+pub fn into_decompress_read(r: oio::Reader, algo: CompressAlgorithm) -> DecompressReader {
+    let r = BufReader::new(r);
+    let decoder = match algo {
+        CompressAlgorithm::Bz2 => Decoder::Bz2(BzDecoder::new(r)),
+        CompressAlgorithm::Gzip => Decoder::Gzip(GzipDecoder::new(r)),
+        CompressAlgorithm::Xz => Decoder::Xz(XzDecoder::new(r)),
+        CompressAlgorithm::Zstd => Decoder::Zstd(ZstdDecoder::new(r)),
+    };
+
+    DecompressReader {
+        decoder,
+        buf: Vec::new(),
+    }
+}
+
+enum Decoder {
+    Bz2(BzDecoder<BufReader<oio::Reader>>),
+    Gzip(GzipDecoder<BufReader<oio::Reader>>),
+    Xz(XzDecoder<BufReader<oio::Reader>>),
+    Zstd(ZstdDecoder<BufReader<oio::Reader>>),
+}
+
+/// DecompressReader decompresses content while reading.
+///
+/// The decompressed content's length is unknown, so seek is not supported.
+pub struct DecompressReader {
+    decoder: Decoder,
+    buf: Vec<u8>,
+}
+
+impl DecompressReader {
+    fn poll_decode(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        match &mut self.decoder {
+            Decoder::Bz2(d) => Pin::new(d).poll_read(cx, buf),
+            Decoder::Gzip(d) => Pin::new(d).poll_read(cx, buf),
+            Decoder::Xz(d) => Pin::new(d).poll_read(cx, buf),
+            Decoder::Zstd(d) => Pin::new(d).poll_read(cx, buf),
+        }
+    }
+}
+
+impl oio::Read for DecompressReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        self.poll_decode(cx, buf).map_err(parse_decompress_error)
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let (_, _) = (cx, pos);
+
+        Poll::Ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "decompress reader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let mut buf = std::mem::take(&mut self.buf);
+        buf.resize(DEFAULT_CHUNK_SIZE, 0);
+        let res = self.poll_decode(cx, &mut buf);
+        self.buf = buf;
+
+        match ready!(res) {
+            Ok(0) => Poll::Ready(None),
+            Ok(n) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(&self.buf[..n])))),
+            Err(err) => Poll::Ready(Some(Err(parse_decompress_error(err)))),
+        }
+    }
+}
+
+/// Errors from underlying reader will be returned as is, others are
+/// treated as corrupted content.
+fn parse_decompress_error(err: io::Error) -> Error {
+    if err.get_ref().map(|v| v.is::<Error>()).unwrap_or_default() {
+        return err
+            .into_inner()
+            .and_then(|v| v.downcast::<Error>().ok())
+            .map(|v| *v)
+            .expect("inner error must be opendal error");
+    }
+
+    Error::new(ErrorKind::Unexpected, "decompress content failed").set_source(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_compression::futures::bufread::GzipEncoder;
+    use async_compression::futures::bufread::ZstdEncoder;
+    use futures::AsyncReadExt;
+
+    use super::*;
+
+    #[test]
+    fn test_compress_algorithm_from_path() {
+        let cases = vec![
+            ("data.csv.gz", Some(CompressAlgorithm::Gzip)),
+            ("data.csv.GZ", Some(CompressAlgorithm::Gzip)),
+            ("dir.gz/data", None),
+            ("data.zst", Some(CompressAlgorithm::Zstd)),
+            ("data.bz2", Some(CompressAlgorithm::Bz2)),
+            ("data.xz", Some(CompressAlgorithm::Xz)),
+            ("data.csv", None),
+            ("data", None),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(CompressAlgorithm::from_path(path), expected, "{path}")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_into_decompress_read() {
+        use oio::ReadExt;
+
+        let content = "Hello, OpenDAL!".repeat(1024);
+
+        let mut gz = vec![];
+        GzipEncoder::new(content.as_bytes())
+            .read_to_end(&mut gz)
+            .await
+            .expect("compress must succeed");
+        let mut zst = vec![];
+        ZstdEncoder::new(content.as_bytes())
+            .read_to_end(&mut zst)
+            .await
+            .expect("compress must succeed");
+
+        for (algo, compressed) in [
+            (CompressAlgorithm::Gzip, gz),
+            (CompressAlgorithm::Zstd, zst),
+        ] {
+            let r = Box::new(oio::Cursor::from(compressed)) as oio::Reader;
+            let mut r = into_decompress_read(r, algo);
+
+            let mut buf = vec![];
+            while let Some(bs) = r.next().await {
+                buf.extend_from_slice(&bs.expect("read must succeed"));
+            }
+            assert_eq!(buf, content.as_bytes(), "{algo:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_into_decompress_read_invalid() {
+        use oio::ReadExt;
+
+        let r = Box::new(oio::Cursor::from(b"not gzip".to_vec())) as oio::Reader;
+        let mut r = into_decompress_read(r, CompressAlgorithm::Gzip);
+
+        let mut buf = vec![0; 16];
+        let err = r.read(&mut buf).await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
+}
@@ -0,0 +1,117 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::SeekFrom;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// into_progress_read is used to report the progress of given [`oio::Read`].
+pub fn into_progress_read<R>(
+    r: R,
+    callback: ProgressCallback,
+    total: Option<u64>,
+) -> ProgressReader<R> {
+    ProgressReader {
+        r,
+        callback,
+        total,
+        transferred: 0,
+    }
+}
+
+/// Report the progress after every chunk has been read.
+pub struct ProgressReader<R> {
+    r: R,
+    callback: ProgressCallback,
+    total: Option<u64>,
+    transferred: u64,
+}
+
+impl<R> ProgressReader<R> {
+    fn report(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        self.transferred += n as u64;
+        self.callback.call(self.transferred, self.total);
+    }
+}
+
+impl<R: oio::Read> oio::Read for ProgressReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.r.poll_read(cx, buf))?;
+        self.report(n);
+
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        self.r.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let res = ready!(self.r.poll_next(cx));
+        if let Some(Ok(bs)) = &res {
+            self.report(bs.len());
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_into_progress_read() {
+        use oio::ReadExt;
+
+        let reports = Arc::new(Mutex::new(vec![]));
+        let callback = {
+            let reports = reports.clone();
+            ProgressCallback::new(move |transferred, total| {
+                reports.lock().unwrap().push((transferred, total))
+            })
+        };
+
+        let r = oio::Cursor::from(vec![0; 10]);
+        let mut r = into_progress_read(Box::new(r) as oio::Reader, callback, Some(10));
+
+        let mut buf = vec![0; 4];
+        loop {
+            let n = r.read(&mut buf).await.expect("read must succeed");
+            if n == 0 {
+                break;
+            }
+        }
+        assert_eq!(
+            *reports.lock().unwrap(),
+            vec![(4, Some(10)), (8, Some(10)), (10, Some(10))]
+        );
+    }
+}
@@ -29,6 +29,10 @@ use futures::future::BoxFuture;
 use crate::raw::*;
 use crate::*;
 
+/// The max times to request the missing data again while services return
+/// less data than requested.
+const MAX_INCOMPLETE_RETRIES: usize = 3;
+
 /// Convert given reader into [`oio::Reader`] by range.
 ///
 /// # Input
@@ -43,6 +47,10 @@ use crate::*;
 ///
 /// This operation is not zero cost. If the accessor already returns a
 /// seekable reader, please don't use this.
+///
+/// If the reader is consumed before reaching the end of requested range,
+/// the missing data will be requested again. `ContentIncomplete` will be
+/// returned if services keep returning incomplete data.
 pub fn into_seekable_read_by_range<A: Accessor, R>(
     acc: Arc<A>,
     path: &str,
@@ -56,8 +64,12 @@ pub fn into_seekable_read_by_range<A: Accessor, R>(
         offset,
         size,
         cur: 0,
+        end: size,
         state: State::Reading(reader),
         last_seek_pos: None,
+        op: OpRead::default(),
+        chunk: None,
+        incomplete_retries: 0,
     }
 }
 
@@ -69,6 +81,8 @@ pub struct ByRangeSeekableReader<A: Accessor, R> {
     offset: u64,
     size: u64,
     cur: u64,
+    /// The end position of current range read request.
+    end: u64,
     state: State<R>,
 
     /// Seek operation could return Pending which may lead
@@ -77,6 +91,15 @@ pub struct ByRangeSeekableReader<A: Accessor, R> {
     /// So we need to store the last seek pos to make sure
     /// we always seek to the right position.
     last_seek_pos: Option<u64>,
+
+    /// The args used to send range read requests.
+    op: OpRead,
+    /// The max size of every range read request.
+    ///
+    /// If not set, we will read all remaining data in one request.
+    chunk: Option<usize>,
+    /// The times that the missing data has been requested again.
+    incomplete_retries: usize,
 }
 
 enum State<R> {
@@ -92,6 +115,61 @@ impl<A, R> ByRangeSeekableReader<A, R>
 where
     A: Accessor,
 {
+    /// Set the args used to send range read requests, the range of args
+    /// will be ignored.
+    pub fn with_op(mut self, op: OpRead) -> Self {
+        self.op = op;
+        self
+    }
+
+    /// Set the max size of every range read request.
+    ///
+    /// Reader will send a new request for the next chunk after the
+    /// current one has been consumed.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Build the args for next range read request.
+    fn next_op(&mut self) -> OpRead {
+        let remaining = self.size - self.cur;
+        let size = match self.chunk {
+            Some(chunk) => remaining.min(chunk as u64),
+            None => remaining,
+        };
+        self.end = self.cur + size;
+
+        self.op
+            .clone()
+            .with_range(BytesRange::new(Some(self.offset + self.cur), Some(size)))
+    }
+
+    /// Check the current reader after it has been consumed.
+    ///
+    /// Services could return less data than requested without any error,
+    /// we will request the missing data again until retries are exhausted.
+    fn check_incomplete(&mut self) -> Result<()> {
+        if self.cur >= self.end {
+            return Ok(());
+        }
+
+        if self.incomplete_retries >= MAX_INCOMPLETE_RETRIES {
+            return Err(Error::new(
+                ErrorKind::ContentIncomplete,
+                &format!(
+                    "reader got too less data, expect: {}, actual: {}",
+                    self.offset + self.end,
+                    self.offset + self.cur
+                ),
+            )
+            .with_context("path", &self.path));
+        }
+
+        self.incomplete_retries += 1;
+        Ok(())
+    }
+
     /// calculate the seek position.
     ///
     /// This operation will not update the `self.cur`.
@@ -124,13 +202,10 @@ where
     A: Accessor<Reader = R>,
     R: oio::Read,
 {
-    fn read_future(&self) -> BoxFuture<'static, Result<(RpRead, R)>> {
+    fn read_future(&mut self) -> BoxFuture<'static, Result<(RpRead, R)>> {
         let acc = self.acc.clone();
         let path = self.path.clone();
-        let op = OpRead::default().with_range(BytesRange::new(
-            Some(self.offset + self.cur),
-            Some(self.size - self.cur),
-        ));
+        let op = self.next_op();
 
         Box::pin(async move { acc.read(&path, op).await })
     }
@@ -141,13 +216,10 @@ where
     A: Accessor<BlockingReader = R>,
     R: oio::BlockingRead,
 {
-    fn read_action(&self) -> Result<(RpRead, R)> {
+    fn read_action(&mut self) -> Result<(RpRead, R)> {
         let acc = self.acc.clone();
         let path = self.path.clone();
-        let op = OpRead::default().with_range(BytesRange::new(
-            Some(self.offset + self.cur),
-            Some(self.size - self.cur),
-        ));
+        let op = self.next_op();
 
         acc.blocking_read(&path, op)
     }
@@ -159,6 +231,10 @@ where
     R: oio::Read,
 {
     fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
         match &mut self.state {
             State::Idle => {
                 if self.cur >= self.size {
@@ -186,10 +262,16 @@ where
                 Ok(n) if n == 0 => {
                     // Reset state to Idle after all data has been consumed.
                     self.state = State::Idle;
+                    self.check_incomplete()?;
+                    // Read the next chunk or the missing data.
+                    if self.cur < self.size {
+                        return self.poll_read(cx, buf);
+                    }
                     Poll::Ready(Ok(0))
                 }
                 Ok(n) => {
                     self.cur += n as u64;
+                    self.incomplete_retries = 0;
                     Poll::Ready(Ok(n))
                 }
                 Err(e) => {
@@ -260,6 +342,7 @@ where
             State::Reading(r) => match ready!(Pin::new(r).poll_next(cx)) {
                 Some(Ok(bs)) => {
                     self.cur += bs.len() as u64;
+                    self.incomplete_retries = 0;
                     Poll::Ready(Some(Ok(bs)))
                 }
                 Some(Err(err)) => {
@@ -268,6 +351,11 @@ where
                 }
                 None => {
                     self.state = State::Idle;
+                    self.check_incomplete()?;
+                    // Read the next chunk or the missing data.
+                    if self.cur < self.size {
+                        return self.poll_next(cx);
+                    }
                     Poll::Ready(None)
                 }
             },
@@ -281,6 +369,10 @@ where
     R: oio::BlockingRead,
 {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
         match &mut self.state {
             State::Idle => {
                 if self.cur >= self.size {
@@ -296,10 +388,16 @@ where
                     Ok(n) if n == 0 => {
                         // Reset state to Idle after all data has been consumed.
                         self.state = State::Idle;
+                        self.check_incomplete()?;
+                        // Read the next chunk or the missing data.
+                        if self.cur < self.size {
+                            return self.read(buf);
+                        }
                         Ok(0)
                     }
                     Ok(n) => {
                         self.cur += n as u64;
+                        self.incomplete_retries = 0;
                         Ok(n)
                     }
                     Err(e) => {
@@ -354,6 +452,7 @@ where
             State::Reading(r) => match r.next() {
                 Some(Ok(bs)) => {
                     self.cur += bs.len() as u64;
+                    self.incomplete_retries = 0;
                     Some(Ok(bs))
                 }
                 Some(Err(err)) => {
@@ -362,6 +461,13 @@ where
                 }
                 None => {
                     self.state = State::Idle;
+                    if let Err(err) = self.check_incomplete() {
+                        return Some(Err(err));
+                    }
+                    // Read the next chunk or the missing data.
+                    if self.cur < self.size {
+                        return self.next();
+                    }
                     None
                 }
             },
@@ -375,6 +481,8 @@ where
 #[cfg(test)]
 mod tests {
     use std::io::SeekFrom;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
 
     use async_trait::async_trait;
     use bytes::Bytes;
@@ -401,11 +509,22 @@ mod tests {
     #[derive(Debug, Clone, Default)]
     struct MockReadService {
         data: Bytes,
+        /// The number of following requests that will return empty body
+        /// without any error.
+        empty_reads: Arc<AtomicUsize>,
     }
 
     impl MockReadService {
         fn new(data: Bytes) -> Self {
-            Self { data }
+            Self {
+                data,
+                ..Default::default()
+            }
+        }
+
+        fn with_empty_reads(self, n: usize) -> Self {
+            self.empty_reads.store(n, Ordering::SeqCst);
+            self
         }
     }
 
@@ -430,7 +549,14 @@ mod tests {
         }
 
         async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-            let bs = args.range().apply_on_bytes(self.data.clone());
+            let mut bs = args.range().apply_on_bytes(self.data.clone());
+            let empty = self
+                .empty_reads
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if empty {
+                bs = Bytes::new();
+            }
 
             Ok((
                 RpRead::new(bs.len() as u64),
@@ -567,4 +693,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_incomplete() -> anyhow::Result<()> {
+        let (bs, _) = gen_bytes();
+        let acc = Arc::new(MockReadService::new(bs.clone()).with_empty_reads(2));
+
+        // The first reader returns less data than expected, and the next
+        // two requests for the missing data return nothing.
+        let r = MockReader {
+            inner: futures::io::Cursor::new(bs[..4096].to_vec()),
+        };
+        let mut r =
+            Box::new(into_seekable_read_by_range(acc, "x", r, 0, bs.len() as u64)) as oio::Reader;
+
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf).await?;
+        assert_eq!(bs.len(), buf.len(), "read size");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs)),
+            format!("{:x}", Sha256::digest(&buf)),
+            "read content"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_next_incomplete() -> anyhow::Result<()> {
+        use oio::ReadExt;
+
+        let (bs, _) = gen_bytes();
+        let acc = Arc::new(MockReadService::new(bs.clone()).with_empty_reads(1));
+
+        let r = MockReader {
+            inner: futures::io::Cursor::new(bs[..4096].to_vec()),
+        };
+        let mut r =
+            Box::new(into_seekable_read_by_range(acc, "x", r, 0, bs.len() as u64)) as oio::Reader;
+
+        let mut buf = Vec::new();
+        while let Some(chunk) = r.next().await {
+            buf.extend_from_slice(&chunk?);
+        }
+        assert_eq!(bs.len(), buf.len(), "read size");
+        assert_eq!(
+            format!("{:x}", Sha256::digest(&bs)),
+            format!("{:x}", Sha256::digest(&buf)),
+            "read content"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_incomplete_exhausted() -> anyhow::Result<()> {
+        let (bs, _) = gen_bytes();
+        let acc = Arc::new(MockReadService::new(bs.clone()).with_empty_reads(usize::MAX));
+
+        let r = MockReader {
+            inner: futures::io::Cursor::new(bs[..4096].to_vec()),
+        };
+        let mut r =
+            Box::new(into_seekable_read_by_range(acc, "x", r, 0, bs.len() as u64)) as oio::Reader;
+
+        let mut buf = Vec::new();
+        let err = r
+            .read_to_end(&mut buf)
+            .await
+            .expect_err("read must fail after retries exhausted");
+        let err = err
+            .into_inner()
+            .and_then(|v| v.downcast::<Error>().ok())
+            .expect("inner error must be opendal error");
+        assert_eq!(err.kind(), ErrorKind::ContentIncomplete);
+        assert_eq!(buf.len(), 4096);
+
+        Ok(())
+    }
 }
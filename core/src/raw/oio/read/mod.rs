@@ -34,3 +34,16 @@ pub use into_seekable_read_by_range::ByRangeSeekableReader;
 mod into_read_from_file;
 pub use into_read_from_file::into_read_from_file;
 pub use into_read_from_file::FromFileReader;
+
+mod into_progress_read;
+pub use into_progress_read::into_progress_read;
+pub use into_progress_read::ProgressReader;
+
+#[cfg(feature = "compress")]
+mod into_decompress_read;
+#[cfg(feature = "compress")]
+pub use into_decompress_read::into_decompress_read;
+#[cfg(feature = "compress")]
+pub use into_decompress_read::CompressAlgorithm;
+#[cfg(feature = "compress")]
+pub use into_decompress_read::DecompressReader;
@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::StreamExt;
 
 use crate::raw::*;
 use crate::*;
@@ -32,6 +33,8 @@ pub enum WriteOperation {
     Write,
     /// Operation for [`Write::sink`]
     Sink,
+    /// Operation for [`Write::sink_unsized`]
+    SinkUnsized,
     /// Operation for [`Write::abort`]
     Abort,
     /// Operation for [`Write::close`]
@@ -62,6 +65,7 @@ impl From<WriteOperation> for &'static str {
         match v {
             Write => "Writer::write",
             Sink => "Writer::sink",
+            SinkUnsized => "Writer::sink_unsized",
             Abort => "Writer::abort",
             Close => "Writer::close",
             BlockingWrite => "BlockingWriter::write",
@@ -100,6 +104,20 @@ pub trait Write: Unpin + Send + Sync {
     /// Sink given stream into writer.
     async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()>;
 
+    /// Sink given stream of unknown size into writer.
+    ///
+    /// The default implementation writes every chunk of stream by
+    /// [`Write::write`], so that services based on multipart upload only
+    /// buffer one part in memory. Services that can stream the whole body
+    /// in one request (like chunked transfer encoding) should override it.
+    async fn sink_unsized(&mut self, mut s: oio::Streamer) -> Result<()> {
+        while let Some(bs) = s.next().await {
+            self.write(bs?).await?;
+        }
+
+        Ok(())
+    }
+
     /// Abort the pending writer.
     async fn abort(&mut self) -> Result<()>;
 
@@ -150,6 +168,10 @@ impl<T: Write + ?Sized> Write for Box<T> {
         (**self).sink(n, s).await
     }
 
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        (**self).sink_unsized(s).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         (**self).abort().await
     }
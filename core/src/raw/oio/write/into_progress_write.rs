@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// into_progress_write is used to report the progress of given [`oio::Write`].
+pub fn into_progress_write<W>(
+    w: W,
+    callback: ProgressCallback,
+    total: Option<u64>,
+) -> ProgressWriter<W> {
+    ProgressWriter {
+        w,
+        callback,
+        total,
+        transferred: 0,
+    }
+}
+
+/// Report the progress after every chunk has been written.
+pub struct ProgressWriter<W> {
+    w: W,
+    callback: ProgressCallback,
+    total: Option<u64>,
+    transferred: u64,
+}
+
+impl<W> ProgressWriter<W> {
+    fn report(&mut self, n: u64) {
+        self.transferred += n;
+        self.callback.call(self.transferred, self.total);
+    }
+}
+
+#[async_trait]
+impl<W: oio::Write> oio::Write for ProgressWriter<W> {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let n = bs.len() as u64;
+        self.w.write(bs).await?;
+        self.report(n);
+
+        Ok(())
+    }
+
+    async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.w.sink(size, s).await?;
+        self.report(size);
+
+        Ok(())
+    }
+
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        let s = ProgressStream {
+            s,
+            callback: self.callback.clone(),
+            total: self.total,
+            transferred: self.transferred,
+        };
+        self.w.sink_unsized(Box::new(s)).await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.w.abort().await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.w.close().await
+    }
+}
+
+/// Report the progress after every chunk has been polled from the stream,
+/// since the size of stream is unknown until it ends.
+struct ProgressStream {
+    s: oio::Streamer,
+    callback: ProgressCallback,
+    total: Option<u64>,
+    transferred: u64,
+}
+
+impl oio::Stream for ProgressStream {
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        let res = ready!(self.s.poll_next(cx));
+        if let Some(Ok(bs)) = &res {
+            self.transferred += bs.len() as u64;
+            self.callback.call(self.transferred, self.total);
+        }
+        Poll::Ready(res)
+    }
+}
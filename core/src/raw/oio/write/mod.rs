@@ -23,6 +23,12 @@ pub use api::WriteOperation;
 pub use api::Writer;
 
 mod multipart_upload_write;
+pub use multipart_upload_write::MultipartUploadOperation;
 pub use multipart_upload_write::MultipartUploadPart;
 pub use multipart_upload_write::MultipartUploadWrite;
 pub use multipart_upload_write::MultipartUploadWriter;
+pub use multipart_upload_write::MultipartUploader;
+
+mod into_progress_write;
+pub use into_progress_write::into_progress_write;
+pub use into_progress_write::ProgressWriter;
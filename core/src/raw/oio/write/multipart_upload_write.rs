@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use async_trait::async_trait;
 use bytes::Bytes;
 
@@ -22,6 +25,11 @@ use crate::raw::*;
 use crate::*;
 
 const DEFAULT_WRITE_MIN_SIZE: usize = 8 * 1024 * 1024;
+/// The max number of parts in one multipart upload, which is shared by
+/// most services like s3, oss and cos.
+const MAX_PARTS: u64 = 10000;
+/// Part size will be aligned to MiB while it's calculated from total size.
+const PART_SIZE_ALIGNMENT: u64 = 1024 * 1024;
 
 /// MultipartUploadWrite is used to implement [`Write`] based on multipart
 /// uploads. By implementing MultipartUploadWrite, services don't need to
@@ -72,6 +80,52 @@ pub trait MultipartUploadWrite: Send + Sync + Unpin {
     async fn abort_part(&self, upload_id: &str) -> Result<()>;
 }
 
+/// MultipartUploader is a type erased [`MultipartUploadWrite`].
+pub type MultipartUploader = Box<dyn MultipartUploadWrite>;
+
+/// MultipartUploadOperation is the name for APIs of [`MultipartUploadWrite`].
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MultipartUploadOperation {
+    /// Operation for [`MultipartUploadWrite::write_once`]
+    WriteOnce,
+    /// Operation for [`MultipartUploadWrite::initiate_part`]
+    InitiatePart,
+    /// Operation for [`MultipartUploadWrite::write_part`]
+    WritePart,
+    /// Operation for [`MultipartUploadWrite::complete_part`]
+    CompletePart,
+    /// Operation for [`MultipartUploadWrite::abort_part`]
+    AbortPart,
+}
+
+impl MultipartUploadOperation {
+    /// Convert self into static str.
+    pub fn into_static(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for MultipartUploadOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_static())
+    }
+}
+
+impl From<MultipartUploadOperation> for &'static str {
+    fn from(v: MultipartUploadOperation) -> &'static str {
+        use MultipartUploadOperation::*;
+
+        match v {
+            WriteOnce => "MultipartUploader::write_once",
+            InitiatePart => "MultipartUploader::initiate_part",
+            WritePart => "MultipartUploader::write_part",
+            CompletePart => "MultipartUploader::complete_part",
+            AbortPart => "MultipartUploader::abort_part",
+        }
+    }
+}
+
 /// The result of [`MultipartUploadWrite::write_part`].
 ///
 /// services implement should convert MultipartUploadPart to their own represents.
@@ -83,23 +137,48 @@ pub struct MultipartUploadPart {
     pub part_number: usize,
     /// The etag of the part.
     pub etag: String,
+    /// The checksum of the part, if services calculate checksums for parts.
+    pub checksum: Option<String>,
 }
 
 /// MultipartUploadWriter will implements [`Write`] based on multipart
 /// uploads.
 ///
+/// ## Cancel safety
+///
+/// All operations are cancel safe: the upload will only be completed in
+/// `close`, and dropping the writer will never complete it. A `write` that
+/// has been cancelled while uploading a part is treated as not happened,
+/// so users can retry it with the same data.
+///
+/// ## Part size
+///
+/// The size of parts is decided by `write_min_size`, `write_memory_budget`
+/// and the total size of data if it's known:
+///
+/// - Data that is known to be no larger than the memory budget will be
+///   buffered and uploaded by `write_once` instead of multipart uploads.
+/// - Parts will be as large as the memory budget allows, but never smaller
+///   than `write_min_size`.
+/// - If the total size is known, parts will be large enough to keep the
+///   number of parts no more than 10000, even if it exceeds the budget.
+///
 /// ## TODO
 ///
-/// - Add threshold for `write_once` to avoid unnecessary multipart uploads.
 /// - Allow users to switch to un-buffered mode if users write 16MiB every time.
 pub struct MultipartUploadWriter<W: MultipartUploadWrite> {
     inner: W,
     total_size: Option<u64>,
+    write_min_size: usize,
+    write_memory_budget: Option<usize>,
 
     upload_id: Option<String>,
     parts: Vec<MultipartUploadPart>,
     buffer: oio::VectorCursor,
     buffer_size: usize,
+    /// Set while `write` is uploading a part that includes the newly
+    /// pushed bytes, so we can drop them if that `write` has been cancelled.
+    cancelled_write: bool,
 }
 
 impl<W: MultipartUploadWrite> MultipartUploadWriter<W> {
@@ -108,11 +187,14 @@ impl<W: MultipartUploadWrite> MultipartUploadWriter<W> {
         Self {
             inner,
             total_size,
+            write_min_size: DEFAULT_WRITE_MIN_SIZE,
+            write_memory_budget: None,
 
             upload_id: None,
             parts: Vec::new(),
             buffer: oio::VectorCursor::new(),
-            buffer_size: DEFAULT_WRITE_MIN_SIZE,
+            buffer_size: part_size(total_size, DEFAULT_WRITE_MIN_SIZE, None),
+            cancelled_write: false,
         }
     }
 
@@ -125,9 +207,78 @@ impl<W: MultipartUploadWrite> MultipartUploadWriter<W> {
     ///
     /// This value is default to 8 MiB (as recommended by AWS).
     pub fn with_write_min_size(mut self, v: usize) -> Self {
-        self.buffer_size = v;
+        self.write_min_size = v;
+        self.buffer_size = part_size(self.total_size, v, self.write_memory_budget);
         self
     }
+
+    /// Configure the write_memory_budget.
+    ///
+    /// write_memory_budget is the max size of data that could be buffered
+    /// in memory, which will be used as the threshold of `write_once` and
+    /// the size of parts. See [`MultipartUploadWriter`] for more details.
+    ///
+    /// This value is default to `None`, which means only `write_min_size`
+    /// will be buffered.
+    pub fn with_write_memory_budget(mut self, v: Option<usize>) -> Self {
+        self.write_memory_budget = v;
+        self.buffer_size = part_size(self.total_size, self.write_min_size, v);
+        self
+    }
+
+    /// Whether the data is small enough to be uploaded by `write_once`.
+    ///
+    /// Only available while the total size is known.
+    fn is_write_once(&self) -> bool {
+        let limit = self
+            .write_min_size
+            .max(self.write_memory_budget.unwrap_or(0));
+        matches!(self.total_size, Some(size) if size <= limit as u64)
+    }
+
+    /// Buffer the data until all of them have been written, and then
+    /// upload them by `write_once`.
+    async fn write_once_buffered(&mut self, bs: Bytes) -> Result<()> {
+        // Ignore empty bytes
+        if bs.is_empty() {
+            return Ok(());
+        }
+
+        self.buffer.push(bs);
+        if (self.buffer.len() as u64) < self.total_size.unwrap_or_default() {
+            return Ok(());
+        }
+
+        let bs = self.buffer.peak_exact(self.buffer.len());
+        let size = bs.len();
+
+        self.cancelled_write = true;
+        let res = self
+            .inner
+            .write_once(size as u64, AsyncBody::Bytes(bs))
+            .await;
+        self.cancelled_write = false;
+
+        match res {
+            Ok(_) => {
+                self.buffer.clear();
+                Ok(())
+            }
+            Err(e) => {
+                self.buffer.pop();
+                Err(e)
+            }
+        }
+    }
+
+    /// Drop the bytes pushed by the last `write` if it has been cancelled
+    /// before the part upload finished.
+    fn drop_cancelled_write(&mut self) {
+        if self.cancelled_write {
+            self.buffer.pop();
+            self.cancelled_write = false;
+        }
+    }
 }
 
 #[async_trait]
@@ -136,16 +287,23 @@ where
     W: MultipartUploadWrite,
 {
     async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.drop_cancelled_write();
+
         let upload_id = match &self.upload_id {
             Some(upload_id) => upload_id,
             None => {
-                if self.total_size.unwrap_or_default() == bs.len() as u64 {
+                if self.buffer.is_empty() && self.total_size.unwrap_or_default() == bs.len() as u64
+                {
                     return self
                         .inner
                         .write_once(bs.len() as u64, AsyncBody::Bytes(bs))
                         .await;
                 }
 
+                if self.is_write_once() {
+                    return self.write_once_buffered(bs).await;
+                }
+
                 let upload_id = self.inner.initiate_part().await?;
                 self.upload_id = Some(upload_id);
                 self.upload_id.as_deref().unwrap()
@@ -166,7 +324,8 @@ where
         let bs = self.buffer.peak_at_least(self.buffer_size);
         let size = bs.len();
 
-        match self
+        self.cancelled_write = true;
+        let res = self
             .inner
             .write_part(
                 upload_id,
@@ -174,8 +333,10 @@ where
                 size as u64,
                 AsyncBody::Bytes(bs),
             )
-            .await
-        {
+            .await;
+        self.cancelled_write = false;
+
+        match res {
             Ok(part) => {
                 self.buffer.take(size);
                 self.parts.push(part);
@@ -191,6 +352,8 @@ where
     }
 
     async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
+        self.drop_cancelled_write();
+
         if !self.buffer.is_empty() {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
@@ -221,9 +384,20 @@ where
     }
 
     async fn close(&mut self) -> Result<()> {
+        self.drop_cancelled_write();
+
         let upload_id = if let Some(upload_id) = &self.upload_id {
             upload_id
         } else {
+            // Data that is smaller than the total size will be buffered
+            // without upload, flush them by `write_once`.
+            if !self.buffer.is_empty() {
+                let bs = self.buffer.peak_exact(self.buffer.len());
+                self.inner
+                    .write_once(bs.len() as u64, AsyncBody::Bytes(bs))
+                    .await?;
+                self.buffer.clear();
+            }
             return Ok(());
         };
 
@@ -264,3 +438,214 @@ where
         self.inner.abort_part(upload_id).await
     }
 }
+
+/// Calculate the size of parts.
+///
+/// - Parts are as large as `write_memory_budget`, but no smaller than
+///   `write_min_size`.
+/// - If `total_size` is known, parts will be large enough to keep the
+///   number of parts no more than [`MAX_PARTS`].
+fn part_size(
+    total_size: Option<u64>,
+    write_min_size: usize,
+    write_memory_budget: Option<usize>,
+) -> usize {
+    let size = write_min_size.max(write_memory_budget.unwrap_or(0));
+
+    match total_size {
+        Some(total) => {
+            let required = (total + MAX_PARTS - 1) / MAX_PARTS;
+            if required <= size as u64 {
+                return size;
+            }
+            let required =
+                (required + PART_SIZE_ALIGNMENT - 1) / PART_SIZE_ALIGNMENT * PART_SIZE_ALIGNMENT;
+            required as usize
+        }
+        None => size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockState {
+        blocking: bool,
+        parts: Vec<Bytes>,
+        object: Option<Bytes>,
+    }
+
+    #[derive(Clone, Default)]
+    struct MockWrite {
+        state: Arc<Mutex<MockState>>,
+    }
+
+    #[async_trait]
+    impl MultipartUploadWrite for MockWrite {
+        async fn write_once(&self, _: u64, body: AsyncBody) -> Result<()> {
+            if let AsyncBody::Bytes(bs) = body {
+                self.state.lock().unwrap().object = Some(bs);
+            }
+            Ok(())
+        }
+
+        async fn initiate_part(&self) -> Result<String> {
+            Ok("upload".to_string())
+        }
+
+        async fn write_part(
+            &self,
+            _: &str,
+            part_number: usize,
+            _: u64,
+            body: AsyncBody,
+        ) -> Result<MultipartUploadPart> {
+            if self.state.lock().unwrap().blocking {
+                futures::future::pending::<()>().await;
+            }
+
+            if let AsyncBody::Bytes(bs) = body {
+                self.state.lock().unwrap().parts.push(bs);
+            }
+            Ok(MultipartUploadPart {
+                part_number,
+                etag: String::new(),
+                checksum: None,
+            })
+        }
+
+        async fn complete_part(&self, _: &str, _: &[MultipartUploadPart]) -> Result<()> {
+            let mut state = self.state.lock().unwrap();
+            let object = state.parts.concat();
+            state.object = Some(Bytes::from(object));
+            Ok(())
+        }
+
+        async fn abort_part(&self, _: &str) -> Result<()> {
+            self.state.lock().unwrap().parts.clear();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_part_size() {
+        const MIB: usize = 1024 * 1024;
+        const GIB: u64 = 1024 * MIB as u64;
+        const TIB: u64 = 1024 * GIB;
+        let min = 8 * MIB;
+
+        let cases: Vec<(Option<u64>, Option<usize>, usize)> = vec![
+            // (total_size, write_memory_budget, expected part size)
+            (None, None, 8 * MIB),
+            (None, Some(64 * MIB), 64 * MIB),
+            // Budget smaller than write_min_size will be ignored.
+            (None, Some(MIB), 8 * MIB),
+            (Some(MIB as u64), None, 8 * MIB),
+            (Some(100 * MIB as u64), None, 8 * MIB),
+            (Some(10 * GIB), Some(64 * MIB), 64 * MIB),
+            // 8 MiB * 10000 is less than 100 GiB.
+            (Some(100 * GIB), None, 11 * MIB),
+            (Some(TIB), Some(64 * MIB), 105 * MIB),
+            (Some(5 * TIB), None, 525 * MIB),
+        ];
+
+        for (total, budget, expected) in cases {
+            let size = part_size(total, min, budget);
+            assert_eq!(size, expected, "total: {total:?}, budget: {budget:?}");
+            if let Some(total) = total {
+                let size = size as u64;
+                assert!((total + size - 1) / size <= MAX_PARTS);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_once_under_budget() {
+        let mock = MockWrite::default();
+
+        let mut w = MultipartUploadWriter::new(mock.clone(), Some(10))
+            .with_write_min_size(4)
+            .with_write_memory_budget(Some(16));
+        oio::Write::write(&mut w, Bytes::from("hello"))
+            .await
+            .expect("write must succeed");
+        oio::Write::write(&mut w, Bytes::from("world"))
+            .await
+            .expect("write must succeed");
+        oio::Write::close(&mut w).await.expect("close must succeed");
+
+        let state = mock.state.lock().unwrap();
+        assert!(state.parts.is_empty(), "multipart must not be used");
+        assert_eq!(state.object, Some(Bytes::from("helloworld")));
+    }
+
+    #[tokio::test]
+    async fn test_multipart_over_budget() {
+        let mock = MockWrite::default();
+
+        let mut w = MultipartUploadWriter::new(mock.clone(), Some(10))
+            .with_write_min_size(4)
+            .with_write_memory_budget(Some(4));
+        oio::Write::write(&mut w, Bytes::from("hello"))
+            .await
+            .expect("write must succeed");
+        oio::Write::write(&mut w, Bytes::from("world"))
+            .await
+            .expect("write must succeed");
+        oio::Write::close(&mut w).await.expect("close must succeed");
+
+        let state = mock.state.lock().unwrap();
+        assert_eq!(state.parts.len(), 2);
+        assert_eq!(state.object, Some(Bytes::from("helloworld")));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_write() {
+        let mock = MockWrite::default();
+        mock.state.lock().unwrap().blocking = true;
+
+        let mut w = MultipartUploadWriter::new(mock.clone(), None).with_write_min_size(4);
+        let res = tokio::time::timeout(
+            Duration::from_millis(10),
+            oio::Write::write(&mut w, Bytes::from("hello")),
+        )
+        .await;
+        assert!(res.is_err(), "write must be cancelled");
+
+        // Dropping the writer must not complete the upload.
+        drop(w);
+        assert!(mock.state.lock().unwrap().object.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_write_retry() {
+        let mock = MockWrite::default();
+        mock.state.lock().unwrap().blocking = true;
+
+        let mut w = MultipartUploadWriter::new(mock.clone(), None).with_write_min_size(4);
+        let res = tokio::time::timeout(
+            Duration::from_millis(10),
+            oio::Write::write(&mut w, Bytes::from("hello")),
+        )
+        .await;
+        assert!(res.is_err(), "write must be cancelled");
+
+        // Retry the cancelled write with the same data.
+        mock.state.lock().unwrap().blocking = false;
+        oio::Write::write(&mut w, Bytes::from("hello"))
+            .await
+            .expect("write must succeed");
+        oio::Write::close(&mut w).await.expect("close must succeed");
+
+        assert_eq!(
+            mock.state.lock().unwrap().object,
+            Some(Bytes::from("hello"))
+        );
+    }
+}
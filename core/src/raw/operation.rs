@@ -63,6 +63,8 @@ pub enum Operation {
     BlockingDelete,
     /// Operation for [`crate::raw::Accessor::blocking_list`]
     BlockingList,
+    /// Operation for [`crate::raw::Accessor::blocking_presign`]
+    BlockingPresign,
 }
 
 impl Operation {
@@ -101,6 +103,7 @@ impl From<Operation> for &'static str {
             Operation::BlockingStat => "blocking_stat",
             Operation::BlockingDelete => "blocking_delete",
             Operation::BlockingList => "blocking_list",
+            Operation::BlockingPresign => "blocking_presign",
         }
     }
 }
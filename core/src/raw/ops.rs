@@ -19,12 +19,24 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
 use std::time::Duration;
 
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
 
 use crate::raw::*;
+use crate::ChecksumAlgorithm;
 use crate::Metakey;
+use crate::ObjectLockMode;
+use crate::SortKey;
 
 /// Args for `create` operation.
 ///
@@ -45,6 +57,8 @@ impl OpCreateDir {
 #[derive(Debug, Clone, Default)]
 pub struct OpDelete {
     version: Option<String>,
+    recursive: bool,
+    extensions: Extensions,
 }
 
 impl OpDelete {
@@ -65,6 +79,37 @@ impl OpDelete {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Change the recursive flag of this delete operation.
+    ///
+    /// If recursive is set, the directory will be deleted along with all
+    /// its children.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Get the recursive flag of this delete operation.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `list` operation.
@@ -74,24 +119,51 @@ pub struct OpList {
     /// that could return.
     limit: Option<usize>,
 
+    /// The max results that the list operation will return in total.
+    ///
+    /// Different from `limit` which is the size of one page, listing will
+    /// stop fetching more pages once `max_results` entries are returned.
+    max_results: Option<usize>,
+
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     start_after: Option<String>,
 
+    /// The continuation token returned by previous listing to resume from.
+    continuation: Option<String>,
+
     /// The delimiter used to for the list operation. Default to be `/`
     delimiter: String,
 
     metakey: FlagSet<Metakey>,
+
+    /// Whether to list all versions of objects instead of the latest only.
+    versions: bool,
+
+    /// The key to sort entries by, only used by `Operator::list_with`.
+    sort_by: Option<SortKey>,
+
+    /// Whether the paths of entries are relative to the root, only used
+    /// by `Operator` instead of services.
+    absolute: bool,
+
+    extensions: Extensions,
 }
 
 impl Default for OpList {
     fn default() -> Self {
         OpList {
             limit: None,
+            max_results: None,
             start_after: None,
+            continuation: None,
             delimiter: "/".to_string(),
             // By default, we want to know what's the mode of this entry.
             metakey: Metakey::Mode.into(),
+            versions: false,
+            sort_by: None,
+            absolute: true,
+            extensions: Extensions::default(),
         }
     }
 }
@@ -113,6 +185,17 @@ impl OpList {
         self.limit
     }
 
+    /// Change the max results of this list operation.
+    pub fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Get the max results of list operation.
+    pub fn max_results(&self) -> Option<usize> {
+        self.max_results
+    }
+
     /// Change the start_after of this list operation.
     pub fn with_start_after(mut self, start_after: &str) -> Self {
         self.start_after = Some(start_after.into());
@@ -124,6 +207,20 @@ impl OpList {
         self.start_after.as_deref()
     }
 
+    /// Change the continuation token of this list operation.
+    ///
+    /// The token must be returned by the same service while listing the
+    /// same path, see [`Lister::continuation_token`][crate::Lister::continuation_token].
+    pub fn with_continuation(mut self, token: &str) -> Self {
+        self.continuation = Some(token.into());
+        self
+    }
+
+    /// Get the continuation token of list operation.
+    pub fn continuation(&self) -> Option<&str> {
+        self.continuation.as_deref()
+    }
+
     /// Change the delimiter. The default delimiter is "/"
     pub fn with_delimiter(mut self, delimiter: &str) -> Self {
         self.delimiter = delimiter.to_string();
@@ -147,6 +244,64 @@ impl OpList {
     pub fn metakey(&self) -> FlagSet<Metakey> {
         self.metakey
     }
+
+    /// Change whether the paths of entries are relative to the root.
+    ///
+    /// - `true` (default): paths are relative to the root of operator,
+    ///   like `dir/sub/file`.
+    /// - `false`: paths are relative to the listed directory, like
+    ///   `sub/file` while listing `dir/`.
+    pub fn with_absolute(mut self, absolute: bool) -> Self {
+        self.absolute = absolute;
+        self
+    }
+
+    /// Get whether the paths of entries are relative to the root.
+    pub fn absolute(&self) -> bool {
+        self.absolute
+    }
+
+    /// Change the versions of this list operation.
+    ///
+    /// If enabled, all versions of objects will be returned and every
+    /// version is a separate entry.
+    pub fn with_versions(mut self, versions: bool) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Get the current versions.
+    pub fn versions(&self) -> bool {
+        self.versions
+    }
+
+    /// Change the sort key of this list operation.
+    pub fn with_sort_by(mut self, sort_by: SortKey) -> Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    /// Get the current sort key.
+    pub fn sort_by(&self) -> Option<SortKey> {
+        self.sort_by
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `presign` operation.
@@ -238,6 +393,10 @@ impl OpBatch {
 pub enum BatchOperation {
     /// Batch delete operation.
     Delete(OpDelete),
+    /// Batch write operation with the whole content.
+    ///
+    /// Require [`Capability::batch_write`](crate::Capability::batch_write)
+    Write(OpWrite, Bytes),
 }
 
 impl From<OpDelete> for BatchOperation {
@@ -252,20 +411,98 @@ impl BatchOperation {
         use BatchOperation::*;
         match self {
             Delete(_) => Operation::Delete,
+            Write(_, _) => Operation::Write,
         }
     }
 }
 
+/// Callback to report the progress of transferring.
+///
+/// The callback will be called with the bytes that have been transferred
+/// so far and the total size if known, once per chunk.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(u64, Option<u64>) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Create a new progress callback.
+    pub fn new(f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Report the progress.
+    pub fn call(&self, transferred: u64, total: Option<u64>) {
+        (self.0)(transferred, total)
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressCallback").finish_non_exhaustive()
+    }
+}
+
+/// Extensions carries request-scoped context of an operation, like the
+/// tenant id or a short-lived token.
+///
+/// Values are indexed by their types, so users are expected to define
+/// their own types to avoid conflicts. Layers and services can inspect
+/// them via `args.extensions().get::<T>()`.
+///
+/// Operations issued by another operation, like copying every entry while
+/// copying recursively, should carry its extensions via `with_extensions`.
+#[derive(Clone, Default)]
+pub struct Extensions(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl Extensions {
+    /// Create a new empty extensions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a value into extensions, the old value of the same type
+    /// will be replaced.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, v: T) {
+        self.0.insert(TypeId::of::<T>(), Arc::new(v));
+    }
+
+    /// Get the value of given type from extensions.
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|v| v.downcast_ref::<T>())
+    }
+
+    /// Check if extensions is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Debug for Extensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
 /// Args for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRead {
     br: BytesRange,
     if_match: Option<String>,
     if_none_match: Option<String>,
+    if_range: Option<String>,
     override_content_type: Option<String>,
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    buffer: Option<usize>,
+    progress: Option<ProgressCallback>,
+    max_retries: Option<usize>,
+    verify: bool,
+    auto_decompress: bool,
+    extensions: Extensions,
 }
 
 impl OpRead {
@@ -320,8 +557,10 @@ impl OpRead {
     }
 
     /// Set the If-Match of the option
+    ///
+    /// Both quoted and unquoted etags are accepted.
     pub fn with_if_match(mut self, if_match: &str) -> Self {
-        self.if_match = Some(if_match.to_string());
+        self.if_match = Some(format_etag(if_match));
         self
     }
 
@@ -331,8 +570,10 @@ impl OpRead {
     }
 
     /// Set the If-None-Match of the option
+    ///
+    /// Both quoted and unquoted etags are accepted.
     pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
-        self.if_none_match = Some(if_none_match.to_string());
+        self.if_none_match = Some(format_etag(if_none_match));
         self
     }
 
@@ -341,6 +582,19 @@ impl OpRead {
         self.if_none_match.as_deref()
     }
 
+    /// Set the If-Range of the option
+    ///
+    /// The value is an etag or a HTTP-date, which will be sent as is.
+    pub fn with_if_range(mut self, if_range: &str) -> Self {
+        self.if_range = Some(if_range.to_string());
+        self
+    }
+
+    /// Get If-Range from option
+    pub fn if_range(&self) -> Option<&str> {
+        self.if_range.as_deref()
+    }
+
     /// Set the version of the option
     pub fn with_version(mut self, version: &str) -> Self {
         self.version = Some(version.to_string());
@@ -351,6 +605,89 @@ impl OpRead {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the buffer size of the option.
+    ///
+    /// Buffer is the max size of data fetched in one request while
+    /// streaming reading.
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = Some(buffer);
+        self
+    }
+
+    /// Get buffer size from option.
+    pub fn buffer(&self) -> Option<usize> {
+        self.buffer
+    }
+
+    /// Set the progress callback of the option.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Get the progress callback from option.
+    pub fn progress(&self) -> Option<&ProgressCallback> {
+        self.progress.as_ref()
+    }
+
+    /// Set the max retries of the option.
+    ///
+    /// [`RetryLayer`][crate::layers::RetryLayer] will retry this operation
+    /// at most `max_retries` times instead of its configured max times.
+    /// `0` means this operation will not be retried at all.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Get max retries from option.
+    pub fn max_retries(&self) -> Option<usize> {
+        self.max_retries
+    }
+
+    /// Set whether to verify the checksum of read content.
+    ///
+    /// If set, services will verify the content against the checksum
+    /// stored along with the object and return
+    /// [`ErrorKind::ChecksumMismatch`][crate::ErrorKind::ChecksumMismatch] at EOF if they don't match.
+    pub fn with_verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Get whether to verify the checksum of read content from option.
+    pub fn verify(&self) -> bool {
+        self.verify
+    }
+
+    /// Set whether to decompress the read content by the extension of path.
+    pub fn with_auto_decompress(mut self, auto_decompress: bool) -> Self {
+        self.auto_decompress = auto_decompress;
+        self
+    }
+
+    /// Get whether to decompress the read content by the extension of path.
+    pub fn auto_decompress(&self) -> bool {
+        self.auto_decompress
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `stat` operation.
@@ -359,6 +696,8 @@ pub struct OpStat {
     if_match: Option<String>,
     if_none_match: Option<String>,
     version: Option<String>,
+    max_retries: Option<usize>,
+    extensions: Extensions,
 }
 
 impl OpStat {
@@ -368,8 +707,10 @@ impl OpStat {
     }
 
     /// Set the If-Match of the option
+    ///
+    /// Both quoted and unquoted etags are accepted.
     pub fn with_if_match(mut self, if_match: &str) -> Self {
-        self.if_match = Some(if_match.to_string());
+        self.if_match = Some(format_etag(if_match));
         self
     }
 
@@ -379,8 +720,10 @@ impl OpStat {
     }
 
     /// Set the If-None-Match of the option
+    ///
+    /// Both quoted and unquoted etags are accepted.
     pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
-        self.if_none_match = Some(if_none_match.to_string());
+        self.if_none_match = Some(format_etag(if_none_match));
         self
     }
 
@@ -399,6 +742,38 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set the max retries of the option.
+    ///
+    /// [`RetryLayer`][crate::layers::RetryLayer] will retry this operation
+    /// at most `max_retries` times instead of its configured max times.
+    /// `0` means this operation will not be retried at all.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Get max retries from option.
+    pub fn max_retries(&self) -> Option<usize> {
+        self.max_retries
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `write` operation.
@@ -408,6 +783,15 @@ pub struct OpWrite {
     content_type: Option<String>,
     content_disposition: Option<String>,
     cache_control: Option<String>,
+    content_md5: Option<String>,
+    auto_content_md5: bool,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+    if_not_exists: bool,
+    object_lock_mode: Option<ObjectLockMode>,
+    retain_until: Option<DateTime<Utc>>,
+    chunked_unknown_size: bool,
+    progress: Option<ProgressCallback>,
+    extensions: Extensions,
 }
 
 impl OpWrite {
@@ -466,6 +850,130 @@ impl OpWrite {
         self.cache_control = Some(cache_control.to_string());
         self
     }
+
+    /// Get the content md5 from option
+    pub fn content_md5(&self) -> Option<&str> {
+        self.content_md5.as_deref()
+    }
+
+    /// Set the content md5 of option
+    ///
+    /// The value should be the base64 encoded md5 digest of the whole
+    /// content, services will reject the write if not matched.
+    pub fn with_content_md5(mut self, content_md5: &str) -> Self {
+        self.content_md5 = Some(content_md5.to_string());
+        self
+    }
+
+    /// Check if content md5 should be calculated automatically.
+    pub fn auto_content_md5(&self) -> bool {
+        self.auto_content_md5
+    }
+
+    /// Set whether content md5 should be calculated automatically.
+    ///
+    /// If enabled, services will calculate the md5 of every request body
+    /// (including every part of multipart upload) and send it along.
+    pub fn with_auto_content_md5(mut self, auto_content_md5: bool) -> Self {
+        self.auto_content_md5 = auto_content_md5;
+        self
+    }
+
+    /// Get the checksum algorithm from option
+    pub fn checksum_algorithm(&self) -> Option<ChecksumAlgorithm> {
+        self.checksum_algorithm
+    }
+
+    /// Set the checksum algorithm of option
+    ///
+    /// Services will calculate the checksum of every request body by
+    /// given algorithm and send it along.
+    pub fn with_checksum_algorithm(mut self, checksum_algorithm: ChecksumAlgorithm) -> Self {
+        self.checksum_algorithm = Some(checksum_algorithm);
+        self
+    }
+
+    /// Get the if not exists from option.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Set the if not exists of option
+    ///
+    /// The write will fail with `AlreadyExists` if the path exists.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Get the object lock mode from option.
+    pub fn object_lock_mode(&self) -> Option<ObjectLockMode> {
+        self.object_lock_mode
+    }
+
+    /// Set the object lock mode of option.
+    ///
+    /// Must be set along with [`OpWrite::with_retain_until`].
+    pub fn with_object_lock_mode(mut self, mode: ObjectLockMode) -> Self {
+        self.object_lock_mode = Some(mode);
+        self
+    }
+
+    /// Get the retain until date of object lock from option.
+    pub fn retain_until(&self) -> Option<DateTime<Utc>> {
+        self.retain_until
+    }
+
+    /// Set the retain until date of object lock.
+    ///
+    /// Must be set along with [`OpWrite::with_object_lock_mode`].
+    pub fn with_retain_until(mut self, retain_until: DateTime<Utc>) -> Self {
+        self.retain_until = Some(retain_until);
+        self
+    }
+
+    /// Check if the write will stream data of unknown size.
+    pub fn chunked_unknown_size(&self) -> bool {
+        self.chunked_unknown_size
+    }
+
+    /// Set whether the write will stream data of unknown size.
+    ///
+    /// Services will pick the best strategy they support: multipart
+    /// upload for object storages and chunked transfer encoding for
+    /// http based services like webdav.
+    pub fn with_chunked_unknown_size(mut self, chunked_unknown_size: bool) -> Self {
+        self.chunked_unknown_size = chunked_unknown_size;
+        self
+    }
+
+    /// Get the progress callback from option.
+    pub fn progress(&self) -> Option<&ProgressCallback> {
+        self.progress.as_ref()
+    }
+
+    /// Set the progress callback of option.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `append` operation.
@@ -518,13 +1026,85 @@ impl OpAppend {
 
 /// Args for `copy` operation.
 #[derive(Debug, Clone, Default)]
-pub struct OpCopy {}
+pub struct OpCopy {
+    recursive: bool,
+    concurrent: Option<usize>,
+    range: BytesRange,
+    if_not_exists: bool,
+    extensions: Extensions,
+}
 
 impl OpCopy {
     /// Create a new `OpCopy`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Change the recursive flag of this copy operation.
+    ///
+    /// Recursive copy is handled by [`Operator`](crate::Operator) via listing
+    /// and copying every entry, services will never see this flag set.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Get the recursive flag of this copy operation.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Change the max concurrent copies of recursive copy.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = Some(concurrent);
+        self
+    }
+
+    /// Get the max concurrent copies of recursive copy.
+    pub fn concurrent(&self) -> Option<usize> {
+        self.concurrent
+    }
+
+    /// Set the range of source to copy.
+    ///
+    /// Require [`Capability::copy_with_range`](crate::Capability::copy_with_range).
+    pub fn with_range(mut self, range: BytesRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range of source to copy.
+    pub fn range(&self) -> BytesRange {
+        self.range
+    }
+
+    /// Set whether to fail with `AlreadyExists` if the target path exists.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Get whether to fail with `AlreadyExists` if the target path exists.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Insert an extension into the option, see [`Extensions`] for details.
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.extensions.insert(v);
+        self
+    }
+
+    /// Replace all extensions of the option.
+    pub fn with_extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Get extensions from option.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
 }
 
 /// Args for `rename` operation.
@@ -152,6 +152,8 @@ impl RpBatch {
 pub enum BatchedReply {
     /// results of `delete batch` operation
     Delete(RpDelete),
+    /// results of `write batch` operation
+    Write(RpWrite),
 }
 
 impl From<RpDelete> for BatchedReply {
@@ -160,6 +162,12 @@ impl From<RpDelete> for BatchedReply {
     }
 }
 
+impl From<RpWrite> for BatchedReply {
+    fn from(rp: RpWrite) -> Self {
+        Self::Write(rp)
+    }
+}
+
 /// Reply for `stat` operation.
 #[derive(Debug, Clone)]
 pub struct RpStat {
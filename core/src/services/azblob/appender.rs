@@ -114,6 +114,7 @@ impl oio::Append for AzblobAppender {
             &self.path,
             size,
             self.position,
+            None,
             AsyncBody::Bytes(bs),
         )?;
 
@@ -31,6 +31,7 @@ use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
 use sha2::Digest;
 use sha2::Sha256;
+use tokio::sync::OnceCell;
 
 use super::appender::AzblobAppender;
 use super::batch::parse_batch_delete_response;
@@ -39,6 +40,7 @@ use super::pager::AzblobPager;
 use super::writer::AzblobWriter;
 use crate::raw::*;
 use crate::services::azblob::core::AzblobCore;
+use crate::services::azblob::core::BlobType;
 use crate::types::Metadata;
 use crate::*;
 
@@ -67,7 +69,9 @@ pub struct AzblobBuilder {
     encryption_algorithm: Option<String>,
     sas_token: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
     batch_max_operations: Option<usize>,
+    blob_type: Option<String>,
 }
 
 impl Debug for AzblobBuilder {
@@ -77,6 +81,7 @@ impl Debug for AzblobBuilder {
         ds.field("root", &self.root);
         ds.field("container", &self.container);
         ds.field("endpoint", &self.endpoint);
+        ds.field("blob_type", &self.blob_type);
 
         if self.account_name.is_some() {
             ds.field("account_name", &"<redacted>");
@@ -236,9 +241,17 @@ impl AzblobBuilder {
     /// - If sas_token is set, we will take user's input first.
     /// - If not, we will try to load it from environment.
     ///
+    /// With sas_token, requests will carry the token in query instead of
+    /// being signed, so account_key is not required. Operations out of
+    /// the scope of token (like write with a read-only token) will return
+    /// [`ErrorKind::PermissionDenied`].
+    ///
+    /// The leading `?` copied from Azure Portal will be trimmed.
+    ///
     /// See [Grant limited access to Azure Storage resources using shared access signatures (SAS)](https://learn.microsoft.com/en-us/azure/storage/common/storage-sas-overview)
     /// for more info.
     pub fn sas_token(&mut self, sas_token: &str) -> &mut Self {
+        let sas_token = sas_token.trim_start_matches('?');
         if !sas_token.is_empty() {
             self.sas_token = Some(sas_token.to_string());
         }
@@ -257,6 +270,17 @@ impl AzblobBuilder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// Set maximum batch operations of this backend.
     pub fn batch_max_operations(&mut self, batch_max_operations: usize) -> &mut Self {
         self.batch_max_operations = Some(batch_max_operations);
@@ -264,6 +288,23 @@ impl AzblobBuilder {
         self
     }
 
+    /// Set the type of blobs created by write.
+    ///
+    /// Available values:
+    ///
+    /// - `block`: Create block blobs, which is the default.
+    /// - `append`: Create append blobs, so that they could be appended later.
+    /// - `page`: Create page blobs, the size of written data must be aligned to 512 bytes.
+    ///
+    /// Azure's type names like `BlockBlob` are accepted too.
+    pub fn blob_type(&mut self, v: &str) -> &mut Self {
+        if !v.is_empty() {
+            self.blob_type = Some(v.to_string());
+        }
+
+        self
+    }
+
     /// from_connection_string will make a builder from connection string
     ///
     /// connection string looks like:
@@ -369,6 +410,10 @@ impl Builder for AzblobBuilder {
         map.get("sas_token").map(|v| builder.sas_token(v));
         map.get("batch_max_operations")
             .map(|v| builder.batch_max_operations(v.parse::<usize>().unwrap()));
+        map.get("blob_type").map(|v| builder.blob_type(v));
+
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
 
         builder
     }
@@ -396,7 +441,7 @@ impl Builder for AzblobBuilder {
         }?;
         debug!("backend use endpoint {}", &container);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -404,6 +449,12 @@ impl Builder for AzblobBuilder {
                     .with_context("service", Scheme::Azblob)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Azblob)
+            })?;
+        }
 
         let config_loader = AzureStorageConfig {
             account_name: self
@@ -452,6 +503,19 @@ impl Builder for AzblobBuilder {
 
         let batch_max_operations = self.batch_max_operations.unwrap_or(AZBLOB_BATCH_LIMIT);
 
+        let blob_type = match &self.blob_type {
+            None => BlobType::default(),
+            Some(v) => BlobType::parse(v).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "blob_type value must be one of block, append or page",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Azblob)
+                .with_context("blob_type", v)
+            })?,
+        };
+
         debug!("backend build finished: {:?}", &self);
         Ok(AzblobBackend {
             core: Arc::new(AzblobCore {
@@ -466,6 +530,8 @@ impl Builder for AzblobBuilder {
                 loader: cred_loader,
                 signer,
                 batch_max_operations,
+                blob_type,
+                hns: OnceCell::new(),
             }),
             has_sas_token: self.sas_token.is_some(),
         })
@@ -528,12 +594,17 @@ impl Accessor for AzblobBackend {
                 read_with_range: true,
                 read_with_if_match: true,
                 read_with_if_none_match: true,
+                read_with_override_cache_control: true,
                 read_with_override_content_disposition: true,
+                read_with_override_content_type: true,
 
                 write: true,
                 write_can_sink: true,
                 write_with_cache_control: true,
+                write_with_content_md5: true,
+                write_with_if_not_exists: true,
                 write_with_content_type: true,
+                write_total_max_size: Some(5000 * 1024 * 1024),
 
                 append: true,
                 append_with_cache_control: true,
@@ -544,8 +615,10 @@ impl Accessor for AzblobBackend {
                 copy: true,
 
                 list: true,
+                list_max_limit: Some(5000),
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
+                list_with_continuation: true,
 
                 presign: self.has_sas_token,
                 presign_stat: self.has_sas_token,
@@ -565,7 +638,7 @@ impl Accessor for AzblobBackend {
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let mut req =
             self.core
-                .azblob_put_blob_request(path, Some(0), None, None, AsyncBody::Empty)?;
+                .azblob_put_blob_request(path, Some(0), None, None, None, AsyncBody::Empty)?;
 
         self.core.sign(&mut req).await?;
 
@@ -583,16 +656,7 @@ impl Accessor for AzblobBackend {
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        let resp = self
-            .core
-            .azblob_get_blob(
-                path,
-                args.range(),
-                args.if_none_match(),
-                args.if_match(),
-                args.override_content_disposition(),
-            )
-            .await?;
+        let resp = self.core.azblob_get_blob(path, &args).await?;
 
         let status = resp.status();
 
@@ -680,6 +744,7 @@ impl Accessor for AzblobBackend {
             path.to_string(),
             args.delimiter().to_string(),
             args.limit(),
+            args.continuation(),
         );
 
         Ok((RpList::default(), op))
@@ -691,16 +756,10 @@ impl Accessor for AzblobBackend {
                 self.core
                     .azblob_head_blob_request(path, v.if_none_match(), v.if_match())?
             }
-            PresignOperation::Read(v) => self.core.azblob_get_blob_request(
-                path,
-                v.range(),
-                v.if_none_match(),
-                v.if_match(),
-                v.override_content_disposition(),
-            )?,
+            PresignOperation::Read(v) => self.core.azblob_get_blob_request(path, v)?,
             PresignOperation::Write(_) => {
                 self.core
-                    .azblob_put_blob_request(path, None, None, None, AsyncBody::Empty)?
+                    .azblob_put_blob_request(path, None, None, None, None, AsyncBody::Empty)?
             }
         };
 
@@ -717,7 +776,17 @@ impl Accessor for AzblobBackend {
 
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
-        let paths = ops.into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => Ok(p),
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob services don't support batch write",
+                )
+                .with_context("path", p)),
+            })
+            .collect::<Result<Vec<_>>>()?;
         if paths.len() > AZBLOB_BATCH_LIMIT {
             return Err(Error::new(
                 ErrorKind::Unsupported,
@@ -775,9 +844,22 @@ impl Accessor for AzblobBackend {
 
 #[cfg(test)]
 mod tests {
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::ResponseTemplate;
+
     use super::AzblobBuilder;
+    use crate::raw::OpRead;
     use crate::services::azblob::backend::infer_storage_name_from_endpoint;
     use crate::Builder;
+    use crate::EntryMode;
+    use crate::ErrorKind;
+    use crate::Operator;
+    use crate::Result;
 
     #[test]
     fn test_infer_storage_name_from_endpoint() {
@@ -939,4 +1021,248 @@ SharedAccessSignature=sv=2021-01-01&ss=b&srt=c&sp=rwdlaciytfx&se=2022-01-01T11:0
         assert_eq!(builder.account_name, None);
         assert_eq!(builder.account_key, None);
     }
+
+    #[tokio::test]
+    async fn test_read_only_sas_token() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/container/file"))
+            .and(query_param("sp", "r"))
+            .and(query_param("sig", "signature"))
+            .and(|req: &Request| req.headers.get(&"authorization".into()).is_none())
+            .respond_with(ResponseTemplate::new(200).set_body_string("Hello, World!"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/container/file"))
+            .respond_with(ResponseTemplate::new(403).set_body_string(
+                r#"<?xml version="1.0" encoding="utf-8"?><Error><Code>AuthorizationPermissionMismatch</Code><Message>This request is not authorized to perform this operation using this permission.</Message></Error>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = AzblobBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .container("container")
+            .sas_token("?sv=2021-01-01&sr=c&sp=r&sig=signature");
+        let op = Operator::new(builder)?.finish();
+
+        assert_eq!(op.read("file").await?, b"Hello, World!");
+
+        let err = op
+            .write("file", "Hello, World!")
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(
+            err.to_string().contains("AuthorizationPermissionMismatch"),
+            "{err}"
+        );
+        Ok(())
+    }
+
+    async fn list_dir(mock_server: &MockServer) -> Result<Vec<(String, EntryMode)>> {
+        let mut builder = AzblobBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .container("container")
+            .account_name("account")
+            .account_key("YWNjb3VudGtleQ==");
+        let op = Operator::new(builder)?.finish();
+
+        let mut entries: Vec<_> = op
+            .list("dir/")
+            .await?
+            .into_iter()
+            .map(|e| (e.path().to_string(), e.metadata().mode()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(entries)
+    }
+
+    /// Blob listing of the `dir/` on accounts with hierarchical namespace,
+    /// the directory `dir/sub` is returned as a placeholder blob.
+    const LIST_BLOBS_HNS: &str = r#"<?xml version="1.0" encoding="utf-8"?><EnumerationResults ServiceEndpoint="http://127.0.0.1/" ContainerName="container"><Prefix>dir/</Prefix><Delimiter>/</Delimiter><Blobs><Blob><Name>dir/file</Name><Properties><Last-Modified>Sun, 20 Mar 2022 11:29:03 GMT</Last-Modified><Etag>0x8DA0A64D66790C3</Etag><Content-Length>13</Content-Length></Properties></Blob><Blob><Name>dir/sub</Name><Properties><Last-Modified>Sun, 20 Mar 2022 11:29:03 GMT</Last-Modified><Etag>0x8DA0A64D66790C4</Etag><Content-Length>0</Content-Length></Properties></Blob><BlobPrefix><Name>dir/sub/</Name></BlobPrefix></Blobs><NextMarker /></EnumerationResults>"#;
+
+    #[tokio::test]
+    async fn test_list_with_hns() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("restype", "account"))
+            .and(query_param("comp", "properties"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-ms-is-hns-enabled", "true"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/container"))
+            .and(query_param("resource", "filesystem"))
+            .and(query_param("directory", "dir"))
+            .and(query_param("recursive", "false"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"paths":[{"contentLength":"13","etag":"0x8DA0A64D66790C3","lastModified":"Sun, 20 Mar 2022 11:29:03 GMT","name":"dir/file"},{"contentLength":"0","etag":"0x8DA0A64D66790C4","isDirectory":"true","lastModified":"Sun, 20 Mar 2022 11:29:03 GMT","name":"dir/sub"}]}"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/container"))
+            .and(query_param("comp", "list"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(LIST_BLOBS_HNS))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let expected = vec![
+            ("dir/file".to_string(), EntryMode::FILE),
+            ("dir/sub/".to_string(), EntryMode::DIR),
+        ];
+        assert_eq!(list_dir(&mock_server).await?, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_without_hns() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("restype", "account"))
+            .and(query_param("comp", "properties"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-ms-is-hns-enabled", "false"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/container"))
+            .and(query_param("comp", "list"))
+            .and(query_param("prefix", "dir/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<?xml version="1.0" encoding="utf-8"?><EnumerationResults ServiceEndpoint="http://127.0.0.1/" ContainerName="container"><Prefix>dir/</Prefix><Delimiter>/</Delimiter><Blobs><Blob><Name>dir/file</Name><Properties><Last-Modified>Sun, 20 Mar 2022 11:29:03 GMT</Last-Modified><Etag>0x8DA0A64D66790C3</Etag><Content-Length>13</Content-Length></Properties></Blob><BlobPrefix><Name>dir/sub/</Name></BlobPrefix></Blobs><NextMarker /></EnumerationResults>"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Flat accounts are listed via blob API with the same result.
+        let expected = vec![
+            ("dir/file".to_string(), EntryMode::FILE),
+            ("dir/sub/".to_string(), EntryMode::DIR),
+        ];
+        assert_eq!(list_dir(&mock_server).await?, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_fallback_to_blobs() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        // Account info can't be read with container level SAS tokens, the
+        // account will be treated as flat.
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("restype", "account"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/container"))
+            .and(query_param("comp", "list"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(LIST_BLOBS_HNS))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Placeholder blobs of directories are returned as files.
+        let expected = vec![
+            ("dir/file".to_string(), EntryMode::FILE),
+            ("dir/sub".to_string(), EntryMode::FILE),
+            ("dir/sub/".to_string(), EntryMode::DIR),
+        ];
+        assert_eq!(list_dir(&mock_server).await?, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_retry_hns_detection() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        // Failures other than permission denied must not be cached as flat.
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("restype", "account"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .and(query_param("restype", "account"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-ms-is-hns-enabled", "false"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/container"))
+            .and(query_param("comp", "list"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(LIST_BLOBS_HNS))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = AzblobBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .container("container")
+            .account_name("account")
+            .account_key("YWNjb3VudGtleQ==");
+        let op = Operator::new(builder)?.finish();
+
+        let err = op.list("dir/").await.expect_err("list must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        assert!(err.is_temporary());
+
+        assert_eq!(op.list("dir/").await?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_blob_request_with_override() -> Result<()> {
+        let mut builder = AzblobBuilder::default();
+        builder
+            .endpoint("http://127.0.0.1:10000/account")
+            .container("container")
+            .account_name("account")
+            .account_key("YWNjb3VudGtleQ==");
+        let backend = builder.build()?;
+
+        let args = OpRead::new()
+            .with_override_content_disposition("attachment; filename=\"test.png\"")
+            .with_override_content_type("image/png")
+            .with_override_cache_control("no-cache");
+        let req = backend.core.azblob_get_blob_request("file", &args)?;
+        assert_eq!(
+            req.uri().to_string(),
+            "http://127.0.0.1:10000/account/container/file\
+             ?rscd=attachment%3B%20filename%3D%22test.png%22\
+             &rsct=image/png\
+             &rscc=no-cache"
+        );
+
+        let req = backend
+            .core
+            .azblob_get_blob_request("file", &OpRead::new())?;
+        assert_eq!(
+            req.uri().to_string(),
+            "http://127.0.0.1:10000/account/container/file"
+        );
+        Ok(())
+    }
 }
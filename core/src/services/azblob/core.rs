@@ -29,10 +29,13 @@ use http::header::IF_NONE_MATCH;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
 use reqsign::AzureStorageCredential;
 use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
+use tokio::sync::OnceCell;
 
+use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
@@ -43,6 +46,12 @@ mod constants {
     pub const X_MS_COPY_SOURCE: &str = "x-ms-copy-source";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
+    pub const X_MS_BLOB_CONTENT_LENGTH: &str = "x-ms-blob-content-length";
+    pub const X_MS_RANGE: &str = "x-ms-range";
+    pub const X_MS_PAGE_WRITE: &str = "x-ms-page-write";
+    pub const X_MS_IS_HNS_ENABLED: &str = "x-ms-is-hns-enabled";
+
+    pub const CONTENT_MD5: &str = "content-md5";
 
     // Server-side encryption with customer-provided headers
     pub const X_MS_ENCRYPTION_KEY: &str = "x-ms-encryption-key";
@@ -50,6 +59,46 @@ mod constants {
     pub const X_MS_ENCRYPTION_ALGORITHM: &str = "x-ms-encryption-algorithm";
 }
 
+/// The type of blobs that created by `write`.
+///
+/// Reference: <https://learn.microsoft.com/en-us/rest/api/storageservices/understanding-block-blobs--append-blobs--and-page-blobs>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlobType {
+    /// Block blobs are the default type which is optimized for uploading
+    /// large amounts of data.
+    #[default]
+    Block,
+    /// Append blobs are optimized for append operations like logging.
+    Append,
+    /// Page blobs are collections of 512-byte pages optimized for random
+    /// read and write operations.
+    Page,
+}
+
+impl BlobType {
+    /// Page blobs are made of 512-byte pages, every write must be aligned.
+    pub const PAGE_SIZE: usize = 512;
+
+    /// Parse blob type from user input like `block` or `BlockBlob`.
+    pub fn parse(v: &str) -> Option<Self> {
+        match v.to_lowercase().as_str() {
+            "block" | "blockblob" => Some(BlobType::Block),
+            "append" | "appendblob" => Some(BlobType::Append),
+            "page" | "pageblob" => Some(BlobType::Page),
+            _ => None,
+        }
+    }
+
+    /// The value of `x-ms-blob-type` header.
+    pub fn as_header_value(&self) -> &'static str {
+        match self {
+            BlobType::Block => "BlockBlob",
+            BlobType::Append => "AppendBlob",
+            BlobType::Page => "PageBlob",
+        }
+    }
+}
+
 pub struct AzblobCore {
     pub container: String,
     pub root: String,
@@ -61,6 +110,10 @@ pub struct AzblobCore {
     pub loader: AzureStorageLoader,
     pub signer: AzureStorageSigner,
     pub batch_max_operations: usize,
+    pub blob_type: BlobType,
+    /// Whether hierarchical namespace is enabled on this account, detected
+    /// at the first time of listing.
+    pub hns: OnceCell<bool>,
 }
 
 impl Debug for AzblobCore {
@@ -69,6 +122,7 @@ impl Debug for AzblobCore {
             .field("container", &self.container)
             .field("root", &self.root)
             .field("endpoint", &self.endpoint)
+            .field("blob_type", &self.blob_type)
             .finish_non_exhaustive()
     }
 }
@@ -157,14 +211,7 @@ impl AzblobCore {
 }
 
 impl AzblobCore {
-    pub fn azblob_get_blob_request(
-        &self,
-        path: &str,
-        range: BytesRange,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
-        override_content_disposition: Option<&str>,
-    ) -> Result<Request<AsyncBody>> {
+    pub fn azblob_get_blob_request(&self, path: &str, args: &OpRead) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
@@ -175,12 +222,24 @@ impl AzblobCore {
         );
 
         let mut query_args = Vec::new();
-        if let Some(override_content_disposition) = override_content_disposition {
+        if let Some(override_content_disposition) = args.override_content_disposition() {
             query_args.push(format!(
                 "rscd={}",
                 percent_encode_path(override_content_disposition)
             ))
         }
+        if let Some(override_content_type) = args.override_content_type() {
+            query_args.push(format!(
+                "rsct={}",
+                percent_encode_path(override_content_type)
+            ))
+        }
+        if let Some(override_cache_control) = args.override_cache_control() {
+            query_args.push(format!(
+                "rscc={}",
+                percent_encode_path(override_cache_control)
+            ))
+        }
 
         if !query_args.is_empty() {
             url.push_str(&format!("?{}", query_args.join("&")));
@@ -191,6 +250,7 @@ impl AzblobCore {
         // Set SSE headers.
         req = self.insert_sse_headers(req);
 
+        let range = args.range();
         if !range.is_full() {
             // azblob doesn't support read with suffix range.
             //
@@ -205,11 +265,11 @@ impl AzblobCore {
             req = req.header(http::header::RANGE, range.to_header());
         }
 
-        if let Some(if_none_match) = if_none_match {
+        if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
-        if let Some(if_match) = if_match {
+        if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
         }
 
@@ -223,18 +283,9 @@ impl AzblobCore {
     pub async fn azblob_get_blob(
         &self,
         path: &str,
-        range: BytesRange,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
-        override_content_disposition: Option<&str>,
+        args: &OpRead,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.azblob_get_blob_request(
-            path,
-            range,
-            if_none_match,
-            if_match,
-            override_content_disposition,
-        )?;
+        let mut req = self.azblob_get_blob_request(path, args)?;
 
         self.sign(&mut req).await?;
 
@@ -247,6 +298,7 @@ impl AzblobCore {
         size: Option<u64>,
         content_type: Option<&str>,
         cache_control: Option<&str>,
+        content_md5: Option<&str>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -274,9 +326,13 @@ impl AzblobCore {
             req = req.header(CONTENT_TYPE, ty)
         }
 
+        if let Some(content_md5) = content_md5 {
+            req = req.header(constants::CONTENT_MD5, content_md5)
+        }
+
         req = req.header(
             HeaderName::from_static(constants::X_MS_BLOB_TYPE),
-            "BlockBlob",
+            BlobType::Block.as_header_value(),
         );
 
         // Set body
@@ -327,7 +383,7 @@ impl AzblobCore {
         req = req.header(CONTENT_LENGTH, 0);
         req = req.header(
             HeaderName::from_static(constants::X_MS_BLOB_TYPE),
-            "AppendBlob",
+            BlobType::Append.as_header_value(),
         );
 
         if let Some(ty) = content_type {
@@ -367,6 +423,7 @@ impl AzblobCore {
         path: &str,
         size: usize,
         position: Option<u64>,
+        content_md5: Option<&str>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -392,6 +449,123 @@ impl AzblobCore {
             );
         }
 
+        if let Some(content_md5) = content_md5 {
+            req = req.header(constants::CONTENT_MD5, content_md5)
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Page blob could be created by `put` an empty blob with
+    /// `x-ms-blob-type` header set to `PageBlob` and the max size of the blob
+    /// set in `x-ms-blob-content-length`.
+    ///
+    /// If want to write content to it, we should use the following method
+    /// `azblob_put_page_request`.
+    ///
+    /// # Notes
+    ///
+    /// The size of page blob must be aligned to 512 bytes.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-blob
+    pub fn azblob_init_page_blob_request(
+        &self,
+        path: &str,
+        size: u64,
+        content_type: Option<&str>,
+        cache_control: Option<&str>,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        // The content-length header must be set to zero
+        // when creating a page blob.
+        req = req.header(CONTENT_LENGTH, 0);
+        req = req.header(
+            HeaderName::from_static(constants::X_MS_BLOB_TYPE),
+            BlobType::Page.as_header_value(),
+        );
+        req = req.header(
+            HeaderName::from_static(constants::X_MS_BLOB_CONTENT_LENGTH),
+            size.to_string(),
+        );
+
+        if let Some(ty) = content_type {
+            req = req.header(CONTENT_TYPE, ty)
+        }
+
+        if let Some(cache_control) = cache_control {
+            req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
+        }
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Write pages into a page blob.
+    ///
+    /// # Notes
+    ///
+    /// - The maximum size of the content could be written is 4MB.
+    /// - Both `offset` and `size` must be aligned to 512 bytes.
+    ///
+    /// # Reference
+    ///
+    /// https://learn.microsoft.com/en-us/rest/api/storageservices/put-page
+    pub fn azblob_put_page_request(
+        &self,
+        path: &str,
+        offset: u64,
+        size: usize,
+        content_md5: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=page",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        req = req.header(CONTENT_LENGTH, size);
+        req = req.header(
+            HeaderName::from_static(constants::X_MS_RANGE),
+            BytesRange::new(Some(offset), Some(size as u64)).to_header(),
+        );
+        req = req.header(
+            HeaderName::from_static(constants::X_MS_PAGE_WRITE),
+            "update",
+        );
+
+        if let Some(content_md5) = content_md5 {
+            req = req.header(constants::CONTENT_MD5, content_md5)
+        }
+
         let req = req.body(body).map_err(new_request_build_error)?;
 
         Ok(req)
@@ -534,6 +708,96 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    pub async fn azblob_get_account_info(&self) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}/?restype=account&comp=properties", self.endpoint);
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Check whether hierarchical namespace is enabled on this account.
+    ///
+    /// The result is cached after the first success. Accounts that can't
+    /// be detected because of permission (for example, with a container
+    /// level SAS token) will be treated as flat. Other errors will be
+    /// returned without caching so that the detection can be retried.
+    pub async fn is_hns_enabled(&self) -> Result<bool> {
+        self.hns
+            .get_or_try_init(|| async {
+                let resp = self.azblob_get_account_info().await?;
+                match resp.status() {
+                    StatusCode::OK => {}
+                    StatusCode::FORBIDDEN => {
+                        resp.into_body().consume().await?;
+                        return Ok(false);
+                    }
+                    _ => return Err(parse_error(resp).await?),
+                }
+
+                let enabled = resp
+                    .headers()
+                    .get(constants::X_MS_IS_HNS_ENABLED)
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.eq_ignore_ascii_case("true"))
+                    .unwrap_or_default();
+                resp.into_body().consume().await?;
+                Ok(enabled)
+            })
+            .await
+            .copied()
+    }
+
+    /// The endpoint of data lake storage API of this account.
+    ///
+    /// Endpoints like `https://account.blob.core.windows.net` will be
+    /// converted to `https://account.dfs.core.windows.net`, others (like
+    /// Azurite) will be used as is.
+    pub fn dfs_endpoint(&self) -> String {
+        self.endpoint.replacen(".blob.", ".dfs.", 1)
+    }
+
+    /// List paths via data lake storage API, which is only available on
+    /// accounts with hierarchical namespace enabled.
+    pub async fn azblob_list_paths(
+        &self,
+        path: &str,
+        continuation: &str,
+        recursive: bool,
+        limit: Option<usize>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path)
+            .trim_end_matches('/')
+            .to_string();
+
+        let mut url = format!(
+            "{}/{}?resource=filesystem&recursive={recursive}",
+            self.dfs_endpoint(),
+            self.container
+        );
+        if !p.is_empty() {
+            write!(url, "&directory={}", percent_encode_path(&p))
+                .expect("write into string must succeed");
+        }
+        if let Some(limit) = limit {
+            write!(url, "&maxresults={limit}").expect("write into string must succeed");
+        }
+        if !continuation.is_empty() {
+            write!(url, "&continuation={}", percent_encode_path(continuation))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_batch_delete(
         &self,
         paths: &[String],
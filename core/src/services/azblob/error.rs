@@ -63,6 +63,7 @@ impl Debug for AzblobError {
 pub fn parse_http_error(status: StatusCode, body: &str) -> Result<Error> {
     let (kind, retryable) = match status {
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -87,12 +88,13 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
-    let (kind, retryable) = match parts.status {
+    let (mut kind, mut retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
             (ErrorKind::ConditionNotMatch, false)
         }
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -100,11 +102,26 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let mut message = match de::from_reader::<_, AzblobError>(bs.clone().reader()) {
-        Ok(azblob_err) => format!("{azblob_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let azblob_err = de::from_reader::<_, AzblobError>(bs.clone().reader()).ok();
+    let mut message = match &azblob_err {
+        Some(azblob_err) => format!("{azblob_err:?}"),
+        None => String::from_utf8_lossy(&bs).into_owned(),
     };
 
+    let code = azblob_err
+        .as_ref()
+        .map(|v| v.code.as_str())
+        .filter(|v| !v.is_empty())
+        .or_else(|| {
+            parts
+                .headers
+                .get("x-ms-error-code")
+                .and_then(|v| v.to_str().ok())
+        });
+    if let Some(code) = code {
+        (kind, retryable) = parse_azblob_error_code(code).unwrap_or((kind, retryable));
+    }
+
     // If there is no body here, fill with error code.
     if message.is_empty() {
         if let Some(v) = parts.headers.get("x-ms-error-code") {
@@ -131,6 +148,29 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     Ok(err)
 }
 
+/// Returns the `Error kind` of this code and whether the error is retryable.
+///
+/// Reference: <https://learn.microsoft.com/en-us/rest/api/storageservices/blob-service-error-codes>
+fn parse_azblob_error_code(code: &str) -> Option<(ErrorKind, bool)> {
+    match code {
+        // > The MD5 value specified in the request did not match the MD5
+        // > value calculated by the server.
+        "Md5Mismatch" => Some((ErrorKind::ChecksumMismatch, false)),
+        // > The MD5 value specified in the request is invalid.
+        "InvalidMd5" => Some((ErrorKind::InvalidInput, false)),
+        // > The specified blob already exists.
+        "BlobAlreadyExists" => Some((ErrorKind::AlreadyExists, false)),
+        // > This request is not authorized to perform this operation using
+        // > this permission.
+        //
+        // Returned while the operation is out of the scope of SAS token.
+        "AuthorizationPermissionMismatch"
+        | "AuthorizationResourceTypeMismatch"
+        | "AuthorizationServiceMismatch" => Some((ErrorKind::PermissionDenied, false)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +224,29 @@ mod tests {
         );
         assert_eq!(out.reason, "invalid receipt format");
     }
+
+    #[tokio::test]
+    async fn test_parse_error_md5_mismatch() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<Error>
+  <Code>Md5Mismatch</Code>
+  <Message>The MD5 value specified in the request did not match with the MD5 value calculated by the server.</Message>
+</Error>"#,
+        );
+        let body = IncomingAsyncBody::new(
+            Box::new(oio::into_stream(futures::stream::iter(vec![Ok(bs)]))),
+            None,
+        );
+        let resp = Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(body)
+            .unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        assert!(!err.is_temporary());
+
+        Ok(())
+    }
 }
@@ -44,6 +44,7 @@ impl AzblobPager {
         path: String,
         delimiter: String,
         limit: Option<usize>,
+        continuation: Option<&str>,
     ) -> Self {
         Self {
             core,
@@ -51,7 +52,7 @@ impl AzblobPager {
             delimiter,
             limit,
 
-            next_marker: "".to_string(),
+            next_marker: continuation.unwrap_or_default().to_string(),
             done: false,
         }
     }
@@ -64,6 +65,26 @@ impl oio::Page for AzblobPager {
             return Ok(None);
         }
 
+        // Directories are real on accounts with hierarchical namespace, list
+        // them via data lake storage API so that we can get accurate entries
+        // instead of blob prefixes and placeholder blobs.
+        //
+        // Only dirs can be listed in this way, paths without trailing `/`
+        // are still listed as prefix.
+        if self.path.ends_with('/') && self.core.is_hns_enabled().await? {
+            return self.next_paths().await;
+        }
+
+        self.next_blobs().await
+    }
+
+    fn continuation_token(&self) -> Option<String> {
+        (!self.done && !self.next_marker.is_empty()).then(|| self.next_marker.clone())
+    }
+}
+
+impl AzblobPager {
+    async fn next_blobs(&mut self) -> Result<Option<Vec<oio::Entry>>> {
         let resp = self
             .core
             .azblob_list_blobs(&self.path, &self.next_marker, &self.delimiter, self.limit)
@@ -108,8 +129,7 @@ impl oio::Page for AzblobPager {
             }
 
             let meta = Metadata::new(EntryMode::FILE)
-                // Keep fit with ETag header.
-                .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
+                .with_etag(object.properties.etag)
                 .with_content_length(object.properties.content_length)
                 .with_content_md5(object.properties.content_md5)
                 .with_content_type(object.properties.content_type)
@@ -124,6 +144,70 @@ impl oio::Page for AzblobPager {
 
         Ok(Some(entries))
     }
+
+    async fn next_paths(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let resp = self
+            .core
+            .azblob_list_paths(
+                &self.path,
+                &self.next_marker,
+                self.delimiter.is_empty(),
+                self.limit,
+            )
+            .await?;
+
+        // Listing a not exist directory will return not found.
+        if resp.status() == http::StatusCode::NOT_FOUND {
+            resp.into_body().consume().await?;
+            self.done = true;
+            return Ok(None);
+        }
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        // The list is done if there is no continuation.
+        self.next_marker = resp
+            .headers()
+            .get("x-ms-continuation")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        self.done = self.next_marker.is_empty();
+
+        let bs = resp.into_body().bytes().await?;
+
+        let output: PathsOutput = serde_json::from_slice(&bs).map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "deserialize json from response").set_source(e)
+        })?;
+
+        let mut entries = Vec::with_capacity(output.paths.len());
+
+        for object in output.paths {
+            // Data lake storage API will return `"true"` for directories.
+            let (mode, name) = if object.is_directory == "true" {
+                (EntryMode::DIR, format!("{}/", object.name))
+            } else {
+                (EntryMode::FILE, object.name)
+            };
+
+            let mut meta = Metadata::new(mode)
+                .with_etag(object.etag)
+                .with_last_modified(parse_datetime_from_rfc2822(&object.last_modified)?);
+            if mode == EntryMode::FILE {
+                meta.set_content_length(object.content_length.parse().map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "content length is not valid integer")
+                        .set_source(err)
+                })?);
+            }
+
+            let de = oio::Entry::new(&build_rel_path(&self.core.root, &name), meta);
+
+            entries.push(de);
+        }
+
+        Ok(Some(entries))
+    }
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -168,6 +252,30 @@ struct Properties {
     etag: String,
 }
 
+/// Output of data lake storage API's list paths.
+///
+/// # Examples
+///
+/// ```json
+/// {"paths":[{"contentLength":"1977097","etag":"0x8DACF9B0061305F","lastModified":"Sat, 26 Nov 2022 10:43:05 GMT","name":"dir/file"},{"contentLength":"0","etag":"0x8DACF9B0061305F","isDirectory":"true","lastModified":"Sat, 26 Nov 2022 10:43:05 GMT","name":"dir/sub"}]}
+/// ```
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct PathsOutput {
+    paths: Vec<Path>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Path {
+    content_length: String,
+    etag: String,
+    /// `"true"` for directories, absent for files.
+    is_directory: String,
+    last_modified: String,
+    name: String,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -176,7 +284,7 @@ mod tests {
 
     #[test]
     fn test_parse_xml() {
-        let bs = bytes::Bytes::from(
+        let bs = Bytes::from(
             r#"
             <?xml version="1.0" encoding="utf-8"?>
             <EnumerationResults ServiceEndpoint="https://test.blob.core.windows.net/" ContainerName="myazurebucket">
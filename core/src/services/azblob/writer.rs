@@ -19,13 +19,23 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use bytes::BytesMut;
+use futures::TryStreamExt;
+use http::header::IF_NONE_MATCH;
+use http::HeaderValue;
+use http::Request;
 use http::StatusCode;
 
 use super::core::AzblobCore;
+use super::core::BlobType;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+/// The max size of content could be written in one `Append Block` or
+/// `Put Page` request.
+const MAX_BLOCK_SIZE: usize = 4 * 1024 * 1024;
+
 pub struct AzblobWriter {
     core: Arc<AzblobCore>,
 
@@ -39,13 +49,51 @@ impl AzblobWriter {
     }
 
     async fn write_oneshot(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let res = match self.core.blob_type {
+            BlobType::Block => self.write_block_blob(size, body).await,
+            BlobType::Append => {
+                let bs = collect_body(size, body).await?;
+                self.check_content_md5(&bs)?;
+                self.write_append_blob(bs).await
+            }
+            BlobType::Page => {
+                // Check alignment before collecting the whole body.
+                check_page_aligned(size)?;
+                let bs = collect_body(size, body).await?;
+                self.check_content_md5(&bs)?;
+                self.write_page_blob(bs).await
+            }
+        };
+
+        // The precondition failure means the blob exists while writing
+        // with `if_not_exists`.
+        res.map_err(|err| {
+            if self.op.if_not_exists() && err.kind() == ErrorKind::ConditionNotMatch {
+                Error::new(ErrorKind::AlreadyExists, "the path to write already exists")
+                    .with_operation("AzblobWriter::write")
+                    .with_context("path", &self.path)
+                    .set_source(err)
+            } else {
+                err
+            }
+        })
+    }
+
+    async fn write_block_blob(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let content_md5 = match self.op.content_md5() {
+            Some(v) => Some(v.to_string()),
+            None => self.auto_content_md5(&body)?,
+        };
+
         let mut req = self.core.azblob_put_blob_request(
             &self.path,
             Some(size),
             self.op.content_type(),
             self.op.cache_control(),
+            content_md5.as_deref(),
             body,
         )?;
+        self.insert_if_not_exists(&mut req);
 
         self.core.sign(&mut req).await?;
 
@@ -61,6 +109,174 @@ impl AzblobWriter {
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    /// Create an empty append blob which overwrites the existing one, and
+    /// then append all content to it.
+    async fn write_append_blob(&self, bs: Bytes) -> Result<()> {
+        let mut req = self.core.azblob_init_appendable_blob_request(
+            &self.path,
+            self.op.content_type(),
+            self.op.cache_control(),
+        )?;
+        self.insert_if_not_exists(&mut req);
+        self.send_created(req).await?;
+
+        let mut offset = 0;
+        while offset < bs.len() {
+            let size = (bs.len() - offset).min(MAX_BLOCK_SIZE);
+            let body = AsyncBody::Bytes(bs.slice(offset..offset + size));
+            let content_md5 = self.auto_content_md5(&body)?;
+            let req = self.core.azblob_append_blob_request(
+                &self.path,
+                size,
+                Some(offset as u64),
+                content_md5.as_deref(),
+                body,
+            )?;
+            self.send_created(req).await?;
+            offset += size;
+        }
+
+        Ok(())
+    }
+
+    /// Create a page blob with the size of content which overwrites the
+    /// existing one, and then write all non-zero pages to it.
+    async fn write_page_blob(&self, bs: Bytes) -> Result<()> {
+        check_page_aligned(bs.len() as u64)?;
+
+        let mut req = self.core.azblob_init_page_blob_request(
+            &self.path,
+            bs.len() as u64,
+            self.op.content_type(),
+            self.op.cache_control(),
+        )?;
+        self.insert_if_not_exists(&mut req);
+        self.send_created(req).await?;
+
+        for (offset, size) in non_zero_page_ranges(&bs) {
+            let body = AsyncBody::Bytes(bs.slice(offset..offset + size));
+            let content_md5 = self.auto_content_md5(&body)?;
+            let req = self.core.azblob_put_page_request(
+                &self.path,
+                offset as u64,
+                size,
+                content_md5.as_deref(),
+                body,
+            )?;
+            self.send_created(req).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Make the request which creates the blob fail if the blob exists.
+    fn insert_if_not_exists(&self, req: &mut Request<AsyncBody>) {
+        if self.op.if_not_exists() {
+            req.headers_mut()
+                .insert(IF_NONE_MATCH, HeaderValue::from_static("*"));
+        }
+    }
+
+    /// Calculate the content md5 of given request body if users enabled
+    /// `auto_content_md5`.
+    fn auto_content_md5(&self, body: &AsyncBody) -> Result<Option<String>> {
+        if self.op.auto_content_md5() {
+            format_content_md5_of_body(body).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Append blobs and page blobs are uploaded in multiple requests, so
+    /// the content md5 of whole content can only be checked locally.
+    fn check_content_md5(&self, bs: &[u8]) -> Result<()> {
+        let Some(expected) = self.op.content_md5() else {
+            return Ok(());
+        };
+
+        let actual = format_content_md5(bs);
+        if actual != expected {
+            return Err(Error::new(
+                ErrorKind::ChecksumMismatch,
+                "content md5 of the content doesn't match the given one",
+            )
+            .with_operation("AzblobWriter::write")
+            .with_context("path", &self.path)
+            .with_context("expected", expected)
+            .with_context("actual", actual));
+        }
+
+        Ok(())
+    }
+
+    async fn send_created(&self, mut req: Request<AsyncBody>) -> Result<()> {
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+/// Collect the body into bytes, since append blobs and page blobs need to
+/// be uploaded in multiple requests.
+async fn collect_body(size: u64, body: AsyncBody) -> Result<Bytes> {
+    match body {
+        AsyncBody::Empty => Ok(Bytes::new()),
+        AsyncBody::Bytes(bs) => Ok(bs),
+        AsyncBody::Stream(s) => {
+            let chunks: Vec<Bytes> = s.try_collect().await?;
+            let mut buf = BytesMut::with_capacity(size as usize);
+            chunks.iter().for_each(|bs| buf.extend_from_slice(bs));
+            Ok(buf.freeze())
+        }
+    }
+}
+
+fn check_page_aligned(size: u64) -> Result<()> {
+    if size % BlobType::PAGE_SIZE as u64 != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "the size of page blob must be aligned to 512 bytes",
+        )
+        .with_operation(oio::WriteOperation::Write)
+        .with_context("size", size.to_string()));
+    }
+    Ok(())
+}
+
+/// Returns `(offset, size)` of ranges that contain non-zero pages.
+///
+/// Unwritten pages of a page blob read as zeros, so all-zero pages are
+/// skipped. Adjacent non-zero pages are merged into one range no larger
+/// than [`MAX_BLOCK_SIZE`].
+fn non_zero_page_ranges(bs: &[u8]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for (idx, page) in bs.chunks(BlobType::PAGE_SIZE).enumerate() {
+        if page.iter().all(|b| *b == 0) {
+            continue;
+        }
+
+        let offset = idx * BlobType::PAGE_SIZE;
+        match ranges.last_mut() {
+            Some((start, size)) if *start + *size == offset && *size < MAX_BLOCK_SIZE => {
+                *size += page.len()
+            }
+            _ => ranges.push((offset, page.len())),
+        }
+    }
+
+    ranges
 }
 
 #[async_trait]
@@ -82,3 +298,254 @@ impl oio::Write for AzblobWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+    use crate::services::Azblob;
+
+    /// A fake blob, (blob type, content).
+    type Blob = (String, Vec<u8>);
+
+    /// A fake blob service which only supports the blob type related APIs.
+    #[derive(Clone, Default)]
+    struct BlobService {
+        blobs: Arc<Mutex<HashMap<String, Blob>>>,
+        /// The bytes uploaded via `Put Page`.
+        uploaded_pages: Arc<Mutex<usize>>,
+    }
+
+    impl Respond for BlobService {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let header = |k: &str| req.headers.get(&k.into()).map(|v| v.as_str().to_string());
+            let comp = req
+                .url
+                .query_pairs()
+                .find(|(k, _)| k == "comp")
+                .map(|(_, v)| v.to_string());
+
+            let mut blobs = self.blobs.lock().unwrap();
+            let key = req.url.path().to_string();
+
+            match (req.method.as_ref(), comp.as_deref()) {
+                ("HEAD", _) => match blobs.get(&key) {
+                    Some((ty, content)) => ResponseTemplate::new(200)
+                        .insert_header("x-ms-blob-type", ty.as_str())
+                        .insert_header("content-length", content.len().to_string().as_str()),
+                    None => ResponseTemplate::new(404),
+                },
+                ("PUT", None) => {
+                    if header("if-none-match").as_deref() == Some("*") && blobs.contains_key(&key) {
+                        return ResponseTemplate::new(409)
+                            .insert_header("x-ms-error-code", "BlobAlreadyExists");
+                    }
+                    let ty = header("x-ms-blob-type").unwrap();
+                    let content = match ty.as_str() {
+                        "PageBlob" => {
+                            let size = header("x-ms-blob-content-length").unwrap();
+                            vec![0; size.parse().unwrap()]
+                        }
+                        _ => req.body.clone(),
+                    };
+                    blobs.insert(key, (ty, content));
+                    ResponseTemplate::new(201)
+                }
+                ("PUT", Some("appendblock")) => {
+                    let (ty, content) = blobs.get_mut(&key).unwrap();
+                    assert_eq!(ty, "AppendBlob");
+                    if let Some(pos) = header("x-ms-blob-condition-appendpos") {
+                        assert_eq!(pos.parse::<usize>().unwrap(), content.len());
+                    }
+                    let offset = content.len();
+                    content.extend_from_slice(&req.body);
+                    ResponseTemplate::new(201)
+                        .insert_header("x-ms-blob-append-offset", offset.to_string().as_str())
+                }
+                ("PUT", Some("page")) => {
+                    let (ty, content) = blobs.get_mut(&key).unwrap();
+                    assert_eq!(ty, "PageBlob");
+                    let range = header("x-ms-range").unwrap();
+                    let (start, end) = range
+                        .strip_prefix("bytes=")
+                        .unwrap()
+                        .split_once('-')
+                        .unwrap();
+                    let (start, end): (usize, usize) =
+                        (start.parse().unwrap(), end.parse().unwrap());
+                    assert_eq!(start % BlobType::PAGE_SIZE, 0);
+                    assert_eq!((end + 1) % BlobType::PAGE_SIZE, 0);
+                    assert_eq!(req.body.len(), end - start + 1);
+                    content[start..=end].copy_from_slice(&req.body);
+                    *self.uploaded_pages.lock().unwrap() += req.body.len();
+                    ResponseTemplate::new(201)
+                }
+                _ => ResponseTemplate::new(400),
+            }
+        }
+    }
+
+    async fn new_operator(blob_type: &str) -> (MockServer, BlobService, Operator) {
+        let mock_server = MockServer::start().await;
+        let service = BlobService::default();
+        Mock::given(path("/container/test"))
+            .respond_with(service.clone())
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = Azblob::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .container("container")
+            .account_name("account")
+            .account_key("YWNjb3VudGtleQ==")
+            .blob_type(blob_type);
+        let op = Operator::new(builder).unwrap().finish();
+
+        (mock_server, service, op)
+    }
+
+    fn stat_blob_type(service: &BlobService) -> String {
+        let blobs = service.blobs.lock().unwrap();
+        blobs.get("/container/test").unwrap().0.clone()
+    }
+
+    #[tokio::test]
+    async fn test_write_block_blob() -> Result<()> {
+        let (_server, service, op) = new_operator("block").await;
+
+        op.write("test", "Hello, World!").await?;
+
+        let meta = op.stat("test").await?;
+        assert_eq!(meta.content_length(), 13);
+        assert_eq!(stat_blob_type(&service), "BlockBlob");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_if_not_exists() -> Result<()> {
+        for blob_type in ["block", "AppendBlob"] {
+            let (_server, _, op) = new_operator(blob_type).await;
+
+            op.write_with("test", "Hello, World!")
+                .if_not_exists(true)
+                .await?;
+
+            let err = op
+                .write_with("test", "Hello, World!")
+                .if_not_exists(true)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+            // Overwrite is still allowed without if_not_exists.
+            op.write("test", "Hello").await?;
+            assert_eq!(op.stat("test").await?.content_length(), 5);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_append_blob() -> Result<()> {
+        let (_server, service, op) = new_operator("AppendBlob").await;
+
+        let content = vec![1; MAX_BLOCK_SIZE + 1024];
+        op.write("test", content.clone()).await?;
+        op.append("test", "Hello, World!").await?;
+
+        let meta = op.stat("test").await?;
+        assert_eq!(meta.content_length(), content.len() as u64 + 13);
+        assert_eq!(stat_blob_type(&service), "AppendBlob");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_page_blob() -> Result<()> {
+        let (_server, service, op) = new_operator("page").await;
+
+        // Only the first and the last page contain data.
+        let mut content = vec![0; 8 * BlobType::PAGE_SIZE];
+        content[0] = 1;
+        *content.last_mut().unwrap() = 1;
+        op.write("test", content.clone()).await?;
+
+        let meta = op.stat("test").await?;
+        assert_eq!(meta.content_length(), content.len() as u64);
+        assert_eq!(stat_blob_type(&service), "PageBlob");
+        assert_eq!(
+            *service.uploaded_pages.lock().unwrap(),
+            2 * BlobType::PAGE_SIZE
+        );
+        assert!(service.blobs.lock().unwrap()["/container/test"].1 == content);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_page_blob_unaligned() -> Result<()> {
+        let (_server, service, op) = new_operator("page").await;
+
+        let err = op.write("test", vec![1; 1000]).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(service.blobs.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_append_blob_with_content_md5() -> Result<()> {
+        let (_server, service, op) = new_operator("append").await;
+
+        op.write_with("test", "Hello, World!")
+            .content_md5(&format_content_md5(b"Hello, World!"))
+            .await?;
+
+        // The whole content md5 is checked before uploading.
+        service.blobs.lock().unwrap().clear();
+        let err = op
+            .write_with("test", "Hello, World?")
+            .content_md5(&format_content_md5(b"Hello, World!"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        assert!(service.blobs.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_blob_type() {
+        let mut builder = Azblob::default();
+        builder
+            .endpoint("http://127.0.0.1")
+            .container("container")
+            .blob_type("unknown");
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_non_zero_page_ranges() {
+        let page = BlobType::PAGE_SIZE;
+        let mut bs = vec![0; 6 * page];
+        bs[page] = 1;
+        bs[2 * page + 1] = 1;
+        bs[5 * page] = 1;
+        assert_eq!(
+            non_zero_page_ranges(&bs),
+            vec![(page, 2 * page), (5 * page, page)]
+        );
+
+        let bs = vec![1; 2 * MAX_BLOCK_SIZE];
+        assert_eq!(
+            non_zero_page_ranges(&bs),
+            vec![(0, MAX_BLOCK_SIZE), (MAX_BLOCK_SIZE, MAX_BLOCK_SIZE)]
+        );
+    }
+}
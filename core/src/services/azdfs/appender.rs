@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::StatusCode;
+
+use super::core::AzdfsCore;
+use super::error::parse_error;
+use crate::raw::*;
+use crate::*;
+
+const X_MS_RESOURCE_TYPE: &str = "x-ms-resource-type";
+
+/// AzdfsAppender appends data via ADLS Gen2 path update API.
+///
+/// - The file will be created at the first append if it does not exist.
+/// - Every append will be flushed at once, so appended data is visible
+///   to others without closing the appender.
+///
+/// Creating the file and appending data are two separate requests, so
+/// create on append is not atomic.
+pub struct AzdfsAppender {
+    core: Arc<AzdfsCore>,
+
+    op: OpAppend,
+    path: String,
+    /// The position of next append, `None` means we don't know the file's
+    /// length yet.
+    position: Option<u64>,
+}
+
+impl AzdfsAppender {
+    pub fn new(core: Arc<AzdfsCore>, path: &str, op: OpAppend) -> Self {
+        AzdfsAppender {
+            core,
+            op,
+            path: path.to_string(),
+            position: None,
+        }
+    }
+
+    /// Get the current length of file, create it if not exist.
+    async fn position(&mut self) -> Result<u64> {
+        if let Some(position) = self.position {
+            return Ok(position);
+        }
+
+        let resp = self.core.azdfs_get_properties(&self.path).await?;
+
+        let status = resp.status();
+        let position = match status {
+            StatusCode::OK => {
+                let headers = resp.headers();
+                if headers
+                    .get(X_MS_RESOURCE_TYPE)
+                    .and_then(|v| v.to_str().ok())
+                    == Some("directory")
+                {
+                    return Err(Error::new(
+                        ErrorKind::IsADirectory,
+                        "the path is a directory, can't append",
+                    ));
+                }
+
+                let position = parse_content_length(headers)?.ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "missing content-length, the file may not be appendable",
+                    )
+                })?;
+                resp.into_body().consume().await?;
+                position
+            }
+            StatusCode::NOT_FOUND => {
+                resp.into_body().consume().await?;
+
+                let mut req = self.core.azdfs_create_request(
+                    &self.path,
+                    "file",
+                    self.op.content_type(),
+                    self.op.content_disposition(),
+                    AsyncBody::Empty,
+                )?;
+
+                self.core.sign(&mut req).await?;
+
+                let resp = self.core.send(req).await?;
+
+                let status = resp.status();
+                match status {
+                    StatusCode::CREATED | StatusCode::OK => {
+                        resp.into_body().consume().await?;
+                    }
+                    _ => {
+                        return Err(parse_error(resp)
+                            .await?
+                            .with_operation("Backend::azdfs_create_request"))
+                    }
+                }
+                0
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        self.position = Some(position);
+        Ok(position)
+    }
+}
+
+#[async_trait]
+impl oio::Append for AzdfsAppender {
+    async fn append(&mut self, bs: Bytes) -> Result<()> {
+        let position = self.position().await?;
+        if bs.is_empty() {
+            return Ok(());
+        }
+
+        let size = bs.len();
+        let mut req = self.core.azdfs_append_request(
+            &self.path,
+            position,
+            Some(size),
+            AsyncBody::Bytes(bs),
+        )?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::ACCEPTED => {
+                resp.into_body().consume().await?;
+            }
+            _ => {
+                return Err(parse_error(resp)
+                    .await?
+                    .with_operation("Backend::azdfs_append_request"))
+            }
+        }
+
+        let position = position + size as u64;
+        let mut req = self.core.azdfs_flush_request(&self.path, position)?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::ACCEPTED => {
+                resp.into_body().consume().await?;
+                self.position = Some(position);
+                Ok(())
+            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("Backend::azdfs_flush_request")),
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
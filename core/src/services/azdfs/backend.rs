@@ -27,6 +27,7 @@ use reqsign::AzureStorageConfig;
 use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
 
+use super::appender::AzdfsAppender;
 use super::core::AzdfsCore;
 use super::error::parse_error;
 use super::pager::AzdfsPager;
@@ -54,6 +55,7 @@ pub struct AzdfsBuilder {
     account_name: Option<String>,
     account_key: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for AzdfsBuilder {
@@ -143,6 +145,17 @@ impl AzdfsBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for AzdfsBuilder {
@@ -172,7 +185,7 @@ impl Builder for AzdfsBuilder {
         }?;
         debug!("backend use endpoint {}", &filesystem);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -180,6 +193,12 @@ impl Builder for AzdfsBuilder {
                     .with_context("service", Scheme::Azdfs)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Azdfs)
+            })?;
+        }
 
         let config_loader = AzureStorageConfig {
             account_name: self
@@ -216,6 +235,9 @@ impl Builder for AzdfsBuilder {
         map.get("account_name").map(|v| builder.account_name(v));
         map.get("account_key").map(|v| builder.account_key(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 }
@@ -232,7 +254,7 @@ impl Accessor for AzdfsBackend {
     type BlockingReader = ();
     type Writer = AzdfsWriter;
     type BlockingWriter = ();
-    type Appender = ();
+    type Appender = AzdfsAppender;
     type Pager = AzdfsPager;
     type BlockingPager = ();
 
@@ -249,9 +271,15 @@ impl Accessor for AzdfsBackend {
                 read_with_range: true,
 
                 write: true,
+                write_without_content_length: true,
+                append: true,
+                append_with_content_type: true,
+                append_with_content_disposition: true,
                 create_dir: true,
                 delete: true,
                 rename: true,
+                rename_atomic: true,
+                rename_can_dir: true,
 
                 list: true,
                 list_with_delimiter_slash: true,
@@ -297,19 +325,19 @@ impl Accessor for AzdfsBackend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        if args.content_length().is_none() {
-            return Err(Error::new(
-                ErrorKind::Unsupported,
-                "write without content length is not supported",
-            ));
-        }
-
         Ok((
             RpWrite::default(),
             AzdfsWriter::new(self.core.clone(), args, path.to_string()),
         ))
     }
 
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        Ok((
+            RpAppend::default(),
+            AzdfsAppender::new(self.core.clone(), path, args),
+        ))
+    }
+
     async fn rename(&self, from: &str, to: &str, _args: OpRename) -> Result<RpRename> {
         if let Some(resp) = self.core.azdfs_ensure_parent_path(to).await? {
             let status = resp.status();
@@ -397,9 +425,19 @@ fn infer_storage_name_from_endpoint(endpoint: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
     use super::AzdfsBuilder;
     use crate::services::azdfs::backend::infer_storage_name_from_endpoint;
     use crate::Builder;
+    use crate::Operator;
+    use crate::Result;
 
     #[test]
     fn test_infer_storage_name_from_endpoint() {
@@ -456,4 +494,148 @@ mod tests {
 
         assert_eq!(azdfs_builder.account_key, None);
     }
+
+    fn mock_operator(endpoint: &str) -> Operator {
+        let mut builder = AzdfsBuilder::default();
+        builder
+            .endpoint(endpoint)
+            .filesystem("filesystem")
+            .account_name("account")
+            .account_key("dGVzdA==");
+
+        Operator::new(builder).expect("must build").finish()
+    }
+
+    #[tokio::test]
+    async fn test_write_append_and_flush() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/filesystem/test"))
+            .and(query_param("resource", "file"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        for position in ["0", "5"] {
+            Mock::given(method("PATCH"))
+                .and(path("/filesystem/test"))
+                .and(query_param("action", "append"))
+                .and(query_param("position", position))
+                .respond_with(ResponseTemplate::new(202))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("PATCH"))
+            .and(path("/filesystem/test"))
+            .and(query_param("action", "flush"))
+            .and(query_param("position", "10"))
+            .and(query_param("close", "true"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let mut w = op.writer("test").await?;
+        w.write("Hello").await?;
+        w.write("World").await?;
+        w.close().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_append_create_if_not_exist() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/filesystem/test"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/filesystem/test"))
+            .and(query_param("resource", "file"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/filesystem/test"))
+            .and(query_param("action", "append"))
+            .and(query_param("position", "0"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/filesystem/test"))
+            .and(query_param("action", "flush"))
+            .and(query_param("position", "5"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        op.append("test", "Hello").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_append_to_existing_file() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/filesystem/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ms-resource-type", "file")
+                    .insert_header("content-length", "3"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        for (append, flush) in [("3", "8"), ("8", "13")] {
+            Mock::given(method("PATCH"))
+                .and(path("/filesystem/test"))
+                .and(query_param("action", "append"))
+                .and(query_param("position", append))
+                .respond_with(ResponseTemplate::new(202))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("PATCH"))
+                .and(path("/filesystem/test"))
+                .and(query_param("action", "flush"))
+                .and(query_param("position", flush))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let op = mock_operator(&mock_server.uri());
+        let mut a = op.appender("test").await?;
+        a.append("Hello").await?;
+        a.append("World").await?;
+        a.close().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rename_dir() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/filesystem/dst"))
+            .and(header("x-ms-rename-source", "/filesystem/src"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().rename_can_dir);
+        op.rename("src/", "dst/").await?;
+        Ok(())
+    }
 }
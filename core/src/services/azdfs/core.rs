@@ -176,9 +176,17 @@ impl AzdfsCore {
         Ok(req)
     }
 
+    /// Rename a file or a directory, a directory will be moved with all
+    /// its children atomically.
+    ///
+    /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/create
     pub async fn azdfs_rename(&self, from: &str, to: &str) -> Result<Response<IncomingAsyncBody>> {
-        let source = build_abs_path(&self.root, from);
-        let target = build_abs_path(&self.root, to);
+        let source = build_abs_path(&self.root, from)
+            .trim_end_matches('/')
+            .to_string();
+        let target = build_abs_path(&self.root, to)
+            .trim_end_matches('/')
+            .to_string();
 
         let url = format!(
             "{}/{}/{}",
@@ -200,19 +208,20 @@ impl AzdfsCore {
         self.send(req).await
     }
 
+    /// Append data at given position, data will not be visible until flushed.
+    ///
     /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/update
-    pub fn azdfs_update_request(
+    pub fn azdfs_append_request(
         &self,
         path: &str,
+        position: u64,
         size: Option<usize>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        // - close: Make this is the final action to this file.
-        // - flush: Flush the file directly.
         let url = format!(
-            "{}/{}/{}?action=append&close=true&flush=true&position=0",
+            "{}/{}/{}?action=append&position={position}",
             self.endpoint,
             self.filesystem,
             percent_encode_path(&p)
@@ -230,6 +239,31 @@ impl AzdfsCore {
         Ok(req)
     }
 
+    /// Flush all appended data, position must be equal to the length of the
+    /// file after all data has been appended.
+    ///
+    /// - close: Make this is the final action to this file.
+    ///
+    /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/update
+    pub fn azdfs_flush_request(&self, path: &str, position: u64) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?action=flush&close=true&position={position}",
+            self.endpoint,
+            self.filesystem,
+            percent_encode_path(&p)
+        );
+
+        // Content length must be 0 for flush request.
+        let req = Request::patch(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
     pub async fn azdfs_get_properties(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path)
             .trim_end_matches('/')
@@ -26,22 +26,35 @@ use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+/// AzdfsWriter writes data via ADLS Gen2 path update API.
+///
+/// - The file will be created at the first write.
+/// - Every write will append data at the end of the file.
+/// - All appended data will be flushed and visible after close.
 pub struct AzdfsWriter {
     core: Arc<AzdfsCore>,
 
     op: OpWrite,
     path: String,
+    /// The position of next append, `None` means file is not created yet.
+    position: Option<u64>,
 }
 
 impl AzdfsWriter {
     pub fn new(core: Arc<AzdfsCore>, op: OpWrite, path: String) -> Self {
-        AzdfsWriter { core, op, path }
+        AzdfsWriter {
+            core,
+            op,
+            path,
+            position: None,
+        }
     }
-}
 
-#[async_trait]
-impl oio::Write for AzdfsWriter {
-    async fn write(&mut self, bs: Bytes) -> Result<()> {
+    async fn create(&mut self) -> Result<u64> {
+        if let Some(position) = self.position {
+            return Ok(position);
+        }
+
         let mut req = self.core.azdfs_create_request(
             &self.path,
             "file",
@@ -58,17 +71,31 @@ impl oio::Write for AzdfsWriter {
         match status {
             StatusCode::CREATED | StatusCode::OK => {
                 resp.into_body().consume().await?;
+                self.position = Some(0);
+                Ok(0)
             }
-            _ => {
-                return Err(parse_error(resp)
-                    .await?
-                    .with_operation("Backend::azdfs_create_request"));
-            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("Backend::azdfs_create_request")),
         }
+    }
+}
 
-        let mut req =
-            self.core
-                .azdfs_update_request(&self.path, Some(bs.len()), AsyncBody::Bytes(bs))?;
+#[async_trait]
+impl oio::Write for AzdfsWriter {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        let position = self.create().await?;
+        if bs.is_empty() {
+            return Ok(());
+        }
+
+        let size = bs.len();
+        let mut req = self.core.azdfs_append_request(
+            &self.path,
+            position,
+            Some(size),
+            AsyncBody::Bytes(bs),
+        )?;
 
         self.core.sign(&mut req).await?;
 
@@ -78,11 +105,12 @@ impl oio::Write for AzdfsWriter {
         match status {
             StatusCode::OK | StatusCode::ACCEPTED => {
                 resp.into_body().consume().await?;
+                self.position = Some(position + size as u64);
                 Ok(())
             }
             _ => Err(parse_error(resp)
                 .await?
-                .with_operation("Backend::azdfs_update_request")),
+                .with_operation("Backend::azdfs_append_request")),
         }
     }
 
@@ -98,6 +126,23 @@ impl oio::Write for AzdfsWriter {
     }
 
     async fn close(&mut self) -> Result<()> {
-        Ok(())
+        let position = self.create().await?;
+
+        let mut req = self.core.azdfs_flush_request(&self.path, position)?;
+
+        self.core.sign(&mut req).await?;
+
+        let resp = self.core.send(req).await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::ACCEPTED => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("Backend::azdfs_flush_request")),
+        }
     }
 }
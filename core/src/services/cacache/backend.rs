@@ -18,10 +18,14 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::io;
+use std::io::Write;
 use std::str;
 
 use async_trait::async_trait;
 use cacache;
+use chrono::TimeZone;
+use chrono::Utc;
 
 use crate::raw::adapters::kv;
 use crate::Builder;
@@ -96,6 +100,7 @@ impl kv::Adapter for Adapter {
                 read: true,
                 write: true,
                 delete: true,
+                list: true,
                 blocking: true,
                 ..Default::default()
             },
@@ -116,6 +121,20 @@ impl kv::Adapter for Adapter {
         ))
     }
 
+    async fn stat(&self, path: &str) -> Result<Option<Metadata>> {
+        let meta = cacache::metadata(&self.datadir, path)
+            .await
+            .map_err(parse_error)?;
+
+        Ok(meta.map(parse_metadata))
+    }
+
+    fn blocking_stat(&self, path: &str) -> Result<Option<Metadata>> {
+        let meta = cacache::metadata_sync(&self.datadir, path).map_err(parse_error)?;
+
+        Ok(meta.map(parse_metadata))
+    }
+
     async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
         cacache::write(&self.datadir, path, value)
             .await
@@ -125,7 +144,17 @@ impl kv::Adapter for Adapter {
     }
 
     fn blocking_set(&self, path: &str, value: &[u8]) -> Result<()> {
-        cacache::write_sync(&self.datadir, path, value).map_err(parse_error)?;
+        // `cacache::write_sync` doesn't record the size into index, so we
+        // write with size specified instead.
+        let mut writer = cacache::WriteOpts::new()
+            .algorithm(cacache::Algorithm::Sha256)
+            .size(value.len())
+            .open_sync(&self.datadir, path)
+            .map_err(parse_error)?;
+        writer.write_all(value).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "error from cacache").set_source(err)
+        })?;
+        writer.commit().map_err(parse_error)?;
 
         Ok(())
     }
@@ -143,13 +172,131 @@ impl kv::Adapter for Adapter {
 
         Ok(())
     }
+
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        self.blocking_scan(path)
+    }
+
+    fn blocking_scan(&self, path: &str) -> Result<Vec<String>> {
+        let mut res = Vec::default();
+
+        for meta in cacache::list_sync(&self.datadir) {
+            let meta = match meta {
+                Ok(meta) => meta,
+                // The index will not be created until the first write.
+                Err(cacache::Error::IoError(err, _)) if err.kind() == io::ErrorKind::NotFound => {
+                    break
+                }
+                Err(err) => return Err(parse_error(err)),
+            };
+
+            if meta.key.starts_with(path) {
+                res.push(meta.key);
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// Build metadata from the index entry of cacache.
+///
+/// The integrity hash of content (like `sha256-xxx`) will be used as etag.
+fn parse_metadata(meta: cacache::Metadata) -> Metadata {
+    let mut m = Metadata::new(EntryMode::FILE)
+        .with_content_length(meta.size as u64)
+        .with_etag(meta.integrity.to_string());
+    if let Some(t) = Utc.timestamp_millis_opt(meta.time as i64).single() {
+        m = m.with_last_modified(t);
+    }
+    m
 }
 
 fn parse_error(err: cacache::Error) -> Error {
     let kind = match err {
         cacache::Error::EntryNotFound(_, _) => ErrorKind::NotFound,
+        cacache::Error::IntegrityError(_) | cacache::Error::SizeMismatch(_, _) => {
+            ErrorKind::ChecksumMismatch
+        }
         _ => ErrorKind::Unexpected,
     };
 
     Error::new(kind, "error from cacache").set_source(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    fn new_operator() -> Result<(String, BlockingOperator)> {
+        let datadir =
+            std::env::temp_dir().join(format!("opendal-cacache-{}", uuid::Uuid::new_v4()));
+        let datadir = datadir.to_string_lossy().to_string();
+
+        let mut builder = CacacheBuilder::default();
+        builder.datadir(&datadir);
+        Ok((datadir, Operator::new(builder)?.finish().blocking()))
+    }
+
+    /// Find all content files under given dir.
+    fn content_files(dir: &Path) -> Vec<std::path::PathBuf> {
+        let mut files = vec![];
+        for entry in fs::read_dir(dir).expect("read dir must succeed") {
+            let path = entry.expect("read dir entry must succeed").path();
+            if path.is_dir() {
+                files.extend(content_files(&path));
+            } else {
+                files.push(path);
+            }
+        }
+        files
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let (_, op) = new_operator()?;
+        assert!(op.list("/")?.next().is_none());
+
+        op.write("dir/a", "hello")?;
+        op.write("dir/b", "world")?;
+        assert_eq!(op.read("dir/a")?, b"hello");
+
+        let meta = op.stat("dir/a")?;
+        assert_eq!(meta.content_length(), 5);
+        assert!(meta.etag().expect("etag must exist").starts_with("sha256-"));
+        assert!(meta.last_modified().is_some());
+
+        let mut paths = op
+            .scan("dir/")?
+            .map(|e| e.map(|e| e.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        paths.sort();
+        assert_eq!(paths, vec!["dir/a", "dir/b"]);
+
+        op.delete("dir/a")?;
+        assert!(!op.is_exist("dir/a")?);
+        let paths = op
+            .scan("dir/")?
+            .map(|e| e.map(|e| e.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(paths, vec!["dir/b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_corrupted_content() -> Result<()> {
+        let (datadir, op) = new_operator()?;
+        op.write("file", "hello")?;
+
+        let files = content_files(&Path::new(&datadir).join("content-v2"));
+        assert_eq!(files.len(), 1);
+        fs::write(&files[0], "world").expect("corrupt content must succeed");
+
+        let err = op.read("file").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        Ok(())
+    }
+}
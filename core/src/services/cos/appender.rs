@@ -66,14 +66,14 @@ impl oio::Append for CosAppender {
                         .and_then(|v| v.to_str().ok())
                         .ok_or_else(|| {
                             Error::new(
-                                ErrorKind::Unexpected,
+                                ErrorKind::ConditionNotMatch,
                                 "missing x-cos-object-type, the object may not be appendable",
                             )
                         })?;
 
                     if object_type != "appendable" {
                         return Err(Error::new(
-                            ErrorKind::Unexpected,
+                            ErrorKind::ConditionNotMatch,
                             "object_type mismatch. the object may not be appendable",
                         ));
                     }
@@ -47,10 +47,13 @@ pub struct CosBuilder {
     secret_key: Option<String>,
     bucket: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 
     /// the part size of cos multipart upload, which should be 1 MB to 5 GB.
     /// There is no minimum size limit on the last part of your multipart upload
     write_min_size: Option<usize>,
+    /// the max size of data that could be buffered in memory while writing.
+    write_memory_budget: Option<usize>,
 
     disable_config_load: bool,
 }
@@ -134,6 +137,22 @@ impl CosBuilder {
         self
     }
 
+    /// Set the max size of data that could be buffered in memory while
+    /// writing.
+    ///
+    /// - Data with known size that is no larger than the budget will be
+    ///   uploaded in one request instead of multipart upload.
+    /// - Parts will be as large as the budget allows to reduce requests,
+    ///   but never smaller than `write_min_size`.
+    ///
+    /// The size of parts could still exceed the budget to keep the number
+    /// of parts no more than 10000 for large data.
+    pub fn write_memory_budget(&mut self, write_memory_budget: usize) -> &mut Self {
+        self.write_memory_budget = Some(write_memory_budget);
+
+        self
+    }
+
     /// Disable config load so that opendal will not load config from
     /// environment.
     ///
@@ -155,6 +174,17 @@ impl CosBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for CosBuilder {
@@ -171,6 +201,11 @@ impl Builder for CosBuilder {
         map.get("secret_key").map(|v| builder.secret_key(v));
         map.get("write_min_size")
             .map(|v| builder.write_min_size(v.parse().expect("input must be a number")));
+        map.get("write_memory_budget")
+            .map(|v| builder.write_memory_budget(v.parse().expect("input must be a number")));
+
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
 
         builder
     }
@@ -210,7 +245,7 @@ impl Builder for CosBuilder {
         let endpoint = uri.host().unwrap().replace(&format!("//{bucket}."), "//");
         debug!("backend use endpoint {}", &endpoint);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -218,6 +253,12 @@ impl Builder for CosBuilder {
                     .with_context("service", Scheme::Cos)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Cos)
+            })?;
+        }
 
         let mut cfg = TencentCosConfig::default();
         if !self.disable_config_load {
@@ -253,6 +294,7 @@ impl Builder for CosBuilder {
                 loader: cred_loader,
                 client,
                 write_min_size,
+                write_memory_budget: self.write_memory_budget,
             }),
         })
     }
@@ -292,10 +334,14 @@ impl Accessor for CosBackend {
 
                 write: true,
                 write_can_sink: true,
+                write_can_multi: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 write_with_content_disposition: true,
                 write_without_content_length: true,
+                write_multi_min_size: Some(1024 * 1024),
+                write_multi_max_size: Some(5 * 1024 * 1024 * 1024),
+                write_total_max_size: Some(48_800 * 1024 * 1024 * 1024),
 
                 append: true,
                 append_with_cache_control: true,
@@ -307,6 +353,7 @@ impl Accessor for CosBackend {
                 copy: true,
 
                 list: true,
+                list_max_limit: Some(1000),
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
 
@@ -365,6 +412,17 @@ impl Accessor for CosBackend {
         ))
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        Ok((
+            RpWrite::default(),
+            Box::new(CosWriter::uploader(self.core.clone(), path, args)),
+        ))
+    }
+
     async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
         Ok((
             RpAppend::default(),
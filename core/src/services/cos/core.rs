@@ -46,6 +46,7 @@ pub struct CosCore {
     pub loader: TencentCosCredentialLoader,
     pub client: HttpClient,
     pub write_min_size: usize,
+    pub write_memory_budget: Option<usize>,
 }
 
 impl Debug for CosCore {
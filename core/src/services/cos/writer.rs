@@ -36,13 +36,21 @@ pub struct CosWriter {
 impl CosWriter {
     pub fn new(core: Arc<CosCore>, path: &str, op: OpWrite) -> oio::MultipartUploadWriter<Self> {
         let write_min_size = core.write_min_size;
+        let write_memory_budget = core.write_memory_budget;
         let total_size = op.content_length();
-        let cos_writer = CosWriter {
+
+        oio::MultipartUploadWriter::new(Self::uploader(core, path, op), total_size)
+            .with_write_min_size(write_min_size)
+            .with_write_memory_budget(write_memory_budget)
+    }
+
+    /// Create a raw uploader which allows users to control parts manually.
+    pub fn uploader(core: Arc<CosCore>, path: &str, op: OpWrite) -> Self {
+        CosWriter {
             core,
             path: path.to_string(),
             op,
-        };
-        oio::MultipartUploadWriter::new(cos_writer, total_size).with_write_min_size(write_min_size)
+        }
     }
 }
 
@@ -129,7 +137,11 @@ impl oio::MultipartUploadWrite for CosWriter {
 
                 resp.into_body().consume().await?;
 
-                Ok(oio::MultipartUploadPart { part_number, etag })
+                Ok(oio::MultipartUploadPart {
+                    part_number,
+                    etag,
+                    checksum: None,
+                })
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -0,0 +1,649 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::AUTHORIZATION;
+use http::header::CONTENT_TYPE;
+use http::Request;
+use http::StatusCode;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::error::parse_error;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+const DEFAULT_ENDPOINT: &str = "https://api.cloudflare.com/client/v4";
+
+/// [Cloudflare D1](https://developers.cloudflare.com/d1) services support.
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct D1Builder {
+    token: Option<String>,
+    account_id: Option<String>,
+    database_id: Option<String>,
+    endpoint: Option<String>,
+
+    table: Option<String>,
+    key_field: Option<String>,
+    value_field: Option<String>,
+    root: Option<String>,
+
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for D1Builder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Builder");
+        ds.field("account_id", &self.account_id);
+        ds.field("database_id", &self.database_id);
+        ds.field("table", &self.table);
+        ds.finish()
+    }
+}
+
+impl D1Builder {
+    /// Set the api token of the Cloudflare account.
+    ///
+    /// The token requires the `D1:Edit` permission.
+    pub fn token(&mut self, token: &str) -> &mut Self {
+        if !token.is_empty() {
+            self.token = Some(token.to_string());
+        }
+        self
+    }
+
+    /// Set the account id of Cloudflare.
+    pub fn account_id(&mut self, account_id: &str) -> &mut Self {
+        if !account_id.is_empty() {
+            self.account_id = Some(account_id.to_string());
+        }
+        self
+    }
+
+    /// Set the database id of D1.
+    pub fn database_id(&mut self, database_id: &str) -> &mut Self {
+        if !database_id.is_empty() {
+            self.database_id = Some(database_id.to_string());
+        }
+        self
+    }
+
+    /// Set the endpoint of Cloudflare API.
+    ///
+    /// Default to `https://api.cloudflare.com/client/v4` if not specified.
+    pub fn endpoint(&mut self, endpoint: &str) -> &mut Self {
+        if !endpoint.is_empty() {
+            self.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+
+    /// Set the table name of the d1 service to read/write.
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        if !table.is_empty() {
+            self.table = Some(table.to_string());
+        }
+        self
+    }
+
+    /// Set the key field name of the d1 service to read/write.
+    ///
+    /// Default to `key` if not specified.
+    pub fn key_field(&mut self, key_field: &str) -> &mut Self {
+        if !key_field.is_empty() {
+            self.key_field = Some(key_field.to_string());
+        }
+        self
+    }
+
+    /// Set the value field name of the d1 service to read/write.
+    ///
+    /// Default to `value` if not specified.
+    pub fn value_field(&mut self, value_field: &str) -> &mut Self {
+        if !value_field.is_empty() {
+            self.value_field = Some(value_field.to_string());
+        }
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(&mut self, http_client: HttpClient) -> &mut Self {
+        self.http_client = Some(http_client);
+        self
+    }
+}
+
+impl Builder for D1Builder {
+    const SCHEME: Scheme = Scheme::D1;
+    type Accessor = D1Backend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = D1Builder::default();
+
+        map.get("token").map(|v| builder.token(v));
+        map.get("account_id").map(|v| builder.account_id(v));
+        map.get("database_id").map(|v| builder.database_id(v));
+        map.get("endpoint").map(|v| builder.endpoint(v));
+        map.get("table").map(|v| builder.table(v));
+        map.get("key_field").map(|v| builder.key_field(v));
+        map.get("value_field").map(|v| builder.value_field(v));
+        map.get("root").map(|v| builder.root(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let token = match self.token.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "token is empty")
+                    .with_context("service", Scheme::D1))
+            }
+        };
+        let account_id = match self.account_id.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "account_id is empty")
+                    .with_context("service", Scheme::D1))
+            }
+        };
+        let database_id = match self.database_id.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "database_id is empty")
+                    .with_context("service", Scheme::D1))
+            }
+        };
+        let endpoint = self
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+
+        let table = match self.table.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "table is empty")
+                    .with_context("service", Scheme::D1))
+            }
+        };
+        let key_field = match self.key_field.clone() {
+            Some(v) => v,
+            None => "key".to_string(),
+        };
+        let value_field = match self.value_field.clone() {
+            Some(v) => v,
+            None => "value".to_string(),
+        };
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        let client = if let Some(client) = self.http_client.take() {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::D1)
+            })?
+        };
+
+        Ok(D1Backend::new(Adapter {
+            client,
+            url: format!("{endpoint}/accounts/{account_id}/d1/database/{database_id}/query"),
+            token,
+            table,
+            key_field,
+            value_field,
+        })
+        .with_root(&root))
+    }
+}
+
+/// Backend for d1 service
+pub type D1Backend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    client: HttpClient,
+    url: String,
+    token: String,
+
+    table: String,
+    key_field: String,
+    value_field: String,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("url", &self.url);
+        ds.field("table", &self.table);
+        ds.finish_non_exhaustive()
+    }
+}
+
+impl Adapter {
+    /// Send statements via the query API and return rows of every statement.
+    ///
+    /// D1 returns `429 Too Many Requests` while exceeding the rate limit,
+    /// which will be marked as temporary so that `RetryLayer` could retry it.
+    async fn query(&self, body: &QueryRequest) -> Result<Vec<Vec<HashMap<String, Value>>>> {
+        let bs = serde_json::to_vec(body).map_err(new_json_serialize_error)?;
+
+        let req = Request::post(&self.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(AUTHORIZATION, format!("Bearer {}", self.token))
+            .body(AsyncBody::Bytes(Bytes::from(bs)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        let resp: QueryResponse =
+            serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+
+        if !resp.success || resp.result.iter().any(|v| !v.success) {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "d1 returns failure for query")
+                    .with_context("response", String::from_utf8_lossy(&bs)),
+            );
+        }
+
+        Ok(resp.result.into_iter().map(|v| v.results).collect())
+    }
+
+    /// Execute a single statement and return its rows.
+    async fn execute(
+        &self,
+        sql: String,
+        params: Vec<Value>,
+    ) -> Result<Vec<HashMap<String, Value>>> {
+        let mut results = self
+            .query(&QueryRequest::Single(Statement { sql, params }))
+            .await?;
+
+        if results.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "d1 returns no result for query",
+            ));
+        }
+        Ok(results.swap_remove(0))
+    }
+
+    fn set_statement(&self, path: &str, value: &[u8]) -> Statement {
+        let table = &self.table;
+        let key_field = &self.key_field;
+        let value_field = &self.value_field;
+
+        Statement {
+            sql: format!(
+                "INSERT INTO {table} ({key_field}, {value_field}) \
+                    VALUES (?, ?) \
+                    ON CONFLICT ({key_field}) \
+                        DO UPDATE SET {value_field} = excluded.{value_field}",
+            ),
+            params: vec![path.into(), value.into()],
+        }
+    }
+
+    fn delete_statement(&self, path: &str) -> Statement {
+        Statement {
+            sql: format!("DELETE FROM {} WHERE {} = ?", self.table, self.key_field),
+            params: vec![path.into()],
+        }
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::D1,
+            &self.table,
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+                batch_write: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let query = format!(
+            "SELECT {} FROM {} WHERE {} = ? LIMIT 1",
+            self.value_field, self.table, self.key_field
+        );
+        let rows = self.execute(query, vec![path.into()]).await?;
+
+        match rows.into_iter().next() {
+            None => Ok(None),
+            Some(mut row) => match row.remove(&self.value_field) {
+                None => Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "d1 returns row without value field",
+                )),
+                Some(v) => parse_value(v).map(Some),
+            },
+        }
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        self.query(&QueryRequest::Single(self.set_statement(path, value)))
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.query(&QueryRequest::Single(self.delete_statement(path)))
+            .await?;
+        Ok(())
+    }
+
+    /// Scan keys by comparing the prefix directly.
+    ///
+    /// We don't use `LIKE` here, which is case-insensitive for ASCII and
+    /// requires escaping of `%` and `_` in path.
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let query = format!(
+            "SELECT {key_field} FROM {table} WHERE substr({key_field}, 1, length(?)) = ?",
+            key_field = self.key_field,
+            table = self.table
+        );
+        let rows = self.execute(query, vec![path.into(), path.into()]).await?;
+
+        rows.into_iter()
+            .map(|mut row| match row.remove(&self.key_field) {
+                Some(Value::String(v)) => Ok(v),
+                _ => Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "d1 returns row without valid key field",
+                )),
+            })
+            .collect()
+    }
+
+    /// Commit mutations via the batch query API.
+    ///
+    /// D1 executes all statements in one batch as a transaction, so the
+    /// batch will be committed atomically.
+    async fn batch(&self, mutations: Vec<(String, kv::Mutation)>) -> Result<()> {
+        let statements = mutations
+            .iter()
+            .map(|(path, mutation)| match mutation {
+                kv::Mutation::Set(bs) => self.set_statement(path, bs),
+                kv::Mutation::Delete => self.delete_statement(path),
+            })
+            .collect();
+
+        self.query(&QueryRequest::Batch { batch: statements })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Convert a value returned by d1 into bytes.
+///
+/// Blobs are returned as array of bytes, text is accepted as well since
+/// sqlite doesn't enforce column types.
+fn parse_value(v: Value) -> Result<Vec<u8>> {
+    match v {
+        Value::Null => Ok(vec![]),
+        Value::String(s) => Ok(s.into_bytes()),
+        Value::Array(_) => serde_json::from_value(v).map_err(new_json_deserialize_error),
+        v => Err(
+            Error::new(ErrorKind::Unexpected, "value type is not supported")
+                .with_context("value", v.to_string()),
+        ),
+    }
+}
+
+/// Request of d1's query API.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum QueryRequest {
+    Single(Statement),
+    Batch { batch: Vec<Statement> },
+}
+
+#[derive(Debug, Serialize)]
+struct Statement {
+    sql: String,
+    params: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResponse {
+    #[serde(default)]
+    result: Vec<QueryResult>,
+    success: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResult {
+    #[serde(default)]
+    results: Vec<HashMap<String, Value>>,
+    success: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use serde_json::json;
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+    use crate::layers::RetryLayer;
+
+    /// A fake d1 server which only understands the statements sent by this
+    /// service.
+    #[derive(Clone, Default)]
+    struct FakeD1 {
+        rows: Arc<Mutex<BTreeMap<String, Value>>>,
+        requests: Arc<Mutex<usize>>,
+    }
+
+    impl FakeD1 {
+        fn execute(rows: &mut BTreeMap<String, Value>, stmt: &Value) -> Value {
+            let sql = stmt["sql"].as_str().unwrap();
+            let params = stmt["params"].as_array().unwrap();
+            let key = params[0].as_str().unwrap().to_string();
+
+            let results: Vec<Value> = if sql.starts_with("SELECT value") {
+                rows.get(&key)
+                    .map(|v| json!({ "value": v }))
+                    .into_iter()
+                    .collect()
+            } else if sql.starts_with("SELECT key") {
+                rows.keys()
+                    .filter(|k| k.starts_with(&key))
+                    .map(|k| json!({ "key": k }))
+                    .collect()
+            } else if sql.starts_with("INSERT") {
+                rows.insert(key, params[1].clone());
+                vec![]
+            } else if sql.starts_with("DELETE") {
+                rows.remove(&key);
+                vec![]
+            } else {
+                unreachable!("unexpected sql: {sql}")
+            };
+
+            json!({ "meta": {}, "results": results, "success": true })
+        }
+    }
+
+    impl Respond for FakeD1 {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            // Reject every third request to make sure rate limit is retried.
+            {
+                let mut requests = self.requests.lock().unwrap();
+                *requests += 1;
+                if *requests % 3 == 0 {
+                    return ResponseTemplate::new(429).set_body_json(json!({
+                        "result": null,
+                        "success": false,
+                        "errors": [{ "code": 7429, "message": "Too many requests" }],
+                        "messages": [],
+                    }));
+                }
+            }
+
+            let body: Value = serde_json::from_slice(&req.body).unwrap();
+            let mut rows = self.rows.lock().unwrap();
+            let result: Vec<Value> = match body.get("batch") {
+                Some(batch) => batch
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|stmt| Self::execute(&mut rows, stmt))
+                    .collect(),
+                None => vec![Self::execute(&mut rows, &body)],
+            };
+
+            ResponseTemplate::new(200).set_body_json(json!({
+                "result": result,
+                "success": true,
+                "errors": [],
+                "messages": [],
+            }))
+        }
+    }
+
+    async fn new_operator(mock_server: &MockServer) -> Operator {
+        Mock::given(method("POST"))
+            .and(path("/accounts/account/d1/database/database/query"))
+            .and(header("Authorization", "Bearer token"))
+            .respond_with(FakeD1::default())
+            .mount(mock_server)
+            .await;
+
+        let mut builder = D1Builder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .token("token")
+            .account_id("account")
+            .database_id("database")
+            .table("data");
+        Operator::new(builder)
+            .unwrap()
+            .layer(RetryLayer::new().with_min_delay(Duration::from_millis(1)))
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_d1_kv() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let op = new_operator(&mock_server).await;
+
+        op.write("dir/a", "Hello, World!").await?;
+        op.write("dir/b", vec![0, 1, 2, 255]).await?;
+        op.write("other", "").await?;
+
+        assert_eq!(op.read("dir/a").await?, b"Hello, World!");
+        assert_eq!(op.read("dir/b").await?, vec![0, 1, 2, 255]);
+        assert_eq!(op.stat("dir/a").await?.content_length(), 13);
+
+        let mut entries: Vec<String> = op
+            .list_with("dir/")
+            .delimiter("")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, vec!["dir/a", "dir/b"]);
+
+        op.delete("dir/a").await?;
+        let err = op.read("dir/a").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_d1_batch() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let op = new_operator(&mock_server).await;
+        assert!(op.info().capability().batch_write);
+
+        op.write("a", "a").await?;
+        op.batch_write(vec![
+            ("b".to_string(), Some(Bytes::from("b"))),
+            ("a".to_string(), None),
+        ])
+        .await?;
+
+        assert!(!op.is_exist("a").await?);
+        assert_eq!(op.read("b").await?, b"b");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(
+            parse_value(json!([0, 1, 2, 255])).unwrap(),
+            vec![0, 1, 2, 255]
+        );
+        assert_eq!(parse_value(json!("abc")).unwrap(), b"abc");
+        assert_eq!(parse_value(Value::Null).unwrap(), b"");
+        assert!(parse_value(json!(1)).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use http::Response;
+use http::StatusCode;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::Error;
+use crate::ErrorKind;
+use crate::Result;
+
+/// The error body returned by Cloudflare API.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct D1Error {
+    pub errors: Vec<D1ErrorDetail>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct D1ErrorDetail {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Parse error response into Error.
+pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes().await?;
+
+    let (kind, retryable) = match parts.status {
+        // The account or database is not found, which is not the same thing
+        // as a key is not found.
+        StatusCode::NOT_FOUND => (ErrorKind::ConfigInvalid, false),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let (message, d1_err) = serde_json::from_slice::<D1Error>(&bs)
+        .map(|d1_err| (format!("{d1_err:?}"), Some(d1_err)))
+        .unwrap_or_else(|_| (String::from_utf8_lossy(&bs).into_owned(), None));
+
+    let mut err = Error::new(kind, &message);
+
+    let request_id = parts
+        .headers
+        .get("cf-ray")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    // Cloudflare could return multiple errors, use the first one as error code.
+    let (code, message) = match d1_err.as_ref().and_then(|v| v.errors.first()) {
+        Some(v) => (v.code.to_string(), v.message.as_str()),
+        None => (String::new(), ""),
+    };
+    err = err.with_service_context(&code, message, request_id);
+
+    err = with_error_response_context(err, parts);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    Ok(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_parse_error() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"{"result":null,"success":false,"errors":[{"code":7429,"message":"Too many requests"}],"messages":[]}"#,
+        );
+        let body =
+            IncomingAsyncBody::new(Box::new(oio::into_stream(stream::iter(vec![Ok(bs)]))), None);
+        let resp = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("cf-ray", "7f1b2c3d4e5f6a7b-SJC")
+            .body(body)
+            .unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+        assert_eq!(err.service_code(), Some("7429"));
+        assert_eq!(err.service_message(), Some("Too many requests"));
+        assert_eq!(err.request_id(), Some("7f1b2c3d4e5f6a7b-SJC"));
+
+        Ok(())
+    }
+}
@@ -72,6 +72,8 @@ impl Accessor for DropboxBackend {
 
                 batch: true,
                 batch_delete: true,
+                // Dropbox's `delete_batch` accepts at most 1000 entries.
+                batch_max_operations: Some(1000),
 
                 ..Default::default()
             });
@@ -188,7 +190,17 @@ impl Accessor for DropboxBackend {
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths = ops.into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => Ok(p),
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "dropbox services don't support batch write",
+                )
+                .with_context("path", p)),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let resp = self.core.dropbox_delete_batch(paths).await?;
 
@@ -108,6 +108,7 @@ pub struct DropboxBuilder {
     client_secret: Option<String>,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for DropboxBuilder {
@@ -172,6 +173,17 @@ impl DropboxBuilder {
         self.http_client = Some(http_client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for DropboxBuilder {
@@ -185,12 +197,15 @@ impl Builder for DropboxBuilder {
         map.get("refresh_token").map(|v| builder.refresh_token(v));
         map.get("client_id").map(|v| builder.client_id(v));
         map.get("client_secret").map(|v| builder.client_secret(v));
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
     fn build(&mut self) -> Result<Self::Accessor> {
         let root = normalize_root(&self.root.take().unwrap_or_default());
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -198,6 +213,12 @@ impl Builder for DropboxBuilder {
                     .with_context("service", Scheme::Dropbox)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Dropbox)
+            })?;
+        }
 
         let signer = match (self.access_token.take(), self.refresh_token.take()) {
             (Some(access_token), None) => DropboxSigner {
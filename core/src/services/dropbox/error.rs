@@ -82,3 +82,53 @@ pub fn parse_dropbox_error_summary(summary: &str) -> Option<(ErrorKind, bool)> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_parse_error_classification() -> Result<()> {
+        let cases = vec![
+            (404, "", ErrorKind::NotFound, false),
+            (
+                409,
+                r#"{"error_summary": "path/not_found/.."}"#,
+                ErrorKind::NotFound,
+                false,
+            ),
+            (
+                409,
+                r#"{"error_summary": "too_many_write_operations/.."}"#,
+                ErrorKind::RateLimited,
+                true,
+            ),
+            (429, "", ErrorKind::RateLimited, true),
+            (
+                429,
+                r#"{"error_summary": "too_many_requests/.."}"#,
+                ErrorKind::RateLimited,
+                true,
+            ),
+            (500, "", ErrorKind::Unexpected, true),
+            (503, "", ErrorKind::Unexpected, true),
+        ];
+
+        for (status, content, kind, temporary) in cases {
+            let bs = bytes::Bytes::from(content);
+            let body = IncomingAsyncBody::new(
+                Box::new(oio::into_stream(stream::iter(vec![Ok(bs)]))),
+                None,
+            );
+            let resp = Response::builder().status(status).body(body).unwrap();
+
+            let err = parse_error(resp).await?;
+            assert_eq!(err.kind(), kind, "status {status}: {content}");
+            assert_eq!(err.is_temporary(), temporary, "status {status}: {content}");
+        }
+
+        Ok(())
+    }
+}
@@ -18,11 +18,14 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::mem;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use foundationdb::api::NetworkAutoStop;
+use foundationdb::options::TransactionOption;
 use foundationdb::Database;
+use foundationdb::FdbBindingError;
 
 use crate::raw::adapters::kv;
 use crate::raw::normalize_root;
@@ -100,6 +103,13 @@ impl Builder for FoundationdbBuilder {
 /// Backend for Foundationdb services.
 pub type FoundationdbBackend = kv::Backend<Adapter>;
 
+/// FoundationDB doesn't allow transactions larger than 10,000,000 bytes,
+/// we reserve some space for the overhead of mutations.
+const TRANSACTION_SIZE_LIMIT: usize = 9_000_000;
+
+/// FoundationDB doesn't allow transactions running longer than 5 seconds.
+const TRANSACTION_TIMEOUT_MS: i32 = 5000;
+
 #[derive(Clone)]
 pub struct Adapter {
     db: Arc<Database>,
@@ -124,6 +134,7 @@ impl kv::Adapter for Adapter {
                 write: true,
                 delete: true,
                 create_dir: true,
+                batch_write: true,
                 ..Default::default()
             },
         )
@@ -167,9 +178,111 @@ impl kv::Adapter for Adapter {
             Err(e) => Err(parse_transaction_commit_error(e)),
         }
     }
+
+    /// Commit mutations in transactions, conflicts will be retried by
+    /// foundationdb until the transaction timeout.
+    ///
+    /// Mutations that exceed the size limit of one transaction will be split
+    /// into multiple transactions, which is not atomic as a whole.
+    async fn batch(&self, mutations: Vec<(String, kv::Mutation)>) -> Result<()> {
+        for chunk in split_mutations(mutations, TRANSACTION_SIZE_LIMIT) {
+            self.db
+                .run(|trx, _| {
+                    let chunk = &chunk;
+                    async move {
+                        trx.set_option(TransactionOption::Timeout(TRANSACTION_TIMEOUT_MS))?;
+                        for (path, mutation) in chunk {
+                            match mutation {
+                                kv::Mutation::Set(bs) => trx.set(path.as_bytes(), bs),
+                                kv::Mutation::Delete => trx.clear(path.as_bytes()),
+                            }
+                        }
+                        Ok::<(), FdbBindingError>(())
+                    }
+                })
+                .await
+                .map_err(parse_binding_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Split mutations into chunks so that every chunk fits in one transaction.
+///
+/// A single mutation larger than the limit will still be committed alone and
+/// rejected by foundationdb.
+fn split_mutations(
+    mutations: Vec<(String, kv::Mutation)>,
+    limit: usize,
+) -> Vec<Vec<(String, kv::Mutation)>> {
+    let mut chunks = vec![];
+    let mut chunk = vec![];
+    let mut size = 0;
+
+    for (path, mutation) in mutations {
+        let n = match &mutation {
+            kv::Mutation::Set(bs) => path.len() + bs.len(),
+            kv::Mutation::Delete => path.len(),
+        };
+        if !chunk.is_empty() && size + n > limit {
+            chunks.push(mem::take(&mut chunk));
+            size = 0;
+        }
+
+        size += n;
+        chunk.push((path, mutation));
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+fn parse_binding_error(e: FdbBindingError) -> Error {
+    // Retryable errors like `not_committed` could still be returned after
+    // reaching the retry limit, we mark them as temporary to retry later.
+    let retryable = match &e {
+        FdbBindingError::NonRetryableFdbError(err) => err.is_retryable(),
+        _ => false,
+    };
+
+    let err = Error::new(ErrorKind::Unexpected, "commit transaction")
+        .with_context("service", Scheme::Foundationdb)
+        .set_source(e);
+    if retryable {
+        err.set_temporary()
+    } else {
+        err
+    }
 }
 
 fn parse_transaction_commit_error(e: foundationdb::TransactionCommitError) -> Error {
     Error::new(ErrorKind::Unexpected, e.to_string().as_str())
         .with_context("service", Scheme::Foundationdb)
 }
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn test_split_mutations() {
+        let mutations = vec![
+            ("a".to_string(), kv::Mutation::Set(Bytes::from(vec![0; 4]))),
+            ("b".to_string(), kv::Mutation::Set(Bytes::from(vec![0; 4]))),
+            ("c".to_string(), kv::Mutation::Delete),
+            ("d".to_string(), kv::Mutation::Set(Bytes::from(vec![0; 16]))),
+        ];
+
+        let chunks = split_mutations(mutations, 10);
+        let paths: Vec<Vec<&str>> = chunks
+            .iter()
+            .map(|chunk| chunk.iter().map(|(path, _)| path.as_str()).collect())
+            .collect();
+        assert_eq!(paths, vec![vec!["a", "b"], vec!["c"], vec!["d"]]);
+    }
+}
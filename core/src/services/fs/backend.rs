@@ -144,20 +144,23 @@ impl Builder for FsBuilder {
             .with_context("root", root.to_string_lossy())
             .set_source(e)
         })?;
+        let root = to_extended_length_path(root);
 
         // Canonicalize the atomic_write_dir directory. This should work since we already know that
         // we can get the metadata of the path.
         let atomic_write_dir = atomic_write_dir
             .map(|p| {
-                p.canonicalize().map(Some).map_err(|e| {
-                    Error::new(
-                        ErrorKind::Unexpected,
-                        "canonicalize of atomic_write_dir directory failed",
-                    )
-                    .with_operation("Builder::build")
-                    .with_context("root", root.to_string_lossy())
-                    .set_source(e)
-                })
+                p.canonicalize()
+                    .map(|p| Some(to_extended_length_path(p)))
+                    .map_err(|e| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "canonicalize of atomic_write_dir directory failed",
+                        )
+                        .with_operation("Builder::build")
+                        .with_context("root", root.to_string_lossy())
+                        .set_source(e)
+                    })
             })
             .unwrap_or(Ok(None))?;
 
@@ -186,10 +189,56 @@ fn tmp_file_of(path: &str) -> String {
     format!("{name}.{uuid}")
 }
 
+/// Join the relative path to the given root.
+///
+/// Extended-length paths on Windows don't accept `/` as separator, so we
+/// need to convert them before joining.
+#[inline]
+fn join_path(root: &Path, path: &str) -> PathBuf {
+    if cfg!(windows) {
+        root.join(path.replace('/', "\\"))
+    } else {
+        root.join(path)
+    }
+}
+
+/// Convert the canonicalized path into an extended-length path on Windows,
+/// so that paths longer than `MAX_PATH` (260 chars) and network shares work
+/// as expected.
+#[cfg(windows)]
+fn to_extended_length_path(path: PathBuf) -> PathBuf {
+    match path.to_str() {
+        Some(p) => PathBuf::from(extended_length_path(p)),
+        None => path,
+    }
+}
+
+#[cfg(not(windows))]
+#[inline]
+fn to_extended_length_path(path: PathBuf) -> PathBuf {
+    path
+}
+
+/// Build the extended-length form of a Windows path:
+///
+/// - `C:\path` will be converted into `\\?\C:\path`.
+/// - `\\server\share\path` will be converted into `\\?\UNC\server\share\path`.
+/// - Paths already in extended-length or device form are kept as is.
+#[cfg(any(windows, test))]
+fn extended_length_path(path: &str) -> String {
+    if path.starts_with(r"\\?\") || path.starts_with(r"\\.\") {
+        path.to_string()
+    } else if let Some(unc) = path.strip_prefix(r"\\") {
+        format!(r"\\?\UNC\{unc}")
+    } else {
+        format!(r"\\?\{path}")
+    }
+}
+
 impl FsBackend {
     // Synchronously build write path and ensure the parent dirs created
     fn blocking_ensure_write_abs_path(parent: &Path, path: &str) -> Result<PathBuf> {
-        let p = parent.join(path);
+        let p = join_path(parent, path);
 
         // Create dir before write path.
         //
@@ -215,7 +264,7 @@ impl FsBackend {
 
     // Build write path and ensure the parent dirs created
     async fn ensure_write_abs_path(parent: &Path, path: &str) -> Result<PathBuf> {
-        let p = parent.join(path);
+        let p = join_path(parent, path);
 
         // Create dir before write path.
         //
@@ -268,6 +317,7 @@ impl Accessor for FsBackend {
                 write_without_content_length: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
 
                 append: true,
 
@@ -276,6 +326,7 @@ impl Accessor for FsBackend {
 
                 copy: true,
                 rename: true,
+                rename_atomic: true,
                 blocking: true,
 
                 ..Default::default()
@@ -285,7 +336,7 @@ impl Accessor for FsBackend {
     }
 
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         tokio::fs::create_dir_all(&p)
             .await
@@ -306,7 +357,7 @@ impl Accessor for FsBackend {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         use oio::ReadExt;
 
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let mut f = tokio::fs::OpenOptions::new()
             .read(true)
@@ -404,7 +455,7 @@ impl Accessor for FsBackend {
     }
 
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let from = self.root.join(from.trim_end_matches('/'));
+        let from = join_path(&self.root, from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
         tokio::fs::metadata(&from).await.map_err(parse_io_error)?;
@@ -417,7 +468,7 @@ impl Accessor for FsBackend {
     }
 
     async fn rename(&self, from: &str, to: &str, _args: OpRename) -> Result<RpRename> {
-        let from = self.root.join(from.trim_end_matches('/'));
+        let from = join_path(&self.root, from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
         tokio::fs::metadata(&from).await.map_err(parse_io_error)?;
@@ -430,7 +481,7 @@ impl Accessor for FsBackend {
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let meta = tokio::fs::metadata(&p).await.map_err(parse_io_error)?;
 
@@ -459,14 +510,18 @@ impl Accessor for FsBackend {
         Ok(RpStat::new(m))
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let p = self.root.join(path.trim_end_matches('/'));
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let meta = tokio::fs::metadata(&p).await;
 
         match meta {
             Ok(meta) => {
-                if meta.is_dir() {
+                if meta.is_dir() && args.recursive() {
+                    tokio::fs::remove_dir_all(&p)
+                        .await
+                        .map_err(parse_io_error)?;
+                } else if meta.is_dir() {
                     tokio::fs::remove_dir(&p).await.map_err(parse_io_error)?;
                 } else {
                     tokio::fs::remove_file(&p).await.map_err(parse_io_error)?;
@@ -480,7 +535,7 @@ impl Accessor for FsBackend {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let f = match tokio::fs::read_dir(&p).await {
             Ok(rd) => rd,
@@ -499,7 +554,7 @@ impl Accessor for FsBackend {
     }
 
     fn blocking_create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         std::fs::create_dir_all(p).map_err(parse_io_error)?;
 
@@ -509,7 +564,7 @@ impl Accessor for FsBackend {
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
         use oio::BlockingRead;
 
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let mut f = std::fs::OpenOptions::new()
             .read(true)
@@ -589,7 +644,7 @@ impl Accessor for FsBackend {
     }
 
     fn blocking_copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let from = self.root.join(from.trim_end_matches('/'));
+        let from = join_path(&self.root, from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
         std::fs::metadata(&from).map_err(parse_io_error)?;
@@ -602,7 +657,7 @@ impl Accessor for FsBackend {
     }
 
     fn blocking_rename(&self, from: &str, to: &str, _args: OpRename) -> Result<RpRename> {
-        let from = self.root.join(from.trim_end_matches('/'));
+        let from = join_path(&self.root, from.trim_end_matches('/'));
 
         // try to get the metadata of the source file to ensure it exists
         std::fs::metadata(&from).map_err(parse_io_error)?;
@@ -615,7 +670,7 @@ impl Accessor for FsBackend {
     }
 
     fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let meta = std::fs::metadata(p).map_err(parse_io_error)?;
 
@@ -644,14 +699,16 @@ impl Accessor for FsBackend {
         Ok(RpStat::new(m))
     }
 
-    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let p = self.root.join(path.trim_end_matches('/'));
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let meta = std::fs::metadata(&p);
 
         match meta {
             Ok(meta) => {
-                if meta.is_dir() {
+                if meta.is_dir() && args.recursive() {
+                    std::fs::remove_dir_all(&p).map_err(parse_io_error)?;
+                } else if meta.is_dir() {
                     std::fs::remove_dir(&p).map_err(parse_io_error)?;
                 } else {
                     std::fs::remove_file(&p).map_err(parse_io_error)?;
@@ -665,7 +722,7 @@ impl Accessor for FsBackend {
     }
 
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
-        let p = self.root.join(path.trim_end_matches('/'));
+        let p = join_path(&self.root, path.trim_end_matches('/'));
 
         let f = match std::fs::read_dir(p) {
             Ok(rd) => rd,
@@ -702,4 +759,43 @@ mod tests {
             assert!(tmp_file.starts_with(expected_prefix));
         }
     }
+
+    #[test]
+    fn test_extended_length_path() {
+        let cases = vec![
+            (r"C:\data\root", r"\\?\C:\data\root"),
+            (r"\\server\share\root", r"\\?\UNC\server\share\root"),
+            (r"\\?\C:\data\root", r"\\?\C:\data\root"),
+            (r"\\?\UNC\server\share", r"\\?\UNC\server\share"),
+            (r"\\.\pipe\name", r"\\.\pipe\name"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(extended_length_path(input), expected, "{input}");
+        }
+    }
+
+    #[cfg(windows)]
+    #[tokio::test]
+    async fn test_write_long_path() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("opendal-fs-{}", Uuid::new_v4()));
+        let mut builder = FsBuilder::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::new(builder)?.finish();
+
+        let path = format!("{}/{}.txt", "a".repeat(200), "b".repeat(100));
+        assert!(root.to_string_lossy().len() + path.len() > 260);
+
+        op.write(&path, "hello").await?;
+        assert_eq!(op.read(&path).await?, b"hello");
+        assert_eq!(op.stat(&path).await?.content_length(), 5);
+
+        let entries = op.list(&format!("{}/", "a".repeat(200))).await?;
+        let paths: Vec<String> = entries.iter().map(|e| e.path().to_string()).collect();
+        assert_eq!(paths, vec![path.clone()]);
+
+        op.delete(&path).await?;
+        let _ = std::fs::remove_dir_all(&root);
+        Ok(())
+    }
 }
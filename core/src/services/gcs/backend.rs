@@ -32,6 +32,7 @@ use serde::Deserialize;
 use serde_json;
 
 use super::core::GcsCore;
+use super::core::GcsHmacKey;
 use super::error::parse_error;
 use super::pager::GcsPager;
 use super::writer::GcsWriter;
@@ -42,6 +43,36 @@ const DEFAULT_GCS_ENDPOINT: &str = "https://storage.googleapis.com";
 const DEFAULT_GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
 /// It's recommended that you use at least 8 MiB for the chunk size.
 const DEFAULT_WRITE_FIXED_SIZE: usize = 8 * 1024 * 1024;
+
+/// GcsIfMetagenerationMatch makes read, stat, write and delete only succeed
+/// if the metageneration of the object matches the given value.
+///
+/// The metageneration of an object is changed whenever its metadata is
+/// updated, so it can be used to avoid overwriting changes made by others.
+/// Failed preconditions will be returned as [`ErrorKind::ConditionNotMatch`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::GcsIfMetagenerationMatch;
+/// use opendal::Operator;
+///
+/// async fn test(op: Operator) -> Result<()> {
+///     op.delete_with("path/to/file")
+///         .extension(GcsIfMetagenerationMatch(2))
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GcsIfMetagenerationMatch(pub u64);
+
+/// Get the metageneration precondition attached to the operation.
+pub(super) fn if_metageneration_match(extensions: &Extensions) -> Option<u64> {
+    extensions.get::<GcsIfMetagenerationMatch>().map(|v| v.0)
+}
+
 /// Google Cloud Storage service.
 ///
 /// # Capabilities
@@ -66,11 +97,26 @@ const DEFAULT_WRITE_FIXED_SIZE: usize = 8 * 1024 * 1024;
 /// - `bucket`: Set the container name for backend
 /// - `endpoint`: Customizable endpoint setting
 /// - `credentials`: Credential string for GCS OAuth2
+/// - `hmac_access_id`, `hmac_secret`: HMAC key for GCS, requests will be signed in S3 style
 /// - `predefined_acl`: Predefined ACL for GCS
 /// - `default_storage_class`: Default storage class for GCS
 ///
 /// You can refer to [`GcsBuilder`]'s docs for more information
 ///
+/// # Versioning
+///
+/// GCS uses object generations as its versioning mechanism. OpenDAL exposes
+/// the generation as the version of an object:
+///
+/// - `list_with(path).versions(true)` lists all generations of objects, and
+///   returns every generation as a separate entry.
+/// - `read_with`, `stat_with` and `delete_with` accept `version` to target a
+///   specific generation.
+///
+/// Attach [`GcsIfMetagenerationMatch`] as extension to make operations only
+/// succeed if the metageneration of the object matches. Failed preconditions
+/// will be returned as [`ErrorKind::ConditionNotMatch`].
+///
 /// # Example
 ///
 /// ## Via Builder
@@ -119,8 +165,13 @@ pub struct GcsBuilder {
     credential: Option<String>,
     /// credential path for GCS service.
     credential_path: Option<String>,
+    /// HMAC access id for GCS service.
+    hmac_access_id: Option<String>,
+    /// HMAC secret for GCS service.
+    hmac_secret: Option<String>,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
     customed_token_loader: Option<Box<dyn GoogleTokenLoad>>,
     predefined_acl: Option<String>,
     default_storage_class: Option<String>,
@@ -198,6 +249,31 @@ impl GcsBuilder {
         self
     }
 
+    /// Set the access id of HMAC key for GCS.
+    ///
+    /// With HMAC key, requests will be sent over XML API and signed in S3
+    /// style, which is useful for users migrating from S3. HMAC key can't
+    /// be used along with service account credentials.
+    ///
+    /// Only object operations like `read`, `stat`, `write` and `delete` are
+    /// supported with HMAC key.
+    ///
+    /// Reference: [HMAC keys](https://cloud.google.com/storage/docs/authentication/hmackeys)
+    pub fn hmac_access_id(&mut self, access_id: &str) -> &mut Self {
+        if !access_id.is_empty() {
+            self.hmac_access_id = Some(access_id.to_string())
+        };
+        self
+    }
+
+    /// Set the secret of HMAC key for GCS.
+    pub fn hmac_secret(&mut self, secret: &str) -> &mut Self {
+        if !secret.is_empty() {
+            self.hmac_secret = Some(secret.to_string())
+        };
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -209,6 +285,17 @@ impl GcsBuilder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// Specify the customed token loader used by this service.
     pub fn customed_token_loader(&mut self, token_load: Box<dyn GoogleTokenLoad>) -> &mut Self {
         self.customed_token_loader = Some(token_load);
@@ -266,6 +353,12 @@ impl Debug for GcsBuilder {
         if self.credential.is_some() {
             ds.field("credentials", &"<redacted>");
         }
+        if self.hmac_access_id.is_some() {
+            ds.field("hmac_access_id", &self.hmac_access_id);
+        }
+        if self.hmac_secret.is_some() {
+            ds.field("hmac_secret", &"<redacted>");
+        }
         if self.predefined_acl.is_some() {
             ds.field("predefined_acl", &self.predefined_acl);
         }
@@ -286,10 +379,15 @@ impl Builder for GcsBuilder {
         map.get("endpoint").map(|v| builder.endpoint(v));
         map.get("credential").map(|v| builder.credential(v));
         map.get("scope").map(|v| builder.scope(v));
+        map.get("hmac_access_id").map(|v| builder.hmac_access_id(v));
+        map.get("hmac_secret").map(|v| builder.hmac_secret(v));
         map.get("predefined_acl").map(|v| builder.predefined_acl(v));
         map.get("default_storage_class")
             .map(|v| builder.default_storage_class(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -311,7 +409,7 @@ impl Builder for GcsBuilder {
 
         // TODO: server side encryption
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -319,6 +417,12 @@ impl Builder for GcsBuilder {
                     .with_context("service", Scheme::Gcs)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Gcs)
+            })?;
+        }
 
         let endpoint = self
             .endpoint
@@ -326,6 +430,33 @@ impl Builder for GcsBuilder {
             .unwrap_or_else(|| DEFAULT_GCS_ENDPOINT.to_string());
         debug!("backend use endpoint: {endpoint}");
 
+        let hmac_key = match (&self.hmac_access_id, &self.hmac_secret) {
+            (None, None) => None,
+            (Some(access_id), Some(secret)) => {
+                if self.credential.is_some()
+                    || self.credential_path.is_some()
+                    || self.service_account.is_some()
+                    || self.customed_token_loader.is_some()
+                {
+                    return Err(Error::new(
+                        ErrorKind::ConfigInvalid,
+                        "hmac key and service account credential can't be set at the same time",
+                    )
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Gcs));
+                }
+                Some(GcsHmacKey::new(access_id, secret))
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "hmac_access_id and hmac_secret must be set together",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Gcs))
+            }
+        };
+
         let mut cred_loader = GoogleCredentialLoader::default();
         if let Some(cred) = &self.credential {
             cred_loader = cred_loader.with_content(cred);
@@ -373,6 +504,7 @@ impl Builder for GcsBuilder {
                 signer,
                 token_loader,
                 credential_loader: cred_loader,
+                hmac_key,
                 predefined_acl: self.predefined_acl.clone(),
                 default_storage_class: self.default_storage_class.clone(),
                 write_fixed_size,
@@ -400,52 +532,80 @@ impl Accessor for GcsBackend {
     type BlockingPager = ();
 
     fn info(&self) -> AccessorInfo {
+        let mut cap = Capability {
+            create_dir: true,
+
+            stat: true,
+            stat_with_if_match: true,
+            stat_with_if_none_match: true,
+            stat_with_version: true,
+
+            read: true,
+            read_can_next: true,
+            read_with_range: true,
+            read_with_if_match: true,
+            read_with_if_none_match: true,
+            read_with_version: true,
+
+            write: true,
+            write_can_sink: true,
+            write_with_content_type: true,
+            write_without_content_length: true,
+            write_total_max_size: Some(5 * 1024 * 1024 * 1024 * 1024),
+            delete: true,
+            delete_with_version: true,
+            copy: true,
+
+            list: true,
+            list_with_limit: true,
+            list_max_limit: Some(1000),
+            list_with_start_after: true,
+            list_with_delimiter_slash: true,
+            list_without_delimiter: true,
+            list_with_delimiter_custom: true,
+            list_with_versions: true,
+            list_with_continuation: true,
+
+            batch: true,
+            batch_max_operations: Some(100),
+            presign: true,
+            presign_stat: true,
+            presign_read: true,
+            presign_write: true,
+
+            ..Default::default()
+        };
+        // Only object operations are supported over XML API.
+        if self.core.use_xml_api() {
+            cap.write_without_content_length = false;
+            cap.copy = false;
+            cap.list = false;
+            cap.list_with_limit = false;
+            cap.list_with_start_after = false;
+            cap.list_with_delimiter_slash = false;
+            cap.list_without_delimiter = false;
+            cap.list_with_delimiter_custom = false;
+            cap.list_with_versions = false;
+            cap.list_with_continuation = false;
+            cap.batch = false;
+        }
+
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::Gcs)
             .set_root(&self.core.root)
             .set_name(&self.core.bucket)
-            .set_capability(Capability {
-                create_dir: true,
-
-                stat: true,
-                stat_with_if_match: true,
-                stat_with_if_none_match: true,
-
-                read: true,
-                read_can_next: true,
-                read_with_range: true,
-                read_with_if_match: true,
-                read_with_if_none_match: true,
-
-                write: true,
-                write_can_sink: true,
-                write_with_content_type: true,
-                write_without_content_length: true,
-                delete: true,
-                copy: true,
-
-                list: true,
-                list_with_limit: true,
-                list_with_start_after: true,
-                list_with_delimiter_slash: true,
-                list_without_delimiter: true,
-
-                batch: true,
-                batch_max_operations: Some(100),
-                presign: true,
-                presign_stat: true,
-                presign_read: true,
-                presign_write: true,
-
-                ..Default::default()
-            });
+            .set_capability(cap);
         am
     }
 
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
-        let mut req = self
-            .core
-            .gcs_insert_object_request(path, Some(0), None, AsyncBody::Empty)?;
+        let mut req = if self.core.use_xml_api() {
+            self.core
+                .gcs_insert_object_xml_request(path, Some(0), None, None, AsyncBody::Empty)?
+        } else {
+            self.core
+                .gcs_insert_object_request(path, Some(0), None, None, AsyncBody::Empty)?
+        };
 
         self.core.sign(&mut req).await?;
 
@@ -462,7 +622,14 @@ impl Accessor for GcsBackend {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let resp = self
             .core
-            .gcs_get_object(path, args.range(), args.if_match(), args.if_none_match())
+            .gcs_get_object(
+                path,
+                args.range(),
+                args.if_match(),
+                args.if_none_match(),
+                args.version(),
+                if_metageneration_match(args.extensions()),
+            )
             .await?;
 
         if resp.status().is_success() {
@@ -499,10 +666,28 @@ impl Accessor for GcsBackend {
 
         let resp = self
             .core
-            .gcs_get_object_metadata(path, args.if_match(), args.if_none_match())
+            .gcs_get_object_metadata(
+                path,
+                args.if_match(),
+                args.if_none_match(),
+                args.version(),
+                if_metageneration_match(args.extensions()),
+            )
             .await?;
 
-        if resp.status().is_success() {
+        if resp.status().is_success() && self.core.use_xml_api() {
+            let mut m = parse_into_metadata(path, resp.headers())?;
+            if let Some(v) = resp
+                .headers()
+                .get("x-goog-generation")
+                .and_then(|v| v.to_str().ok())
+            {
+                m.set_version(v);
+            }
+            resp.into_body().consume().await?;
+
+            Ok(RpStat::new(m))
+        } else if resp.status().is_success() {
             // read http response body
             let slc = resp.into_body().bytes().await?;
 
@@ -518,6 +703,9 @@ impl Accessor for GcsBackend {
 
             m.set_etag(&meta.etag);
             m.set_content_md5(&meta.md5_hash);
+            if !meta.generation.is_empty() {
+                m.set_version(&meta.generation);
+            }
 
             let size = meta
                 .size
@@ -538,8 +726,15 @@ impl Accessor for GcsBackend {
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.gcs_delete_object(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self
+            .core
+            .gcs_delete_object(
+                path,
+                args.version(),
+                if_metageneration_match(args.extensions()),
+            )
+            .await?;
 
         // deleting not existing objects is ok
         if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
@@ -558,6 +753,8 @@ impl Accessor for GcsBackend {
                 args.delimiter(),
                 args.limit(),
                 args.start_after(),
+                args.versions(),
+                args.continuation(),
             ),
         ))
     }
@@ -572,7 +769,17 @@ impl Accessor for GcsBackend {
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => Ok(p),
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs services don't support batch write",
+                )
+                .with_context("path", p)),
+            })
+            .collect::<Result<Vec<_>>>()?;
         let resp = self.core.gcs_delete_objects(paths.clone()).await?;
 
         let status = resp.status();
@@ -624,20 +831,30 @@ impl Accessor for GcsBackend {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
-            PresignOperation::Stat(v) => {
-                self.core
-                    .gcs_head_object_xml_request(path, v.if_match(), v.if_none_match())?
-            }
+            PresignOperation::Stat(v) => self.core.gcs_head_object_xml_request(
+                path,
+                v.if_match(),
+                v.if_none_match(),
+                v.version(),
+                if_metageneration_match(v.extensions()),
+            )?,
             PresignOperation::Read(v) => self.core.gcs_get_object_xml_request(
                 path,
                 v.range(),
                 v.if_match(),
                 v.if_none_match(),
+                v.version(),
+                if_metageneration_match(v.extensions()),
+                v.override_content_type(),
+                v.override_content_disposition(),
+            )?,
+            PresignOperation::Write(v) => self.core.gcs_insert_object_xml_request(
+                path,
+                None,
+                None,
+                if_metageneration_match(v.extensions()),
+                AsyncBody::Empty,
             )?,
-            PresignOperation::Write(_) => {
-                self.core
-                    .gcs_insert_object_xml_request(path, None, AsyncBody::Empty)?
-            }
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -684,12 +901,333 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"contentType": "image/png",`
     content_type: String,
+    /// Generation of this object, which is used as version.
+    ///
+    /// For example: `"generation": "1660563214863653"`
+    generation: String,
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use reqsign::GoogleToken;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::ResponseTemplate;
+
     use super::*;
 
+    #[derive(Debug)]
+    struct MockTokenLoader;
+
+    #[async_trait]
+    impl GoogleTokenLoad for MockTokenLoader {
+        async fn load(&self, _: reqwest::Client) -> anyhow::Result<Option<GoogleToken>> {
+            Ok(Some(GoogleToken::new("token", 3600, DEFAULT_GCS_SCOPE)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_versions() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/storage/v1/b/bucket/o"))
+            .and(query_param("versions", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{
+  "items": [
+    {
+      "name": "test",
+      "generation": "1",
+      "metageneration": "1",
+      "size": "3",
+      "etag": "CAEQAQ==",
+      "md5Hash": "",
+      "updated": "2022-08-15T11:33:34.866Z"
+    },
+    {
+      "name": "test",
+      "generation": "2",
+      "metageneration": "1",
+      "size": "3",
+      "etag": "CAIQAQ==",
+      "md5Hash": "",
+      "updated": "2022-08-16T11:33:34.866Z"
+    }
+  ]
+}"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/storage/v1/b/bucket/o/test"))
+            .and(query_param("alt", "media"))
+            .and(query_param("generation", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "3")
+                    .set_body_string("old"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/storage/v1/b/bucket/o/test"))
+            .and(query_param("generation", "1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("bucket")
+            .customed_token_loader(Box::new(MockTokenLoader));
+        let op = Operator::new(builder)?.finish();
+
+        let entries = op.list_with("").versions(true).await?;
+        let versions: Vec<_> = entries
+            .iter()
+            .map(|e| (e.path(), e.metadata().version()))
+            .collect();
+        assert_eq!(versions, vec![("test", Some("1")), ("test", Some("2"))]);
+
+        let bs = op.read_with("test").version("1").await?;
+        assert_eq!(bs, b"old");
+
+        op.delete_with("test").version("1").await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_if_metageneration_match() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/storage/v1/b/bucket/o/test"))
+            .and(query_param("alt", "media"))
+            .and(query_param("ifMetagenerationMatch", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "3")
+                    .set_body_string("new"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/storage/v1/b/bucket/o/test"))
+            .and(query_param("generation", "1"))
+            .and(query_param("ifMetagenerationMatch", "1"))
+            .respond_with(ResponseTemplate::new(412).set_body_string(
+                r#"{"error":{"code":412,"message":"At least one of the pre-conditions you specified did not hold."}}"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("bucket")
+            .customed_token_loader(Box::new(MockTokenLoader));
+        let op = Operator::new(builder)?.finish();
+
+        let bs = op
+            .read_with("test")
+            .extension(GcsIfMetagenerationMatch(2))
+            .await?;
+        assert_eq!(bs, b"new");
+
+        let err = op
+            .delete_with("test")
+            .version("1")
+            .extension(GcsIfMetagenerationMatch(1))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_if_metageneration_match_xml_request() -> Result<()> {
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint("http://127.0.0.1:9000")
+            .bucket("bucket")
+            .hmac_access_id("GOOG1EACCESSID")
+            .hmac_secret("secret");
+        let backend = builder.build()?;
+
+        let req = backend
+            .core
+            .gcs_delete_object_xml_request("test", None, Some(3))?;
+        assert_eq!(
+            req.headers().get("x-goog-if-metageneration-match"),
+            Some(&http::HeaderValue::from_static("3"))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hmac_key() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        // Requests must be signed in S3 style with HMAC key.
+        let authorization = || {
+            |req: &Request| {
+                req.headers
+                    .get(&"authorization".into())
+                    .map(|v| {
+                        v.iter().any(|v| {
+                            v.as_str()
+                                .starts_with("AWS4-HMAC-SHA256 Credential=GOOG1EACCESSID/")
+                        })
+                    })
+                    .unwrap_or_default()
+            }
+        };
+        Mock::given(method("GET"))
+            .and(path("/bucket/test"))
+            .and(authorization())
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .set_body_string("Hello, World!"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/bucket/test"))
+            .and(authorization())
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .insert_header("etag", "\"65a8e27d8879283831b664bd8b7f0ad4\"")
+                    .insert_header("x-goog-generation", "1660563214863653"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/bucket/test"))
+            .and(authorization())
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint(&mock_server.uri())
+            .bucket("bucket")
+            .hmac_access_id("GOOG1EACCESSID")
+            .hmac_secret("secret");
+        let op = Operator::new(builder)?.finish();
+        assert!(!op.info().capability().list);
+
+        op.write("test", "Hello, World!").await?;
+        assert_eq!(op.read("test").await?, b"Hello, World!");
+        let meta = op.stat("test").await?;
+        assert_eq!(meta.content_length(), 13);
+        assert_eq!(meta.version(), Some("1660563214863653"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hmac_key_presign() -> Result<()> {
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint("http://127.0.0.1:9000")
+            .bucket("bucket")
+            .hmac_access_id("GOOG1EACCESSID")
+            .hmac_secret("secret");
+        let op = Operator::new(builder)?.finish();
+        assert!(op.info().capability().presign_read);
+
+        let req = op
+            .presign_read("dir/hello world.txt", Duration::from_secs(3600))
+            .await?;
+        assert_eq!(req.method(), http::Method::GET);
+        assert_eq!(req.uri().path(), "/bucket/dir/hello%20world.txt");
+        let query = req.uri().query().expect("query must be set");
+        assert!(query.contains("X-Amz-Credential=GOOG1EACCESSID"), "{query}");
+        assert!(query.contains("X-Amz-Expires=3600"), "{query}");
+        assert!(query.contains("X-Amz-Signature="), "{query}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_object_xml_request_with_override() -> Result<()> {
+        let mut builder = GcsBuilder::default();
+        builder
+            .endpoint("http://127.0.0.1:9000")
+            .bucket("bucket")
+            .customed_token_loader(Box::new(MockTokenLoader));
+        let backend = builder.build()?;
+
+        let req = backend.core.gcs_get_object_xml_request(
+            "test",
+            BytesRange::default(),
+            None,
+            None,
+            Some("1"),
+            None,
+            Some("image/png"),
+            Some("attachment; filename=\"test.png\""),
+        )?;
+        assert_eq!(
+            req.uri().to_string(),
+            "http://127.0.0.1:9000/bucket/test?generation=1\
+             &response-content-type=image%2Fpng\
+             &response-content-disposition=attachment%3B%20filename%3D%22test.png%22"
+        );
+
+        let req = backend.core.gcs_get_object_xml_request(
+            "test",
+            BytesRange::default(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(req.uri().to_string(), "http://127.0.0.1:9000/bucket/test");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hmac_key_conflicts_with_credential() {
+        let mut builder = GcsBuilder::default();
+        builder
+            .bucket("bucket")
+            .hmac_access_id("GOOG1EACCESSID")
+            .hmac_secret("secret")
+            .customed_token_loader(Box::new(MockTokenLoader));
+        let err = builder.build().expect_err("build must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        let mut builder = GcsBuilder::default();
+        builder.bucket("bucket").hmac_access_id("GOOG1EACCESSID");
+        let err = builder.build().expect_err("build must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
     #[test]
     fn test_deserialize_get_object_json_response() {
         let content = r#"{
@@ -720,5 +1258,6 @@ mod tests {
         assert_eq!(meta.md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
+        assert_eq!(meta.generation, "1660563214863653");
     }
 }
@@ -32,6 +32,8 @@ use http::header::IF_NONE_MATCH;
 use http::Request;
 use http::Response;
 use once_cell::sync::Lazy;
+use reqsign::AwsCredential;
+use reqsign::AwsV4Signer;
 use reqsign::GoogleCredential;
 use reqsign::GoogleCredentialLoader;
 use reqsign::GoogleSigner;
@@ -39,9 +41,15 @@ use reqsign::GoogleToken;
 use reqsign::GoogleTokenLoader;
 
 use super::uri::percent_encode_path;
+use super::uri::percent_encode_xml_path;
 use crate::raw::*;
 use crate::*;
 
+/// Header of the metageneration precondition over XML API.
+///
+/// Reference: <https://cloud.google.com/storage/docs/xml-api/reference-headers#xgoogifmetagenerationmatch>
+const X_GOOG_IF_METAGENERATION_MATCH: &str = "x-goog-if-metageneration-match";
+
 pub struct GcsCore {
     pub endpoint: String,
     pub bucket: String,
@@ -51,6 +59,9 @@ pub struct GcsCore {
     pub signer: GoogleSigner,
     pub token_loader: GoogleTokenLoader,
     pub credential_loader: GoogleCredentialLoader,
+    /// If set, requests will be sent over XML API and signed by HMAC key
+    /// instead of OAuth2 token.
+    pub hmac_key: Option<GcsHmacKey>,
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
@@ -68,6 +79,29 @@ impl Debug for GcsCore {
     }
 }
 
+/// GcsHmacKey signs requests in S3 style with HMAC key.
+///
+/// Reference: <https://cloud.google.com/storage/docs/authentication/hmackeys>
+pub struct GcsHmacKey {
+    pub signer: AwsV4Signer,
+    pub credential: AwsCredential,
+}
+
+impl GcsHmacKey {
+    pub fn new(access_id: &str, secret: &str) -> Self {
+        GcsHmacKey {
+            // GCS accepts S3 style signature with region `auto`.
+            signer: AwsV4Signer::new("s3", "auto"),
+            credential: AwsCredential {
+                access_key_id: access_id.to_string(),
+                secret_access_key: secret.to_string(),
+                session_token: None,
+                expires_in: None,
+            },
+        }
+    }
+}
+
 static BACKOFF: Lazy<ExponentialBuilder> =
     Lazy::new(|| ExponentialBuilder::default().with_jitter());
 
@@ -104,13 +138,32 @@ impl GcsCore {
         }
     }
 
+    /// HMAC key could only be used over XML API.
+    pub fn use_xml_api(&self) -> bool {
+        self.hmac_key.is_some()
+    }
+
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
+        if let Some(key) = &self.hmac_key {
+            return key
+                .signer
+                .sign(req, &key.credential)
+                .map_err(new_request_sign_error);
+        }
+
         let cred = self.load_token().await?;
 
         self.signer.sign(req, &cred).map_err(new_request_sign_error)
     }
 
     pub async fn sign_query<T>(&self, req: &mut Request<T>, duration: Duration) -> Result<()> {
+        if let Some(key) = &self.hmac_key {
+            return key
+                .signer
+                .sign_query(req, duration, &key.credential)
+                .map_err(new_request_sign_error);
+        }
+
         let cred = self.load_credential()?;
 
         self.signer
@@ -131,15 +184,24 @@ impl GcsCore {
         range: BytesRange,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}?alt=media",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        if let Some(version) = version {
+            write!(url, "&generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
+        if let Some(v) = if_metageneration_match {
+            write!(url, "&ifMetagenerationMatch={v}").expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -161,16 +223,48 @@ impl GcsCore {
     }
 
     // It's for presign operation. Gcs only supports query sign over XML API.
+    //
+    // Response headers can only be overridden over XML API.
+    #[allow(clippy::too_many_arguments)]
     pub fn gcs_get_object_xml_request(
         &self,
         path: &str,
         range: BytesRange,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
+        override_content_type: Option<&str>,
+        override_content_disposition: Option<&str>,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        let mut url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_xml_path(&p)
+        );
+
+        let mut query_args = Vec::new();
+        if let Some(version) = version {
+            query_args.push(format!("generation={}", percent_encode_path(version)))
+        }
+        if let Some(override_content_type) = override_content_type {
+            query_args.push(format!(
+                "response-content-type={}",
+                percent_encode_path(override_content_type)
+            ))
+        }
+        if let Some(override_content_disposition) = override_content_disposition {
+            query_args.push(format!(
+                "response-content-disposition={}",
+                percent_encode_path(override_content_disposition)
+            ))
+        }
+        if !query_args.is_empty() {
+            write!(url, "?{}", query_args.join("&")).expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -183,6 +277,9 @@ impl GcsCore {
         if !range.is_full() {
             req = req.header(http::header::RANGE, range.to_header());
         }
+        if let Some(v) = if_metageneration_match {
+            req = req.header(X_GOOG_IF_METAGENERATION_MATCH, v);
+        }
 
         let req = req
             .body(AsyncBody::Empty)
@@ -197,8 +294,30 @@ impl GcsCore {
         range: BytesRange,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.gcs_get_object_request(path, range, if_match, if_none_match)?;
+        let mut req = if self.use_xml_api() {
+            self.gcs_get_object_xml_request(
+                path,
+                range,
+                if_match,
+                if_none_match,
+                version,
+                if_metageneration_match,
+                None,
+                None,
+            )?
+        } else {
+            self.gcs_get_object_request(
+                path,
+                range,
+                if_match,
+                if_none_match,
+                version,
+                if_metageneration_match,
+            )?
+        };
 
         self.sign(&mut req).await?;
         self.send(req).await
@@ -209,6 +328,7 @@ impl GcsCore {
         path: &str,
         size: Option<u64>,
         content_type: Option<&str>,
+        if_metageneration_match: Option<u64>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -228,6 +348,9 @@ impl GcsCore {
         if let Some(acl) = &self.predefined_acl {
             write!(&mut url, "&predefinedAcl={}", acl).unwrap();
         }
+        if let Some(v) = if_metageneration_match {
+            write!(&mut url, "&ifMetagenerationMatch={v}").unwrap();
+        }
 
         let mut req = Request::post(&url);
 
@@ -271,15 +394,26 @@ impl GcsCore {
     pub fn gcs_insert_object_xml_request(
         &self,
         path: &str,
+        size: Option<u64>,
         content_type: Option<&str>,
+        if_metageneration_match: Option<u64>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        let url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_xml_path(&p)
+        );
 
         let mut req = Request::put(&url);
 
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
         if let Some(content_type) = content_type {
             req = req.header(CONTENT_TYPE, content_type);
         }
@@ -292,6 +426,10 @@ impl GcsCore {
             req = req.header("x-goog-storage-class", storage_class);
         }
 
+        if let Some(v) = if_metageneration_match {
+            req = req.header(X_GOOG_IF_METAGENERATION_MATCH, v);
+        }
+
         let req = req.body(body).map_err(new_request_build_error)?;
 
         Ok(req)
@@ -302,15 +440,27 @@ impl GcsCore {
         path: &str,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        let mut query_args = Vec::new();
+        if let Some(version) = version {
+            query_args.push(format!("generation={}", percent_encode_path(version)))
+        }
+        if let Some(v) = if_metageneration_match {
+            query_args.push(format!("ifMetagenerationMatch={v}"))
+        }
+        if !query_args.is_empty() {
+            write!(url, "?{}", query_args.join("&")).expect("write into string must succeed");
+        }
 
         let mut req = Request::get(&url);
 
@@ -321,7 +471,6 @@ impl GcsCore {
         if let Some(if_match) = if_match {
             req = req.header(IF_MATCH, if_match);
         }
-
         let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
@@ -335,10 +484,21 @@ impl GcsCore {
         path: &str,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}", self.endpoint, self.bucket, p);
+        let mut url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_xml_path(&p)
+        );
+        if let Some(version) = version {
+            write!(url, "?generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
 
         let mut req = Request::head(&url);
 
@@ -350,6 +510,9 @@ impl GcsCore {
             req = req.header(IF_MATCH, if_match);
         }
 
+        if let Some(v) = if_metageneration_match {
+            req = req.header(X_GOOG_IF_METAGENERATION_MATCH, v);
+        }
         let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
@@ -362,36 +525,106 @@ impl GcsCore {
         path: &str,
         if_match: Option<&str>,
         if_none_match: Option<&str>,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.gcs_head_object_request(path, if_match, if_none_match)?;
+        let mut req = if self.use_xml_api() {
+            self.gcs_head_object_xml_request(
+                path,
+                if_match,
+                if_none_match,
+                version,
+                if_metageneration_match,
+            )?
+        } else {
+            self.gcs_head_object_request(
+                path,
+                if_match,
+                if_none_match,
+                version,
+                if_metageneration_match,
+            )?
+        };
 
         self.sign(&mut req).await?;
 
         self.send(req).await
     }
 
-    pub async fn gcs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.gcs_delete_object_request(path)?;
+    pub async fn gcs_delete_object(
+        &self,
+        path: &str,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = if self.use_xml_api() {
+            self.gcs_delete_object_xml_request(path, version, if_metageneration_match)?
+        } else {
+            self.gcs_delete_object_request(path, version, if_metageneration_match)?
+        };
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub fn gcs_delete_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+    pub fn gcs_delete_object_request(
+        &self,
+        path: &str,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!(
+        let mut url = format!(
             "{}/storage/v1/b/{}/o/{}",
             self.endpoint,
             self.bucket,
             percent_encode_path(&p)
         );
+        let mut query_args = Vec::new();
+        if let Some(version) = version {
+            query_args.push(format!("generation={}", percent_encode_path(version)))
+        }
+        if let Some(v) = if_metageneration_match {
+            query_args.push(format!("ifMetagenerationMatch={v}"))
+        }
+        if !query_args.is_empty() {
+            write!(url, "?{}", query_args.join("&")).expect("write into string must succeed");
+        }
 
         Request::delete(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)
     }
 
+    pub fn gcs_delete_object_xml_request(
+        &self,
+        path: &str,
+        version: Option<&str>,
+        if_metageneration_match: Option<u64>,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_xml_path(&p)
+        );
+        if let Some(version) = version {
+            write!(url, "?generation={}", percent_encode_path(version))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::delete(&url);
+
+        if let Some(v) = if_metageneration_match {
+            req = req.header(X_GOOG_IF_METAGENERATION_MATCH, v);
+        }
+
+        req.body(AsyncBody::Empty).map_err(new_request_build_error)
+    }
+
     pub async fn gcs_delete_objects(
         &self,
         paths: Vec<String>,
@@ -401,7 +634,7 @@ impl GcsCore {
         let mut multipart = Multipart::new();
 
         for (idx, path) in paths.iter().enumerate() {
-            let req = self.gcs_delete_object_request(path)?;
+            let req = self.gcs_delete_object_request(path, None, None)?;
 
             multipart = multipart.part(
                 MixedPart::from_request(req).part_header("content-id".parse().unwrap(), idx.into()),
@@ -448,6 +681,7 @@ impl GcsCore {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<String>,
+        versions: bool,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -458,7 +692,8 @@ impl GcsCore {
             percent_encode_path(&p)
         );
         if !delimiter.is_empty() {
-            write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
+            write!(url, "&delimiter={}", percent_encode_path(delimiter))
+                .expect("write into string must succeed");
         }
         if let Some(limit) = limit {
             write!(url, "&maxResults={limit}").expect("write into string must succeed");
@@ -468,6 +703,9 @@ impl GcsCore {
             write!(url, "&startOffset={}", percent_encode_path(&start_after))
                 .expect("write into string must succeed");
         }
+        if versions {
+            write!(url, "&versions=true").expect("write into string must succeed");
+        }
 
         if !page_token.is_empty() {
             // NOTE:
@@ -492,12 +730,16 @@ impl GcsCore {
     pub async fn gcs_initiate_resumable_upload(
         &self,
         path: &str,
+        if_metageneration_match: Option<u64>,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
-        let url = format!(
+        let mut url = format!(
             "{}/upload/storage/v1/b/{}/o?uploadType=resumable&name={}",
             self.endpoint, self.bucket, p
         );
+        if let Some(v) = if_metageneration_match {
+            write!(url, "&ifMetagenerationMatch={v}").expect("write into string must succeed");
+        }
 
         let mut req = Request::post(&url)
             .header(CONTENT_LENGTH, 0)
@@ -60,6 +60,7 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
             (ErrorKind::ConditionNotMatch, false)
         }
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -67,13 +68,31 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         _ => (ErrorKind::Unexpected, false),
     };
 
-    let message = match de::from_slice::<GcsErrorResponse>(&bs) {
-        Ok(gcs_err) => format!("{gcs_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
-    };
+    let (message, gcs_err) = de::from_slice::<GcsErrorResponse>(&bs)
+        .map(|gcs_err| (format!("{gcs_err:?}"), Some(gcs_err)))
+        .unwrap_or_else(|_| (String::from_utf8_lossy(&bs).into_owned(), None));
 
     let mut err = Error::new(kind, &message);
 
+    let request_id = parts
+        .headers
+        .get("x-guploader-uploadid")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let (code, message) = match &gcs_err {
+        // GCS uses the reason of the first error detail as error code.
+        Some(v) => (
+            v.error
+                .errors
+                .first()
+                .map(|v| v.reason.as_str())
+                .unwrap_or_default(),
+            v.error.message.as_str(),
+        ),
+        None => ("", ""),
+    };
+    err = err.with_service_context(code, message, request_id);
+
     err = with_error_response_context(err, parts);
 
     if retryable {
@@ -85,6 +104,8 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
 
 #[cfg(test)]
 mod tests {
+    use futures::stream;
+
     use super::*;
 
     #[test]
@@ -120,4 +141,42 @@ mod tests {
         assert_eq!(out.error.errors[0].location_type, "header");
         assert_eq!(out.error.errors[0].location, "Authorization");
     }
+
+    #[tokio::test]
+    async fn test_parse_error_with_service_context() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"
+{
+"error": {
+ "errors": [
+  {
+   "domain": "global",
+   "reason": "rateLimitExceeded",
+   "message": "The project exceeded the rate limit."
+  }
+ ],
+ "code": 429,
+ "message": "The project exceeded the rate limit."
+ }
+}
+"#,
+        );
+        let body =
+            IncomingAsyncBody::new(Box::new(oio::into_stream(stream::iter(vec![Ok(bs)]))), None);
+        let resp = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("x-guploader-uploadid", "ADPycdvmTsFg")
+            .body(body)
+            .unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.service_code(), Some("rateLimitExceeded"));
+        assert_eq!(
+            err.service_message(),
+            Some("The project exceeded the rate limit.")
+        );
+        assert_eq!(err.request_id(), Some("ADPycdvmTsFg"));
+
+        Ok(())
+    }
 }
@@ -17,6 +17,7 @@
 
 mod backend;
 pub use backend::GcsBuilder as Gcs;
+pub use backend::GcsIfMetagenerationMatch;
 
 mod core;
 mod error;
@@ -38,6 +38,8 @@ pub struct GcsPager {
     /// Filter results to objects whose names are lexicographically
     /// **equal to or after** startOffset
     start_after: Option<String>,
+    /// List all generations of objects if enabled.
+    versions: bool,
 
     page_token: String,
     done: bool,
@@ -51,6 +53,8 @@ impl GcsPager {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<&str>,
+        versions: bool,
+        continuation: Option<&str>,
     ) -> Self {
         Self {
             core,
@@ -59,8 +63,9 @@ impl GcsPager {
             delimiter: delimiter.to_string(),
             limit,
             start_after: start_after.map(String::from),
+            versions,
 
-            page_token: "".to_string(),
+            page_token: continuation.unwrap_or_default().to_string(),
             done: false,
         }
     }
@@ -81,6 +86,7 @@ impl oio::Page for GcsPager {
                 &self.delimiter,
                 self.limit,
                 self.start_after.clone(),
+                self.versions,
             )
             .await?;
 
@@ -101,9 +107,9 @@ impl oio::Page for GcsPager {
         let mut entries = Vec::with_capacity(output.prefixes.len() + output.items.len());
 
         for prefix in output.prefixes {
-            let de = oio::Entry::new(
+            let de = oio::Entry::new_delimited_dir(
                 &build_rel_path(&self.core.root, &prefix),
-                Metadata::new(EntryMode::DIR),
+                &self.delimiter,
             );
 
             entries.push(de);
@@ -125,6 +131,9 @@ impl oio::Page for GcsPager {
             // set metadata fields
             meta.set_content_md5(object.md5_hash.as_str());
             meta.set_etag(object.etag.as_str());
+            if !object.generation.is_empty() {
+                meta.set_version(&object.generation);
+            }
 
             let size = object.size.parse().map_err(|e| {
                 Error::new(ErrorKind::Unexpected, "parse u64 from list response").set_source(e)
@@ -143,6 +152,10 @@ impl oio::Page for GcsPager {
 
         Ok(Some(entries))
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        (!self.done && !self.page_token.is_empty()).then(|| self.page_token.clone())
+    }
 }
 
 /// Response JSON from GCS list objects API.
@@ -172,6 +185,7 @@ struct ListResponseItem {
     md5_hash: String,
     updated: String,
     content_type: String,
+    generation: String,
 }
 
 #[cfg(test)]
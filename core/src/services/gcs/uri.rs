@@ -30,6 +30,10 @@ static GCS_PATH_ENCODE_SET: AsciiSet = NON_ALPHANUMERIC
     .remove(b'.')
     .remove(b'*');
 
+/// GCS_XML_PATH_ENCODE_SET is the same as [`GCS_PATH_ENCODE_SET`] but keeps
+/// '/' as is.
+static GCS_XML_PATH_ENCODE_SET: AsciiSet = GCS_PATH_ENCODE_SET.remove(b'/');
+
 /// percent_encode_path will do percent encoding for http encode path.
 ///
 /// Follows [encodeURIComponent](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent)
@@ -40,6 +44,15 @@ pub(super) fn percent_encode_path(path: &str) -> String {
     utf8_percent_encode(path, &GCS_PATH_ENCODE_SET).to_string()
 }
 
+/// percent_encode_xml_path will do percent encoding for object path over
+/// XML API.
+///
+/// Different from JSON API, object path is part of the url path in XML API,
+/// so '/'s in paths are kept as is.
+pub(super) fn percent_encode_xml_path(path: &str) -> String {
+    utf8_percent_encode(path, &GCS_XML_PATH_ENCODE_SET).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,4 +85,23 @@ mod tests {
             assert_eq!(actual, expected, "{name}");
         }
     }
+
+    #[test]
+    fn test_percent_encode_xml_path() {
+        let cases = vec![
+            ("Slash", "dir/file", "dir/file"),
+            (
+                "Reserved Characters",
+                ";,?:@&=+$",
+                "%3B%2C%3F%3A%40%26%3D%2B%24",
+            ),
+            ("Space", "dir/hello world.txt", "dir/hello%20world.txt"),
+        ];
+
+        for (name, input, expected) in cases {
+            let actual = percent_encode_xml_path(input);
+
+            assert_eq!(actual, expected, "{name}");
+        }
+    }
 }
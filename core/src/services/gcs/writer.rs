@@ -21,6 +21,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use http::StatusCode;
 
+use super::backend::if_metageneration_match;
 use super::core::GcsCore;
 use super::error::parse_error;
 use crate::raw::*;
@@ -53,12 +54,23 @@ impl GcsWriter {
     }
 
     async fn write_oneshot(&self, size: u64, body: AsyncBody) -> Result<()> {
-        let mut req = self.core.gcs_insert_object_request(
-            &percent_encode_path(&self.path),
-            Some(size),
-            self.op.content_type(),
-            body,
-        )?;
+        let mut req = if self.core.use_xml_api() {
+            self.core.gcs_insert_object_xml_request(
+                &self.path,
+                Some(size),
+                self.op.content_type(),
+                if_metageneration_match(self.op.extensions()),
+                body,
+            )?
+        } else {
+            self.core.gcs_insert_object_request(
+                &percent_encode_path(&self.path),
+                Some(size),
+                self.op.content_type(),
+                if_metageneration_match(self.op.extensions()),
+                body,
+            )?
+        };
 
         self.core.sign(&mut req).await?;
 
@@ -76,7 +88,22 @@ impl GcsWriter {
     }
 
     async fn initiate_upload(&self) -> Result<String> {
-        let resp = self.core.gcs_initiate_resumable_upload(&self.path).await?;
+        if self.core.use_xml_api() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "write without content length is not supported with hmac key",
+            )
+            .with_operation("GcsWriter::initiate_upload")
+            .with_context("service", Scheme::Gcs));
+        }
+
+        let resp = self
+            .core
+            .gcs_initiate_resumable_upload(
+                &self.path,
+                if_metageneration_match(self.op.extensions()),
+            )
+            .await?;
         let status = resp.status();
 
         match status {
@@ -27,8 +27,6 @@ use super::writer::GdriveWriter;
 use crate::raw::*;
 use crate::types::Result;
 use crate::Capability;
-use crate::Error;
-use crate::ErrorKind;
 
 #[derive(Clone, Debug)]
 pub struct GdriveBackend {
@@ -52,7 +50,7 @@ impl GdriveBackend {
 impl Accessor for GdriveBackend {
     type Reader = IncomingAsyncBody;
     type BlockingReader = ();
-    type Writer = GdriveWriter;
+    type Writer = oio::MultipartUploadWriter<GdriveWriter>;
     type BlockingWriter = ();
     type Appender = ();
     type Pager = ();
@@ -65,6 +63,7 @@ impl Accessor for GdriveBackend {
             .set_capability(Capability {
                 read: true,
                 write: true,
+                write_without_content_length: true,
                 delete: true,
                 ..Default::default()
             });
@@ -87,13 +86,6 @@ impl Accessor for GdriveBackend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        if args.content_length().is_none() {
-            return Err(Error::new(
-                ErrorKind::Unsupported,
-                "write without content length is not supported",
-            ));
-        }
-
         Ok((
             RpWrite::default(),
             GdriveWriter::new(self.core.clone(), args, String::from(path)),
@@ -106,7 +98,10 @@ impl Accessor for GdriveBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::NO_CONTENT => Ok(RpDelete::default()),
+            StatusCode::NO_CONTENT => {
+                self.core.invalidate_path(path).await;
+                Ok(RpDelete::default())
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
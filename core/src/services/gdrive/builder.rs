@@ -43,6 +43,10 @@ use crate::*;
 ///
 /// # Notes
 ///
+/// Small files written at once are uploaded via simple upload, others
+/// are uploaded via [resumable upload](https://developers.google.com/drive/api/guides/manage-uploads#resumable)
+/// in chunks, so writing large files or writing without content length
+/// is supported.
 ///
 /// # Configuration
 ///
@@ -82,6 +86,7 @@ pub struct GdriveBuilder {
     access_token: Option<String>,
     root: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for GdriveBuilder {
@@ -113,6 +118,17 @@ impl GdriveBuilder {
         self.http_client = Some(http_client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for GdriveBuilder {
@@ -126,6 +142,9 @@ impl Builder for GdriveBuilder {
         map.get("root").map(|v| builder.root(v));
         map.get("access_token").map(|v| builder.access_token(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -133,7 +152,7 @@ impl Builder for GdriveBuilder {
         let root = normalize_root(&self.root.take().unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -141,6 +160,12 @@ impl Builder for GdriveBuilder {
                     .with_context("service", Scheme::Gdrive)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Gdrive)
+            })?;
+        }
 
         match self.access_token.clone() {
             Some(access_token) => Ok(GdriveBackend::new(root, access_token, client)),
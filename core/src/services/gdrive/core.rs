@@ -44,7 +44,7 @@ pub struct GdriveCore {
     pub root: String,
     pub access_token: String,
     pub client: HttpClient,
-    pub path_cache: Arc<Mutex<HashMap<String, String>>>,
+    pub path_cache: Arc<Mutex<PathCache>>,
 }
 
 impl Debug for GdriveCore {
@@ -60,7 +60,7 @@ impl GdriveCore {
         let root = "root";
 
         if let Some(root_id) = self.path_cache.lock().await.get(root) {
-            return Ok(root_id.to_string());
+            return Ok(root_id);
         }
 
         let req = self
@@ -95,7 +95,7 @@ impl GdriveCore {
         let path = build_rooted_abs_path(&self.root, file_path);
 
         if let Some(file_id) = self.path_cache.lock().await.get(&path) {
-            return Ok(file_id.to_string());
+            return Ok(file_id);
         }
 
         let mut parent_id = self.get_abs_root_id().await?;
@@ -184,6 +184,81 @@ impl GdriveCore {
         self.client.send(req).await
     }
 
+    /// Initiate a resumable upload session for the file, the session uri
+    /// will be returned in `Location` header.
+    ///
+    /// ref: https://developers.google.com/drive/api/guides/manage-uploads#resumable
+    pub async fn gdrive_upload_initiate(
+        &self,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!(
+            "https://www.googleapis.com/upload/drive/v3/files/{}?uploadType=resumable",
+            self.get_file_id_by_path(path).await?
+        );
+
+        let mut req = Request::patch(&url).header(header::CONTENT_LENGTH, 0);
+
+        if let Some(size) = size {
+            req = req.header("X-Upload-Content-Length", size)
+        }
+
+        if let Some(mime) = content_type {
+            req = req.header("X-Upload-Content-Type", mime)
+        }
+
+        let req = self
+            .sign(req)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    /// Upload a chunk starting at `offset` to the resumable upload session.
+    ///
+    /// `total` should be set for the last chunk so that Drive knows the
+    /// upload is finished. Drive will respond `308 Resume Incomplete` for
+    /// the chunks that are not the last.
+    pub async fn gdrive_upload_chunk(
+        &self,
+        session_uri: &str,
+        offset: u64,
+        size: u64,
+        total: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let total = total.map_or_else(|| "*".to_string(), |v| v.to_string());
+        let range = if size == 0 {
+            format!("bytes */{total}")
+        } else {
+            format!("bytes {}-{}/{total}", offset, offset + size - 1)
+        };
+
+        let req = Request::put(session_uri)
+            .header(header::CONTENT_LENGTH, size)
+            .header(header::CONTENT_RANGE, range);
+
+        let req = self.sign(req).body(body).map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    /// Cancel the resumable upload session.
+    pub async fn gdrive_upload_cancel(
+        &self,
+        session_uri: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self
+            .sign(Request::delete(session_uri))
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
     pub async fn gdrive_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
         let url = format!(
             "https://www.googleapis.com/drive/v3/files/{}",
@@ -198,6 +273,14 @@ impl GdriveCore {
         self.client.send(req).await
     }
 
+    /// Remove the cached ids of path and all its children.
+    ///
+    /// Must be called after the path has been deleted or moved.
+    pub async fn invalidate_path(&self, path: &str) {
+        let path = build_rooted_abs_path(&self.root, path);
+        self.path_cache.lock().await.remove_all(&path);
+    }
+
     fn sign(&self, mut req: Builder) -> Builder {
         let auth_header_content = format!("Bearer {}", self.access_token);
         req = req.header(header::AUTHORIZATION, auth_header_content);
@@ -205,6 +288,79 @@ impl GdriveCore {
     }
 }
 
+/// The default max entries of [`PathCache`].
+const DEFAULT_PATH_CACHE_CAPACITY: usize = 4096;
+
+/// PathCache is a bounded LRU cache from path to file id.
+///
+/// Drive addresses files by id, resolving a path requires one lookup for
+/// every component of it. PathCache keeps the resolved ids to avoid
+/// those lookups.
+///
+/// The least recently used entry will be evicted while the cache is full.
+/// Finding it requires a scan over all entries, which is cheap compared
+/// to the api calls.
+pub struct PathCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<String, (String, u64)>,
+}
+
+impl Default for PathCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_PATH_CACHE_CAPACITY)
+    }
+}
+
+impl PathCache {
+    /// Create a new PathCache which holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Get the cached id of path and mark it as recently used.
+    pub fn get(&mut self, path: &str) -> Option<String> {
+        self.tick += 1;
+        let tick = self.tick;
+
+        self.entries.get_mut(path).map(|(id, used)| {
+            *used = tick;
+            id.clone()
+        })
+    }
+
+    /// Insert the id of path, evict the least recently used entry if full.
+    pub fn insert(&mut self, path: String, id: String) {
+        self.tick += 1;
+
+        if !self.entries.contains_key(&path) && self.entries.len() >= self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(k, _)| k.clone());
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+
+        self.entries.insert(path, (id, self.tick));
+    }
+
+    /// Remove path and all its children.
+    pub fn remove_all(&mut self, path: &str) {
+        let path = path.trim_end_matches('/');
+        let prefix = format!("{path}/");
+
+        self.entries
+            .retain(|k, _| k.trim_end_matches('/') != path && !k.starts_with(&prefix));
+    }
+}
+
 // refer to https://developers.google.com/drive/api/reference/rest/v3/files#File
 #[derive(Deserialize)]
 struct GdriveFile {
@@ -216,3 +372,40 @@ struct GdriveFile {
 struct GdriveFileList {
     files: Vec<GdriveFile>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_cache_evict_lru() {
+        let mut cache = PathCache::new(2);
+        cache.insert("/a".to_string(), "id_a".to_string());
+        cache.insert("/b".to_string(), "id_b".to_string());
+
+        // Mark `/a` as recently used, so `/b` will be evicted.
+        assert_eq!(cache.get("/a"), Some("id_a".to_string()));
+        cache.insert("/c".to_string(), "id_c".to_string());
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get("/a"), Some("id_a".to_string()));
+        assert_eq!(cache.get("/b"), None);
+        assert_eq!(cache.get("/c"), Some("id_c".to_string()));
+    }
+
+    #[test]
+    fn test_path_cache_remove_all() {
+        let mut cache = PathCache::default();
+        for path in ["/dir/", "/dir/a", "/dir/sub/b", "/dir_other", "root"] {
+            cache.insert(path.to_string(), format!("id_{path}"));
+        }
+
+        cache.remove_all("/dir/");
+
+        assert_eq!(cache.get("/dir/"), None);
+        assert_eq!(cache.get("/dir/a"), None);
+        assert_eq!(cache.get("/dir/sub/b"), None);
+        assert!(cache.get("/dir_other").is_some());
+        assert!(cache.get("root").is_some());
+    }
+}
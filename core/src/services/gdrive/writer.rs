@@ -16,9 +16,14 @@
 // under the License.
 
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use bytes::BytesMut;
+use futures::TryStreamExt;
+use http::header;
+use http::HeaderMap;
 use http::StatusCode;
 
 use super::core::GdriveCore;
@@ -26,28 +31,66 @@ use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
 
+/// Drive requires every chunk except the last to be a multiple of 256 KiB.
+const CHUNK_ALIGN: u64 = 256 * 1024;
+/// Files larger than this will be uploaded via resumable upload even if
+/// it's written at once.
+const SIMPLE_UPLOAD_MAX_SIZE: u64 = 5 * 1024 * 1024;
+/// Non-standard status code returned by Drive for uncompleted uploads.
+const RESUME_INCOMPLETE: u16 = 308;
+
+/// GdriveWriter writes data via Drive's upload API.
+///
+/// - Small files that are written at once will be uploaded via simple upload.
+/// - Others will be uploaded via resumable upload, and `upload_id` of
+///   [`oio::MultipartUploadWrite`] is the resumable session uri.
 pub struct GdriveWriter {
     core: Arc<GdriveCore>,
     op: OpWrite,
     path: String,
+
+    state: Mutex<ResumableState>,
+}
+
+/// The state of a resumable upload session.
+#[derive(Default)]
+struct ResumableState {
+    /// The bytes that have been persisted by Drive.
+    offset: u64,
+    /// The bytes that have not been sent since they are not aligned to
+    /// [`CHUNK_ALIGN`] or not persisted by Drive.
+    pending: Bytes,
 }
 
 impl GdriveWriter {
-    pub fn new(core: Arc<GdriveCore>, op: OpWrite, path: String) -> Self {
-        GdriveWriter { core, op, path }
+    pub fn new(
+        core: Arc<GdriveCore>,
+        op: OpWrite,
+        path: String,
+    ) -> oio::MultipartUploadWriter<Self> {
+        let total_size = op.content_length();
+
+        oio::MultipartUploadWriter::new(Self::uploader(core, op, path), total_size)
     }
-}
 
-#[async_trait]
-impl oio::Write for GdriveWriter {
-    async fn write(&mut self, bs: Bytes) -> Result<()> {
+    /// Create a raw uploader which allows users to control parts manually.
+    pub fn uploader(core: Arc<GdriveCore>, op: OpWrite, path: String) -> Self {
+        GdriveWriter {
+            core,
+            op,
+            path,
+            state: Mutex::new(ResumableState::default()),
+        }
+    }
+
+    async fn simple_upload(&self, size: u64, body: AsyncBody) -> Result<()> {
         let resp = self
             .core
             .gdrive_update(
                 &self.path,
-                Some(bs.len()),
+                Some(size as usize),
                 self.op.content_type(),
-                AsyncBody::Bytes(bs),
+                body,
             )
             .await?;
 
@@ -62,18 +105,342 @@ impl oio::Write for GdriveWriter {
         }
     }
 
-    async fn sink(&mut self, _size: u64, _s: oio::Streamer) -> Result<()> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "Write::sink is not supported",
-        ))
+    /// Upload all bytes in `pending` as the last chunk and finish the upload.
+    ///
+    /// Drive could persist only a part of the data, we will resend the
+    /// remaining bytes until all of them have been persisted.
+    async fn upload_last(
+        &self,
+        session_uri: &str,
+        mut offset: u64,
+        mut pending: Bytes,
+    ) -> Result<()> {
+        let total = offset + pending.len() as u64;
+
+        loop {
+            let size = pending.len() as u64;
+            let resp = self
+                .core
+                .gdrive_upload_chunk(
+                    session_uri,
+                    offset,
+                    size,
+                    Some(total),
+                    AsyncBody::Bytes(pending.clone()),
+                )
+                .await?;
+
+            let status = resp.status();
+            match status {
+                StatusCode::OK | StatusCode::CREATED => {
+                    resp.into_body().consume().await?;
+                    return Ok(());
+                }
+                _ if status.as_u16() == RESUME_INCOMPLETE => {
+                    let persisted = parse_persisted_range(resp.headers())?;
+                    resp.into_body().consume().await?;
+                    if persisted <= offset || persisted > total {
+                        return Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "resumable upload makes no progress",
+                        )
+                        .with_context("offset", offset.to_string())
+                        .with_context("persisted", persisted.to_string())
+                        .set_temporary());
+                    }
+                    pending = pending.slice((persisted - offset) as usize..);
+                    offset = persisted;
+                }
+                _ => return Err(parse_error(resp).await?),
+            }
+        }
     }
+}
 
-    async fn abort(&mut self) -> Result<()> {
-        Ok(())
+#[async_trait]
+impl oio::MultipartUploadWrite for GdriveWriter {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        if size <= SIMPLE_UPLOAD_MAX_SIZE {
+            return self.simple_upload(size, body).await;
+        }
+
+        // Upload large file via resumable upload in one request.
+        let session_uri = self.initiate_part().await?;
+        let resp = self
+            .core
+            .gdrive_upload_chunk(&session_uri, 0, size, Some(size), body)
+            .await?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::CREATED => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ if status.as_u16() == RESUME_INCOMPLETE => Err(Error::new(
+                ErrorKind::Unexpected,
+                "resumable upload is not completed, the upload could be retried",
+            )
+            .set_temporary()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        let resp = self
+            .core
+            .gdrive_upload_initiate(&self.path, self.op.content_length(), self.op.content_type())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let session_uri = parse_location(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "location is not present in response, the session uri is unknown",
+                        )
+                    })?
+                    .to_string();
+                resp.into_body().consume().await?;
+
+                *self.state.lock().expect("lock must succeed") = ResumableState::default();
+                Ok(session_uri)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let bs = match body {
+            AsyncBody::Empty => Bytes::new(),
+            AsyncBody::Bytes(bs) => bs,
+            AsyncBody::Stream(s) => {
+                let chunks: Vec<Bytes> = s.try_collect().await?;
+                let mut buf = BytesMut::with_capacity(size as usize);
+                chunks.iter().for_each(|bs| buf.extend_from_slice(bs));
+                buf.freeze()
+            }
+        };
+
+        let (offset, data) = {
+            let state = self.state.lock().expect("lock must succeed");
+            let mut data = BytesMut::with_capacity(state.pending.len() + bs.len());
+            data.extend_from_slice(&state.pending);
+            data.extend_from_slice(&bs);
+            (state.offset, data.freeze())
+        };
+
+        // Only send aligned bytes, the rest will be sent along with next part.
+        let aligned = data.len() as u64 / CHUNK_ALIGN * CHUNK_ALIGN;
+        let (offset, pending) = if aligned == 0 {
+            (offset, data)
+        } else {
+            let resp = self
+                .core
+                .gdrive_upload_chunk(
+                    upload_id,
+                    offset,
+                    aligned,
+                    None,
+                    AsyncBody::Bytes(data.slice(..aligned as usize)),
+                )
+                .await?;
+
+            let status = resp.status();
+            if status.as_u16() != RESUME_INCOMPLETE {
+                return Err(parse_error(resp).await?);
+            }
+
+            // Drive may persist fewer bytes than we sent, keep the rest and
+            // send them again later.
+            let persisted = parse_persisted_range(resp.headers())?;
+            resp.into_body().consume().await?;
+            if persisted < offset || persisted > offset + aligned {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "persisted range returned by drive is invalid",
+                )
+                .with_context("offset", offset.to_string())
+                .with_context("persisted", persisted.to_string()));
+            }
+            (persisted, data.slice((persisted - offset) as usize..))
+        };
+
+        *self.state.lock().expect("lock must succeed") = ResumableState { offset, pending };
+
+        Ok(oio::MultipartUploadPart {
+            part_number,
+            etag: String::new(),
+            checksum: None,
+        })
+    }
+
+    async fn complete_part(&self, upload_id: &str, _: &[oio::MultipartUploadPart]) -> Result<()> {
+        let (offset, pending) = {
+            let state = self.state.lock().expect("lock must succeed");
+            (state.offset, state.pending.clone())
+        };
+
+        self.upload_last(upload_id, offset, pending).await
     }
 
-    async fn close(&mut self) -> Result<()> {
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        let resp = self.core.gdrive_upload_cancel(upload_id).await?;
+
+        let status = resp.status();
+        // Drive returns `499 Client Closed Request` for cancelled uploads.
+        match status.as_u16() {
+            200 | 204 | 499 => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+/// Parse the bytes that have been persisted from `Range` header like
+/// `bytes=0-42`, no `Range` means nothing has been persisted.
+fn parse_persisted_range(headers: &HeaderMap) -> Result<u64> {
+    let Some(range) = headers.get(header::RANGE) else {
+        return Ok(0);
+    };
+
+    range
+        .to_str()
+        .ok()
+        .and_then(|v| v.strip_prefix("bytes=0-"))
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(|v| v + 1)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "range header returned by drive is invalid",
+            )
+            .with_context("range", format!("{range:?}"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    use oio::MultipartUploadWrite;
+    use rand::prelude::*;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    /// A fake resumable upload session.
+    ///
+    /// The first chunk will only be persisted partially to make sure
+    /// unpersisted bytes will be sent again.
+    struct Session {
+        data: Arc<Mutex<Vec<u8>>>,
+        partial: AtomicBool,
+    }
+
+    impl Respond for Session {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let range = req.headers.get(&"content-range".into()).unwrap().as_str();
+            let (range, total) = range
+                .strip_prefix("bytes ")
+                .unwrap()
+                .split_once('/')
+                .unwrap();
+
+            let mut data = self.data.lock().unwrap();
+            if range != "*" {
+                let (start, end) = range.split_once('-').unwrap();
+                let (start, end): (usize, usize) = (start.parse().unwrap(), end.parse().unwrap());
+                assert_eq!(start, data.len(), "chunk must start at persisted offset");
+                assert_eq!(req.body.len(), end - start + 1);
+
+                let mut body = &req.body[..];
+                if !self.partial.swap(true, Ordering::SeqCst) {
+                    body = &body[..CHUNK_ALIGN as usize];
+                }
+                data.extend_from_slice(body);
+            }
+
+            if total != "*" && total.parse::<usize>().unwrap() == data.len() {
+                return ResponseTemplate::new(200);
+            }
+            ResponseTemplate::new(308)
+                .insert_header("range", format!("bytes=0-{}", data.len() - 1).as_str())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resumable_upload_large_file() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let data = Arc::new(Mutex::new(Vec::new()));
+        Mock::given(method("PUT"))
+            .and(path("/upload/session"))
+            .respond_with(Session {
+                data: data.clone(),
+                partial: AtomicBool::new(false),
+            })
+            .mount(&mock_server)
+            .await;
+
+        let core = Arc::new(GdriveCore {
+            root: "/".to_string(),
+            access_token: "token".to_string(),
+            client: HttpClient::new()?,
+            path_cache: Arc::default(),
+        });
+        let w = GdriveWriter::uploader(core, OpWrite::default(), "test".to_string());
+        let session_uri = format!("{}/upload/session", mock_server.uri());
+
+        // Parts are not aligned to 256 KiB on purpose.
+        let mut rng = thread_rng();
+        let mut content = Vec::new();
+        for part_number in 0..4 {
+            let mut bs = vec![0; 3 * 1024 * 1024 + 100 * 1024];
+            rng.fill_bytes(&mut bs);
+            content.extend_from_slice(&bs);
+
+            w.write_part(
+                &session_uri,
+                part_number,
+                bs.len() as u64,
+                AsyncBody::Bytes(bs.into()),
+            )
+            .await?;
+        }
+        w.complete_part(&session_uri, &[]).await?;
+
+        assert_eq!(data.lock().unwrap().len(), content.len());
+        assert!(*data.lock().unwrap() == content);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_persisted_range() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_persisted_range(&headers).unwrap(), 0);
+
+        headers.insert(header::RANGE, "bytes=0-42".parse().unwrap());
+        assert_eq!(parse_persisted_range(&headers).unwrap(), 43);
+
+        headers.insert(header::RANGE, "bytes=1-42".parse().unwrap());
+        assert!(parse_persisted_range(&headers).is_err());
+    }
 }
@@ -25,7 +25,6 @@ use http::header::AUTHORIZATION;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_RANGE;
 use http::header::CONTENT_TYPE;
-use http::header::USER_AGENT;
 use http::Request;
 use http::Response;
 use http::StatusCode;
@@ -153,6 +152,7 @@ pub struct GhacBuilder {
     enable_create_simulation: bool,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl GhacBuilder {
@@ -201,6 +201,17 @@ impl GhacBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for GhacBuilder {
@@ -216,6 +227,9 @@ impl Builder for GhacBuilder {
             .filter(|v| *v == "on" || *v == "true")
             .map(|_| builder.enable_create_simulation());
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -225,7 +239,7 @@ impl Builder for GhacBuilder {
         let root = normalize_root(&self.root.take().unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -233,6 +247,12 @@ impl Builder for GhacBuilder {
                     .with_context("service", Scheme::Ghac)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Ghac)
+            })?;
+        }
 
         let backend = GhacBackend {
             root,
@@ -623,7 +643,6 @@ impl GhacBackend {
 
         let mut req = Request::delete(&url);
         req = req.header(AUTHORIZATION, format!("Bearer {}", self.api_token));
-        req = req.header(USER_AGENT, format!("opendal/{VERSION} (service ghac)"));
         req = req.header("X-GitHub-Api-Version", GITHUB_API_VERSION);
 
         let req = req
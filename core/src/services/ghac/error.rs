@@ -52,3 +52,32 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
 
     Ok(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_parse_error_classification() -> Result<()> {
+        let cases = vec![
+            (404, ErrorKind::NotFound, false),
+            (409, ErrorKind::AlreadyExists, false),
+            (429, ErrorKind::RateLimited, true),
+            (500, ErrorKind::Unexpected, true),
+            (503, ErrorKind::Unexpected, true),
+        ];
+
+        for (status, kind, temporary) in cases {
+            let body = IncomingAsyncBody::new(Box::new(oio::into_stream(stream::empty())), None);
+            let resp = Response::builder().status(status).body(body).unwrap();
+
+            let err = parse_error(resp).await?;
+            assert_eq!(err.kind(), kind, "status {status}");
+            assert_eq!(err.is_temporary(), temporary, "status {status}");
+        }
+
+        Ok(())
+    }
+}
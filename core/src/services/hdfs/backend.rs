@@ -24,6 +24,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use futures::AsyncWriteExt;
 use log::debug;
 
 use super::appender::HdfsAppender;
@@ -159,6 +160,55 @@ pub struct HdfsBackend {
 unsafe impl Send for HdfsBackend {}
 unsafe impl Sync for HdfsBackend {}
 
+impl HdfsBackend {
+    /// Rename `from` to `to` by `hdfsRename`.
+    ///
+    /// HDFS refuses to rename to an existing file, so the existing file will
+    /// be removed first to keep the same behavior with other services. The
+    /// rename is not atomic as a result.
+    ///
+    /// HDFS moves the source into the target if it's a dir, which is
+    /// rejected instead.
+    fn rename_internal(&self, from: &str, to: &str) -> Result<()> {
+        let from_path = build_rooted_abs_path(&self.root, from);
+        let to_path = build_rooted_abs_path(&self.root, to);
+
+        // Make sure the source exists before touching the target.
+        self.client.metadata(&from_path).map_err(parse_io_error)?;
+
+        match self.client.metadata(&to_path) {
+            Ok(meta) if meta.is_dir() => {
+                return Err(
+                    Error::new(ErrorKind::IsADirectory, "rename target is a directory")
+                        .with_context("input", &to_path),
+                )
+            }
+            Ok(_) => self.client.remove_file(&to_path).map_err(parse_io_error)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let parent = PathBuf::from(&to_path)
+                    .parent()
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "path should have parent but not, it must be malformed",
+                        )
+                        .with_context("input", &to_path)
+                    })?
+                    .to_path_buf();
+
+                self.client
+                    .create_dir(&parent.to_string_lossy())
+                    .map_err(parse_io_error)?;
+            }
+            Err(err) => return Err(parse_io_error(err)),
+        }
+
+        self.client
+            .rename_file(&from_path, &to_path)
+            .map_err(parse_io_error)
+    }
+}
+
 #[async_trait]
 impl Accessor for HdfsBackend {
     type Reader = oio::FromFileReader<hdrs::AsyncFile>;
@@ -181,8 +231,11 @@ impl Accessor for HdfsBackend {
                 read_with_range: true,
 
                 write: true,
+                append: true,
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
+                rename: true,
 
                 list: true,
                 list_with_delimiter_slash: true,
@@ -255,6 +308,25 @@ impl Accessor for HdfsBackend {
             .create_dir(&parent.to_string_lossy())
             .map_err(parse_io_error)?;
 
+        // HDFS can't open a not exist file in append mode, so we create
+        // an empty file first. This is not atomic: another client could
+        // create the file between `metadata` and `open_file`.
+        match self.client.metadata(&p) {
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let mut f = self
+                    .client
+                    .open_file()
+                    .create(true)
+                    .write(true)
+                    .async_open(&p)
+                    .await
+                    .map_err(parse_io_error)?;
+                f.close().await.map_err(parse_io_error)?;
+            }
+            Err(err) => return Err(parse_io_error(err)),
+        }
+
         let f = self
             .client
             .open_file()
@@ -316,7 +388,13 @@ impl Accessor for HdfsBackend {
         Ok(RpStat::new(m))
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.rename_internal(from, to)?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let meta = self.client.metadata(&p);
@@ -332,7 +410,9 @@ impl Accessor for HdfsBackend {
         // Safety: Err branch has been checked, it's OK to unwrap.
         let meta = meta.ok().unwrap();
 
-        let result = if meta.is_dir() {
+        let result = if meta.is_dir() && args.recursive() {
+            self.client.remove_dir_all(&p)
+        } else if meta.is_dir() {
             self.client.remove_dir(&p)
         } else {
             self.client.remove_file(&p)
@@ -452,7 +532,13 @@ impl Accessor for HdfsBackend {
         Ok(RpStat::new(m))
     }
 
-    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    fn blocking_rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        self.rename_internal(from, to)?;
+
+        Ok(RpRename::default())
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let meta = self.client.metadata(&p);
@@ -468,7 +554,9 @@ impl Accessor for HdfsBackend {
         // Safety: Err branch has been checked, it's OK to unwrap.
         let meta = meta.ok().unwrap();
 
-        let result = if meta.is_dir() {
+        let result = if meta.is_dir() && args.recursive() {
+            self.client.remove_dir_all(&p)
+        } else if meta.is_dir() {
             self.client.remove_dir(&p)
         } else {
             self.client.remove_file(&p)
@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hdfs_native::client::WriteOptions;
+use hdfs_native::Client;
+use log::debug;
+
+use super::appender::HdfsNativeAppender;
+use super::error::parse_hdfs_error;
+use super::pager::HdfsNativePager;
+use super::reader::HdfsNativeReader;
+use super::writer::HdfsNativeWriter;
+use crate::raw::*;
+use crate::*;
+
+/// The permission of dirs created by OpenDAL, the same as `hdfs dfs -mkdir`.
+const DEFAULT_DIR_PERMISSION: u32 = 0o755;
+
+/// [Hadoop Distributed File System (HDFS™)](https://hadoop.apache.org/) support
+/// via pure-Rust [hdfs-native](https://crates.io/crates/hdfs-native) client.
+#[doc = include_str!("docs.md")]
+#[derive(Debug, Default)]
+pub struct HdfsNativeBuilder {
+    root: Option<String>,
+    name_node: Option<String>,
+}
+
+impl HdfsNativeBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        self.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set name_node of this backend.
+    ///
+    /// Valid format including:
+    ///
+    /// - `hdfs://127.0.0.1:9000`: connect to hdfs cluster.
+    ///
+    /// Unlike [`Hdfs`][crate::services::Hdfs], `default` is not supported
+    /// since there is no hadoop config to load.
+    pub fn name_node(&mut self, name_node: &str) -> &mut Self {
+        if !name_node.is_empty() {
+            // Trim trailing `/` so that we can accept `http://127.0.0.1:9000/`
+            self.name_node = Some(name_node.trim_end_matches('/').to_string())
+        }
+
+        self
+    }
+}
+
+impl Builder for HdfsNativeBuilder {
+    const SCHEME: Scheme = Scheme::HdfsNative;
+    type Accessor = HdfsNativeBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = HdfsNativeBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("name_node").map(|v| builder.name_node(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        debug!("backend build started: {:?}", &self);
+
+        let name_node = match &self.name_node {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "name node is empty")
+                    .with_context("service", Scheme::HdfsNative))
+            }
+        };
+
+        let root = normalize_root(&self.root.take().unwrap_or_default());
+        debug!("backend use root {}", root);
+
+        let client = Client::new(name_node).map_err(|err| {
+            parse_hdfs_error(err)
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::HdfsNative)
+        })?;
+
+        debug!("backend build finished: {:?}", &self);
+        Ok(HdfsNativeBackend {
+            root,
+            client: Arc::new(client),
+        })
+    }
+}
+
+/// Backend for hdfs-native services.
+#[derive(Clone)]
+pub struct HdfsNativeBackend {
+    root: String,
+    client: Arc<Client>,
+}
+
+impl Debug for HdfsNativeBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HdfsNativeBackend")
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HdfsNativeBackend {
+    /// Create the parent dirs of given path.
+    async fn create_parent(&self, p: &str) -> Result<()> {
+        let parent = get_parent(p);
+
+        self.client
+            .mkdirs(parent, DEFAULT_DIR_PERMISSION, true)
+            .await
+            .map_err(parse_hdfs_error)
+    }
+}
+
+#[async_trait]
+impl Accessor for HdfsNativeBackend {
+    type Reader = HdfsNativeReader;
+    type BlockingReader = ();
+    type Writer = HdfsNativeWriter;
+    type BlockingWriter = ();
+    type Appender = HdfsNativeAppender;
+    type Pager = Option<HdfsNativePager>;
+    type BlockingPager = ();
+
+    fn info(&self) -> AccessorInfo {
+        let mut am = AccessorInfo::default();
+        am.set_scheme(Scheme::HdfsNative)
+            .set_root(&self.root)
+            .set_capability(Capability {
+                stat: true,
+
+                read: true,
+                read_can_seek: true,
+                read_with_range: true,
+
+                write: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                rename: true,
+                rename_atomic: true,
+
+                list: true,
+                list_with_delimiter_slash: true,
+
+                ..Default::default()
+            });
+
+        am
+    }
+
+    async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        self.client
+            .mkdirs(&p, DEFAULT_DIR_PERMISSION, true)
+            .await
+            .map_err(parse_hdfs_error)?;
+
+        Ok(RpCreateDir::default())
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let f = self.client.read(&p).await.map_err(parse_hdfs_error)?;
+        let len = f.file_length() as u64;
+
+        let br = args.range();
+        let (start, end) = match (br.offset(), br.size()) {
+            // Read a specific range.
+            (Some(offset), Some(size)) => (offset.min(len), (offset + size).min(len)),
+            // Read from offset.
+            (Some(offset), None) => (offset.min(len), len),
+            // Read the last size bytes.
+            (None, Some(size)) => (len.saturating_sub(size), len),
+            // Read the whole file.
+            (None, None) => (0, len),
+        };
+
+        Ok((
+            RpRead::new(end - start),
+            HdfsNativeReader::new(f, start, end),
+        ))
+    }
+
+    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        // Parent dirs will be created by `create` itself.
+        let f = self
+            .client
+            .create(&p, WriteOptions::default().overwrite(true))
+            .await
+            .map_err(parse_hdfs_error)?;
+
+        Ok((RpWrite::new(), HdfsNativeWriter::new(f)))
+    }
+
+    async fn append(&self, path: &str, _: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        // HDFS can't open a not exist file in append mode, so we create
+        // the file if it doesn't exist. This is not atomic: another client
+        // could create the file between `append` and `create`.
+        let f = match self.client.append(&p).await.map_err(parse_hdfs_error) {
+            Ok(f) => f,
+            Err(err) if err.kind() == ErrorKind::NotFound => self
+                .client
+                .create(&p, WriteOptions::default())
+                .await
+                .map_err(parse_hdfs_error)?,
+            Err(err) => return Err(err),
+        };
+
+        Ok((RpAppend::new(), HdfsNativeAppender::new(f)))
+    }
+
+    async fn rename(&self, from: &str, to: &str, _: OpRename) -> Result<RpRename> {
+        let from_path = build_rooted_abs_path(&self.root, from);
+        let to_path = build_rooted_abs_path(&self.root, to);
+
+        self.create_parent(&to_path).await?;
+
+        self.client
+            .rename(&from_path, &to_path, true)
+            .await
+            .map_err(parse_hdfs_error)?;
+
+        Ok(RpRename::default())
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let status = self
+            .client
+            .get_file_info(&p)
+            .await
+            .map_err(parse_hdfs_error)?;
+
+        let mode = if status.isdir {
+            EntryMode::DIR
+        } else {
+            EntryMode::FILE
+        };
+        let mut m = Metadata::new(mode);
+        m.set_content_length(status.length as u64);
+        m.set_last_modified(parse_datetime_from_from_timestamp_millis(
+            status.modification_time as i64,
+        )?);
+
+        Ok(RpStat::new(m))
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        // `delete` returns `false` if the path doesn't exist.
+        match self
+            .client
+            .delete(&p, false)
+            .await
+            .map_err(parse_hdfs_error)
+        {
+            Ok(_) => Ok(RpDelete::default()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(RpDelete::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let statuses = match self
+            .client
+            .list_status(&p, false)
+            .await
+            .map_err(parse_hdfs_error)
+        {
+            Ok(statuses) => statuses,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok((RpList::default(), None)),
+            Err(err) => return Err(err),
+        };
+
+        let rd = HdfsNativePager::new(&self.root, &p, statuses, args.limit());
+
+        Ok((RpList::default(), Some(rd)))
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use hdfs_native::HdfsError;
+
+use crate::Error;
+use crate::ErrorKind;
+
+/// Parse hdfs-native error into opendal::Error.
+///
+/// Errors returned by name node are carried by `RPCError` with the java
+/// exception class name, we need to check the class to get the error kind.
+pub fn parse_hdfs_error(err: HdfsError) -> Error {
+    let (kind, retryable) = match &err {
+        HdfsError::FileNotFound(_) | HdfsError::BlocksNotFound(_) => (ErrorKind::NotFound, false),
+        HdfsError::AlreadyExists(_) => (ErrorKind::AlreadyExists, false),
+        HdfsError::IsADirectoryError(_) => (ErrorKind::IsADirectory, false),
+        HdfsError::UrlParseError(_) | HdfsError::InvalidArgument(_) => {
+            (ErrorKind::ConfigInvalid, false)
+        }
+        HdfsError::UnsupportedFeature(_) | HdfsError::UnsupportedErasureCodingPolicy(_) => {
+            (ErrorKind::Unsupported, false)
+        }
+        HdfsError::RPCError(class, _) | HdfsError::FatalRPCError(class, _) => {
+            (parse_exception_class(class), false)
+        }
+        HdfsError::IOError(_) | HdfsError::DataTransferError(_) => (ErrorKind::Unexpected, true),
+        _ => (ErrorKind::Unexpected, false),
+    };
+
+    let mut err = Error::new(kind, &err.to_string()).set_source(err);
+
+    if retryable {
+        err = err.set_temporary();
+    }
+
+    err
+}
+
+fn parse_exception_class(class: &str) -> ErrorKind {
+    match class.rsplit('.').next().unwrap_or_default() {
+        "FileNotFoundException" => ErrorKind::NotFound,
+        "FileAlreadyExistsException" => ErrorKind::AlreadyExists,
+        "AccessControlException" => ErrorKind::PermissionDenied,
+        "ParentNotDirectoryException" => ErrorKind::NotADirectory,
+        _ => ErrorKind::Unexpected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rpc_error() {
+        let err = parse_hdfs_error(HdfsError::RPCError(
+            "java.io.FileNotFoundException".to_string(),
+            "File does not exist: /tmp/not_exist".to_string(),
+        ));
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let err = parse_hdfs_error(HdfsError::RPCError(
+            "org.apache.hadoop.fs.FileAlreadyExistsException".to_string(),
+            "/tmp/test already exists".to_string(),
+        ));
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        let err = parse_hdfs_error(HdfsError::FileNotFound("/tmp/not_exist".to_string()));
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use hdfs_native::client::FileStatus;
+
+use crate::raw::*;
+use crate::*;
+
+pub struct HdfsNativePager {
+    root: String,
+    /// The absolute path of listing dir, ends with `/`.
+    path: String,
+
+    size: usize,
+    statuses: VecDeque<FileStatus>,
+}
+
+impl HdfsNativePager {
+    pub fn new(root: &str, path: &str, statuses: Vec<FileStatus>, limit: Option<usize>) -> Self {
+        Self {
+            root: root.to_string(),
+            path: path.to_string(),
+
+            size: limit.unwrap_or(1000),
+            statuses: statuses.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl oio::Page for HdfsNativePager {
+    async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+        let mut oes: Vec<oio::Entry> = Vec::with_capacity(self.size);
+
+        for _ in 0..self.size {
+            let status = match self.statuses.pop_front() {
+                Some(status) => status,
+                None => break,
+            };
+
+            // Build path from the file name only, so that we don't depend
+            // on whether the returned path is absolute or not.
+            let name = status
+                .path
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or_default();
+            let path = build_rel_path(&self.root, &format!("{}{}", self.path, name));
+
+            let d = if status.isdir {
+                // Make sure we are returning the correct path.
+                oio::Entry::new(&format!("{path}/"), Metadata::new(EntryMode::DIR))
+            } else {
+                let meta = Metadata::new(EntryMode::FILE)
+                    .with_content_length(status.length as u64)
+                    .with_last_modified(parse_datetime_from_from_timestamp_millis(
+                        status.modification_time as i64,
+                    )?);
+                oio::Entry::new(&path, meta)
+            };
+
+            oes.push(d)
+        }
+
+        Ok(if oes.is_empty() { None } else { Some(oes) })
+    }
+}
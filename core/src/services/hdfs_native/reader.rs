@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp;
+use std::io::SeekFrom;
+use std::sync::Arc;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use hdfs_native::file::FileReader;
+
+use super::error::parse_hdfs_error;
+use crate::raw::*;
+use crate::*;
+
+/// The max size of content fetched from datanodes in one request.
+const CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+/// HdfsNativeReader reads the given range of file chunk by chunk, so that
+/// at most one chunk is buffered in memory.
+pub struct HdfsNativeReader {
+    f: Arc<FileReader>,
+    /// The range to read, in absolute offset of the file.
+    start: u64,
+    end: u64,
+    /// The absolute offset of the next chunk to fetch.
+    offset: u64,
+    /// Content fetched but not consumed yet.
+    buf: Bytes,
+    fut: Option<BoxFuture<'static, Result<Bytes>>>,
+}
+
+/// # Safety
+///
+/// HdfsNativeReader will only be accessed by `&mut Self`
+unsafe impl Sync for HdfsNativeReader {}
+
+impl HdfsNativeReader {
+    pub fn new(f: FileReader, start: u64, end: u64) -> Self {
+        Self {
+            f: Arc::new(f),
+            start,
+            end,
+            offset: start,
+            buf: Bytes::new(),
+            fut: None,
+        }
+    }
+
+    /// The position relative to the start of range.
+    fn position(&self) -> u64 {
+        self.offset - self.buf.len() as u64 - self.start
+    }
+}
+
+impl oio::Read for HdfsNativeReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        if self.buf.is_empty() {
+            match ready!(self.poll_next(cx)) {
+                Some(Ok(bs)) => self.buf = bs,
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(0)),
+            }
+        }
+
+        let n = cmp::min(buf.len(), self.buf.len());
+        buf[..n].copy_from_slice(&self.buf[..n]);
+        self.buf.advance(n);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, _: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let size = self.end - self.start;
+        let (base, amt) = match pos {
+            SeekFrom::Start(n) => (0, n as i64),
+            SeekFrom::End(n) => (size as i64, n),
+            SeekFrom::Current(n) => (self.position() as i64, n),
+        };
+
+        let n = match base.checked_add(amt) {
+            Some(n) if n >= 0 => n as u64,
+            _ => {
+                return Poll::Ready(Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )))
+            }
+        };
+
+        // Drop the fetched content and the inflight request.
+        self.offset = self.start + cmp::min(n, size);
+        self.buf = Bytes::new();
+        self.fut = None;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        if !self.buf.is_empty() {
+            let bs = std::mem::take(&mut self.buf);
+            return Poll::Ready(Some(Ok(bs)));
+        }
+
+        if self.fut.is_none() {
+            if self.offset >= self.end {
+                return Poll::Ready(None);
+            }
+
+            let f = self.f.clone();
+            let offset = self.offset as usize;
+            let size = cmp::min(CHUNK_SIZE, self.end - self.offset) as usize;
+            self.fut = Some(
+                async move { f.read_range(offset, size).await.map_err(parse_hdfs_error) }.boxed(),
+            );
+        }
+
+        let res = ready!(self
+            .fut
+            .as_mut()
+            .expect("future must be set")
+            .poll_unpin(cx));
+        self.fut = None;
+        match res {
+            // Stop reading if datanodes return nothing to avoid looping forever.
+            Ok(bs) if bs.is_empty() => {
+                self.offset = self.end;
+                Poll::Ready(None)
+            }
+            Ok(bs) => {
+                self.offset += bs.len() as u64;
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Err(err) => Poll::Ready(Some(Err(err))),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use hdfs_native::file::FileWriter;
+
+use super::error::parse_hdfs_error;
+use crate::raw::*;
+use crate::*;
+
+pub struct HdfsNativeWriter {
+    f: FileWriter,
+}
+
+/// # Safety
+///
+/// HdfsNativeWriter will only be accessed by `&mut Self`
+unsafe impl Sync for HdfsNativeWriter {}
+
+impl HdfsNativeWriter {
+    pub fn new(f: FileWriter) -> Self {
+        Self { f }
+    }
+}
+
+#[async_trait]
+impl oio::Write for HdfsNativeWriter {
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.f.write(bs).await.map_err(parse_hdfs_error)?;
+
+        Ok(())
+    }
+
+    async fn sink(&mut self, _size: u64, _s: oio::Streamer) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "Write::sink is not supported",
+        ))
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "output writer doesn't support abort",
+        ))
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.f.close().await.map_err(parse_hdfs_error)?;
+
+        Ok(())
+    }
+}
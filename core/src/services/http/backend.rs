@@ -59,6 +59,7 @@ use crate::*;
 ///
 /// - `endpoint`: set the endpoint for http
 /// - `root`: Set the work directory for backend
+/// - `enable_stat_fallback_to_range_read`: Stat via `Range: bytes=0-0` if `HEAD` is not supported
 ///
 /// You can refer to [`HttpBuilder`]'s docs for more information
 ///
@@ -89,7 +90,9 @@ pub struct HttpBuilder {
     password: Option<String>,
     token: Option<String>,
     root: Option<String>,
+    enable_stat_fallback_to_range_read: bool,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for HttpBuilder {
@@ -157,6 +160,20 @@ impl HttpBuilder {
         self
     }
 
+    /// Fallback to a ranged read while stat if `HEAD` is not supported by
+    /// server.
+    ///
+    /// If enabled and server returns `405 Method Not Allowed` or
+    /// `501 Not Implemented` for `HEAD`, we will send a `GET` with
+    /// `Range: bytes=0-0` instead and parse the total size from
+    /// `Content-Range`.
+    ///
+    /// This is disabled by default since it could read content from server.
+    pub fn enable_stat_fallback_to_range_read(&mut self) -> &mut Self {
+        self.enable_stat_fallback_to_range_read = true;
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -167,6 +184,17 @@ impl HttpBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for HttpBuilder {
@@ -181,6 +209,12 @@ impl Builder for HttpBuilder {
         map.get("username").map(|v| builder.username(v));
         map.get("password").map(|v| builder.password(v));
         map.get("token").map(|v| builder.token(v));
+        map.get("enable_stat_fallback_to_range_read")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_stat_fallback_to_range_read());
+
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
 
         builder
     }
@@ -199,7 +233,7 @@ impl Builder for HttpBuilder {
         let root = normalize_root(&self.root.take().unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -207,6 +241,12 @@ impl Builder for HttpBuilder {
                     .with_context("service", Scheme::Http)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Http)
+            })?;
+        }
 
         let mut auth = None;
         if let Some(username) = &self.username {
@@ -224,6 +264,7 @@ impl Builder for HttpBuilder {
             endpoint: endpoint.to_string(),
             authorization: auth,
             root,
+            enable_stat_fallback_to_range_read: self.enable_stat_fallback_to_range_read,
             client,
         })
     }
@@ -234,6 +275,7 @@ impl Builder for HttpBuilder {
 pub struct HttpBackend {
     endpoint: String,
     root: String,
+    enable_stat_fallback_to_range_read: bool,
     client: HttpClient,
 
     authorization: Option<String>,
@@ -315,12 +357,58 @@ impl Accessor for HttpBackend {
             StatusCode::NOT_FOUND | StatusCode::FORBIDDEN if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
+            StatusCode::METHOD_NOT_ALLOWED | StatusCode::NOT_IMPLEMENTED
+                if self.enable_stat_fallback_to_range_read =>
+            {
+                self.stat_via_range_read(path, args).await
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
 }
 
 impl HttpBackend {
+    /// Stat by reading the first byte and parsing the total size from
+    /// `Content-Range`.
+    async fn stat_via_range_read(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let resp = self
+            .http_get(
+                path,
+                BytesRange::new(Some(0), Some(1)),
+                args.if_match(),
+                args.if_none_match(),
+            )
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            // Empty file will return `416 Range Not Satisfiable` with
+            // `Content-Range: bytes */0`.
+            StatusCode::PARTIAL_CONTENT | StatusCode::RANGE_NOT_SATISFIABLE => {
+                let mut meta = parse_into_metadata(path, resp.headers())?;
+                let size = parse_content_range(resp.headers())?
+                    .and_then(|v| v.size())
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "content range of response doesn't contain total size",
+                        )
+                        .with_operation("HttpBackend::stat_via_range_read")
+                        .with_context("path", path)
+                    })?;
+                meta.set_content_length(size);
+
+                resp.into_body().consume().await?;
+                Ok(RpStat::new(meta))
+            }
+            // Server ignores the range, the body will be dropped without
+            // reading.
+            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn http_get(
         &self,
         path: &str,
@@ -394,6 +482,7 @@ mod tests {
     use anyhow::Result;
     use wiremock::matchers::basic_auth;
     use wiremock::matchers::bearer_token;
+    use wiremock::matchers::header;
     use wiremock::matchers::headers;
     use wiremock::matchers::method;
     use wiremock::matchers::path;
@@ -490,6 +579,37 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_with_user_agent() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header(
+                "user-agent",
+                format!("opendal/{VERSION} my-app/1.0").as_str(),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .set_body_string("Hello, World!"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        builder.http_user_agent("my-app/1.0");
+        let op = Operator::new(builder)?.finish();
+
+        let bs = op.read("hello").await?;
+
+        assert_eq!(bs, b"Hello, World!");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_stat() -> Result<()> {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
@@ -512,6 +632,45 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_stat_fallback_to_range_read() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .respond_with(ResponseTemplate::new(405))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header("range", "bytes=0-0"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-range", "bytes 0-0/128")
+                    .set_body_string("H"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        let op = Operator::new(builder)?.finish();
+        let err = op.stat("hello").await.expect_err("stat must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        builder.enable_stat_fallback_to_range_read();
+        let op = Operator::new(builder)?.finish();
+        let meta = op.stat("hello").await?;
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), 128);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_with() -> Result<()> {
         let _ = tracing_subscriber::fmt().with_test_writer().try_init();
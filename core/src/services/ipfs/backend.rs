@@ -39,6 +39,7 @@ pub struct IpfsBuilder {
     endpoint: Option<String>,
     root: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl IpfsBuilder {
@@ -87,6 +88,17 @@ impl IpfsBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for IpfsBuilder {
@@ -98,6 +110,9 @@ impl Builder for IpfsBuilder {
         map.get("root").map(|v| builder.root(v));
         map.get("endpoint").map(|v| builder.endpoint(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -123,7 +138,7 @@ impl Builder for IpfsBuilder {
         }?;
         debug!("backend use endpoint {}", &endpoint);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -131,6 +146,12 @@ impl Builder for IpfsBuilder {
                     .with_context("service", Scheme::Ipfs)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Ipfs)
+            })?;
+        }
 
         debug!("backend build finished: {:?}", &self);
         Ok(IpfsBackend {
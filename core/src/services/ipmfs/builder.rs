@@ -70,6 +70,7 @@ pub struct IpmfsBuilder {
     root: Option<String>,
     endpoint: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl IpmfsBuilder {
@@ -106,6 +107,17 @@ impl IpmfsBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for IpmfsBuilder {
@@ -118,6 +130,9 @@ impl Builder for IpmfsBuilder {
         map.get("root").map(|v| builder.root(v));
         map.get("endpoint").map(|v| builder.endpoint(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -130,7 +145,7 @@ impl Builder for IpmfsBuilder {
             .clone()
             .unwrap_or_else(|| "http://localhost:5001".to_string());
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -138,6 +153,12 @@ impl Builder for IpmfsBuilder {
                     .with_context("service", Scheme::Ipmfs)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Ipmfs)
+            })?;
+        }
 
         debug!("backend build finished: {:?}", &self);
         Ok(IpmfsBackend::new(root, client, endpoint))
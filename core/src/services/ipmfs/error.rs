@@ -62,6 +62,7 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
                 (ErrorKind::Unexpected, false)
             }
         }
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
             (ErrorKind::Unexpected, true)
         }
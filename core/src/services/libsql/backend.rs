@@ -0,0 +1,623 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use base64::alphabet;
+use base64::engine::general_purpose::GeneralPurpose;
+use base64::engine::general_purpose::GeneralPurposeConfig;
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use bytes::Bytes;
+use http::header::AUTHORIZATION;
+use http::header::CONTENT_TYPE;
+use http::Request;
+use http::StatusCode;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::error::parse_error;
+use super::error::parse_libsql_error;
+use super::error::LibsqlError;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+/// Engine for blobs in libsql's HTTP protocol, which omits padding while
+/// encoding and accepts both while decoding.
+const BASE64_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// [Libsql](https://github.com/tursodatabase/libsql) services support,
+/// including [Turso](https://turso.tech/).
+#[doc = include_str!("docs.md")]
+#[derive(Default)]
+pub struct LibsqlBuilder {
+    connection_string: Option<String>,
+    auth_token: Option<String>,
+
+    table: Option<String>,
+    key_field: Option<String>,
+    value_field: Option<String>,
+    root: Option<String>,
+
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for LibsqlBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Builder");
+        ds.field("connection_string", &self.connection_string);
+        ds.field("table", &self.table);
+        ds.finish()
+    }
+}
+
+impl LibsqlBuilder {
+    /// Set the connection_string of the libsql service.
+    ///
+    /// Both the `libsql://` url used by Turso and plain `http://` or
+    /// `https://` urls are accepted, `libsql://` will be sent via `https`.
+    ///
+    /// - `libsql://example-db.turso.io`
+    /// - `http://127.0.0.1:8080`
+    pub fn connection_string(&mut self, v: &str) -> &mut Self {
+        if !v.is_empty() {
+            self.connection_string = Some(v.to_string());
+        }
+        self
+    }
+
+    /// Set the auth token of the libsql service.
+    ///
+    /// The token will be sent as `Authorization: Bearer <token>`.
+    pub fn auth_token(&mut self, auth_token: &str) -> &mut Self {
+        if !auth_token.is_empty() {
+            self.auth_token = Some(auth_token.to_string());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+
+    /// Set the table name of the libsql service to read/write.
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        if !table.is_empty() {
+            self.table = Some(table.to_string());
+        }
+        self
+    }
+
+    /// Set the key field name of the libsql service to read/write.
+    ///
+    /// Default to `key` if not specified.
+    pub fn key_field(&mut self, key_field: &str) -> &mut Self {
+        if !key_field.is_empty() {
+            self.key_field = Some(key_field.to_string());
+        }
+        self
+    }
+
+    /// Set the value field name of the libsql service to read/write.
+    ///
+    /// Default to `value` if not specified.
+    pub fn value_field(&mut self, value_field: &str) -> &mut Self {
+        if !value_field.is_empty() {
+            self.value_field = Some(value_field.to_string());
+        }
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(&mut self, http_client: HttpClient) -> &mut Self {
+        self.http_client = Some(http_client);
+        self
+    }
+}
+
+impl Builder for LibsqlBuilder {
+    const SCHEME: Scheme = Scheme::Libsql;
+    type Accessor = LibsqlBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = LibsqlBuilder::default();
+
+        map.get("connection_string")
+            .map(|v| builder.connection_string(v));
+        map.get("auth_token").map(|v| builder.auth_token(v));
+        map.get("table").map(|v| builder.table(v));
+        map.get("key_field").map(|v| builder.key_field(v));
+        map.get("value_field").map(|v| builder.value_field(v));
+        map.get("root").map(|v| builder.root(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let conn = match self.connection_string.clone() {
+            Some(v) => v,
+            None => {
+                return Err(
+                    Error::new(ErrorKind::ConfigInvalid, "connection_string is empty")
+                        .with_context("service", Scheme::Libsql),
+                )
+            }
+        };
+
+        let endpoint = if let Some(v) = conn.strip_prefix("libsql://") {
+            format!("https://{v}")
+        } else if conn.starts_with("http://") || conn.starts_with("https://") {
+            conn
+        } else {
+            return Err(
+                Error::new(ErrorKind::ConfigInvalid, "connection_string is invalid")
+                    .with_context("service", Scheme::Libsql)
+                    .with_context("connection_string", conn),
+            );
+        };
+        let endpoint = endpoint.trim_end_matches('/').to_string();
+
+        let table = match self.table.clone() {
+            Some(v) => v,
+            None => {
+                return Err(Error::new(ErrorKind::ConfigInvalid, "table is empty")
+                    .with_context("service", Scheme::Libsql))
+            }
+        };
+        let key_field = match self.key_field.clone() {
+            Some(v) => v,
+            None => "key".to_string(),
+        };
+        let value_field = match self.value_field.clone() {
+            Some(v) => v,
+            None => "value".to_string(),
+        };
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        let client = if let Some(client) = self.http_client.take() {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Libsql)
+            })?
+        };
+
+        Ok(LibsqlBackend::new(Adapter {
+            client,
+            endpoint,
+            auth_token: self.auth_token.clone(),
+            table,
+            key_field,
+            value_field,
+        })
+        .with_root(&root))
+    }
+}
+
+/// Backend for libsql service
+pub type LibsqlBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    client: HttpClient,
+    endpoint: String,
+    auth_token: Option<String>,
+
+    table: String,
+    key_field: String,
+    value_field: String,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("endpoint", &self.endpoint);
+        ds.field("table", &self.table);
+        ds.finish_non_exhaustive()
+    }
+}
+
+impl Adapter {
+    /// Execute a statement via the `/v2/pipeline` API and return the rows.
+    ///
+    /// Every pipeline is closed after execution, so no stream (baton) will
+    /// be kept between requests.
+    async fn execute(&self, sql: String, args: Vec<Value>) -> Result<Vec<Vec<Value>>> {
+        let body = PipelineRequest {
+            baton: None,
+            requests: vec![
+                StreamRequest::Execute {
+                    stmt: Statement { sql, args },
+                },
+                StreamRequest::Close,
+            ],
+        };
+        let bs = serde_json::to_vec(&body).map_err(new_json_serialize_error)?;
+
+        let mut req = Request::post(format!("{}/v2/pipeline", self.endpoint))
+            .header(CONTENT_TYPE, "application/json");
+        if let Some(token) = &self.auth_token {
+            req = req.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let req = req
+            .body(AsyncBody::Bytes(Bytes::from(bs)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.client.send(req).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp).await?);
+        }
+
+        let bs = resp.into_body().bytes().await?;
+        let resp: PipelineResponse =
+            serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+
+        match resp.results.into_iter().next() {
+            Some(StreamResult::Ok {
+                response: StreamResponse::Execute { result },
+            }) => Ok(result.rows),
+            Some(StreamResult::Error { error }) => Err(parse_libsql_error(error)),
+            _ => Err(Error::new(
+                ErrorKind::Unexpected,
+                "libsql returns unexpected response for execute",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Libsql,
+            &self.table,
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let query = format!(
+            "SELECT {} FROM {} WHERE {} = ? LIMIT 1",
+            self.value_field, self.table, self.key_field
+        );
+        let rows = self.execute(query, vec![Value::text(path)]).await?;
+
+        match rows
+            .into_iter()
+            .next()
+            .and_then(|row| row.into_iter().next())
+        {
+            None => Ok(None),
+            Some(v) => v.into_bytes().map(Some),
+        }
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        let table = &self.table;
+        let key_field = &self.key_field;
+        let value_field = &self.value_field;
+        let query = format!(
+            "INSERT INTO {table} ({key_field}, {value_field}) \
+                VALUES (?, ?) \
+                ON CONFLICT ({key_field}) \
+                    DO UPDATE SET {value_field} = excluded.{value_field}",
+        );
+        self.execute(query, vec![Value::text(path), Value::blob(value)])
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let query = format!("DELETE FROM {} WHERE {} = ?", self.table, self.key_field);
+        self.execute(query, vec![Value::text(path)]).await?;
+        Ok(())
+    }
+
+    /// Scan keys by comparing the prefix directly.
+    ///
+    /// We don't use `LIKE` here, which is case-insensitive for ASCII and
+    /// requires escaping of `%` and `_` in path.
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let query = format!(
+            "SELECT {key_field} FROM {table} WHERE substr({key_field}, 1, length(?)) = ?",
+            key_field = self.key_field,
+            table = self.table
+        );
+        let rows = self
+            .execute(query, vec![Value::text(path), Value::text(path)])
+            .await?;
+
+        rows.into_iter()
+            .filter_map(|row| row.into_iter().next())
+            .map(|v| match v {
+                Value::Text { value } => Ok(value),
+                v => {
+                    let bs = v.into_bytes()?;
+                    String::from_utf8(bs).map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "key is not valid utf-8").set_source(err)
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// Request of libsql's `/v2/pipeline` API.
+#[derive(Debug, Serialize)]
+struct PipelineRequest {
+    baton: Option<String>,
+    requests: Vec<StreamRequest>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamRequest {
+    Execute { stmt: Statement },
+    Close,
+}
+
+#[derive(Debug, Serialize)]
+struct Statement {
+    sql: String,
+    args: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PipelineResponse {
+    results: Vec<StreamResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamResult {
+    Ok { response: StreamResponse },
+    Error { error: LibsqlError },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamResponse {
+    Execute { result: StatementResult },
+    Close,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatementResult {
+    rows: Vec<Vec<Value>>,
+}
+
+/// Value of libsql's HTTP protocol.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Value {
+    Null,
+    Integer { value: String },
+    Float { value: f64 },
+    Text { value: String },
+    Blob { base64: String },
+}
+
+impl Value {
+    fn text(v: &str) -> Self {
+        Value::Text {
+            value: v.to_string(),
+        }
+    }
+
+    fn blob(v: &[u8]) -> Self {
+        Value::Blob {
+            base64: BASE64_ENGINE.encode(v),
+        }
+    }
+
+    /// Convert value into bytes.
+    ///
+    /// Text is accepted as well since sqlite doesn't enforce column types.
+    fn into_bytes(self) -> Result<Vec<u8>> {
+        match self {
+            Value::Null => Ok(vec![]),
+            Value::Text { value } => Ok(value.into_bytes()),
+            Value::Blob { base64 } => BASE64_ENGINE.decode(base64).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "blob is not valid base64").set_source(err)
+            }),
+            v => Err(
+                Error::new(ErrorKind::Unexpected, "value type is not supported")
+                    .with_context("value", format!("{v:?}")),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    /// A fake libsql server which only understands the statements sent by
+    /// this service.
+    #[derive(Clone, Default)]
+    struct FakeLibsql {
+        rows: Arc<Mutex<BTreeMap<String, String>>>,
+    }
+
+    impl Respond for FakeLibsql {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            let stmt = &body["requests"][0]["stmt"];
+            let sql = stmt["sql"].as_str().unwrap();
+            let args = stmt["args"].as_array().unwrap();
+            let text = |idx: usize| args[idx]["value"].as_str().unwrap().to_string();
+
+            let mut rows = self.rows.lock().unwrap();
+            let result: Vec<serde_json::Value> = if sql.starts_with("SELECT value") {
+                rows.get(&text(0))
+                    .map(|v| serde_json::json!([{"type": "blob", "base64": v}]))
+                    .into_iter()
+                    .collect()
+            } else if sql.starts_with("SELECT key") {
+                let prefix = text(0);
+                rows.keys()
+                    .filter(|k| k.starts_with(&prefix))
+                    .map(|k| serde_json::json!([{"type": "text", "value": k}]))
+                    .collect()
+            } else if sql.starts_with("INSERT") {
+                let value = args[1]["base64"].as_str().unwrap().to_string();
+                rows.insert(text(0), value);
+                vec![]
+            } else if sql.starts_with("DELETE") {
+                rows.remove(&text(0));
+                vec![]
+            } else {
+                return ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "baton": null,
+                    "results": [
+                        {"type": "error", "error": {"message": "no such table", "code": "SQLITE_ERROR"}},
+                        {"type": "error", "error": {"message": "stream closed"}},
+                    ]
+                }));
+            };
+
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "baton": null,
+                "base_url": null,
+                "results": [
+                    {"type": "ok", "response": {"type": "execute", "result": {
+                        "cols": [],
+                        "rows": result,
+                        "affected_row_count": 0,
+                        "last_insert_rowid": null,
+                    }}},
+                    {"type": "ok", "response": {"type": "close"}},
+                ]
+            }))
+        }
+    }
+
+    async fn new_operator(mock_server: &MockServer) -> Operator {
+        Mock::given(method("POST"))
+            .and(path("/v2/pipeline"))
+            .and(header("Authorization", "Bearer token"))
+            .respond_with(FakeLibsql::default())
+            .mount(mock_server)
+            .await;
+
+        let mut builder = LibsqlBuilder::default();
+        builder
+            .connection_string(&mock_server.uri())
+            .auth_token("token")
+            .table("data");
+        Operator::new(builder).unwrap().finish()
+    }
+
+    #[tokio::test]
+    async fn test_libsql_kv() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let op = new_operator(&mock_server).await;
+
+        op.write("dir/a", "Hello, World!").await?;
+        op.write("dir/b", vec![0, 1, 2, 255]).await?;
+        op.write("other", "").await?;
+
+        assert_eq!(op.read("dir/a").await?, b"Hello, World!");
+        assert_eq!(op.read("dir/b").await?, vec![0, 1, 2, 255]);
+        assert_eq!(op.stat("dir/a").await?.content_length(), 13);
+
+        let mut entries: Vec<String> = op
+            .list_with("dir/")
+            .delimiter("")
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, vec!["dir/a", "dir/b"]);
+
+        op.delete("dir/a").await?;
+        let err = op.read("dir/a").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_value() {
+        let v: Value = serde_json::from_str(r#"{"type":"blob","base64":"AAEC/w"}"#).unwrap();
+        assert_eq!(v.into_bytes().unwrap(), vec![0, 1, 2, 255]);
+        let v: Value = serde_json::from_str(r#"{"type":"blob","base64":"AAEC/w=="}"#).unwrap();
+        assert_eq!(v.into_bytes().unwrap(), vec![0, 1, 2, 255]);
+        let v: Value = serde_json::from_str(r#"{"type":"text","value":"abc"}"#).unwrap();
+        assert_eq!(v.into_bytes().unwrap(), b"abc");
+        let v: Value = serde_json::from_str(r#"{"type":"integer","value":"1"}"#).unwrap();
+        assert!(v.into_bytes().is_err());
+
+        assert_eq!(
+            serde_json::to_string(&Value::blob(&[0, 1, 2, 255])).unwrap(),
+            r#"{"type":"blob","base64":"AAEC/w"}"#
+        );
+    }
+
+    #[test]
+    fn test_build_endpoint() {
+        let mut builder = LibsqlBuilder::default();
+        builder.connection_string("mysql://localhost").table("data");
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
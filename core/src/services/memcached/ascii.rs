@@ -16,27 +16,69 @@
 // under the License.
 
 use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
 use tokio::io::AsyncWriteExt;
 use tokio::io::BufReader;
-use tokio::net::TcpStream;
 
 use super::backend::parse_io_error;
 use crate::*;
 
+/// The underlying stream of a connection, could be plain tcp or tls.
+pub trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Stream for T {}
+
 pub struct Connection {
-    io: BufReader<TcpStream>,
+    io: BufReader<Box<dyn Stream>>,
     buf: Vec<u8>,
 }
 
 impl Connection {
-    pub fn new(io: TcpStream) -> Self {
+    pub fn new(io: Box<dyn Stream>) -> Self {
         Self {
             io: BufReader::new(io),
             buf: Vec::new(),
         }
     }
 
+    /// Authenticate via memcached's ascii authentication.
+    ///
+    /// The server must be started with `-Y <authfile>`. Credentials are sent
+    /// by a `set` command with `<username> <password>` as value, the key is
+    /// ignored by server.
+    pub async fn auth(&mut self, username: &str, password: &str) -> Result<()> {
+        let credential = format!("{username} {password}");
+        let header = format!("set auth 0 0 {}\r\n", credential.len());
+        self.io
+            .write_all(header.as_bytes())
+            .await
+            .map_err(parse_io_error)?;
+        self.io
+            .write_all(credential.as_bytes())
+            .await
+            .map_err(parse_io_error)?;
+        self.io.write_all(b"\r\n").await.map_err(parse_io_error)?;
+        self.io.flush().await.map_err(parse_io_error)?;
+
+        // Read response header
+        let header = self.read_header().await?;
+
+        if header.starts_with("STORED") {
+            return Ok(());
+        } else if header.contains("authentication failure") {
+            return Err(
+                Error::new(ErrorKind::PermissionDenied, "authentication failed")
+                    .with_context("message", header.trim_end()),
+            );
+        }
+        Err(
+            Error::new(ErrorKind::Unexpected, "unexpected data received")
+                .with_context("message", header),
+        )
+    }
+
     pub async fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
         // Send command
         let writer = self.io.get_mut();
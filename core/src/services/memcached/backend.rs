@@ -16,6 +16,8 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -51,6 +53,10 @@ use crate::*;
 /// - `root`: Set the working directory of `OpenDAL`
 /// - `endpoint`: Set the network address of memcached server
 /// - `default_ttl`: Set the ttl for memcached service.
+/// - `username`: Set the username for authentication.
+/// - `password`: Set the password for authentication.
+/// - `tls`: Connect to memcached server over TLS, requires the
+///   `services-memcached-rustls` feature.
 ///
 /// You can refer to [`MemcachedBuilder`]'s docs for more information
 ///
@@ -73,6 +79,16 @@ use crate::*;
 ///     Ok(())
 /// }
 /// ```
+///
+/// # Authentication
+///
+/// OpenDAL talks to memcached via the text protocol, so authentication is
+/// done by memcached's [ascii authentication](https://github.com/memcached/memcached/wiki/SASLHowto#ascii-authentication)
+/// which requires server started with `-Y <authfile>`. SASL via the binary
+/// protocol is not supported.
+///
+/// Connections will be rejected with [`ErrorKind::PermissionDenied`] if
+/// server refuses the credentials.
 #[derive(Clone, Default)]
 pub struct MemcachedBuilder {
     /// network address of the memcached service.
@@ -85,6 +101,27 @@ pub struct MemcachedBuilder {
     root: Option<String>,
     /// The default ttl for put operations.
     default_ttl: Option<Duration>,
+    /// The username for authentication.
+    username: Option<String>,
+    /// The password for authentication.
+    password: Option<String>,
+    /// Connect to memcached server over TLS or not.
+    tls: bool,
+}
+
+impl Debug for MemcachedBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("MemcachedBuilder");
+        ds.field("endpoint", &self.endpoint);
+        ds.field("root", &self.root);
+        ds.field("default_ttl", &self.default_ttl);
+        ds.field("username", &self.username);
+        if self.password.is_some() {
+            ds.field("password", &"<redacted>");
+        }
+        ds.field("tls", &self.tls);
+        ds.finish()
+    }
 }
 
 impl MemcachedBuilder {
@@ -113,6 +150,33 @@ impl MemcachedBuilder {
         self.default_ttl = Some(ttl);
         self
     }
+
+    /// Set the username for authentication.
+    pub fn username(&mut self, username: &str) -> &mut Self {
+        if !username.is_empty() {
+            self.username = Some(username.to_owned());
+        }
+        self
+    }
+
+    /// Set the password for authentication.
+    pub fn password(&mut self, password: &str) -> &mut Self {
+        if !password.is_empty() {
+            self.password = Some(password.to_owned());
+        }
+        self
+    }
+
+    /// Connect to memcached server over TLS.
+    ///
+    /// This requires the `services-memcached-rustls` feature, and server
+    /// certificate will be verified against system's native roots.
+    ///
+    /// default: false
+    pub fn tls(&mut self, tls: bool) -> &mut Self {
+        self.tls = tls;
+        self
+    }
 }
 
 impl Builder for MemcachedBuilder {
@@ -124,6 +188,11 @@ impl Builder for MemcachedBuilder {
 
         map.get("root").map(|v| builder.root(v));
         map.get("endpoint").map(|v| builder.endpoint(v));
+        map.get("username").map(|v| builder.username(v));
+        map.get("password").map(|v| builder.password(v));
+        map.get("tls")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.tls(true));
 
         builder
     }
@@ -177,6 +246,26 @@ impl Builder for MemcachedBuilder {
         };
         let endpoint = format!("{host}:{port}",);
 
+        let credential = match (self.username.clone(), self.password.clone()) {
+            (None, None) => None,
+            (Some(username), Some(password)) => Some((username, password)),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "username and password must be set together",
+                )
+                .with_context("service", Scheme::Memcached));
+            }
+        };
+
+        if self.tls && cfg!(not(feature = "services-memcached-rustls")) {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "tls is enabled but feature services-memcached-rustls is not enabled",
+            )
+            .with_context("service", Scheme::Memcached));
+        }
+
         let root = normalize_root(
             self.root
                 .clone()
@@ -186,7 +275,12 @@ impl Builder for MemcachedBuilder {
 
         let conn = OnceCell::new();
         Ok(MemcachedBackend::new(Adapter {
-            endpoint,
+            manager: MemcacheConnectionManager {
+                address: endpoint,
+                host,
+                credential,
+                tls: self.tls,
+            },
             conn,
             default_ttl: self.default_ttl,
         })
@@ -199,7 +293,7 @@ pub type MemcachedBackend = kv::Backend<Adapter>;
 
 #[derive(Clone, Debug)]
 pub struct Adapter {
-    endpoint: String,
+    manager: MemcacheConnectionManager,
     default_ttl: Option<Duration>,
     conn: OnceCell<bb8::Pool<MemcacheConnectionManager>>,
 }
@@ -209,7 +303,12 @@ impl Adapter {
         let pool = self
             .conn
             .get_or_try_init(|| async {
-                let mgr = MemcacheConnectionManager::new(&self.endpoint);
+                // bb8 will retry failed connections in background and only
+                // returns a timeout error, connect once here to surface the
+                // real error (like authentication failure) to users.
+                bb8::ManageConnection::connect(&self.manager).await?;
+
+                let mgr = self.manager.clone();
 
                 bb8::Pool::builder().build(mgr).await.map_err(|err| {
                     Error::new(ErrorKind::ConfigInvalid, "connect to memecached failed")
@@ -271,16 +370,69 @@ impl kv::Adapter for Adapter {
 }
 
 /// A `bb8::ManageConnection` for `memcache_async::ascii::Protocol`.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 struct MemcacheConnectionManager {
     address: String,
+    /// The host of endpoint, used as server name while tls is enabled.
+    #[cfg_attr(not(feature = "services-memcached-rustls"), allow(dead_code))]
+    host: String,
+    credential: Option<(String, String)>,
+    tls: bool,
+}
+
+impl Debug for MemcacheConnectionManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("MemcacheConnectionManager");
+        ds.field("address", &self.address);
+        ds.field("username", &self.credential.as_ref().map(|(u, _)| u));
+        ds.field("tls", &self.tls);
+        ds.finish()
+    }
 }
 
 impl MemcacheConnectionManager {
-    fn new(address: &str) -> Self {
-        Self {
-            address: address.to_string(),
+    #[cfg(feature = "services-memcached-rustls")]
+    async fn connect_tls(&self, conn: TcpStream) -> Result<Box<dyn ascii::Stream>> {
+        use std::sync::Arc;
+
+        use tokio_rustls::rustls;
+
+        let mut roots = rustls::RootCertStore::empty();
+        let certs = rustls_native_certs::load_native_certs().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "load native certs failed").set_source(err)
+        })?;
+        for cert in certs {
+            // Ignore invalid certs in system's store like other clients do.
+            let _ = roots.add(&rustls::Certificate(cert.0));
         }
+
+        let config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let server_name = rustls::ServerName::try_from(self.host.as_str()).map_err(|err| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "endpoint host is invalid server name",
+            )
+            .with_context("service", Scheme::Memcached)
+            .with_context("host", &self.host)
+            .set_source(err)
+        })?;
+
+        let conn = tokio_rustls::TlsConnector::from(Arc::new(config))
+            .connect(server_name, conn)
+            .await
+            .map_err(parse_io_error)?;
+        Ok(Box::new(conn))
+    }
+
+    #[cfg(not(feature = "services-memcached-rustls"))]
+    async fn connect_tls(&self, _: TcpStream) -> Result<Box<dyn ascii::Stream>> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "tls is enabled but feature services-memcached-rustls is not enabled",
+        ))
     }
 }
 
@@ -294,7 +446,17 @@ impl bb8::ManageConnection for MemcacheConnectionManager {
         let conn = TcpStream::connect(&self.address)
             .await
             .map_err(parse_io_error)?;
-        Ok(ascii::Connection::new(conn))
+        let conn: Box<dyn ascii::Stream> = if self.tls {
+            self.connect_tls(conn).await?
+        } else {
+            Box::new(conn)
+        };
+
+        let mut conn = ascii::Connection::new(conn);
+        if let Some((username, password)) = &self.credential {
+            conn.auth(username, password).await?;
+        }
+        Ok(conn)
     }
 
     async fn is_valid(&self, conn: &mut Self::Connection) -> std::result::Result<(), Self::Error> {
@@ -309,3 +471,87 @@ impl bb8::ManageConnection for MemcacheConnectionManager {
 pub fn parse_io_error(err: std::io::Error) -> Error {
     Error::new(ErrorKind::Unexpected, &err.kind().to_string()).set_source(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::io::BufReader;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Start a fake memcached server which accepts `user secret` only.
+    async fn start_auth_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut authed = false;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        if stream.read_line(&mut line).await.unwrap() == 0 {
+                            return;
+                        }
+                        let resp: &[u8] = if line.starts_with("set auth") {
+                            line.clear();
+                            stream.read_line(&mut line).await.unwrap();
+                            if line.trim_end() == "user secret" {
+                                authed = true;
+                                b"STORED\r\n"
+                            } else {
+                                b"CLIENT_ERROR authentication failure\r\n"
+                            }
+                        } else if !authed {
+                            b"CLIENT_ERROR unauthenticated\r\n"
+                        } else if line.starts_with("version") {
+                            b"VERSION 1.6.21\r\n"
+                        } else {
+                            b"END\r\n"
+                        };
+                        stream.get_mut().write_all(resp).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        format!("tcp://{addr}")
+    }
+
+    #[tokio::test]
+    async fn test_auth() -> Result<()> {
+        let endpoint = start_auth_server().await;
+
+        let mut builder = MemcachedBuilder::default();
+        builder
+            .endpoint(&endpoint)
+            .username("user")
+            .password("secret");
+        let op = Operator::new(builder)?.finish();
+        assert!(!op.is_exist("not_exist").await?);
+
+        let mut builder = MemcachedBuilder::default();
+        builder
+            .endpoint(&endpoint)
+            .username("user")
+            .password("wrong");
+        let op = Operator::new(builder)?.finish();
+        let err = op.stat("not_exist").await.expect_err("auth must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_username_without_password() {
+        let mut builder = MemcachedBuilder::default();
+        builder.endpoint("tcp://127.0.0.1:11211").username("user");
+        let err = builder.build().expect_err("build must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
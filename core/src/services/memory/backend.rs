@@ -21,6 +21,10 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use bytes::BytesMut;
+use chrono::DateTime;
+use chrono::Utc;
 use parking_lot::Mutex;
 
 use crate::raw::adapters::typed_kv;
@@ -31,6 +35,7 @@ use crate::*;
 #[derive(Default)]
 pub struct MemoryBuilder {
     root: Option<String>,
+    clock: Option<Arc<dyn MemoryClock>>,
 }
 
 impl MemoryBuilder {
@@ -39,6 +44,64 @@ impl MemoryBuilder {
         self.root = Some(path.into());
         self
     }
+
+    /// Set the clock used to set the last modified time of entries.
+    ///
+    /// The system clock will be used by default. Tests could use
+    /// [`MemoryManualClock`] to control the time.
+    pub fn clock(&mut self, clock: impl MemoryClock) -> &mut Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+}
+
+/// MemoryClock is used by memory service to get the current time.
+pub trait MemoryClock: Send + Sync + 'static {
+    /// Get the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// SystemClock returns the current time of system.
+struct SystemClock;
+
+impl MemoryClock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// MemoryManualClock is a clock that only changes when users set or
+/// advance it.
+///
+/// It's cheap to clone, all clones share the same time.
+#[derive(Debug, Clone)]
+pub struct MemoryManualClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MemoryManualClock {
+    /// Create a new clock starts at given time.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Set the current time of this clock.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock() = now;
+    }
+
+    /// Advance the current time of this clock by given duration.
+    pub fn advance(&self, duration: chrono::Duration) {
+        *self.now.lock() += duration;
+    }
+}
+
+impl MemoryClock for MemoryManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock()
+    }
 }
 
 impl Builder for MemoryBuilder {
@@ -56,6 +119,7 @@ impl Builder for MemoryBuilder {
     fn build(&mut self) -> Result<Self::Accessor> {
         let adapter = Adapter {
             inner: Arc::new(Mutex::new(BTreeMap::default())),
+            clock: self.clock.take().unwrap_or_else(|| Arc::new(SystemClock)),
         };
 
         Ok(MemoryBackend::new(adapter).with_root(self.root.as_deref().unwrap_or_default()))
@@ -63,11 +127,52 @@ impl Builder for MemoryBuilder {
 }
 
 /// Backend is used to serve `Accessor` support in memory.
+///
+/// Build it directly to keep a typed handle which can manage all entries
+/// in bulk:
+///
+/// ```
+/// use anyhow::Result;
+/// use opendal::services::Memory;
+/// use opendal::Builder;
+/// use opendal::OperatorBuilder;
+///
+/// fn main() -> Result<()> {
+///     let backend = Memory::default().build()?;
+///     let _op = OperatorBuilder::new(backend.clone()).finish();
+///
+///     backend.clear();
+///     assert!(backend.is_empty());
+///     Ok(())
+/// }
+/// ```
 pub type MemoryBackend = typed_kv::Backend<Adapter>;
 
+impl MemoryBackend {
+    /// Remove all entries of this backend in one operation.
+    ///
+    /// All entries will be dropped no matter which root they belong to,
+    /// since they are shared by all clones of this backend.
+    pub fn clear(&self) {
+        self.adapter().inner.lock().clear();
+    }
+
+    /// Get the count of entries in this backend, including directories
+    /// created by `create_dir`.
+    pub fn len(&self) -> usize {
+        self.adapter().inner.lock().len()
+    }
+
+    /// Check if this backend is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adapter().inner.lock().is_empty()
+    }
+}
+
 #[derive(Clone)]
 pub struct Adapter {
     inner: Arc<Mutex<BTreeMap<String, typed_kv::Value>>>,
+    clock: Arc<dyn MemoryClock>,
 }
 
 impl Debug for Adapter {
@@ -87,6 +192,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                append: true,
             },
         )
     }
@@ -106,7 +212,8 @@ impl typed_kv::Adapter for Adapter {
         self.blocking_set(path, value)
     }
 
-    fn blocking_set(&self, path: &str, value: typed_kv::Value) -> Result<()> {
+    fn blocking_set(&self, path: &str, mut value: typed_kv::Value) -> Result<()> {
+        value.metadata.set_last_modified(self.clock.now());
         self.inner.lock().insert(path.to_string(), value);
 
         Ok(())
@@ -140,6 +247,35 @@ impl typed_kv::Adapter for Adapter {
 
         Ok(keys)
     }
+
+    async fn append(&self, path: &str, value: Bytes) -> Result<()> {
+        let now = self.clock.now();
+        let mut inner = self.inner.lock();
+
+        let mut v = match inner.remove(path) {
+            Some(old) => {
+                let mut bs = BytesMut::with_capacity(old.value.len() + value.len());
+                bs.extend_from_slice(&old.value);
+                bs.extend_from_slice(&value);
+
+                typed_kv::Value {
+                    metadata: old.metadata,
+                    value: bs.freeze(),
+                }
+            }
+            None => typed_kv::Value {
+                metadata: Metadata::new(EntryMode::FILE),
+                value,
+            },
+        };
+
+        v.metadata
+            .set_content_length(v.value.len() as u64)
+            .set_last_modified(now);
+        inner.insert(path.to_string(), v);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -155,4 +291,83 @@ mod tests {
         let b2 = MemoryBuilder::default().build().unwrap();
         assert_ne!(b1.info().name(), b2.info().name())
     }
+
+    #[test]
+    fn test_operator_info_limits() {
+        let op = Operator::new(MemoryBuilder::default()).unwrap().finish();
+        let info = op.info();
+
+        assert!(!info.can_presign());
+        assert!(!info.can_presign_read());
+        assert_eq!(info.batch_max_operations(), None);
+        assert_eq!(info.write_multi_min_size(), None);
+        assert_eq!(info.write_multi_max_size(), None);
+        assert_eq!(info.write_total_max_size(), None);
+        assert_eq!(info.list_max_limit(), None);
+    }
+
+    #[tokio::test]
+    async fn test_clear() -> Result<()> {
+        let backend = MemoryBuilder::default().build()?;
+        let op = OperatorBuilder::new(backend.clone()).finish();
+        assert!(backend.is_empty());
+
+        op.write("a", "Hello, World!").await?;
+        op.write("dir/b", "Hello, World!").await?;
+        op.create_dir("empty/").await?;
+        assert_eq!(backend.len(), 3);
+
+        backend.clear();
+        assert!(backend.is_empty());
+        assert!(!op.is_exist("a").await?);
+        assert!(!op.is_exist("dir/b").await?);
+
+        // The backend is still usable after clear.
+        op.write("c", "Hello, World!").await?;
+        assert_eq!(backend.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_append() -> Result<()> {
+        let op = Operator::new(MemoryBuilder::default())?.finish();
+        assert!(op.info().capability().append);
+
+        op.append("a", "Hello").await?;
+        op.append("a", ", ").await?;
+        op.append("a", "World!").await?;
+        assert_eq!(op.read("a").await?, b"Hello, World!");
+        assert_eq!(op.stat("a").await?.content_length(), 13);
+
+        // Append to a written file will append to the end.
+        op.write("b", "Hello").await?;
+        let mut a = op.appender("b").await?;
+        a.append(", ").await?;
+        a.append("World!").await?;
+        a.close().await?;
+        assert_eq!(op.read("b").await?, b"Hello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock() -> Result<()> {
+        let now = "2022-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let clock = MemoryManualClock::new(now);
+
+        let mut builder = MemoryBuilder::default();
+        builder.clock(clock.clone());
+        let op = Operator::new(builder)?.finish();
+
+        op.write("a", "Hello, World!").await?;
+        assert_eq!(op.stat("a").await?.last_modified(), Some(now));
+
+        clock.advance(chrono::Duration::hours(1));
+        op.write("b", "Hello, World!").await?;
+        assert_eq!(op.stat("a").await?.last_modified(), Some(now));
+        assert_eq!(
+            op.stat("b").await?.last_modified(),
+            Some(now + chrono::Duration::hours(1))
+        );
+        Ok(())
+    }
 }
@@ -16,4 +16,7 @@
 // under the License.
 
 mod backend;
+pub use backend::MemoryBackend;
 pub use backend::MemoryBuilder as Memory;
+pub use backend::MemoryClock;
+pub use backend::MemoryManualClock;
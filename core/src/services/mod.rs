@@ -58,6 +58,8 @@ pub use ftp::Ftp;
 mod gcs;
 #[cfg(feature = "services-gcs")]
 pub use gcs::Gcs;
+#[cfg(feature = "services-gcs")]
+pub use gcs::GcsIfMetagenerationMatch;
 
 #[cfg(feature = "services-ghac")]
 mod ghac;
@@ -69,6 +71,11 @@ mod hdfs;
 #[cfg(feature = "services-hdfs")]
 pub use hdfs::Hdfs;
 
+#[cfg(feature = "services-hdfs-native")]
+mod hdfs_native;
+#[cfg(feature = "services-hdfs-native")]
+pub use self::hdfs_native::HdfsNative;
+
 #[cfg(feature = "services-http")]
 mod http;
 #[cfg(feature = "services-http")]
@@ -93,6 +100,12 @@ pub use memcached::Memcached;
 mod memory;
 #[cfg(feature = "services-memory")]
 pub use memory::Memory;
+#[cfg(feature = "services-memory")]
+pub use memory::MemoryBackend;
+#[cfg(feature = "services-memory")]
+pub use memory::MemoryClock;
+#[cfg(feature = "services-memory")]
+pub use memory::MemoryManualClock;
 
 #[cfg(feature = "services-mini-moka")]
 mod mini_moka;
@@ -103,6 +116,8 @@ pub use self::mini_moka::MiniMoka;
 mod moka;
 #[cfg(feature = "services-moka")]
 pub use self::moka::Moka;
+#[cfg(feature = "services-moka")]
+pub use self::moka::MokaBackend;
 
 #[cfg(feature = "services-obs")]
 mod obs;
@@ -137,6 +152,20 @@ pub use self::rocksdb::Rocksdb;
 #[cfg(feature = "services-s3")]
 mod s3;
 #[cfg(feature = "services-s3")]
+pub use s3::S3Credential;
+#[cfg(feature = "services-s3")]
+pub use s3::S3CredentialChain;
+#[cfg(feature = "services-s3")]
+pub use s3::S3EnvCredentialLoader;
+#[cfg(feature = "services-s3")]
+pub use s3::S3Imdsv2CredentialLoader;
+#[cfg(feature = "services-s3")]
+pub use s3::S3ProfileCredentialLoader;
+#[cfg(feature = "services-s3")]
+pub use s3::S3RequesterPays;
+#[cfg(feature = "services-s3")]
+pub use s3::S3WebIdentityCredentialLoader;
+#[cfg(feature = "services-s3")]
 pub use s3::S3;
 
 #[cfg(feature = "services-sftp")]
@@ -163,6 +192,12 @@ pub use wasabi::Wasabi;
 mod webdav;
 #[cfg(feature = "services-webdav")]
 pub use webdav::Webdav;
+#[cfg(feature = "services-webdav")]
+pub use webdav::WebdavBackend;
+#[cfg(feature = "services-webdav")]
+pub use webdav::WebdavLock;
+#[cfg(feature = "services-webdav")]
+pub use webdav::WebdavLockScope;
 
 #[cfg(feature = "services-webhdfs")]
 mod webhdfs;
@@ -208,3 +243,13 @@ pub use self::foundationdb::Foundationdb;
 mod postgresql;
 #[cfg(feature = "services-postgresql")]
 pub use self::postgresql::Postgresql;
+
+#[cfg(feature = "services-libsql")]
+mod libsql;
+#[cfg(feature = "services-libsql")]
+pub use self::libsql::Libsql;
+
+#[cfg(feature = "services-d1")]
+mod d1;
+#[cfg(feature = "services-d1")]
+pub use self::d1::D1;
@@ -169,6 +169,29 @@ impl Builder for MokaBuilder {
 /// Backend is used to serve `Accessor` support in moka.
 pub type MokaBackend = typed_kv::Backend<Adapter>;
 
+impl MokaBackend {
+    /// Remove all entries of this backend in one operation.
+    ///
+    /// Refer to [`moka::sync::SegmentedCache::invalidate_all`](https://docs.rs/moka/latest/moka/sync/struct.SegmentedCache.html#method.invalidate_all)
+    pub fn clear(&self) {
+        self.adapter().inner.invalidate_all();
+    }
+
+    /// Get the count of entries in this backend, including directories
+    /// created by `create_dir`.
+    ///
+    /// Expired and invalidated entries are not counted even if they have
+    /// not been evicted yet.
+    pub fn len(&self) -> usize {
+        self.adapter().inner.iter().count()
+    }
+
+    /// Check if this backend is empty.
+    pub fn is_empty(&self) -> bool {
+        self.adapter().inner.iter().next().is_none()
+    }
+}
+
 #[derive(Clone)]
 pub struct Adapter {
     inner: SegmentedCache<String, typed_kv::Value>,
@@ -194,6 +217,7 @@ impl typed_kv::Adapter for Adapter {
                 set: true,
                 delete: true,
                 scan: true,
+                append: false,
             },
         )
     }
@@ -16,4 +16,5 @@
 // under the License.
 
 mod backend;
+pub use backend::MokaBackend;
 pub use backend::MokaBuilder as Moka;
@@ -63,7 +63,7 @@ impl oio::Append for ObsAppender {
                         .get( X_OBS_NEXT_APPEND_POSITION)
                         .and_then(|v| v.to_str().ok())
                         .and_then(|v|v.parse::<u64>().ok())
-                        .ok_or_else(|| Error::new(ErrorKind::Unexpected, "missing x-obs-next-append-position, the object may not be appendable"))?;
+                        .ok_or_else(|| Error::new(ErrorKind::ConditionNotMatch, "missing x-obs-next-append-position, the object may not be appendable"))?;
                     self.position = Some(position);
                 }
 
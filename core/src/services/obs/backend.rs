@@ -104,6 +104,7 @@ pub struct ObsBuilder {
     secret_access_key: Option<String>,
     bucket: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
     /// the part size of obs multipart upload, which should be 100 KiB to 5 GiB.
     /// There is no minimum size limit on the last part of your multipart upload
     write_min_size: Option<usize>,
@@ -192,6 +193,17 @@ impl ObsBuilder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// set the minimum size of unsized write, it should be greater than 100 KB.
     /// Reference: [Huawei Obs multipart upload limits](https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0099.html)
     pub fn write_min_size(&mut self, write_min_size: usize) -> &mut Self {
@@ -217,6 +229,9 @@ impl Builder for ObsBuilder {
         map.get("write_min_size")
             .map(|v| builder.write_min_size(v.parse().expect("input must be a number")));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -260,7 +275,7 @@ impl Builder for ObsBuilder {
         };
         debug!("backend use endpoint {}", &endpoint);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -268,6 +283,12 @@ impl Builder for ObsBuilder {
                     .with_context("service", Scheme::Obs)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Obs)
+            })?;
+        }
 
         let mut cfg = HuaweicloudObsConfig::default();
         // Load cfg from env first.
@@ -358,6 +379,9 @@ impl Accessor for ObsBackend {
                 write_with_content_type: true,
                 write_with_cache_control: true,
                 write_without_content_length: true,
+                write_multi_min_size: Some(100 * 1024),
+                write_multi_max_size: Some(5 * 1024 * 1024 * 1024),
+                write_total_max_size: Some(48_800 * 1024 * 1024 * 1024),
 
                 append: true,
                 append_with_cache_control: true,
@@ -369,6 +393,7 @@ impl Accessor for ObsBackend {
                 copy: true,
 
                 list: true,
+                list_max_limit: Some(1000),
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
 
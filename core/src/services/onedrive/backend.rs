@@ -64,7 +64,7 @@ impl Debug for OnedriveBackend {
 impl Accessor for OnedriveBackend {
     type Reader = IncomingAsyncBody;
     type BlockingReader = ();
-    type Writer = OneDriveWriter;
+    type Writer = oio::MultipartUploadWriter<OneDriveWriter>;
     type BlockingWriter = ();
     type Appender = ();
     type Pager = OnedrivePager;
@@ -339,6 +339,38 @@ impl OnedriveBackend {
         self.client.send(req).await
     }
 
+    pub(crate) async fn onedrive_get_upload_session(
+        &self,
+        url: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = Request::get(url);
+
+        let auth_header_content = format!("Bearer {}", self.access_token);
+        req = req.header(header::AUTHORIZATION, auth_header_content);
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    pub(crate) async fn onedrive_cancel_upload_session(
+        &self,
+        url: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = Request::delete(url);
+
+        let auth_header_content = format!("Bearer {}", self.access_token);
+        req = req.header(header::AUTHORIZATION, auth_header_content);
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
     async fn onedrive_create_dir(
         &self,
         url: &str,
@@ -78,6 +78,7 @@ pub struct OnedriveBuilder {
     access_token: Option<String>,
     root: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for OnedriveBuilder {
@@ -111,6 +112,17 @@ impl OnedriveBuilder {
         self.http_client = Some(http_client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for OnedriveBuilder {
@@ -124,6 +136,9 @@ impl Builder for OnedriveBuilder {
         map.get("root").map(|v| builder.root(v));
         map.get("access_token").map(|v| builder.access_token(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -131,7 +146,7 @@ impl Builder for OnedriveBuilder {
         let root = normalize_root(&self.root.take().unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -139,6 +154,12 @@ impl Builder for OnedriveBuilder {
                     .with_context("service", Scheme::Onedrive)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Onedrive)
+            })?;
+        }
 
         match self.access_token.clone() {
             Some(access_token) => Ok(OnedriveBackend::new(root, access_token, client)),
@@ -127,6 +127,27 @@ pub struct OneDriveUploadSessionCreationResponseBody {
     pub expiration_date_time: String,
 }
 
+/// The status of an upload session, returned by fragment uploads and
+/// querying the upload url.
+///
+/// ref: https://learn.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_createuploadsession?view=odsp-graph-online#resuming-an-in-progress-upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OneDriveUploadSessionStatus {
+    #[serde(rename = "nextExpectedRanges", default)]
+    pub next_expected_ranges: Vec<String>,
+}
+
+impl OneDriveUploadSessionStatus {
+    /// Returns the start of the first expected range like `12345-` or
+    /// `12345-23456`.
+    pub fn next_expected_offset(&self) -> Option<usize> {
+        self.next_expected_ranges
+            .first()
+            .and_then(|v| v.split('-').next())
+            .and_then(|v| v.parse().ok())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OneDriveUploadSessionCreationRequestBody {
     item: FileUploadItem,
@@ -297,3 +318,16 @@ fn test_parse_folder_single() {
         panic!("item_type is not folder");
     }
 }
+
+#[test]
+fn test_parse_upload_session_status() {
+    let response_json = r#"{
+        "expirationDateTime": "2015-01-29T09:21:55.523Z",
+        "nextExpectedRanges": ["26-", "128-256"]
+    }"#;
+    let status: OneDriveUploadSessionStatus = serde_json::from_str(response_json).unwrap();
+    assert_eq!(status.next_expected_offset(), Some(26));
+
+    let status: OneDriveUploadSessionStatus = serde_json::from_str("{}").unwrap();
+    assert_eq!(status.next_expected_offset(), None);
+}
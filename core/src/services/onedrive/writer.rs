@@ -15,23 +15,46 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Mutex;
+
 use async_trait::async_trait;
 use bytes::Buf;
 use bytes::Bytes;
+use bytes::BytesMut;
+use futures::TryStreamExt;
 use http::StatusCode;
 
 use super::backend::OnedriveBackend;
 use super::error::parse_error;
 use super::graph_model::OneDriveUploadSessionCreationRequestBody;
 use super::graph_model::OneDriveUploadSessionCreationResponseBody;
+use super::graph_model::OneDriveUploadSessionStatus;
 use crate::raw::*;
 use crate::*;
 
+/// OneDriveWriter writes data via OneDrive's upload API.
+///
+/// - Files no larger than [`OneDriveWriter::MAX_SIMPLE_SIZE`] that are
+///   written at once will be uploaded via simple upload.
+/// - Others will be uploaded via upload sessions, and `upload_id` of
+///   [`oio::MultipartUploadWrite`] is the upload url of the session.
 pub struct OneDriveWriter {
     backend: OnedriveBackend,
 
     op: OpWrite,
     path: String,
+
+    state: Mutex<SessionState>,
+}
+
+/// The state of an upload session.
+#[derive(Default)]
+struct SessionState {
+    /// The bytes that have been uploaded.
+    offset: usize,
+    /// The bytes that have not been uploaded since they are not aligned
+    /// to [`OneDriveWriter::CHUNK_SIZE_FACTOR`].
+    pending: Bytes,
 }
 
 impl OneDriveWriter {
@@ -39,41 +62,42 @@ impl OneDriveWriter {
     // If your app splits a file into multiple byte ranges, the size of each byte range MUST be a multiple of 320 KiB (327,680 bytes). Using a fragment size that does not divide evenly by 320 KiB will result in errors committing some files.
     // https://learn.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_createuploadsession?view=odsp-graph-online#upload-bytes-to-the-upload-session
     const CHUNK_SIZE_FACTOR: usize = 327_680;
-    pub fn new(backend: OnedriveBackend, op: OpWrite, path: String) -> Self {
-        OneDriveWriter { backend, op, path }
-    }
-}
+    /// The max size of one fragment, must be less than 60 MiB.
+    const MAX_FRAGMENT_SIZE: usize = 32 * Self::CHUNK_SIZE_FACTOR;
+    /// The max times to retry a failed fragment.
+    const MAX_FRAGMENT_RETRIES: usize = 3;
 
-#[async_trait]
-impl oio::Write for OneDriveWriter {
-    async fn write(&mut self, bs: Bytes) -> Result<()> {
-        let size = bs.len();
-
-        if size <= Self::MAX_SIMPLE_SIZE {
-            self.write_simple(bs).await
-        } else {
-            self.write_chunked(bs).await
-        }
-    }
+    pub fn new(
+        backend: OnedriveBackend,
+        op: OpWrite,
+        path: String,
+    ) -> oio::MultipartUploadWriter<Self> {
+        let total_size = op.content_length();
 
-    async fn sink(&mut self, _size: u64, _s: oio::Streamer) -> Result<()> {
-        Err(Error::new(
-            ErrorKind::Unsupported,
-            "Write::sink is not supported",
-        ))
+        oio::MultipartUploadWriter::new(Self::uploader(backend, op, path), total_size)
     }
 
-    async fn abort(&mut self) -> Result<()> {
-        Ok(())
+    /// Create a raw uploader which allows users to control parts manually.
+    pub fn uploader(backend: OnedriveBackend, op: OpWrite, path: String) -> Self {
+        OneDriveWriter {
+            backend,
+            op,
+            path,
+            state: Mutex::new(SessionState::default()),
+        }
     }
 
-    async fn close(&mut self) -> Result<()> {
-        Ok(())
+    /// The total size of the file, which is required by `Content-Range`.
+    fn total_size(&self) -> Result<usize> {
+        self.op.content_length().map(|v| v as usize).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "write without content length is not supported",
+            )
+        })
     }
-}
 
-impl OneDriveWriter {
-    async fn write_simple(&mut self, bs: Bytes) -> Result<()> {
+    async fn write_simple(&self, bs: Bytes) -> Result<()> {
         let resp = self
             .backend
             .onedrive_upload_simple(
@@ -97,53 +121,100 @@ impl OneDriveWriter {
         }
     }
 
-    pub(crate) async fn write_chunked(&self, total_bytes: Bytes) -> Result<()> {
-        // Upload large files via sessions: https://learn.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_createuploadsession?view=odsp-graph-online#upload-bytes-to-the-upload-session
-        // 1. Create an upload session
-        // 2. Upload the bytes of each chunk
-        // 3. Commit the session
-
-        let session_response = self.create_upload_session().await?;
-
-        let mut offset = 0;
-
-        let iter = total_bytes.chunks(OneDriveWriter::CHUNK_SIZE_FACTOR);
+    /// Upload `data` starting at `offset` as fragments to the session.
+    ///
+    /// Only bytes aligned to [`Self::CHUNK_SIZE_FACTOR`] will be uploaded
+    /// unless `last` is set. Returns the new offset and the bytes not
+    /// uploaded.
+    ///
+    /// A failed fragment will be retried from the `nextExpectedRanges`
+    /// reported by the session.
+    async fn upload_fragments(
+        &self,
+        upload_url: &str,
+        mut offset: usize,
+        mut data: Bytes,
+        last: bool,
+    ) -> Result<(usize, Bytes)> {
+        let total = self.total_size()?;
+        let mut retries = 0;
 
-        for chunk in iter {
-            let mut end = offset + OneDriveWriter::CHUNK_SIZE_FACTOR;
-            if end > total_bytes.len() {
-                end = total_bytes.len();
+        loop {
+            let sendable = if last {
+                data.len()
+            } else {
+                data.len() / Self::CHUNK_SIZE_FACTOR * Self::CHUNK_SIZE_FACTOR
+            };
+            if sendable == 0 {
+                return Ok((offset, data));
             }
-            let total_len = total_bytes.len();
-            let chunk_end = end - 1;
+            let size = sendable.min(Self::MAX_FRAGMENT_SIZE);
 
-            let resp = self
+            let res = self
                 .backend
                 .onedrive_chunked_upload(
-                    &session_response.upload_url,
+                    upload_url,
                     None,
                     offset,
-                    chunk_end,
-                    total_len,
-                    AsyncBody::Bytes(Bytes::copy_from_slice(chunk)),
+                    offset + size - 1,
+                    total,
+                    AsyncBody::Bytes(data.slice(..size)),
                 )
-                .await?;
+                .await;
 
-            let status = resp.status();
+            let err = match res {
+                Ok(resp) => match resp.status() {
+                    // Typical response code: 202 Accepted
+                    // Reference: https://learn.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_put_content?view=odsp-graph-online#response
+                    StatusCode::ACCEPTED | StatusCode::CREATED | StatusCode::OK => {
+                        resp.into_body().consume().await?;
+                        offset += size;
+                        data = data.slice(size..);
+                        retries = 0;
+                        continue;
+                    }
+                    _ => parse_error(resp).await?,
+                },
+                Err(err) => err,
+            };
 
-            match status {
-                // Typical response code: 202 Accepted
-                // Reference: https://learn.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_put_content?view=odsp-graph-online#response
-                StatusCode::ACCEPTED | StatusCode::CREATED | StatusCode::OK => {
-                    resp.into_body().consume().await?;
-                }
-                _ => return Err(parse_error(resp).await?),
+            if retries >= Self::MAX_FRAGMENT_RETRIES {
+                return Err(err);
             }
+            retries += 1;
 
-            offset += OneDriveWriter::CHUNK_SIZE_FACTOR;
+            // The fragment may have been received partially or entirely,
+            // ask the session where to continue.
+            let expected = self.next_expected_offset(upload_url).await?;
+            if expected < offset || expected > offset + size {
+                return Err(err
+                    .with_context("offset", offset.to_string())
+                    .with_context("next_expected_offset", expected.to_string()));
+            }
+            data = data.slice(expected - offset..);
+            offset = expected;
         }
+    }
 
-        Ok(())
+    async fn next_expected_offset(&self, upload_url: &str) -> Result<usize> {
+        let resp = self.backend.onedrive_get_upload_session(upload_url).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                let result: OneDriveUploadSessionStatus =
+                    serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+                result.next_expected_offset().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "upload session doesn't have next expected ranges",
+                    )
+                })
+            }
+            _ => Err(parse_error(resp).await?),
+        }
     }
 
     async fn create_upload_session(&self) -> Result<OneDriveUploadSessionCreationResponseBody> {
@@ -179,3 +250,240 @@ impl OneDriveWriter {
         }
     }
 }
+
+/// Collect the body into bytes, fragments need to be resent on failure.
+async fn collect_body(size: u64, body: AsyncBody) -> Result<Bytes> {
+    match body {
+        AsyncBody::Empty => Ok(Bytes::new()),
+        AsyncBody::Bytes(bs) => Ok(bs),
+        AsyncBody::Stream(s) => {
+            let chunks: Vec<Bytes> = s.try_collect().await?;
+            let mut buf = BytesMut::with_capacity(size as usize);
+            chunks.iter().for_each(|bs| buf.extend_from_slice(bs));
+            Ok(buf.freeze())
+        }
+    }
+}
+
+#[async_trait]
+impl oio::MultipartUploadWrite for OneDriveWriter {
+    async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let bs = collect_body(size, body).await?;
+        if bs.len() <= Self::MAX_SIMPLE_SIZE {
+            return self.write_simple(bs).await;
+        }
+
+        let upload_url = self.initiate_part().await?;
+        self.upload_fragments(&upload_url, 0, bs, true).await?;
+        Ok(())
+    }
+
+    async fn initiate_part(&self) -> Result<String> {
+        // Make sure we know the total size before creating session.
+        self.total_size()?;
+
+        let session = self.create_upload_session().await?;
+        *self.state.lock().expect("lock must succeed") = SessionState::default();
+        Ok(session.upload_url)
+    }
+
+    async fn write_part(
+        &self,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: AsyncBody,
+    ) -> Result<oio::MultipartUploadPart> {
+        let bs = collect_body(size, body).await?;
+
+        let (offset, data) = {
+            let state = self.state.lock().expect("lock must succeed");
+            let mut data = BytesMut::with_capacity(state.pending.len() + bs.len());
+            data.extend_from_slice(&state.pending);
+            data.extend_from_slice(&bs);
+            (state.offset, data.freeze())
+        };
+
+        let (offset, pending) = self
+            .upload_fragments(upload_id, offset, data, false)
+            .await?;
+        *self.state.lock().expect("lock must succeed") = SessionState { offset, pending };
+
+        Ok(oio::MultipartUploadPart {
+            part_number,
+            etag: String::new(),
+            checksum: None,
+        })
+    }
+
+    async fn complete_part(&self, upload_id: &str, _: &[oio::MultipartUploadPart]) -> Result<()> {
+        let (offset, pending) = {
+            let state = self.state.lock().expect("lock must succeed");
+            (state.offset, state.pending.clone())
+        };
+
+        let (offset, _) = self
+            .upload_fragments(upload_id, offset, pending, true)
+            .await?;
+
+        let total = self.total_size()?;
+        if offset != total {
+            return Err(Error::new(
+                ErrorKind::ContentIncomplete,
+                "the size of written data doesn't match content length",
+            )
+            .with_context("written", offset.to_string())
+            .with_context("content_length", total.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn abort_part(&self, upload_id: &str) -> Result<()> {
+        let resp = self
+            .backend
+            .onedrive_cancel_upload_session(upload_id)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use oio::MultipartUploadWrite;
+    use rand::prelude::*;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    /// A fake upload session.
+    ///
+    /// The second fragment will be persisted but answered with an error to
+    /// make sure the writer continues from `nextExpectedRanges`.
+    struct Session {
+        data: Arc<Mutex<Vec<u8>>>,
+        fragments: AtomicUsize,
+    }
+
+    impl Respond for Session {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let mut data = self.data.lock().unwrap();
+            if req.method.as_ref() == "GET" {
+                return ResponseTemplate::new(200).set_body_string(format!(
+                    r#"{{"expirationDateTime":"2015-01-29T09:21:55.523Z","nextExpectedRanges":["{}-"]}}"#,
+                    data.len()
+                ));
+            }
+
+            let range = req.headers.get(&"content-range".into()).unwrap().as_str();
+            let (range, total) = range
+                .strip_prefix("bytes ")
+                .unwrap()
+                .split_once('/')
+                .unwrap();
+            let (start, end) = range.split_once('-').unwrap();
+            let (start, end, total): (usize, usize, usize) = (
+                start.parse().unwrap(),
+                end.parse().unwrap(),
+                total.parse().unwrap(),
+            );
+            assert_eq!(
+                start,
+                data.len(),
+                "fragment must start at next expected offset"
+            );
+            assert_eq!(req.body.len(), end - start + 1);
+            assert!(
+                end + 1 == total || req.body.len() % OneDriveWriter::CHUNK_SIZE_FACTOR == 0,
+                "fragment must be aligned to 320 KiB"
+            );
+            data.extend_from_slice(&req.body);
+
+            if self.fragments.fetch_add(1, Ordering::SeqCst) == 1 {
+                return ResponseTemplate::new(500);
+            }
+            if data.len() == total {
+                return ResponseTemplate::new(201).set_body_string("{}");
+            }
+            ResponseTemplate::new(202).set_body_string(format!(
+                r#"{{"expirationDateTime":"2015-01-29T09:21:55.523Z","nextExpectedRanges":["{}-"]}}"#,
+                data.len()
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_session_large_file() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        let data = Arc::new(Mutex::new(Vec::new()));
+        Mock::given(path("/upload/session"))
+            .and(method("PUT"))
+            .respond_with(Session {
+                data: data.clone(),
+                fragments: AtomicUsize::new(0),
+            })
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/upload/session"))
+            .and(method("GET"))
+            .respond_with(Session {
+                data: data.clone(),
+                fragments: AtomicUsize::new(0),
+            })
+            .mount(&mock_server)
+            .await;
+
+        // Parts are not aligned to 320 KiB on purpose, and the total size
+        // is larger than the simple upload limit.
+        let part_size = 3 * 1024 * 1024 + 100 * 1024;
+        let total = 3 * part_size;
+
+        let backend =
+            OnedriveBackend::new("/".to_string(), "token".to_string(), HttpClient::new()?);
+        let w = OneDriveWriter::uploader(
+            backend,
+            OpWrite::default().with_content_length(total as u64),
+            "/test".to_string(),
+        );
+        let upload_url = format!("{}/upload/session", mock_server.uri());
+
+        let mut rng = thread_rng();
+        let mut content = Vec::new();
+        for part_number in 0..3 {
+            let mut bs = vec![0; part_size];
+            rng.fill_bytes(&mut bs);
+            content.extend_from_slice(&bs);
+
+            w.write_part(
+                &upload_url,
+                part_number,
+                bs.len() as u64,
+                AsyncBody::Bytes(bs.into()),
+            )
+            .await?;
+        }
+        w.complete_part(&upload_url, &[]).await?;
+
+        assert_eq!(data.lock().unwrap().len(), content.len());
+        assert!(*data.lock().unwrap() == content);
+        Ok(())
+    }
+}
@@ -60,8 +60,11 @@ pub struct OssBuilder {
     access_key_secret: Option<String>,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
     /// the size of each part, and the range is 5MB ~ 5 GB.
     write_min_size: Option<usize>,
+    /// the max size of data that could be buffered in memory while writing.
+    write_memory_budget: Option<usize>,
     /// batch_max_operations
     batch_max_operations: Option<usize>,
 }
@@ -160,6 +163,17 @@ impl OssBuilder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// preprocess the endpoint option
     fn parse_endpoint(&self, endpoint: &Option<String>, bucket: &str) -> Result<(String, String)> {
         let (endpoint, host) = match endpoint.clone() {
@@ -242,6 +256,22 @@ impl OssBuilder {
         self
     }
 
+    /// Set the max size of data that could be buffered in memory while
+    /// writing.
+    ///
+    /// - Data with known size that is no larger than the budget will be
+    ///   uploaded in one request instead of multipart upload.
+    /// - Parts will be as large as the budget allows to reduce requests,
+    ///   but never smaller than `write_min_size`.
+    ///
+    /// The size of parts could still exceed the budget to keep the number
+    /// of parts no more than 10000 for large data.
+    pub fn write_memory_budget(&mut self, write_memory_budget: usize) -> &mut Self {
+        self.write_memory_budget = Some(write_memory_budget);
+
+        self
+    }
+
     /// Set maximum batch operations of this backend.
     pub fn batch_max_operations(&mut self, batch_max_operations: usize) -> &mut Self {
         self.batch_max_operations = Some(batch_max_operations);
@@ -271,8 +301,13 @@ impl Builder for OssBuilder {
             .map(|v| builder.server_side_encryption_key_id(v));
         map.get("write_min_size")
             .map(|v| builder.write_min_size(v.parse::<usize>().unwrap()));
+        map.get("write_memory_budget")
+            .map(|v| builder.write_memory_budget(v.parse::<usize>().unwrap()));
         map.get("batch_max_operations")
             .map(|v| builder.batch_max_operations(v.parse::<usize>().unwrap()));
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -291,7 +326,7 @@ impl Builder for OssBuilder {
             ),
         }?;
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -299,6 +334,12 @@ impl Builder for OssBuilder {
                     .with_context("service", Scheme::Oss)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Oss)
+            })?;
+        }
 
         // Retrieve endpoint and host by parsing the endpoint option and bucket. If presign_endpoint is not
         // set, take endpoint as default presign_endpoint.
@@ -370,6 +411,7 @@ impl Builder for OssBuilder {
                 server_side_encryption,
                 server_side_encryption_key_id,
                 write_min_size,
+                write_memory_budget: self.write_memory_budget,
                 batch_max_operations,
             }),
         })
@@ -410,10 +452,14 @@ impl Accessor for OssBackend {
 
                 write: true,
                 write_can_sink: true,
+                write_can_multi: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
                 write_with_content_disposition: true,
                 write_without_content_length: true,
+                write_multi_min_size: Some(100 * 1024),
+                write_multi_max_size: Some(5 * 1024 * 1024 * 1024),
+                write_total_max_size: Some(48_800 * 1024 * 1024 * 1024),
                 delete: true,
                 create_dir: true,
                 copy: true,
@@ -424,6 +470,7 @@ impl Accessor for OssBackend {
                 append_with_content_disposition: true,
 
                 list: true,
+                list_max_limit: Some(1000),
                 list_with_delimiter_slash: true,
                 list_without_delimiter: true,
 
@@ -487,6 +534,17 @@ impl Accessor for OssBackend {
         ))
     }
 
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        Ok((
+            RpWrite::default(),
+            Box::new(OssWriter::uploader(self.core.clone(), path, args)),
+        ))
+    }
+
     async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
         Ok((
             RpAppend::default(),
@@ -605,11 +663,18 @@ impl Accessor for OssBackend {
 
         let paths = ops
             .into_iter()
-            .map(|(p, _)| {
-                keys.insert(p.clone());
-                p
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => {
+                    keys.insert(p.clone());
+                    Ok(p)
+                }
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "oss services don't support batch write",
+                )
+                .with_context("path", p)),
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
 
         let resp = self.core.oss_delete_objects(paths).await?;
 
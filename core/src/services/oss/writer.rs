@@ -34,19 +34,23 @@ pub struct OssWriter {
 }
 
 impl OssWriter {
-    pub fn new(
-        core: Arc<OssCore>,
-        path: &str,
-        op: OpWrite,
-    ) -> oio::MultipartUploadWriter<OssWriter> {
+    pub fn new(core: Arc<OssCore>, path: &str, op: OpWrite) -> oio::MultipartUploadWriter<Self> {
         let write_min_size = core.write_min_size;
+        let write_memory_budget = core.write_memory_budget;
         let total_size = op.content_length();
-        let oss_writer = OssWriter {
+
+        oio::MultipartUploadWriter::new(Self::uploader(core, path, op), total_size)
+            .with_write_min_size(write_min_size)
+            .with_write_memory_budget(write_memory_budget)
+    }
+
+    /// Create a raw uploader which allows users to control parts manually.
+    pub fn uploader(core: Arc<OssCore>, path: &str, op: OpWrite) -> Self {
+        OssWriter {
             core,
             path: path.to_string(),
             op,
-        };
-        oio::MultipartUploadWriter::new(oss_writer, total_size).with_write_min_size(write_min_size)
+        }
     }
 }
 
@@ -135,7 +139,11 @@ impl oio::MultipartUploadWrite for OssWriter {
 
                 resp.into_body().consume().await?;
 
-                Ok(oio::MultipartUploadPart { part_number, etag })
+                Ok(oio::MultipartUploadPart {
+                    part_number,
+                    etag,
+                    checksum: None,
+                })
             }
             _ => Err(parse_error(resp).await?),
         }
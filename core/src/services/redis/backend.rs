@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::path::PathBuf;
@@ -38,6 +39,22 @@ use crate::*;
 
 const DEFAULT_REDIS_ENDPOINT: &str = "tcp://127.0.0.1:6379";
 const DEFAULT_REDIS_PORT: u16 = 6379;
+const DEFAULT_REDIS_SCAN_COUNT: usize = 1000;
+
+/// Rename `KEYS[1]` to `KEYS[2]` atomically, returns 0 if `KEYS[1]` is not
+/// exist.
+///
+/// `RENAME` returns an error for not exist keys which can't be told from
+/// other errors reliably, so we check the existence in the same script.
+/// `RENAME` also keeps the TTL of the source key and overwrites the
+/// destination key if it's exist.
+const RENAME_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 0 then
+    return 0
+end
+redis.call('RENAME', KEYS[1], KEYS[2])
+return 1
+"#;
 
 /// [Redis](https://redis.io/) services support.
 #[doc = include_str!("docs.md")]
@@ -65,6 +82,10 @@ pub struct RedisBuilder {
     db: i64,
     /// The default ttl for put operations.
     default_ttl: Option<Duration>,
+    /// The `COUNT` hint used by `SCAN` while listing.
+    ///
+    /// default is 1000
+    scan_count: Option<usize>,
 }
 
 impl Debug for RedisBuilder {
@@ -81,6 +102,7 @@ impl Debug for RedisBuilder {
         if self.password.is_some() {
             ds.field("password", &"<redacted>");
         }
+        ds.field("scan_count", &self.scan_count);
         ds.finish()
     }
 }
@@ -144,6 +166,20 @@ impl RedisBuilder {
         }
         self
     }
+
+    /// Set the `COUNT` hint of `SCAN` used while listing.
+    ///
+    /// Listing iterates the keyspace via `SCAN` instead of `KEYS`, so it
+    /// won't block the server. Larger count means fewer round trips but
+    /// more work per call.
+    ///
+    /// default: 1000
+    pub fn scan_count(&mut self, count: usize) -> &mut Self {
+        if count > 0 {
+            self.scan_count = Some(count);
+        }
+        self
+    }
 }
 
 impl Builder for RedisBuilder {
@@ -159,6 +195,8 @@ impl Builder for RedisBuilder {
         map.get("password").map(|v| builder.password(v));
         map.get("db")
             .map(|v| v.parse::<i64>().map(|v| builder.db(v)));
+        map.get("scan_count")
+            .map(|v| v.parse::<usize>().map(|v| builder.scan_count(v)));
 
         builder
     }
@@ -241,6 +279,7 @@ impl Builder for RedisBuilder {
             client,
             conn,
             default_ttl: self.default_ttl,
+            scan_count: self.scan_count.unwrap_or(DEFAULT_REDIS_SCAN_COUNT),
         })
         .with_root(&root))
     }
@@ -255,6 +294,7 @@ pub struct Adapter {
     conn: OnceCell<ConnectionManager>,
 
     default_ttl: Option<Duration>,
+    scan_count: usize,
 }
 
 // implement `Debug` manually, or password may be leaked.
@@ -289,7 +329,10 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 create_dir: true,
+                // Rename is implemented by a lua script atomically.
+                rename_atomic: true,
 
                 ..Default::default()
             },
@@ -322,6 +365,76 @@ impl kv::Adapter for Adapter {
         conn.append(key, value).await?;
         Ok(())
     }
+
+    /// Rename the key via a lua script so that readers will never see
+    /// both keys or neither of them.
+    async fn rename(&self, from: &str, to: &str) -> Result<()> {
+        let mut conn = self.conn().await?;
+        let renamed: i64 = redis::cmd("EVAL")
+            .arg(RENAME_SCRIPT)
+            .arg(2)
+            .arg(from)
+            .arg(to)
+            .query_async(&mut conn)
+            .await?;
+
+        if renamed == 0 {
+            return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")
+                .with_context("path", from));
+        }
+        Ok(())
+    }
+
+    /// Scan keys via `SCAN` with `MATCH` and `COUNT` until the cursor
+    /// returns to 0.
+    ///
+    /// We don't use `KEYS` here, which will block the server on large
+    /// keyspaces.
+    async fn scan(&self, path: &str) -> Result<Vec<String>> {
+        let mut conn = self.conn().await?;
+        let pattern = format!("{}*", escape_glob_pattern(path));
+
+        let mut cursor: u64 = 0;
+        let mut seen = HashSet::new();
+        let mut res = Vec::new();
+        loop {
+            let (next, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(self.scan_count)
+                .query_async(&mut conn)
+                .await?;
+
+            // SCAN could return the same key multiple times.
+            for key in keys {
+                if seen.insert(key.clone()) {
+                    res.push(key);
+                }
+            }
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        Ok(res)
+    }
+}
+
+/// Escape the special characters of redis glob-style pattern so that
+/// they are matched literally.
+fn escape_glob_pattern(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 impl From<RedisError> for Error {
@@ -331,3 +444,207 @@ impl From<RedisError> for Error {
             .set_temporary()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::io::BufReader;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Read a RESP array of bulk strings.
+    async fn read_command(r: &mut BufReader<tokio::net::TcpStream>) -> Option<Vec<String>> {
+        let mut line = String::new();
+        if r.read_line(&mut line).await.unwrap() == 0 {
+            return None;
+        }
+        let n: usize = line.trim_end().trim_start_matches('*').parse().unwrap();
+
+        let mut args = Vec::with_capacity(n);
+        for _ in 0..n {
+            line.clear();
+            r.read_line(&mut line).await.unwrap();
+            let len: usize = line.trim_end().trim_start_matches('$').parse().unwrap();
+            let mut buf = vec![0; len + 2];
+            r.read_exact(&mut buf).await.unwrap();
+            buf.truncate(len);
+            args.push(String::from_utf8(buf).unwrap());
+        }
+        Some(args)
+    }
+
+    fn bulk(s: &str) -> String {
+        format!("${}\r\n{}\r\n", s.len(), s)
+    }
+
+    /// Start a fake redis server that only serves `SCAN` over given keys.
+    ///
+    /// Returns the endpoint and counters of `SCAN` and `KEYS` calls.
+    async fn start_scan_server(keys: Vec<String>) -> (String, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let scans = Arc::new(AtomicUsize::new(0));
+        let keys_calls = Arc::new(AtomicUsize::new(0));
+
+        let (scans_cloned, keys_calls_cloned) = (scans.clone(), keys_calls.clone());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            while let Some(args) = read_command(&mut stream).await {
+                let resp = match args[0].to_uppercase().as_str() {
+                    "SCAN" => {
+                        scans_cloned.fetch_add(1, Ordering::SeqCst);
+                        assert_eq!(args[2], "MATCH");
+                        assert_eq!(args[4], "COUNT");
+                        let cursor: usize = args[1].parse().unwrap();
+                        let count: usize = args[5].parse().unwrap();
+                        let prefix = args[3].trim_end_matches('*').replace('\\', "");
+
+                        // Emulate SCAN by walking `count` slots of the keyspace.
+                        let end = (cursor + count).min(keys.len());
+                        let matched: Vec<&String> = keys[cursor..end]
+                            .iter()
+                            .filter(|k| k.starts_with(&prefix))
+                            .collect();
+                        let next = if end == keys.len() { 0 } else { end };
+
+                        let mut resp =
+                            format!("*2\r\n{}*{}\r\n", bulk(&next.to_string()), matched.len());
+                        for k in matched {
+                            resp.push_str(&bulk(k));
+                        }
+                        resp
+                    }
+                    "KEYS" => {
+                        keys_calls_cloned.fetch_add(1, Ordering::SeqCst);
+                        "-ERR KEYS is not allowed\r\n".to_string()
+                    }
+                    _ => "+OK\r\n".to_string(),
+                };
+                stream.get_mut().write_all(resp.as_bytes()).await.unwrap();
+            }
+        });
+
+        (format!("tcp://{addr}"), scans, keys_calls)
+    }
+
+    /// Start a fake redis server that serves `GET`, `SET`, `DEL` and the
+    /// rename script over an in-memory keyspace.
+    ///
+    /// Returns the endpoint and counter of `EVAL` calls.
+    async fn start_kv_server(keys: Vec<(&str, &str)>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let evals = Arc::new(AtomicUsize::new(0));
+        let mut store: HashMap<String, String> = keys
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        let evals_cloned = evals.clone();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            while let Some(args) = read_command(&mut stream).await {
+                let resp = match args[0].to_uppercase().as_str() {
+                    "GET" => match store.get(&args[1]) {
+                        Some(v) => bulk(v),
+                        None => "$-1\r\n".to_string(),
+                    },
+                    "SET" => {
+                        store.insert(args[1].clone(), args[2].clone());
+                        "+OK\r\n".to_string()
+                    }
+                    "DEL" => format!(":{}\r\n", store.remove(&args[1]).map_or(0, |_| 1)),
+                    "EVAL" => {
+                        evals_cloned.fetch_add(1, Ordering::SeqCst);
+                        assert_eq!(args[1], RENAME_SCRIPT);
+                        assert_eq!(args[2], "2");
+                        // Commands are served one by one, so the script is
+                        // executed atomically like a real redis server.
+                        match store.remove(&args[3]) {
+                            Some(v) => {
+                                store.insert(args[4].clone(), v);
+                                ":1\r\n".to_string()
+                            }
+                            None => ":0\r\n".to_string(),
+                        }
+                    }
+                    _ => "+OK\r\n".to_string(),
+                };
+                stream.get_mut().write_all(resp.as_bytes()).await.unwrap();
+            }
+        });
+
+        (format!("tcp://{addr}"), evals)
+    }
+
+    #[tokio::test]
+    async fn test_rename_atomically() -> Result<()> {
+        let (endpoint, evals) = start_kv_server(vec![("from", "new"), ("to", "old")]).await;
+
+        let mut builder = RedisBuilder::default();
+        builder.endpoint(&endpoint);
+        let op = Operator::new(builder)?.finish();
+
+        // The reader must never see the new content in `to` while `from`
+        // still exists, which is possible for get + set + delete.
+        let reader = {
+            let op = op.clone();
+            tokio::spawn(async move {
+                loop {
+                    if op.read("to").await? == b"new" {
+                        assert!(!op.is_exist("from").await?);
+                        return Ok::<_, Error>(());
+                    }
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        op.rename("from", "to").await?;
+        reader.await.unwrap()?;
+
+        assert_eq!(op.read("to").await?, b"new");
+        assert_eq!(evals.load(Ordering::SeqCst), 1);
+
+        let err = op.rename("from", "to").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(evals.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scan_all_keys() -> Result<()> {
+        let mut keys: Vec<String> = (0..2500).map(|i| format!("dir/{i}")).collect();
+        keys.extend((0..500).map(|i| format!("other/{i}")));
+        let (endpoint, scans, keys_calls) = start_scan_server(keys).await;
+
+        let mut builder = RedisBuilder::default();
+        builder.endpoint(&endpoint).scan_count(100);
+        let op = Operator::new(builder)?.finish();
+
+        let entries = op.list_with("dir/").delimiter("").await?;
+        let paths: HashSet<String> = entries.iter().map(|e| e.path().to_string()).collect();
+
+        assert_eq!(paths.len(), 2500);
+        assert!(paths.iter().all(|p| p.starts_with("dir/")));
+        assert_eq!(scans.load(Ordering::SeqCst), 30);
+        assert_eq!(keys_calls.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_glob_pattern() {
+        assert_eq!(escape_glob_pattern("dir/"), "dir/");
+        assert_eq!(escape_glob_pattern("a*b?c[d]\\"), "a\\*b\\?c\\[d\\]\\\\");
+    }
+}
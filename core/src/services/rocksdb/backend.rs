@@ -21,6 +21,8 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use rocksdb::ColumnFamily;
+use rocksdb::Options;
 use rocksdb::DB;
 
 use crate::raw::adapters::kv;
@@ -37,6 +39,10 @@ pub struct RocksdbBuilder {
     ///
     /// default is "/"
     root: Option<String>,
+    /// The column family to use, all operations will be scoped in it.
+    ///
+    /// default is rocksdb's "default" column family.
+    column_family: Option<String>,
 }
 
 impl RocksdbBuilder {
@@ -55,6 +61,18 @@ impl RocksdbBuilder {
         }
         self
     }
+
+    /// Set the column family to use. Will create if not exists.
+    ///
+    /// All operations (including scan) will be scoped in this column family.
+    ///
+    /// default: rocksdb's "default" column family
+    pub fn column_family(&mut self, column_family: &str) -> &mut Self {
+        if !column_family.is_empty() {
+            self.column_family = Some(column_family.to_owned());
+        }
+        self
+    }
 }
 
 impl Builder for RocksdbBuilder {
@@ -65,6 +83,7 @@ impl Builder for RocksdbBuilder {
         let mut builder = RocksdbBuilder::default();
 
         map.get("datadir").map(|v| builder.datadir(v));
+        map.get("column_family").map(|v| builder.column_family(v));
 
         builder
     }
@@ -74,14 +93,40 @@ impl Builder for RocksdbBuilder {
             Error::new(ErrorKind::ConfigInvalid, "datadir is required but not set")
                 .with_context("service", Scheme::Rocksdb)
         })?;
-        let db = DB::open_default(&path).map_err(|e| {
-            Error::new(ErrorKind::ConfigInvalid, "open default transaction db")
+        let column_family = self.column_family.take();
+
+        let db = match &column_family {
+            None => DB::open_default(&path),
+            Some(cf) => {
+                let mut opts = Options::default();
+                opts.create_if_missing(true);
+                opts.create_missing_column_families(true);
+
+                // All existing column families must be opened, so we need to
+                // list them first. Listing fails if the db doesn't exist yet.
+                let mut cfs = DB::list_cf(&opts, &path)
+                    .unwrap_or_else(|_| vec![rocksdb::DEFAULT_COLUMN_FAMILY_NAME.to_string()]);
+                if !cfs.contains(cf) {
+                    cfs.push(cf.clone());
+                }
+
+                DB::open_cf(&opts, &path, cfs)
+            }
+        }
+        .map_err(|e| {
+            let mut err = Error::new(ErrorKind::ConfigInvalid, "open default transaction db")
                 .with_context("service", Scheme::Rocksdb)
-                .with_context("datadir", path)
-                .set_source(e)
+                .with_context("datadir", &path);
+            if let Some(cf) = &column_family {
+                err = err.with_context("column_family", cf);
+            }
+            err.set_source(e)
         })?;
 
-        Ok(RocksdbBackend::new(Adapter { db: Arc::new(db) }))
+        Ok(RocksdbBackend::new(Adapter {
+            db: Arc::new(db),
+            column_family,
+        }))
     }
 }
 
@@ -91,12 +136,30 @@ pub type RocksdbBackend = kv::Backend<Adapter>;
 #[derive(Clone)]
 pub struct Adapter {
     db: Arc<DB>,
+    column_family: Option<String>,
+}
+
+impl Adapter {
+    /// Get the column family handle used by this adapter.
+    ///
+    /// Returns `None` if the default column family is used.
+    fn cf(&self) -> Result<Option<&ColumnFamily>> {
+        match &self.column_family {
+            None => Ok(None),
+            Some(name) => self.db.cf_handle(name).map(Some).ok_or_else(|| {
+                Error::new(ErrorKind::Unexpected, "column family not found")
+                    .with_context("service", Scheme::Rocksdb)
+                    .with_context("column_family", name)
+            }),
+        }
+    }
 }
 
 impl Debug for Adapter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut ds = f.debug_struct("Adapter");
         ds.field("path", &self.db.path());
+        ds.field("column_family", &self.column_family);
         ds.finish()
     }
 }
@@ -122,7 +185,10 @@ impl kv::Adapter for Adapter {
     }
 
     fn blocking_get(&self, path: &str) -> Result<Option<Vec<u8>>> {
-        Ok(self.db.get(path)?)
+        match self.cf()? {
+            Some(cf) => Ok(self.db.get_cf(cf, path)?),
+            None => Ok(self.db.get(path)?),
+        }
     }
 
     async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
@@ -130,7 +196,10 @@ impl kv::Adapter for Adapter {
     }
 
     fn blocking_set(&self, path: &str, value: &[u8]) -> Result<()> {
-        Ok(self.db.put(path, value)?)
+        match self.cf()? {
+            Some(cf) => Ok(self.db.put_cf(cf, path, value)?),
+            None => Ok(self.db.put(path, value)?),
+        }
     }
 
     async fn delete(&self, path: &str) -> Result<()> {
@@ -138,7 +207,10 @@ impl kv::Adapter for Adapter {
     }
 
     fn blocking_delete(&self, path: &str) -> Result<()> {
-        Ok(self.db.delete(path)?)
+        match self.cf()? {
+            Some(cf) => Ok(self.db.delete_cf(cf, path)?),
+            None => Ok(self.db.delete(path)?),
+        }
     }
 
     async fn scan(&self, path: &str) -> Result<Vec<String>> {
@@ -147,7 +219,11 @@ impl kv::Adapter for Adapter {
 
     /// TODO: we only need key here.
     fn blocking_scan(&self, path: &str) -> Result<Vec<String>> {
-        let it = self.db.prefix_iterator(path).map(|r| r.map(|(k, _)| k));
+        let it = match self.cf()? {
+            Some(cf) => self.db.prefix_iterator_cf(cf, path),
+            None => self.db.prefix_iterator(path),
+        }
+        .map(|r| r.map(|(k, _)| k));
         let mut res = Vec::default();
 
         for key in it {
@@ -169,3 +245,47 @@ impl From<rocksdb::Error> for Error {
         Error::new(ErrorKind::Unexpected, "got rocksdb error").set_source(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_family_isolation() -> Result<()> {
+        let datadir =
+            std::env::temp_dir().join(format!("opendal-rocksdb-{}", uuid::Uuid::new_v4()));
+        let datadir = datadir.to_string_lossy().to_string();
+
+        let new_op = |cf: &str| -> Result<BlockingOperator> {
+            let mut builder = RocksdbBuilder::default();
+            builder.datadir(&datadir).column_family(cf);
+            Ok(Operator::new(builder)?.finish().blocking())
+        };
+
+        // rocksdb holds a lock on datadir, open one column family at a time.
+        {
+            let op = new_op("cf_a")?;
+            op.write("dir/a", "a")?;
+        }
+        {
+            let op = new_op("cf_b")?;
+            op.write("dir/b", "b")?;
+            assert_eq!(op.read("dir/b")?, b"b");
+            assert!(!op.is_exist("dir/a")?);
+
+            let entries: Vec<String> = op
+                .scan("dir/")?
+                .map(|e| e.map(|e| e.path().to_string()))
+                .collect::<Result<_>>()?;
+            assert_eq!(entries, vec!["dir/b".to_string()]);
+        }
+        {
+            let op = new_op("cf_a")?;
+            assert_eq!(op.read("dir/a")?, b"a");
+            assert!(!op.is_exist("dir/b")?);
+        }
+
+        let _ = std::fs::remove_dir_all(&datadir);
+        Ok(())
+    }
+}
@@ -37,10 +37,15 @@ use reqsign::AwsCredentialLoad;
 use reqsign::AwsDefaultLoader;
 use reqsign::AwsV4Signer;
 
+use super::checksum::parse_content_md5;
+use super::checksum::S3Checksum;
+use super::checksum::Verifier;
 use super::core::*;
+use super::credential::CachedCredentialLoader;
 use super::error::parse_error;
 use super::error::parse_s3_error_code;
 use super::pager::S3Pager;
+use super::reader::S3Reader;
 use super::writer::S3Writer;
 use crate::raw::*;
 use crate::*;
@@ -59,6 +64,42 @@ static ENDPOINT_TEMPLATES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new
 const DEFAULT_WRITE_MIN_SIZE: usize = 8 * 1024 * 1024;
 const DEFAULT_BATCH_MAX_OPERATIONS: usize = 1000;
 
+/// Check if the host of given endpoint is an AWS S3 domain.
+fn is_aws_endpoint(endpoint: &str) -> bool {
+    let host = endpoint
+        .split_once("://")
+        .map_or(endpoint, |(_, v)| v)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    // Trim the port, IPv6 addresses like `[::1]:9000` are not AWS domains
+    // anyway.
+    let host = host.rsplit_once(':').map_or(host, |(v, _)| v);
+
+    host.ends_with(".amazonaws.com") || host.ends_with(".amazonaws.com.cn")
+}
+
+/// S3RequesterPays overrides whether the requester pays for a single read
+/// or stat operation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::S3RequesterPays;
+/// use opendal::Operator;
+///
+/// async fn test(op: Operator) -> Result<()> {
+///     let _ = op
+///         .read_with("path/to/file")
+///         .extension(S3RequesterPays(true))
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct S3RequesterPays(pub bool);
+
 /// Aws S3 and compatible services (including minio, digitalocean space, Tencent Cloud Object Storage(COS) and so on) support.
 /// For more information about s3-compatible services, refer to [Compatible Services](#compatible-services).
 #[doc = include_str!("docs.md")]
@@ -80,6 +121,8 @@ pub struct S3Builder {
     disable_config_load: bool,
     disable_ec2_metadata: bool,
     allow_anonymous: bool,
+    anonymous: bool,
+    requester_pays: bool,
     customed_credential_load: Option<Box<dyn AwsCredentialLoad>>,
 
     // S3 features flags
@@ -88,16 +131,22 @@ pub struct S3Builder {
     server_side_encryption_customer_algorithm: Option<String>,
     server_side_encryption_customer_key: Option<String>,
     server_side_encryption_customer_key_md5: Option<String>,
+    server_side_encryption_bucket_key_enabled: bool,
     default_storage_class: Option<String>,
+    checksum_algorithm: Option<String>,
     enable_virtual_host_style: bool,
+    auto_detect_virtual_host_style: bool,
 
     /// the part size of s3 multipart upload, which should be 5 MiB to 5 GiB.
     /// There is no minimum size limit on the last part of your multipart upload
     write_min_size: Option<usize>,
+    /// the max size of data that could be buffered in memory while writing.
+    write_memory_budget: Option<usize>,
     /// batch_max_operations
     batch_max_operations: Option<usize>,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for S3Builder {
@@ -233,6 +282,20 @@ impl S3Builder {
         self
     }
 
+    /// Set default checksum algorithm for this backend.
+    ///
+    /// If set, the checksum of every write will be calculated and sent
+    /// along, which could be overwritten by `checksum_algorithm` of write.
+    ///
+    /// Available values: `crc32c`, `sha256`.
+    pub fn checksum_algorithm(&mut self, v: &str) -> &mut Self {
+        if !v.is_empty() {
+            self.checksum_algorithm = Some(v.to_string())
+        }
+
+        self
+    }
+
     /// Set server_side_encryption for this backend.
     ///
     /// Available values: `AES256`, `aws:kms`.
@@ -386,6 +449,17 @@ impl S3Builder {
         self
     }
 
+    /// Enable S3 Bucket Key for SSE-KMS so that S3 will use a bucket-level
+    /// key to reduce the requests to AWS KMS.
+    ///
+    /// - If `server_side_encryption` is not `aws:kms`, setting this is a noop.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/bucket-key.html>
+    pub fn server_side_encryption_bucket_key_enabled(&mut self) -> &mut Self {
+        self.server_side_encryption_bucket_key_enabled = true;
+        self
+    }
+
     /// Set temporary credential used in AWS S3 connections
     ///
     /// # Warning
@@ -426,6 +500,27 @@ impl S3Builder {
         self
     }
 
+    /// Enable anonymous mode to read public buckets.
+    ///
+    /// Different from `allow_anonymous`, requests will never be signed even
+    /// if credential is available. Only read, stat and list are supported
+    /// in this mode, other operations will return `Unsupported`.
+    pub fn anonymous(&mut self) -> &mut Self {
+        self.anonymous = true;
+        self
+    }
+
+    /// Enable requester pays so that opendal will send `x-amz-request-payer`
+    /// header while reading, stating and listing, which is required by
+    /// [requester pays buckets](https://docs.aws.amazon.com/AmazonS3/latest/userguide/RequesterPaysBuckets.html).
+    ///
+    /// It could be overridden for a single read or stat operation by
+    /// attaching [`S3RequesterPays`] as extension.
+    pub fn enable_requester_pays(&mut self) -> &mut Self {
+        self.requester_pays = true;
+        self
+    }
+
     /// Enable virtual host style so that opendal will send API requests
     /// in virtual host style instead of path style.
     ///
@@ -436,10 +531,38 @@ impl S3Builder {
         self
     }
 
+    /// Detect whether to use virtual host style by endpoint automatically.
+    ///
+    /// Virtual host style will be used only if:
+    ///
+    /// - The host of endpoint is an AWS S3 domain, a.k.a, ends with
+    ///   `.amazonaws.com` or `.amazonaws.com.cn`.
+    /// - The bucket name doesn't contain dot(.) character.
+    ///
+    /// Otherwise, like IP addresses, `localhost` and hosts of MinIO or other
+    /// gateways, path style will be used.
+    ///
+    /// `enable_virtual_host_style` takes precedence over this option.
+    pub fn auto_detect_virtual_host_style(&mut self) -> &mut Self {
+        self.auto_detect_virtual_host_style = true;
+        self
+    }
+
     /// Adding a customed credential load for service.
     ///
     /// If customed_credential_load has been set, we will ignore all other
     /// credential load methods.
+    ///
+    /// Credentials returned by the loader will be cached until they are
+    /// going to expire in 2 minutes, so loaders that talk to STS or other
+    /// token services don't need to cache by themselves.
+    ///
+    /// Built-in loaders like [`S3WebIdentityCredentialLoader`] and
+    /// [`S3Imdsv2CredentialLoader`] can be combined by [`S3CredentialChain`].
+    ///
+    /// [`S3WebIdentityCredentialLoader`]: crate::services::S3WebIdentityCredentialLoader
+    /// [`S3Imdsv2CredentialLoader`]: crate::services::S3Imdsv2CredentialLoader
+    /// [`S3CredentialChain`]: crate::services::S3CredentialChain
     pub fn customed_credential_load(&mut self, cred: Box<dyn AwsCredentialLoad>) -> &mut Self {
         self.customed_credential_load = Some(cred);
         self
@@ -456,6 +579,17 @@ impl S3Builder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// Check if `bucket` is valid
     /// `bucket` must be not empty and if `enable_virtual_host_style` is true
     /// it couldn't contain dot(.) character
@@ -505,7 +639,11 @@ impl S3Builder {
         };
 
         // Apply virtual host style.
-        if self.enable_virtual_host_style {
+        let virtual_host_style = self.enable_virtual_host_style
+            || (self.auto_detect_virtual_host_style
+                && !bucket.contains('.')
+                && is_aws_endpoint(&endpoint));
+        if virtual_host_style {
             endpoint = endpoint.replace("//", &format!("//{bucket}."))
         } else {
             write!(endpoint, "/{bucket}").expect("write into string must succeed");
@@ -521,6 +659,22 @@ impl S3Builder {
 
         self
     }
+
+    /// Set the max size of data that could be buffered in memory while
+    /// writing.
+    ///
+    /// - Data with known size that is no larger than the budget will be
+    ///   uploaded in one request instead of multipart upload.
+    /// - Parts will be as large as the budget allows to reduce requests,
+    ///   but never smaller than `write_min_size`.
+    ///
+    /// The size of parts could still exceed the budget to keep the number
+    /// of parts no more than 10000 for large data.
+    pub fn write_memory_budget(&mut self, write_memory_budget: usize) -> &mut Self {
+        self.write_memory_budget = Some(write_memory_budget);
+
+        self
+    }
     /// Set maximum batch operations of this backend.
     pub fn batch_max_operations(&mut self, batch_max_operations: usize) -> &mut Self {
         self.batch_max_operations = Some(batch_max_operations);
@@ -666,25 +820,44 @@ impl Builder for S3Builder {
             .map(|v| builder.server_side_encryption_customer_key(v));
         map.get("server_side_encryption_customer_key_md5")
             .map(|v| builder.server_side_encryption_customer_key_md5(v));
+        map.get("server_side_encryption_bucket_key_enabled")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.server_side_encryption_bucket_key_enabled());
         map.get("disable_config_load")
             .filter(|v| *v == "on" || *v == "true")
             .map(|_| builder.disable_config_load());
         map.get("disable_ec2_metadata")
             .filter(|v| *v == "on" || *v == "true")
             .map(|_| builder.disable_ec2_metadata());
+        map.get("auto_detect_virtual_host_style")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.auto_detect_virtual_host_style());
         map.get("enable_virtual_host_style")
             .filter(|v| *v == "on" || *v == "true")
             .map(|_| builder.enable_virtual_host_style());
         map.get("allow_anonymous")
             .filter(|v| *v == "on" || *v == "true")
             .map(|_| builder.allow_anonymous());
+        map.get("anonymous")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.anonymous());
+        map.get("enable_requester_pays")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_requester_pays());
         map.get("default_storage_class")
             .map(|v: &String| builder.default_storage_class(v));
+        map.get("checksum_algorithm")
+            .map(|v: &String| builder.checksum_algorithm(v));
         map.get("write_min_size")
             .map(|v| builder.write_min_size(v.parse().expect("input must be a number")));
+        map.get("write_memory_budget")
+            .map(|v| builder.write_memory_budget(v.parse().expect("input must be a number")));
         map.get("batch_max_operations")
             .map(|v| builder.batch_max_operations(v.parse().expect("input must be a number")));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -712,6 +885,16 @@ impl Builder for S3Builder {
             ),
         };
 
+        let checksum_algorithm = match &self.checksum_algorithm {
+            None => None,
+            Some(v) => Some(v.parse::<ChecksumAlgorithm>().map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "checksum_algorithm is invalid")
+                    .with_context("service", Scheme::S3)
+                    .with_context("checksum_algorithm", v)
+                    .set_source(err)
+            })?),
+        };
+
         let server_side_encryption = match &self.server_side_encryption {
             None => None,
             Some(v) => Some(
@@ -752,7 +935,7 @@ impl Builder for S3Builder {
                 })?),
             };
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -760,6 +943,12 @@ impl Builder for S3Builder {
                     .with_context("service", Scheme::S3)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::S3)
+            })?;
+        }
 
         // This is our current config.
         let mut cfg = AwsConfig::default();
@@ -844,6 +1033,8 @@ impl Builder for S3Builder {
                 Box::new(default_loader)
             }
         };
+        // Cache credentials until they are near expiry.
+        let loader = Box::new(CachedCredentialLoader::new(loader));
 
         let signer = AwsV4Signer::new("s3", &region);
 
@@ -869,12 +1060,18 @@ impl Builder for S3Builder {
                 server_side_encryption_customer_algorithm,
                 server_side_encryption_customer_key,
                 server_side_encryption_customer_key_md5,
+                server_side_encryption_bucket_key_enabled: self
+                    .server_side_encryption_bucket_key_enabled,
                 default_storage_class,
+                checksum_algorithm,
                 allow_anonymous: self.allow_anonymous,
+                anonymous: self.anonymous,
+                requester_pays: self.requester_pays,
                 signer,
                 loader,
                 client,
                 write_min_size,
+                write_memory_budget: self.write_memory_budget,
                 batch_max_operations,
             }),
         })
@@ -887,9 +1084,116 @@ pub struct S3Backend {
     core: Arc<S3Core>,
 }
 
+impl S3Backend {
+    /// Copy given range of `from` into `to` by a multipart upload with only
+    /// one part uploaded via `UploadPartCopy`.
+    ///
+    /// The only part is also the last part, so it's not limited by the min
+    /// part size, but it can't be larger than 5 GiB.
+    async fn copy_range(&self, from: &str, to: &str, args: &OpCopy) -> Result<()> {
+        let range = args.range();
+        let extensions = args.extensions();
+        // `x-amz-copy-source-range` only accepts closed range, so we need to
+        // resolve open ranges with the size of source.
+        let (offset, size) = match (range.offset(), range.size()) {
+            (Some(offset), Some(size)) => (offset, size),
+            (offset, size) => {
+                let total = self
+                    .stat(from, OpStat::new().with_extensions(extensions.clone()))
+                    .await?
+                    .into_metadata()
+                    .content_length();
+                match (offset, size) {
+                    (Some(offset), _) => (offset, total.saturating_sub(offset)),
+                    (None, Some(size)) => (total.saturating_sub(size), size.min(total)),
+                    (None, None) => (0, total),
+                }
+            }
+        };
+        if size == 0 {
+            return Err(
+                Error::new(ErrorKind::InvalidInput, "copy range must not be empty")
+                    .with_context("range", range.to_string()),
+            );
+        }
+
+        let resp = self
+            .core
+            .s3_initiate_multipart_upload(to, None, None, None, None, None, extensions)
+            .await?;
+        let upload_id = match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+                result.upload_id
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        let res = self
+            .copy_range_part(
+                from,
+                to,
+                &upload_id,
+                BytesRange::new(Some(offset), Some(size)),
+                extensions,
+            )
+            .await;
+        if res.is_err() {
+            // Abort the upload so that the copied part will not be charged.
+            let _ = self
+                .core
+                .s3_abort_multipart_upload(to, &upload_id, extensions)
+                .await;
+        }
+        res
+    }
+
+    async fn copy_range_part(
+        &self,
+        from: &str,
+        to: &str,
+        upload_id: &str,
+        range: BytesRange,
+        extensions: &Extensions,
+    ) -> Result<()> {
+        let resp = self
+            .core
+            .s3_upload_part_copy(from, to, upload_id, 1, range, extensions)
+            .await?;
+        let etag = match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                let result: CopyPartResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+                result.etag
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        let part = CompleteMultipartUploadRequestPart {
+            part_number: 1,
+            etag,
+            ..Default::default()
+        };
+        let resp = self
+            .core
+            .s3_complete_multipart_upload(to, upload_id, vec![part], extensions)
+            .await?;
+        match resp.status() {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
 #[async_trait]
 impl Accessor for S3Backend {
-    type Reader = IncomingAsyncBody;
+    type Reader = S3Reader;
     type BlockingReader = ();
     type Writer = oio::MultipartUploadWriter<S3Writer>;
     type BlockingWriter = ();
@@ -898,57 +1202,100 @@ impl Accessor for S3Backend {
     type BlockingPager = ();
 
     fn info(&self) -> AccessorInfo {
+        let mut cap = Capability {
+            stat: true,
+            stat_with_if_match: true,
+            stat_with_if_none_match: true,
+
+            read: true,
+            read_can_next: true,
+            read_with_range: true,
+            read_with_if_match: true,
+            read_with_if_none_match: true,
+            read_with_if_range: true,
+            read_with_override_cache_control: true,
+            read_with_override_content_disposition: true,
+            read_with_override_content_type: true,
+            read_with_verify: true,
+
+            write: true,
+            write_can_sink: true,
+            write_can_multi: true,
+            write_with_cache_control: true,
+            write_with_content_md5: true,
+            write_with_checksum_algorithm: true,
+            write_with_object_lock: true,
+            write_with_content_type: true,
+            write_without_content_length: true,
+            write_multi_min_size: Some(5 * 1024 * 1024),
+            write_multi_max_size: Some(5 * 1024 * 1024 * 1024),
+            write_total_max_size: Some(5 * 1024 * 1024 * 1024 * 1024),
+            create_dir: true,
+            delete: true,
+            copy: true,
+            copy_with_range: true,
+
+            list: true,
+            list_with_limit: true,
+            list_max_limit: Some(1000),
+            list_with_start_after: true,
+            list_without_delimiter: true,
+            list_with_delimiter_slash: true,
+            list_with_delimiter_custom: true,
+            list_with_continuation: true,
+
+            presign: true,
+            presign_stat: true,
+            presign_read: true,
+            presign_write: true,
+
+            batch: true,
+            batch_max_operations: Some(self.core.batch_max_operations),
+
+            ..Default::default()
+        };
+        // Requests can't be signed in anonymous mode, only read operations
+        // against public buckets are allowed.
+        if self.core.anonymous {
+            cap.write = false;
+            cap.write_can_sink = false;
+            cap.write_can_multi = false;
+            cap.create_dir = false;
+            cap.delete = false;
+            cap.copy = false;
+            cap.copy_with_range = false;
+            cap.presign = false;
+            cap.presign_stat = false;
+            cap.presign_read = false;
+            cap.presign_write = false;
+            cap.batch = false;
+            cap.batch_max_operations = None;
+        }
+
         let mut am = AccessorInfo::default();
         am.set_scheme(Scheme::S3)
             .set_root(&self.core.root)
             .set_name(&self.core.bucket)
-            .set_capability(Capability {
-                stat: true,
-                stat_with_if_match: true,
-                stat_with_if_none_match: true,
-
-                read: true,
-                read_can_next: true,
-                read_with_range: true,
-                read_with_if_match: true,
-                read_with_if_none_match: true,
-                read_with_override_cache_control: true,
-                read_with_override_content_disposition: true,
-                read_with_override_content_type: true,
-
-                write: true,
-                write_can_sink: true,
-                write_with_cache_control: true,
-                write_with_content_type: true,
-                write_without_content_length: true,
-                create_dir: true,
-                delete: true,
-                copy: true,
-
-                list: true,
-                list_with_limit: true,
-                list_with_start_after: true,
-                list_without_delimiter: true,
-                list_with_delimiter_slash: true,
-
-                presign: true,
-                presign_stat: true,
-                presign_read: true,
-                presign_write: true,
-
-                batch: true,
-                batch_max_operations: Some(self.core.batch_max_operations),
-
-                ..Default::default()
-            });
+            .set_capability(cap);
+        if let Some(algorithm) = self.core.checksum_algorithm {
+            am.set_checksum_algorithm(algorithm);
+        }
 
         am
     }
 
     async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
-        let mut req =
-            self.core
-                .s3_put_object_request(path, Some(0), None, None, None, AsyncBody::Empty)?;
+        let mut req = self.core.s3_put_object_request(
+            path,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            AsyncBody::Empty,
+        )?;
 
         self.core.sign(&mut req).await?;
 
@@ -966,14 +1313,43 @@ impl Accessor for S3Backend {
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let is_full = args.range().is_full();
+        let verify = args.verify();
+        if verify && !is_full {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "verify is not supported for range read",
+            )
+            .with_operation(Operation::Read)
+            .with_context("service", Scheme::S3)
+            .with_context("path", path));
+        }
+
         let resp = self.core.s3_get_object(path, args).await?;
 
         let status = resp.status();
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                let meta = parse_into_metadata(path, resp.headers())?;
-                Ok((RpRead::with_metadata(meta), resp.into_body()))
+                let meta = parse_s3_metadata(path, resp.headers())?;
+                // Only the content of full object can be verified.
+                //
+                // Additional checksum is always verified if returned, and
+                // md5 will be used as fallback if users ask to verify.
+                let verifier = if !is_full {
+                    None
+                } else if let Some(checksum) = S3Checksum::from_headers(resp.headers()) {
+                    Some(Verifier::from_checksum(checksum))
+                } else if verify {
+                    parse_content_md5(resp.headers()).map(Verifier::from_md5)
+                } else {
+                    None
+                };
+                let size = parse_content_length(resp.headers())?;
+                Ok((
+                    RpRead::with_metadata(meta),
+                    S3Reader::new(resp.into_body(), verifier, size),
+                ))
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -986,8 +1362,24 @@ impl Accessor for S3Backend {
         ))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self.core.s3_copy_object(from, to).await?;
+    async fn multipart(
+        &self,
+        path: &str,
+        args: OpWrite,
+    ) -> Result<(RpWrite, oio::MultipartUploader)> {
+        Ok((
+            RpWrite::default(),
+            Box::new(S3Writer::uploader(self.core.clone(), path, args)),
+        ))
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        if !args.range().is_full() {
+            self.copy_range(from, to, &args).await?;
+            return Ok(RpCopy::default());
+        }
+
+        let resp = self.core.s3_copy_object(from, to, &args).await?;
 
         let status = resp.status();
 
@@ -1009,15 +1401,12 @@ impl Accessor for S3Backend {
             return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
         }
 
-        let resp = self
-            .core
-            .s3_head_object(path, args.if_none_match(), args.if_match())
-            .await?;
+        let resp = self.core.s3_head_object(path, &args).await?;
 
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => parse_s3_metadata(path, resp.headers()).map(RpStat::new),
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -1025,8 +1414,8 @@ impl Accessor for S3Backend {
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
-        let resp = self.core.s3_delete_object(path).await?;
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let resp = self.core.s3_delete_object(path, &args).await?;
 
         let status = resp.status();
 
@@ -1045,6 +1434,8 @@ impl Accessor for S3Backend {
                 args.delimiter(),
                 args.limit(),
                 args.start_after(),
+                args.continuation(),
+                args.extensions().clone(),
             ),
         ))
     }
@@ -1052,18 +1443,29 @@ impl Accessor for S3Backend {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
-            PresignOperation::Stat(v) => {
-                self.core
-                    .s3_head_object_request(path, v.if_none_match(), v.if_match())?
-            }
+            PresignOperation::Stat(v) => self.core.s3_head_object_request(path, v)?,
             PresignOperation::Read(v) => self.core.s3_get_object_request(path, v.clone())?,
-            PresignOperation::Write(_) => {
-                self.core
-                    .s3_put_object_request(path, None, None, None, None, AsyncBody::Empty)?
-            }
+            PresignOperation::Write(_) => self.core.s3_put_object_request(
+                path,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                AsyncBody::Empty,
+            )?,
         };
 
-        self.core.sign_query(&mut req, args.expire()).await?;
+        let extensions = match args.operation() {
+            PresignOperation::Stat(v) => v.extensions(),
+            PresignOperation::Read(v) => v.extensions(),
+            PresignOperation::Write(v) => v.extensions(),
+        };
+        self.core
+            .sign_query(&mut req, args.expire(), extensions)
+            .await?;
 
         // We don't need this request anymore, consume it directly.
         let (parts, _) = req.into_parts();
@@ -1085,7 +1487,17 @@ impl Accessor for S3Backend {
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths = ops.into_iter().map(|(p, _)| p).collect();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => Ok(p),
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "s3 services don't support batch write",
+                )
+                .with_context("path", p)),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let resp = self.core.s3_delete_objects(paths).await?;
 
@@ -1125,7 +1537,35 @@ impl Accessor for S3Backend {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use chrono::DateTime;
+    use chrono::Utc;
+    use futures::AsyncReadExt;
+    use futures::StreamExt;
+    use futures::TryStreamExt;
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::path_regex;
+    use wiremock::matchers::query_param;
+    use wiremock::matchers::query_param_is_missing;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
     use super::*;
+    use crate::services::s3::checksum::Checksummer;
+    use crate::services::S3Credential;
 
     #[test]
     fn test_is_valid_bucket() {
@@ -1183,6 +1623,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_operator_info_limits() {
+        let mut b = S3Builder::default();
+        b.bucket("test").region("us-east-1");
+        let op = Operator::new(b).unwrap().finish();
+        let info = op.info();
+
+        assert!(info.can_presign());
+        assert!(info.can_presign_read());
+        assert!(info.can_presign_stat());
+        assert!(info.can_presign_write());
+        assert_eq!(info.batch_max_operations(), Some(1000));
+        assert_eq!(info.write_multi_min_size(), Some(5 * 1024 * 1024));
+        assert_eq!(info.write_multi_max_size(), Some(5 * 1024 * 1024 * 1024));
+        assert_eq!(
+            info.write_total_max_size(),
+            Some(5 * 1024 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(info.list_max_limit(), Some(1000));
+    }
+
+    #[test]
+    fn test_build_endpoint_with_auto_detect_virtual_host_style() {
+        let cases = vec![
+            (
+                "aws endpoint",
+                "https://s3.amazonaws.com",
+                "test",
+                "https://test.s3.us-east-2.amazonaws.com",
+            ),
+            (
+                "aws cn endpoint",
+                "https://s3.cn-north-1.amazonaws.com.cn",
+                "test",
+                "https://test.s3.cn-north-1.amazonaws.com.cn",
+            ),
+            (
+                "bucket with dot",
+                "https://s3.amazonaws.com",
+                "test.xyz",
+                "https://s3.us-east-2.amazonaws.com/test.xyz",
+            ),
+            (
+                "ip endpoint",
+                "http://127.0.0.1:9000",
+                "test",
+                "http://127.0.0.1:9000/test",
+            ),
+            (
+                "ipv6 endpoint",
+                "http://[::1]:9000",
+                "test",
+                "http://[::1]:9000/test",
+            ),
+            (
+                "localhost endpoint",
+                "http://localhost:9000",
+                "test",
+                "http://localhost:9000/test",
+            ),
+            (
+                "gateway endpoint",
+                "https://minio.example.com",
+                "test",
+                "https://minio.example.com/test",
+            ),
+        ];
+
+        for (name, endpoint, bucket, expected) in cases {
+            let mut b = S3Builder::default();
+            b.bucket(bucket)
+                .endpoint(endpoint)
+                .auto_detect_virtual_host_style();
+            assert!(b.is_bucket_valid(), "{name}");
+            assert_eq!(b.build_endpoint("us-east-2"), expected, "{name}");
+        }
+
+        // Explicit virtual host style should be honored.
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint("http://127.0.0.1:9000")
+            .enable_virtual_host_style()
+            .auto_detect_virtual_host_style();
+        assert_eq!(b.build_endpoint("us-east-2"), "http://test.127.0.0.1:9000");
+    }
+
     #[tokio::test]
     async fn test_detect_region() {
         let cases = vec![
@@ -1229,4 +1755,1172 @@ mod tests {
             assert_eq!(region.as_deref(), expected, "{}", name);
         }
     }
+
+    fn mock_operator(endpoint: &str) -> Operator {
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(endpoint)
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .disable_config_load();
+
+        Operator::new(b).expect("must build").finish()
+    }
+
+    #[tokio::test]
+    async fn test_list_with_continuation() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .and(query_param("prefix", "dir/"))
+            .and(query_param_is_missing("continuation-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<ListBucketResult>
+  <IsTruncated>true</IsTruncated>
+  <NextContinuationToken>token/1=</NextContinuationToken>
+  <Contents><Key>dir/a</Key><Size>1</Size><ETag>"etag"</ETag><LastModified>2023-01-01T00:00:00.000Z</LastModified></Contents>
+  <Contents><Key>dir/b</Key><Size>1</Size><ETag>"etag"</ETag><LastModified>2023-01-01T00:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .and(query_param("prefix", "dir/"))
+            .and(query_param("continuation-token", "token/1="))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<ListBucketResult>
+  <IsTruncated>false</IsTruncated>
+  <Contents><Key>dir/c</Key><Size>1</Size><ETag>"etag"</ETag><LastModified>2023-01-01T00:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().list_with_continuation);
+
+        let mut lister = op.lister_with("dir/").limit(2).await?;
+        assert_eq!(lister.continuation_token(), None);
+        assert_eq!(lister.try_next().await?.unwrap().path(), "dir/a");
+        // Token is not available until the whole page has been returned.
+        assert_eq!(lister.continuation_token(), None);
+        assert_eq!(lister.try_next().await?.unwrap().path(), "dir/b");
+        let token = lister.continuation_token().expect("token must exist");
+        assert_eq!(token, "token/1=");
+        drop(lister);
+
+        let mut lister = op.lister_with("dir/").continuation(&token).await?;
+        assert_eq!(lister.try_next().await?.unwrap().path(), "dir/c");
+        assert!(lister.try_next().await?.is_none());
+        assert_eq!(lister.continuation_token(), None);
+
+        let entries = op.list_with("dir/").continuation(&token).await?;
+        assert_eq!(entries.len(), 1);
+
+        // Services without native continuation will reject the token.
+        let op = Operator::new(services::Memory::default())?.finish();
+        let err = op
+            .list_with("dir/")
+            .continuation(&token)
+            .await
+            .expect_err("list must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_with_custom_delimiter() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .and(query_param("prefix", "logs/"))
+            .and(query_param("delimiter", ":"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<ListBucketResult>
+  <IsTruncated>false</IsTruncated>
+  <CommonPrefixes><Prefix>logs/2023:</Prefix></CommonPrefixes>
+  <CommonPrefixes><Prefix>logs/2024:</Prefix></CommonPrefixes>
+  <Contents><Key>logs/latest</Key><Size>1</Size><ETag>"etag"</ETag><LastModified>2023-01-01T00:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().list_with_delimiter_custom);
+
+        let entries = op.list_with("logs/").delimiter(":").await?;
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|e| (e.path().to_string(), e.metadata().mode()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("logs/2023:".to_string(), EntryMode::DIR),
+                ("logs/2024:".to_string(), EntryMode::DIR),
+                ("logs/latest".to_string(), EntryMode::FILE),
+            ]
+        );
+
+        // Services only support `/` will reject other delimiters.
+        let op = Operator::new(services::Memory::default())?.finish();
+        let err = op
+            .list_with("logs/")
+            .delimiter(":")
+            .await
+            .expect_err("list must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_presign_read_with_override_content_type() -> Result<()> {
+        let op = mock_operator("http://127.0.0.1:9000");
+        let expire = Duration::from_secs(3600);
+
+        let presign = |content_type: &'static str| {
+            let op = op.clone();
+            async move {
+                let req = op
+                    .presign_read_with("file", expire)
+                    .override_content_type(content_type)
+                    .await?;
+                let url = reqwest::Url::parse(&req.uri().to_string()).expect("must be valid url");
+                let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+                Ok::<_, Error>(query)
+            }
+        };
+
+        // Presign the same request with different overrides at the same
+        // time, the signature must differ only if the override is signed.
+        let (png, html) = loop {
+            let png = presign("image/png").await?;
+            let html = presign("text/html").await?;
+            if png["X-Amz-Date"] == html["X-Amz-Date"] {
+                break (png, html);
+            }
+        };
+        assert_eq!(png["response-content-type"], "image/png");
+        assert_eq!(html["response-content-type"], "text/html");
+        assert_eq!(png["X-Amz-SignedHeaders"], html["X-Amz-SignedHeaders"]);
+        assert_ne!(png["X-Amz-Signature"], html["X-Amz-Signature"]);
+
+        // Signing is deterministic, the same override must produce the same
+        // signature.
+        let (first, second) = loop {
+            let first = presign("image/png").await?;
+            let second = presign("image/png").await?;
+            if first["X-Amz-Date"] == second["X-Amz-Date"] {
+                break (first, second);
+            }
+        };
+        assert_eq!(first["X-Amz-Signature"], second["X-Amz-Signature"]);
+        Ok(())
+    }
+
+    /// A fake public bucket which rejects all signed requests.
+    #[derive(Clone, Default)]
+    struct PublicBucket {
+        signed: Arc<AtomicUsize>,
+    }
+
+    impl Respond for PublicBucket {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let signed = req.headers.get(&"authorization".into()).is_some()
+                || req.url.query_pairs().any(|(k, _)| k == "X-Amz-Signature");
+            if signed {
+                self.signed.fetch_add(1, Ordering::SeqCst);
+                return ResponseTemplate::new(403).set_body_string(
+                    "<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>",
+                );
+            }
+
+            match (req.method.as_ref(), req.url.path()) {
+                ("GET", "/test/public") | ("HEAD", "/test/public") => ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .insert_header("etag", "\"etag\"")
+                    .set_body_string("Hello, World!"),
+                ("GET", "/test") => ResponseTemplate::new(200).set_body_string(
+                    r#"<ListBucketResult>
+  <IsTruncated>false</IsTruncated>
+  <Contents><Key>public</Key><Size>13</Size><ETag>"etag"</ETag><LastModified>2023-01-01T00:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+                ),
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_anonymous() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let bucket = PublicBucket::default();
+        Mock::given(path_regex("^/test"))
+            .respond_with(bucket.clone())
+            .mount(&mock_server)
+            .await;
+
+        // Credentials will be ignored in anonymous mode.
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .disable_config_load()
+            .anonymous();
+        let op = Operator::new(b)?.finish();
+
+        assert_eq!(op.read("public").await?, b"Hello, World!");
+        assert_eq!(op.stat("public").await?.content_length(), 13);
+        let entries = op.list("/").await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path(), "public");
+        assert_eq!(bucket.signed.load(Ordering::SeqCst), 0);
+
+        let cap = op.info().capability();
+        assert!(cap.read && cap.stat && cap.list);
+        assert!(!cap.write && !cap.delete && !cap.presign);
+        let err = op
+            .write("public", "Hello, World?")
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        let err = op.delete("public").await.expect_err("delete must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        // Signed requests will be rejected by public buckets.
+        let op = mock_operator(&mock_server.uri());
+        let err = op.read("public").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(bucket.signed.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    /// Object which has been overwritten with etag `"new"`.
+    struct ChangedObject;
+
+    impl Respond for ChangedObject {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let content = "Hello, World!";
+            let if_range = req
+                .headers
+                .get(&"if-range".into())
+                .map(|v| v.as_str().to_string());
+            match if_range.as_deref() {
+                Some("\"new\"") => ResponseTemplate::new(206)
+                    .insert_header("content-length", "8")
+                    .insert_header("content-range", "bytes 5-12/13")
+                    .insert_header("etag", "\"new\"")
+                    .set_body_string(&content[5..]),
+                _ => ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .insert_header("etag", "\"new\"")
+                    .set_body_string(content),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_if_range() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/test/file"))
+            .and(header("range", "bytes=5-"))
+            .respond_with(ChangedObject)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+
+        // The range will be returned if etag matches.
+        let bs = op.read_with("file").range(5..).if_range("\"new\"").await?;
+        assert_eq!(bs, b", World!");
+
+        // The whole content will be returned if object has been changed.
+        let bs = op.read_with("file").range(5..).if_range("\"old\"").await?;
+        assert_eq!(bs, b"Hello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rename() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/test/to"))
+            .and(header("x-amz-copy-source", "test/from"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/test/from"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(!op.info().capability().rename);
+        assert!(op.info().capability().rename_emulated);
+        assert!(!op.info().capability().rename_atomic);
+
+        op.rename("from", "to").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rename_rollback() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/test/to"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/test/from"))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/test/to"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let err = op.rename("from", "to").await.expect_err("rename must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    /// A fake s3 service which validates the content md5 of uploaded
+    /// objects and parts like AWS S3 does.
+    #[derive(Clone, Default)]
+    struct ContentMd5Checker {
+        /// The requests that carried a valid content md5.
+        checked: Arc<AtomicUsize>,
+    }
+
+    impl Respond for ContentMd5Checker {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let has_query = |k: &str| req.url.query_pairs().any(|(key, _)| key == k);
+
+            match req.method.as_ref() {
+                "POST" if has_query("uploads") => ResponseTemplate::new(200).set_body_string(
+                    "<InitiateMultipartUploadResult><UploadId>upload_id</UploadId></InitiateMultipartUploadResult>",
+                ),
+                "POST" if has_query("uploadId") => ResponseTemplate::new(200),
+                "PUT" => {
+                    if let Some(v) = req.headers.get(&"content-md5".into()) {
+                        if v.as_str() != format_content_md5(&req.body) {
+                            return ResponseTemplate::new(400).set_body_string(
+                                "<Error><Code>BadDigest</Code><Message>The Content-MD5 you specified did not match what we received.</Message></Error>",
+                            );
+                        }
+                        self.checked.fetch_add(1, Ordering::SeqCst);
+                    }
+                    ResponseTemplate::new(200).insert_header("etag", "\"etag\"")
+                }
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    async fn mock_content_md5_server() -> (MockServer, ContentMd5Checker) {
+        let mock_server = MockServer::start().await;
+        let checker = ContentMd5Checker::default();
+        Mock::given(path("/test/file"))
+            .respond_with(checker.clone())
+            .mount(&mock_server)
+            .await;
+
+        (mock_server, checker)
+    }
+
+    #[tokio::test]
+    async fn test_write_with_content_md5() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let (mock_server, checker) = mock_content_md5_server().await;
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().write_with_content_md5);
+
+        op.write_with("file", "Hello, World!")
+            .content_md5(&format_content_md5(b"Hello, World!"))
+            .await?;
+        op.write_with("file", "Hello, World!")
+            .auto_content_md5(true)
+            .await?;
+        assert_eq!(checker.checked.load(Ordering::SeqCst), 2);
+
+        // The content is corrupted, s3 will reject it with `BadDigest`.
+        let err = op
+            .write_with("file", "Hello, World?")
+            .content_md5(&format_content_md5(b"Hello, World!"))
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_writer_with_auto_content_md5() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let (mock_server, checker) = mock_content_md5_server().await;
+        let op = mock_operator(&mock_server.uri());
+
+        let mut w = op.writer_with("file").auto_content_md5(true).await?;
+        w.write("Hello, World!").await?;
+        w.close().await?;
+        // Every uploaded part should carry its own content md5.
+        assert_eq!(checker.checked.load(Ordering::SeqCst), 1);
+
+        // The content md5 of whole object can't be checked by multipart upload.
+        let mut w = op
+            .writer_with("file")
+            .content_md5(&format_content_md5(b"Hello, World!"))
+            .await?;
+        let err = w.write("Hello, World!").await.expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        Ok(())
+    }
+
+    /// A fake s3 service which validates the crc32c checksum of uploaded
+    /// objects and parts, and returns the checksum of object while reading.
+    #[derive(Clone, Default)]
+    struct Crc32cChecker {
+        objects: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        /// Whether to corrupt the content returned by `GetObject`.
+        corrupted: Arc<AtomicBool>,
+        /// The number of completed multipart uploads with part checksums.
+        completed: Arc<AtomicUsize>,
+    }
+
+    impl Respond for Crc32cChecker {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let header = |k: &str| req.headers.get(&k.into()).map(|v| v.as_str().to_string());
+            let has_query = |k: &str| req.url.query_pairs().any(|(key, _)| key == k);
+            let crc32c = |bs: &[u8]| {
+                let mut checksummer = Checksummer::new(ChecksumAlgorithm::Crc32c);
+                checksummer.update(bs);
+                checksummer.finish()
+            };
+            let key = req.url.path().to_string();
+
+            match req.method.as_ref() {
+                "POST" if has_query("uploads") => {
+                    assert_eq!(
+                        header("x-amz-checksum-algorithm").as_deref(),
+                        Some("CRC32C")
+                    );
+                    ResponseTemplate::new(200).set_body_string(
+                        "<InitiateMultipartUploadResult><UploadId>upload_id</UploadId></InitiateMultipartUploadResult>",
+                    )
+                }
+                "POST" if has_query("uploadId") => {
+                    let body = String::from_utf8_lossy(&req.body);
+                    assert!(body.contains("<ChecksumCRC32C>"), "{body}");
+                    self.completed.fetch_add(1, Ordering::SeqCst);
+                    ResponseTemplate::new(200)
+                }
+                "PUT" => {
+                    let checksum = crc32c(&req.body);
+                    if header("x-amz-checksum-crc32c").as_deref() != Some(checksum.as_str()) {
+                        return ResponseTemplate::new(400).set_body_string(
+                            "<Error><Code>BadDigest</Code><Message>The CRC32C you specified did not match the calculated checksum.</Message></Error>",
+                        );
+                    }
+                    if !has_query("partNumber") {
+                        self.objects.lock().unwrap().insert(key, req.body.clone());
+                    }
+                    ResponseTemplate::new(200)
+                        .insert_header("etag", "\"etag\"")
+                        .insert_header("x-amz-checksum-crc32c", checksum.as_str())
+                }
+                "GET" => {
+                    let Some(mut content) = self.objects.lock().unwrap().get(&key).cloned() else {
+                        return ResponseTemplate::new(404);
+                    };
+                    let mut resp = ResponseTemplate::new(200);
+                    if header("x-amz-checksum-mode").as_deref() == Some("ENABLED") {
+                        resp =
+                            resp.insert_header("x-amz-checksum-crc32c", crc32c(&content).as_str());
+                    }
+                    if self.corrupted.load(Ordering::SeqCst) {
+                        content[0] = !content[0];
+                    }
+                    resp.set_body_bytes(content)
+                }
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_with_checksum_algorithm() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let checker = Crc32cChecker::default();
+        Mock::given(path("/test/file"))
+            .respond_with(checker.clone())
+            .mount(&mock_server)
+            .await;
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().write_with_checksum_algorithm);
+
+        op.write_with("file", "Hello, World!")
+            .checksum_algorithm(ChecksumAlgorithm::Crc32c)
+            .await?;
+        let bs = op.read("file").await?;
+        assert_eq!(bs, b"Hello, World!");
+
+        // Corrupted content should be detected while reading.
+        checker.corrupted.store(true, Ordering::SeqCst);
+        let err = op.read("file").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+        // Checksum is not verified for range read.
+        op.range_read("file", 1..5).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_writer_with_checksum_algorithm() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let checker = Crc32cChecker::default();
+        Mock::given(path("/test/file"))
+            .respond_with(checker.clone())
+            .mount(&mock_server)
+            .await;
+
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .checksum_algorithm("crc32c")
+            .disable_config_load();
+        let op = Operator::new(b)?.finish();
+        assert_eq!(
+            op.info().checksum_algorithm(),
+            Some(ChecksumAlgorithm::Crc32c)
+        );
+
+        let mut w = op.writer("file").await?;
+        w.write("Hello, World!").await?;
+        w.close().await?;
+        assert_eq!(checker.completed.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    /// A fake s3 service which stores objects in memory and supports
+    /// copying range of objects via multipart upload.
+    #[derive(Clone, Default)]
+    struct RangeCopyService {
+        objects: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        parts: Arc<Mutex<BTreeMap<usize, Vec<u8>>>>,
+        copy_ranges: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Respond for RangeCopyService {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let query = |k: &str| {
+                req.url
+                    .query_pairs()
+                    .find(|(key, _)| key == k)
+                    .map(|(_, v)| v.to_string())
+            };
+            let key = req.url.path().trim_start_matches("/test/").to_string();
+
+            match req.method.as_ref() {
+                "GET" => match self.objects.lock().unwrap().get(&key) {
+                    Some(bs) => ResponseTemplate::new(200).set_body_bytes(bs.clone()),
+                    None => ResponseTemplate::new(404),
+                },
+                "POST" if query("uploads").is_some() => ResponseTemplate::new(200).set_body_string(
+                    "<InitiateMultipartUploadResult><UploadId>upload_id</UploadId></InitiateMultipartUploadResult>",
+                ),
+                "POST" if query("uploadId").is_some() => {
+                    let content = std::mem::take(&mut *self.parts.lock().unwrap())
+                        .into_values()
+                        .collect::<Vec<_>>()
+                        .concat();
+                    self.objects.lock().unwrap().insert(key, content);
+                    ResponseTemplate::new(200)
+                }
+                "PUT" => {
+                    let part_number: usize = query("partNumber").unwrap().parse().unwrap();
+                    let header = |k: &str| req.headers.get(&k.into()).unwrap().as_str().to_string();
+                    let source = header("x-amz-copy-source");
+                    let range = header("x-amz-copy-source-range");
+                    self.copy_ranges.lock().unwrap().push(range.clone());
+
+                    let (first, last) = range
+                        .trim_start_matches("bytes=")
+                        .split_once('-')
+                        .unwrap();
+                    let (first, last): (usize, usize) =
+                        (first.parse().unwrap(), last.parse().unwrap());
+                    let objects = self.objects.lock().unwrap();
+                    let src = &objects[source.trim_start_matches("test/")];
+                    self.parts
+                        .lock()
+                        .unwrap()
+                        .insert(part_number, src[first..=last].to_vec());
+                    ResponseTemplate::new(200).set_body_string(
+                        "<CopyPartResult><ETag>\"etag\"</ETag></CopyPartResult>",
+                    )
+                }
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_copy_with_range() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let service = RangeCopyService::default();
+        let content: Vec<u8> = (0..16 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+        service
+            .objects
+            .lock()
+            .unwrap()
+            .insert("src".to_string(), content.clone());
+        Mock::given(wiremock::matchers::any())
+            .respond_with(service.clone())
+            .mount(&mock_server)
+            .await;
+
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .disable_config_load();
+        let op = Operator::new(b)?.finish();
+
+        // Copy the middle 4 MiB which is smaller than the min part size.
+        let (start, end) = (6 * 1024 * 1024, 10 * 1024 * 1024);
+        op.copy_with("src", "dst").range(start..end).await?;
+
+        assert_eq!(
+            service.copy_ranges.lock().unwrap().as_slice(),
+            [format!("bytes={}-{}", start, end - 1)]
+        );
+        let dst = op.read("dst").await?;
+        assert_eq!(dst.len(), (end - start) as usize);
+        assert_eq!(dst, content[start as usize..end as usize]);
+        Ok(())
+    }
+
+    /// A fake s3 service which records the size of every uploaded part.
+    #[derive(Clone, Default)]
+    struct MultipartRecorder {
+        parts: Arc<Mutex<BTreeMap<usize, Vec<u8>>>>,
+        completed: Arc<AtomicBool>,
+    }
+
+    impl Respond for MultipartRecorder {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let query = |k: &str| {
+                req.url
+                    .query_pairs()
+                    .find(|(key, _)| key == k)
+                    .map(|(_, v)| v.to_string())
+            };
+
+            match req.method.as_ref() {
+                "POST" if query("uploads").is_some() => ResponseTemplate::new(200).set_body_string(
+                    "<InitiateMultipartUploadResult><UploadId>upload_id</UploadId></InitiateMultipartUploadResult>",
+                ),
+                "POST" if query("uploadId").is_some() => {
+                    self.completed.store(true, Ordering::SeqCst);
+                    ResponseTemplate::new(200)
+                }
+                "PUT" => match query("partNumber") {
+                    Some(part_number) => {
+                        self.parts
+                            .lock()
+                            .unwrap()
+                            .insert(part_number.parse().unwrap(), req.body.clone());
+                        ResponseTemplate::new(200).insert_header("etag", "\"etag\"")
+                    }
+                    // Unknown size stream must not be uploaded in one request.
+                    None => ResponseTemplate::new(400),
+                },
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_writer_with_chunked_unknown_size() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let recorder = MultipartRecorder::default();
+        Mock::given(path("/test/file"))
+            .respond_with(recorder.clone())
+            .mount(&mock_server)
+            .await;
+
+        let write_min_size = 5 * 1024 * 1024;
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .write_min_size(write_min_size)
+            .disable_config_load();
+        let op = Operator::new(b)?.finish();
+
+        // A stream of 12 MiB whose size is unknown ahead.
+        let chunk_size = 1024 * 1024;
+        let chunks: Vec<Vec<u8>> = (0..12u8).map(|i| vec![i; chunk_size]).collect();
+        let expected = chunks.concat();
+        let stream = futures::stream::iter(chunks).map(Ok::<_, Error>);
+
+        let mut w = op.writer_with("file").chunked_unknown_size().await?;
+        w.sink_unsized(stream).await?;
+        w.close().await?;
+        assert!(recorder.completed.load(Ordering::SeqCst));
+
+        let parts: Vec<Vec<u8>> = recorder.parts.lock().unwrap().values().cloned().collect();
+        assert!(parts.len() > 1, "stream must be uploaded by multipart");
+        for part in &parts[..parts.len() - 1] {
+            // Only one part (plus the last chunk) will be buffered.
+            assert!(part.len() >= write_min_size);
+            assert!(part.len() <= write_min_size + chunk_size);
+        }
+        assert_eq!(parts.concat(), expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_buffer() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        let recorder = MultipartRecorder::default();
+        Mock::given(path("/test/file"))
+            .respond_with(recorder.clone())
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let min_size = op.info().capability().write_multi_min_size.unwrap() as usize;
+
+        // A buffer of 12 chunks, each chunk is 1 MiB.
+        let chunk_size = 1024 * 1024;
+        let chunks: Vec<Bytes> = (0..12u8)
+            .map(|i| Bytes::from(vec![i; chunk_size]))
+            .collect();
+        let expected: Vec<u8> = chunks.concat();
+        let buf = Buffer::from(chunks);
+        assert_eq!(buf.chunk_count(), 12);
+
+        op.write_buffer("file", buf).await?;
+        assert!(recorder.completed.load(Ordering::SeqCst));
+
+        // Parts are split along chunk boundaries.
+        let parts: Vec<Vec<u8>> = recorder.parts.lock().unwrap().values().cloned().collect();
+        let sizes: Vec<usize> = parts.iter().map(|v| v.len()).collect();
+        assert_eq!(
+            sizes,
+            vec![min_size, min_size, 12 * chunk_size - 2 * min_size]
+        );
+        assert_eq!(parts.concat(), expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_verify() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        // md5 of `Hello, World!`.
+        Mock::given(path("/test/file"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"65a8e27d8879283831b664bd8b7f0ad4\"")
+                    .set_body_string("Hello, World!"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/test/corrupted"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"65a8e27d8879283831b664bd8b7f0ad4\"")
+                    .set_body_string("Hello, World?"),
+            )
+            .mount(&mock_server)
+            .await;
+        // ETag of multipart upload is not the md5 of content.
+        Mock::given(path("/test/multipart"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"65a8e27d8879283831b664bd8b7f0ad4-2\"")
+                    .set_body_string("Hello, World?"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().read_with_verify);
+
+        let bs = op.read_with("file").verify(true).await?;
+        assert_eq!(bs, b"Hello, World!");
+
+        let err = op
+            .read_with("corrupted")
+            .verify(true)
+            .await
+            .expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        let mut r = op.reader_with("corrupted").verify(true).await?;
+        let mut bs = vec![];
+        let err = r.read_to_end(&mut bs).await.expect_err("read must fail");
+        assert_eq!(
+            err.get_ref()
+                .and_then(|v| v.downcast_ref::<Error>())
+                .map(|v| v.kind()),
+            Some(ErrorKind::ChecksumMismatch)
+        );
+        // Content is not verified against md5 without verify.
+        assert_eq!(op.read("corrupted").await?, b"Hello, World?");
+
+        assert_eq!(
+            op.read_with("multipart").verify(true).await?,
+            b"Hello, World?"
+        );
+
+        let err = op
+            .read_with("file")
+            .range(0..5)
+            .verify(true)
+            .await
+            .expect_err("range read with verify must fail");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_requester_pays() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(path("/test/file"))
+            .and(header("x-amz-request-payer", "requester"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-amz-request-charged", "requester")
+                    .set_body_string("Hello, World!"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .and(header("x-amz-request-payer", "requester"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<ListBucketResult><IsTruncated>false</IsTruncated></ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        // Requests without `x-amz-request-payer` will be rejected.
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(403).set_body_string(
+                r#"<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let err = op.read("file").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let bs = op
+            .read_with("file")
+            .extension(S3RequesterPays(true))
+            .await?;
+        assert_eq!(bs, b"Hello, World!");
+
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .enable_requester_pays()
+            .disable_config_load();
+        let op = Operator::new(b)?.finish();
+
+        assert_eq!(op.read("file").await?, b"Hello, World!");
+        let meta = op.stat("file").await?;
+        assert_eq!(meta.content_length(), 13);
+        assert!(meta.request_charged());
+        op.list("dir/").await?;
+        let err = op
+            .stat_with("file")
+            .extension(S3RequesterPays(false))
+            .await
+            .expect_err("stat must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_credential_extension() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        // Only requests signed by the credential of tenant are accepted.
+        let signed_by_tenant = || {
+            Mock::given(|req: &Request| {
+                req.headers.get(&"authorization".into()).map_or(false, |v| {
+                    v.iter()
+                        .any(|v| v.as_str().contains("Credential=tenant_key/"))
+                })
+            })
+            .and(header("x-amz-security-token", "tenant_token"))
+        };
+        signed_by_tenant()
+            .and(path("/test/file"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("Hello, World!"))
+            .mount(&mock_server)
+            .await;
+        signed_by_tenant()
+            .and(path("/test/file"))
+            .and(method("HEAD"))
+            .respond_with(ResponseTemplate::new(200).insert_header("content-length", "13"))
+            .mount(&mock_server)
+            .await;
+        signed_by_tenant()
+            .and(path("/test/file"))
+            .and(method("DELETE"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+        signed_by_tenant()
+            .and(path("/test"))
+            .and(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<ListBucketResult><IsTruncated>false</IsTruncated></ListBucketResult>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(403).set_body_string(
+                r#"<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let cred =
+            S3Credential::new("tenant_key", "tenant_secret").with_session_token("tenant_token");
+
+        let err = op.read("file").await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let bs = op.read_with("file").extension(cred.clone()).await?;
+        assert_eq!(bs, b"Hello, World!");
+        let meta = op.stat_with("file").extension(cred.clone()).await?;
+        assert_eq!(meta.content_length(), 13);
+        op.list_with("dir/").extension(cred.clone()).await?;
+        op.delete_with("file").extension(cred).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_server_side_encryption_with_bucket_key() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/test/file"))
+            .and(header("x-amz-server-side-encryption", "aws:kms"))
+            .and(header(
+                "x-amz-server-side-encryption-aws-kms-key-id",
+                "key_id",
+            ))
+            .and(header(
+                "x-amz-server-side-encryption-bucket-key-enabled",
+                "true",
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/test/file"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .insert_header("x-amz-server-side-encryption", "aws:kms"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .server_side_encryption_with_customer_managed_kms_key("key_id")
+            .server_side_encryption_bucket_key_enabled()
+            .disable_config_load();
+        let op = Operator::new(b)?.finish();
+
+        op.write("file", "Hello, World!").await?;
+        let meta = op.stat("file").await?;
+        assert_eq!(meta.server_side_encryption(), Some("aws:kms"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_checksum_algorithm() {
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint("http://127.0.0.1")
+            .region("us-east-1")
+            .checksum_algorithm("md5");
+        let err = b.build().expect_err("build must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_write_with_object_lock() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/test/file"))
+            .and(header("x-amz-object-lock-mode", "GOVERNANCE"))
+            .and(header(
+                "x-amz-object-lock-retain-until-date",
+                "2030-01-01T00:00:00Z",
+            ))
+            // S3 requires content md5 for uploads with object lock.
+            .and(header(
+                "content-md5",
+                format_content_md5(b"Hello, World!").as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/test/file"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .insert_header("x-amz-object-lock-mode", "GOVERNANCE")
+                    .insert_header(
+                        "x-amz-object-lock-retain-until-date",
+                        "2030-01-01T00:00:00.000Z",
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        assert!(op.info().capability().write_with_object_lock);
+
+        let retain_until = DateTime::parse_from_rfc3339("2030-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        op.write_with("file", "Hello, World!")
+            .object_lock_mode(ObjectLockMode::Governance)
+            .retain_until(retain_until)
+            .await?;
+
+        let meta = op.stat("file").await?;
+        assert_eq!(meta.object_lock_mode(), Some(ObjectLockMode::Governance));
+        assert_eq!(meta.object_lock_retain_until(), Some(retain_until));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_with_object_lock_not_enabled() -> Result<()> {
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/test/file"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                "<Error><Code>InvalidRequest</Code><Message>Bucket is missing Object Lock Configuration</Message></Error>",
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri());
+        let err = op
+            .write_with("file", "Hello, World!")
+            .object_lock_mode(ObjectLockMode::Compliance)
+            .retain_until(Utc::now() + chrono::Duration::days(1))
+            .await
+            .expect_err("write must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_write_unsupported() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut b = S3Builder::default();
+        b.bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("access_key_id")
+            .secret_access_key("secret_access_key")
+            .disable_config_load();
+        let backend = b.build()?;
+
+        let res = backend
+            .batch(OpBatch::new(vec![
+                ("a".to_string(), OpDelete::new().into()),
+                (
+                    "b".to_string(),
+                    BatchOperation::Write(OpWrite::new(), Bytes::from("Hello")),
+                ),
+            ]))
+            .await;
+        assert_eq!(res.err().map(|v| v.kind()), Some(ErrorKind::Unsupported));
+        Ok(())
+    }
 }
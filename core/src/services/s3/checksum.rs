@@ -0,0 +1,315 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use http::header::ETAG;
+use http::HeaderMap;
+use md5::Md5;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::raw::*;
+use crate::*;
+
+/// All checksum algorithms that s3 supports, in the order of detecting.
+const CHECKSUM_ALGORITHMS: [ChecksumAlgorithm; 2] =
+    [ChecksumAlgorithm::Crc32c, ChecksumAlgorithm::Sha256];
+
+/// The header to carry the checksum calculated by given algorithm.
+///
+/// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html>
+pub fn checksum_header_name(algorithm: ChecksumAlgorithm) -> &'static str {
+    match algorithm {
+        ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+        ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+    }
+}
+
+/// The value of `x-amz-checksum-algorithm` for given algorithm.
+pub fn checksum_algorithm_value(algorithm: ChecksumAlgorithm) -> &'static str {
+    match algorithm {
+        ChecksumAlgorithm::Crc32c => "CRC32C",
+        ChecksumAlgorithm::Sha256 => "SHA256",
+    }
+}
+
+/// Checksummer calculates the checksum of content incrementally.
+pub enum Checksummer {
+    Crc32c(u32),
+    Sha256(Box<Sha256>),
+    Md5(Box<Md5>),
+}
+
+impl Checksummer {
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Crc32c => Checksummer::Crc32c(0),
+            ChecksumAlgorithm::Sha256 => Checksummer::Sha256(Box::default()),
+        }
+    }
+
+    pub fn update(&mut self, bs: &[u8]) {
+        match self {
+            Checksummer::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, bs),
+            Checksummer::Sha256(hasher) => hasher.update(bs),
+            Checksummer::Md5(hasher) => hasher.update(bs),
+        }
+    }
+
+    /// Finish the calculation and return the base64 encoded checksum.
+    pub fn finish(self) -> String {
+        match self {
+            Checksummer::Crc32c(crc) => general_purpose::STANDARD.encode(crc.to_be_bytes()),
+            Checksummer::Sha256(hasher) => general_purpose::STANDARD.encode(hasher.finalize()),
+            Checksummer::Md5(hasher) => general_purpose::STANDARD.encode(hasher.finalize()),
+        }
+    }
+}
+
+/// Verifier verifies the content against the expected checksum.
+pub struct Verifier {
+    name: &'static str,
+    expected: String,
+    checksummer: Checksummer,
+}
+
+impl Verifier {
+    /// Create a verifier for the additional checksum of object.
+    pub fn from_checksum(checksum: S3Checksum) -> Self {
+        Verifier {
+            name: checksum.algorithm.into_static(),
+            expected: checksum.value,
+            checksummer: Checksummer::new(checksum.algorithm),
+        }
+    }
+
+    /// Create a verifier for the base64 encoded md5 of object.
+    pub fn from_md5(expected: String) -> Self {
+        Verifier {
+            name: "md5",
+            expected,
+            checksummer: Checksummer::Md5(Box::default()),
+        }
+    }
+
+    pub fn update(&mut self, bs: &[u8]) {
+        self.checksummer.update(bs)
+    }
+
+    pub fn verify(self) -> Result<()> {
+        let actual = self.checksummer.finish();
+        if actual != self.expected {
+            return Err(Error::new(
+                ErrorKind::ChecksumMismatch,
+                "checksum of read content doesn't match the object's",
+            )
+            .with_context("algorithm", self.name)
+            .with_context("expected", self.expected)
+            .with_context("actual", actual));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse the base64 encoded md5 of the whole object from response headers.
+///
+/// `Content-MD5` will be used if returned, otherwise we will fall back to
+/// `ETag` which is the hex encoded md5 of content only if the object is
+/// not uploaded by multipart upload (`xxxx-3`) and not encrypted by
+/// SSE-KMS or SSE-C.
+pub fn parse_content_md5(headers: &HeaderMap) -> Option<String> {
+    if let Some(v) = headers.get("content-md5") {
+        return v.to_str().ok().map(|v| v.to_string());
+    }
+
+    let is_encrypted = headers
+        .get("x-amz-server-side-encryption")
+        .map(|v| v.as_bytes() != b"AES256")
+        .unwrap_or_default()
+        || headers.contains_key("x-amz-server-side-encryption-customer-algorithm");
+    if is_encrypted {
+        return None;
+    }
+
+    let etag = headers.get(ETAG)?.to_str().ok()?.trim_matches('"');
+    if etag.len() != 32 {
+        return None;
+    }
+    let md5 = (0..etag.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(etag.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    Some(general_purpose::STANDARD.encode(md5))
+}
+
+/// S3Checksum is the base64 encoded checksum of content and the algorithm
+/// used to calculate it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3Checksum {
+    pub algorithm: ChecksumAlgorithm,
+    pub value: String,
+}
+
+impl S3Checksum {
+    /// Calculate the checksum of given body.
+    ///
+    /// Streaming body can't be read twice, so it's checksum can't be
+    /// calculated ahead.
+    pub fn from_body(algorithm: ChecksumAlgorithm, body: &AsyncBody) -> Result<Self> {
+        let mut checksummer = Checksummer::new(algorithm);
+        match body {
+            AsyncBody::Empty => {}
+            AsyncBody::Bytes(bs) => checksummer.update(bs),
+            AsyncBody::Stream(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "checksum can't be calculated for streaming body",
+                )
+                .with_context("algorithm", algorithm.into_static()))
+            }
+        }
+
+        Ok(S3Checksum {
+            algorithm,
+            value: checksummer.finish(),
+        })
+    }
+
+    /// Parse the checksum of the whole object from response headers.
+    ///
+    /// Objects uploaded by multipart upload carry a checksum of checksums
+    /// like `xxxx-3` which can't be verified against the content, we will
+    /// ignore them.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        CHECKSUM_ALGORITHMS.iter().find_map(|algorithm| {
+            let value = headers
+                .get(checksum_header_name(*algorithm))?
+                .to_str()
+                .ok()?;
+            if value.contains('-') {
+                return None;
+            }
+
+            Some(S3Checksum {
+                algorithm: *algorithm,
+                value: value.to_string(),
+            })
+        })
+    }
+
+    pub fn header_name(&self) -> &'static str {
+        checksum_header_name(self.algorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_checksum_from_body() -> Result<()> {
+        let body = AsyncBody::Bytes(Bytes::from("Hello, World!"));
+
+        let checksum = S3Checksum::from_body(ChecksumAlgorithm::Crc32c, &body)?;
+        assert_eq!(checksum.value, "TVUQaA==");
+        let checksum = S3Checksum::from_body(ChecksumAlgorithm::Sha256, &body)?;
+        assert_eq!(
+            checksum.value,
+            "3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8="
+        );
+
+        let checksum = S3Checksum::from_body(ChecksumAlgorithm::Crc32c, &AsyncBody::Empty)?;
+        assert_eq!(checksum.value, "AAAAAA==");
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksummer_incremental() {
+        let mut checksummer = Checksummer::new(ChecksumAlgorithm::Crc32c);
+        checksummer.update(b"Hello, ");
+        checksummer.update(b"World!");
+        assert_eq!(checksummer.finish(), "TVUQaA==");
+    }
+
+    #[test]
+    fn test_checksum_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(S3Checksum::from_headers(&headers), None);
+
+        headers.insert(
+            "x-amz-checksum-sha256",
+            HeaderValue::from_static("3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8="),
+        );
+        assert_eq!(
+            S3Checksum::from_headers(&headers),
+            Some(S3Checksum {
+                algorithm: ChecksumAlgorithm::Sha256,
+                value: "3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8=".to_string(),
+            })
+        );
+
+        // Checksum of checksums should be ignored.
+        headers.insert(
+            "x-amz-checksum-sha256",
+            HeaderValue::from_static("3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8=-2"),
+        );
+        assert_eq!(S3Checksum::from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_parse_content_md5() {
+        let cases = vec![
+            ("empty", vec![], None),
+            (
+                "content md5",
+                vec![("content-md5", "ZajifYh5KDgxtmS9i38K1A==")],
+                Some("ZajifYh5KDgxtmS9i38K1A=="),
+            ),
+            (
+                "etag",
+                vec![("etag", "\"65a8e27d8879283831b664bd8b7f0ad4\"")],
+                Some("ZajifYh5KDgxtmS9i38K1A=="),
+            ),
+            (
+                "multipart etag",
+                vec![("etag", "\"65a8e27d8879283831b664bd8b7f0ad4-2\"")],
+                None,
+            ),
+            (
+                "sse-kms etag",
+                vec![
+                    ("etag", "\"65a8e27d8879283831b664bd8b7f0ad4\""),
+                    ("x-amz-server-side-encryption", "aws:kms"),
+                ],
+                None,
+            ),
+        ];
+
+        for (name, input, expected) in cases {
+            let mut headers = HeaderMap::new();
+            for (k, v) in input {
+                headers.insert(k, HeaderValue::from_static(v));
+            }
+            assert_eq!(parse_content_md5(&headers).as_deref(), expected, "{name}");
+        }
+    }
+}
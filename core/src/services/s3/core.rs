@@ -22,6 +22,9 @@ use std::fmt::Write;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
 use http::header::HeaderName;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
@@ -29,6 +32,7 @@ use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::header::IF_RANGE;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
@@ -38,11 +42,16 @@ use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::backend::S3RequesterPays;
+use super::checksum::checksum_algorithm_value;
+use super::checksum::S3Checksum;
+use super::credential::S3Credential;
 use crate::raw::*;
 use crate::*;
 
 mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
+    pub const X_AMZ_COPY_SOURCE_RANGE: &str = "x-amz-copy-source-range";
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
@@ -51,9 +60,17 @@ mod constants {
         "x-amz-server-side-encryption-customer-key";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5: &str =
         "x-amz-server-side-encryption-customer-key-md5";
+    pub const X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED: &str =
+        "x-amz-server-side-encryption-bucket-key-enabled";
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
         "x-amz-server-side-encryption-aws-kms-key-id";
     pub const X_AMZ_STORAGE_CLASS: &str = "x-amz-storage-class";
+    pub const X_AMZ_CHECKSUM_ALGORITHM: &str = "x-amz-checksum-algorithm";
+    pub const X_AMZ_CHECKSUM_MODE: &str = "x-amz-checksum-mode";
+    pub const X_AMZ_REQUEST_PAYER: &str = "x-amz-request-payer";
+    pub const X_AMZ_REQUEST_CHARGED: &str = "x-amz-request-charged";
+    pub const X_AMZ_OBJECT_LOCK_MODE: &str = "x-amz-object-lock-mode";
+    pub const X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE: &str = "x-amz-object-lock-retain-until-date";
 
     pub const X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM: &str =
         "x-amz-copy-source-server-side-encryption-customer-algorithm";
@@ -76,13 +93,18 @@ pub struct S3Core {
     pub server_side_encryption_customer_algorithm: Option<HeaderValue>,
     pub server_side_encryption_customer_key: Option<HeaderValue>,
     pub server_side_encryption_customer_key_md5: Option<HeaderValue>,
+    pub server_side_encryption_bucket_key_enabled: bool,
     pub default_storage_class: Option<HeaderValue>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
     pub allow_anonymous: bool,
+    pub anonymous: bool,
+    pub requester_pays: bool,
 
     pub signer: AwsV4Signer,
     pub loader: Box<dyn AwsCredentialLoad>,
     pub client: HttpClient,
     pub write_min_size: usize,
+    pub write_memory_budget: Option<usize>,
     pub batch_max_operations: usize,
 }
 
@@ -98,7 +120,19 @@ impl Debug for S3Core {
 
 impl S3Core {
     /// If credential is not found, we will not sign the request.
-    async fn load_credential(&self) -> Result<Option<AwsCredential>> {
+    ///
+    /// [`S3Credential`] attached to the operation takes precedence over the
+    /// credential loaded by `loader`, even in anonymous mode.
+    async fn load_credential(&self, extensions: &Extensions) -> Result<Option<AwsCredential>> {
+        if let Some(cred) = extensions.get::<S3Credential>() {
+            return Ok(Some(cred.to_aws_credential()));
+        }
+
+        // Never sign requests in anonymous mode.
+        if self.anonymous {
+            return Ok(None);
+        }
+
         let cred = self
             .loader
             .load_credential(self.client.client())
@@ -121,7 +155,13 @@ impl S3Core {
     }
 
     pub async fn sign<T>(&self, req: &mut Request<T>) -> Result<()> {
-        let cred = if let Some(cred) = self.load_credential().await? {
+        self.sign_with(req, &Extensions::default()).await
+    }
+
+    /// Sign the request with the extensions of operation, see
+    /// [`S3Credential`] for details.
+    pub async fn sign_with<T>(&self, req: &mut Request<T>, extensions: &Extensions) -> Result<()> {
+        let cred = if let Some(cred) = self.load_credential(extensions).await? {
             cred
         } else {
             return Ok(());
@@ -130,8 +170,13 @@ impl S3Core {
         self.signer.sign(req, &cred).map_err(new_request_sign_error)
     }
 
-    pub async fn sign_query<T>(&self, req: &mut Request<T>, duration: Duration) -> Result<()> {
-        let cred = if let Some(cred) = self.load_credential().await? {
+    pub async fn sign_query<T>(
+        &self,
+        req: &mut Request<T>,
+        duration: Duration,
+        extensions: &Extensions,
+    ) -> Result<()> {
+        let cred = if let Some(cred) = self.load_credential(extensions).await? {
             cred
         } else {
             return Ok(());
@@ -175,6 +220,14 @@ impl S3Core {
                     v,
                 )
             }
+            if self.server_side_encryption_bucket_key_enabled {
+                req = req.header(
+                    HeaderName::from_static(
+                        constants::X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED,
+                    ),
+                    "true",
+                )
+            }
         }
 
         if let Some(v) = &self.server_side_encryption_customer_algorithm {
@@ -207,15 +260,152 @@ impl S3Core {
 
         req
     }
+
+    /// Insert SSE-C headers of the copy source, which is the same as the
+    /// destination since they are in the same bucket.
+    pub fn insert_copy_source_sse_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.server_side_encryption_customer_algorithm {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+                ),
+                v,
+            )
+        }
+
+        req
+    }
+
+    /// Insert `x-amz-request-payer` header if requester pays is enabled.
+    ///
+    /// The per-operation [`S3RequesterPays`] extension takes precedence
+    /// over the config of backend.
+    pub fn insert_request_payer_header(
+        &self,
+        req: http::request::Builder,
+        extensions: Option<&Extensions>,
+    ) -> http::request::Builder {
+        let requester_pays = extensions
+            .and_then(|v| v.get::<S3RequesterPays>())
+            .map_or(self.requester_pays, |v| v.0);
+
+        if requester_pays {
+            req.header(constants::X_AMZ_REQUEST_PAYER, "requester")
+        } else {
+            req
+        }
+    }
+
+    /// Insert object lock headers to set the retention of uploading object.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/object-lock-managing.html>
+    pub fn insert_object_lock_headers(
+        &self,
+        mut req: http::request::Builder,
+        object_lock: Option<(ObjectLockMode, DateTime<Utc>)>,
+    ) -> http::request::Builder {
+        if let Some((mode, retain_until)) = object_lock {
+            req = req.header(
+                constants::X_AMZ_OBJECT_LOCK_MODE,
+                object_lock_mode_value(mode),
+            );
+            req = req.header(
+                constants::X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+                retain_until.to_rfc3339_opts(SecondsFormat::Secs, true),
+            );
+        }
+        req
+    }
+}
+
+/// The value of `x-amz-object-lock-mode` for given mode.
+pub fn object_lock_mode_value(mode: ObjectLockMode) -> &'static str {
+    match mode {
+        ObjectLockMode::Governance => "GOVERNANCE",
+        ObjectLockMode::Compliance => "COMPLIANCE",
+    }
+}
+
+/// Parse metadata from response headers, including s3 specific fields
+/// like server side encryption.
+pub fn parse_s3_metadata(path: &str, headers: &http::HeaderMap) -> Result<Metadata> {
+    let mut m = parse_into_metadata(path, headers)?;
+
+    let parse_header = |name: &str| -> Result<Option<&str>> {
+        headers
+            .get(name)
+            .map(|v| {
+                v.to_str().map_err(|e| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "header value is not valid utf-8 string",
+                    )
+                    .with_operation("parse_s3_metadata")
+                    .with_context("header", name)
+                    .set_source(e)
+                })
+            })
+            .transpose()
+    };
+
+    if let Some(v) = parse_header(constants::X_AMZ_SERVER_SIDE_ENCRYPTION)? {
+        m.set_server_side_encryption(v);
+    }
+
+    // Object lock headers are only returned for objects with retention.
+    if let Some(v) = parse_header(constants::X_AMZ_OBJECT_LOCK_MODE)? {
+        m.set_object_lock_mode(v.parse()?);
+    }
+    if let Some(v) = parse_header(constants::X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE)? {
+        m.set_object_lock_retain_until(parse_datetime_from_rfc3339(v)?);
+    }
+    m.set_request_charged(parse_request_charged(headers));
+
+    Ok(m)
+}
+
+/// Check whether the requester has been charged for this request.
+///
+/// S3 only returns `x-amz-request-charged` for requester pays buckets.
+pub fn parse_request_charged(headers: &http::HeaderMap) -> bool {
+    headers
+        .get(constants::X_AMZ_REQUEST_CHARGED)
+        .and_then(|v| v.to_str().ok())
+        == Some("requester")
 }
 
 impl S3Core {
-    pub fn s3_head_object_request(
-        &self,
-        path: &str,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
-    ) -> Result<Request<AsyncBody>> {
+    pub fn s3_head_object_request(&self, path: &str, args: &OpStat) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -223,12 +413,13 @@ impl S3Core {
         let mut req = Request::head(&url);
 
         req = self.insert_sse_headers(req, false);
+        req = self.insert_request_payer_header(req, Some(args.extensions()));
 
-        if let Some(if_none_match) = if_none_match {
+        if let Some(if_none_match) = args.if_none_match() {
             req = req.header(IF_NONE_MATCH, if_none_match);
         }
 
-        if let Some(if_match) = if_match {
+        if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
         }
 
@@ -277,6 +468,11 @@ impl S3Core {
         let range = args.range();
         if !range.is_full() {
             req = req.header(http::header::RANGE, range.to_header());
+
+            // If-Range only makes sense with Range.
+            if let Some(if_range) = args.if_range() {
+                req = req.header(IF_RANGE, if_range);
+            }
         }
 
         if let Some(if_none_match) = args.if_none_match() {
@@ -289,6 +485,7 @@ impl S3Core {
         // Set SSE headers.
         // TODO: how will this work with presign?
         req = self.insert_sse_headers(req, false);
+        req = self.insert_request_payer_header(req, Some(args.extensions()));
 
         let req = req
             .body(AsyncBody::Empty)
@@ -302,13 +499,25 @@ impl S3Core {
         path: &str,
         args: OpRead,
     ) -> Result<Response<IncomingAsyncBody>> {
+        let is_full = args.range().is_full();
+        let extensions = args.extensions().clone();
         let mut req = self.s3_get_object_request(path, args)?;
 
-        self.sign(&mut req).await?;
+        // Ask s3 to return the checksum of object so that we can verify
+        // it after read. Checksum is only returned for full object.
+        if is_full {
+            req.headers_mut().insert(
+                constants::X_AMZ_CHECKSUM_MODE,
+                HeaderValue::from_static("ENABLED"),
+            );
+        }
+
+        self.sign_with(&mut req, &extensions).await?;
 
         self.send(req).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn s3_put_object_request(
         &self,
         path: &str,
@@ -316,6 +525,9 @@ impl S3Core {
         content_type: Option<&str>,
         content_disposition: Option<&str>,
         cache_control: Option<&str>,
+        content_md5: Option<&str>,
+        checksum: Option<&S3Checksum>,
+        object_lock: Option<(ObjectLockMode, DateTime<Utc>)>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -340,11 +552,21 @@ impl S3Core {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(content_md5) = content_md5 {
+            req = req.header("CONTENT-MD5", content_md5)
+        }
+
+        if let Some(checksum) = checksum {
+            req = req.header(checksum.header_name(), &checksum.value)
+        }
+
         // Set storage class header
         if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
+        req = self.insert_object_lock_headers(req, object_lock);
+
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
@@ -357,17 +579,20 @@ impl S3Core {
     pub async fn s3_head_object(
         &self,
         path: &str,
-        if_none_match: Option<&str>,
-        if_match: Option<&str>,
+        args: &OpStat,
     ) -> Result<Response<IncomingAsyncBody>> {
-        let mut req = self.s3_head_object_request(path, if_none_match, if_match)?;
+        let mut req = self.s3_head_object_request(path, args)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, args.extensions()).await?;
 
         self.send(req).await
     }
 
-    pub async fn s3_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    pub async fn s3_delete_object(
+        &self,
+        path: &str,
+        args: &OpDelete,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -376,7 +601,7 @@ impl S3Core {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, args.extensions()).await?;
 
         self.send(req).await
     }
@@ -385,6 +610,7 @@ impl S3Core {
         &self,
         from: &str,
         to: &str,
+        args: &OpCopy,
     ) -> Result<Response<IncomingAsyncBody>> {
         let from = build_abs_path(&self.root, from);
         let to = build_abs_path(&self.root, to);
@@ -396,49 +622,14 @@ impl S3Core {
 
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
-
-        if let Some(v) = &self.server_side_encryption_customer_algorithm {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
-                ),
-                v,
-            )
-        }
-
-        if let Some(v) = &self.server_side_encryption_customer_key {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
-                ),
-                v,
-            )
-        }
-
-        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
-            let mut v = v.clone();
-            v.set_sensitive(true);
-
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
-                ),
-                v,
-            )
-        }
+        req = self.insert_copy_source_sse_headers(req);
 
         let mut req = req
             .header(constants::X_AMZ_COPY_SOURCE, percent_encode_path(&source))
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, args.extensions()).await?;
 
         self.send(req).await
     }
@@ -450,6 +641,7 @@ impl S3Core {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<String>,
+        extensions: &Extensions,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -459,7 +651,8 @@ impl S3Core {
                 .expect("write into string must succeed");
         }
         if !delimiter.is_empty() {
-            write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
+            write!(url, "&delimiter={}", percent_encode_path(delimiter))
+                .expect("write into string must succeed");
         }
         if let Some(limit) = limit {
             write!(url, "&max-keys={limit}").expect("write into string must succeed");
@@ -482,21 +675,26 @@ impl S3Core {
             .expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let mut req = self
+            .insert_request_payer_header(Request::get(&url), Some(extensions))
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, extensions).await?;
 
         self.send(req).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn s3_initiate_multipart_upload(
         &self,
         path: &str,
         content_type: Option<&str>,
         content_disposition: Option<&str>,
         cache_control: Option<&str>,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+        object_lock: Option<(ObjectLockMode, DateTime<Utc>)>,
+        extensions: &Extensions,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -516,11 +714,20 @@ impl S3Core {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(algorithm) = checksum_algorithm {
+            req = req.header(
+                constants::X_AMZ_CHECKSUM_ALGORITHM,
+                checksum_algorithm_value(algorithm),
+            )
+        }
+
         // Set storage class header
         if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
         }
 
+        req = self.insert_object_lock_headers(req, object_lock);
+
         // Set SSE headers.
         let req = self.insert_sse_headers(req, true);
 
@@ -528,17 +735,20 @@ impl S3Core {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, extensions).await?;
 
         self.send(req).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn s3_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
         size: u64,
+        content_md5: Option<&str>,
+        checksum: Option<&S3Checksum>,
         body: AsyncBody,
     ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
@@ -555,6 +765,14 @@ impl S3Core {
 
         req = req.header(CONTENT_LENGTH, size);
 
+        if let Some(content_md5) = content_md5 {
+            req = req.header("CONTENT-MD5", content_md5)
+        }
+
+        if let Some(checksum) = checksum {
+            req = req.header(checksum.header_name(), &checksum.value)
+        }
+
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
@@ -564,11 +782,53 @@ impl S3Core {
         Ok(req)
     }
 
+    /// Upload a part by copying given range of an existing object.
+    ///
+    /// The range must be a closed range like `bytes=first-last`.
+    pub async fn s3_upload_part_copy(
+        &self,
+        from: &str,
+        to: &str,
+        upload_id: &str,
+        part_number: usize,
+        range: BytesRange,
+        extensions: &Extensions,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let from = build_abs_path(&self.root, from);
+        let to = build_abs_path(&self.root, to);
+
+        let source = format!("{}/{}", self.bucket, percent_encode_path(&from));
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&to),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req, true);
+        req = self.insert_copy_source_sse_headers(req);
+
+        let mut req = req
+            .header(constants::X_AMZ_COPY_SOURCE, percent_encode_path(&source))
+            .header(constants::X_AMZ_COPY_SOURCE_RANGE, range.to_header())
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.sign_with(&mut req, extensions).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_complete_multipart_upload(
         &self,
         path: &str,
         upload_id: &str,
         parts: Vec<CompleteMultipartUploadRequestPart>,
+        extensions: &Extensions,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -595,7 +855,7 @@ impl S3Core {
             .body(AsyncBody::Bytes(Bytes::from(content)))
             .map_err(new_request_build_error)?;
 
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, extensions).await?;
 
         self.send(req).await
     }
@@ -605,6 +865,7 @@ impl S3Core {
         &self,
         path: &str,
         upload_id: &str,
+        extensions: &Extensions,
     ) -> Result<Response<IncomingAsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -618,7 +879,7 @@ impl S3Core {
         let mut req = Request::delete(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
-        self.sign(&mut req).await?;
+        self.sign_with(&mut req, extensions).await?;
         self.send(req).await
     }
 
@@ -664,6 +925,14 @@ pub struct InitiateMultipartUploadResult {
     pub upload_id: String,
 }
 
+/// Result of UploadPartCopy
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
 /// Request of CompleteMultipartUploadRequest
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
@@ -706,6 +975,10 @@ pub struct CompleteMultipartUploadRequestPart {
     /// ref: <https://github.com/tafia/quick-xml/issues/362>
     #[serde(rename = "ETag")]
     pub etag: String,
+    #[serde(rename = "ChecksumCRC32C", skip_serializing_if = "Option::is_none")]
+    pub checksum_crc32c: Option<String>,
+    #[serde(rename = "ChecksumSHA256", skip_serializing_if = "Option::is_none")]
+    pub checksum_sha256: Option<String>,
 }
 
 /// Request of DeleteObjects.
@@ -779,14 +1052,17 @@ mod tests {
                 CompleteMultipartUploadRequestPart {
                     part_number: 1,
                     etag: "\"a54357aff0632cce46d942af68356b38\"".to_string(),
+                    ..Default::default()
                 },
                 CompleteMultipartUploadRequestPart {
                     part_number: 2,
                     etag: "\"0c78aef83f66abc1fa1e8477f296d394\"".to_string(),
+                    ..Default::default()
                 },
                 CompleteMultipartUploadRequestPart {
                     part_number: 3,
                     etag: "\"acbd18db4cc2f85cedef654fccc4a4d8\"".to_string(),
+                    ..Default::default()
                 },
             ],
         };
@@ -816,6 +1092,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_serialize_complete_multipart_upload_request_with_checksum() {
+        let req = CompleteMultipartUploadRequest {
+            part: vec![CompleteMultipartUploadRequestPart {
+                part_number: 1,
+                etag: "etag".to_string(),
+                checksum_crc32c: Some("TVUQaA==".to_string()),
+                checksum_sha256: None,
+            }],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>etag</ETag><ChecksumCRC32C>TVUQaA==</ChecksumCRC32C></Part></CompleteMultipartUpload>"
+        )
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
     #[test]
     fn test_serialize_delete_objects_request() {
@@ -0,0 +1,724 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::env;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fs;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use log::debug;
+use reqsign::AwsConfig;
+use reqsign::AwsCredential;
+use reqsign::AwsCredentialLoad;
+use serde::Deserialize;
+
+use crate::raw::*;
+
+const AWS_ACCESS_KEY_ID: &str = "AWS_ACCESS_KEY_ID";
+const AWS_SECRET_ACCESS_KEY: &str = "AWS_SECRET_ACCESS_KEY";
+const AWS_SESSION_TOKEN: &str = "AWS_SESSION_TOKEN";
+const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
+const AWS_SHARED_CREDENTIALS_FILE: &str = "AWS_SHARED_CREDENTIALS_FILE";
+const AWS_PROFILE: &str = "AWS_PROFILE";
+const AWS_ROLE_ARN: &str = "AWS_ROLE_ARN";
+const AWS_ROLE_SESSION_NAME: &str = "AWS_ROLE_SESSION_NAME";
+const AWS_WEB_IDENTITY_TOKEN_FILE: &str = "AWS_WEB_IDENTITY_TOKEN_FILE";
+
+const DEFAULT_STS_ENDPOINT: &str = "https://sts.amazonaws.com";
+const DEFAULT_IMDS_ENDPOINT: &str = "http://169.254.169.254";
+
+/// S3Credential is a request-scoped credential that could be attached
+/// to an operation via `extension`.
+///
+/// The attached credential will be used to sign requests of this
+/// operation instead of the credential loaded by the service, which is
+/// useful for multi-tenant services holding short-lived tokens of every
+/// tenant.
+///
+/// # Examples
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::S3Credential;
+/// use opendal::Operator;
+///
+/// async fn read_as_tenant(op: Operator, token: &str) -> Result<Vec<u8>> {
+///     let cred = S3Credential::new("access_key_id", "secret_access_key")
+///         .with_session_token(token);
+///     Ok(op.read_with("path/to/file").extension(cred).await?)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct S3Credential {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl S3Credential {
+    /// Create a new credential with access key id and secret access key.
+    pub fn new(access_key_id: &str, secret_access_key: &str) -> Self {
+        Self {
+            access_key_id: access_key_id.to_string(),
+            secret_access_key: secret_access_key.to_string(),
+            session_token: None,
+        }
+    }
+
+    /// Set the session token of temporary credential.
+    pub fn with_session_token(mut self, session_token: &str) -> Self {
+        self.session_token = Some(session_token.to_string());
+        self
+    }
+
+    pub(super) fn to_aws_credential(&self) -> AwsCredential {
+        AwsCredential {
+            access_key_id: self.access_key_id.clone(),
+            secret_access_key: self.secret_access_key.clone(),
+            session_token: self.session_token.clone(),
+            expires_in: None,
+        }
+    }
+}
+
+impl Debug for S3Credential {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3Credential")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// CachedCredentialLoader caches the credential loaded by inner loader
+/// until it's near expiry.
+///
+/// Loaders like `AwsAssumeRoleLoader` and customed loaders will send a
+/// request every time they are called. With this loader, the credential
+/// will be refreshed only if it's going to expire in 2 minutes, so that
+/// in-flight requests will never be signed by an expired credential.
+pub struct CachedCredentialLoader {
+    inner: Box<dyn AwsCredentialLoad>,
+    credential: Mutex<Option<AwsCredential>>,
+}
+
+impl CachedCredentialLoader {
+    pub fn new(inner: Box<dyn AwsCredentialLoad>) -> Self {
+        Self {
+            inner,
+            credential: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for CachedCredentialLoader {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AwsCredential>> {
+        if let Some(cred) = self.credential.lock().expect("lock poisoned").clone() {
+            // `is_valid` will take credentials that expire in 2 minutes
+            // as invalid.
+            if cred.is_valid() {
+                return Ok(Some(cred));
+            }
+        }
+
+        let cred = self.inner.load_credential(client).await?;
+        *self.credential.lock().expect("lock poisoned") = cred.clone();
+        Ok(cred)
+    }
+}
+
+/// S3CredentialChain loads credential from given loaders in order, the
+/// first loaded credential will be used.
+///
+/// Failures of loaders will be logged and skipped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use opendal::services::S3CredentialChain;
+/// use opendal::services::S3;
+///
+/// let mut builder = S3::default();
+/// builder.customed_credential_load(Box::new(S3CredentialChain::default()));
+/// ```
+pub struct S3CredentialChain {
+    loaders: Vec<Box<dyn AwsCredentialLoad>>,
+}
+
+impl Default for S3CredentialChain {
+    /// Create a chain of built-in loaders:
+    ///
+    /// 1. [`S3EnvCredentialLoader`]
+    /// 2. [`S3ProfileCredentialLoader`]
+    /// 3. [`S3WebIdentityCredentialLoader`]
+    /// 4. [`S3Imdsv2CredentialLoader`]
+    fn default() -> Self {
+        Self::new()
+            .push(S3EnvCredentialLoader)
+            .push(S3ProfileCredentialLoader::default())
+            .push(S3WebIdentityCredentialLoader::default())
+            .push(S3Imdsv2CredentialLoader::default())
+    }
+}
+
+impl S3CredentialChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self {
+            loaders: Vec::new(),
+        }
+    }
+
+    /// Push a loader into the end of chain.
+    pub fn push(mut self, loader: impl AwsCredentialLoad + 'static) -> Self {
+        self.loaders.push(Box::new(loader));
+        self
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for S3CredentialChain {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AwsCredential>> {
+        for loader in &self.loaders {
+            match loader.load_credential(client.clone()).await {
+                Ok(Some(cred)) => return Ok(Some(cred)),
+                Ok(None) => {}
+                Err(err) => debug!("load s3 credential failed: {err:?}"),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// S3EnvCredentialLoader loads credential from env `AWS_ACCESS_KEY_ID`,
+/// `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`.
+pub struct S3EnvCredentialLoader;
+
+#[async_trait]
+impl AwsCredentialLoad for S3EnvCredentialLoader {
+    async fn load_credential(&self, _: reqwest::Client) -> anyhow::Result<Option<AwsCredential>> {
+        let (Ok(access_key_id), Ok(secret_access_key)) =
+            (env::var(AWS_ACCESS_KEY_ID), env::var(AWS_SECRET_ACCESS_KEY))
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(AwsCredential {
+            access_key_id,
+            secret_access_key,
+            session_token: env::var(AWS_SESSION_TOKEN).ok(),
+            expires_in: None,
+        }))
+    }
+}
+
+/// S3ProfileCredentialLoader loads credential from shared config files
+/// `~/.aws/config` and `~/.aws/credentials`.
+///
+/// Paths of files and the profile can be changed by env `AWS_CONFIG_FILE`,
+/// `AWS_SHARED_CREDENTIALS_FILE` and `AWS_PROFILE`.
+pub struct S3ProfileCredentialLoader {
+    config: AwsConfig,
+}
+
+impl Default for S3ProfileCredentialLoader {
+    fn default() -> Self {
+        let mut config = AwsConfig::default();
+        if let Ok(v) = env::var(AWS_CONFIG_FILE) {
+            config.config_file = v;
+        }
+        if let Ok(v) = env::var(AWS_SHARED_CREDENTIALS_FILE) {
+            config.shared_credentials_file = v;
+        }
+        if let Ok(v) = env::var(AWS_PROFILE) {
+            config.profile = v;
+        }
+        Self { config }
+    }
+}
+
+impl S3ProfileCredentialLoader {
+    /// Set the path of config file.
+    pub fn with_config_file(mut self, path: &str) -> Self {
+        self.config.config_file = path.to_string();
+        self
+    }
+
+    /// Set the path of shared credentials file.
+    pub fn with_shared_credentials_file(mut self, path: &str) -> Self {
+        self.config.shared_credentials_file = path.to_string();
+        self
+    }
+
+    /// Set the profile to load.
+    pub fn with_profile(mut self, profile: &str) -> Self {
+        self.config.profile = profile.to_string();
+        self
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for S3ProfileCredentialLoader {
+    async fn load_credential(&self, _: reqwest::Client) -> anyhow::Result<Option<AwsCredential>> {
+        // Files will be read every time, they are expected to be cached by
+        // `CachedCredentialLoader`.
+        let config = self.config.clone().from_profile();
+        let (Some(access_key_id), Some(secret_access_key)) =
+            (config.access_key_id, config.secret_access_key)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(AwsCredential {
+            access_key_id,
+            secret_access_key,
+            session_token: config.session_token,
+            expires_in: None,
+        }))
+    }
+}
+
+/// S3WebIdentityCredentialLoader loads credential via STS
+/// `AssumeRoleWithWebIdentity` with the token in given file, which is
+/// used by EKS service accounts.
+///
+/// The role, token file and session name will be loaded from env
+/// `AWS_ROLE_ARN`, `AWS_WEB_IDENTITY_TOKEN_FILE` and `AWS_ROLE_SESSION_NAME`
+/// by default.
+pub struct S3WebIdentityCredentialLoader {
+    endpoint: String,
+    role_arn: Option<String>,
+    token_file: Option<String>,
+    role_session_name: String,
+}
+
+impl Default for S3WebIdentityCredentialLoader {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_STS_ENDPOINT.to_string(),
+            role_arn: env::var(AWS_ROLE_ARN).ok(),
+            token_file: env::var(AWS_WEB_IDENTITY_TOKEN_FILE).ok(),
+            role_session_name: env::var(AWS_ROLE_SESSION_NAME)
+                .unwrap_or_else(|_| "opendal".to_string()),
+        }
+    }
+}
+
+impl S3WebIdentityCredentialLoader {
+    /// Set the endpoint of STS.
+    ///
+    /// Default to `https://sts.amazonaws.com`.
+    pub fn with_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = endpoint.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the arn of role to assume.
+    pub fn with_role_arn(mut self, role_arn: &str) -> Self {
+        self.role_arn = Some(role_arn.to_string());
+        self
+    }
+
+    /// Set the path of web identity token file.
+    pub fn with_token_file(mut self, path: &str) -> Self {
+        self.token_file = Some(path.to_string());
+        self
+    }
+
+    /// Set the session name of assumed role.
+    pub fn with_role_session_name(mut self, name: &str) -> Self {
+        self.role_session_name = name.to_string();
+        self
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for S3WebIdentityCredentialLoader {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AwsCredential>> {
+        let (Some(role_arn), Some(token_file)) = (&self.role_arn, &self.token_file) else {
+            return Ok(None);
+        };
+
+        // Token will be rotated by the platform, read it every time.
+        let token = fs::read_to_string(token_file)?;
+        let url = format!(
+            "{}/?Action=AssumeRoleWithWebIdentity&Version=2011-06-15&RoleArn={}&RoleSessionName={}&WebIdentityToken={}",
+            self.endpoint,
+            percent_encode_path(role_arn),
+            percent_encode_path(&self.role_session_name),
+            percent_encode_path(token.trim()),
+        );
+
+        let resp = client.get(url).send().await?;
+        if !resp.status().is_success() {
+            let content = resp.text().await?;
+            return Err(anyhow!("request to AWS STS failed: {content}"));
+        }
+
+        let resp: AssumeRoleWithWebIdentityResponse = quick_xml::de::from_str(&resp.text().await?)?;
+        let cred = resp.result.credentials;
+        Ok(Some(AwsCredential {
+            access_key_id: cred.access_key_id,
+            secret_access_key: cred.secret_access_key,
+            session_token: Some(cred.session_token),
+            expires_in: Some(parse_datetime_from_rfc3339(&cred.expiration)?),
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleWithWebIdentityResponse {
+    #[serde(rename = "AssumeRoleWithWebIdentityResult")]
+    result: AssumeRoleWithWebIdentityResult,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleWithWebIdentityResult {
+    credentials: StsCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct StsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: String,
+}
+
+/// S3Imdsv2CredentialLoader loads credential of the instance profile from
+/// EC2 instance metadata service with IMDSv2 session tokens.
+pub struct S3Imdsv2CredentialLoader {
+    endpoint: String,
+}
+
+impl Default for S3Imdsv2CredentialLoader {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_IMDS_ENDPOINT.to_string(),
+        }
+    }
+}
+
+impl S3Imdsv2CredentialLoader {
+    /// Set the endpoint of instance metadata service.
+    ///
+    /// Default to `http://169.254.169.254`.
+    pub fn with_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = endpoint.trim_end_matches('/').to_string();
+        self
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for S3Imdsv2CredentialLoader {
+    async fn load_credential(
+        &self,
+        client: reqwest::Client,
+    ) -> anyhow::Result<Option<AwsCredential>> {
+        let resp = client
+            .put(format!("{}/latest/api/token", self.endpoint))
+            .header("x-aws-ec2-metadata-token-ttl-seconds", "21600")
+            // Fail fast outside of EC2.
+            .timeout(Duration::from_secs(1))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let content = resp.text().await?;
+            return Err(anyhow!("request to AWS IMDS failed: {content}"));
+        }
+        let token = resp.text().await?;
+
+        let url = format!(
+            "{}/latest/meta-data/iam/security-credentials/",
+            self.endpoint
+        );
+        let resp = client
+            .get(&url)
+            .header("x-aws-ec2-metadata-token", &token)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let content = resp.text().await?;
+            return Err(anyhow!("request to AWS IMDS failed: {content}"));
+        }
+        let role = resp.text().await?;
+
+        let resp = client
+            .get(format!("{url}{}", role.trim()))
+            .header("x-aws-ec2-metadata-token", &token)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let content = resp.text().await?;
+            return Err(anyhow!("request to AWS IMDS failed: {content}"));
+        }
+
+        let cred: ImdsCredentials = serde_json::from_slice(&resp.bytes().await?)?;
+        if cred.code != "Success" {
+            return Err(anyhow!(
+                "load credential from AWS IMDS failed: {}",
+                cred.code
+            ));
+        }
+        Ok(Some(AwsCredential {
+            access_key_id: cred.access_key_id,
+            secret_access_key: cred.secret_access_key,
+            session_token: Some(cred.token),
+            expires_in: Some(parse_datetime_from_rfc3339(&cred.expiration)?),
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImdsCredentials {
+    code: String,
+    access_key_id: String,
+    secret_access_key: String,
+    token: String,
+    expiration: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use chrono::Utc;
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    /// MockStsLoader loads credential from a mock STS endpoint.
+    struct MockStsLoader {
+        endpoint: String,
+    }
+
+    #[async_trait]
+    impl AwsCredentialLoad for MockStsLoader {
+        async fn load_credential(
+            &self,
+            client: reqwest::Client,
+        ) -> anyhow::Result<Option<AwsCredential>> {
+            let url = format!("{}/?Action=AssumeRole", self.endpoint);
+            let expires_in: i64 = client.get(url).send().await?.text().await?.parse()?;
+
+            Ok(Some(AwsCredential {
+                access_key_id: "access_key_id".to_string(),
+                secret_access_key: "secret_access_key".to_string(),
+                session_token: Some("session_token".to_string()),
+                expires_in: Some(Utc::now() + Duration::seconds(expires_in)),
+            }))
+        }
+    }
+
+    async fn mock_sts(expires_in: i64) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("Action", "AssumeRole"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(expires_in.to_string()))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_cached_credential_loader() -> anyhow::Result<()> {
+        let server = mock_sts(3600).await;
+        let loader = CachedCredentialLoader::new(Box::new(MockStsLoader {
+            endpoint: server.uri(),
+        }));
+
+        for _ in 0..3 {
+            let cred = loader.load_credential(reqwest::Client::new()).await?;
+            assert!(cred.expect("credential must be loaded").is_valid());
+        }
+        let requests = server.received_requests().await.unwrap_or_default();
+        assert_eq!(requests.len(), 1, "valid credential should be cached");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cached_credential_loader_refresh_before_expiry() -> anyhow::Result<()> {
+        // The credential is still valid for 60s, but we should refresh it
+        // ahead instead of using it until expired.
+        let server = mock_sts(60).await;
+        let loader = CachedCredentialLoader::new(Box::new(MockStsLoader {
+            endpoint: server.uri(),
+        }));
+
+        for _ in 0..3 {
+            let cred = loader.load_credential(reqwest::Client::new()).await?;
+            assert!(cred.is_some());
+        }
+        let requests = server.received_requests().await.unwrap_or_default();
+        assert_eq!(
+            requests.len(),
+            3,
+            "credential near expiry should be refreshed"
+        );
+        Ok(())
+    }
+
+    fn temp_file(content: &str) -> String {
+        let path = env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        fs::write(&path, content).expect("write must succeed");
+        path.to_string_lossy().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_web_identity_loader_refresh_before_expiry() -> anyhow::Result<()> {
+        let token_file = temp_file("web-identity-token");
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("Action", "AssumeRoleWithWebIdentity"))
+            .and(query_param("RoleArn", "arn:aws:iam::123456789012:role/test"))
+            .and(query_param("WebIdentityToken", "web-identity-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                r#"<AssumeRoleWithWebIdentityResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleWithWebIdentityResult>
+    <Credentials>
+      <AccessKeyId>access_key_id</AccessKeyId>
+      <SecretAccessKey>secret_access_key</SecretAccessKey>
+      <SessionToken>session_token</SessionToken>
+      <Expiration>{}</Expiration>
+    </Credentials>
+  </AssumeRoleWithWebIdentityResult>
+</AssumeRoleWithWebIdentityResponse>"#,
+                (Utc::now() + Duration::seconds(60)).to_rfc3339()
+            )))
+            .mount(&server)
+            .await;
+
+        let loader = CachedCredentialLoader::new(Box::new(
+            S3WebIdentityCredentialLoader::default()
+                .with_endpoint(&server.uri())
+                .with_role_arn("arn:aws:iam::123456789012:role/test")
+                .with_token_file(&token_file),
+        ));
+        for _ in 0..2 {
+            let cred = loader
+                .load_credential(reqwest::Client::new())
+                .await?
+                .expect("credential must be loaded");
+            assert_eq!(cred.access_key_id, "access_key_id");
+            assert_eq!(cred.session_token.as_deref(), Some("session_token"));
+        }
+        let requests = server.received_requests().await.unwrap_or_default();
+        assert_eq!(
+            requests.len(),
+            2,
+            "credential near expiry should be refreshed"
+        );
+
+        fs::remove_file(token_file)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_imdsv2_loader() -> anyhow::Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/latest/api/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("imds-token"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/latest/meta-data/iam/security-credentials/"))
+            .and(header("x-aws-ec2-metadata-token", "imds-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("test-role"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/latest/meta-data/iam/security-credentials/test-role"))
+            .and(header("x-aws-ec2-metadata-token", "imds-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                r#"{{"Code":"Success","AccessKeyId":"access_key_id","SecretAccessKey":"secret_access_key","Token":"session_token","Expiration":"{}"}}"#,
+                (Utc::now() + Duration::hours(6)).to_rfc3339()
+            )))
+            .mount(&server)
+            .await;
+
+        let loader = S3Imdsv2CredentialLoader::default().with_endpoint(&server.uri());
+        let cred = loader
+            .load_credential(reqwest::Client::new())
+            .await?
+            .expect("credential must be loaded");
+        assert_eq!(cred.access_key_id, "access_key_id");
+        assert_eq!(cred.secret_access_key, "secret_access_key");
+        assert!(cred.is_valid());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_credential_chain() -> anyhow::Result<()> {
+        let credentials_file = temp_file(
+            "[default]\naws_access_key_id = default_id\naws_secret_access_key = default_secret\n\n[test]\naws_access_key_id = test_id\naws_secret_access_key = test_secret\n",
+        );
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        // Failed and empty loaders are skipped.
+        let chain = S3CredentialChain::new()
+            .push(S3Imdsv2CredentialLoader::default().with_endpoint(&server.uri()))
+            .push(S3WebIdentityCredentialLoader::default().with_token_file("not_exist"))
+            .push(
+                S3ProfileCredentialLoader::default()
+                    .with_config_file("not_exist")
+                    .with_shared_credentials_file(&credentials_file)
+                    .with_profile("test"),
+            );
+        let cred = chain
+            .load_credential(reqwest::Client::new())
+            .await?
+            .expect("credential must be loaded");
+        assert_eq!(cred.access_key_id, "test_id");
+        assert_eq!(cred.secret_access_key, "test_secret");
+
+        let chain = S3CredentialChain::new();
+        assert!(chain
+            .load_credential(reqwest::Client::new())
+            .await?
+            .is_none());
+
+        fs::remove_file(credentials_file)?;
+        Ok(())
+    }
+}
@@ -47,6 +47,7 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         // Service like R2 could return 499 error with a message like:
         // Client Disconnect, we should retry it.
         499 => (ErrorKind::Unexpected, true),
+        429 => (ErrorKind::RateLimited, true),
         500 | 502 | 503 | 504 => (ErrorKind::Unexpected, true),
         _ => (ErrorKind::Unexpected, false),
     };
@@ -55,11 +56,34 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         .map(|s3_err| (format!("{s3_err:?}"), Some(s3_err)))
         .unwrap_or_else(|_| (String::from_utf8_lossy(&bs).into_owned(), None));
 
-    if let Some(s3_err) = s3_err {
+    if let Some(s3_err) = &s3_err {
         (kind, retryable) = parse_s3_error_code(s3_err.code.as_str()).unwrap_or((kind, retryable));
     }
 
-    let mut err = Error::new(kind, &message);
+    let mut err = match &s3_err {
+        // S3 rejects object lock headers with `InvalidRequest` if object
+        // lock is not enabled on the bucket, which is a config error.
+        Some(s3_err) if is_object_lock_not_enabled(s3_err) => Error::new(
+            ErrorKind::ConfigInvalid,
+            "object lock is not enabled on the bucket, it must be enabled while creating bucket",
+        ),
+        _ => Error::new(kind, &message),
+    };
+
+    // Prefer the request id in header since the body could be empty, like
+    // responses of `HEAD` requests.
+    let request_id = parts
+        .headers
+        .get("x-amz-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+        .or_else(|| s3_err.as_ref().map(|v| v.request_id.clone()))
+        .unwrap_or_default();
+    if let Some(s3_err) = &s3_err {
+        err = err.with_service_context(&s3_err.code, &s3_err.message, &request_id);
+    } else {
+        err = err.with_service_context("", "", &request_id);
+    }
 
     err = with_error_response_context(err, parts);
 
@@ -70,6 +94,11 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     Ok(err)
 }
 
+/// Check if the error is caused by object lock not enabled on the bucket.
+fn is_object_lock_not_enabled(s3_err: &S3Error) -> bool {
+    s3_err.code == "InvalidRequest" && s3_err.message.contains("Object Lock")
+}
+
 /// Returns the `Error kind` of this code and whether the error is retryable.
 /// All possible error code: <https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html#ErrorCodeList>
 pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
@@ -94,12 +123,19 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // indicates a temporary issue with the service or server, such as high load,
         // maintenance, or an internal problem.
         "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        // > The Content-MD5 or checksum value that you specified did
+        // > not match what the server received.
+        "BadDigest" => Some((ErrorKind::ChecksumMismatch, false)),
+        // > The Content-MD5 or checksum value that you specified is not valid.
+        "InvalidDigest" => Some((ErrorKind::InvalidInput, false)),
         _ => None,
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use futures::stream;
+
     use super::*;
 
     /// Error response example is from https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html
@@ -125,4 +161,91 @@ mod tests {
         assert_eq!(out.resource, "/mybucket/myfoto.jpg");
         assert_eq!(out.request_id, "4442587FB7D0A2F9");
     }
+
+    #[tokio::test]
+    async fn test_parse_error_classification() -> Result<()> {
+        let cases = vec![
+            (404, ErrorKind::NotFound, false),
+            (403, ErrorKind::PermissionDenied, false),
+            (429, ErrorKind::RateLimited, true),
+            (500, ErrorKind::Unexpected, true),
+            (503, ErrorKind::Unexpected, true),
+        ];
+
+        for (status, kind, temporary) in cases {
+            let body = IncomingAsyncBody::new(Box::new(oio::into_stream(stream::empty())), None);
+            let resp = Response::builder().status(status).body(body).unwrap();
+
+            let err = parse_error(resp).await?;
+            assert_eq!(err.kind(), kind, "status {status}");
+            assert_eq!(err.is_temporary(), temporary, "status {status}");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_with_service_context() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>SlowDown</Code>
+  <Message>Please reduce your request rate.</Message>
+  <Resource>/mybucket/myfoto.jpg</Resource>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>
+"#,
+        );
+        let body =
+            IncomingAsyncBody::new(Box::new(oio::into_stream(stream::iter(vec![Ok(bs)]))), None);
+        let resp = Response::builder().status(503).body(body).unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::RateLimited);
+        assert!(err.is_temporary());
+        assert_eq!(err.service_code(), Some("SlowDown"));
+        assert_eq!(
+            err.service_message(),
+            Some("Please reduce your request rate.")
+        );
+        assert_eq!(err.request_id(), Some("4442587FB7D0A2F9"));
+
+        // Request id in header should be used for responses without body.
+        let body = IncomingAsyncBody::new(Box::new(oio::into_stream(stream::empty())), None);
+        let resp = Response::builder()
+            .status(404)
+            .header("x-amz-request-id", "0A49CE4060975EAC")
+            .body(body)
+            .unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(err.service_code(), None);
+        assert_eq!(err.request_id(), Some("0A49CE4060975EAC"));
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_parse_error_object_lock_not_enabled() -> Result<()> {
+        let bs = bytes::Bytes::from(
+            r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>InvalidRequest</Code>
+  <Message>Bucket is missing Object Lock Configuration</Message>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>
+"#,
+        );
+        let body =
+            IncomingAsyncBody::new(Box::new(oio::into_stream(stream::iter(vec![Ok(bs)]))), None);
+        let resp = Response::builder().status(400).body(body).unwrap();
+
+        let err = parse_error(resp).await?;
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(!err.is_temporary());
+        assert_eq!(err.service_code(), Some("InvalidRequest"));
+        Ok(())
+    }
 }
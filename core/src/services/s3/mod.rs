@@ -17,8 +17,18 @@
 
 mod backend;
 pub use backend::S3Builder as S3;
+pub use backend::S3RequesterPays;
 
+mod checksum;
 mod core;
+mod credential;
+pub use credential::S3Credential;
+pub use credential::S3CredentialChain;
+pub use credential::S3EnvCredentialLoader;
+pub use credential::S3Imdsv2CredentialLoader;
+pub use credential::S3ProfileCredentialLoader;
+pub use credential::S3WebIdentityCredentialLoader;
 mod error;
 mod pager;
+mod reader;
 mod writer;
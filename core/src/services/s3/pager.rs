@@ -41,6 +41,8 @@ pub struct S3Pager {
 
     token: String,
     done: bool,
+
+    extensions: Extensions,
 }
 
 impl S3Pager {
@@ -50,6 +52,8 @@ impl S3Pager {
         delimiter: &str,
         limit: Option<usize>,
         start_after: Option<&str>,
+        continuation: Option<&str>,
+        extensions: Extensions,
     ) -> Self {
         Self {
             core,
@@ -59,8 +63,10 @@ impl S3Pager {
             limit,
             start_after: start_after.map(String::from),
 
-            token: "".to_string(),
+            token: continuation.unwrap_or_default().to_string(),
             done: false,
+
+            extensions,
         }
     }
 }
@@ -80,6 +86,7 @@ impl oio::Page for S3Pager {
                 &self.delimiter,
                 self.limit,
                 self.start_after.clone(),
+                &self.extensions,
             )
             .await?;
 
@@ -108,9 +115,9 @@ impl oio::Page for S3Pager {
         let mut entries = Vec::with_capacity(output.common_prefixes.len() + output.contents.len());
 
         for prefix in output.common_prefixes {
-            let de = oio::Entry::new(
+            let de = oio::Entry::new_delimited_dir(
                 &build_rel_path(&self.core.root, &prefix.prefix),
-                Metadata::new(EntryMode::DIR),
+                &self.delimiter,
             );
 
             entries.push(de);
@@ -141,6 +148,10 @@ impl oio::Page for S3Pager {
 
         Ok(Some(entries))
     }
+
+    fn continuation_token(&self) -> Option<String> {
+        (!self.done && !self.token.is_empty()).then(|| self.token.clone())
+    }
 }
 
 /// Output of ListBucket/ListObjects.
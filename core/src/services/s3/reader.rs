@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+
+use super::checksum::Verifier;
+use crate::raw::*;
+use crate::*;
+
+/// S3Reader will verify the checksum of content after all data has been
+/// read if the object carries one.
+pub struct S3Reader {
+    inner: IncomingAsyncBody,
+    /// The verifier of read content.
+    verifier: Option<Verifier>,
+    /// The content length of object.
+    ///
+    /// Users could stop reading once all content has been read without
+    /// reaching EOF, so we verify the checksum as soon as `size` reached.
+    size: Option<u64>,
+    consumed: u64,
+}
+
+impl S3Reader {
+    pub fn new(inner: IncomingAsyncBody, verifier: Option<Verifier>, size: Option<u64>) -> Self {
+        S3Reader {
+            inner,
+            verifier,
+            size,
+            consumed: 0,
+        }
+    }
+
+    fn update(&mut self, bs: &[u8]) -> Result<()> {
+        if let Some(verifier) = &mut self.verifier {
+            verifier.update(bs);
+
+            self.consumed += bs.len() as u64;
+            if Some(self.consumed) == self.size {
+                return self.verify();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify(&mut self) -> Result<()> {
+        match self.verifier.take() {
+            Some(verifier) => verifier
+                .verify()
+                .map_err(|err| err.with_operation("S3Reader::verify")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl oio::Read for S3Reader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.inner.poll_read(cx, buf))?;
+        if n == 0 && !buf.is_empty() {
+            self.verify()?;
+        } else {
+            self.update(&buf[..n])?;
+        }
+
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match ready!(self.inner.poll_next(cx)) {
+            Some(Ok(bs)) => match self.update(&bs) {
+                Ok(()) => Poll::Ready(Some(Ok(bs))),
+                Err(err) => Poll::Ready(Some(Err(err))),
+            },
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => match self.verify() {
+                Ok(()) => Poll::Ready(None),
+                Err(err) => Poll::Ready(Some(Err(err))),
+            },
+        }
+    }
+}
@@ -19,8 +19,11 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use chrono::DateTime;
+use chrono::Utc;
 use http::StatusCode;
 
+use super::checksum::S3Checksum;
 use super::core::*;
 use super::error::parse_error;
 use crate::raw::*;
@@ -36,31 +39,74 @@ pub struct S3Writer {
 impl S3Writer {
     pub fn new(core: Arc<S3Core>, path: &str, op: OpWrite) -> oio::MultipartUploadWriter<Self> {
         let write_min_size = core.write_min_size;
-
+        let write_memory_budget = core.write_memory_budget;
         let total_size = op.content_length();
-        let s3_writer = S3Writer {
+
+        oio::MultipartUploadWriter::new(Self::uploader(core, path, op), total_size)
+            .with_write_min_size(write_min_size)
+            .with_write_memory_budget(write_memory_budget)
+    }
+
+    /// Create a raw uploader which allows users to control parts manually.
+    pub fn uploader(core: Arc<S3Core>, path: &str, op: OpWrite) -> Self {
+        S3Writer {
             core,
             path: path.to_string(),
             op,
-        };
+        }
+    }
+
+    /// The checksum algorithm of write, fallback to the default one of
+    /// backend if not set.
+    fn checksum_algorithm(&self) -> Option<ChecksumAlgorithm> {
+        self.op
+            .checksum_algorithm()
+            .or(self.core.checksum_algorithm)
+    }
 
-        oio::MultipartUploadWriter::new(s3_writer, total_size).with_write_min_size(write_min_size)
+    fn checksum(&self, body: &AsyncBody) -> Result<Option<S3Checksum>> {
+        self.checksum_algorithm()
+            .map(|algorithm| S3Checksum::from_body(algorithm, body))
+            .transpose()
+    }
+
+    fn object_lock(&self) -> Option<(ObjectLockMode, DateTime<Utc>)> {
+        self.op.object_lock_mode().zip(self.op.retain_until())
+    }
+
+    /// Whether content md5 should be calculated for every request body.
+    ///
+    /// S3 requires `Content-MD5` or checksum to upload objects with
+    /// object lock, so we will calculate it if no checksum is sent.
+    fn auto_content_md5(&self) -> bool {
+        self.op.auto_content_md5()
+            || (self.object_lock().is_some() && self.checksum_algorithm().is_none())
     }
 }
 
 #[async_trait]
 impl oio::MultipartUploadWrite for S3Writer {
     async fn write_once(&self, size: u64, body: AsyncBody) -> Result<()> {
+        let content_md5 = match self.op.content_md5() {
+            Some(v) => Some(v.to_string()),
+            None if self.auto_content_md5() => Some(format_content_md5_of_body(&body)?),
+            None => None,
+        };
+        let checksum = self.checksum(&body)?;
+
         let mut req = self.core.s3_put_object_request(
             &self.path,
             Some(size),
             self.op.content_type(),
             self.op.content_disposition(),
             self.op.cache_control(),
+            content_md5.as_deref(),
+            checksum.as_ref(),
+            self.object_lock(),
             body,
         )?;
 
-        self.core.sign(&mut req).await?;
+        self.core.sign_with(&mut req, self.op.extensions()).await?;
 
         let resp = self.core.send(req).await?;
 
@@ -76,6 +122,17 @@ impl oio::MultipartUploadWrite for S3Writer {
     }
 
     async fn initiate_part(&self) -> Result<String> {
+        // The content md5 of whole object can't be checked by multipart
+        // upload, only the per part content md5 is supported.
+        if self.op.content_md5().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "content md5 of whole object is not supported by multipart upload, use auto content md5 instead",
+            )
+            .with_operation("S3Writer::initiate_part")
+            .with_context("path", &self.path));
+        }
+
         let resp = self
             .core
             .s3_initiate_multipart_upload(
@@ -83,6 +140,9 @@ impl oio::MultipartUploadWrite for S3Writer {
                 self.op.content_type(),
                 self.op.content_disposition(),
                 self.op.cache_control(),
+                self.checksum_algorithm(),
+                self.object_lock(),
+                self.op.extensions(),
             )
             .await?;
 
@@ -111,11 +171,24 @@ impl oio::MultipartUploadWrite for S3Writer {
         // AWS S3 requires part number must between [1..=10000]
         let part_number = part_number + 1;
 
-        let mut req =
-            self.core
-                .s3_upload_part_request(&self.path, upload_id, part_number, size, body)?;
+        let content_md5 = if self.auto_content_md5() {
+            Some(format_content_md5_of_body(&body)?)
+        } else {
+            None
+        };
+        let checksum = self.checksum(&body)?;
+
+        let mut req = self.core.s3_upload_part_request(
+            &self.path,
+            upload_id,
+            part_number,
+            size,
+            content_md5.as_deref(),
+            checksum.as_ref(),
+            body,
+        )?;
 
-        self.core.sign(&mut req).await?;
+        self.core.sign_with(&mut req, self.op.extensions()).await?;
 
         let resp = self.core.send(req).await?;
 
@@ -134,7 +207,11 @@ impl oio::MultipartUploadWrite for S3Writer {
 
                 resp.into_body().consume().await?;
 
-                Ok(oio::MultipartUploadPart { part_number, etag })
+                Ok(oio::MultipartUploadPart {
+                    part_number,
+                    etag,
+                    checksum: checksum.map(|v| v.value),
+                })
             }
             _ => Err(parse_error(resp).await?),
         }
@@ -147,15 +224,24 @@ impl oio::MultipartUploadWrite for S3Writer {
     ) -> Result<()> {
         let parts = parts
             .iter()
-            .map(|p| CompleteMultipartUploadRequestPart {
-                part_number: p.part_number,
-                etag: p.etag.clone(),
+            .map(|p| {
+                let mut part = CompleteMultipartUploadRequestPart {
+                    part_number: p.part_number,
+                    etag: p.etag.clone(),
+                    ..Default::default()
+                };
+                match self.checksum_algorithm() {
+                    Some(ChecksumAlgorithm::Crc32c) => part.checksum_crc32c = p.checksum.clone(),
+                    Some(ChecksumAlgorithm::Sha256) => part.checksum_sha256 = p.checksum.clone(),
+                    _ => {}
+                }
+                part
             })
             .collect();
 
         let resp = self
             .core
-            .s3_complete_multipart_upload(&self.path, upload_id, parts)
+            .s3_complete_multipart_upload(&self.path, upload_id, parts, self.op.extensions())
             .await?;
 
         let status = resp.status();
@@ -173,7 +259,7 @@ impl oio::MultipartUploadWrite for S3Writer {
     async fn abort_part(&self, upload_id: &str) -> Result<()> {
         let resp = self
             .core
-            .s3_abort_multipart_upload(&self.path, upload_id)
+            .s3_abort_multipart_upload(&self.path, upload_id, self.op.extensions())
             .await?;
         match resp.status() {
             // s3 returns code 204 if abort succeeds.
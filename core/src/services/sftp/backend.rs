@@ -23,7 +23,6 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use async_trait::async_trait;
-use futures::StreamExt;
 use log::debug;
 use openssh::KnownHosts;
 use openssh::SessionBuilder;
@@ -251,6 +250,7 @@ impl Accessor for SftpBackend {
 
                 copy: self.copyable,
                 rename: true,
+                rename_atomic: true,
                 append: true,
 
                 ..Default::default()
@@ -405,38 +405,9 @@ impl Accessor for SftpBackend {
         let mut fs = client.fs();
         fs.set_cwd(&self.root);
 
+        // Non-empty dir will be rejected by server, recursive delete will be
+        // completed by listing and deleting all children instead.
         if path.ends_with('/') {
-            let file_path = format!("./{}", path);
-            let mut dir = match fs.open_dir(&file_path).await {
-                Ok(dir) => dir,
-                Err(e) => {
-                    if is_not_found(&e) {
-                        return Ok(RpDelete::default());
-                    } else {
-                        return Err(e.into());
-                    }
-                }
-            }
-            .read_dir()
-            .boxed();
-
-            while let Some(file) = dir.next().await {
-                let file = file?;
-                let file_name = file.filename().to_str();
-                if file_name == Some(".") || file_name == Some("..") {
-                    continue;
-                }
-                let file_path = Path::new(&self.root).join(file.filename());
-                self.delete(
-                    file_path.to_str().ok_or(Error::new(
-                        ErrorKind::Unexpected,
-                        "unable to convert file path to str",
-                    ))?,
-                    OpDelete::default(),
-                )
-                .await?;
-            }
-
             match fs.remove_dir(path).await {
                 Err(e) if !is_not_found(&e) => {
                     return Err(e.into());
@@ -18,29 +18,40 @@
 use std::pin::Pin;
 
 use async_trait::async_trait;
+use futures::Stream;
 use futures::StreamExt;
 use openssh_sftp_client::fs::DirEntry;
 use openssh_sftp_client::fs::ReadDir;
 
 use crate::raw::oio;
+use crate::Error;
 use crate::Result;
 
+/// The default entries returned in one page if limit is not specified.
+const DEFAULT_PAGE_SIZE: usize = 1000;
+
+/// SftpPager streams entries of a directory page by page.
+///
+/// Servers return entries of a directory in batches, and `ReadDir` only
+/// fetches the next batch after current one is consumed. So we only hold
+/// at most one page (and one batch) in memory no matter how large the
+/// directory is.
 pub struct SftpPager {
     dir: Pin<Box<ReadDir>>,
     prefix: String,
     limit: usize,
+    done: bool,
 }
 
 impl SftpPager {
     pub fn new(dir: ReadDir, path: String, limit: Option<usize>) -> Self {
         let prefix = if path == "/" { "".to_owned() } else { path };
 
-        let limit = limit.unwrap_or(usize::MAX);
-
         SftpPager {
             dir: Box::pin(dir),
             prefix,
-            limit,
+            limit: limit.unwrap_or(DEFAULT_PAGE_SIZE).max(1),
+            done: false,
         }
     }
 }
@@ -48,32 +59,56 @@ impl SftpPager {
 #[async_trait]
 impl oio::Page for SftpPager {
     async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
-        if self.limit == 0 {
+        if self.done {
             return Ok(None);
         }
 
-        let item = self.dir.next().await;
+        let prefix = self.prefix.as_str();
+        let mut dir = self.dir.by_ref().map(|v| v.map_err(Error::from));
+        let (entries, done) = next_page(&mut dir, self.limit, |e| map_entry(prefix, e)).await?;
+        self.done = done;
 
-        match item {
-            Some(Ok(e)) => {
-                if e.filename().to_str() == Some(".") || e.filename().to_str() == Some("..") {
-                    self.next().await
-                } else {
-                    self.limit -= 1;
-                    Ok(Some(vec![map_entry(self.prefix.as_str(), e.clone())]))
-                }
-            }
-            Some(Err(e)) => Err(e.into()),
-            None => Ok(None),
+        if entries.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(entries))
         }
     }
 }
 
-fn map_entry(prefix: &str, value: DirEntry) -> oio::Entry {
+/// Collect at most `limit` entries from the stream, returns the entries
+/// and whether the stream has been exhausted.
+///
+/// Items that `f` returns `None` will be skipped.
+async fn next_page<S, T>(
+    s: &mut S,
+    limit: usize,
+    f: impl Fn(T) -> Option<oio::Entry>,
+) -> Result<(Vec<oio::Entry>, bool)>
+where
+    S: Stream<Item = Result<T>> + Unpin,
+{
+    let mut entries = Vec::new();
+    while entries.len() < limit {
+        match s.next().await {
+            Some(item) => entries.extend(f(item?)),
+            None => return Ok((entries, true)),
+        }
+    }
+
+    Ok((entries, false))
+}
+
+fn map_entry(prefix: &str, value: DirEntry) -> Option<oio::Entry> {
+    let filename = value.filename().to_str().unwrap();
+    if filename == "." || filename == ".." {
+        return None;
+    }
+
     let path = format!(
         "{}{}{}",
         prefix,
-        value.filename().to_str().unwrap(),
+        filename,
         if value.file_type().unwrap().is_dir() {
             "/"
         } else {
@@ -81,5 +116,54 @@ fn map_entry(prefix: &str, value: DirEntry) -> oio::Entry {
         }
     );
 
-    oio::Entry::new(path.as_str(), value.metadata().into())
+    Some(oio::Entry::new(path.as_str(), value.metadata().into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use futures::stream;
+
+    use super::*;
+    use crate::EntryMode;
+    use crate::Metadata;
+
+    #[tokio::test]
+    async fn test_next_page_with_large_dir() -> Result<()> {
+        const TOTAL: usize = 100_000;
+        const LIMIT: usize = 1000;
+
+        // Count how many entries have been fetched from the fake dir.
+        let fetched = AtomicUsize::new(0);
+        let mut dir = stream::iter(0..TOTAL + 2).map(|i| {
+            fetched.fetch_add(1, Ordering::SeqCst);
+            // Servers will return `.` and `..` too.
+            Ok(match i {
+                0 => ".".to_string(),
+                1 => "..".to_string(),
+                _ => format!("file-{i}"),
+            })
+        });
+        let f = |name: String| {
+            (name != "." && name != "..")
+                .then(|| oio::Entry::new(&name, Metadata::new(EntryMode::FILE)))
+        };
+
+        let mut total = 0;
+        loop {
+            let (entries, done) = next_page(&mut dir, LIMIT, f).await?;
+            assert!(entries.len() <= LIMIT);
+            total += entries.len();
+            // Only entries of current page are fetched.
+            assert!(fetched.load(Ordering::SeqCst) <= total + 2);
+            if done {
+                break;
+            }
+            assert_eq!(entries.len(), LIMIT);
+        }
+        assert_eq!(total, TOTAL);
+        Ok(())
+    }
 }
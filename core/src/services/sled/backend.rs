@@ -200,3 +200,46 @@ impl kv::Adapter for Adapter {
 fn parse_error(err: sled::Error) -> Error {
     Error::new(ErrorKind::Unexpected, "error from sled").set_source(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_isolation() -> Result<()> {
+        let datadir = std::env::temp_dir().join(format!("opendal-sled-{}", uuid::Uuid::new_v4()));
+        let datadir = datadir.to_string_lossy().to_string();
+
+        let new_op = |tree: &str| -> Result<BlockingOperator> {
+            let mut builder = SledBuilder::default();
+            builder.datadir(&datadir).tree(tree);
+            Ok(Operator::new(builder)?.finish().blocking())
+        };
+
+        // sled holds a lock on datadir, open one tree at a time.
+        {
+            let op = new_op("tree_a")?;
+            op.write("dir/a", "a")?;
+        }
+        {
+            let op = new_op("tree_b")?;
+            op.write("dir/b", "b")?;
+            assert_eq!(op.read("dir/b")?, b"b");
+            assert!(!op.is_exist("dir/a")?);
+
+            let entries: Vec<String> = op
+                .scan("dir/")?
+                .map(|e| e.map(|e| e.path().to_string()))
+                .collect::<Result<_>>()?;
+            assert_eq!(entries, vec!["dir/b".to_string()]);
+        }
+        {
+            let op = new_op("tree_a")?;
+            assert_eq!(op.read("dir/a")?, b"a");
+            assert!(!op.is_exist("dir/b")?);
+        }
+
+        let _ = std::fs::remove_dir_all(&datadir);
+        Ok(())
+    }
+}
@@ -19,7 +19,11 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Buf;
+use http::HeaderMap;
+use http::Method;
 use http::StatusCode;
+use http::Uri;
 use log::debug;
 
 use super::core::*;
@@ -41,7 +45,7 @@ use crate::*;
 /// - [ ] rename
 /// - [ ] list
 /// - [ ] scan
-/// - [ ] presign
+/// - [x] presign
 /// - [ ] blocking
 ///
 /// # Configuration
@@ -57,6 +61,11 @@ use crate::*;
 /// service_role_key(Secret key). The former one can only write public resources while the latter one
 /// can access all resources. Note that if you want to read public resources, do not set the key.
 ///
+/// ## Presign
+///
+/// Only `presign_read` is supported, which will create a signed url via supabase. The key is
+/// required to create signed urls.
+///
 /// # Example
 ///
 /// ```no_run
@@ -91,6 +100,7 @@ pub struct SupabaseBuilder {
     // todo: optional file_size_limit, currently 0
     // todo: optional allowed_mime_types, currently only string
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for SupabaseBuilder {
@@ -153,6 +163,17 @@ impl SupabaseBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for SupabaseBuilder {
@@ -167,6 +188,9 @@ impl Builder for SupabaseBuilder {
         map.get("endpoint").map(|v| builder.endpoint(v));
         map.get("key").map(|v| builder.key(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -178,7 +202,7 @@ impl Builder for SupabaseBuilder {
 
         let endpoint = self.endpoint.take().unwrap_or_default();
 
-        let http_client = if let Some(client) = self.http_client.take() {
+        let mut http_client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -186,6 +210,12 @@ impl Builder for SupabaseBuilder {
                     .with_context("service", Scheme::Supabase)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            http_client = http_client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Supabase)
+            })?;
+        }
 
         let key = self.key.as_ref().map(|k| k.to_owned());
 
@@ -227,6 +257,9 @@ impl Accessor for SupabaseBackend {
                 create_dir: true,
                 delete: true,
 
+                presign: true,
+                presign_read: true,
+
                 ..Default::default()
             });
 
@@ -326,4 +359,119 @@ impl Accessor for SupabaseBackend {
             }
         }
     }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        if !matches!(args.operation(), PresignOperation::Read(_)) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "supabase only supports presign read",
+            ));
+        }
+
+        let resp = self.core.supabase_sign_object(path, args.expire()).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                let output: SignObjectResponse =
+                    serde_json::from_reader(bs.reader()).map_err(new_json_deserialize_error)?;
+
+                let url = format!("{}/storage/v1{}", self.core.endpoint, output.signed_url);
+                let uri: Uri = url.parse().map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "signed url is invalid")
+                        .with_context("url", &url)
+                        .set_source(err)
+                })?;
+
+                Ok(RpPresign::new(PresignedRequest::new(
+                    Method::GET,
+                    uri,
+                    HeaderMap::new(),
+                )))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wiremock::matchers::body_json;
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    fn mock_operator(endpoint: &str, key: Option<&str>) -> Operator {
+        let mut builder = SupabaseBuilder::default();
+        builder.bucket("bucket").endpoint(endpoint);
+        if let Some(key) = key {
+            builder.key(key);
+        }
+
+        Operator::new(builder).expect("must build").finish()
+    }
+
+    #[tokio::test]
+    async fn test_presign_read() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/storage/v1/object/sign/bucket/test"))
+            .and(header("authorization", "Bearer key"))
+            .and(body_json(serde_json::json!({ "expiresIn": 3600 })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(
+                    r#"{"signedURL": "/object/sign/bucket/test?token=signed-token"}"#,
+                ),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri(), Some("key"));
+        let signed = op.presign_read("test", Duration::from_secs(3600)).await?;
+
+        assert_eq!(signed.method(), Method::GET);
+        assert_eq!(
+            signed.uri().to_string(),
+            format!(
+                "{}/storage/v1/object/sign/bucket/test?token=signed-token",
+                mock_server.uri()
+            )
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_presign_read_permission_denied() -> Result<()> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/storage/v1/object/sign/bucket/test"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                r#"{"statusCode": "403", "error": "Unauthorized", "message": "invalid signature"}"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let op = mock_operator(&mock_server.uri(), Some("invalid"));
+        let err = op
+            .presign_read("test", Duration::from_secs(3600))
+            .await
+            .expect_err("presign must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        let op = mock_operator(&mock_server.uri(), None);
+        let err = op
+            .presign_read("test", Duration::from_secs(3600))
+            .await
+            .expect_err("presign without key must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
 }
@@ -16,12 +16,15 @@
 // under the License.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::raw::*;
 use crate::*;
@@ -204,6 +207,35 @@ impl SupabaseCore {
             .map_err(new_request_build_error)
     }
 
+    /// Create a signed url for the object, which expires after given duration.
+    ///
+    /// ref: https://supabase.github.io/storage/#/object/post_object_sign__bucketName___wildcard_
+    pub fn supabase_sign_object_request(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/storage/v1/object/sign/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        // Supabase requires expiresIn to be at least 1 second.
+        let body = serde_json::to_vec(&SignObjectRequest {
+            expires_in: expire.as_secs().max(1),
+        })
+        .map_err(new_json_serialize_error)?;
+
+        Request::post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_LENGTH, body.len())
+            .body(AsyncBody::Bytes(body.into()))
+            .map_err(new_request_build_error)
+    }
+
     pub fn supabase_get_object_info_auth_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let url = format!(
@@ -267,4 +299,34 @@ impl SupabaseCore {
         self.sign(&mut req)?;
         self.send(req).await
     }
+
+    pub async fn supabase_sign_object(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        if self.key.is_none() {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "key is required to create signed url",
+            ));
+        }
+
+        let mut req = self.supabase_sign_object_request(path, expire)?;
+        self.sign(&mut req)?;
+        self.send(req).await
+    }
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignObjectRequest {
+    pub expires_in: u64,
+}
+
+#[derive(Default, Debug, Deserialize)]
+pub struct SignObjectResponse {
+    /// The signed path like `/object/sign/<bucket>/<path>?token=<token>`.
+    #[serde(rename = "signedURL", alias = "signedUrl")]
+    pub signed_url: String,
 }
@@ -18,6 +18,7 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::fs;
 
 use async_trait::async_trait;
 use tikv_client::Config;
@@ -35,7 +36,7 @@ use crate::*;
 /// TiKV backend builder
 #[derive(Clone, Default)]
 pub struct TikvBuilder {
-    /// network address of the TiKV service.
+    /// network address of the PD endpoints of TiKV service.
     endpoints: Option<Vec<String>>,
     /// whether using insecure connection to TiKV
     insecure: bool,
@@ -48,7 +49,10 @@ pub struct TikvBuilder {
 }
 
 impl TikvBuilder {
-    /// Set the network address of the TiKV service.
+    /// Set the network address of the PD endpoints of TiKV service.
+    ///
+    /// Multiple endpoints could be set for high availability, the client
+    /// will try the next one if the current PD is unavailable.
     pub fn endpoints(&mut self, endpoints: Vec<String>) -> &mut Self {
         if !endpoints.is_empty() {
             self.endpoints = Some(endpoints)
@@ -57,6 +61,9 @@ impl TikvBuilder {
     }
 
     /// Set the insecure connection to TiKV.
+    ///
+    /// Either `insecure` or all of `ca_path`, `cert_path` and `key_path`
+    /// must be set.
     pub fn insecure(&mut self) -> &mut Self {
         self.insecure = true;
         self
@@ -95,7 +102,12 @@ impl Builder for TikvBuilder {
         let mut builder = TikvBuilder::default();
 
         map.get("endpoints")
-            .map(|v| v.split(',').map(|s| s.to_owned()).collect::<Vec<String>>())
+            .map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_owned())
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<String>>()
+            })
             .map(|v| builder.endpoints(v));
         map.get("insecure")
             .filter(|v| *v == "on" || *v == "true")
@@ -126,17 +138,56 @@ impl Builder for TikvBuilder {
             )?;
         }
 
+        let config = if self.insecure {
+            None
+        } else {
+            let (Some(ca_path), Some(cert_path), Some(key_path)) =
+                (&self.ca_path, &self.cert_path, &self.key_path)
+            else {
+                return Err(Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "ca_path, cert_path and key_path are required for tls connection",
+                )
+                .with_context("service", Scheme::Tikv)
+                .with_context("endpoints", format!("{:?}", endpoints)));
+            };
+
+            check_tls_file("ca_path", ca_path)?;
+            check_tls_file("cert_path", cert_path)?;
+            check_tls_file("key_path", key_path)?;
+
+            Some(Config::default().with_security(ca_path, cert_path, key_path))
+        };
+
         Ok(Backend::new(Adapter {
             client: OnceCell::new(),
             endpoints,
-            insecure: self.insecure,
-            ca_path: self.ca_path.clone(),
-            cert_path: self.cert_path.clone(),
-            key_path: self.key_path.clone(),
+            config,
         }))
     }
 }
 
+/// Make sure the tls file exists and is PEM encoded, so that we can fail
+/// clearly at build instead of the first connection.
+fn check_tls_file(key: &'static str, path: &str) -> Result<()> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        Error::new(ErrorKind::ConfigInvalid, "tls file is not readable")
+            .with_context("service", Scheme::Tikv)
+            .with_context(key, path)
+            .set_source(e)
+    })?;
+
+    if !content.contains("-----BEGIN ") {
+        return Err(
+            Error::new(ErrorKind::ConfigInvalid, "tls file is not PEM encoded")
+                .with_context("service", Scheme::Tikv)
+                .with_context(key, path),
+        );
+    }
+
+    Ok(())
+}
+
 /// Backend for TiKV service
 pub type Backend = kv::Backend<Adapter>;
 
@@ -144,16 +195,15 @@ pub type Backend = kv::Backend<Adapter>;
 pub struct Adapter {
     client: OnceCell<RawClient>,
     endpoints: Vec<String>,
-    insecure: bool,
-    ca_path: Option<String>,
-    cert_path: Option<String>,
-    key_path: Option<String>,
+    /// The config with tls security, `None` means insecure connection.
+    config: Option<Config>,
 }
 
 impl Debug for Adapter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut ds = f.debug_struct("Adapter");
         ds.field("endpoints", &self.endpoints);
+        ds.field("insecure", &self.config.is_none());
         ds.finish()
     }
 }
@@ -163,26 +213,13 @@ impl Adapter {
         if let Some(client) = self.client.get() {
             return Ok(client.clone());
         }
-        let client = if self.insecure {
-            RawClient::new(self.endpoints.clone())
+        let client = match &self.config {
+            None => RawClient::new(self.endpoints.clone())
                 .await
-                .map_err(parse_tikv_config_error)?
-        } else if self.ca_path.is_some() && self.key_path.is_some() && self.cert_path.is_some() {
-            let (ca_path, key_path, cert_path) = (
-                self.ca_path.clone().unwrap(),
-                self.key_path.clone().unwrap(),
-                self.cert_path.clone().unwrap(),
-            );
-            let config = Config::default().with_security(ca_path, cert_path, key_path);
-            RawClient::new_with_config(self.endpoints.clone(), config)
+                .map_err(parse_tikv_config_error)?,
+            Some(config) => RawClient::new_with_config(self.endpoints.clone(), config.clone())
                 .await
-                .map_err(parse_tikv_config_error)?
-        } else {
-            return Err(
-                Error::new(ErrorKind::ConfigInvalid, "invalid configuration")
-                    .with_context("service", Scheme::Tikv)
-                    .with_context("endpoints", format!("{:?}", self.endpoints)),
-            );
+                .map_err(parse_tikv_config_error)?,
         };
         self.client.set(client.clone()).ok();
         Ok(client)
@@ -238,3 +275,83 @@ fn parse_tikv_config_error(e: tikv_client::Error) -> Error {
         .with_context("service", Scheme::Tikv)
         .set_source(e)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PEM: &str = "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n";
+
+    fn tls_file(content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("opendal-tikv-{}.pem", uuid::Uuid::new_v4()));
+        fs::write(&path, content).expect("write tls file must succeed");
+        path.to_string_lossy().to_string()
+    }
+
+    fn builder(ca: &str, cert: &str, key: &str) -> TikvBuilder {
+        let mut builder = TikvBuilder::default();
+        builder
+            .endpoints(vec![
+                "127.0.0.1:2379".to_string(),
+                "127.0.0.1:2382".to_string(),
+            ])
+            .ca_path(ca)
+            .cert_path(cert)
+            .key_path(key);
+        builder
+    }
+
+    #[test]
+    fn test_from_map_endpoints() {
+        let map = HashMap::from([(
+            "endpoints".to_string(),
+            "127.0.0.1:2379, 127.0.0.1:2382,".to_string(),
+        )]);
+        let builder = TikvBuilder::from_map(map);
+        assert_eq!(
+            builder.endpoints,
+            Some(vec![
+                "127.0.0.1:2379".to_string(),
+                "127.0.0.1:2382".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_build_with_tls() {
+        let path = &tls_file(PEM);
+
+        let backend = builder(path, path, path).build();
+        assert!(backend.is_ok());
+    }
+
+    #[test]
+    fn test_build_without_tls() {
+        let mut builder = TikvBuilder::default();
+        builder.endpoints(vec!["127.0.0.1:2379".to_string()]);
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        let path = &tls_file(PEM);
+        builder
+            .endpoints(vec!["127.0.0.1:2379".to_string()])
+            .ca_path(path)
+            .cert_path(path);
+        let err = builder.build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[test]
+    fn test_build_with_invalid_tls_file() {
+        let path = &tls_file(PEM);
+
+        let err = builder(path, path, "/path/to/not_exist.pem")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        let invalid = tls_file("not a certificate");
+        let err = builder(&invalid, path, path).build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+}
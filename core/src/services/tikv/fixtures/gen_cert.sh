@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+# Generate the certificates for the tls test of tikv and pd into /tmp/tikv/ssl.
+set -ex
+
+SSL_DIR=/tmp/tikv/ssl
+mkdir -p $SSL_DIR
+cd $SSL_DIR
+
+cat >openssl.cnf <<CNF
+[req]
+distinguished_name = req_distinguished_name
+[req_distinguished_name]
+[v3_req]
+basicConstraints = CA:FALSE
+keyUsage = nonRepudiation, digitalSignature, keyEncipherment
+extendedKeyUsage = serverAuth, clientAuth
+subjectAltName = @alt_names
+[alt_names]
+IP.1 = 127.0.0.1
+DNS.1 = localhost
+CNF
+
+openssl genrsa -out ca-key.pem 4096
+openssl req -new -x509 -days 1000 -key ca-key.pem -out ca.pem -subj "/CN=tikv-test-ca"
+
+for name in pd-server tikv-server client; do
+  openssl genrsa -out $name-key.pem 2048
+  openssl req -new -key $name-key.pem -out $name.csr -subj "/CN=$name"
+  openssl x509 -req -days 365 -CA ca.pem -CAkey ca-key.pem -CAcreateserial \
+    -in $name.csr -out $name.pem -extensions v3_req -extfile openssl.cnf
+done
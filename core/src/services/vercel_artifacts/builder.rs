@@ -71,6 +71,7 @@ use crate::*;
 pub struct VercelArtifactsBuilder {
     access_token: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
 }
 
 impl VercelArtifactsBuilder {
@@ -92,6 +93,17 @@ impl VercelArtifactsBuilder {
         self.http_client = Some(http_client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for VercelArtifactsBuilder {
@@ -102,11 +114,14 @@ impl Builder for VercelArtifactsBuilder {
     fn from_map(map: HashMap<String, String>) -> Self {
         let mut builder = Self::default();
         map.get("access_token").map(|v| builder.access_token(v));
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
     fn build(&mut self) -> Result<Self::Accessor> {
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -114,6 +129,12 @@ impl Builder for VercelArtifactsBuilder {
                     .with_context("service", Scheme::VercelArtifacts)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::VercelArtifacts)
+            })?;
+        }
 
         match self.access_token.clone() {
             Some(access_token) => Ok(VercelArtifactsBackend {
@@ -303,6 +303,7 @@ pub struct WasabiBuilder {
     enable_virtual_host_style: bool,
 
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
     customed_credential_load: Option<Box<dyn AwsCredentialLoad>>,
 }
 
@@ -639,6 +640,17 @@ impl WasabiBuilder {
         self
     }
 
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
     /// Check if `bucket` is valid
     /// `bucket` must be not empty and if `enable_virtual_host_style` is true
     /// it couldn't contain dot(.) character
@@ -737,6 +749,9 @@ impl Builder for WasabiBuilder {
         map.get("default_storage_class")
             .map(|v| builder.default_storage_class(v));
 
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
+
         builder
     }
 
@@ -804,7 +819,7 @@ impl Builder for WasabiBuilder {
                 })?),
             };
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -812,6 +827,12 @@ impl Builder for WasabiBuilder {
                     .with_context("service", Scheme::S3)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::S3)
+            })?;
+        }
 
         let mut cfg = AwsConfig::default();
         if !self.disable_config_load {
@@ -912,12 +933,14 @@ impl Accessor for WasabiBackend {
                 read_with_range: true,
 
                 write: true,
+                write_total_max_size: Some(5 * 1024 * 1024 * 1024 * 1024),
                 create_dir: true,
                 delete: true,
                 copy: true,
                 rename: true,
 
                 list: true,
+                list_max_limit: Some(1000),
                 list_without_delimiter: true,
                 list_with_delimiter_slash: true,
 
@@ -927,6 +950,7 @@ impl Accessor for WasabiBackend {
                 presign_write: true,
 
                 batch: true,
+                batch_max_operations: Some(1000),
 
                 ..Default::default()
             });
@@ -1078,7 +1102,17 @@ impl Accessor for WasabiBackend {
             .with_context("length", ops.len().to_string()));
         }
 
-        let paths = ops.into_iter().map(|(p, _)| p).collect();
+        let paths = ops
+            .into_iter()
+            .map(|(p, op)| match op {
+                BatchOperation::Delete(_) => Ok(p),
+                BatchOperation::Write(..) => Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "wasabi services don't support batch write",
+                )
+                .with_context("path", p)),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let resp = self.core.delete_objects(paths).await?;
 
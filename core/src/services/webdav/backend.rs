@@ -20,17 +20,26 @@ use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use http::header;
 use http::HeaderMap;
 use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
+use log::warn;
 
 use super::error::parse_error;
+use super::error::parse_lock_conflict_error;
+use super::lock::build_lock_body;
+use super::lock::format_timeout;
+use super::lock::parse_lock;
+use super::lock::WebdavLock;
+use super::lock::WebdavLockScope;
 use super::pager::Multistatus;
 use super::pager::WebdavPager;
 use super::writer::WebdavWriter;
@@ -60,10 +69,39 @@ use crate::*;
 /// Bazel Remote Caching and Ccache HTTP Storage is also part of this service.
 /// Users can use `webdav` to connect those services.
 ///
+/// # Locking
+///
+/// [`WebdavBackend`] provides `LOCK` and `UNLOCK` support for collaborative
+/// editing, which is not part of [`Operator`]:
+///
+/// - [`WebdavBackend::lock`] acquires an exclusive or shared lock and returns
+///   the lock token. Lock conflicts will return [`ErrorKind::ConditionNotMatch`].
+/// - [`WebdavBackend::refresh_lock`] refreshes the timeout of lock.
+/// - [`WebdavBackend::unlock`] releases the lock.
+/// - [`WebdavBackend::write_with_lock`] writes locked resource with the token
+///   carried in `If` header.
+///
+/// Not all servers support locking, and some of them (like nginx) only
+/// support exclusive lock.
+///
+/// # Copy and Rename
+///
+/// `copy` and `rename` are implemented via `COPY` and `MOVE`, dead properties
+/// are preserved by servers, but last modified will always be updated by
+/// servers.
+///
+/// Some servers drop the content type while copying or moving. With
+/// [`WebdavBuilder::enable_preserve_content_type`], content type will be
+/// checked and set back via `PROPPATCH` on a best-effort basis.
+///
+/// `copy` with `if_not_exists` sends `Overwrite: F` and returns
+/// [`ErrorKind::AlreadyExists`] if the destination exists.
+///
 /// # Configuration
 ///
 /// - `endpoint`: set the endpoint for webdav
 /// - `root`: Set the work directory for backend
+/// - `enable_preserve_content_type`: Set content type back after copy or rename
 ///
 /// You can refer to [`WebdavBuilder`]'s docs for more information
 ///
@@ -90,6 +128,37 @@ use crate::*;
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Lock resource
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::services::Webdav;
+/// use opendal::services::WebdavLockScope;
+/// use opendal::Builder;
+/// use opendal::OperatorBuilder;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Webdav::default();
+///     builder.endpoint("http://127.0.0.1:8080");
+///
+///     let backend = builder.build()?;
+///     // Operator can still be built upon the same backend.
+///     let _op = OperatorBuilder::new(backend.clone()).finish();
+///
+///     let lock = backend
+///         .lock("docs/report.md", WebdavLockScope::Exclusive, Some(Duration::from_secs(60)))
+///         .await?;
+///     backend
+///         .write_with_lock("docs/report.md", lock.token(), "Hello, World!".into())
+///         .await?;
+///     backend.unlock("docs/report.md", lock.token()).await?;
+///     Ok(())
+/// }
+/// ```
 #[derive(Default)]
 pub struct WebdavBuilder {
     endpoint: Option<String>,
@@ -98,6 +167,8 @@ pub struct WebdavBuilder {
     token: Option<String>,
     root: Option<String>,
     http_client: Option<HttpClient>,
+    http_user_agent: Option<String>,
+    enable_preserve_content_type: bool,
 }
 
 impl Debug for WebdavBuilder {
@@ -105,6 +176,10 @@ impl Debug for WebdavBuilder {
         let mut de = f.debug_struct("Builder");
         de.field("endpoint", &self.endpoint);
         de.field("root", &self.root);
+        de.field(
+            "enable_preserve_content_type",
+            &self.enable_preserve_content_type,
+        );
 
         de.finish()
     }
@@ -175,6 +250,30 @@ impl WebdavBuilder {
         self.http_client = Some(client);
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
+
+    /// Enable preserving content type for copy and rename.
+    ///
+    /// If enabled, the content type of the source will be set back via
+    /// `PROPPATCH` if servers drop it while copying or moving. This costs
+    /// extra `PROPFIND` requests, and failures of `PROPPATCH` will be
+    /// logged and ignored.
+    ///
+    /// This is disabled by default.
+    pub fn enable_preserve_content_type(&mut self) -> &mut Self {
+        self.enable_preserve_content_type = true;
+        self
+    }
 }
 
 impl Builder for WebdavBuilder {
@@ -189,6 +288,12 @@ impl Builder for WebdavBuilder {
         map.get("username").map(|v| builder.username(v));
         map.get("password").map(|v| builder.password(v));
         map.get("token").map(|v| builder.token(v));
+        map.get("enable_preserve_content_type")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_preserve_content_type());
+
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
 
         builder
     }
@@ -216,7 +321,7 @@ impl Builder for WebdavBuilder {
         let root = normalize_root(&self.root.take().unwrap_or_default());
         debug!("backend use root {}", root);
 
-        let client = if let Some(client) = self.http_client.take() {
+        let mut client = if let Some(client) = self.http_client.take() {
             client
         } else {
             HttpClient::new().map_err(|err| {
@@ -224,6 +329,12 @@ impl Builder for WebdavBuilder {
                     .with_context("service", Scheme::Webdav)
             })?
         };
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Webdav)
+            })?;
+        }
 
         let mut auth = None;
         if let Some(username) = &self.username {
@@ -243,11 +354,14 @@ impl Builder for WebdavBuilder {
             authorization: auth,
             root,
             client,
+            enable_preserve_content_type: self.enable_preserve_content_type,
         })
     }
 }
 
-/// Backend is used to serve `Accessor` support for http.
+/// Backend is used to serve `Accessor` support for webdav.
+///
+/// Please refer to [`Webdav`](crate::services::Webdav) for locking support.
 #[derive(Clone)]
 pub struct WebdavBackend {
     endpoint: String,
@@ -256,6 +370,7 @@ pub struct WebdavBackend {
     client: HttpClient,
 
     authorization: Option<String>,
+    enable_preserve_content_type: bool,
 }
 
 impl Debug for WebdavBackend {
@@ -291,11 +406,14 @@ impl Accessor for WebdavBackend {
 
                 write: true,
                 write_can_sink: true,
+                write_can_chunked: true,
 
                 create_dir: true,
                 delete: true,
+                delete_with_recursive: true,
 
                 copy: true,
+                copy_with_if_not_exists: true,
 
                 rename: true,
 
@@ -328,7 +446,7 @@ impl Accessor for WebdavBackend {
     }
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        if args.content_length().is_none() {
+        if args.content_length().is_none() && !args.chunked_unknown_size() {
             return Err(Error::new(
                 ErrorKind::Unsupported,
                 "write without content length is not supported",
@@ -348,26 +466,47 @@ impl Accessor for WebdavBackend {
     /// if the source exists first.
     ///
     /// For example: <https://github.com/apache/incubator-opendal/pull/2809>
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        if let Err(err) = self.stat(from, OpStat::default()).await {
-            if err.kind() == ErrorKind::NotFound {
-                return Err(err);
-            }
-        }
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let source = match self.stat_complete(from).await {
+            Ok(meta) => Some(meta),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Err(err),
+            Err(_) => None,
+        };
 
         self.ensure_parent_path(to).await?;
 
-        let resp = self.webdav_copy(from, to).await?;
+        let resp = self.webdav_copy(from, to, !args.if_not_exists()).await?;
 
         let status = resp.status();
 
         match status {
-            StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(RpCopy::default()),
+            StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                self.preserve_content_type(to, source).await;
+                Ok(RpCopy::default())
+            }
+            // `Overwrite: F` is specified but the destination exists.
+            StatusCode::PRECONDITION_FAILED if args.if_not_exists() => {
+                resp.into_body().consume().await?;
+                Err(
+                    Error::new(ErrorKind::AlreadyExists, "the target path already exists")
+                        .with_operation(Operation::Copy)
+                        .with_context("from", from)
+                        .with_context("to", to),
+                )
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
 
     async fn rename(&self, from: &str, to: &str, _args: OpRename) -> Result<RpRename> {
+        // Keep the source metadata to check if content type is lost after moving.
+        let source = if self.enable_preserve_content_type {
+            self.stat_complete(from).await.ok()
+        } else {
+            None
+        };
+
         self.ensure_parent_path(to).await?;
 
         let resp = self.webdav_move(from, to).await?;
@@ -375,7 +514,11 @@ impl Accessor for WebdavBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(RpRename::default()),
+            StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                self.preserve_content_type(to, source).await;
+                Ok(RpRename::default())
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
@@ -422,7 +565,20 @@ impl Accessor for WebdavBackend {
         }
     }
 
-    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        // DELETE on a collection always acts as if `Depth: infinity`, so we
+        // must make sure it's empty if recursive is not set.
+        //
+        // Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-9.6.1>
+        if path.ends_with('/') && !args.recursive() && self.is_non_empty_dir(path).await? {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "directory is not empty, please delete with recursive",
+            )
+            .with_operation(Operation::Delete)
+            .with_context("path", path));
+        }
+
         let resp = self.webdav_delete(path).await?;
 
         let status = resp.status();
@@ -465,6 +621,88 @@ impl Accessor for WebdavBackend {
 }
 
 impl WebdavBackend {
+    /// Lock the resource at given path.
+    ///
+    /// Pass `None` as timeout to request a lock that never expires, servers
+    /// are free to return a different timeout, please check
+    /// [`WebdavLock::timeout`] instead.
+    ///
+    /// Returns [`ErrorKind::ConditionNotMatch`] if the resource has been
+    /// locked by others.
+    pub async fn lock(
+        &self,
+        path: &str,
+        scope: WebdavLockScope,
+        timeout: Option<Duration>,
+    ) -> Result<WebdavLock> {
+        let resp = self
+            .webdav_lock(path, Some(build_lock_body(scope)), None, timeout)
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::CREATED => {
+                let token = resp
+                    .headers()
+                    .get("Lock-Token")
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.to_string());
+                let bs = resp.into_body().bytes().await?;
+                parse_lock(bs, token.as_deref())
+            }
+            StatusCode::LOCKED => Err(parse_lock_conflict_error(resp).await?),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Refresh the timeout of lock with given token.
+    pub async fn refresh_lock(
+        &self,
+        path: &str,
+        token: &str,
+        timeout: Option<Duration>,
+    ) -> Result<WebdavLock> {
+        let resp = self.webdav_lock(path, None, Some(token), timeout).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                parse_lock(bs, Some(token))
+            }
+            StatusCode::LOCKED => Err(parse_lock_conflict_error(resp).await?),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Release the lock with given token.
+    pub async fn unlock(&self, path: &str, token: &str) -> Result<()> {
+        let resp = self.webdav_unlock(path, token).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Write the whole content into resource locked by given token.
+    pub async fn write_with_lock(&self, path: &str, token: &str, bs: Bytes) -> Result<()> {
+        self.ensure_parent_path(path).await?;
+
+        let p = build_abs_path(&self.root, path);
+        let op = OpWrite::new().with_content_length(bs.len() as u64);
+
+        let mut w = WebdavWriter::new(self.clone(), op, p).with_lock_token(token);
+        oio::Write::write(&mut w, bs).await
+    }
+
     async fn webdav_get(
         &self,
         path: &str,
@@ -490,12 +728,13 @@ impl WebdavBackend {
         self.client.send(req).await
     }
 
-    pub async fn webdav_put(
+    pub(super) async fn webdav_put(
         &self,
         abs_path: &str,
         size: Option<u64>,
         content_type: Option<&str>,
         content_disposition: Option<&str>,
+        lock_token: Option<&str>,
         body: AsyncBody,
     ) -> Result<Response<IncomingAsyncBody>> {
         let url = format!("{}/{}", self.endpoint, percent_encode_path(abs_path));
@@ -518,6 +757,10 @@ impl WebdavBackend {
             req = req.header(header::CONTENT_DISPOSITION, cd)
         }
 
+        if let Some(token) = lock_token {
+            req = req.header("If", format!("(<{token}>)"))
+        }
+
         // Set body
         let req = req.body(body).map_err(new_request_build_error)?;
 
@@ -572,7 +815,7 @@ impl WebdavBackend {
                 <D:allprop/>
             </D:propfind>
         "#;
-            body = AsyncBody::Bytes(bytes::Bytes::from(all_prop_xml_body));
+            body = AsyncBody::Bytes(Bytes::from(all_prop_xml_body));
         }
 
         let req = req.body(body).map_err(new_request_build_error)?;
@@ -598,7 +841,18 @@ impl WebdavBackend {
         self.client.send(req).await
     }
 
-    async fn webdav_copy(&self, from: &str, to: &str) -> Result<Response<IncomingAsyncBody>> {
+    /// Send a `COPY` request.
+    ///
+    /// The destination will be replaced if `overwrite` is true, otherwise
+    /// server will return `412 Precondition Failed` if it exists.
+    ///
+    /// Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-9.8>
+    async fn webdav_copy(
+        &self,
+        from: &str,
+        to: &str,
+        overwrite: bool,
+    ) -> Result<Response<IncomingAsyncBody>> {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
 
@@ -612,9 +866,7 @@ impl WebdavBackend {
         }
 
         req = req.header("Destination", target);
-
-        // We always specific "T" for keeping to overwrite the destination.
-        req = req.header("Overwrite", "T");
+        req = req.header("Overwrite", if overwrite { "T" } else { "F" });
 
         let req = req
             .body(AsyncBody::Empty)
@@ -648,6 +900,164 @@ impl WebdavBackend {
         self.client.send(req).await
     }
 
+    /// Send a `PROPPATCH` request to set the `getcontenttype` property.
+    ///
+    /// Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-9.2>
+    async fn webdav_proppatch_content_type(
+        &self,
+        path: &str,
+        content_type: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::builder().method("PROPPATCH").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req = req.header(header::CONTENT_TYPE, "application/xml");
+
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8" ?><D:propertyupdate xmlns:D="DAV:"><D:set><D:prop><D:getcontenttype>{}</D:getcontenttype></D:prop></D:set></D:propertyupdate>"#,
+            quick_xml::escape::escape(content_type)
+        );
+
+        let req = req
+            .body(AsyncBody::Bytes(Bytes::from(body)))
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    /// Send a `LOCK` request.
+    ///
+    /// Creating a new lock must carry the `lockinfo` body, while refreshing
+    /// must carry the token without body.
+    ///
+    /// Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-9.10>
+    async fn webdav_lock(
+        &self,
+        path: &str,
+        body: Option<Bytes>,
+        token: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::builder().method("LOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        // Only lock the resource itself.
+        req = req.header("Depth", "0");
+        req = req.header("Timeout", format_timeout(timeout));
+
+        if let Some(token) = token {
+            req = req.header("If", format!("(<{token}>)"));
+        }
+
+        let body = match body {
+            Some(bs) => {
+                req = req.header(header::CONTENT_TYPE, "application/xml");
+                AsyncBody::Bytes(bs)
+            }
+            None => AsyncBody::Empty,
+        };
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    async fn webdav_unlock(&self, path: &str, token: &str) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::builder().method("UNLOCK").uri(&url);
+
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        req = req.header("Lock-Token", format!("<{token}>"));
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    /// Some servers don't keep the `getcontenttype` of source while copying
+    /// or moving, we will set it back via `PROPPATCH` if it's lost.
+    ///
+    /// Dead properties are copied by servers as required by RFC 4918, while
+    /// `getlastmodified` is a live property maintained by servers which
+    /// can't be preserved.
+    ///
+    /// The copy or move has succeeded already, so failures here will be
+    /// logged and ignored.
+    async fn preserve_content_type(&self, path: &str, source: Option<Metadata>) {
+        if !self.enable_preserve_content_type {
+            return;
+        }
+        let Some(content_type) = source
+            .as_ref()
+            .filter(|m| m.mode().is_file())
+            .and_then(|m| m.content_type())
+        else {
+            return;
+        };
+
+        if let Err(err) = self.set_content_type(path, content_type).await {
+            warn!("failed to preserve content type of {path}: {err}");
+        }
+    }
+
+    /// Stat via `PROPFIND`, all properties of the resource are returned.
+    async fn stat_complete(&self, path: &str) -> Result<Metadata> {
+        let meta = self.stat(path, OpStat::default()).await?.into_metadata();
+        Ok(meta.with_bit(Metakey::Complete))
+    }
+
+    async fn set_content_type(&self, path: &str, content_type: &str) -> Result<()> {
+        let meta = self.stat_complete(path).await?;
+        if meta.content_type() == Some(content_type) {
+            return Ok(());
+        }
+
+        let resp = self
+            .webdav_proppatch_content_type(path, content_type)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::MULTI_STATUS => {
+                resp.into_body().consume().await?;
+                Ok(())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn is_non_empty_dir(&self, path: &str) -> Result<bool> {
+        let (_, pager) = self.list(path, OpList::new()).await?;
+        let Some(mut pager) = pager else {
+            return Ok(false);
+        };
+
+        // WebdavPager returns all children in the first page.
+        let entries = oio::Page::next(&mut pager).await?;
+        Ok(entries.map(|v| !v.is_empty()).unwrap_or_default())
+    }
+
     async fn create_dir_internal(&self, path: &str) -> Result<()> {
         let resp = self.webdav_mkcol(path).await?;
 
@@ -696,3 +1106,243 @@ impl WebdavBackend {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use wiremock::matchers::any;
+    use wiremock::matchers::method;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::Request;
+    use wiremock::Respond;
+    use wiremock::ResponseTemplate;
+
+    use super::*;
+
+    /// A fake webdav server which drops the content type while copying or
+    /// moving like some servers do.
+    #[derive(Clone)]
+    struct FakeWebdav {
+        endpoint: String,
+        /// Files with their content types.
+        files: Arc<Mutex<HashMap<String, String>>>,
+        /// Reject all `PROPPATCH` requests.
+        reject_proppatch: bool,
+    }
+
+    impl FakeWebdav {
+        fn propfind(&self, path: &str) -> ResponseTemplate {
+            let prop = if path.ends_with('/') {
+                "<D:resourcetype><D:collection/></D:resourcetype>".to_string()
+            } else {
+                match self.files.lock().unwrap().get(path) {
+                    Some(ct) if ct.is_empty() => "<D:resourcetype></D:resourcetype>".to_string(),
+                    Some(ct) => format!(
+                        "<D:getcontenttype>{ct}</D:getcontenttype><D:resourcetype></D:resourcetype>"
+                    ),
+                    None => return ResponseTemplate::new(404),
+                }
+            };
+
+            ResponseTemplate::new(207).set_body_string(format!(
+                r#"<D:multistatus xmlns:D="DAV:"><D:response><D:href>{path}</D:href><D:propstat><D:prop><D:getlastmodified>Sat, 07 May 2022 05:52:22 GMT</D:getlastmodified>{prop}</D:prop><D:status>HTTP/1.1 200 OK</D:status></D:propstat></D:response></D:multistatus>"#
+            ))
+        }
+    }
+
+    impl Respond for FakeWebdav {
+        fn respond(&self, req: &Request) -> ResponseTemplate {
+            let header = |k: &str| req.headers.get(&k.into()).map(|v| v.as_str().to_string());
+            let path = req.url.path().to_string();
+
+            match req.method.as_ref() {
+                "PROPFIND" => self.propfind(&path),
+                "MKCOL" => ResponseTemplate::new(201),
+                "COPY" | "MOVE" => {
+                    let target = header("Destination").expect("destination must be set");
+                    let target = target
+                        .strip_prefix(&self.endpoint)
+                        .expect("destination must be a full url")
+                        .to_string();
+
+                    let mut files = self.files.lock().unwrap();
+                    if !files.contains_key(&path) {
+                        return ResponseTemplate::new(404);
+                    }
+                    let exist = files.contains_key(&target);
+                    match header("Overwrite").as_deref() {
+                        Some("F") if exist => return ResponseTemplate::new(412),
+                        Some("T") | Some("F") => {}
+                        v => panic!("invalid overwrite header: {v:?}"),
+                    }
+                    if req.method.as_ref() == "MOVE" {
+                        files.remove(&path);
+                    }
+                    // Content type is dropped.
+                    files.insert(target, String::new());
+
+                    ResponseTemplate::new(if exist { 204 } else { 201 })
+                }
+                "PROPPATCH" if self.reject_proppatch => ResponseTemplate::new(403),
+                "PROPPATCH" => {
+                    let body = String::from_utf8_lossy(&req.body);
+                    let ct = body
+                        .split_once("<D:getcontenttype>")
+                        .and_then(|(_, v)| v.split_once("</D:getcontenttype>"))
+                        .map(|(v, _)| v.to_string())
+                        .expect("content type must be set");
+
+                    let mut files = self.files.lock().unwrap();
+                    match files.get_mut(&path) {
+                        Some(v) => {
+                            *v = ct;
+                            ResponseTemplate::new(207)
+                        }
+                        None => ResponseTemplate::new(404),
+                    }
+                }
+                _ => ResponseTemplate::new(405),
+            }
+        }
+    }
+
+    async fn mock_operator() -> (MockServer, Operator) {
+        new_operator(true, false).await
+    }
+
+    async fn new_operator(
+        preserve_content_type: bool,
+        reject_proppatch: bool,
+    ) -> (MockServer, Operator) {
+        let mock_server = MockServer::start().await;
+        let fake = FakeWebdav {
+            endpoint: mock_server.uri(),
+            files: Arc::new(Mutex::new(HashMap::from([(
+                "/from".to_string(),
+                "text/plain".to_string(),
+            )]))),
+            reject_proppatch,
+        };
+        Mock::given(any())
+            .respond_with(fake)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = WebdavBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        if preserve_content_type {
+            builder.enable_preserve_content_type();
+        }
+        let op = Operator::new(builder).expect("must build").finish();
+
+        (mock_server, op)
+    }
+
+    #[tokio::test]
+    async fn test_copy_preserves_content_type() -> Result<()> {
+        let (_server, op) = mock_operator().await;
+
+        op.copy("from", "to").await?;
+
+        let meta = op.stat("to").await?;
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rename_preserves_content_type() -> Result<()> {
+        let (_server, op) = mock_operator().await;
+
+        op.rename("from", "to").await?;
+
+        let meta = op.stat("to").await?;
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        let err = op.stat("from").await.expect_err("source must be moved");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_without_preserve_content_type() -> Result<()> {
+        let (server, op) = new_operator(false, false).await;
+
+        op.copy("from", "to").await?;
+
+        let meta = op.stat("to").await?;
+        assert_eq!(meta.content_type(), None);
+        // Only the source and the target are checked.
+        let requests = server.received_requests().await.expect("must be recorded");
+        let propfinds = requests
+            .iter()
+            .filter(|r| r.method.as_ref() == "PROPFIND")
+            .count();
+        assert_eq!(propfinds, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_ignores_failed_proppatch() -> Result<()> {
+        let (_server, op) = new_operator(true, true).await;
+
+        op.copy("from", "to").await?;
+        op.rename("to", "renamed").await?;
+
+        let meta = op.stat("renamed").await?;
+        assert_eq!(meta.content_type(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_with_if_not_exists() -> Result<()> {
+        let (_server, op) = mock_operator().await;
+
+        op.copy_with("from", "to").if_not_exists(true).await?;
+
+        let err = op
+            .copy_with("from", "to")
+            .if_not_exists(true)
+            .await
+            .expect_err("copy must fail");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        // Overwrite is allowed by default.
+        op.copy("from", "to").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_chunked_unknown_size() -> Result<()> {
+        let (server, op) = mock_operator().await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(201))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+
+        let mut w = op.writer_with("file").chunked_unknown_size().await?;
+        w.write("hello, ").await?;
+        w.write("world").await?;
+        w.close().await?;
+
+        let requests = server.received_requests().await.expect("must be recorded");
+        let puts: Vec<_> = requests
+            .iter()
+            .filter(|r| r.method.as_ref() == "PUT")
+            .collect();
+        // All writes are streamed within the same request.
+        assert_eq!(puts.len(), 1);
+        assert_eq!(
+            puts[0]
+                .headers
+                .get(&"Transfer-Encoding".into())
+                .map(|v| v.as_str()),
+            Some("chunked")
+        );
+        assert_eq!(puts[0].body, b"hello, world");
+        Ok(())
+    }
+}
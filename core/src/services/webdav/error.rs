@@ -30,10 +30,13 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
 
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
+        // The lock token carried in `If` header doesn't match.
+        StatusCode::PRECONDITION_FAILED => (ErrorKind::ConditionNotMatch, false),
         // Some services (like owncloud) return 403 while file locked.
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, true),
         // Allowing retry for resource locked.
         StatusCode::LOCKED => (ErrorKind::Unexpected, true),
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -53,3 +56,22 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
 
     Ok(err)
 }
+
+/// Parse the `423 Locked` response of `LOCK` into lock conflict error.
+///
+/// Lock conflicts will not be resolved by retrying soon, so we don't mark
+/// them as temporary like [`parse_error`] does.
+pub async fn parse_lock_conflict_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes().await?;
+
+    let mut err = Error::new(
+        ErrorKind::ConditionNotMatch,
+        "resource has been locked by others",
+    );
+    if !bs.is_empty() {
+        err = err.with_context("response", String::from_utf8_lossy(&bs));
+    }
+
+    Ok(with_error_response_context(err, parts))
+}
@@ -0,0 +1,258 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use bytes::Buf;
+use bytes::Bytes;
+use serde::Deserialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The scope of a [`WebdavLock`].
+///
+/// Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-6.1>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebdavLockScope {
+    /// Only one principal can hold the lock, other lockers will be blocked.
+    Exclusive,
+    /// Multiple principals can hold shared locks at the same time, but
+    /// exclusive lockers will be blocked.
+    Shared,
+}
+
+impl WebdavLockScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            WebdavLockScope::Exclusive => "exclusive",
+            WebdavLockScope::Shared => "shared",
+        }
+    }
+}
+
+/// WebdavLock is a write lock acquired via `LOCK` on webdav resource.
+///
+/// Please keep the [`WebdavLock::token`] to refresh or release the lock,
+/// and carry it while writing the locked resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebdavLock {
+    token: String,
+    scope: WebdavLockScope,
+    timeout: Option<Duration>,
+}
+
+impl WebdavLock {
+    /// The token of this lock, like `opaquelocktoken:e71d4fae-5dec-22d6-fea5-00a0c91e6be4`.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The scope of this lock.
+    pub fn scope(&self) -> WebdavLockScope {
+        self.scope
+    }
+
+    /// The timeout of this lock returned by server.
+    ///
+    /// `None` means the lock will never expire unless released.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+/// Build the `lockinfo` body of a `LOCK` request.
+pub fn build_lock_body(scope: WebdavLockScope) -> Bytes {
+    Bytes::from(format!(
+        r#"<?xml version="1.0" encoding="utf-8" ?><D:lockinfo xmlns:D="DAV:"><D:lockscope><D:{}/></D:lockscope><D:locktype><D:write/></D:locktype></D:lockinfo>"#,
+        scope.as_str()
+    ))
+}
+
+/// Format the value of `Timeout` header.
+///
+/// Reference: <https://datatracker.ietf.org/doc/html/rfc4918#section-10.7>
+pub fn format_timeout(timeout: Option<Duration>) -> String {
+    match timeout {
+        Some(v) => format!("Second-{}", v.as_secs()),
+        None => "Infinite".to_string(),
+    }
+}
+
+fn parse_timeout(v: &str) -> Option<Duration> {
+    v.trim()
+        .strip_prefix("Second-")
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Parse the lock from the body of `LOCK` response.
+///
+/// `token` is the lock token returned in `Lock-Token` header while creating
+/// or the token to refresh. If it's `None`, the first active lock will be
+/// used.
+pub fn parse_lock(bs: Bytes, token: Option<&str>) -> Result<WebdavLock> {
+    let prop: LockProp =
+        quick_xml::de::from_reader(bs.reader()).map_err(new_xml_deserialize_error)?;
+
+    let token = token.map(|v| v.trim_start_matches('<').trim_end_matches('>'));
+
+    let lock = prop
+        .lockdiscovery
+        .activelock
+        .into_iter()
+        .find_map(|v| {
+            let href = v.locktoken.map(|v| v.href);
+            match (token, href) {
+                (Some(token), Some(href)) if href.trim() != token => None,
+                (Some(token), _) => Some((token.to_string(), v.lockscope, v.timeout)),
+                (None, Some(href)) => Some((href.trim().to_string(), v.lockscope, v.timeout)),
+                (None, None) => None,
+            }
+        })
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "lock response doesn't contain the active lock",
+            )
+            .with_context("token", token.unwrap_or_default())
+        })?;
+
+    let (token, lockscope, timeout) = lock;
+    let scope = if lockscope.shared.is_some() {
+        WebdavLockScope::Shared
+    } else {
+        WebdavLockScope::Exclusive
+    };
+
+    Ok(WebdavLock {
+        token,
+        scope,
+        timeout: timeout.as_deref().and_then(parse_timeout),
+    })
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct LockProp {
+    lockdiscovery: LockDiscovery,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct LockDiscovery {
+    #[serde(default)]
+    activelock: Vec<ActiveLock>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct ActiveLock {
+    lockscope: LockScope,
+    timeout: Option<String>,
+    locktoken: Option<LockToken>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct LockScope {
+    exclusive: Option<Empty>,
+    shared: Option<Empty>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct Empty {}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct LockToken {
+    href: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCK_RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8" ?>
+        <D:prop xmlns:D="DAV:">
+            <D:lockdiscovery>
+                <D:activelock>
+                    <D:locktype><D:write/></D:locktype>
+                    <D:lockscope><D:exclusive/></D:lockscope>
+                    <D:depth>infinity</D:depth>
+                    <D:owner>
+                        <D:href>http://example.org/~ejw/contact.html</D:href>
+                    </D:owner>
+                    <D:timeout>Second-604800</D:timeout>
+                    <D:locktoken>
+                        <D:href>urn:uuid:e71d4fae-5dec-22d6-fea5-00a0c91e6be4</D:href>
+                    </D:locktoken>
+                    <D:lockroot>
+                        <D:href>http://example.com/workspace/webdav/proposal.doc</D:href>
+                    </D:lockroot>
+                </D:activelock>
+            </D:lockdiscovery>
+        </D:prop>"#;
+
+    #[test]
+    fn test_parse_lock() -> Result<()> {
+        let lock = parse_lock(
+            Bytes::from(LOCK_RESPONSE),
+            Some("<urn:uuid:e71d4fae-5dec-22d6-fea5-00a0c91e6be4>"),
+        )?;
+        assert_eq!(
+            lock,
+            WebdavLock {
+                token: "urn:uuid:e71d4fae-5dec-22d6-fea5-00a0c91e6be4".to_string(),
+                scope: WebdavLockScope::Exclusive,
+                timeout: Some(Duration::from_secs(604800)),
+            }
+        );
+
+        // Token should be read from body if header is missing.
+        let lock = parse_lock(Bytes::from(LOCK_RESPONSE), None)?;
+        assert_eq!(
+            lock.token(),
+            "urn:uuid:e71d4fae-5dec-22d6-fea5-00a0c91e6be4"
+        );
+
+        let err = parse_lock(Bytes::from(LOCK_RESPONSE), Some("urn:uuid:other")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_shared_lock_without_timeout() -> Result<()> {
+        let xml = r#"<D:prop xmlns:D="DAV:">
+            <D:lockdiscovery>
+                <D:activelock>
+                    <D:locktype><D:write/></D:locktype>
+                    <D:lockscope><D:shared/></D:lockscope>
+                    <D:timeout>Infinite</D:timeout>
+                    <D:locktoken><D:href>opaquelocktoken:abc</D:href></D:locktoken>
+                </D:activelock>
+            </D:lockdiscovery>
+        </D:prop>"#;
+
+        let lock = parse_lock(Bytes::from(xml), None)?;
+        assert_eq!(lock.token(), "opaquelocktoken:abc");
+        assert_eq!(lock.scope(), WebdavLockScope::Shared);
+        assert_eq!(lock.timeout(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_timeout() {
+        assert_eq!(format_timeout(Some(Duration::from_secs(60))), "Second-60");
+        assert_eq!(format_timeout(None), "Infinite");
+    }
+}
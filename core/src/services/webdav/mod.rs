@@ -16,8 +16,12 @@
 // under the License.
 
 mod backend;
+pub use backend::WebdavBackend;
 pub use backend::WebdavBuilder as Webdav;
 
 mod error;
+mod lock;
+pub use lock::WebdavLock;
+pub use lock::WebdavLockScope;
 mod pager;
 mod writer;
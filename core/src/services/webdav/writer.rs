@@ -17,6 +17,11 @@
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures::channel::mpsc;
+use futures::future::BoxFuture;
+use futures::future::Either;
+use futures::FutureExt;
+use futures::SinkExt;
 use http::StatusCode;
 
 use super::backend::WebdavBackend;
@@ -29,53 +34,142 @@ pub struct WebdavWriter {
 
     op: OpWrite,
     path: String,
+    lock_token: Option<String>,
+    /// The sender of the chunked request started by `write` while writing
+    /// data of unknown size.
+    sender: Option<mpsc::Sender<Result<Bytes>>>,
+    /// The inflight chunked request, which will be finished while closing.
+    fut: Option<BoxFuture<'static, Result<()>>>,
 }
 
+/// # Safety
+///
+/// WebdavWriter will only be accessed by `&mut Self`
+unsafe impl Sync for WebdavWriter {}
+
 impl WebdavWriter {
     pub fn new(backend: WebdavBackend, op: OpWrite, path: String) -> Self {
-        WebdavWriter { backend, op, path }
+        WebdavWriter {
+            backend,
+            op,
+            path,
+            lock_token: None,
+            sender: None,
+            fut: None,
+        }
+    }
+
+    /// Carry the lock token while writing locked resource.
+    pub fn with_lock_token(mut self, token: &str) -> Self {
+        self.lock_token = Some(token.to_string());
+        self
     }
 
-    async fn write_oneshot(&mut self, size: u64, body: AsyncBody) -> Result<()> {
-        let resp = self
-            .backend
-            .webdav_put(
-                &self.path,
-                Some(size),
-                self.op.content_type(),
-                self.op.content_disposition(),
-                body,
-            )
-            .await?;
-
-        let status = resp.status();
-
-        match status {
-            StatusCode::CREATED | StatusCode::OK | StatusCode::NO_CONTENT => {
-                resp.into_body().consume().await?;
-                Ok(())
+    /// Build the request to send the whole body, the body will be sent with
+    /// chunked transfer encoding if size is unknown.
+    fn put(&self, size: Option<u64>, body: AsyncBody) -> BoxFuture<'static, Result<()>> {
+        let backend = self.backend.clone();
+        let op = self.op.clone();
+        let path = self.path.clone();
+        let lock_token = self.lock_token.clone();
+
+        async move {
+            let resp = backend
+                .webdav_put(
+                    &path,
+                    size,
+                    op.content_type(),
+                    op.content_disposition(),
+                    lock_token.as_deref(),
+                    body,
+                )
+                .await?;
+
+            let status = resp.status();
+
+            match status {
+                StatusCode::CREATED | StatusCode::OK | StatusCode::NO_CONTENT => {
+                    resp.into_body().consume().await?;
+                    Ok(())
+                }
+                _ => Err(parse_error(resp).await?),
             }
-            _ => Err(parse_error(resp).await?),
         }
+        .boxed()
+    }
+
+    /// Send data into the inflight chunked request, and start the request
+    /// if it's not started yet.
+    async fn write_chunked(&mut self, bs: Bytes) -> Result<()> {
+        if self.fut.is_none() {
+            let (tx, rx) = mpsc::channel(1);
+            self.fut = Some(self.put(None, AsyncBody::Stream(Box::new(oio::into_stream(rx)))));
+            self.sender = Some(tx);
+        }
+
+        let (Some(sender), Some(fut)) = (self.sender.as_mut(), self.fut.as_mut()) else {
+            unreachable!("chunked request must be started")
+        };
+
+        // Drive the request while waiting for the body to be consumed.
+        let res = match futures::future::select(sender.send(Ok(bs)), fut).await {
+            Either::Left((Ok(()), _)) => return Ok(()),
+            // The body has been dropped which means the request is finished.
+            Either::Left((Err(_), fut)) => fut.await,
+            Either::Right((res, _)) => res,
+        };
+        self.sender = None;
+        self.fut = None;
+
+        res?;
+        Err(Error::new(
+            ErrorKind::Unexpected,
+            "webdav request finished before all data has been written",
+        ))
     }
 }
 
 #[async_trait]
 impl oio::Write for WebdavWriter {
     async fn write(&mut self, bs: Bytes) -> Result<()> {
-        self.write_oneshot(bs.len() as u64, AsyncBody::Bytes(bs))
-            .await
+        // Every request overwrites the whole file, so data of unknown size
+        // will be streamed in one chunked request.
+        if self.op.chunked_unknown_size() {
+            return self.write_chunked(bs).await;
+        }
+
+        self.put(Some(bs.len() as u64), AsyncBody::Bytes(bs)).await
     }
 
     async fn sink(&mut self, size: u64, s: oio::Streamer) -> Result<()> {
-        self.write_oneshot(size, AsyncBody::Stream(s)).await
+        self.put(Some(size), AsyncBody::Stream(s)).await
+    }
+
+    async fn sink_unsized(&mut self, s: oio::Streamer) -> Result<()> {
+        if self.fut.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Writer::sink_unsized should not be used mixed with existing write",
+            ));
+        }
+
+        self.put(None, AsyncBody::Stream(s)).await
     }
 
+    /// Drop the inflight request so that the body will never be finished.
     async fn abort(&mut self) -> Result<()> {
+        self.sender = None;
+        self.fut = None;
         Ok(())
     }
 
     async fn close(&mut self) -> Result<()> {
-        Ok(())
+        let Some(fut) = self.fut.take() else {
+            return Ok(());
+        };
+
+        // Close the body to finish the chunked request.
+        self.sender = None;
+        fut.await
     }
 }
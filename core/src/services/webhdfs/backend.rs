@@ -48,6 +48,7 @@ pub struct WebhdfsBuilder {
     endpoint: Option<String>,
     delegation: Option<String>,
     disable_list_batch: bool,
+    http_user_agent: Option<String>,
 }
 
 impl Debug for WebhdfsBuilder {
@@ -116,6 +117,17 @@ impl WebhdfsBuilder {
         self.disable_list_batch = true;
         self
     }
+
+    /// Set the user agent of http requests sent by this service.
+    ///
+    /// The user agent will be appended to the default one of OpenDAL instead
+    /// of replacing it, for example: `opendal/{version} my-app/1.0`.
+    pub fn http_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        if !user_agent.is_empty() {
+            self.http_user_agent = Some(user_agent.to_string());
+        }
+        self
+    }
 }
 
 impl Builder for WebhdfsBuilder {
@@ -131,6 +143,8 @@ impl Builder for WebhdfsBuilder {
         map.get("disable_list_batch")
             .filter(|v| v == &"true")
             .map(|_| builder.disable_list_batch());
+        map.get("http_user_agent")
+            .map(|v| builder.http_user_agent(v));
 
         builder
     }
@@ -166,7 +180,13 @@ impl Builder for WebhdfsBuilder {
             .take()
             .map(|dt| format!("delegation_token={dt}"));
 
-        let client = HttpClient::new()?;
+        let mut client = HttpClient::new()?;
+        if let Some(ua) = &self.http_user_agent {
+            client = client.with_user_agent(ua).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Webhdfs)
+            })?;
+        }
 
         let backend = WebhdfsBackend {
             root,
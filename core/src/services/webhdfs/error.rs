@@ -53,6 +53,7 @@ fn parse_error_msg(parts: Parts, body: &str) -> Result<Error> {
         // passing invalid arguments will return BAD_REQUEST
         // should be un-retryable
         StatusCode::BAD_REQUEST => (ErrorKind::Unexpected, false),
+        StatusCode::TOO_MANY_REQUESTS => (ErrorKind::RateLimited, true),
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
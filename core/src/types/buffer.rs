@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::vec_deque;
+use std::collections::VecDeque;
+use std::io::IoSlice;
+
+use bytes::Buf;
+use bytes::Bytes;
+use bytes::BytesMut;
+
+/// Buffer is a non-contiguous sequence of [`Bytes`] returned by services.
+///
+/// It can also be written via
+/// [`Operator::write_buffer`](crate::Operator::write_buffer)
+/// without concatenating chunks.
+///
+/// The chunk boundaries returned by services are preserved, so content can
+/// be forwarded without concatenation, for example:
+///
+/// - Iterate over chunks via [`Buffer::chunks`] or `IntoIterator`.
+/// - Fill [`IoSlice`]s via [`Buf::chunks_vectored`] for
+///   `AsyncWrite::poll_write_vectored`.
+///
+/// Use [`Buffer::to_bytes`] or [`Buffer::to_vec`] if contiguous content is
+/// required, which will copy only if there are more than one chunk.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// # use opendal::Operator;
+/// use futures::AsyncWriteExt;
+///
+/// # #[tokio::main]
+/// # async fn test(op: Operator) -> Result<()> {
+/// let buf = op.read_buffer("path/to/file").await?;
+///
+/// let mut w = futures::io::Cursor::new(vec![]);
+/// for bs in buf.chunks() {
+///     w.write_all(bs).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Buffer {
+    chunks: VecDeque<Bytes>,
+    size: usize,
+}
+
+impl Buffer {
+    /// Create a new empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a chunk into the end of buffer.
+    ///
+    /// Empty chunks will be ignored.
+    pub fn push(&mut self, bs: Bytes) {
+        if bs.is_empty() {
+            return;
+        }
+
+        self.size += bs.len();
+        self.chunks.push_back(bs);
+    }
+
+    /// Get the total length of all chunks.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Check if buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Get the count of chunks.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Iterate over all chunks without copying.
+    pub fn chunks(&self) -> vec_deque::Iter<'_, Bytes> {
+        self.chunks.iter()
+    }
+
+    /// Fill `dst` with [`IoSlice`]s of chunks, returns the number of slices
+    /// that have been filled.
+    ///
+    /// This is a shortcut of [`Buf::chunks_vectored`] which is friendly for
+    /// `write_vectored`.
+    pub fn io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        self.chunks_vectored(dst)
+    }
+
+    /// Convert buffer into a contiguous [`Bytes`].
+    ///
+    /// No copy will happen if there is at most one chunk.
+    pub fn to_bytes(&self) -> Bytes {
+        match self.chunks.len() {
+            0 => Bytes::new(),
+            1 => self.chunks[0].clone(),
+            _ => {
+                let mut bs = BytesMut::with_capacity(self.size);
+                for chunk in self.chunks.iter() {
+                    bs.extend_from_slice(chunk);
+                }
+                bs.freeze()
+            }
+        }
+    }
+
+    /// Copy all content into a new `Vec<u8>`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut vec = Vec::with_capacity(self.size);
+        for chunk in self.chunks.iter() {
+            vec.extend_from_slice(chunk);
+        }
+        vec
+    }
+}
+
+impl From<Bytes> for Buffer {
+    fn from(bs: Bytes) -> Self {
+        let mut buf = Buffer::new();
+        buf.push(bs);
+        buf
+    }
+}
+
+impl From<Vec<Bytes>> for Buffer {
+    fn from(chunks: Vec<Bytes>) -> Self {
+        chunks.into_iter().collect()
+    }
+}
+
+impl FromIterator<Bytes> for Buffer {
+    fn from_iter<T: IntoIterator<Item = Bytes>>(iter: T) -> Self {
+        let mut buf = Buffer::new();
+        for bs in iter {
+            buf.push(bs);
+        }
+        buf
+    }
+}
+
+impl IntoIterator for Buffer {
+    type Item = Bytes;
+    type IntoIter = vec_deque::IntoIter<Bytes>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chunks.into_iter()
+    }
+}
+
+impl Buf for Buffer {
+    fn remaining(&self) -> usize {
+        self.size
+    }
+
+    fn chunk(&self) -> &[u8] {
+        match self.chunks.front() {
+            Some(bs) => bs.as_ref(),
+            None => &[],
+        }
+    }
+
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut n = 0;
+        for (slice, chunk) in dst.iter_mut().zip(self.chunks.iter()) {
+            *slice = IoSlice::new(chunk);
+            n += 1;
+        }
+        n
+    }
+
+    fn advance(&mut self, mut cnt: usize) {
+        assert!(
+            cnt <= self.size,
+            "cannot advance past {} bytes, only {} bytes left",
+            cnt,
+            self.size
+        );
+
+        self.size -= cnt;
+        while cnt > 0 {
+            let front = self.chunks.front_mut().expect("chunk must exist");
+            if cnt < front.len() {
+                front.advance(cnt);
+                return;
+            }
+            cnt -= front.len();
+            self.chunks.pop_front();
+        }
+    }
+
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        // Avoid copy if the first chunk is large enough.
+        match self.chunks.front_mut() {
+            Some(front) if front.len() >= len => {
+                let bs = front.split_to(len);
+                if front.is_empty() {
+                    self.chunks.pop_front();
+                }
+                self.size -= len;
+                bs
+            }
+            _ => {
+                assert!(len <= self.size, "`len` greater than remaining");
+
+                let mut bs = BytesMut::with_capacity(len);
+                while bs.len() < len {
+                    let chunk = self.chunk();
+                    let n = chunk.len().min(len - bs.len());
+                    bs.extend_from_slice(&chunk[..n]);
+                    self.advance(n);
+                }
+                bs.freeze()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks() -> Vec<Bytes> {
+        vec![
+            Bytes::from("hello"),
+            Bytes::new(),
+            Bytes::from(", "),
+            Bytes::from("world"),
+        ]
+    }
+
+    #[test]
+    fn test_buffer_preserves_chunks() {
+        let chunks = chunks();
+        let buf = Buffer::from(chunks.clone());
+
+        assert_eq!(buf.len(), 12);
+        // Empty chunk is ignored.
+        assert_eq!(buf.chunk_count(), 3);
+        let got: Vec<&Bytes> = buf.chunks().collect();
+        assert_eq!(got, vec![&chunks[0], &chunks[2], &chunks[3]]);
+        // Chunks are shared instead of copied.
+        assert_eq!(got[0].as_ptr(), chunks[0].as_ptr());
+
+        assert_eq!(buf.to_bytes(), Bytes::from("hello, world"));
+        assert_eq!(buf.to_vec(), b"hello, world");
+    }
+
+    #[test]
+    fn test_buffer_chunks_vectored() {
+        let buf = Buffer::from(chunks());
+
+        let mut slices = [IoSlice::new(&[]); 4];
+        assert_eq!(buf.io_slices(&mut slices), 3);
+        assert_eq!(&*slices[0], b"hello");
+        assert_eq!(&*slices[1], b", ");
+        assert_eq!(&*slices[2], b"world");
+
+        let mut slices = [IoSlice::new(&[]); 2];
+        assert_eq!(buf.io_slices(&mut slices), 2);
+    }
+
+    #[test]
+    fn test_buffer_buf() {
+        let mut buf = Buffer::from(chunks());
+
+        assert_eq!(buf.chunk(), b"hello");
+        buf.advance(6);
+        assert_eq!(buf.remaining(), 6);
+        assert_eq!(buf.chunk(), b" ");
+
+        assert_eq!(buf.copy_to_bytes(3), Bytes::from(" wo"));
+        assert_eq!(buf.copy_to_bytes(3), Bytes::from("rld"));
+        assert!(buf.is_empty());
+        assert_eq!(buf.chunk_count(), 0);
+    }
+}
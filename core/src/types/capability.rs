@@ -44,7 +44,7 @@ use std::fmt::Debug;
 /// - Operation with variants should be named like `read_can_seek`.
 /// - Operation with arguments should be named like `read_with_range`.
 /// - Operation with limitations should be named like `batch_max_operations`.
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Capability {
     /// If operator supports stat natively, it will be true.
     pub stat: bool,
@@ -52,6 +52,8 @@ pub struct Capability {
     pub stat_with_if_match: bool,
     /// If operator supports stat with if none match natively, it will be true.
     pub stat_with_if_none_match: bool,
+    /// If operator supports stat with version natively, it will be true.
+    pub stat_with_version: bool,
 
     /// If operator supports read natively, it will be true.
     pub read: bool,
@@ -67,30 +69,63 @@ pub struct Capability {
     pub read_with_if_match: bool,
     /// If operator supports read with if none match natively, it will be true.
     pub read_with_if_none_match: bool,
+    /// If operator supports read with if range natively, it will be true.
+    pub read_with_if_range: bool,
+    /// If operator supports read with version natively, it will be true.
+    pub read_with_version: bool,
     /// if operator supports read with override cache control natively, it will be true.
     pub read_with_override_cache_control: bool,
     /// if operator supports read with override content disposition natively, it will be true.
     pub read_with_override_content_disposition: bool,
     /// if operator supports read with override content type natively, it will be true.
     pub read_with_override_content_type: bool,
+    /// If operator supports read with verifying the checksum of content
+    /// natively, it will be true.
+    pub read_with_verify: bool,
 
     /// If operator supports write natively, it will be true.
     pub write: bool,
     /// If operator supports write by sink a stream into, it will be true.
     pub write_can_sink: bool,
+    /// If operator supports write by uploading parts manually, it will be true.
+    pub write_can_multi: bool,
     /// If operator supports write with without content length, it will
     /// be true.
     ///
     /// This feature also be called as `Unsized` write or streaming write.
     pub write_without_content_length: bool,
+    /// If operator supports write by streaming data of unknown size with
+    /// chunked transfer encoding, it will be true.
+    pub write_can_chunked: bool,
     /// If operator supports write with content type natively, it will be true.
     pub write_with_content_type: bool,
     /// If operator supports write with content disposition natively, it will be true.
     pub write_with_content_disposition: bool,
     /// If operator supports write with cache control natively, it will be true.
     pub write_with_cache_control: bool,
+    /// If operator supports write with content md5 natively, it will be true.
+    pub write_with_content_md5: bool,
+    /// If operator supports write with checksum algorithm natively, it will be true.
+    pub write_with_checksum_algorithm: bool,
+    /// If operator supports write with if not exists natively, it will be true.
+    ///
+    /// Otherwise, it will be emulated by checking the existence before
+    /// writing which is racy.
+    pub write_with_if_not_exists: bool,
+    /// If operator supports write with object lock retention natively, it will be true.
+    pub write_with_object_lock: bool,
+    /// The min part size that operator supports in multipart write.
+    pub write_multi_min_size: Option<u64>,
+    /// The max part size that operator supports in multipart write.
+    pub write_multi_max_size: Option<u64>,
+    /// The max size of a single object that operator supports to write.
+    pub write_total_max_size: Option<u64>,
 
     /// If operator supports append natively, it will be true.
+    ///
+    /// Append will create the file if it does not exist, and append data
+    /// to the end if it exists. Services that can't create on append
+    /// atomically will document their fallback.
     pub append: bool,
     /// If operator supports append with content type natively, it will be true.
     pub append_with_content_type: bool,
@@ -104,23 +139,56 @@ pub struct Capability {
 
     /// If operator supports delete natively, it will be true.
     pub delete: bool,
+    /// If operator supports delete with version natively, it will be true.
+    pub delete_with_version: bool,
+    /// If operator supports delete directory with all its children natively,
+    /// it will be true.
+    ///
+    /// Services that support it must still reject deleting non-empty
+    /// directory if recursive is not set.
+    pub delete_with_recursive: bool,
 
     /// If operator supports copy natively, it will be true.
     pub copy: bool,
+    /// If operator supports copy with range of source natively, it will be true.
+    pub copy_with_range: bool,
+    /// If operator supports copy with if not exists natively, it will be true.
+    pub copy_with_if_not_exists: bool,
 
     /// If operator supports rename natively, it will be true.
     pub rename: bool,
+    /// If operator supports rename atomically, it will be true.
+    ///
+    /// Readers will never see both `from` and `to` or neither of them
+    /// while renaming. Native rename is not always atomic, for example,
+    /// webdav's `MOVE` could be implemented as copy and delete by servers.
+    pub rename_atomic: bool,
+    /// If operator supports rename directories with all their children
+    /// atomically, it will be true.
+    pub rename_can_dir: bool,
+    /// If operator supports rename by emulating it with copy and delete,
+    /// it will be true.
+    pub rename_emulated: bool,
 
     /// If operator supports list natively, it will be true.
     pub list: bool,
     /// If backend supports list with limit, it will be true.
     pub list_with_limit: bool,
+    /// The max entries that backend returns in one list page.
+    pub list_max_limit: Option<usize>,
     /// If backend supports list with start after, it will be true.
     pub list_with_start_after: bool,
     /// If backend support list with using slash as delimiter.
     pub list_with_delimiter_slash: bool,
     /// If backend supports list without delimiter.
     pub list_without_delimiter: bool,
+    /// If backend supports list with delimiters other than slash, like
+    /// `:` or `|`.
+    pub list_with_delimiter_custom: bool,
+    /// If backend supports list with versions.
+    pub list_with_versions: bool,
+    /// If backend supports resuming list with continuation token.
+    pub list_with_continuation: bool,
 
     /// If operator supports presign natively, it will be true.
     pub presign: bool,
@@ -135,6 +203,11 @@ pub struct Capability {
     pub batch: bool,
     /// If operator supports batch delete natively, it will be true.
     pub batch_delete: bool,
+    /// If operator supports batch write natively, it will be true.
+    ///
+    /// Services may commit all operations in the same batch atomically,
+    /// please check the docs of services for details.
+    pub batch_write: bool,
     /// The max operations that operator supports in batch.
     pub batch_max_operations: Option<usize>,
 
@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::Error;
+use crate::ErrorKind;
+
+/// Algorithms of checksum that used to verify the integrity of content.
+///
+/// # Notes
+///
+/// - ChecksumAlgorithm is `non_exhaustive`, new variant COULD be added at any time.
+/// - Not all services support all algorithms, please check the docs of
+///   services for details.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChecksumAlgorithm {
+    /// CRC32C (Castagnoli) checksum.
+    Crc32c,
+    /// SHA-256 checksum.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Convert self into static str.
+    pub fn into_static(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "crc32c" => Ok(ChecksumAlgorithm::Crc32c),
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "checksum algorithm is not supported",
+            )
+            .with_context("algorithm", s)),
+        }
+    }
+}
+
+impl From<ChecksumAlgorithm> for &'static str {
+    fn from(v: ChecksumAlgorithm) -> Self {
+        match v {
+            ChecksumAlgorithm::Crc32c => "crc32c",
+            ChecksumAlgorithm::Sha256 => "sha256",
+        }
+    }
+}
+
+impl Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_static())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_checksum_algorithm() {
+        assert_eq!(
+            "CRC32C".parse::<ChecksumAlgorithm>().unwrap(),
+            ChecksumAlgorithm::Crc32c
+        );
+        assert_eq!(
+            "sha256".parse::<ChecksumAlgorithm>().unwrap(),
+            ChecksumAlgorithm::Sha256
+        );
+        assert_eq!(
+            "md5".parse::<ChecksumAlgorithm>().unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(ChecksumAlgorithm::Crc32c.to_string(), "crc32c");
+    }
+}
@@ -0,0 +1,39 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// DiskUsage is the result of [`Operator::du`][crate::Operator::du].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    bytes: u64,
+    count: usize,
+}
+
+impl DiskUsage {
+    pub(crate) fn new(bytes: u64, count: usize) -> Self {
+        Self { bytes, count }
+    }
+
+    /// Total bytes of all files.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Count of all files, dirs are not counted.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
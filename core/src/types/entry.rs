@@ -107,6 +107,12 @@ impl Entry {
     ///
     /// Visiting a metadata that not covered by `metakey` could result in panic.
     ///
+    /// The metadata is complete only if listing with `Metakey::Complete` or the
+    /// service returns all metadata during listing, otherwise it's partial and only
+    /// contains what the service returned plus the required `metakey`. Use
+    /// [`Operator::metadata`] to fetch metadata with given `metakey`, which will
+    /// reuse the cached metadata and only call `stat` if it's not enough.
+    ///
     /// # Examples
     ///
     /// Please use `metakey` to specify the metadata you want, for example:
@@ -44,6 +44,13 @@ use std::io;
 /// Result that is a wrapper of `Result<T, opendal::Error>`
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The context key of error code returned by service.
+const ERROR_CONTEXT_SERVICE_CODE: &str = "service_code";
+/// The context key of error message returned by service.
+const ERROR_CONTEXT_SERVICE_MESSAGE: &str = "service_message";
+/// The context key of request id returned by service.
+const ERROR_CONTEXT_REQUEST_ID: &str = "request_id";
+
 /// ErrorKind is all kinds of Error of opendal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -103,6 +110,11 @@ pub enum ErrorKind {
     ///
     /// For example, user try to seek to a negative position
     InvalidInput,
+    /// The checksum of content is not match.
+    ///
+    /// For example, the `Content-MD5` sent along with a write doesn't
+    /// match the content received by service.
+    ChecksumMismatch,
 }
 
 impl ErrorKind {
@@ -110,6 +122,20 @@ impl ErrorKind {
     pub fn into_static(self) -> &'static str {
         self.into()
     }
+
+    /// Check if this kind of error is transient by nature.
+    ///
+    /// Transient errors like [`ErrorKind::RateLimited`] are expected to be
+    /// resolved by retrying later.
+    ///
+    /// # Notes
+    ///
+    /// Other kinds like [`ErrorKind::Unexpected`] could also be transient,
+    /// for example, services returns `503 Service Unavailable`. Please
+    /// use [`Error::is_temporary`] to decide whether to retry or not.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, ErrorKind::RateLimited)
+    }
 }
 
 impl Display for ErrorKind {
@@ -135,6 +161,7 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::ContentTruncated => "ContentTruncated",
             ErrorKind::ContentIncomplete => "ContentIncomplete",
             ErrorKind::InvalidInput => "InvalidInput",
+            ErrorKind::ChecksumMismatch => "ChecksumMismatch",
         }
     }
 }
@@ -289,6 +316,28 @@ impl Error {
         self
     }
 
+    /// Add the error code, message and request id returned by service
+    /// into error's context.
+    ///
+    /// Empty values will be ignored.
+    pub(crate) fn with_service_context(
+        mut self,
+        code: &str,
+        message: &str,
+        request_id: &str,
+    ) -> Self {
+        for (key, value) in [
+            (ERROR_CONTEXT_SERVICE_CODE, code),
+            (ERROR_CONTEXT_SERVICE_MESSAGE, message),
+            (ERROR_CONTEXT_REQUEST_ID, request_id),
+        ] {
+            if !value.is_empty() {
+                self = self.with_context(key, value);
+            }
+        }
+        self
+    }
+
     /// Set source for error.
     ///
     /// # Notes
@@ -337,9 +386,48 @@ impl Error {
     }
 
     /// Check if this error is temporary.
+    ///
+    /// Temporary errors are retryable, for example:
+    ///
+    /// - services returned `429 Too Many Requests` or `5xx` status.
+    /// - connection has been reset or timed out while sending requests
+    ///   or reading responses.
+    ///
+    /// [`crate::layers::RetryLayer`] will retry temporary errors and mark
+    /// the error as persistent if it still fails, after which this function
+    /// will return `false`.
     pub fn is_temporary(&self) -> bool {
         self.status == ErrorStatus::Temporary
     }
+
+    /// Return the value of given context key.
+    ///
+    /// If the key has been added multiple times, the first value will be returned.
+    pub fn context(&self, key: &str) -> Option<&str> {
+        self.context
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Return the error code returned by the underlying service.
+    ///
+    /// For example, `NoSuchKey` for s3.
+    pub fn service_code(&self) -> Option<&str> {
+        self.context(ERROR_CONTEXT_SERVICE_CODE)
+    }
+
+    /// Return the error message returned by the underlying service.
+    pub fn service_message(&self) -> Option<&str> {
+        self.context(ERROR_CONTEXT_SERVICE_MESSAGE)
+    }
+
+    /// Return the request id returned by the underlying service.
+    ///
+    /// For example, `x-amz-request-id` for s3.
+    pub fn request_id(&self) -> Option<&str> {
+        self.context(ERROR_CONTEXT_REQUEST_ID)
+    }
 }
 
 impl From<Error> for io::Error {
@@ -348,6 +436,7 @@ impl From<Error> for io::Error {
             ErrorKind::NotFound => io::ErrorKind::NotFound,
             ErrorKind::PermissionDenied => io::ErrorKind::PermissionDenied,
             ErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+            ErrorKind::ChecksumMismatch => io::ErrorKind::InvalidData,
             _ => io::ErrorKind::Other,
         };
 
@@ -383,6 +472,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_error_classification() {
+        assert!(ErrorKind::RateLimited.is_transient());
+        assert!(!ErrorKind::NotFound.is_transient());
+        assert!(!ErrorKind::Unexpected.is_transient());
+
+        let err = Error::new(ErrorKind::NotFound, "not found");
+        assert!(!err.is_temporary());
+
+        let err = Error::new(ErrorKind::Unexpected, "service unavailable").set_temporary();
+        assert!(err.is_temporary());
+
+        let err = err.set_persistent();
+        assert!(!err.is_temporary());
+    }
+
     #[test]
     fn test_error_debug() {
         let s = format!("{:?}", Lazy::force(&TEST_ERROR));
@@ -44,6 +44,12 @@ pub struct Lister {
     acc: FusedAccessor,
     /// required_metakey is the metakey required by users.
     required_metakey: FlagSet<Metakey>,
+    /// remaining is the number of entries that could still be returned,
+    /// `None` means no limit.
+    remaining: Option<usize>,
+    /// The listed directory to be trimmed from paths of entries, `None`
+    /// means returning paths relative to the root.
+    relative_to: Option<String>,
 
     buf: VecDeque<oio::Entry>,
     pager: Option<oio::Pager>,
@@ -60,11 +66,16 @@ impl Lister {
     /// Create a new lister.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
+        let remaining = args.max_results();
+        // Paths of entries listed from root are relative to root already.
+        let relative_to = (!args.absolute() && path != "/").then(|| path.to_string());
         let (_, pager) = acc.list(path, args).await?;
 
         Ok(Self {
             acc,
             required_metakey,
+            remaining,
+            relative_to,
 
             buf: VecDeque::new(),
             pager: Some(pager),
@@ -74,6 +85,44 @@ impl Lister {
     }
 }
 
+impl Lister {
+    /// Return the opaque token to resume listing from, which could be
+    /// passed to [`FutureLister::continuation`] to construct a new lister.
+    ///
+    /// Tokens are only available at the boundary of pages, `None` will be
+    /// returned if:
+    ///
+    /// - Service doesn't support native continuation.
+    /// - There are fetched entries that have not been returned yet.
+    /// - All entries have been returned.
+    ///
+    /// Tokens are specific to services and the listed path, and could
+    /// expire after a while.
+    pub fn continuation_token(&self) -> Option<String> {
+        if !self.buf.is_empty() || self.stating.is_some() || self.remaining == Some(0) {
+            return None;
+        }
+
+        self.pager.as_ref()?.continuation_token()
+    }
+
+    fn yield_entry(&mut self, mut entry: Entry) -> Poll<Option<Result<Entry>>> {
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        if let Some(dir) = &self.relative_to {
+            let (path, metadata) = entry.into_parts();
+            let path = match path.strip_prefix(dir.as_str()) {
+                Some("") => "/".to_string(),
+                Some(v) => v.to_string(),
+                None => path,
+            };
+            entry = Entry::new(path, metadata);
+        }
+        Poll::Ready(Some(Ok(entry)))
+    }
+}
+
 impl Stream for Lister {
     type Item = Result<Entry>;
 
@@ -83,14 +132,23 @@ impl Stream for Lister {
             let metadata = rp?.into_metadata();
 
             self.stating = None;
-            return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
+            return self.yield_entry(Entry::new(path, metadata));
+        }
+
+        // All entries have been returned, close the lister so that no more
+        // pages will be fetched.
+        if self.remaining == Some(0) {
+            self.buf.clear();
+            self.pager = None;
+            self.listing = None;
+            return Poll::Ready(None);
         }
 
         if let Some(oe) = self.buf.pop_front() {
             let (path, metadata) = oe.into_entry().into_parts();
             // TODO: we can optimize this by checking the provided metakey provided by services.
             if metadata.contains_bit(self.required_metakey) {
-                return Poll::Ready(Some(Ok(Entry::new(path, metadata))));
+                return self.yield_entry(Entry::new(path, metadata));
             }
 
             let acc = self.acc.clone();
@@ -132,6 +190,48 @@ impl Stream for Lister {
     }
 }
 
+/// SortKey is the key used to sort entries by [`Operator::list_with`].
+///
+/// Entries are always sorted in ascending order, entries that have the
+/// same key are sorted by path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortKey {
+    /// Sort entries by path.
+    Path,
+    /// Sort entries by content length.
+    ContentLength,
+    /// Sort entries by last modified time, entries without last modified
+    /// time come first.
+    LastModified,
+}
+
+impl SortKey {
+    /// The metakey required to sort entries by this key.
+    pub(crate) fn metakey(self) -> FlagSet<Metakey> {
+        match self {
+            SortKey::Path => Metakey::Mode.into(),
+            SortKey::ContentLength => Metakey::ContentLength.into(),
+            SortKey::LastModified => Metakey::LastModified.into(),
+        }
+    }
+
+    /// Sort entries by this key.
+    pub(crate) fn sort(self, entries: &mut [Entry]) {
+        match self {
+            SortKey::Path => entries.sort_by(|a, b| a.path().cmp(b.path())),
+            SortKey::ContentLength => entries.sort_by(|a, b| {
+                (a.metadata().content_length(), a.path())
+                    .cmp(&(b.metadata().content_length(), b.path()))
+            }),
+            SortKey::LastModified => entries.sort_by(|a, b| {
+                (a.metadata().last_modified(), a.path())
+                    .cmp(&(b.metadata().last_modified(), b.path()))
+            }),
+        }
+    }
+}
+
 /// BlockingLister is designed to list entries at given path in a blocking
 /// manner.
 ///
@@ -177,3 +277,167 @@ impl Iterator for BlockingLister {
         self.next()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    const PAGE_SIZE: usize = 10;
+
+    /// A pager that returns endless pages and counts how many pages have
+    /// been fetched.
+    struct CountingPager {
+        pages: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl oio::Page for CountingPager {
+        async fn next(&mut self) -> Result<Option<Vec<oio::Entry>>> {
+            let page = self.pages.fetch_add(1, Ordering::SeqCst);
+            let entries = (0..PAGE_SIZE)
+                .map(|i| {
+                    oio::Entry::new(
+                        &format!("dir/{}", page * PAGE_SIZE + i),
+                        Metadata::new(EntryMode::FILE).with_content_length(i as u64),
+                    )
+                })
+                .collect();
+            Ok(Some(entries))
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountingLayer {
+        pages: Arc<AtomicUsize>,
+        stats: Arc<AtomicUsize>,
+    }
+
+    impl<A: Accessor> Layer<A> for CountingLayer {
+        type LayeredAccessor = CountingAccessor;
+
+        fn layer(&self, _: A) -> Self::LayeredAccessor {
+            CountingAccessor {
+                pages: self.pages.clone(),
+                stats: self.stats.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct CountingAccessor {
+        pages: Arc<AtomicUsize>,
+        stats: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Accessor for CountingAccessor {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = CountingPager;
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.set_scheme(Scheme::Custom("counting"));
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            self.stats.fetch_add(1, Ordering::SeqCst);
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_bit(Metakey::Complete),
+            ))
+        }
+
+        async fn list(&self, _: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+            Ok((
+                RpList::default(),
+                CountingPager {
+                    pages: self.pages.clone(),
+                },
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_with_max_results() -> Result<()> {
+        let pages = Arc::new(AtomicUsize::new(0));
+        let op = Operator::new(Memory::default())?
+            .layer(CountingLayer {
+                pages: pages.clone(),
+                stats: Arc::default(),
+            })
+            .finish();
+
+        let entries = op.list_with("dir/").max_results(25).await?;
+        assert_eq!(entries.len(), 25);
+        assert_eq!(entries[24].path(), "dir/24");
+        // 25 entries only need 3 pages.
+        assert_eq!(pages.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lister_with_max_results() -> Result<()> {
+        let pages = Arc::new(AtomicUsize::new(0));
+        let op = Operator::new(Memory::default())?
+            .layer(CountingLayer {
+                pages: pages.clone(),
+                stats: Arc::default(),
+            })
+            .finish();
+
+        // Max results aligned with page size must not fetch the next page.
+        let mut lister = op.lister_with("dir/").max_results(20).await?;
+        let mut count = 0;
+        while lister.try_next().await?.is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 20);
+        assert_eq!(pages.load(Ordering::SeqCst), 2);
+
+        // Lister has been closed.
+        assert!(lister.try_next().await?.is_none());
+        assert_eq!(pages.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata_from_list_without_stat() -> Result<()> {
+        let stats = Arc::new(AtomicUsize::new(0));
+        let op = Operator::new(Memory::default())?
+            .layer(CountingLayer {
+                pages: Arc::default(),
+                stats: stats.clone(),
+            })
+            .finish();
+
+        let entries = op
+            .list_with("dir/")
+            .metakey(Metakey::ContentLength)
+            .max_results(2)
+            .await?;
+        assert_eq!(stats.load(Ordering::SeqCst), 0);
+
+        // Listing has provided the size, no extra stat is required.
+        let meta = op.metadata(&entries[1], Metakey::ContentLength).await?;
+        assert_eq!(meta.content_length(), 1);
+        assert_eq!(stats.load(Ordering::SeqCst), 0);
+
+        // Etag is not provided by listing, stat is required.
+        op.metadata(&entries[1], Metakey::Etag).await?;
+        assert_eq!(stats.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
@@ -43,8 +43,13 @@ pub struct Metadata {
     content_range: Option<BytesContentRange>,
     content_type: Option<String>,
     etag: Option<String>,
+    etag_weak: bool,
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
+    server_side_encryption: Option<String>,
+    object_lock_mode: Option<ObjectLockMode>,
+    object_lock_retain_until: Option<DateTime<Utc>>,
+    request_charged: bool,
 }
 
 impl Metadata {
@@ -69,8 +74,13 @@ impl Metadata {
             content_range: None,
             last_modified: None,
             etag: None,
+            etag_weak: false,
             content_disposition: None,
             version: None,
+            server_side_encryption: None,
+            object_lock_mode: None,
+            object_lock_retain_until: None,
+            request_charged: false,
         }
     }
 
@@ -355,12 +365,15 @@ impl Metadata {
     /// `ETag` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.etag)
     /// Refer to [MDN ETag](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag) for more information.
     ///
-    /// OpenDAL will return this value AS-IS like the following:
+    /// OpenDAL will return the canonical value without quotes and weak
+    /// indicator no matter how services return it:
     ///
-    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"`
-    /// - `W/"0815"`
+    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"` => `33a64df551425fcc55e4d42a148795d9f25f89d4`
+    /// - `W/"0815"` => `0815`
     ///
-    /// `"` is part of etag.
+    /// Use [`Metadata::is_etag_weak`] to check if this etag is weak. The
+    /// returned value can be passed to `if_match` and `if_none_match`
+    /// directly, OpenDAL will quote it while sending requests.
     ///
     /// # Panics
     ///
@@ -375,19 +388,35 @@ impl Metadata {
         self.etag.as_deref()
     }
 
+    /// Check if the ETag of this entry is a weak validator like `W/"0815"`.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Etag`], otherwise it will panic.
+    pub fn is_etag_weak(&self) -> bool {
+        debug_assert!(
+            self.bit.contains(Metakey::Etag) || self.bit.contains(Metakey::Complete),
+            "visiting not set metadata: etag, maybe a bug"
+        );
+
+        self.etag_weak
+    }
+
     /// Set ETag of this entry.
     ///
     /// `ETag` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.etag)
     /// Refer to [MDN ETag](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag) for more information.
     ///
-    /// OpenDAL will return this value AS-IS like the following:
-    ///
-    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"`
-    /// - `W/"0815"`
+    /// Both quoted and unquoted etags are accepted, they will be normalized
+    /// like the following:
     ///
-    /// `"` is part of etag, don't trim it before setting.
+    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"` => `33a64df551425fcc55e4d42a148795d9f25f89d4`
+    /// - `W/"0815"` => `0815` (weak)
     pub fn set_etag(&mut self, v: &str) -> &mut Self {
+        let (weak, v) = normalize_etag(v);
         self.etag = Some(v.to_string());
+        self.etag_weak = weak;
         self.bit |= Metakey::Etag;
         self
     }
@@ -397,15 +426,13 @@ impl Metadata {
     /// `ETag` is defined by [RFC 7232](https://httpwg.org/specs/rfc7232.html#header.etag)
     /// Refer to [MDN ETag](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/ETag) for more information.
     ///
-    /// OpenDAL will return this value AS-IS like the following:
-    ///
-    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"`
-    /// - `W/"0815"`
+    /// Both quoted and unquoted etags are accepted, they will be normalized
+    /// like the following:
     ///
-    /// `"` is part of etag, don't trim it before setting.
+    /// - `"33a64df551425fcc55e4d42a148795d9f25f89d4"` => `33a64df551425fcc55e4d42a148795d9f25f89d4`
+    /// - `W/"0815"` => `0815` (weak)
     pub fn with_etag(mut self, v: String) -> Self {
-        self.etag = Some(v);
-        self.bit |= Metakey::Etag;
+        self.set_etag(&v);
         self
     }
 
@@ -508,6 +535,130 @@ impl Metadata {
         self.bit |= Metakey::Version;
         self
     }
+
+    /// Server side encryption of this entry.
+    ///
+    /// The value is the algorithm used by service to encrypt content at
+    /// rest, like `AES256` or `aws:kms` in AWS S3.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::ServerSideEncryption`], otherwise it will panic.
+    pub fn server_side_encryption(&self) -> Option<&str> {
+        debug_assert!(
+            self.bit.contains(Metakey::ServerSideEncryption)
+                || self.bit.contains(Metakey::Complete),
+            "visiting not set metadata: server_side_encryption, maybe a bug"
+        );
+
+        self.server_side_encryption.as_deref()
+    }
+
+    /// Set server side encryption of this entry.
+    pub fn with_server_side_encryption(mut self, v: String) -> Self {
+        self.server_side_encryption = Some(v);
+        self.bit |= Metakey::ServerSideEncryption;
+        self
+    }
+
+    /// Set server side encryption of this entry.
+    pub fn set_server_side_encryption(&mut self, v: &str) -> &mut Self {
+        self.server_side_encryption = Some(v.to_string());
+        self.bit |= Metakey::ServerSideEncryption;
+        self
+    }
+
+    /// Object lock mode of this entry.
+    ///
+    /// The entry can't be deleted or overwritten until
+    /// [`Metadata::object_lock_retain_until`] if it's set.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::ObjectLock`], otherwise it will panic.
+    pub fn object_lock_mode(&self) -> Option<ObjectLockMode> {
+        debug_assert!(
+            self.bit.contains(Metakey::ObjectLock) || self.bit.contains(Metakey::Complete),
+            "visiting not set metadata: object_lock_mode, maybe a bug"
+        );
+
+        self.object_lock_mode
+    }
+
+    /// Set object lock mode of this entry.
+    pub fn with_object_lock_mode(mut self, v: ObjectLockMode) -> Self {
+        self.object_lock_mode = Some(v);
+        self.bit |= Metakey::ObjectLock;
+        self
+    }
+
+    /// Set object lock mode of this entry.
+    pub fn set_object_lock_mode(&mut self, v: ObjectLockMode) -> &mut Self {
+        self.object_lock_mode = Some(v);
+        self.bit |= Metakey::ObjectLock;
+        self
+    }
+
+    /// The date until which the object lock of this entry is retained.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::ObjectLock`], otherwise it will panic.
+    pub fn object_lock_retain_until(&self) -> Option<DateTime<Utc>> {
+        debug_assert!(
+            self.bit.contains(Metakey::ObjectLock) || self.bit.contains(Metakey::Complete),
+            "visiting not set metadata: object_lock_retain_until, maybe a bug"
+        );
+
+        self.object_lock_retain_until
+    }
+
+    /// Set the date until which the object lock of this entry is retained.
+    pub fn with_object_lock_retain_until(mut self, v: DateTime<Utc>) -> Self {
+        self.object_lock_retain_until = Some(v);
+        self.bit |= Metakey::ObjectLock;
+        self
+    }
+
+    /// Set the date until which the object lock of this entry is retained.
+    pub fn set_object_lock_retain_until(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.object_lock_retain_until = Some(v);
+        self.bit |= Metakey::ObjectLock;
+        self
+    }
+
+    /// Whether the request that fetched this entry has been charged to the
+    /// requester, like `x-amz-request-charged` in AWS S3 requester pays buckets.
+    ///
+    /// # Panics
+    ///
+    /// This value is only available when calling on result of `stat` or `read` with
+    /// [`Metakey::RequestCharged`], otherwise it will panic.
+    pub fn request_charged(&self) -> bool {
+        debug_assert!(
+            self.bit.contains(Metakey::RequestCharged) || self.bit.contains(Metakey::Complete),
+            "visiting not set metadata: request_charged, maybe a bug"
+        );
+
+        self.request_charged
+    }
+
+    /// Set whether the request of this entry has been charged to the requester.
+    pub fn with_request_charged(mut self, v: bool) -> Self {
+        self.request_charged = v;
+        self.bit |= Metakey::RequestCharged;
+        self
+    }
+
+    /// Set whether the request of this entry has been charged to the requester.
+    pub fn set_request_charged(&mut self, v: bool) -> &mut Self {
+        self.request_charged = v;
+        self.bit |= Metakey::RequestCharged;
+        self
+    }
 }
 
 flags! {
@@ -549,5 +700,46 @@ flags! {
         LastModified,
         /// Key for version.
         Version,
+        /// Key for server side encryption.
+        ServerSideEncryption,
+        /// Key for object lock mode and retain until date.
+        ObjectLock,
+        /// Key for request charged.
+        RequestCharged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_etag_normalization() {
+        let cases = vec![
+            ("\"33a64df5\"", "33a64df5", false),
+            ("33a64df5", "33a64df5", false),
+            ("W/\"0815\"", "0815", true),
+        ];
+
+        for (input, expected, weak) in cases {
+            let mut m = Metadata::new(EntryMode::FILE);
+            m.set_etag(input);
+            assert_eq!(m.etag(), Some(expected), "{input}");
+            assert_eq!(m.is_etag_weak(), weak, "{input}");
+
+            let m = Metadata::new(EntryMode::FILE).with_etag(input.to_string());
+            assert_eq!(m.etag(), Some(expected), "{input}");
+            assert_eq!(m.is_etag_weak(), weak, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_etag_conditional_header() {
+        let m = Metadata::new(EntryMode::FILE).with_etag("W/\"0815\"".to_string());
+        let op = OpStat::new().with_if_none_match(m.etag().unwrap());
+        assert_eq!(op.if_none_match(), Some("\"0815\""));
+
+        let op = OpRead::new().with_if_match("33a64df5");
+        assert_eq!(op.if_match(), Some("\"33a64df5\""));
     }
 }
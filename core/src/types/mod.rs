@@ -25,6 +25,9 @@ mod metadata;
 pub use metadata::Metadata;
 pub use metadata::Metakey;
 
+mod buffer;
+pub use buffer::Buffer;
+
 mod reader;
 pub use reader::BlockingReader;
 pub use reader::Reader;
@@ -36,9 +39,13 @@ pub use writer::Writer;
 mod appender;
 pub use appender::Appender;
 
+mod multipart;
+pub use multipart::MultipartWriter;
+
 mod list;
 pub use list::BlockingLister;
 pub use list::Lister;
+pub use list::SortKey;
 
 mod operator;
 pub use operator::operator_functions;
@@ -46,6 +53,7 @@ pub use operator::operator_futures;
 pub use operator::BlockingOperator;
 pub use operator::Operator;
 pub use operator::OperatorBuilder;
+pub use operator::OperatorConfig;
 pub use operator::OperatorInfo;
 
 mod builder;
@@ -61,3 +69,12 @@ pub use scheme::Scheme;
 
 mod capability;
 pub use capability::Capability;
+
+mod checksum;
+pub use checksum::ChecksumAlgorithm;
+
+mod object_lock;
+pub use object_lock::ObjectLockMode;
+
+mod disk_usage;
+pub use disk_usage::DiskUsage;
@@ -0,0 +1,315 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// MultipartWriter is designed to upload an object by parts which are
+/// controlled by callers.
+///
+/// # Usage
+///
+/// - `write_part` uploads the part with given part number, parts can be
+///   uploaded in any order or concurrently.
+/// - `complete` builds the final object from all uploaded parts, sorted by
+///   their part numbers.
+/// - `abort` cancels the upload and purges all uploaded parts.
+///
+/// ## Notes
+///
+/// Part number starts from `0`. Services may have limits on part sizes, for
+/// example, AWS S3 requires all parts except the last one to be at least
+/// 5 MiB. Please refer to [`Capability::write_multi_min_size`] for details.
+///
+/// The object only becomes visible after `complete` returns successfully.
+pub struct MultipartWriter {
+    inner: oio::MultipartUploader,
+    upload_id: String,
+    parts: Mutex<Vec<oio::MultipartUploadPart>>,
+}
+
+impl Debug for MultipartWriter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultipartWriter")
+            .field("upload_id", &self.upload_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MultipartWriter {
+    /// Create a new multipart writer and initiate the upload.
+    ///
+    /// We don't want to expose those details to users so keep this function
+    /// in crate only.
+    pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpWrite) -> Result<Self> {
+        let (_, inner) = acc.multipart(path, op).await?;
+        let upload_id = inner.initiate_part().await?;
+
+        Ok(MultipartWriter {
+            inner,
+            upload_id,
+            parts: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Get the upload id of this multipart upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Upload a part with given part number.
+    ///
+    /// Uploading the same part number again will overwrite the previous one.
+    pub async fn write_part(&self, part_number: usize, bs: impl Into<Bytes>) -> Result<()> {
+        let bs = bs.into();
+        let part = self
+            .inner
+            .write_part(
+                &self.upload_id,
+                part_number,
+                bs.len() as u64,
+                AsyncBody::Bytes(bs),
+            )
+            .await?;
+
+        let mut parts = self.parts.lock().expect("lock must succeed");
+        parts.retain(|v| v.part_number != part.part_number);
+        parts.push(part);
+        Ok(())
+    }
+
+    /// Complete the upload with all uploaded parts.
+    pub async fn complete(self) -> Result<()> {
+        let mut parts = self.parts.into_inner().expect("lock must succeed");
+        if parts.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "multipart upload must contain at least one part",
+            ));
+        }
+        parts.sort_by_key(|v| v.part_number);
+
+        self.inner.complete_part(&self.upload_id, &parts).await
+    }
+
+    /// Abort the upload and purge all uploaded parts.
+    pub async fn abort(self) -> Result<()> {
+        self.inner.abort_part(&self.upload_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use futures::try_join;
+
+    use super::*;
+    use crate::layers::DryRunAction;
+    use crate::layers::DryRunLayer;
+    use crate::layers::TeeLayer;
+    use crate::Operator;
+    use crate::OperatorBuilder;
+
+    /// MockUploader keeps all uploaded parts in memory and builds the object
+    /// by the order of parts passed to `complete_part`.
+    #[derive(Debug, Default, Clone)]
+    struct MockUploader {
+        parts: Arc<Mutex<HashMap<usize, Bytes>>>,
+        object: Arc<Mutex<Option<Bytes>>>,
+    }
+
+    #[async_trait]
+    impl oio::MultipartUploadWrite for MockUploader {
+        async fn write_once(&self, _: u64, _: AsyncBody) -> Result<()> {
+            unreachable!("write_once should not be called by MultipartWriter")
+        }
+
+        async fn initiate_part(&self) -> Result<String> {
+            Ok("upload".to_string())
+        }
+
+        async fn write_part(
+            &self,
+            _: &str,
+            part_number: usize,
+            _: u64,
+            body: AsyncBody,
+        ) -> Result<oio::MultipartUploadPart> {
+            let bs = match body {
+                AsyncBody::Bytes(bs) => bs,
+                _ => unreachable!("MultipartWriter always uploads bytes"),
+            };
+            self.parts.lock().unwrap().insert(part_number, bs);
+
+            Ok(oio::MultipartUploadPart {
+                part_number,
+                etag: part_number.to_string(),
+                checksum: None,
+            })
+        }
+
+        async fn complete_part(&self, _: &str, parts: &[oio::MultipartUploadPart]) -> Result<()> {
+            let uploaded = self.parts.lock().unwrap();
+            let mut content = Vec::new();
+            for part in parts {
+                content.extend_from_slice(&uploaded[&part.part_number]);
+            }
+            *self.object.lock().unwrap() = Some(Bytes::from(content));
+            Ok(())
+        }
+
+        async fn abort_part(&self, _: &str) -> Result<()> {
+            self.parts.lock().unwrap().clear();
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        uploader: MockUploader,
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.set_capability(Capability {
+                write: true,
+                write_can_multi: true,
+                ..Default::default()
+            });
+            am
+        }
+
+        async fn multipart(
+            &self,
+            _: &str,
+            _: OpWrite,
+        ) -> Result<(RpWrite, oio::MultipartUploader)> {
+            Ok((RpWrite::default(), Box::new(self.uploader.clone())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_parts_out_of_order() -> Result<()> {
+        let uploader = MockUploader::default();
+        let op = OperatorBuilder::new(MockService {
+            uploader: uploader.clone(),
+        })
+        .finish();
+
+        let w = op.multipart_writer("test").await?;
+        assert_eq!(w.upload_id(), "upload");
+
+        w.write_part(2, "c").await?;
+        try_join!(w.write_part(0, "a"), w.write_part(1, "b"))?;
+        w.complete().await?;
+
+        assert_eq!(
+            uploader.object.lock().unwrap().clone(),
+            Some(Bytes::from("abc"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_complete_without_parts() -> Result<()> {
+        let op = OperatorBuilder::new(MockService::default()).finish();
+
+        let w = op.multipart_writer("test").await?;
+        let err = w.complete().await.expect_err("complete must fail");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dry_run() -> Result<()> {
+        let uploader = MockUploader::default();
+        let dry_run = DryRunLayer::new();
+        let op = OperatorBuilder::new(MockService {
+            uploader: uploader.clone(),
+        })
+        .finish()
+        .layer(dry_run.clone());
+
+        let w = op.multipart_writer("test").await?;
+        w.write_part(0, "abc").await?;
+        w.complete().await?;
+
+        assert!(uploader.parts.lock().unwrap().is_empty());
+        assert_eq!(uploader.object.lock().unwrap().clone(), None);
+        assert_eq!(
+            dry_run.actions(),
+            vec![DryRunAction::Write {
+                path: "test".to_string(),
+                size: 3,
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tee() -> Result<()> {
+        let uploader = MockUploader::default();
+        let secondary = Operator::new(services::Memory::default())?.finish();
+        let op = OperatorBuilder::new(MockService {
+            uploader: uploader.clone(),
+        })
+        .finish()
+        .layer(TeeLayer::new(secondary.clone()));
+
+        let w = op.multipart_writer("test").await?;
+        w.write_part(1, "c").await?;
+        w.write_part(0, "ab").await?;
+        w.complete().await?;
+
+        assert_eq!(
+            uploader.object.lock().unwrap().clone(),
+            Some(Bytes::from("abc"))
+        );
+        assert_eq!(secondary.read("test").await?, b"abc");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unsupported() {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+
+        let err = op
+            .multipart_writer("test")
+            .await
+            .expect_err("memory doesn't support multipart");
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+}
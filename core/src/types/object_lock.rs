@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use crate::Error;
+use crate::ErrorKind;
+
+/// Retention modes of object lock, which prevent objects from being
+/// deleted or overwritten until the retain until date.
+///
+/// # Notes
+///
+/// - ObjectLockMode is `non_exhaustive`, new variant COULD be added at any time.
+/// - Object lock must be enabled on the bucket, please check the docs of
+///   services for details.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ObjectLockMode {
+    /// Governance mode, users with special permissions can still
+    /// overwrite or delete the object or alter the lock settings.
+    Governance,
+    /// Compliance mode, no one (including the root user) can overwrite
+    /// or delete the object until the retain until date.
+    Compliance,
+}
+
+impl ObjectLockMode {
+    /// Convert self into static str.
+    pub fn into_static(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl FromStr for ObjectLockMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "governance" => Ok(ObjectLockMode::Governance),
+            "compliance" => Ok(ObjectLockMode::Compliance),
+            _ => Err(
+                Error::new(ErrorKind::InvalidInput, "object lock mode is not supported")
+                    .with_context("mode", s),
+            ),
+        }
+    }
+}
+
+impl From<ObjectLockMode> for &'static str {
+    fn from(v: ObjectLockMode) -> Self {
+        match v {
+            ObjectLockMode::Governance => "governance",
+            ObjectLockMode::Compliance => "compliance",
+        }
+    }
+}
+
+impl Display for ObjectLockMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_static())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_object_lock_mode() {
+        assert_eq!(
+            "GOVERNANCE".parse::<ObjectLockMode>().unwrap(),
+            ObjectLockMode::Governance
+        );
+        assert_eq!(
+            "compliance".parse::<ObjectLockMode>().unwrap(),
+            ObjectLockMode::Compliance
+        );
+        assert_eq!(
+            "legal-hold".parse::<ObjectLockMode>().unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(ObjectLockMode::Compliance.to_string(), "compliance");
+    }
+}
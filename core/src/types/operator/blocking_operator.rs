@@ -17,8 +17,10 @@
 
 use std::io::Read;
 use std::ops::RangeBounds;
+use std::time::Duration;
 
 use bytes::Bytes;
+use flagset::FlagSet;
 
 use super::operator_functions::*;
 use crate::raw::*;
@@ -122,8 +124,8 @@ impl BlockingOperator {
     /// - Want detect the outside changes of path.
     /// - Don't want to read from cached metadata.
     ///
-    /// You may want to use `metadata` if you are working with entries
-    /// returned by [`Lister`]. It's highly possible that metadata
+    /// You may want to use [`BlockingOperator::metadata`] if you are working with entries
+    /// returned by [`BlockingLister`]. It's highly possible that metadata
     /// you want has already been cached.
     ///
     /// # Examples
@@ -152,6 +154,43 @@ impl BlockingOperator {
         Ok(meta)
     }
 
+    /// Get the metadata of given entry with required `metakey`.
+    ///
+    /// # Notes
+    ///
+    /// Entries returned by [`BlockingLister`] may carry metadata that has
+    /// already been fetched during listing. If the cached metadata contains
+    /// all the required `metakey`, it will be returned directly without
+    /// sending any request. Otherwise, `stat` will be called to fetch
+    /// the complete metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use opendal::Metakey;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// for entry in op.list("dir/")? {
+    ///     let entry = entry?;
+    ///     let meta = op.metadata(&entry, Metakey::ContentLength)?;
+    ///     println!("{} has size {}", entry.path(), meta.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn metadata(
+        &self,
+        entry: &Entry,
+        metakey: impl Into<FlagSet<Metakey>>,
+    ) -> Result<Metadata> {
+        if entry.metadata().contains_bit(metakey) {
+            return Ok(entry.metadata().clone());
+        }
+
+        self.stat(entry.path())
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -419,7 +458,9 @@ impl BlockingOperator {
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
+    /// - `from` and `to` must be a file, unless the service supports renaming
+    ///   directories natively (see [`Capability::rename_can_dir`]), in which
+    ///   case both of them can be directories.
     /// - `to` will be overwritten if it exists.
     /// - If `from` and `to` are the same, a `IsSameFile` error will occur.
     ///
@@ -436,8 +477,14 @@ impl BlockingOperator {
     /// ```
     pub fn rename(&self, from: &str, to: &str) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
+        // Rename between directories is allowed if service supports it.
+        let rename_dir = self.info().capability().rename_can_dir
+            && validate_path(&from, EntryMode::DIR)
+            && validate_path(&to, EntryMode::DIR);
+
+        if !rename_dir && !validate_path(&from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
                     .with_operation("BlockingOperator::move")
@@ -446,9 +493,7 @@ impl BlockingOperator {
             );
         }
 
-        let to = normalize_path(to);
-
-        if !validate_path(&to, EntryMode::FILE) {
+        if !rename_dir && !validate_path(&to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation("BlockingOperator::move")
@@ -589,6 +634,7 @@ impl BlockingOperator {
     /// # Notes
     ///
     /// - Delete not existing error won't return errors.
+    /// - Deleting a non-empty directory requires `recursive`.
     ///
     /// # Examples
     ///
@@ -818,3 +864,84 @@ impl BlockingOperator {
         Ok(BlockingLister::new(pager))
     }
 }
+
+/// # Operator presign API.
+impl BlockingOperator {
+    /// Presign an operation for stat(head).
+    ///
+    /// Services that don't support blocking natively can still presign with
+    /// [`BlockingLayer`][crate::layers::BlockingLayer], which drives the async
+    /// presign on the given runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// fn test(op: BlockingOperator) -> Result<()> {
+    ///     let signed_req = op.presign_stat("test", Duration::from_secs(3600))?;
+    ///     let req = http::Request::builder()
+    ///         .method(signed_req.method())
+    ///         .uri(signed_req.uri())
+    ///         .body(())?;
+    ///
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_stat(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpStat::new(), expire);
+
+        let rp = self.inner().blocking_presign(&path, op)?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// fn test(op: BlockingOperator) -> Result<()> {
+    ///     let signed_req = op.presign_read("test.txt", Duration::from_secs(3600))?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_read(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpRead::new(), expire);
+
+        let rp = self.inner().blocking_presign(&path, op)?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for write.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// use std::time::Duration;
+    ///
+    /// fn test(op: BlockingOperator) -> Result<()> {
+    ///     let signed_req = op.presign_write("test.txt", Duration::from_secs(3600))?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn presign_write(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpWrite::new(), expire);
+
+        let rp = self.inner().blocking_presign(&path, op)?;
+        Ok(rp.into_presigned_request())
+    }
+}
@@ -128,6 +128,10 @@ impl Operator {
     /// extra vtable lookup and unable to inline. But from_map requires generic
     /// type parameter which is not always easy to be used.
     ///
+    /// via_map will check the required keys of given scheme before building,
+    /// and return [`ErrorKind::ConfigInvalid`] with the missing keys in the
+    /// `key` context if any of them is absent or empty.
+    ///
     /// # Examples
     ///
     /// ```
@@ -152,6 +156,10 @@ impl Operator {
     /// }
     /// ```
     pub fn via_map(scheme: Scheme, map: HashMap<String, String>) -> Result<Operator> {
+        check_required_keys(scheme, &map)?;
+
+        let config = Arc::new(OperatorConfig::new(scheme, map.clone()).redacted());
+
         let op = match scheme {
             #[cfg(feature = "services-azblob")]
             Scheme::Azblob => Self::from_map::<services::Azblob>(map)?.finish(),
@@ -163,6 +171,10 @@ impl Operator {
             Scheme::Cos => Self::from_map::<services::Cos>(map)?.finish(),
             #[cfg(feature = "services-dashmap")]
             Scheme::Dashmap => Self::from_map::<services::Dashmap>(map)?.finish(),
+            #[cfg(feature = "services-d1")]
+            Scheme::D1 => Self::from_map::<services::D1>(map)?.finish(),
+            #[cfg(feature = "services-dropbox")]
+            Scheme::Dropbox => Self::from_map::<services::Dropbox>(map)?.finish(),
             #[cfg(feature = "services-etcd")]
             Scheme::Etcd => Self::from_map::<services::Etcd>(map)?.finish(),
             #[cfg(feature = "services-foundationdb")]
@@ -177,12 +189,16 @@ impl Operator {
             Scheme::Ghac => Self::from_map::<services::Ghac>(map)?.finish(),
             #[cfg(feature = "services-hdfs")]
             Scheme::Hdfs => Self::from_map::<services::Hdfs>(map)?.finish(),
+            #[cfg(feature = "services-hdfs-native")]
+            Scheme::HdfsNative => Self::from_map::<services::HdfsNative>(map)?.finish(),
             #[cfg(feature = "services-http")]
             Scheme::Http => Self::from_map::<services::Http>(map)?.finish(),
             #[cfg(feature = "services-ipfs")]
             Scheme::Ipfs => Self::from_map::<services::Ipfs>(map)?.finish(),
             #[cfg(feature = "services-ipmfs")]
             Scheme::Ipmfs => Self::from_map::<services::Ipmfs>(map)?.finish(),
+            #[cfg(feature = "services-libsql")]
+            Scheme::Libsql => Self::from_map::<services::Libsql>(map)?.finish(),
             #[cfg(feature = "services-memcached")]
             Scheme::Memcached => Self::from_map::<services::Memcached>(map)?.finish(),
             #[cfg(feature = "services-memory")]
@@ -201,6 +217,8 @@ impl Operator {
             Scheme::Oss => Self::from_map::<services::Oss>(map)?.finish(),
             #[cfg(feature = "services-persy")]
             Scheme::Persy => Self::from_map::<services::Persy>(map)?.finish(),
+            #[cfg(feature = "services-postgresql")]
+            Scheme::Postgresql => Self::from_map::<services::Postgresql>(map)?.finish(),
             #[cfg(feature = "services-redis")]
             Scheme::Redis => Self::from_map::<services::Redis>(map)?.finish(),
             #[cfg(feature = "services-rocksdb")]
@@ -213,6 +231,8 @@ impl Operator {
             Scheme::Sled => Self::from_map::<services::Sled>(map)?.finish(),
             #[cfg(feature = "services-supabase")]
             Scheme::Supabase => Self::from_map::<services::Supabase>(map)?.finish(),
+            #[cfg(feature = "services-tikv")]
+            Scheme::Tikv => Self::from_map::<services::Tikv>(map)?.finish(),
             #[cfg(feature = "services-vercel-artifacts")]
             Scheme::VercelArtifacts => Self::from_map::<services::VercelArtifacts>(map)?.finish(),
             #[cfg(feature = "services-wasabi")]
@@ -232,7 +252,37 @@ impl Operator {
             }
         };
 
-        Ok(op)
+        Ok(op.with_config(Some(config)))
+    }
+
+    /// Create a new operator from given config.
+    ///
+    /// Config is usually returned by [`Operator::config`] with secrets
+    /// redacted, set them back before building if they can't be loaded
+    /// from env.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use std::collections::HashMap;
+    ///
+    /// use opendal::Operator;
+    /// use opendal::OperatorConfig;
+    /// use opendal::Scheme;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let cfg = OperatorConfig::new(
+    ///     Scheme::Memory,
+    ///     HashMap::from([("root".to_string(), "/tmp".to_string())]),
+    /// );
+    /// let op = Operator::via_config(cfg)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn via_config(config: OperatorConfig) -> Result<Operator> {
+        let (scheme, map) = config.into_parts();
+        Self::via_map(scheme, map)
     }
 
     /// Create a new layer with dynamic dispatch.
@@ -266,12 +316,74 @@ impl Operator {
     /// ```
     #[must_use]
     pub fn layer<L: Layer<FusedAccessor>>(self, layer: L) -> Self {
+        let config = self.config.clone();
         Self::from_inner(Arc::new(
             TypeEraseLayer.layer(layer.layer(self.into_inner())),
         ))
+        .with_config(config)
     }
 }
 
+/// Get the keys that must be set to build services of given scheme.
+///
+/// Only the keys that services can't work without are listed here, keys
+/// that have default values or alternatives (like credentials loaded from
+/// env) are not included.
+fn required_keys(scheme: Scheme) -> &'static [&'static str] {
+    match scheme {
+        Scheme::Azblob => &["container", "endpoint"],
+        Scheme::Azdfs => &["filesystem", "endpoint"],
+        Scheme::Cacache => &["datadir"],
+        Scheme::Cos => &["bucket", "endpoint"],
+        Scheme::D1 => &["token", "account_id", "database_id", "table"],
+        Scheme::Fs => &["root"],
+        Scheme::Ftp => &["endpoint"],
+        Scheme::Gcs => &["bucket"],
+        Scheme::Gdrive => &["access_token"],
+        Scheme::Hdfs => &["name_node"],
+        Scheme::HdfsNative => &["name_node"],
+        Scheme::Http => &["endpoint"],
+        Scheme::Ipfs => &["endpoint"],
+        Scheme::Libsql => &["connection_string", "table"],
+        Scheme::Memcached => &["endpoint"],
+        Scheme::Obs => &["bucket", "endpoint"],
+        Scheme::Onedrive => &["access_token"],
+        Scheme::Oss => &["bucket", "endpoint"],
+        Scheme::Persy => &["datafile", "segment", "index"],
+        Scheme::Postgresql => &["connection_string", "table"],
+        Scheme::Redb => &["datadir", "table"],
+        Scheme::Rocksdb => &["datadir"],
+        Scheme::S3 => &["bucket"],
+        Scheme::Sftp => &["endpoint", "user"],
+        Scheme::Sled => &["datadir"],
+        Scheme::Tikv => &["endpoints"],
+        Scheme::VercelArtifacts => &["access_token"],
+        Scheme::Wasabi => &["bucket"],
+        Scheme::Webdav => &["endpoint"],
+        _ => &[],
+    }
+}
+
+/// Check that all required keys of given scheme are set in map.
+fn check_required_keys(scheme: Scheme, map: &HashMap<String, String>) -> Result<()> {
+    let missing: Vec<_> = required_keys(scheme)
+        .iter()
+        .copied()
+        .filter(|key| map.get(*key).map_or(true, |v| v.is_empty()))
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    Err(
+        Error::new(ErrorKind::ConfigInvalid, "required config is missing")
+            .with_operation("Operator::via_map")
+            .with_context("service", scheme)
+            .with_context("key", missing.join(",")),
+    )
+}
+
 /// OperatorBuilder is a typed builder to build an Operator.
 ///
 /// # Notes
@@ -373,3 +485,92 @@ impl<A: Accessor> OperatorBuilder<A> {
         Operator::from_inner(Arc::new(ob.accessor) as FusedAccessor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_via_map_missing_bucket() {
+        let map = HashMap::from([
+            ("endpoint".to_string(), "http://127.0.0.1:9000".to_string()),
+            ("region".to_string(), "us-east-1".to_string()),
+        ]);
+
+        let err = Operator::via_map(Scheme::S3, map).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        let msg = err.to_string();
+        assert!(msg.contains("service: s3"), "{msg}");
+        assert!(msg.contains("key: bucket"), "{msg}");
+    }
+
+    #[test]
+    fn test_via_map_empty_bucket() {
+        let map = HashMap::from([("bucket".to_string(), "".to_string())]);
+
+        let err = Operator::via_map(Scheme::S3, map).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+        assert!(err.to_string().contains("key: bucket"));
+    }
+
+    #[test]
+    fn test_via_map_missing_endpoint() {
+        let map = HashMap::from([("bucket".to_string(), "test".to_string())]);
+
+        let err = Operator::via_map(Scheme::Cos, map).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+
+        let msg = err.to_string();
+        assert!(msg.contains("service: cos"), "{msg}");
+        assert!(msg.contains("key: endpoint"), "{msg}");
+
+        let err = Operator::via_map(Scheme::Cos, HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("key: bucket,endpoint"));
+    }
+
+    #[test]
+    fn test_via_map_with_required_keys() {
+        let map = HashMap::from([
+            ("bucket".to_string(), "test".to_string()),
+            ("endpoint".to_string(), "http://127.0.0.1:9000".to_string()),
+            ("region".to_string(), "us-east-1".to_string()),
+        ]);
+
+        assert!(Operator::via_map(Scheme::S3, map).is_ok());
+    }
+
+    #[test]
+    fn test_config_round_trip() -> Result<()> {
+        let map = HashMap::from([
+            ("bucket".to_string(), "test".to_string()),
+            ("endpoint".to_string(), "http://127.0.0.1:9000".to_string()),
+            ("region".to_string(), "us-east-1".to_string()),
+            ("access_key_id".to_string(), "access_key_id".to_string()),
+            ("secret_access_key".to_string(), "secret".to_string()),
+        ]);
+        let op = Operator::via_map(Scheme::S3, map)?.layer(LoggingLayer::default());
+        assert!(!format!("{op:?}").contains("secret"), "{op:?}");
+
+        let cfg = op.config().expect("config must be retained");
+        let s = serde_json::to_string(&cfg).expect("serialize must succeed");
+        assert!(!s.contains("secret"), "{s}");
+        assert_eq!(
+            s,
+            r#"{"scheme":"s3","options":{"access_key_id":"access_key_id","bucket":"test","endpoint":"http://127.0.0.1:9000","region":"us-east-1"}}"#
+        );
+
+        let cfg: OperatorConfig = serde_json::from_str(&s).expect("deserialize must succeed");
+        assert_eq!(cfg.scheme(), Scheme::S3);
+        assert_eq!(cfg.options().get("secret_access_key"), None);
+
+        let op = Operator::via_config(cfg.clone().with("secret_access_key", "secret"))?;
+        assert_eq!(op.info().name(), "test");
+        assert_eq!(op.config(), Some(cfg));
+
+        // Operators not built via map don't have config.
+        let op = Operator::new(services::Memory::default())?.finish();
+        assert_eq!(op.config(), None);
+        Ok(())
+    }
+}
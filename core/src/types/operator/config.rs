@@ -0,0 +1,195 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::*;
+
+/// Suffixes of config keys which carry secrets, like `secret_access_key`,
+/// `sas_token`, `password` and `connection_string`.
+const SENSITIVE_KEY_SUFFIXES: &[&str] = &[
+    "key",
+    "token",
+    "secret",
+    "password",
+    "credential",
+    "connection_string",
+];
+
+/// OperatorConfig is the config that an operator is built from by
+/// [`Operator::via_map`], which could be used to build the operator again
+/// by [`Operator::via_config`].
+///
+/// # Notes
+///
+/// Secrets like `secret_access_key` will never be exposed by
+/// [`Operator::config`], please set them back by [`OperatorConfig::with`]
+/// or load them from env before rebuilding the operator.
+///
+/// Secrets are recognized by the suffixes of option keys (like `_key`,
+/// `_token` and `password`). This is a heuristic over the raw options map
+/// instead of a typed config of each service: options carrying secrets
+/// under other names won't be redacted, and some harmless options might be
+/// dropped. The `Debug` output of this config will mask the same options.
+///
+/// # Examples
+///
+/// ```
+/// # use anyhow::Result;
+/// use opendal::Operator;
+/// use opendal::OperatorConfig;
+///
+/// # fn test(op: Operator) -> Result<()> {
+/// let cfg = op.config().expect("operator must be built via map");
+/// let s = serde_json::to_string(&cfg)?;
+///
+/// let cfg: OperatorConfig = serde_json::from_str(&s)?;
+/// let op = Operator::via_config(cfg.with("secret_access_key", "secret"))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorConfig {
+    #[serde(with = "scheme_serde")]
+    scheme: Scheme,
+    options: BTreeMap<String, String>,
+}
+
+impl OperatorConfig {
+    /// Create a new config of given scheme and options.
+    pub fn new(scheme: Scheme, options: HashMap<String, String>) -> Self {
+        Self {
+            scheme,
+            options: options.into_iter().collect(),
+        }
+    }
+
+    /// [`Scheme`] of this config.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Options of this config.
+    pub fn options(&self) -> &BTreeMap<String, String> {
+        &self.options
+    }
+
+    /// Set an option of this config.
+    pub fn with(mut self, key: &str, value: &str) -> Self {
+        self.options.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Remove all options which carry secrets.
+    pub fn redacted(mut self) -> Self {
+        self.options.retain(|k, _| !is_sensitive_key(k));
+        self
+    }
+
+    pub(crate) fn into_parts(self) -> (Scheme, HashMap<String, String>) {
+        (self.scheme, self.options.into_iter().collect())
+    }
+}
+
+impl Debug for OperatorConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let options: BTreeMap<&str, &str> = self
+            .options
+            .iter()
+            .map(|(k, v)| {
+                let v = if is_sensitive_key(k) { "<redacted>" } else { v };
+                (k.as_str(), v)
+            })
+            .collect();
+
+        f.debug_struct("OperatorConfig")
+            .field("scheme", &self.scheme)
+            .field("options", &options)
+            .finish()
+    }
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEY_SUFFIXES.iter().any(|s| key.ends_with(s))
+}
+
+mod scheme_serde {
+    use serde::de::Error;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use crate::Scheme;
+
+    pub fn serialize<S: Serializer>(v: &Scheme, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&v.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Scheme, D::Error> {
+        let s = String::deserialize(d)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted() {
+        let cfg = OperatorConfig::new(
+            Scheme::S3,
+            HashMap::from([
+                ("bucket".to_string(), "test".to_string()),
+                ("access_key_id".to_string(), "access_key_id".to_string()),
+                ("secret_access_key".to_string(), "secret".to_string()),
+                ("security_token".to_string(), "token".to_string()),
+                (
+                    "server_side_encryption_customer_key".to_string(),
+                    "key".to_string(),
+                ),
+            ]),
+        )
+        .redacted();
+
+        let keys: Vec<_> = cfg.options().keys().map(|v| v.as_str()).collect();
+        assert_eq!(keys, vec!["access_key_id", "bucket"]);
+    }
+
+    #[test]
+    fn test_debug_redacted() {
+        let cfg = OperatorConfig::new(
+            Scheme::S3,
+            HashMap::from([
+                ("bucket".to_string(), "test".to_string()),
+                ("secret_access_key".to_string(), "secret".to_string()),
+            ]),
+        );
+
+        assert_eq!(
+            format!("{cfg:?}"),
+            r#"OperatorConfig { scheme: S3, options: {"bucket": "test", "secret_access_key": "<redacted>"} }"#
+        );
+    }
+}
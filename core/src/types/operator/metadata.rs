@@ -73,8 +73,11 @@ impl OperatorInfo {
     }
 
     /// Check if current backend supports [`Accessor::rename`] or not.
+    ///
+    /// This also returns true if rename is emulated by copy and delete.
     pub fn can_rename(&self) -> bool {
-        self.0.capability().rename
+        let cap = self.0.capability();
+        cap.rename || cap.rename_emulated
     }
 
     /// Check if current backend supports [`Accessor::list`] or not.
@@ -87,13 +90,70 @@ impl OperatorInfo {
         self.0.capability().presign
     }
 
+    /// Check if current backend supports presign read or not.
+    pub fn can_presign_read(&self) -> bool {
+        self.0.capability().presign_read
+    }
+
+    /// Check if current backend supports presign stat or not.
+    pub fn can_presign_stat(&self) -> bool {
+        self.0.capability().presign_stat
+    }
+
+    /// Check if current backend supports presign write or not.
+    pub fn can_presign_write(&self) -> bool {
+        self.0.capability().presign_write
+    }
+
     /// Check if current backend supports batch operations or not.
     pub fn can_batch(&self) -> bool {
         self.0.capability().batch
     }
 
+    /// The max operations that current backend supports in one batch.
+    ///
+    /// Returns `None` if the backend doesn't have such a limit.
+    pub fn batch_max_operations(&self) -> Option<usize> {
+        self.0.capability().batch_max_operations
+    }
+
+    /// The min part size that current backend supports in multipart write.
+    ///
+    /// Returns `None` if the backend doesn't have such a limit.
+    pub fn write_multi_min_size(&self) -> Option<u64> {
+        self.0.capability().write_multi_min_size
+    }
+
+    /// The max part size that current backend supports in multipart write.
+    ///
+    /// Returns `None` if the backend doesn't have such a limit.
+    pub fn write_multi_max_size(&self) -> Option<u64> {
+        self.0.capability().write_multi_max_size
+    }
+
+    /// The max size of a single file that current backend supports to write.
+    ///
+    /// Returns `None` if the backend doesn't have such a limit.
+    pub fn write_total_max_size(&self) -> Option<u64> {
+        self.0.capability().write_total_max_size
+    }
+
+    /// The max entries that current backend returns in one list page.
+    ///
+    /// Returns `None` if the backend doesn't have such a limit.
+    pub fn list_max_limit(&self) -> Option<usize> {
+        self.0.capability().list_max_limit
+    }
+
     /// Check if current backend supports blocking operations or not.
     pub fn can_blocking(&self) -> bool {
         self.0.capability().blocking
     }
+
+    /// The default checksum algorithm used by current backend's writes.
+    ///
+    /// Returns `None` if no checksum algorithm has been configured.
+    pub fn checksum_algorithm(&self) -> Option<ChecksumAlgorithm> {
+        self.0.checksum_algorithm()
+    }
 }
@@ -30,5 +30,8 @@ pub use builder::OperatorBuilder;
 mod metadata;
 pub use metadata::OperatorInfo;
 
+mod config;
+pub use config::OperatorConfig;
+
 pub mod operator_functions;
 pub mod operator_futures;
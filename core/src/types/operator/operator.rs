@@ -16,11 +16,14 @@
 // under the License.
 
 use std::ops::RangeBounds;
+use std::sync::Arc;
 use std::time::Duration;
 
 use bytes::Bytes;
+use flagset::FlagSet;
 use futures::stream;
 use futures::AsyncReadExt;
+use futures::AsyncWriteExt;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -69,6 +72,9 @@ pub struct Operator {
 
     // limit is usually the maximum size of data that operator will handle in one operation
     limit: usize,
+
+    // config is retained with secrets redacted if operator is built via map
+    pub(super) config: Option<Arc<OperatorConfig>>,
 }
 
 /// # Operator basic API.
@@ -83,10 +89,19 @@ impl Operator {
             .capability()
             .batch_max_operations
             .unwrap_or(1000);
-        Self { accessor, limit }
+        Self {
+            accessor,
+            limit,
+            config: None,
+        }
+    }
+
+    pub(super) fn with_config(mut self, config: Option<Arc<OperatorConfig>>) -> Self {
+        self.config = config;
+        self
     }
 
-    pub(super) fn into_inner(self) -> FusedAccessor {
+    pub(crate) fn into_inner(self) -> FusedAccessor {
         self.accessor
     }
 
@@ -105,6 +120,17 @@ impl Operator {
         op
     }
 
+    /// Get the config of this operator with secrets redacted.
+    ///
+    /// Only operators built by [`Operator::via_map`] or
+    /// [`Operator::via_config`] have config, `None` will be returned
+    /// otherwise.
+    ///
+    /// Refer to [`OperatorConfig`] for more information.
+    pub fn config(&self) -> Option<OperatorConfig> {
+        self.config.as_deref().cloned()
+    }
+
     /// Get information of underlying accessor.
     ///
     /// # Examples
@@ -127,6 +153,12 @@ impl Operator {
     /// Create a new blocking operator.
     ///
     /// This operation is nearly no cost.
+    ///
+    /// For services that don't support blocking natively, please add
+    /// [`BlockingLayer`][crate::layers::BlockingLayer] first. Its runtime
+    /// handle is reused by all blocking calls, use
+    /// [`BlockingLayer::with_handle`][crate::layers::BlockingLayer::with_handle]
+    /// to supply the handle of your own runtime.
     pub fn blocking(&self) -> BlockingOperator {
         BlockingOperator::from_inner(self.accessor.clone()).with_limit(self.limit)
     }
@@ -136,7 +168,18 @@ impl Operator {
 impl Operator {
     /// Check if this operator can work correctly.
     ///
-    /// We will send a `list` request to path and return any errors we met.
+    /// This is designed for health probes like readiness check. We will
+    /// perform the cheapest available operation on root to verify that the
+    /// service is reachable and the credentials are valid:
+    ///
+    /// - If services support `list`, list root with limit `1`.
+    /// - If services support `stat`, stat root.
+    ///
+    /// `NotFound` is treated as success since the service has responded.
+    /// Other errors keep their kind with the original error as source, for
+    /// example bad credentials will be returned as
+    /// [`ErrorKind::PermissionDenied`] and connection failures will be
+    /// temporary.
     ///
     /// ```
     /// # use std::sync::Arc;
@@ -150,10 +193,24 @@ impl Operator {
     /// # }
     /// ```
     pub async fn check(&self) -> Result<()> {
-        let mut ds = self.lister("/").await?;
+        let cap = self.info().capability();
+
+        let res = if cap.list {
+            match self.lister_with("/").limit(1).await {
+                Ok(mut ds) => match ds.next().await {
+                    Some(Err(err)) => Err(err),
+                    _ => Ok(()),
+                },
+                Err(err) => Err(err),
+            }
+        } else if cap.stat {
+            self.stat("/").await.map(|_| ())
+        } else {
+            Ok(())
+        };
 
-        match ds.next().await {
-            Some(Err(e)) if e.kind() != ErrorKind::NotFound => Err(e),
+        match res {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(new_check_error(self.info(), err)),
             _ => Ok(()),
         }
     }
@@ -197,7 +254,7 @@ impl Operator {
     /// - Want to detect the outside changes of path.
     /// - Don't want to read from cached metadata.
     ///
-    /// You may want to use `metadata` if you are working with entries
+    /// You may want to use [`Operator::metadata`] if you are working with entries
     /// returned by [`Lister`]. It's highly possible that metadata
     /// you want has already been cached.
     ///
@@ -239,6 +296,49 @@ impl Operator {
         fut
     }
 
+    /// Get the metadata of given entry with required `metakey`.
+    ///
+    /// # Notes
+    ///
+    /// Entries returned by [`Lister`] may carry metadata that has already
+    /// been fetched during listing. If the cached metadata contains all
+    /// the required `metakey`, it will be returned directly without
+    /// sending any request. Otherwise, `stat` will be called to fetch
+    /// the complete metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use futures::TryStreamExt;
+    /// use opendal::Metakey;
+    /// use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut lister = op
+    ///     .lister_with("dir/")
+    ///     .metakey(Metakey::ContentLength)
+    ///     .await?;
+    /// while let Some(entry) = lister.try_next().await? {
+    ///     // No extra request will be sent since listing has fetched the size.
+    ///     let meta = op.metadata(&entry, Metakey::ContentLength).await?;
+    ///     println!("{} has size {}", entry.path(), meta.content_length());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metadata(
+        &self,
+        entry: &Entry,
+        metakey: impl Into<FlagSet<Metakey>>,
+    ) -> Result<Metadata> {
+        if entry.metadata().contains_bit(metakey) {
+            return Ok(entry.metadata().clone());
+        }
+
+        self.stat(entry.path()).await
+    }
+
     /// Check if this path exists or not.
     ///
     /// # Example
@@ -266,6 +366,55 @@ impl Operator {
         }
     }
 
+    /// Stat many paths concurrently.
+    ///
+    /// At most `concurrent` paths will be statted at the same time, and
+    /// the result of every path will be returned in the same order as
+    /// input. Failing to stat one path won't stop statting others.
+    ///
+    /// # Notes
+    ///
+    /// Every path is statted by [`Operator::stat`] so that all layers like
+    /// [`ConcurrentLimitLayer`][crate::layers::ConcurrentLimitLayer] are
+    /// respected, and the underlying http client's connections are reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ErrorKind;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .stat_many(vec!["path/to/a".to_string(), "path/to/b".to_string()], 16)
+    ///     .await;
+    /// for (path, result) in results {
+    ///     match result {
+    ///         Ok(meta) => println!("{path}: {}", meta.content_length()),
+    ///         Err(err) if err.kind() == ErrorKind::NotFound => println!("{path} not found"),
+    ///         Err(err) => println!("stat {path} failed: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_many(
+        &self,
+        paths: Vec<String>,
+        concurrent: usize,
+    ) -> Vec<(String, Result<Metadata>)> {
+        stream::iter(paths)
+            .map(|path| async move {
+                let res = self.stat(&path).await;
+                (path, res)
+            })
+            .buffered(concurrent.max(1))
+            .collect()
+            .await
+    }
+
     /// Create a dir at given path.
     ///
     /// # Notes
@@ -366,10 +515,25 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
+                    #[cfg(feature = "compress")]
+                    if args.auto_decompress() {
+                        if let Some(algo) = oio::CompressAlgorithm::from_path(&path) {
+                            return read_decompressed(inner, &path, args, algo).await;
+                        }
+                    }
+
                     let br = args.range();
-                    let (rp, mut s) = inner.read(&path, args).await?;
+                    let progress = args.progress().cloned();
+                    let (rp, s) = inner.read(&path, args).await?;
 
                     let length = rp.into_metadata().content_length() as usize;
+                    let mut s = match progress {
+                        Some(progress) => {
+                            Box::new(oio::into_progress_read(s, progress, Some(length as u64)))
+                                as oio::Reader
+                        }
+                        None => s,
+                    };
                     let mut buffer = Vec::with_capacity(length);
 
                     let dst = buffer.spare_capacity_mut();
@@ -380,6 +544,28 @@ impl Operator {
 
                     // TODO: use native read api
                     s.read_exact(buf.initialized_mut()).await.map_err(|err| {
+                        // Corrupted content like checksum mismatch should be
+                        // returned as is so that users are able to handle it.
+                        let is_corrupted = err
+                            .get_ref()
+                            .and_then(|v| v.downcast_ref::<Error>())
+                            .map(|v| {
+                                matches!(
+                                    v.kind(),
+                                    ErrorKind::ChecksumMismatch
+                                        | ErrorKind::ContentIncomplete
+                                        | ErrorKind::ContentTruncated
+                                )
+                            })
+                            .unwrap_or_default();
+                        if is_corrupted {
+                            let err = err
+                                .into_inner()
+                                .and_then(|v| v.downcast::<Error>().ok())
+                                .expect("inner error must be corrupted content");
+                            return err.with_operation("range_read").with_context("path", &path);
+                        }
+
                         Error::new(ErrorKind::Unexpected, "read from storage")
                             .with_operation("range_read")
                             .with_context("service", inner.info().scheme().into_static())
@@ -428,6 +614,12 @@ impl Operator {
 
     /// Create a new reader which can read the whole path.
     ///
+    /// # Notes
+    ///
+    /// The returned reader is owned and can be moved into spawned tasks,
+    /// but the future of this function borrows `self`. Use
+    /// [`Operator::reader_with`] if the future itself needs to be spawned.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -510,6 +702,85 @@ impl Operator {
         fut
     }
 
+    /// Read the whole path into a [`Buffer`].
+    ///
+    /// Different from [`Operator::read`], chunks returned by services are
+    /// kept as is without being concatenated into a contiguous allocation,
+    /// which is friendly for forwarding content via vectored writes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let buf = op.read_buffer("path/to/file").await?;
+    /// for bs in buf.chunks() {
+    ///     println!("got chunk of {} bytes", bs.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_buffer(&self, path: &str) -> Result<Buffer> {
+        self.reader(path).await?.read_buffer().await
+    }
+
+    /// Read the whole path and stream the content into given writer.
+    ///
+    /// Returns the number of bytes that have been written.
+    ///
+    /// # Notes
+    ///
+    /// - Content will be written chunk by chunk as it's returned by services
+    ///   without buffering the whole content, and the writer will be flushed
+    ///   after every chunk.
+    /// - Errors from both the reader and the writer will be returned directly.
+    /// - Dropping the returning future will cancel the reading, content that
+    ///   has been written into the writer will not be reverted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = futures::io::Cursor::new(vec![]);
+    /// let n = op.read_to_writer("path/to/file", &mut w).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_to_writer<W>(&self, path: &str, mut w: W) -> Result<u64>
+    where
+        W: futures::AsyncWrite + Unpin,
+    {
+        let mut r = self.reader(path).await?;
+
+        let mut written = 0;
+        // Use `oio::ReadExt` instead of `Stream` to keep the error from services.
+        while let Some(bs) = oio::ReadExt::next(&mut r).await {
+            let bs = bs?;
+            w.write_all(&bs).await.map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "write into writer failed")
+                    .with_operation("Operator::read_to_writer")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", path)
+                    .set_source(err)
+            })?;
+            w.flush().await.map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "flush writer failed")
+                    .with_operation("Operator::read_to_writer")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", path)
+                    .set_source(err)
+            })?;
+            written += bs.len() as u64;
+        }
+
+        Ok(written)
+    }
+
     /// Write bytes into path.
     ///
     /// # Notes
@@ -543,6 +814,8 @@ impl Operator {
     /// - Append will make sure all bytes has been written, or an error will be returned.
     /// - Append will create the file if it does not exist.
     /// - Append always write bytes to the end of the file.
+    /// - Append to an existing file that is not appendable (like objects
+    ///   not created by append) will return [`ErrorKind::ConditionNotMatch`].
     ///
     /// # Examples
     ///
@@ -584,50 +857,120 @@ impl Operator {
     /// # }
     /// ```
     pub async fn copy(&self, from: &str, to: &str) -> Result<()> {
-        let from = normalize_path(from);
-
-        if !validate_path(&from, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "from path is a directory")
-                    .with_operation("Operator::copy")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from),
-            );
-        }
+        self.copy_with(from, to).await
+    }
 
+    /// Copy from `from` to `to` with extra options.
+    ///
+    /// # Notes
+    ///
+    /// - `from` and `to` must be a file unless `recursive` is set.
+    /// - With `recursive`, `from` and `to` must be a directory. All entries
+    ///   under `from` will be copied to the mirrored paths under `to`.
+    ///   - Service side copy will be used if supported, or content will be
+    ///     read and written back instead.
+    ///   - Entries are copied with bounded concurrency which could be changed
+    ///     by `concurrent`.
+    ///   - Failing entries will not stop the others from copying, an error
+    ///     containing all failed paths will be returned at the end.
+    /// - With `range`, only given range of `from` will be copied into `to`
+    ///   by the service, like `UploadPartCopy` of s3. An `Unsupported` error
+    ///   will be returned if the service can't copy range natively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.copy_with("path/to/dir/", "path/to/dir2/")
+    ///     .recursive(true)
+    ///     .concurrent(16)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_with(&self, from: &str, to: &str) -> FutureCopy {
+        let from = normalize_path(from);
         let to = normalize_path(to);
 
-        if !validate_path(&to, EntryMode::FILE) {
-            return Err(
-                Error::new(ErrorKind::IsADirectory, "to path is a directory")
-                    .with_operation("Operator::copy")
-                    .with_context("service", self.info().scheme())
-                    .with_context("to", to),
-            );
-        }
+        let fut = FutureCopy(OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (OpCopy::new(), to),
+            |inner, from, (args, to)| {
+                let fut = async move {
+                    let (mode, kind, msg) = if args.recursive() {
+                        (
+                            EntryMode::DIR,
+                            ErrorKind::NotADirectory,
+                            "is not a directory",
+                        )
+                    } else {
+                        (EntryMode::FILE, ErrorKind::IsADirectory, "is a directory")
+                    };
+
+                    if !validate_path(&from, mode) {
+                        return Err(Error::new(kind, &format!("from path {msg}"))
+                            .with_operation("Operator::copy")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("from", from));
+                    }
 
-        if from == to {
-            return Err(
-                Error::new(ErrorKind::IsSameFile, "from and to paths are same")
-                    .with_operation("Operator::copy")
-                    .with_context("service", self.info().scheme())
-                    .with_context("from", from)
-                    .with_context("to", to),
-            );
-        }
+                    if !validate_path(&to, mode) {
+                        return Err(Error::new(kind, &format!("to path {msg}"))
+                            .with_operation("Operator::copy")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("to", to));
+                    }
 
-        self.inner().copy(&from, &to, OpCopy::new()).await?;
+                    if from == to {
+                        return Err(Error::new(
+                            ErrorKind::IsSameFile,
+                            "from and to paths are same",
+                        )
+                        .with_operation("Operator::copy")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("from", from)
+                        .with_context("to", to));
+                    }
 
-        Ok(())
+                    if args.recursive() {
+                        if !args.range().is_full() {
+                            return Err(Error::new(
+                                ErrorKind::InvalidInput,
+                                "range can't be used with recursive copy",
+                            )
+                            .with_operation("Operator::copy")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("from", from));
+                        }
+                        return copy_recursive(inner, &from, &to, args).await;
+                    }
+
+                    inner.copy(&from, &to, args).await?;
+                    Ok(())
+                };
+                Box::pin(fut)
+            },
+        ));
+        fut
     }
 
     /// Rename a file from `from` to `to`.
     ///
     /// # Notes
     ///
-    /// - `from` and `to` must be a file.
+    /// - `from` and `to` must be a file, unless the service supports renaming
+    ///   directories natively (see [`Capability::rename_can_dir`]), in which
+    ///   case both of them can be directories.
     /// - `to` will be overwritten if it exists.
     /// - If `from` and `to` are the same, an `IsSameFile` error will occur.
+    /// - For services without native rename (like s3), rename is emulated by
+    ///   copy and delete, which is not atomic. See [`Capability::rename_emulated`].
+    /// - Callers relying on atomic rename should check [`Capability::rename_atomic`].
     ///
     /// # Examples
     ///
@@ -643,8 +986,14 @@ impl Operator {
     /// ```
     pub async fn rename(&self, from: &str, to: &str) -> Result<()> {
         let from = normalize_path(from);
+        let to = normalize_path(to);
 
-        if !validate_path(&from, EntryMode::FILE) {
+        // Rename between directories is allowed if service supports it.
+        let rename_dir = self.info().capability().rename_can_dir
+            && validate_path(&from, EntryMode::DIR)
+            && validate_path(&to, EntryMode::DIR);
+
+        if !rename_dir && !validate_path(&from, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "from path is a directory")
                     .with_operation("Operator::move_")
@@ -653,9 +1002,7 @@ impl Operator {
             );
         }
 
-        let to = normalize_path(to);
-
-        if !validate_path(&to, EntryMode::FILE) {
+        if !rename_dir && !validate_path(&to, EntryMode::FILE) {
             return Err(
                 Error::new(ErrorKind::IsADirectory, "to path is a directory")
                     .with_operation("Operator::move_")
@@ -758,11 +1105,50 @@ impl Operator {
         fut
     }
 
+    /// Upload an object by parts which are controlled by callers.
+    ///
+    /// Refer to [`MultipartWriter`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use futures::try_join;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let w = op.multipart_writer("path/to/file").await?;
+    /// try_join!(
+    ///     w.write_part(1, vec![1; 5 * 1024 * 1024]),
+    ///     w.write_part(0, vec![0; 5 * 1024 * 1024]),
+    /// )?;
+    /// w.complete().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn multipart_writer(&self, path: &str) -> Result<MultipartWriter> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                    .with_operation("Operator::multipart_writer")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", &path),
+            );
+        }
+
+        MultipartWriter::create(self.inner().clone(), &path, OpWrite::default()).await
+    }
+
     /// Write data with extra options.
     ///
     /// # Notes
     ///
     /// - Write will make sure all bytes has been written, or an error will be returned.
+    /// - Use [`Operator::write_buffer`] to write non-contiguous chunks
+    ///   without concatenating them.
     ///
     /// # Examples
     ///
@@ -782,14 +1168,43 @@ impl Operator {
     /// # }
     /// ```
     pub fn write_with(&self, path: &str, bs: impl Into<Bytes>) -> FutureWrite {
+        self.write_buffer(path, Buffer::from(bs.into()))
+    }
+
+    /// Write the given [`Buffer`] with extra options.
+    ///
+    /// Chunks of buffer will be sent without concatenation if service
+    /// supports [`Capability::write_can_sink`], and multipart uploads will
+    /// be split along chunk boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    /// use opendal::Buffer;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let buf = Buffer::from(vec![Bytes::from("hello, "), Bytes::from("world!")]);
+    /// op.write_buffer("path/to/file", buf)
+    ///     .content_type("text/plain")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_buffer(&self, path: &str, buf: Buffer) -> FutureWrite {
         let path = normalize_path(path);
-        let bs = bs.into();
 
         let fut = FutureWrite(OperatorFuture::new(
             self.inner().clone(),
             path,
-            (OpWrite::default().with_content_length(bs.len() as u64), bs),
-            |inner, path, (args, bs)| {
+            (
+                OpWrite::default().with_content_length(buf.len() as u64),
+                buf,
+            ),
+            |inner, path, (args, buf)| {
                 let fut = async move {
                     if !validate_path(&path, EntryMode::FILE) {
                         return Err(Error::new(
@@ -801,8 +1216,17 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
-                    let (_, mut w) = inner.write(&path, args).await?;
-                    w.write(bs).await?;
+                    let progress = args.progress().cloned();
+                    let (_, w) = inner.write(&path, args).await?;
+                    let mut w = match progress {
+                        Some(progress) => Box::new(oio::into_progress_write(
+                            w,
+                            progress,
+                            Some(buf.len() as u64),
+                        )) as oio::Writer,
+                        None => w,
+                    };
+                    write_chunks(&mut w, inner.info().capability(), buf).await?;
                     w.close().await?;
 
                     Ok(())
@@ -813,8 +1237,138 @@ impl Operator {
         fut
     }
 
+    /// Write and delete multiple files in one batch.
+    ///
+    /// Every operation is a path with `Some(bs)` to write the whole content
+    /// or `None` to delete it.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::batch_write`]. Services with transactions like
+    /// `foundationdb` will commit all operations atomically, please check
+    /// the docs of services for their limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.batch_write(vec![
+    ///     ("path/to/a".to_string(), Some(Bytes::from("a"))),
+    ///     ("path/to/b".to_string(), Some(Bytes::from("b"))),
+    ///     ("path/to/c".to_string(), None),
+    /// ])
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn batch_write(&self, ops: Vec<(String, Option<Bytes>)>) -> Result<()> {
+        if !self.info().capability().batch_write {
+            return Err(
+                Error::new(ErrorKind::Unsupported, "operation is not supported")
+                    .with_operation("Operator::batch_write")
+                    .with_context("service", self.info().scheme().into_static()),
+            );
+        }
+
+        let mut batches = Vec::with_capacity(ops.len());
+        for (path, bs) in ops {
+            let path = normalize_path(&path);
+            let op = match bs {
+                Some(bs) => {
+                    if !validate_path(&path, EntryMode::FILE) {
+                        return Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "write path is a directory",
+                        )
+                        .with_operation("Operator::batch_write")
+                        .with_context("service", self.info().scheme().into_static())
+                        .with_context("path", &path));
+                    }
+
+                    let args = OpWrite::default().with_content_length(bs.len() as u64);
+                    BatchOperation::Write(args, bs)
+                }
+                None => OpDelete::default().into(),
+            };
+            batches.push((path, op));
+        }
+
+        let results = self
+            .inner()
+            .batch(OpBatch::new(batches))
+            .await?
+            .into_results();
+        for (_, result) in results {
+            let _ = result?;
+        }
+
+        Ok(())
+    }
+
+    /// Write many small files concurrently.
+    ///
+    /// At most `concurrent` files will be written at the same time, and
+    /// the result of every path will be returned in the same order as
+    /// input. Failing to write one file won't stop writing others.
+    ///
+    /// # Notes
+    ///
+    /// Every file is written by [`Operator::write`] so that all layers like
+    /// [`ConcurrentLimitLayer`][crate::layers::ConcurrentLimitLayer] are
+    /// respected. Please use [`Operator::batch_write`] instead if services
+    /// support batch write natively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .write_many(
+    ///         vec![
+    ///             ("path/to/a".to_string(), Bytes::from("a")),
+    ///             ("path/to/b".to_string(), Bytes::from("b")),
+    ///         ],
+    ///         16,
+    ///     )
+    ///     .await;
+    /// for (path, result) in results {
+    ///     if let Err(err) = result {
+    ///         println!("write {path} failed: {err}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_many(
+        &self,
+        items: Vec<(String, Bytes)>,
+        concurrent: usize,
+    ) -> Vec<(String, Result<()>)> {
+        stream::iter(items)
+            .map(|(path, bs)| async move {
+                let res = self.write(&path, bs).await;
+                (path, res)
+            })
+            .buffered(concurrent.max(1))
+            .collect()
+            .await
+    }
+
     /// Append multiple bytes into path.
     ///
+    /// Appender follows the same semantics as [`Operator::append`]: the file
+    /// will be created if it does not exist.
+    ///
     /// Refer to [`Appender`] for more details.
     ///
     /// # Examples
@@ -896,6 +1450,8 @@ impl Operator {
     /// - Append will make sure all bytes has been written, or an error will be returned.
     /// - Append will create the file if it does not exist.
     /// - Append always write bytes to the end of the file.
+    /// - Append to an existing file that is not appendable (like objects
+    ///   not created by append) will return [`ErrorKind::ConditionNotMatch`].
     ///
     /// # Examples
     ///
@@ -951,7 +1507,8 @@ impl Operator {
     ///
     /// # Notes
     ///
-    /// - Deleting a file that does not exist won't return errors.
+    /// - Deleting a file that does not exist won't return errors, the same
+    ///   as AWS S3 across all services.
     ///
     /// # Examples
     ///
@@ -974,6 +1531,9 @@ impl Operator {
     /// # Notes
     ///
     /// - Deleting a file that does not exist won't return errors.
+    /// - Deleting a non-empty directory requires `recursive`, which will be
+    ///   emulated by listing and deleting all children if the service doesn't
+    ///   support it natively. See [`Capability::delete_with_recursive`].
     ///
     /// # Examples
     ///
@@ -985,6 +1545,7 @@ impl Operator {
     /// # #[tokio::main]
     /// # async fn test(op: Operator) -> Result<()> {
     /// op.delete_with("test").await?;
+    /// op.delete_with("path/to/dir/").recursive(true).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -1073,7 +1634,11 @@ impl Operator {
 
                 // TODO: return error here directly seems not a good idea?
                 for (_, result) in results {
-                    let _ = result?;
+                    match result {
+                        // Deleting a path that doesn't exist is not an error.
+                        Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+                        _ => {}
+                    }
                 }
             }
         } else {
@@ -1089,6 +1654,96 @@ impl Operator {
         Ok(())
     }
 
+    /// Remove all entries returned by given lister.
+    ///
+    /// remove_via_lister interleaves listing and deleting: every chunk of
+    /// entries will be deleted before fetching more, so the memory usage
+    /// is bounded by the batch limit regardless of how many entries the
+    /// lister will return.
+    ///
+    /// `progress` will be called with the number of entries deleted so far
+    /// after every chunk has been deleted.
+    ///
+    /// # Notes
+    ///
+    /// If underlying services support delete in batch, we will use batch
+    /// delete instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let lister = op.lister_with("path/to/dir/").delimiter("").await?;
+    /// op.remove_via_lister(lister, Some(&mut |n| println!("{n} entries deleted")))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_via_lister(
+        &self,
+        lister: Lister,
+        mut progress: Option<&mut (dyn FnMut(usize) + Send)>,
+    ) -> Result<()> {
+        let mut obs = lister.try_chunks(self.limit());
+        let mut deleted = 0;
+
+        while let Some(entries) = obs.next().await {
+            let entries = entries.map_err(|err| err.1)?;
+            let size = entries.len();
+
+            if self.info().can_batch() {
+                let batches = entries
+                    .into_iter()
+                    .map(|v| (v.path().to_string(), OpDelete::default().into()))
+                    .collect();
+
+                let results = self
+                    .inner()
+                    .batch(OpBatch::new(batches))
+                    .await?
+                    .into_results();
+
+                // TODO: return error here directly seems not a good idea?
+                for (_, result) in results {
+                    match result {
+                        // Deleting a path that doesn't exist is not an error.
+                        Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+                        _ => {}
+                    }
+                }
+            } else {
+                // Dirs can't be deleted along with their children concurrently
+                // on services that have real dirs, so we delete them after all
+                // files in the listing order in which children come first.
+                let (dirs, files): (Vec<_>, Vec<_>) = entries
+                    .into_iter()
+                    .partition(|v| v.metadata().mode().is_dir());
+
+                stream::iter(files)
+                    .map(Ok)
+                    .try_for_each_concurrent(self.limit, |v| async move {
+                        let _ = self.inner().delete(v.path(), OpDelete::default()).await?;
+                        Ok::<(), Error>(())
+                    })
+                    .await?;
+                for v in dirs {
+                    let _ = self.inner().delete(v.path(), OpDelete::default()).await?;
+                }
+            }
+
+            deleted += size;
+            if let Some(f) = progress.as_mut() {
+                f(deleted);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Remove the path and all nested dirs and files recursively.
     ///
     /// # Notes
@@ -1125,34 +1780,13 @@ impl Operator {
             return self.delete(path).await;
         }
 
-        let obs = self.lister_with(path).delimiter("").await?;
-
-        if self.info().can_batch() {
-            let mut obs = obs.try_chunks(self.limit());
-
-            while let Some(batches) = obs.next().await {
-                let batches = batches
-                    .map_err(|err| err.1)?
-                    .into_iter()
-                    .map(|v| (v.path().to_string(), OpDelete::default().into()))
-                    .collect();
-
-                let results = self
-                    .inner()
-                    .batch(OpBatch::new(batches))
-                    .await?
-                    .into_results();
-
-                // TODO: return error here directly seems not a good idea?
-                for (_, result) in results {
-                    let _ = result?;
-                }
-            }
-        } else {
-            obs.try_for_each(|v| async move { self.delete(v.path()).await })
-                .await?;
+        if self.info().capability().delete_with_recursive {
+            return self.delete_with(path).recursive(true).await;
         }
 
+        let obs = self.lister_with(path).delimiter("").await?;
+        self.remove_via_lister(obs, None).await?;
+
         // Remove the directory itself.
         self.delete(path).await?;
 
@@ -1309,9 +1943,31 @@ impl Operator {
                         .with_context("path", &path));
                     }
 
-                    let lister = Lister::create(inner, &path, args).await?;
-
-                    lister.try_collect().await
+                    let Some(sort_by) = args.sort_by() else {
+                        let lister = Lister::create(inner, &path, args).await?;
+                        return lister.try_collect().await;
+                    };
+
+                    let metakey = args.metakey() | sort_by.metakey();
+                    let args = args.with_metakey(metakey);
+                    let mut lister = Lister::create(inner.clone(), &path, args).await?;
+
+                    let mut entries = Vec::new();
+                    while let Some(entry) = lister.try_next().await? {
+                        if entries.len() >= LIST_SORT_MAX_ENTRIES {
+                            return Err(Error::new(
+                                ErrorKind::Unsupported,
+                                "too many entries to sort in memory, please use lister instead",
+                            )
+                            .with_operation("Operator::list")
+                            .with_context("service", inner.info().scheme().into_static())
+                            .with_context("path", &path)
+                            .with_context("max_entries", LIST_SORT_MAX_ENTRIES.to_string()));
+                        }
+                        entries.push(entry);
+                    }
+                    sort_by.sort(&mut entries);
+                    Ok(entries)
                 };
                 Box::pin(fut)
             },
@@ -1494,6 +2150,66 @@ impl Operator {
         ));
         fut
     }
+
+    /// Compute the total bytes and count of files under given path
+    /// recursively, like `du`.
+    ///
+    /// # Notes
+    ///
+    /// - For a file path, the usage of this file will be returned.
+    /// - Sizes provided by listing will be used directly. Files without
+    ///   size will be statted with bounded concurrency.
+    /// - Dirs are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let usage = op.du("path/to/dir/").await?;
+    /// println!("{} files, {} bytes", usage.count(), usage.bytes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn du(&self, path: &str) -> Result<DiskUsage> {
+        let path = normalize_path(path);
+
+        if !path.ends_with('/') {
+            let meta = self.stat(&path).await?;
+            return Ok(if meta.is_dir() {
+                DiskUsage::default()
+            } else {
+                DiskUsage::new(meta.content_length(), 1)
+            });
+        }
+
+        let inner = self.inner().clone();
+        let lister = Lister::create(inner.clone(), &path, OpList::new().with_delimiter("")).await?;
+
+        let mut sizes = lister
+            .try_filter(|entry| futures::future::ready(!entry.metadata().is_dir()))
+            .map_ok(|entry| {
+                let inner = inner.clone();
+                async move {
+                    let meta = entry.metadata();
+                    if meta.contains_bit(Metakey::ContentLength) {
+                        return Ok(meta.content_length());
+                    }
+                    let rp = inner.stat(entry.path(), OpStat::new()).await?;
+                    Ok(rp.into_metadata().content_length())
+                }
+            })
+            .try_buffer_unordered(DEFAULT_DU_CONCURRENT);
+
+        let mut usage = DiskUsage::default();
+        while let Some(size) = sizes.try_next().await? {
+            usage = DiskUsage::new(usage.bytes() + size, usage.count() + 1);
+        }
+
+        Ok(usage)
+    }
 }
 
 /// Operator presign API.
@@ -1676,3 +2392,618 @@ impl Operator {
         fut
     }
 }
+
+/// The default concurrent copies of recursive copy.
+const DEFAULT_COPY_CONCURRENT: usize = 8;
+
+/// The default concurrent stats of `du` for files without size.
+const DEFAULT_DU_CONCURRENT: usize = 16;
+
+/// Write all chunks of buffer into writer.
+///
+/// Chunks are sunk as streams if service supports it, so that they can be
+/// sent without concatenation. For services supporting multiple writes,
+/// chunks will be grouped along their boundaries into parts which are
+/// larger than `write_multi_min_size`.
+async fn write_chunks(w: &mut oio::Writer, cap: Capability, buf: Buffer) -> Result<()> {
+    if buf.chunk_count() <= 1 || !cap.write_can_sink {
+        return w.write(buf.to_bytes()).await;
+    }
+
+    let part_size = if cap.write_can_multi {
+        cap.write_multi_min_size.unwrap_or_default() as usize
+    } else {
+        usize::MAX
+    };
+
+    let mut parts = vec![];
+    let mut part = Buffer::new();
+    for bs in buf {
+        part.push(bs);
+        if part.len() >= part_size {
+            parts.push(std::mem::take(&mut part));
+        }
+    }
+    if !part.is_empty() {
+        parts.push(part);
+    }
+
+    for part in parts {
+        let s = Box::new(oio::into_stream(stream::iter(
+            part.clone().into_iter().map(Ok::<_, Error>),
+        )));
+        match w.sink(part.len() as u64, s).await {
+            // Services could fail to sink streams if they need to calculate
+            // the checksum of whole body ahead, fallback to write bytes.
+            Err(err) if err.kind() == ErrorKind::Unsupported => w.write(part.to_bytes()).await?,
+            res => res?,
+        }
+    }
+
+    Ok(())
+}
+
+/// The max entries that `list_with().sort_by()` will buffer in memory.
+const LIST_SORT_MAX_ENTRIES: usize = 100_000;
+
+/// Copy all entries under `from` to the mirrored paths under `to`.
+async fn copy_recursive(inner: FusedAccessor, from: &str, to: &str, args: OpCopy) -> Result<()> {
+    // Copying into the source dir will copy the copied entries again.
+    if to.starts_with(from) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "to path can't be inside from path while copying recursively",
+        )
+        .with_operation("Operator::copy")
+        .with_context("service", inner.info().scheme())
+        .with_context("from", from)
+        .with_context("to", to));
+    }
+
+    let concurrent = args.concurrent().unwrap_or(DEFAULT_COPY_CONCURRENT).max(1);
+    let extensions = args.extensions().clone();
+    let lister = Lister::create(
+        inner.clone(),
+        from,
+        OpList::new()
+            .with_delimiter("")
+            .with_extensions(extensions.clone()),
+    )
+    .await?;
+
+    let mut results = lister
+        .try_filter(|entry| futures::future::ready(entry.path() != from))
+        .map_ok(|entry| {
+            let inner = inner.clone();
+            let target = format!("{to}{}", &entry.path()[from.len()..]);
+            let extensions = extensions.clone();
+            async move {
+                let res = copy_entry(inner, &entry, &target, extensions).await;
+                Ok((entry.path().to_string(), res))
+            }
+        })
+        .try_buffer_unordered(concurrent);
+
+    let mut total = 0;
+    let mut failed = vec![];
+    while let Some(res) = results.next().await {
+        let (path, res) = res?;
+        total += 1;
+        if let Err(err) = res {
+            failed.push((path, err));
+        }
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    let paths = failed
+        .iter()
+        .map(|(path, _)| path.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let failed_count = failed.len();
+    let (_, source) = failed.swap_remove(0);
+    Err(Error::new(
+        ErrorKind::Unexpected,
+        "some entries failed to copy recursively",
+    )
+    .with_operation("Operator::copy")
+    .with_context("service", inner.info().scheme())
+    .with_context("from", from)
+    .with_context("to", to)
+    .with_context("failed", format!("{failed_count}/{total}"))
+    .with_context("failed_paths", paths)
+    .set_source(source))
+}
+
+/// Copy a single entry to target, dirs will be created instead.
+async fn copy_entry(
+    inner: FusedAccessor,
+    entry: &Entry,
+    target: &str,
+    extensions: Extensions,
+) -> Result<()> {
+    let cap = inner.info().capability();
+
+    if entry.metadata().mode().is_dir() {
+        if cap.create_dir {
+            inner.create_dir(target, OpCreateDir::new()).await?;
+        }
+        return Ok(());
+    }
+
+    if cap.copy {
+        inner
+            .copy(
+                entry.path(),
+                target,
+                OpCopy::new().with_extensions(extensions),
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let (_, mut r) = inner
+        .read(
+            entry.path(),
+            OpRead::new().with_extensions(extensions.clone()),
+        )
+        .await?;
+    let mut buf = Vec::new();
+    while let Some(bs) = oio::ReadExt::next(&mut r).await {
+        buf.extend_from_slice(&bs?);
+    }
+
+    let (_, mut w) = inner
+        .write(
+            target,
+            OpWrite::new()
+                .with_content_length(buf.len() as u64)
+                .with_extensions(extensions),
+        )
+        .await?;
+    oio::Write::write(&mut w, Bytes::from(buf)).await?;
+    oio::Write::close(&mut w).await
+}
+
+/// Read the whole path and decompress it with given algorithm.
+///
+/// The decompressed content's length is unknown, so the content will be
+/// read until EOF.
+#[cfg(feature = "compress")]
+async fn read_decompressed(
+    inner: FusedAccessor,
+    path: &str,
+    args: OpRead,
+    algo: oio::CompressAlgorithm,
+) -> Result<Vec<u8>> {
+    let mut r = Reader::create_with_decompress(inner, path, args, algo).await?;
+    let mut buffer = Vec::new();
+    while let Some(bs) = oio::ReadExt::next(&mut r).await {
+        buffer.extend_from_slice(&bs.map_err(|err| {
+            err.with_operation("range_read")
+                .with_context("path", path)
+                .with_context("algorithm", format!("{algo:?}"))
+        })?);
+    }
+
+    Ok(buffer)
+}
+
+/// Build a descriptive error for failed [`Operator::check`] from the
+/// error returned by service.
+fn new_check_error(info: OperatorInfo, err: Error) -> Error {
+    let message = match err.kind() {
+        ErrorKind::PermissionDenied => {
+            "check failed, credentials are invalid or don't have permission"
+        }
+        ErrorKind::ConfigInvalid => "check failed, service is misconfigured",
+        _ if err.is_temporary() => "check failed, service is not reachable",
+        _ => "check failed",
+    };
+
+    let mut e = Error::new(err.kind(), message)
+        .with_operation("Operator::check")
+        .with_context("service", info.scheme())
+        .with_context("root", info.root());
+    if err.is_temporary() {
+        e = e.set_temporary();
+    }
+    e.set_source(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_sort_by_content_length() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("dir/a", "xxx").await?;
+        op.write("dir/b", "x").await?;
+        op.write("dir/c", "xx").await?;
+
+        let entries = op.list_with("dir/").sort_by(SortKey::ContentLength).await?;
+        let paths: Vec<_> = entries.iter().map(|v| v.path()).collect();
+        assert_eq!(paths, vec!["dir/b", "dir/c", "dir/a"]);
+        let sizes: Vec<_> = entries
+            .iter()
+            .map(|v| v.metadata().content_length())
+            .collect();
+        assert_eq!(sizes, vec![1, 2, 3]);
+
+        let entries = op.list_with("dir/").sort_by(SortKey::Path).await?;
+        let paths: Vec<_> = entries.iter().map(|v| v.path()).collect();
+        assert_eq!(paths, vec!["dir/a", "dir/b", "dir/c"]);
+        Ok(())
+    }
+
+    #[cfg(feature = "compress")]
+    #[tokio::test]
+    async fn test_read_with_auto_decompress() -> Result<()> {
+        use async_compression::futures::bufread::GzipEncoder;
+
+        let content = "Hello, OpenDAL!".repeat(1024);
+        let mut gz = vec![];
+        GzipEncoder::new(content.as_bytes())
+            .read_to_end(&mut gz)
+            .await
+            .expect("compress must succeed");
+
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("data.csv.gz", gz.clone()).await?;
+        op.write("data.csv", content.clone()).await?;
+
+        let bs = op.read_with("data.csv.gz").auto_decompress(true).await?;
+        assert_eq!(bs, content.as_bytes());
+        // Paths with unknown extension are read as is.
+        let bs = op.read_with("data.csv").auto_decompress(true).await?;
+        assert_eq!(bs, content.as_bytes());
+        // Content is read as is if auto decompress is not enabled.
+        assert_eq!(op.read("data.csv.gz").await?, gz);
+
+        let err = op
+            .read_with("data.csv.gz")
+            .auto_decompress(true)
+            .range(0..10)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        Ok(())
+    }
+
+    #[cfg(feature = "compress")]
+    #[tokio::test]
+    async fn test_reader_with_auto_decompress() -> Result<()> {
+        use std::io::SeekFrom;
+
+        use async_compression::futures::bufread::ZstdEncoder;
+        use futures::AsyncSeekExt;
+
+        let content = "Hello, OpenDAL!".repeat(1024);
+        let mut zst = vec![];
+        ZstdEncoder::new(content.as_bytes())
+            .read_to_end(&mut zst)
+            .await
+            .expect("compress must succeed");
+
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("data.csv.zst", zst.clone()).await?;
+
+        let mut r = op.reader_with("data.csv.zst").auto_decompress(true).await?;
+        let mut bs = vec![];
+        r.read_to_end(&mut bs).await.expect("read must succeed");
+        assert_eq!(bs, content.as_bytes());
+
+        // Seeking is not supported on decompressed content.
+        assert!(r.seek(SeekFrom::Start(0)).await.is_err());
+
+        let mut r = op.reader("data.csv.zst").await?;
+        let mut bs = vec![];
+        r.read_to_end(&mut bs).await.expect("read must succeed");
+        assert_eq!(bs, zst);
+
+        let err = op
+            .reader_with("data.csv.zst")
+            .auto_decompress(true)
+            .range(0..10)
+            .await
+            .err();
+        assert_eq!(err.map(|v| v.kind()), Some(ErrorKind::InvalidInput));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_if_not_exists() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        assert!(!op.info().capability().write_with_if_not_exists);
+
+        op.write_with("hello", "world").if_not_exists(true).await?;
+        assert_eq!(op.read("hello").await?, b"world");
+
+        let err = op
+            .write_with("hello", "opendal")
+            .if_not_exists(true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(op.read("hello").await?, b"world");
+
+        let err = op.writer_with("hello").if_not_exists(true).await.err();
+        assert_eq!(err.map(|v| v.kind()), Some(ErrorKind::AlreadyExists));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_layer_reads_extension() -> Result<()> {
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        use async_trait::async_trait;
+
+        #[derive(Debug)]
+        struct Tenant(&'static str);
+
+        /// TenantHook records the tenant of every operation it sees.
+        #[derive(Debug, Default, Clone)]
+        struct TenantHook(Arc<Mutex<Vec<String>>>);
+
+        impl TenantHook {
+            fn record(&self, op: &str, extensions: &Extensions) {
+                let tenant = extensions.get::<Tenant>().map_or("-", |v| v.0);
+                self.0.lock().unwrap().push(format!("{op}:{tenant}"));
+            }
+        }
+
+        #[async_trait]
+        impl LayerHook for TenantHook {
+            async fn read<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpRead,
+            ) -> Result<(RpRead, oio::Reader)> {
+                self.record("read", args.extensions());
+                let (rp, r) = inner.read(path, args).await?;
+                Ok((rp, Box::new(r) as oio::Reader))
+            }
+
+            async fn write<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpWrite,
+            ) -> Result<(RpWrite, oio::Writer)> {
+                self.record("write", args.extensions());
+                let (rp, w) = inner.write(path, args).await?;
+                Ok((rp, Box::new(w) as oio::Writer))
+            }
+
+            async fn stat<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpStat,
+            ) -> Result<RpStat> {
+                self.record("stat", args.extensions());
+                inner.stat(path, args).await
+            }
+
+            async fn list<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpList,
+            ) -> Result<(RpList, A::Pager)> {
+                self.record("list", args.extensions());
+                inner.list(path, args).await
+            }
+
+            async fn copy<A: Accessor>(
+                &self,
+                inner: &A,
+                from: &str,
+                to: &str,
+                args: OpCopy,
+            ) -> Result<RpCopy> {
+                self.record("copy", args.extensions());
+                inner.copy(from, to, args).await
+            }
+
+            async fn delete<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpDelete,
+            ) -> Result<RpDelete> {
+                self.record("delete", args.extensions());
+                inner.delete(path, args).await
+            }
+        }
+
+        let hook = TenantHook::default();
+        let op = Operator::new(services::Memory::default())?
+            .layer(HookLayer(hook.clone()))
+            .finish();
+
+        op.write_with("hello", "world")
+            .extension(Tenant("alice"))
+            .await?;
+        let bs = op.read_with("hello").extension(Tenant("bob")).await?;
+        assert_eq!(bs, b"world");
+        op.stat_with("hello").await?;
+        op.list_with("/").extension(Tenant("carol")).await?;
+        op.copy_with("hello", "world")
+            .extension(Tenant("dave"))
+            .await?;
+        op.delete_with("world").extension(Tenant("erin")).await?;
+
+        assert_eq!(
+            *hook.0.lock().unwrap(),
+            vec![
+                "write:alice",
+                "read:bob",
+                "stat:-",
+                "list:carol",
+                "copy:dave",
+                "delete:erin"
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_many() -> Result<()> {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering;
+        use std::sync::Arc;
+
+        use async_trait::async_trait;
+
+        /// InflightHook records the max in-flight writes.
+        #[derive(Debug, Default, Clone)]
+        struct InflightHook {
+            current: Arc<AtomicUsize>,
+            max: Arc<AtomicUsize>,
+        }
+
+        #[async_trait]
+        impl LayerHook for InflightHook {
+            async fn write<A: Accessor>(
+                &self,
+                inner: &A,
+                path: &str,
+                args: OpWrite,
+            ) -> Result<(RpWrite, oio::Writer)> {
+                let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max.fetch_max(current, Ordering::SeqCst);
+                // Yield to make other writes have a chance to start.
+                for _ in 0..4 {
+                    tokio::task::yield_now().await;
+                }
+                let res = inner.write(path, args).await;
+                self.current.fetch_sub(1, Ordering::SeqCst);
+                let (rp, w) = res?;
+                Ok((rp, Box::new(w) as oio::Writer))
+            }
+        }
+
+        let hook = InflightHook::default();
+        let op = Operator::new(services::Memory::default())?
+            .layer(HookLayer(hook.clone()))
+            .finish();
+
+        let items = (0..500)
+            .map(|i| (format!("dir/{i}"), Bytes::from(i.to_string())))
+            .collect::<Vec<_>>();
+        let results = op.write_many(items, 16).await;
+        assert_eq!(results.len(), 500);
+        for (i, (path, res)) in results.into_iter().enumerate() {
+            assert_eq!(path, format!("dir/{i}"));
+            assert!(res.is_ok(), "write {path} failed");
+        }
+        assert_eq!(op.list("dir/").await?.len(), 500);
+
+        let max = hook.max.load(Ordering::SeqCst);
+        assert!(max > 1 && max <= 16, "max in-flight writes is {max}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stat_many() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        for i in (0..200).step_by(2) {
+            op.write(&format!("dir/{i}"), i.to_string()).await?;
+        }
+
+        let paths = (0..200).map(|i| format!("dir/{i}")).collect::<Vec<_>>();
+        let results = op.stat_many(paths, 16).await;
+        assert_eq!(results.len(), 200);
+        for (i, (path, res)) in results.into_iter().enumerate() {
+            assert_eq!(path, format!("dir/{i}"));
+            if i % 2 == 0 {
+                let meta = res.expect("stat must succeed");
+                assert_eq!(meta.content_length(), i.to_string().len() as u64);
+            } else {
+                assert_eq!(res.unwrap_err().kind(), ErrorKind::NotFound);
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_du() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("dir/a", "x").await?;
+        op.write("dir/b/c", "xx").await?;
+        op.write("dir/b/d/e", "xxx").await?;
+        op.create_dir("dir/b/empty/").await?;
+        op.write("dir2/f", "xxxx").await?;
+
+        let usage = op.du("dir/").await?;
+        assert_eq!(usage.bytes(), 6);
+        assert_eq!(usage.count(), 3);
+
+        let usage = op.du("dir/b/").await?;
+        assert_eq!(usage.bytes(), 5);
+        assert_eq!(usage.count(), 2);
+
+        let usage = op.du("dir/b/d/e").await?;
+        assert_eq!(usage.bytes(), 3);
+        assert_eq!(usage.count(), 1);
+
+        assert_eq!(op.du("/").await?, DiskUsage::new(10, 4));
+        assert_eq!(op.du("not_exist/").await?, DiskUsage::default());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_memory() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.check().await?;
+
+        op.write("hello", "world").await?;
+        op.check().await
+    }
+
+    #[cfg(feature = "services-s3")]
+    #[tokio::test]
+    async fn test_check_bad_credentials() -> Result<()> {
+        use wiremock::matchers::method;
+        use wiremock::Mock;
+        use wiremock::MockServer;
+        use wiremock::ResponseTemplate;
+
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(403).set_body_string(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>InvalidAccessKeyId</Code>
+  <Message>The AWS Access Key Id you provided does not exist in our records.</Message>
+</Error>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = services::S3::default();
+        builder
+            .bucket("test")
+            .endpoint(&mock_server.uri())
+            .region("us-east-1")
+            .access_key_id("invalid")
+            .secret_access_key("invalid");
+        let op = Operator::new(builder)?.finish();
+
+        let err = op.check().await.expect_err("check must fail");
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.to_string().contains("credentials are invalid"));
+        assert!(err.to_string().contains("InvalidAccessKeyId"));
+
+        Ok(())
+    }
+}
@@ -89,6 +89,16 @@ impl FunctionWrite {
         self
     }
 
+    /// Only write if the path doesn't exist.
+    ///
+    /// See [`crate::operator_futures::FutureWrite::if_not_exists`] for details.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_if_not_exists(v), bs));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
@@ -108,6 +118,15 @@ impl FunctionDelete {
         self
     }
 
+    /// Delete the directory along with all its children.
+    ///
+    /// Deleting a non-empty directory without recursive will return an
+    /// [`ErrorKind::IsADirectory`] error.
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_recursive(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<()> {
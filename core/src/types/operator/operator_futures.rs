@@ -27,6 +27,8 @@ use std::task::Poll;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
 use futures::future::BoxFuture;
 use futures::Future;
@@ -138,6 +140,25 @@ impl FutureStat {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
+
+    /// Set the max retries for this operation.
+    ///
+    /// This overrides the max times of [`RetryLayer`][crate::layers::RetryLayer]
+    /// for this call only, `0` means failing immediately without retry.
+    pub fn max_retries(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_retries(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureStat {
@@ -253,6 +274,9 @@ impl FuturePresignRead {
     }
 
     /// Sets the content-type header that should be send back by the remote read operation.
+    ///
+    /// The override is included in the signature, so that browsers will
+    /// receive the given content-type while the object keeps its own.
     pub fn override_content_type(mut self, v: &str) -> Self {
         self.0 = self
             .0
@@ -381,11 +405,93 @@ impl FutureRead {
         self
     }
 
+    /// Set the If-Range for this operation.
+    ///
+    /// The value is the etag or last modified time (in HTTP-date format)
+    /// of the object returned earlier, and only takes effect while reading
+    /// with range:
+    ///
+    /// - If the object hasn't been changed, the requested range will be
+    ///   returned (`206 Partial Content`).
+    /// - Otherwise, the whole content of the new object will be returned
+    ///   from the beginning (`200 OK`) instead of a stale range.
+    ///
+    /// Callers resuming downloads should check the returned size (or the
+    /// etag via `stat`) to decide whether to restart from scratch.
+    ///
+    /// Require [`Capability::read_with_if_range`].
+    ///
+    /// [`Capability::read_with_if_range`]: crate::Capability::read_with_if_range
+    pub fn if_range(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_range(v));
+        self
+    }
+
     /// Set the version for this operation.
     pub fn version(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
+
+    /// Set the progress callback for this operation.
+    ///
+    /// The callback will be called with the bytes that have been read so
+    /// far and the total size once per chunk.
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_progress(ProgressCallback::new(f)));
+        self
+    }
+
+    /// Set the max retries for this operation.
+    ///
+    /// This overrides the max times of [`RetryLayer`][crate::layers::RetryLayer]
+    /// for this call only, `0` means failing immediately without retry.
+    pub fn max_retries(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_retries(v));
+        self
+    }
+
+    /// Set whether to verify the checksum of content for this operation.
+    ///
+    /// The content will be verified against the checksum stored along
+    /// with the object while reading, and [`ErrorKind::ChecksumMismatch`][crate::ErrorKind::ChecksumMismatch]
+    /// will be returned if they don't match.
+    ///
+    /// Only services with `read_with_verify` capability support this.
+    pub fn verify(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify(v));
+        self
+    }
+
+    /// Set whether to decompress the content by the extension of path.
+    ///
+    /// The decoder is picked from the extension of path:
+    ///
+    /// - `.gz`: gzip
+    /// - `.zst`: zstd
+    /// - `.bz2`: bzip2
+    /// - `.xz`: xz
+    ///
+    /// Paths with other extensions will be read as is. The decompressed
+    /// content's length is unknown before read, so `range` is not allowed
+    /// to be used together.
+    #[cfg(feature = "compress")]
+    pub fn auto_decompress(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_auto_decompress(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureRead {
@@ -443,6 +549,91 @@ impl FutureReader {
         self.0 = self.0.map_args(|args| args.with_if_none_match(v));
         self
     }
+
+    /// Set the If-Range for this operation.
+    ///
+    /// The value is the etag or last modified time (in HTTP-date format)
+    /// of the object returned earlier, and only takes effect while reading
+    /// with range:
+    ///
+    /// - If the object hasn't been changed, the requested range will be
+    ///   returned (`206 Partial Content`).
+    /// - Otherwise, the whole content of the new object will be returned
+    ///   from the beginning (`200 OK`) instead of a stale range.
+    ///
+    /// Callers resuming downloads should check the returned size (or the
+    /// etag via `stat`) to decide whether to restart from scratch.
+    ///
+    /// Require [`Capability::read_with_if_range`].
+    ///
+    /// [`Capability::read_with_if_range`]: crate::Capability::read_with_if_range
+    pub fn if_range(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_range(v));
+        self
+    }
+
+    /// Set the buffer size for this operation.
+    ///
+    /// Buffer is the max size of data fetched from the service in one
+    /// request while streaming reading, which is different from `range`.
+    ///
+    /// A larger buffer takes more memory but reduces the round trips to
+    /// the service, while a smaller one does the opposite. If not set,
+    /// all data will be fetched in one request.
+    pub fn buffer(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_buffer(v));
+        self
+    }
+
+    /// Set whether to decompress the content by the extension of path.
+    ///
+    /// The decoder is picked from the extension of path:
+    ///
+    /// - `.gz`: gzip
+    /// - `.zst`: zstd
+    /// - `.bz2`: bzip2
+    /// - `.xz`: xz
+    ///
+    /// Paths with other extensions will be read as is. Content is
+    /// decompressed while streaming, the decompressed content's length is
+    /// unknown before read, so neither `range` nor seek is allowed, and
+    /// `buffer` is ignored.
+    #[cfg(feature = "compress")]
+    pub fn auto_decompress(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_auto_decompress(v));
+        self
+    }
+
+    /// Set the max retries for this operation.
+    ///
+    /// This overrides the max times of [`RetryLayer`][crate::layers::RetryLayer]
+    /// for this call only, `0` means failing immediately without retry.
+    pub fn max_retries(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_retries(v));
+        self
+    }
+
+    /// Set whether to verify the checksum of content for this operation.
+    ///
+    /// The content will be verified against the checksum stored along
+    /// with the object while reading, and [`ErrorKind::ChecksumMismatch`][crate::ErrorKind::ChecksumMismatch]
+    /// will be returned if they don't match.
+    ///
+    /// Only services with `read_with_verify` capability support this.
+    pub fn verify(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_verify(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureReader {
@@ -456,7 +647,7 @@ impl Future for FutureReader {
 /// Future that generated by [`Operator::write_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
-pub struct FutureWrite(pub(crate) OperatorFuture<(OpWrite, Bytes), ()>);
+pub struct FutureWrite(pub(crate) OperatorFuture<(OpWrite, Buffer), ()>);
 
 impl FutureWrite {
     /// Set the content length of op.
@@ -493,6 +684,100 @@ impl FutureWrite {
             .map_args(|(args, bs)| (args.with_cache_control(v), bs));
         self
     }
+
+    /// Set the content md5 of option.
+    ///
+    /// The value is the base64 encoded md5 digest of the whole content.
+    /// Services will reject the write with [`ErrorKind::ChecksumMismatch`]
+    /// if the received content doesn't match.
+    ///
+    /// [`ErrorKind::ChecksumMismatch`]: crate::ErrorKind::ChecksumMismatch
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_content_md5(v), bs));
+        self
+    }
+
+    /// Calculate the content md5 of every request automatically.
+    ///
+    /// For multipart uploads, the md5 is calculated for every part.
+    pub fn auto_content_md5(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_auto_content_md5(v), bs));
+        self
+    }
+
+    /// Set the checksum algorithm of option.
+    ///
+    /// The checksum will be calculated by given algorithm and sent along
+    /// with every request, including every part of multipart upload.
+    pub fn checksum_algorithm(mut self, v: ChecksumAlgorithm) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_checksum_algorithm(v), bs));
+        self
+    }
+
+    /// Only write if the path doesn't exist.
+    ///
+    /// An error with kind `AlreadyExists` will be returned if the path exists.
+    ///
+    /// # Notes
+    ///
+    /// Services that don't support conditional write natively will check
+    /// the existence before writing, which is racy: the path could be
+    /// created by others between checking and writing. Check
+    /// [`Capability::write_with_if_not_exists`] for native support.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_if_not_exists(v), bs));
+        self
+    }
+
+    /// Set the object lock mode of this write.
+    ///
+    /// The object will be protected from being deleted or overwritten until
+    /// the date set by `retain_until`, both of them must be set together.
+    /// Object lock must be enabled on the bucket, check
+    /// [`Capability::write_with_object_lock`] for support.
+    pub fn object_lock_mode(mut self, v: ObjectLockMode) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_object_lock_mode(v), bs));
+        self
+    }
+
+    /// Set the retain until date of object lock for this write.
+    ///
+    /// Must be set along with `object_lock_mode`.
+    pub fn retain_until(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_retain_until(v), bs));
+        self
+    }
+
+    /// Set the progress callback for this operation.
+    ///
+    /// The callback will be called with the bytes that have been written so
+    /// far and the total size once per chunk.
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, bs)| (args.with_progress(ProgressCallback::new(f)), bs));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|(args, bs)| (args.with_extension(v), bs));
+        self
+    }
 }
 
 impl Future for FutureWrite {
@@ -535,6 +820,105 @@ impl FutureWriter {
         self.0 = self.0.map_args(|args| args.with_cache_control(v));
         self
     }
+
+    /// Set the content md5 of option.
+    ///
+    /// The value is the base64 encoded md5 digest of the whole content.
+    /// Services will reject the write with [`ErrorKind::ChecksumMismatch`]
+    /// if the received content doesn't match.
+    ///
+    /// [`ErrorKind::ChecksumMismatch`]: crate::ErrorKind::ChecksumMismatch
+    pub fn content_md5(mut self, v: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_content_md5(v));
+        self
+    }
+
+    /// Calculate the content md5 of every request automatically.
+    ///
+    /// For multipart uploads, the md5 is calculated for every part.
+    pub fn auto_content_md5(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_auto_content_md5(v));
+        self
+    }
+
+    /// Set the checksum algorithm of option.
+    ///
+    /// The checksum will be calculated by given algorithm and sent along
+    /// with every request, including every part of multipart upload.
+    pub fn checksum_algorithm(mut self, v: ChecksumAlgorithm) -> Self {
+        self.0 = self.0.map_args(|args| args.with_checksum_algorithm(v));
+        self
+    }
+
+    /// Only write if the path doesn't exist.
+    ///
+    /// An error with kind `AlreadyExists` will be returned if the path exists.
+    ///
+    /// # Notes
+    ///
+    /// Services that don't support conditional write natively will check
+    /// the existence before writing, which is racy: the path could be
+    /// created by others between checking and writing. Check
+    /// [`Capability::write_with_if_not_exists`] for native support.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_if_not_exists(v));
+        self
+    }
+
+    /// Set the object lock mode of this write.
+    ///
+    /// The object will be protected from being deleted or overwritten until
+    /// the date set by `retain_until`, both of them must be set together.
+    /// Object lock must be enabled on the bucket, check
+    /// [`Capability::write_with_object_lock`] for support.
+    pub fn object_lock_mode(mut self, v: ObjectLockMode) -> Self {
+        self.0 = self.0.map_args(|args| args.with_object_lock_mode(v));
+        self
+    }
+
+    /// Set the retain until date of object lock for this write.
+    ///
+    /// Must be set along with `object_lock_mode`.
+    pub fn retain_until(mut self, v: DateTime<Utc>) -> Self {
+        self.0 = self.0.map_args(|args| args.with_retain_until(v));
+        self
+    }
+
+    /// Stream data of unknown size into the writer.
+    ///
+    /// The content length must not be set, and data should be fed by
+    /// [`Writer::sink_unsized`] or [`Writer::copy_unsized`]. Services will
+    /// pick the best strategy for it: multipart upload for object storages,
+    /// which buffers one part in memory at most, or chunked transfer
+    /// encoding for http based services like webdav.
+    ///
+    /// An error with kind `Unsupported` will be returned if the service
+    /// supports neither of them.
+    pub fn chunked_unknown_size(mut self) -> Self {
+        self.0 = self.0.map_args(|args| args.with_chunked_unknown_size(true));
+        self
+    }
+
+    /// Set the progress callback for this operation.
+    ///
+    /// The callback will be called with the bytes that have been written so
+    /// far and the total size (if content length is set) once per chunk.
+    pub fn on_progress(mut self, f: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        self.0 = self
+            .0
+            .map_args(|args| args.with_progress(ProgressCallback::new(f)));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureWriter {
@@ -545,6 +929,70 @@ impl Future for FutureWriter {
     }
 }
 
+/// Future that generated by [`Operator::copy_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub struct FutureCopy(pub(crate) OperatorFuture<(OpCopy, String), ()>);
+
+impl FutureCopy {
+    /// Copy the directory along with all its children.
+    ///
+    /// Both `from` and `to` must be a directory if recursive is set.
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|(args, to)| (args.with_recursive(v), to));
+        self
+    }
+
+    /// Set the max concurrent copies while copying recursively.
+    ///
+    /// Default to 8.
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|(args, to)| (args.with_concurrent(v), to));
+        self
+    }
+
+    /// Copy only given range of `from` into `to`.
+    ///
+    /// Require [`Capability::copy_with_range`], and can't be used with
+    /// `recursive`.
+    pub fn range(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_range(range.into()), to));
+        self
+    }
+
+    /// Fail with [`ErrorKind::AlreadyExists`][crate::ErrorKind::AlreadyExists]
+    /// if the target path already exists.
+    ///
+    /// Services without [`Capability::copy_with_if_not_exists`] will check
+    /// the target path before copying, which is not atomic.
+    pub fn if_not_exists(mut self, v: bool) -> Self {
+        self.0 = self
+            .0
+            .map_args(|(args, to)| (args.with_if_not_exists(v), to));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|(args, to)| (args.with_extension(v), to));
+        self
+    }
+}
+
+impl Future for FutureCopy {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
 /// Future that generated by [`Operator::delete_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -556,6 +1004,25 @@ impl FutureDelete {
         self.0 = self.0.map_args(|args| args.with_version(v));
         self
     }
+
+    /// Delete the directory along with all its children.
+    ///
+    /// Deleting a non-empty directory without recursive will return an
+    /// [`ErrorKind::IsADirectory`] error.
+    pub fn recursive(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_recursive(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureDelete {
@@ -572,13 +1039,39 @@ impl Future for FutureDelete {
 pub struct FutureList(pub(crate) OperatorFuture<OpList, Vec<Entry>>);
 
 impl FutureList {
+    /// Change the max results of this list operation.
+    ///
+    /// Listing will stop fetching more pages from the service once `v`
+    /// entries are returned. This is different from the page size which
+    /// could be set by `limit`.
+    pub fn max_results(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_results(v));
+        self
+    }
+
     /// Change the start_after of this list operation.
     pub fn start_after(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_start_after(v));
         self
     }
 
+    /// Resume listing from the continuation token returned by
+    /// [`Lister::continuation_token`].
+    ///
+    /// Tokens are specific to services and the listed path, and could
+    /// expire after a while, only services with `list_with_continuation`
+    /// capability support this.
+    pub fn continuation(mut self, token: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_continuation(token));
+        self
+    }
+
     /// Change the delimiter. The default delimiter is "/"
+    ///
+    /// Entries sharing the same prefix up to the delimiter will be grouped
+    /// as a dir entry. Delimiters other than "/" and "" are only supported
+    /// by services with `list_with_delimiter_custom` capability, others will
+    /// return `Unsupported`.
     pub fn delimiter(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_delimiter(v));
         self
@@ -589,6 +1082,53 @@ impl FutureList {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
+
+    /// Change the versions of this list operation.
+    ///
+    /// If enabled, all versions of objects will be listed and the version id
+    /// can be fetched via [`Metadata::version`].
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
+    /// Sort the returned entries by given key.
+    ///
+    /// The metakey required by the key will be fetched automatically.
+    ///
+    /// # Notes
+    ///
+    /// All entries have to be buffered in memory before sorting. Listing
+    /// will fail instead of buffering endlessly if more than 100,000
+    /// entries are returned, please use
+    /// `max_results` to limit the entries or use [`Operator::lister_with`]
+    /// for huge listings.
+    pub fn sort_by(mut self, v: SortKey) -> Self {
+        self.0 = self.0.map_args(|args| args.with_sort_by(v));
+        self
+    }
+
+    /// Change whether the paths of entries are relative to the root.
+    ///
+    /// - `true` (default): [`Entry::path`] is relative to the root of
+    ///   operator, like `dir/sub/file` while listing `dir/`.
+    /// - `false`: [`Entry::path`] is relative to the listed directory,
+    ///   like `sub/file` while listing `dir/`. The listed directory itself
+    ///   will be returned as `/` if services return it.
+    pub fn absolute(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_absolute(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureList {
@@ -611,13 +1151,39 @@ impl FutureLister {
         self
     }
 
+    /// Change the max results of this list operation.
+    ///
+    /// The lister will stop fetching more pages from the service and be
+    /// closed once `v` entries are returned. This is different from
+    /// `limit` which is the page size.
+    pub fn max_results(mut self, v: usize) -> Self {
+        self.0 = self.0.map_args(|args| args.with_max_results(v));
+        self
+    }
+
     /// Change the start_after of this list operation.
     pub fn start_after(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_start_after(v));
         self
     }
 
+    /// Resume listing from the continuation token returned by
+    /// [`Lister::continuation_token`].
+    ///
+    /// Tokens are specific to services and the listed path, and could
+    /// expire after a while, only services with `list_with_continuation`
+    /// capability support this.
+    pub fn continuation(mut self, token: &str) -> Self {
+        self.0 = self.0.map_args(|args| args.with_continuation(token));
+        self
+    }
+
     /// Change the delimiter. The default delimiter is "/"
+    ///
+    /// Entries sharing the same prefix up to the delimiter will be grouped
+    /// as a dir entry. Delimiters other than "/" and "" are only supported
+    /// by services with `list_with_delimiter_custom` capability, others will
+    /// return `Unsupported`.
     pub fn delimiter(mut self, v: &str) -> Self {
         self.0 = self.0.map_args(|args| args.with_delimiter(v));
         self
@@ -628,6 +1194,37 @@ impl FutureLister {
         self.0 = self.0.map_args(|args| args.with_metakey(v));
         self
     }
+
+    /// Change the versions of this list operation.
+    ///
+    /// If enabled, all versions of objects will be listed and the version id
+    /// can be fetched via [`Metadata::version`].
+    pub fn versions(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_versions(v));
+        self
+    }
+
+    /// Change whether the paths of entries are relative to the root.
+    ///
+    /// - `true` (default): [`Entry::path`] is relative to the root of
+    ///   operator, like `dir/sub/file` while listing `dir/`.
+    /// - `false`: [`Entry::path`] is relative to the listed directory,
+    ///   like `sub/file` while listing `dir/`. The listed directory itself
+    ///   will be returned as `/` if services return it.
+    pub fn absolute(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_absolute(v));
+        self
+    }
+
+    /// Attach a typed extension to this operation.
+    ///
+    /// Extensions carry request-scoped context like tenant id or
+    /// short-lived token, which could be inspected by layers and services
+    /// via [`Extensions`].
+    pub fn extension<T: Send + Sync + 'static>(mut self, v: T) -> Self {
+        self.0 = self.0.map_args(|args| args.with_extension(v));
+        self
+    }
 }
 
 impl Future for FutureLister {
@@ -17,6 +17,7 @@
 
 use std::io;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
@@ -53,6 +54,12 @@ use crate::*;
 ///
 /// Besides, `Stream` **COULD** reduce an extra copy if underlying reader is
 /// stream based (like services s3, azure which based on HTTP).
+///
+/// # Ownership
+///
+/// Reader doesn't borrow from the [`Operator`] that creates it. It's
+/// `'static` and `Send`, so it can be returned from functions or moved into
+/// spawned tasks freely.
 pub struct Reader {
     inner: oio::Reader,
     seek_state: SeekState,
@@ -67,6 +74,17 @@ impl Reader {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
+        #[cfg(feature = "compress")]
+        if op.auto_decompress() {
+            if let Some(algo) = oio::CompressAlgorithm::from_path(path) {
+                return Self::create_with_decompress(acc, path, op, algo).await;
+            }
+        }
+
+        if let Some(buffer) = op.buffer() {
+            return Self::create_with_buffer(acc, path, op, buffer).await;
+        }
+
         let (_, r) = acc.read(path, op).await?;
 
         Ok(Reader {
@@ -74,6 +92,119 @@ impl Reader {
             seek_state: SeekState::Init,
         })
     }
+
+    /// Create a reader that fetches at most `buffer` bytes in every request.
+    async fn create_with_buffer(
+        acc: FusedAccessor,
+        path: &str,
+        op: OpRead,
+        buffer: usize,
+    ) -> Result<Self> {
+        if buffer == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "read buffer size must be greater than 0",
+            ));
+        }
+
+        let range = op.range();
+        let (offset, size) = match (range.offset(), range.size()) {
+            (Some(offset), Some(size)) => (offset, size),
+            (offset, size) => {
+                let total = acc
+                    .stat(path, OpStat::new())
+                    .await?
+                    .into_metadata()
+                    .content_length();
+                match (offset, size) {
+                    (Some(offset), _) => (offset, total.saturating_sub(offset)),
+                    (None, Some(size)) => (total.saturating_sub(size), size.min(total)),
+                    (None, None) => (0, total),
+                }
+            }
+        };
+
+        // Nothing to read, `BytesRange` with zero size is invalid.
+        if size == 0 {
+            return Ok(Reader {
+                inner: Box::new(oio::Cursor::from(Bytes::new())),
+                seek_state: SeekState::Init,
+            });
+        }
+
+        let first = size.min(buffer as u64);
+        let (_, r) = acc
+            .read(
+                path,
+                op.clone()
+                    .with_range(BytesRange::new(Some(offset), Some(first))),
+            )
+            .await?;
+        let r = oio::into_seekable_read_by_range(Arc::new(acc), path, r, offset, size)
+            .with_op(op)
+            .with_chunk(buffer);
+
+        Ok(Reader {
+            inner: Box::new(r),
+            seek_state: SeekState::Init,
+        })
+    }
+
+    /// Create a reader that decompresses the content with given algorithm
+    /// while reading.
+    ///
+    /// The decompressed content's length is unknown, so neither range nor
+    /// seek is supported.
+    #[cfg(feature = "compress")]
+    pub(crate) async fn create_with_decompress(
+        acc: FusedAccessor,
+        path: &str,
+        op: OpRead,
+        algo: oio::CompressAlgorithm,
+    ) -> Result<Self> {
+        if !op.range().is_full() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "range is not allowed while decompressing automatically",
+            )
+            .with_operation("Reader::create")
+            .with_context("service", acc.info().scheme())
+            .with_context("path", path)
+            .with_context("range", op.range().to_string()));
+        }
+
+        let progress = op.progress().cloned();
+        let (rp, r) = acc.read(path, op).await?;
+        let r = match progress {
+            Some(progress) => Box::new(oio::into_progress_read(
+                r,
+                progress,
+                rp.metadata().content_length_raw(),
+            )) as oio::Reader,
+            None => r,
+        };
+
+        Ok(Reader {
+            inner: Box::new(oio::into_decompress_read(r, algo)),
+            seek_state: SeekState::Init,
+        })
+    }
+}
+
+impl Reader {
+    /// Read all remaining content into a [`Buffer`].
+    ///
+    /// Chunks returned by services are kept as is without concatenation,
+    /// so they can be forwarded without copying, for example, by
+    /// `AsyncWrite::poll_write_vectored`.
+    pub async fn read_buffer(&mut self) -> Result<Buffer> {
+        let mut buf = Buffer::new();
+        // Use `oio::ReadExt` instead of `Stream` to keep the error from services.
+        while let Some(bs) = oio::ReadExt::next(self).await {
+            buf.push(bs?);
+        }
+        Ok(buf)
+    }
 }
 
 impl oio::Read for Reader {
@@ -179,6 +310,9 @@ impl Stream for Reader {
 
 /// BlockingReader is designed to read data from given path in an blocking
 /// manner.
+///
+/// Like [`Reader`], BlockingReader is `'static` and `Send`, so it can be
+/// moved into other threads.
 pub struct BlockingReader {
     pub(crate) inner: oio::BlockingReader,
 }
@@ -242,14 +376,86 @@ impl Iterator for BlockingReader {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use async_trait::async_trait;
     use rand::rngs::ThreadRng;
     use rand::Rng;
     use rand::RngCore;
     use tokio::io::AsyncReadExt;
     use tokio::io::AsyncSeekExt;
 
+    use super::*;
     use crate::services;
     use crate::Operator;
+    use crate::OperatorBuilder;
+
+    /// MockReadService counts all read requests it received.
+    #[derive(Debug, Default)]
+    struct MockReadService {
+        data: Bytes,
+        reads: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Accessor for MockReadService {
+        type Reader = oio::Cursor;
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Appender = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn info(&self) -> AccessorInfo {
+            let mut am = AccessorInfo::default();
+            am.set_capability(Capability {
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                stat: true,
+                ..Default::default()
+            });
+            am
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+
+            let bs = args.range().apply_on_bytes(self.data.clone());
+            Ok((RpRead::new(bs.len() as u64), oio::Cursor::from(bs)))
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Ok(RpStat::new(
+                Metadata::new(EntryMode::FILE).with_content_length(self.data.len() as u64),
+            ))
+        }
+    }
+
+    /// ChunkedReader returns given chunks one by one.
+    struct ChunkedReader {
+        chunks: Vec<Bytes>,
+    }
+
+    impl oio::Read for ChunkedReader {
+        fn poll_read(&mut self, _: &mut Context<'_>, _: &mut [u8]) -> Poll<Result<usize>> {
+            unimplemented!("only poll_next is used")
+        }
+
+        fn poll_seek(&mut self, _: &mut Context<'_>, _: io::SeekFrom) -> Poll<Result<u64>> {
+            unimplemented!("only poll_next is used")
+        }
+
+        fn poll_next(&mut self, _: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+            if self.chunks.is_empty() {
+                return Poll::Ready(None);
+            }
+            Poll::Ready(Some(Ok(self.chunks.remove(0))))
+        }
+    }
 
     fn gen_random_bytes() -> Vec<u8> {
         let mut rng = ThreadRng::default();
@@ -260,6 +466,32 @@ mod tests {
         content
     }
 
+    #[tokio::test]
+    async fn test_reader_read_buffer_preserves_chunks() -> Result<()> {
+        let chunks = vec![
+            Bytes::from("Hello"),
+            Bytes::from(", "),
+            Bytes::from("World!"),
+        ];
+        let mut reader = Reader {
+            inner: Box::new(ChunkedReader {
+                chunks: chunks.clone(),
+            }),
+            seek_state: SeekState::Init,
+        };
+
+        let buf = reader.read_buffer().await?;
+        assert_eq!(buf.len(), 13);
+        assert_eq!(buf.chunk_count(), chunks.len());
+        for (got, expected) in buf.chunks().zip(chunks.iter()) {
+            // The same memory is shared without concatenation.
+            assert_eq!(got.as_ptr(), expected.as_ptr());
+            assert_eq!(got, expected);
+        }
+        assert_eq!(buf.to_vec(), b"Hello, World!");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_reader_async_read() {
         let op = Operator::new(services::Memory::default()).unwrap().finish();
@@ -308,4 +540,90 @@ mod tests {
             .expect("read to end must succeed");
         assert_eq!(buf, content);
     }
+
+    #[tokio::test]
+    async fn test_reader_with_buffer() {
+        let content = Bytes::from(vec![1; 16 * 1024]);
+
+        let mut requests = vec![];
+        for buffer in [1024, 4096] {
+            let reads = Arc::new(AtomicUsize::new(0));
+            let op = OperatorBuilder::new(MockReadService {
+                data: content.clone(),
+                reads: reads.clone(),
+            })
+            .finish();
+
+            let mut reader = op.reader_with("test_file").buffer(buffer).await.unwrap();
+            let mut buf = Vec::new();
+            reader
+                .read_to_end(&mut buf)
+                .await
+                .expect("read to end must succeed");
+            assert_eq!(buf, content);
+
+            requests.push(reads.load(Ordering::SeqCst));
+        }
+
+        assert_eq!(requests, vec![16, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_reader_with_buffer_empty() -> Result<()> {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let op = OperatorBuilder::new(MockReadService {
+            data: Bytes::new(),
+            reads: reads.clone(),
+        })
+        .finish();
+
+        let mut reader = op.reader_with("test_file").buffer(1024).await?;
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert!(buf.is_empty());
+        assert_eq!(reads.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+
+    /// Readers must be owned so that they can be moved around freely.
+    #[allow(dead_code)]
+    fn assert_reader_is_owned() {
+        fn is_owned<T: Send + Sync + 'static>() {}
+
+        is_owned::<Reader>();
+        is_owned::<BlockingReader>();
+    }
+
+    /// Open a reader in a function which doesn't outlive the operator.
+    async fn open_reader(path: &str) -> Result<Reader> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write(path, "Hello, World!").await?;
+        op.reader_with(path).buffer(4).await
+    }
+
+    #[tokio::test]
+    async fn test_reader_in_spawned_task() -> anyhow::Result<()> {
+        let mut reader = open_reader("test_file").await?;
+
+        let handle = tokio::spawn(async move {
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf).await.map(|_| buf)
+        });
+        let buf = handle.await.expect("task must succeed")?;
+        assert_eq!(buf, b"Hello, World!");
+
+        // The future returned by `reader_with` doesn't borrow the operator
+        // either, so it could be moved into spawned tasks too.
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("test_file", "Hello, World!").await?;
+        let handle = tokio::spawn(op.reader_with("test_file"));
+        let mut reader = handle.await.expect("task must succeed")?;
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf).await?;
+        assert_eq!(buf, "Hello, World!");
+        Ok(())
+    }
 }
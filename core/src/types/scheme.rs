@@ -15,11 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
+use crate::Capability;
 use crate::Error;
+use crate::ErrorKind;
 
 /// Services that OpenDAL supports
 ///
@@ -41,6 +44,8 @@ pub enum Scheme {
     Cos,
     /// [dashmap][crate::services::Dashmap]: dashmap backend support.
     Dashmap,
+    /// [d1][crate::services::D1]: Cloudflare D1 services.
+    D1,
     /// [etcd][crate::services::Etcd]: Etcd Services
     Etcd,
     /// [foundationdb][crate::services::Foundationdb]: Foundationdb services.
@@ -55,6 +60,8 @@ pub enum Scheme {
     Ghac,
     /// [hdfs][crate::services::Hdfs]: Hadoop Distributed File System.
     Hdfs,
+    /// [hdfs_native][crate::services::HdfsNative]: Hadoop Distributed File System via pure-Rust client.
+    HdfsNative,
     /// [http][crate::services::Http]: HTTP backend.
     Http,
 
@@ -62,6 +69,8 @@ pub enum Scheme {
     Ipfs,
     /// [ipmfs][crate::services::Ipmfs]: IPFS mutable file system
     Ipmfs,
+    /// [libsql][crate::services::Libsql]: Libsql services, including Turso.
+    Libsql,
     /// [memcached][crate::services::Memcached]: Memcached service support.
     Memcached,
     /// [memory][crate::services::Memory]: In memory backend support.
@@ -122,6 +131,857 @@ impl Scheme {
     pub fn into_static(self) -> &'static str {
         self.into()
     }
+
+    /// Get all schemes that enabled by features of current build.
+    ///
+    /// `Scheme::Custom` will never be included.
+    pub fn enabled() -> HashSet<Scheme> {
+        HashSet::from([
+            #[cfg(feature = "services-azblob")]
+            Scheme::Azblob,
+            #[cfg(feature = "services-azdfs")]
+            Scheme::Azdfs,
+            #[cfg(feature = "services-cacache")]
+            Scheme::Cacache,
+            #[cfg(feature = "services-cos")]
+            Scheme::Cos,
+            #[cfg(feature = "services-dashmap")]
+            Scheme::Dashmap,
+            #[cfg(feature = "services-d1")]
+            Scheme::D1,
+            #[cfg(feature = "services-dropbox")]
+            Scheme::Dropbox,
+            #[cfg(feature = "services-etcd")]
+            Scheme::Etcd,
+            #[cfg(feature = "services-foundationdb")]
+            Scheme::Foundationdb,
+            #[cfg(feature = "services-fs")]
+            Scheme::Fs,
+            #[cfg(feature = "services-ftp")]
+            Scheme::Ftp,
+            #[cfg(feature = "services-gcs")]
+            Scheme::Gcs,
+            #[cfg(feature = "services-gdrive")]
+            Scheme::Gdrive,
+            #[cfg(feature = "services-ghac")]
+            Scheme::Ghac,
+            #[cfg(feature = "services-hdfs")]
+            Scheme::Hdfs,
+            #[cfg(feature = "services-hdfs-native")]
+            Scheme::HdfsNative,
+            #[cfg(feature = "services-http")]
+            Scheme::Http,
+            #[cfg(feature = "services-ipfs")]
+            Scheme::Ipfs,
+            #[cfg(feature = "services-ipmfs")]
+            Scheme::Ipmfs,
+            #[cfg(feature = "services-libsql")]
+            Scheme::Libsql,
+            #[cfg(feature = "services-memcached")]
+            Scheme::Memcached,
+            #[cfg(feature = "services-memory")]
+            Scheme::Memory,
+            #[cfg(feature = "services-mini-moka")]
+            Scheme::MiniMoka,
+            #[cfg(feature = "services-moka")]
+            Scheme::Moka,
+            #[cfg(feature = "services-obs")]
+            Scheme::Obs,
+            #[cfg(feature = "services-onedrive")]
+            Scheme::Onedrive,
+            #[cfg(feature = "services-oss")]
+            Scheme::Oss,
+            #[cfg(feature = "services-persy")]
+            Scheme::Persy,
+            #[cfg(feature = "services-postgresql")]
+            Scheme::Postgresql,
+            #[cfg(feature = "services-redb")]
+            Scheme::Redb,
+            #[cfg(feature = "services-redis")]
+            Scheme::Redis,
+            #[cfg(feature = "services-rocksdb")]
+            Scheme::Rocksdb,
+            #[cfg(feature = "services-s3")]
+            Scheme::S3,
+            #[cfg(feature = "services-sftp")]
+            Scheme::Sftp,
+            #[cfg(feature = "services-sled")]
+            Scheme::Sled,
+            #[cfg(feature = "services-supabase")]
+            Scheme::Supabase,
+            #[cfg(feature = "services-tikv")]
+            Scheme::Tikv,
+            #[cfg(feature = "services-vercel-artifacts")]
+            Scheme::VercelArtifacts,
+            #[cfg(feature = "services-wasabi")]
+            Scheme::Wasabi,
+            #[cfg(feature = "services-webdav")]
+            Scheme::Webdav,
+            #[cfg(feature = "services-webhdfs")]
+            Scheme::Webhdfs,
+        ])
+    }
+
+    /// Get the capability that services of this scheme will provide
+    /// natively with default config.
+    ///
+    /// This lookup table doesn't require the service to be enabled, so
+    /// it can be used to decide which features to enable. Returns `None`
+    /// for `Scheme::Custom`.
+    ///
+    /// # Notes
+    ///
+    /// Some capabilities depend on the config of services (for example,
+    /// `batch_max_operations` of s3 can be changed by config), please use
+    /// [`OperatorInfo::capability`][crate::OperatorInfo::capability] to get
+    /// the real capability of a built operator.
+    pub fn default_capability(self) -> Option<Capability> {
+        let cap = match self {
+            Scheme::Azblob => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                read_with_override_cache_control: true,
+                read_with_override_content_disposition: true,
+                read_with_override_content_type: true,
+                write: true,
+                write_can_sink: true,
+                write_with_content_type: true,
+                write_with_cache_control: true,
+                write_with_content_md5: true,
+                write_with_if_not_exists: true,
+                write_total_max_size: Some(5242880000),
+                append: true,
+                append_with_content_type: true,
+                append_with_cache_control: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                list: true,
+                list_max_limit: Some(5000),
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                list_with_continuation: true,
+                batch: true,
+                batch_delete: true,
+                batch_max_operations: Some(256),
+                ..Default::default()
+            },
+            Scheme::Azdfs => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                write_without_content_length: true,
+                append: true,
+                append_with_content_type: true,
+                append_with_content_disposition: true,
+                create_dir: true,
+                delete: true,
+                rename: true,
+                rename_atomic: true,
+                rename_can_dir: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Cacache => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Cos => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                write: true,
+                write_can_sink: true,
+                write_can_multi: true,
+                write_without_content_length: true,
+                write_with_content_type: true,
+                write_with_content_disposition: true,
+                write_with_cache_control: true,
+                write_multi_min_size: Some(1048576),
+                write_multi_max_size: Some(5368709120),
+                write_total_max_size: Some(52398601011200),
+                append: true,
+                append_with_content_type: true,
+                append_with_content_disposition: true,
+                append_with_cache_control: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                list: true,
+                list_max_limit: Some(1000),
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                ..Default::default()
+            },
+            Scheme::Dashmap => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Etcd => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                ..Default::default()
+            },
+            Scheme::Fs => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_with_range: true,
+                write: true,
+                write_can_sink: true,
+                write_without_content_length: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                delete_with_recursive: true,
+                copy: true,
+                rename: true,
+                rename_atomic: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Gcs => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                stat_with_version: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                read_with_version: true,
+                write: true,
+                write_can_sink: true,
+                write_without_content_length: true,
+                write_with_content_type: true,
+                write_total_max_size: Some(5497558138880),
+                create_dir: true,
+                delete: true,
+                delete_with_version: true,
+                copy: true,
+                list: true,
+                list_with_limit: true,
+                list_max_limit: Some(1000),
+                list_with_start_after: true,
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                list_with_delimiter_custom: true,
+                list_with_versions: true,
+                list_with_continuation: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                batch: true,
+                batch_max_operations: Some(100),
+                ..Default::default()
+            },
+            Scheme::Ghac => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                ..Default::default()
+            },
+            Scheme::Hdfs => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_with_range: true,
+                write: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                delete_with_recursive: true,
+                rename: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::HdfsNative => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_with_range: true,
+                write: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                rename: true,
+                rename_atomic: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Http => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                ..Default::default()
+            },
+            Scheme::Foundationdb => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                batch: true,
+                batch_delete: true,
+                batch_write: true,
+                ..Default::default()
+            },
+            Scheme::Ftp => Capability {
+                stat: true,
+                read: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Ipfs => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Ipmfs => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                delete: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::D1 => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                batch: true,
+                batch_delete: true,
+                batch_write: true,
+                ..Default::default()
+            },
+            Scheme::Libsql => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                ..Default::default()
+            },
+            Scheme::Memcached => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                ..Default::default()
+            },
+            Scheme::Memory => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::MiniMoka => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Moka => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Obs => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                write: true,
+                write_can_sink: true,
+                write_without_content_length: true,
+                write_with_content_type: true,
+                write_with_cache_control: true,
+                write_multi_min_size: Some(102400),
+                write_multi_max_size: Some(5368709120),
+                write_total_max_size: Some(52398601011200),
+                append: true,
+                append_with_content_type: true,
+                append_with_content_disposition: true,
+                append_with_cache_control: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                list: true,
+                list_max_limit: Some(1000),
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                ..Default::default()
+            },
+            Scheme::Onedrive => Capability {
+                stat: true,
+                read: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Persy => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Postgresql => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                ..Default::default()
+            },
+            Scheme::Gdrive => Capability {
+                read: true,
+                write: true,
+                write_without_content_length: true,
+                delete: true,
+                ..Default::default()
+            },
+            Scheme::Dropbox => Capability {
+                stat: true,
+                read: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                batch: true,
+                batch_delete: true,
+                batch_max_operations: Some(1000),
+                ..Default::default()
+            },
+            Scheme::Redis => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                rename_atomic: true,
+                list: true,
+                list_without_delimiter: true,
+                ..Default::default()
+            },
+            Scheme::Rocksdb => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::S3 => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                read_with_if_range: true,
+                read_with_override_cache_control: true,
+                read_with_override_content_disposition: true,
+                read_with_override_content_type: true,
+                read_with_verify: true,
+                write: true,
+                write_can_sink: true,
+                write_can_multi: true,
+                write_without_content_length: true,
+                write_with_content_type: true,
+                write_with_cache_control: true,
+                write_with_content_md5: true,
+                write_with_checksum_algorithm: true,
+                write_with_object_lock: true,
+                write_multi_min_size: Some(5242880),
+                write_multi_max_size: Some(5368709120),
+                write_total_max_size: Some(5497558138880),
+                create_dir: true,
+                delete: true,
+                copy: true,
+                copy_with_range: true,
+                list: true,
+                list_with_limit: true,
+                list_max_limit: Some(1000),
+                list_with_start_after: true,
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                list_with_delimiter_custom: true,
+                list_with_continuation: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                batch: true,
+                batch_max_operations: Some(1000),
+                ..Default::default()
+            },
+            Scheme::Sftp => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_with_range: true,
+                write: true,
+                write_without_content_length: true,
+                append: true,
+                create_dir: true,
+                delete: true,
+                rename: true,
+                rename_atomic: true,
+                list: true,
+                list_with_limit: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Sled => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_without_delimiter: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Supabase => Capability {
+                stat: true,
+                read: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                presign: true,
+                presign_read: true,
+                ..Default::default()
+            },
+            Scheme::VercelArtifacts => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                write: true,
+                ..Default::default()
+            },
+            Scheme::Oss => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                read_with_if_match: true,
+                read_with_if_none_match: true,
+                write: true,
+                write_can_sink: true,
+                write_can_multi: true,
+                write_without_content_length: true,
+                write_with_content_type: true,
+                write_with_content_disposition: true,
+                write_with_cache_control: true,
+                write_multi_min_size: Some(102400),
+                write_multi_max_size: Some(5368709120),
+                write_total_max_size: Some(52398601011200),
+                append: true,
+                append_with_content_type: true,
+                append_with_content_disposition: true,
+                append_with_cache_control: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                list: true,
+                list_max_limit: Some(1000),
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                batch: true,
+                batch_max_operations: Some(1000),
+                ..Default::default()
+            },
+            Scheme::Wasabi => Capability {
+                stat: true,
+                stat_with_if_match: true,
+                stat_with_if_none_match: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                write_total_max_size: Some(5497558138880),
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                list: true,
+                list_max_limit: Some(1000),
+                list_with_delimiter_slash: true,
+                list_without_delimiter: true,
+                presign: true,
+                presign_read: true,
+                presign_stat: true,
+                presign_write: true,
+                batch: true,
+                batch_max_operations: Some(1000),
+                ..Default::default()
+            },
+            Scheme::Webdav => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                write_can_sink: true,
+                write_can_chunked: true,
+                create_dir: true,
+                delete: true,
+                delete_with_recursive: true,
+                copy: true,
+                copy_with_if_not_exists: true,
+                rename: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Webhdfs => Capability {
+                stat: true,
+                read: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                list: true,
+                list_with_delimiter_slash: true,
+                ..Default::default()
+            },
+            Scheme::Redb => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                blocking: true,
+                ..Default::default()
+            },
+            Scheme::Tikv => Capability {
+                stat: true,
+                read: true,
+                read_can_seek: true,
+                read_can_next: true,
+                read_with_range: true,
+                write: true,
+                create_dir: true,
+                delete: true,
+                copy: true,
+                rename: true,
+                ..Default::default()
+            },
+            Scheme::Custom(_) => return None,
+        };
+
+        Some(cap)
+    }
+
+    /// Get the feature name that enables this scheme.
+    fn feature(self) -> String {
+        format!("services-{}", self.into_static().replace('_', "-"))
+    }
+
+    /// Format enabled schemes in sorted order for error messages.
+    fn format_enabled() -> String {
+        let mut schemes: Vec<_> = Scheme::enabled()
+            .into_iter()
+            .map(Scheme::into_static)
+            .collect();
+        schemes.sort_unstable();
+        schemes.join(",")
+    }
 }
 
 impl Default for Scheme {
@@ -141,46 +1001,68 @@ impl FromStr for Scheme {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.to_lowercase();
-        match s.as_str() {
-            "azblob" => Ok(Scheme::Azblob),
-            "azdfs" => Ok(Scheme::Azdfs),
-            "cacache" => Ok(Scheme::Cacache),
-            "cos" => Ok(Scheme::Cos),
-            "dashmap" => Ok(Scheme::Dashmap),
-            "dropbox" => Ok(Scheme::Dropbox),
-            "etcd" => Ok(Scheme::Etcd),
-            "fs" => Ok(Scheme::Fs),
-            "gcs" => Ok(Scheme::Gcs),
-            "gdrive" => Ok(Scheme::Gdrive),
-            "ghac" => Ok(Scheme::Ghac),
-            "hdfs" => Ok(Scheme::Hdfs),
-            "http" | "https" => Ok(Scheme::Http),
-            "ftp" | "ftps" => Ok(Scheme::Ftp),
-            "ipfs" | "ipns" => Ok(Scheme::Ipfs),
-            "ipmfs" => Ok(Scheme::Ipmfs),
-            "memcached" => Ok(Scheme::Memcached),
-            "memory" => Ok(Scheme::Memory),
-            "mini_moka" => Ok(Scheme::MiniMoka),
-            "moka" => Ok(Scheme::Moka),
-            "obs" => Ok(Scheme::Obs),
-            "onedrive" => Ok(Scheme::Onedrive),
-            "persy" => Ok(Scheme::Persy),
-            "postgresql" => Ok(Scheme::Postgresql),
-            "redb" => Ok(Scheme::Redb),
-            "redis" => Ok(Scheme::Redis),
-            "rocksdb" => Ok(Scheme::Rocksdb),
-            "s3" => Ok(Scheme::S3),
-            "sftp" => Ok(Scheme::Sftp),
-            "sled" => Ok(Scheme::Sled),
-            "supabase" => Ok(Scheme::Supabase),
-            "oss" => Ok(Scheme::Oss),
-            "vercel_artifacts" => Ok(Scheme::VercelArtifacts),
-            "wasabi" => Ok(Scheme::Wasabi),
-            "webdav" => Ok(Scheme::Webdav),
-            "webhdfs" => Ok(Scheme::Webhdfs),
-            "tikv" => Ok(Scheme::Tikv),
-            _ => Ok(Scheme::Custom(Box::leak(s.into_boxed_str()))),
+        let scheme = match s.as_str() {
+            "azblob" => Scheme::Azblob,
+            "azdfs" => Scheme::Azdfs,
+            "cacache" => Scheme::Cacache,
+            "cos" => Scheme::Cos,
+            "dashmap" => Scheme::Dashmap,
+            "d1" => Scheme::D1,
+            "dropbox" => Scheme::Dropbox,
+            "etcd" => Scheme::Etcd,
+            "foundationdb" => Scheme::Foundationdb,
+            "fs" => Scheme::Fs,
+            "gcs" => Scheme::Gcs,
+            "gdrive" => Scheme::Gdrive,
+            "ghac" => Scheme::Ghac,
+            "hdfs" => Scheme::Hdfs,
+            "hdfs_native" => Scheme::HdfsNative,
+            "http" | "https" => Scheme::Http,
+            "ftp" | "ftps" => Scheme::Ftp,
+            "ipfs" | "ipns" => Scheme::Ipfs,
+            "ipmfs" => Scheme::Ipmfs,
+            "libsql" => Scheme::Libsql,
+            "memcached" => Scheme::Memcached,
+            "memory" => Scheme::Memory,
+            "mini_moka" => Scheme::MiniMoka,
+            "moka" => Scheme::Moka,
+            "obs" => Scheme::Obs,
+            "onedrive" => Scheme::Onedrive,
+            "persy" => Scheme::Persy,
+            "postgresql" => Scheme::Postgresql,
+            "redb" => Scheme::Redb,
+            "redis" => Scheme::Redis,
+            "rocksdb" => Scheme::Rocksdb,
+            "s3" => Scheme::S3,
+            "sftp" => Scheme::Sftp,
+            "sled" => Scheme::Sled,
+            "supabase" => Scheme::Supabase,
+            "oss" => Scheme::Oss,
+            "vercel_artifacts" => Scheme::VercelArtifacts,
+            "wasabi" => Scheme::Wasabi,
+            "webdav" => Scheme::Webdav,
+            "webhdfs" => Scheme::Webhdfs,
+            "tikv" => Scheme::Tikv,
+            _ => {
+                return Err(
+                    Error::new(ErrorKind::Unsupported, "scheme is not supported")
+                        .with_context("scheme", s)
+                        .with_context("enabled", Scheme::format_enabled()),
+                )
+            }
+        };
+
+        if !Scheme::enabled().contains(&scheme) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "scheme is not enabled, please enable the corresponding feature",
+            )
+            .with_context("scheme", scheme)
+            .with_context("feature", scheme.feature())
+            .with_context("enabled", Scheme::format_enabled()));
         }
+
+        Ok(scheme)
     }
 }
 
@@ -192,16 +1074,19 @@ impl From<Scheme> for &'static str {
             Scheme::Cacache => "cacache",
             Scheme::Cos => "cos",
             Scheme::Dashmap => "dashmap",
+            Scheme::D1 => "d1",
             Scheme::Etcd => "etcd",
             Scheme::Fs => "fs",
             Scheme::Gcs => "gcs",
             Scheme::Ghac => "ghac",
             Scheme::Hdfs => "hdfs",
+            Scheme::HdfsNative => "hdfs_native",
             Scheme::Http => "http",
             Scheme::Foundationdb => "foundationdb",
             Scheme::Ftp => "ftp",
             Scheme::Ipfs => "ipfs",
             Scheme::Ipmfs => "ipmfs",
+            Scheme::Libsql => "libsql",
             Scheme::Memcached => "memcached",
             Scheme::Memory => "memory",
             Scheme::MiniMoka => "mini_moka",
@@ -235,3 +1120,169 @@ impl From<Scheme> for String {
         v.into_static().to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::Accessor;
+    use crate::services;
+    use crate::Builder;
+
+    #[test]
+    fn test_parse_enabled_scheme() {
+        assert_eq!("memory".parse::<Scheme>().unwrap(), Scheme::Memory);
+        assert_eq!("HTTPS".parse::<Scheme>().unwrap(), Scheme::Http);
+        assert!(Scheme::enabled().contains(&Scheme::Memory));
+    }
+
+    #[test]
+    #[cfg(not(feature = "services-sled"))]
+    fn test_parse_disabled_scheme() {
+        let err = "sled".parse::<Scheme>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let msg = err.to_string();
+        assert!(msg.contains("feature: services-sled"), "{msg}");
+        assert!(msg.contains("memory"), "{msg}");
+    }
+
+    #[test]
+    fn test_parse_unknown_scheme() {
+        let err = "unknown".parse::<Scheme>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        assert!(err.to_string().contains("memory"));
+    }
+
+    #[test]
+    fn test_default_capability() {
+        let cap = Scheme::S3.default_capability().unwrap();
+        assert!(cap.presign);
+        assert_eq!(cap.write_multi_min_size, Some(5 * 1024 * 1024));
+        assert!(cap.list_with_delimiter_custom);
+        assert!(
+            !Scheme::Fs
+                .default_capability()
+                .unwrap()
+                .list_with_delimiter_custom
+        );
+
+        assert!(Scheme::Custom("custom").default_capability().is_none());
+    }
+
+    #[test]
+    fn test_default_capability_batch_max_operations() {
+        let cases = [
+            (Scheme::Azblob, Some(256)),
+            (Scheme::Dropbox, Some(1000)),
+            (Scheme::Gcs, Some(100)),
+            (Scheme::S3, Some(1000)),
+            (Scheme::Wasabi, Some(1000)),
+            (Scheme::Fs, None),
+            (Scheme::Memory, None),
+        ];
+
+        for (scheme, expected) in cases {
+            let cap = scheme.default_capability().unwrap();
+            assert_eq!(cap.batch_max_operations, expected, "{scheme}");
+            // Only services supporting batch have the limit.
+            assert_eq!(cap.batch, expected.is_some(), "{scheme}");
+        }
+    }
+
+    #[test]
+    fn test_default_capability_rename_atomic() {
+        let cases = vec![
+            (Scheme::Azdfs, true),
+            (Scheme::Fs, true),
+            (Scheme::HdfsNative, true),
+            (Scheme::Redis, true),
+            (Scheme::Sftp, true),
+            // Native but not atomic.
+            (Scheme::Hdfs, false),
+            (Scheme::Memory, false),
+            (Scheme::Wasabi, false),
+            (Scheme::Webdav, false),
+            // Emulated by copy and delete.
+            (Scheme::S3, false),
+        ];
+
+        for (scheme, atomic) in cases {
+            let cap = scheme.default_capability().unwrap();
+            assert_eq!(cap.rename_atomic, atomic, "{scheme}");
+        }
+    }
+
+    /// Build the backend of given builder with config and return its
+    /// native capability.
+    fn native_capability<B: Builder>(config: &[(&str, &str)]) -> (Scheme, Capability) {
+        let map = config
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let info = B::from_map(map).build().expect("build must succeed").info();
+        (info.scheme(), info.capability())
+    }
+
+    #[test]
+    fn test_default_capability_matches_backend() {
+        #[allow(unused_mut)]
+        let mut cases: Vec<(Scheme, Capability)> = vec![];
+        #[cfg(feature = "services-azblob")]
+        cases.push(native_capability::<services::Azblob>(&[
+            ("container", "container"),
+            ("endpoint", "http://127.0.0.1:10000/account"),
+        ]));
+        #[cfg(feature = "services-azdfs")]
+        cases.push(native_capability::<services::Azdfs>(&[
+            ("filesystem", "filesystem"),
+            ("endpoint", "http://127.0.0.1:10000/account"),
+        ]));
+        #[cfg(feature = "services-cos")]
+        cases.push(native_capability::<services::Cos>(&[
+            ("bucket", "bucket"),
+            ("endpoint", "http://127.0.0.1:9000"),
+        ]));
+        #[cfg(feature = "services-fs")]
+        cases.push(native_capability::<services::Fs>(&[("root", "/tmp")]));
+        #[cfg(feature = "services-http")]
+        cases.push(native_capability::<services::Http>(&[(
+            "endpoint",
+            "http://127.0.0.1:8080",
+        )]));
+        #[cfg(feature = "services-ipmfs")]
+        cases.push(native_capability::<services::Ipmfs>(&[]));
+        #[cfg(feature = "services-memory")]
+        cases.push(native_capability::<services::Memory>(&[]));
+        #[cfg(feature = "services-obs")]
+        cases.push(native_capability::<services::Obs>(&[
+            ("bucket", "bucket"),
+            ("endpoint", "http://127.0.0.1:9000"),
+        ]));
+        #[cfg(feature = "services-oss")]
+        cases.push(native_capability::<services::Oss>(&[
+            ("bucket", "bucket"),
+            ("endpoint", "http://127.0.0.1:9000"),
+        ]));
+        #[cfg(feature = "services-s3")]
+        cases.push(native_capability::<services::S3>(&[
+            ("bucket", "bucket"),
+            ("region", "us-east-1"),
+            ("endpoint", "http://127.0.0.1:9000"),
+        ]));
+        #[cfg(feature = "services-webdav")]
+        cases.push(native_capability::<services::Webdav>(&[(
+            "endpoint",
+            "http://127.0.0.1:8080",
+        )]));
+        #[cfg(feature = "services-webhdfs")]
+        cases.push(native_capability::<services::Webhdfs>(&[(
+            "endpoint",
+            "http://127.0.0.1:9870",
+        )]));
+
+        for (scheme, cap) in cases {
+            let expected = scheme.default_capability().unwrap();
+            assert!(cap == expected, "capability of {scheme} doesn't match");
+        }
+    }
+}
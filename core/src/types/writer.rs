@@ -40,6 +40,17 @@ use crate::*;
 /// Please make sure either `close` or `abort` has been called before
 /// dropping the writer otherwise the data could be lost.
 ///
+/// ## Cancel safety
+///
+/// Objects only become visible after `close` returns successfully. Dropping
+/// the writer, or cancelling any of its futures before `close` finishes, will
+/// never commit a partial object. For services based on multipart uploads,
+/// the upload is left uncompleted so that it will be purged by the service's
+/// lifecycle rules or an explicit `abort`.
+///
+/// The only exception is `fs` without `atomic_write_dir`, which writes into
+/// the target file directly.
+///
 /// ## Notes
 ///
 /// Writer can be used in two ways:
@@ -72,23 +83,60 @@ impl Writer {
     /// We don't want to expose those details to users so keep this function
     /// in crate only.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpWrite) -> Result<Self> {
+        let progress = op.progress().cloned();
+        let total = op.content_length();
         let (_, w) = acc.write(path, op).await?;
+        let w = match progress {
+            Some(progress) => Box::new(oio::into_progress_write(w, progress, total)) as oio::Writer,
+            None => w,
+        };
 
         Ok(Writer {
             state: State::Idle(Some(w)),
         })
     }
 
+    /// Get the inner writer, finishing the pending operation left by a
+    /// cancelled `AsyncWrite` call first.
+    ///
+    /// Pending futures are polled by reference so that this function is
+    /// cancel safe too.
+    async fn writer(&mut self) -> Result<&mut oio::Writer> {
+        loop {
+            match &mut self.state {
+                State::Idle(Some(_)) => break,
+                State::Idle(None) => {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "writer has been closed or aborted",
+                    ))
+                }
+                State::Write(fut) => match fut.await {
+                    Ok((_, w)) => self.state = State::Idle(Some(w)),
+                    Err(err) => {
+                        self.state = State::Idle(None);
+                        return Err(err);
+                    }
+                },
+                State::Close(fut) => match fut.await {
+                    Ok(w) => self.state = State::Idle(Some(w)),
+                    Err(err) => {
+                        self.state = State::Idle(None);
+                        return Err(err);
+                    }
+                },
+            }
+        }
+
+        match &mut self.state {
+            State::Idle(Some(w)) => Ok(w),
+            _ => unreachable!("writer state must be Idle with writer"),
+        }
+    }
+
     /// Write into inner writer.
     pub async fn write(&mut self, bs: impl Into<Bytes>) -> Result<()> {
-        if let State::Idle(Some(w)) = &mut self.state {
-            w.write(bs.into()).await
-        } else {
-            unreachable!(
-                "writer state invalid while write, expect Idle, actual {}",
-                self.state
-            );
-        }
+        self.writer().await?.write(bs.into()).await
     }
 
     /// Sink into writer.
@@ -99,7 +147,8 @@ impl Writer {
     /// # Notes
     ///
     /// - Sink doesn't support to be used with write concurrently.
-    /// - Sink doesn't support to be used without content length now.
+    /// - Sink requires the size of stream, use [`Writer::sink_unsized`]
+    ///   for streams of unknown size.
     ///
     /// # Examples
     ///
@@ -128,15 +177,8 @@ impl Writer {
         S: futures::Stream<Item = Result<T>> + Send + Sync + Unpin + 'static,
         T: Into<Bytes>,
     {
-        if let State::Idle(Some(w)) = &mut self.state {
-            let s = Box::new(oio::into_stream(sink_from.map_ok(|v| v.into())));
-            w.sink(size, s).await
-        } else {
-            unreachable!(
-                "writer state invalid while sink, expect Idle, actual {}",
-                self.state
-            );
-        }
+        let s = Box::new(oio::into_stream(sink_from.map_ok(|v| v.into())));
+        self.writer().await?.sink(size, s).await
     }
 
     /// Copy into writer.
@@ -147,7 +189,8 @@ impl Writer {
     /// # Notes
     ///
     /// - Copy doesn't support to be used with write concurrently.
-    /// - Copy doesn't support to be used without content length now.
+    /// - Copy requires the size of reader, use [`Writer::copy_unsized`]
+    ///   for readers of unknown size.
     ///
     /// # Examples
     ///
@@ -173,15 +216,86 @@ impl Writer {
     where
         R: futures::AsyncRead + Send + Sync + Unpin + 'static,
     {
-        if let State::Idle(Some(w)) = &mut self.state {
-            let s = Box::new(oio::into_stream_from_reader(read_from));
-            w.sink(size, s).await
-        } else {
-            unreachable!(
-                "writer state invalid while copy, expect Idle, actual {}",
-                self.state
-            );
-        }
+        let s = Box::new(oio::into_stream_from_reader(read_from));
+        self.writer().await?.sink(size, s).await
+    }
+
+    /// Sink a stream of unknown size into writer.
+    ///
+    /// The writer should be created with `chunked_unknown_size` set, and
+    /// services will pick the best strategy they support:
+    ///
+    /// - Object storages like `s3` will upload the stream by multipart
+    ///   upload. Only one part (`write_min_size` of services) will be
+    ///   buffered in memory at any time.
+    /// - Http based services like `webdav` will send the stream in one
+    ///   request with chunked transfer encoding without buffering.
+    ///
+    /// # Notes
+    ///
+    /// - Sink unsized doesn't support to be used with write concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::io::Result;
+    ///
+    /// use bytes::Bytes;
+    /// use futures::stream;
+    /// use futures::StreamExt;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn sink_unsized_example(op: Operator) -> Result<()> {
+    ///     let mut w = op
+    ///         .writer_with("path/to/file")
+    ///         .chunked_unknown_size()
+    ///         .await?;
+    ///     let stream = stream::iter(vec![vec![0; 4096], vec![1; 4096]]).map(Ok);
+    ///     w.sink_unsized(stream).await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sink_unsized<S, T>(&mut self, sink_from: S) -> Result<()>
+    where
+        S: futures::Stream<Item = Result<T>> + Send + Sync + Unpin + 'static,
+        T: Into<Bytes>,
+    {
+        let s = Box::new(oio::into_stream(sink_from.map_ok(|v| v.into())));
+        self.writer().await?.sink_unsized(s).await
+    }
+
+    /// Copy a reader of unknown size into writer.
+    ///
+    /// Refer to [`Writer::sink_unsized`] for how services handle it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::io::Result;
+    ///
+    /// use futures::io::Cursor;
+    /// use opendal::Operator;
+    ///
+    /// #[tokio::main]
+    /// async fn copy_unsized_example(op: Operator) -> Result<()> {
+    ///     let mut w = op
+    ///         .writer_with("path/to/file")
+    ///         .chunked_unknown_size()
+    ///         .await?;
+    ///     let reader = Cursor::new(vec![0; 4096]);
+    ///     w.copy_unsized(reader).await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn copy_unsized<R>(&mut self, read_from: R) -> Result<()>
+    where
+        R: futures::AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        let s = Box::new(oio::into_stream_from_reader(read_from));
+        self.writer().await?.sink_unsized(s).await
     }
 
     /// Abort the writer and clean up all written data.
@@ -191,14 +305,7 @@ impl Writer {
     /// Abort should only be called when the writer is not closed or
     /// aborted, otherwise an unexpected error could be returned.
     pub async fn abort(&mut self) -> Result<()> {
-        if let State::Idle(Some(w)) = &mut self.state {
-            w.abort().await
-        } else {
-            unreachable!(
-                "writer state invalid while abort, expect Idle, actual {}",
-                self.state
-            );
-        }
+        self.writer().await?.abort().await
     }
 
     /// Close the writer and make sure all data have been committed.
@@ -208,14 +315,7 @@ impl Writer {
     /// Close should only be called when the writer is not closed or
     /// aborted, otherwise an unexpected error could be returned.
     pub async fn close(&mut self) -> Result<()> {
-        if let State::Idle(Some(w)) = &mut self.state {
-            w.close().await
-        } else {
-            unreachable!(
-                "writer state invalid while close, expect Idle, actual {}",
-                self.state
-            );
-        }
+        self.writer().await?.close().await
     }
 }
 
@@ -287,9 +387,11 @@ impl AsyncWrite for Writer {
                     };
                     self.state = State::Close(Box::pin(fut));
                 }
-                State::Write(_) => {
-                    unreachable!("invalid state of writer: poll_close with State::Write")
-                }
+                // Finish the write left by a cancelled `poll_write` first.
+                State::Write(fut) => match ready!(fut.poll_unpin(cx)) {
+                    Ok((_, w)) => self.state = State::Idle(Some(w)),
+                    Err(err) => return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, err))),
+                },
                 State::Close(fut) => match ready!(fut.poll_unpin(cx)) {
                     Ok(w) => {
                         self.state = State::Idle(Some(w));
@@ -353,9 +455,11 @@ impl tokio::io::AsyncWrite for Writer {
                     };
                     self.state = State::Close(Box::pin(fut));
                 }
-                State::Write(_) => {
-                    unreachable!("invalid state of writer: poll_close with State::Write")
-                }
+                // Finish the write left by a cancelled `poll_write` first.
+                State::Write(fut) => match ready!(fut.poll_unpin(cx)) {
+                    Ok((_, w)) => self.state = State::Idle(Some(w)),
+                    Err(err) => return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, err))),
+                },
                 State::Close(fut) => match ready!(fut.poll_unpin(cx)) {
                     Ok(w) => {
                         self.state = State::Idle(Some(w));
@@ -36,6 +36,8 @@ pub fn behavior_append_tests(op: &Operator) -> Vec<Trial> {
     async_trials!(
         op,
         test_append_create_append,
+        test_append_create_with_parent,
+        test_append_to_written_file,
         test_append_with_dir_path,
         test_append_with_cache_control,
         test_append_with_content_type,
@@ -70,6 +72,61 @@ pub async fn test_append_create_append(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Test append to a file whose parent doesn't exist must create it.
+pub async fn test_append_create_with_parent(op: Operator) -> Result<()> {
+    let path = format!(
+        "{}/{}/{}",
+        uuid::Uuid::new_v4(),
+        uuid::Uuid::new_v4(),
+        uuid::Uuid::new_v4()
+    );
+    let (content, size) = gen_bytes();
+
+    op.append(&path, content.clone())
+        .await
+        .expect("append to a not exist file must success");
+
+    let bs = op.read(&path).await.expect("read file must success");
+    assert_eq!(bs.len(), size);
+    assert_eq!(bs, content);
+
+    op.delete(&path).await.expect("delete file must success");
+
+    Ok(())
+}
+
+/// Test append to a file created by write must either append to the end or
+/// fail with `ConditionNotMatch` if the file is not appendable.
+pub async fn test_append_to_written_file(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content_one, size_one) = gen_bytes();
+    let (content_two, size_two) = gen_bytes();
+
+    op.write(&path, content_one.clone())
+        .await
+        .expect("write file must success");
+
+    match op.append(&path, content_two.clone()).await {
+        Ok(()) => {
+            let bs = op.read(&path).await.expect("read file must success");
+            assert_eq!(bs.len(), size_one + size_two);
+            assert_eq!(bs[..size_one], content_one);
+            assert_eq!(bs[size_one..], content_two);
+        }
+        Err(err) => {
+            assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+            // The file must be untouched.
+            let bs = op.read(&path).await.expect("read file must success");
+            assert_eq!(bs, content_one);
+        }
+    }
+
+    op.delete(&path).await.expect("delete file must success");
+
+    Ok(())
+}
+
 /// Test append to a directory path must fail.
 pub async fn test_append_with_dir_path(op: Operator) -> Result<()> {
     let path = format!("{}/", uuid::Uuid::new_v4());
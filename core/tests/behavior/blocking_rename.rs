@@ -22,7 +22,7 @@ use crate::*;
 pub fn behavior_blocking_rename_tests(op: &Operator) -> Vec<Trial> {
     let cap = op.info().capability();
 
-    if !(cap.read && cap.write && cap.copy && cap.blocking && cap.rename) {
+    if !(cap.read && cap.write && cap.copy && cap.blocking && (cap.rename || cap.rename_emulated)) {
         return vec![];
     }
 
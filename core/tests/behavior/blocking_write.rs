@@ -51,6 +51,8 @@ pub fn behavior_blocking_write_tests(op: &Operator) -> Vec<Trial> {
         test_blocking_fuzz_offset_reader,
         test_blocking_fuzz_part_reader,
         test_blocking_delete_file,
+        test_blocking_delete_not_existing,
+        test_blocking_delete_dir_recursive,
         test_blocking_remove_one_file
     )
 }
@@ -412,6 +414,35 @@ pub fn test_blocking_delete_file(op: BlockingOperator) -> Result<()> {
     Ok(())
 }
 
+/// Delete not existing file should also succeed.
+pub fn test_blocking_delete_not_existing(op: BlockingOperator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    op.delete(&path)?;
+
+    Ok(())
+}
+
+/// Delete dir with recursive should remove all its children.
+pub fn test_blocking_delete_dir_recursive(op: BlockingOperator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes_with_range(1..1024);
+
+    let files = [format!("{dir}a"), format!("{dir}b/c")];
+    for path in files.iter() {
+        op.write(path, content.clone()).expect("write must succeed");
+    }
+
+    op.delete_with(&dir).recursive(true).call()?;
+
+    for path in files.iter() {
+        assert!(!op.is_exist(path)?, "{path} should be deleted");
+    }
+    assert_eq!(op.list(&dir)?.count(), 0, "{dir} should be empty");
+
+    Ok(())
+}
+
 /// Remove one file
 pub fn test_blocking_remove_one_file(op: BlockingOperator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -34,7 +34,9 @@ pub fn behavior_copy_tests(op: &Operator) -> Vec<Trial> {
         test_copy_target_dir,
         test_copy_self,
         test_copy_nested,
-        test_copy_overwrite
+        test_copy_overwrite,
+        test_copy_dir_recursive,
+        test_copy_recursive_into_source
     )
 }
 
@@ -170,3 +172,74 @@ pub async fn test_copy_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy a dir recursively should copy all entries with the same structure.
+pub async fn test_copy_dir_recursive(op: Operator) -> Result<()> {
+    if !op.info().capability().list {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    let files = ["a", "b/c", "b/d/e", "f/g"];
+    let mut contents = vec![];
+    for file in files {
+        let (content, _) = gen_bytes_with_range(1..1024);
+        op.write(&format!("{source_dir}{file}"), content.clone())
+            .await?;
+        contents.push(content);
+    }
+
+    op.copy_with(&source_dir, &target_dir)
+        .recursive(true)
+        .concurrent(2)
+        .await?;
+
+    for (file, content) in files.iter().zip(contents) {
+        let bs = op
+            .read(&format!("{target_dir}{file}"))
+            .await
+            .expect("read must succeed");
+        assert_eq!(bs, content, "content of {file} should be copied");
+    }
+
+    let mut copied = op
+        .list_with(&target_dir)
+        .delimiter("")
+        .await?
+        .into_iter()
+        .filter(|v| v.metadata().mode().is_file())
+        .map(|v| v.path()[target_dir.len()..].to_string())
+        .collect::<Vec<_>>();
+    copied.sort();
+    assert_eq!(copied, files);
+
+    // Source should be kept.
+    assert!(op.is_exist(&format!("{source_dir}b/d/e")).await?);
+
+    op.remove_all(&source_dir).await?;
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
+
+/// Copy a dir recursively into itself should return an error.
+pub async fn test_copy_recursive_into_source(op: Operator) -> Result<()> {
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let target_dir = format!("{source_dir}sub/");
+
+    let err = op
+        .copy_with(&source_dir, &target_dir)
+        .recursive(true)
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let err = op
+        .copy_with(&format!("{source_dir}file"), &target_dir)
+        .recursive(true)
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    Ok(())
+}
@@ -48,7 +48,9 @@ pub fn behavior_list_tests(op: &Operator) -> Vec<Trial> {
         test_list_with_start_after,
         test_scan,
         test_scan_root,
-        test_remove_all
+        test_list_with_absolute,
+        test_remove_all,
+        test_remove_via_lister
     )
 }
 
@@ -427,6 +429,63 @@ pub async fn test_scan(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with absolute set to false should return paths relative to the
+/// listed directory.
+pub async fn test_list_with_absolute(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    for path in ["x/y", "x/x/y", "x/x/x/y"] {
+        op.write(&format!("{parent}/{path}"), "test_list_with_absolute")
+            .await?;
+    }
+
+    let dir = format!("{parent}/x/");
+    let files = |entries: Vec<Entry>| {
+        entries
+            .into_iter()
+            .filter(|v| v.metadata().mode() == EntryMode::FILE)
+            .map(|v| v.path().to_string())
+            .collect::<HashSet<_>>()
+    };
+
+    let absolute = files(op.list_with(&dir).delimiter("").await?);
+    let expected = ["x/y", "x/x/y", "x/x/x/y"]
+        .iter()
+        .map(|v| format!("{parent}/{v}"))
+        .collect::<HashSet<_>>();
+    assert_eq!(absolute, expected);
+
+    let relative = files(op.list_with(&dir).delimiter("").absolute(false).await?);
+    let expected = ["y", "x/y", "x/x/y"]
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<HashSet<_>>();
+    assert_eq!(relative, expected);
+
+    // Lister should behave the same as list.
+    let relative = files(
+        op.lister_with(&dir)
+            .delimiter("")
+            .absolute(false)
+            .await?
+            .try_collect()
+            .await?,
+    );
+    assert_eq!(relative, expected);
+
+    // Non-recursive listing returns the sub dir relatively too.
+    let entries = op.list_with(&dir).absolute(false).await?;
+    let paths = entries
+        .iter()
+        .map(|v| v.path())
+        .filter(|v| *v != "/")
+        .collect::<HashSet<_>>();
+    assert_eq!(paths, HashSet::from(["y", "x/"]));
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
 // Remove all should remove all in this path.
 pub async fn test_remove_all(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
@@ -455,3 +514,38 @@ pub async fn test_remove_all(op: Operator) -> Result<()> {
     }
     Ok(())
 }
+
+// Remove via lister should remove all entries by chunks.
+pub async fn test_remove_via_lister(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let op = op.with_limit(10);
+
+    for i in 0..45 {
+        op.write(&format!("{parent}/x/{i}"), "test_remove_via_lister")
+            .await?;
+    }
+
+    let lister = op
+        .lister_with(&format!("{parent}/x/"))
+        .delimiter("")
+        .await?;
+    let mut progress = vec![];
+    op.remove_via_lister(lister, Some(&mut |n| progress.push(n)))
+        .await?;
+
+    // Every step should be bounded by the batch limit.
+    assert_eq!(progress.last(), Some(&45));
+    let mut last = 0;
+    for n in progress {
+        assert!(n > last && n - last <= 10, "step must be bounded by limit");
+        last = n;
+    }
+
+    for i in 0..45 {
+        assert!(
+            !op.is_exist(&format!("{parent}/x/{i}")).await?,
+            "{parent}/x/{i} should be removed"
+        )
+    }
+    Ok(())
+}
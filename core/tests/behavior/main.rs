@@ -53,6 +53,11 @@ use blocking_list::behavior_blocking_list_tests;
 use blocking_read_only::behavior_blocking_read_only_tests;
 use blocking_rename::behavior_blocking_rename_tests;
 use blocking_write::behavior_blocking_write_tests;
+
+// Service specific test cases
+#[cfg(feature = "services-webdav")]
+mod webdav;
+
 // External dependences
 use libtest_mimic::{Arguments, Trial};
 use once_cell::sync::Lazy;
@@ -107,6 +112,8 @@ fn main() -> anyhow::Result<()> {
     tests.extend(behavior_test::<services::Cos>());
     #[cfg(feature = "services-dashmap")]
     tests.extend(behavior_test::<services::Dashmap>());
+    #[cfg(feature = "services-d1")]
+    tests.extend(behavior_test::<services::D1>());
     #[cfg(feature = "services-etcd")]
     tests.extend(behavior_test::<services::Etcd>());
     #[cfg(feature = "services-foundationdb")]
@@ -121,12 +128,16 @@ fn main() -> anyhow::Result<()> {
     tests.extend(behavior_test::<services::Ghac>());
     #[cfg(feature = "services-hdfs")]
     tests.extend(behavior_test::<services::Hdfs>());
+    #[cfg(feature = "services-hdfs-native")]
+    tests.extend(behavior_test::<services::HdfsNative>());
     #[cfg(feature = "services-http")]
     tests.extend(behavior_test::<services::Http>());
     #[cfg(feature = "services-ipfs")]
     tests.extend(behavior_test::<services::Ipfs>());
     #[cfg(feature = "services-ipmfs")]
     tests.extend(behavior_test::<services::Ipmfs>());
+    #[cfg(feature = "services-libsql")]
+    tests.extend(behavior_test::<services::Libsql>());
     #[cfg(feature = "services-memcached")]
     tests.extend(behavior_test::<services::Memcached>());
     #[cfg(feature = "services-memory")]
@@ -167,6 +178,8 @@ fn main() -> anyhow::Result<()> {
     tests.extend(behavior_test::<services::Wasabi>());
     #[cfg(feature = "services-webdav")]
     tests.extend(behavior_test::<services::Webdav>());
+    #[cfg(feature = "services-webdav")]
+    tests.extend(webdav::behavior_webdav_tests());
     #[cfg(feature = "services-webhdfs")]
     tests.extend(behavior_test::<services::Webhdfs>());
     #[cfg(feature = "services-redb")]
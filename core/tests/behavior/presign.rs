@@ -35,7 +35,13 @@ pub fn behavior_presign_tests(op: &Operator) -> Vec<Trial> {
         return vec![];
     }
 
-    async_trials!(op, test_presign_write, test_presign_read, test_presign_stat)
+    async_trials!(
+        op,
+        test_presign_write,
+        test_presign_read,
+        test_presign_read_with_override_content_type,
+        test_presign_stat
+    )
 }
 
 /// Presign write should succeed.
@@ -135,3 +141,43 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+// Presign read with override content type should respond with given content type.
+pub async fn test_presign_read_with_override_content_type(op: Operator) -> Result<()> {
+    if !op.info().capability().read_with_override_content_type {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes();
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let signed_req = op
+        .presign_read_with(&path, Duration::from_secs(3600))
+        .override_content_type("image/png")
+        .await?;
+    debug!("Generated request: {signed_req:?}");
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send request must succeed");
+    assert!(resp.status().is_success(), "presigned read must succeed");
+    assert_eq!(
+        resp.headers()
+            .get(header::CONTENT_TYPE)
+            .expect("content type must exist"),
+        "image/png"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
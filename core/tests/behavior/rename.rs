@@ -22,7 +22,7 @@ use crate::*;
 pub fn behavior_rename_tests(op: &Operator) -> Vec<Trial> {
     let cap = op.info().capability();
 
-    if !(cap.read && cap.write && cap.rename) {
+    if !(cap.read && cap.write && (cap.rename || cap.rename_emulated)) {
         return vec![];
     }
 
@@ -34,7 +34,8 @@ pub fn behavior_rename_tests(op: &Operator) -> Vec<Trial> {
         test_rename_target_dir,
         test_rename_self,
         test_rename_nested,
-        test_rename_overwrite
+        test_rename_overwrite,
+        test_rename_dir
     )
 }
 
@@ -179,3 +180,39 @@ pub async fn test_rename_overwrite(op: Operator) -> Result<()> {
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Rename a dir should move all its children if service supports it.
+pub async fn test_rename_dir(op: Operator) -> Result<()> {
+    if !op.info().capability().rename_can_dir {
+        return Ok(());
+    }
+
+    let source_dir = format!("{}/", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes();
+
+    op.create_dir(&source_dir).await?;
+    op.write(&format!("{source_dir}file"), content.clone())
+        .await?;
+    op.write(&format!("{source_dir}nested/file"), content.clone())
+        .await?;
+
+    let target_dir = format!("{}/", uuid::Uuid::new_v4());
+
+    op.rename(&source_dir, &target_dir).await?;
+
+    for path in ["file", "nested/file"] {
+        assert!(
+            !op.is_exist(&format!("{source_dir}{path}")).await?,
+            "{source_dir}{path} should be moved"
+        );
+
+        let target_content = op
+            .read(&format!("{target_dir}{path}"))
+            .await
+            .expect("read must succeed");
+        assert_eq!(target_content, content);
+    }
+
+    op.remove_all(&target_dir).await?;
+    Ok(())
+}
@@ -45,6 +45,32 @@ use crate::RUNTIME;
 /// - If `opendal_{schema}_test` is on, construct a new Operator with given root.
 /// - Else, returns a `None` to represent no valid config for operator.
 pub fn init_service<B: Builder>() -> Option<Operator> {
+    let cfg = init_service_config::<B>()?;
+
+    let op = Operator::from_map::<B>(cfg).expect("must succeed");
+
+    #[cfg(feature = "layers-chaos")]
+    let op = {
+        use opendal::layers::ChaosLayer;
+        op.layer(ChaosLayer::new(0.1))
+    };
+
+    let _guard = RUNTIME.enter();
+    let op = op
+        .layer(BlockingLayer::create().expect("blocking layer must be created"))
+        .layer(LoggingLayer::default())
+        .layer(TimeoutLayer::new())
+        .layer(RetryLayer::new())
+        .finish();
+
+    Some(op)
+}
+
+/// Load the config of service with given scheme from env.
+///
+/// Returns `None` if `opendal_{schema}_test` is not on, the root will be
+/// randomized unless `OPENDAL_DISABLE_RANDOM_ROOT` is set to true.
+pub fn init_service_config<B: Builder>() -> Option<HashMap<String, String>> {
     let _ = dotenvy::dotenv();
 
     let prefix = format!("opendal_{}_", B::SCHEME);
@@ -74,23 +100,7 @@ pub fn init_service<B: Builder>() -> Option<Operator> {
         cfg.insert("root".to_string(), root);
     }
 
-    let op = Operator::from_map::<B>(cfg).expect("must succeed");
-
-    #[cfg(feature = "layers-chaos")]
-    let op = {
-        use opendal::layers::ChaosLayer;
-        op.layer(ChaosLayer::new(0.1))
-    };
-
-    let _guard = RUNTIME.enter();
-    let op = op
-        .layer(BlockingLayer::create().expect("blocking layer must be created"))
-        .layer(LoggingLayer::default())
-        .layer(TimeoutLayer::new())
-        .layer(RetryLayer::new())
-        .finish();
-
-    Some(op)
+    Some(cfg)
 }
 
 pub fn gen_bytes() -> (Vec<u8>, usize) {
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use libtest_mimic::Failed;
+use libtest_mimic::Trial;
+use opendal::services::Webdav;
+use opendal::services::WebdavBackend;
+use opendal::services::WebdavLockScope;
+use opendal::Builder;
+use opendal::ErrorKind;
+use opendal::OperatorBuilder;
+
+use crate::*;
+
+/// Webdav only tests for features that not exposed by Operator, like locking.
+pub fn behavior_webdav_tests() -> Vec<Trial> {
+    let cfg = match init_service_config::<Webdav>() {
+        Some(cfg) => cfg,
+        None => return Vec::new(),
+    };
+    let backend = Webdav::from_map(cfg)
+        .build()
+        .expect("webdav backend must be built");
+
+    vec![build_webdav_trial("test_lock", &backend, test_lock)]
+}
+
+fn build_webdav_trial<F, Fut>(name: &str, backend: &WebdavBackend, f: F) -> Trial
+where
+    F: FnOnce(WebdavBackend) -> Fut + Send + 'static,
+    Fut: futures::Future<Output = Result<()>>,
+{
+    let name = format!("services_webdav::{}", name);
+    let handle = RUNTIME.handle().clone();
+    let backend = backend.clone();
+
+    Trial::test(name, move || {
+        handle
+            .block_on(f(backend))
+            .map_err(|err| Failed::from(err.to_string()))
+    })
+}
+
+/// Lock a resource should block the second locker until unlocked.
+pub async fn test_lock(backend: WebdavBackend) -> Result<()> {
+    let op = OperatorBuilder::new(backend.clone()).finish();
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes();
+    op.write(&path, content).await.expect("write must succeed");
+
+    let timeout = Some(Duration::from_secs(60));
+    let lock = backend
+        .lock(&path, WebdavLockScope::Exclusive, timeout)
+        .await?;
+    assert!(!lock.token().is_empty());
+    assert_eq!(lock.scope(), WebdavLockScope::Exclusive);
+
+    let err = backend
+        .lock(&path, WebdavLockScope::Exclusive, timeout)
+        .await
+        .expect_err("second locker must be blocked");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+    // Writing without lock token must be rejected.
+    let (content, _) = gen_bytes();
+    assert!(op.write(&path, content.clone()).await.is_err());
+
+    let refreshed = backend.refresh_lock(&path, lock.token(), timeout).await?;
+    assert_eq!(refreshed.token(), lock.token());
+
+    backend
+        .write_with_lock(&path, lock.token(), content.clone().into())
+        .await?;
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, content, "content written with lock");
+
+    backend.unlock(&path, lock.token()).await?;
+
+    // The resource could be locked again after unlocked.
+    let lock = backend
+        .lock(&path, WebdavLockScope::Exclusive, timeout)
+        .await?;
+    backend.unlock(&path, lock.token()).await?;
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
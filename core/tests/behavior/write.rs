@@ -16,6 +16,9 @@
 // under the License.
 
 use std::str::FromStr;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Result;
@@ -32,6 +35,8 @@ use reqwest::Url;
 use sha2::Digest;
 use sha2::Sha256;
 
+use opendal::raw::format_content_md5;
+
 use crate::*;
 
 pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
@@ -51,6 +56,10 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_write_with_cache_control,
         test_write_with_content_type,
         test_write_with_content_disposition,
+        test_write_with_content_md5,
+        test_write_with_checksum_algorithm,
+        test_write_with_progress,
+        test_write_with_if_not_exists,
         test_stat_file,
         test_stat_dir,
         test_stat_with_special_chars,
@@ -60,6 +69,8 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_stat_with_if_none_match,
         test_stat_root,
         test_read_full,
+        test_read_to_writer,
+        test_read_with_progress,
         test_read_range,
         test_read_large_range,
         test_reader_range,
@@ -78,10 +89,15 @@ pub fn behavior_write_tests(op: &Operator) -> Vec<Trial> {
         test_read_with_override_content_type,
         test_delete_file,
         test_delete_empty_dir,
+        test_delete_dir_recursive,
+        test_delete_non_empty_dir_without_recursive,
         test_delete_with_special_chars,
         test_delete_not_existing,
+        test_delete_not_existing_dir_recursive,
+        test_remove_not_existing,
         test_delete_stream,
         test_remove_one_file,
+        test_batch_write,
         test_writer_write,
         test_writer_sink,
         test_writer_copy,
@@ -246,6 +262,113 @@ pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with content md5 should succeed, and corrupted
+/// content should be rejected.
+pub async fn test_write_with_content_md5(op: Operator) -> Result<()> {
+    if !op.info().capability().write_with_content_md5 {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.write_with(&path, content.clone())
+        .content_md5(&format_content_md5(&content))
+        .await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let mut corrupted = content.clone();
+    corrupted[0] = !corrupted[0];
+    let err = op
+        .write_with(&path, corrupted)
+        .content_md5(&format_content_md5(&content))
+        .await
+        .expect_err("write with corrupted content must fail");
+    assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs, content, "content must not be overwritten");
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Write a single file with checksum algorithm should succeed.
+pub async fn test_write_with_checksum_algorithm(op: Operator) -> Result<()> {
+    if !op.info().capability().write_with_checksum_algorithm {
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes();
+
+    op.write_with(&path, content.clone())
+        .checksum_algorithm(ChecksumAlgorithm::Crc32c)
+        .await?;
+
+    let bs = op.read(&path).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+
+    Ok(())
+}
+
+/// Write with progress should report all bytes that have been written.
+pub async fn test_write_with_progress(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    let transferred = Arc::new(AtomicU64::new(0));
+    {
+        let transferred = transferred.clone();
+        op.write_with(&path, content)
+            .on_progress(move |n, total| {
+                assert_eq!(total, Some(size as u64));
+                transferred.store(n, Ordering::SeqCst);
+            })
+            .await?;
+    }
+    assert_eq!(transferred.load(Ordering::SeqCst), size as u64);
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Write with if_not_exists should fail if the file exists
+pub async fn test_write_with_if_not_exists(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.write_with(&path, content.clone())
+        .if_not_exists(true)
+        .await
+        .expect("write must succeed");
+
+    let err = op
+        .write_with(&path, "Hello, World!")
+        .if_not_exists(true)
+        .await
+        .expect_err("write must fail");
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Stat existing file should return metadata
 pub async fn test_stat_file(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -423,6 +546,57 @@ pub async fn test_read_full(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read with progress should report all bytes that have been read.
+pub async fn test_read_with_progress(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let transferred = Arc::new(AtomicU64::new(0));
+    let bs = {
+        let transferred = transferred.clone();
+        op.read_with(&path)
+            .on_progress(move |n, total| {
+                assert_eq!(total, Some(size as u64));
+                transferred.store(n, Ordering::SeqCst);
+            })
+            .await?
+    };
+    assert_eq!(bs, content, "read content");
+    assert_eq!(transferred.load(Ordering::SeqCst), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Read full content into writer should match.
+pub async fn test_read_to_writer(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+
+    op.write(&path, content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut w = Cursor::new(vec![]);
+    let n = op.read_to_writer(&path, &mut w).await?;
+    assert_eq!(n, size as u64, "read size");
+    let bs = w.into_inner();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
 /// Read range content should match.
 pub async fn test_read_range(op: Operator) -> Result<()> {
     if !op.info().capability().read_with_range {
@@ -1030,6 +1204,58 @@ pub async fn test_delete_empty_dir(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Delete dir with recursive should remove all its children.
+pub async fn test_delete_dir_recursive(op: Operator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let (content, _) = gen_bytes_with_range(1..1024);
+
+    let files = [
+        format!("{dir}a"),
+        format!("{dir}b/c"),
+        format!("{dir}b/d/e"),
+    ];
+    for path in files.iter() {
+        op.write(path, content.clone())
+            .await
+            .expect("write must succeed");
+    }
+    op.create_dir(&format!("{dir}f/"))
+        .await
+        .expect("create must succeed");
+
+    op.delete_with(&dir).recursive(true).await?;
+
+    for path in files.iter() {
+        assert!(!op.is_exist(path).await?, "{path} should be deleted");
+    }
+    assert!(op.list(&dir).await?.is_empty(), "{dir} should be empty");
+
+    Ok(())
+}
+
+/// Delete non-empty dir without recursive should return IsADirectory.
+pub async fn test_delete_non_empty_dir_without_recursive(op: Operator) -> Result<()> {
+    // Object storage services don't have real directories, deleting
+    // a dir will not affect its children.
+    if !op.info().capability().delete_with_recursive {
+        return Ok(());
+    }
+
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let path = format!("{dir}file");
+    let (content, _) = gen_bytes_with_range(1..1024);
+    op.write(&path, content).await.expect("write must succeed");
+
+    let err = op.delete(&dir).await.expect_err("delete must fail");
+    assert_eq!(err.kind(), ErrorKind::IsADirectory);
+    assert!(op.is_exist(&path).await?, "children should be kept");
+
+    op.delete_with(&dir).recursive(true).await?;
+    assert!(!op.is_exist(&path).await?);
+
+    Ok(())
+}
+
 /// Delete file with special chars should succeed.
 pub async fn test_delete_with_special_chars(op: Operator) -> Result<()> {
     // Ignore test for supabase until https://github.com/apache/incubator-opendal/issues/2194 addressed.
@@ -1061,6 +1287,24 @@ pub async fn test_delete_not_existing(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Delete not existing dir with recursive should also succeed.
+pub async fn test_delete_not_existing_dir_recursive(op: Operator) -> Result<()> {
+    let path = format!("{}/", uuid::Uuid::new_v4());
+
+    op.delete_with(&path).recursive(true).await?;
+
+    Ok(())
+}
+
+/// Remove not existing files should also succeed.
+pub async fn test_remove_not_existing(op: Operator) -> Result<()> {
+    let paths = (0..3).map(|_| uuid::Uuid::new_v4().to_string()).collect();
+
+    op.remove(paths).await?;
+
+    Ok(())
+}
+
 /// Remove one file
 pub async fn test_remove_one_file(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -1076,6 +1320,38 @@ pub async fn test_remove_one_file(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write and delete multiple files in one batch.
+pub async fn test_batch_write(op: Operator) -> Result<()> {
+    if !op.info().capability().batch_write {
+        return Ok(());
+    }
+
+    let existing = uuid::Uuid::new_v4().to_string();
+    op.write(&existing, "existing")
+        .await
+        .expect("write must succeed");
+
+    let mut ops = vec![(existing.clone(), None)];
+    let mut expected = vec![];
+    for _ in 0..3 {
+        let path = uuid::Uuid::new_v4().to_string();
+        let (content, _) = gen_bytes();
+        ops.push((path.clone(), Some(content.clone().into())));
+        expected.push((path, content));
+    }
+
+    op.batch_write(ops).await?;
+
+    assert!(!op.is_exist(&existing).await?);
+    for (path, content) in expected {
+        let bs = op.read(&path).await.expect("read must succeed");
+        assert_eq!(bs, content, "read content of {path}");
+
+        op.delete(&path).await.expect("delete must succeed");
+    }
+    Ok(())
+}
+
 /// Delete via stream.
 pub async fn test_delete_stream(op: Operator) -> Result<()> {
     let dir = uuid::Uuid::new_v4().to_string();